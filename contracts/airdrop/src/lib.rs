@@ -0,0 +1,1410 @@
+#![no_std]
+
+use launchpad_merkle as merkle;
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractclient, contractimpl, contracttype, symbol_short, token, vec,
+    xdr::ToXdr,
+    Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+/// Denominator for all basis-point values.
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Most transfers a single `distribute` call makes, to stay within the
+/// invocation budget.
+pub const DISTRIBUTION_CHUNK: u32 = 25;
+
+/// Most holders a single `ingest_holders` call reads from the registry.
+pub const INGESTION_CHUNK: u32 = 25;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Token,
+    RoundCount,
+    Round(u32),
+    TotalClaimed,
+    ClaimMode,
+    TotalDistributed,
+    /// Entries of a pushed batch paid so far, keyed by the batch hash.
+    Distribution(BytesN<32>),
+    /// Claimed bitmap of a round, 128 indices per word.
+    Claimed(u32, u32),
+    /// Amount paid so far on a streamed leaf of a round.
+    Streamed(u32, u32),
+    Attestor,
+    QuestPool,
+    QuestClaimed(u32, Address),
+    /// Registry index and snapshot balance of an eligible holder in a
+    /// holder round.
+    Eligible(u32, Address),
+}
+
+/// One airdrop wave with its own allocation list and budget.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Round {
+    pub source: RoundSource,
+    /// Last ledger on which claims are accepted.
+    pub deadline: u32,
+    /// Tokens set aside for the round.
+    pub total: i128,
+    pub claimed: i128,
+    pub swept: bool,
+}
+
+/// Where a round's allocations come from.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum RoundSource {
+    /// Root of an off-chain allocation tree.
+    Merkle(BytesN<32>),
+    /// Computed on-chain from a token snapshot.
+    Holders(HolderDrop),
+}
+
+/// Per-holder allocation rule of a holder round.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum HolderFormula {
+    /// The round total split in proportion to snapshot balances.
+    ProRata,
+    /// The same amount for every eligible holder.
+    Fixed(i128),
+}
+
+/// A round allocated to the holders of `registry` at snapshot
+/// `snapshot_id` with at least `min_balance`. Holders are read from the
+/// registry in chunks by `ingest_holders` before anyone can claim.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct HolderDrop {
+    pub registry: Address,
+    pub snapshot_id: u32,
+    pub formula: HolderFormula,
+    pub min_balance: i128,
+    /// Registry size when the round was added; later holders had no
+    /// balance at the snapshot.
+    pub holder_count: u32,
+    /// Holders read so far.
+    pub ingested: u32,
+    pub eligible_count: u32,
+    /// Sum of the eligible snapshot balances.
+    pub eligible_balance: i128,
+}
+
+/// Vesting applied to claims: `tge_bps` of each allocation is paid out on
+/// claim and the rest vests linearly from `cliff_ledger` to `end_ledger` in
+/// `vesting`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct VestingTerms {
+    pub vesting: Address,
+    pub tge_bps: u32,
+    pub cliff_ledger: u32,
+    pub end_ledger: u32,
+}
+
+/// Linear unlock of every allocation from `start_ledger` to `end_ledger`.
+/// Streams must end by the deadline of every open round, so each
+/// allocation has fully unlocked while it can still be claimed.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct StreamTerms {
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+}
+
+/// How claimed allocations are paid out.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum ClaimMode {
+    /// Transferred to the claimant in full.
+    Direct,
+    Vested(VestingTerms),
+    /// Allocations unlock linearly and each claim pays what has accrued.
+    Streaming(StreamTerms),
+}
+
+/// Authorized-creator endpoints of the vesting contract. Both pull the
+/// amount from `creator`.
+#[contractclient(name = "VestingClient")]
+pub trait Vesting {
+    fn create_schedule_from(
+        env: Env,
+        creator: Address,
+        recipient: Address,
+        total_amount: i128,
+        cliff_ledger: u32,
+        end_ledger: u32,
+    );
+    fn top_up_from(env: Env, creator: Address, recipient: Address, amount: i128);
+    fn has_schedule(env: Env, recipient: Address) -> bool;
+}
+
+/// Holder registry and balance snapshots of the token contract.
+#[contractclient(name = "HolderRegistryClient")]
+pub trait HolderRegistry {
+    fn snapshot_ledger(env: Env, snapshot_id: u32) -> u32;
+    fn holder_count(env: Env) -> u32;
+    fn holder_at(env: Env, index: u32) -> Address;
+    fn balance_at(env: Env, id: Address, snapshot_id: u32) -> i128;
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Airdrop Contract — Merkle distributor for community airdrops.
+///
+/// Each airdrop wave is a round added with `add_round`, which takes its
+/// budget from the admin. A round's allocation list lives off-chain as a
+/// Merkle tree whose leaves are `sha256(xdr((index, account, amount)))`;
+/// inner nodes hash the sorted pair of their children. Accounts claim with a
+/// proof against the round's root until its deadline, a per-round bitmap
+/// over leaf indices keeps every leaf claimable once, and whatever is left
+/// after the deadline can be swept.
+///
+/// Holder-based drops need no off-chain list at all: `add_holder_round`
+/// points a round at a token snapshot and an allocation formula, the admin
+/// reads the token's holder registry in with `ingest_holders`, and holders
+/// then `claim_allocation` what the formula gives them.
+///
+/// Quests completed off-chain are paid from a separate pool through
+/// `claim_with_attestation`, against an ed25519 signature from the attestor
+/// key over `xdr((airdrop, account, amount, quest_id))`.
+///
+/// Small curated lists can skip the claim flow: the admin pushes them from
+/// their own balance with `distribute`, which pays a chunk per call and
+/// resumes where it left off.
+///
+/// In `ClaimMode::Vested` only the TGE share is paid on claim; the rest goes
+/// into a vesting schedule for the claimant, so airdrops are not dumped at
+/// once. Later payouts to the same claimant top up that schedule. This
+/// contract must be an authorized creator of the vesting contract.
+/// In `ClaimMode::Streaming` allocations drip out instead: each claim pays
+/// the share unlocked so far, without any vesting schedules.
+#[contract]
+pub struct AirdropContract;
+
+#[contractimpl]
+impl AirdropContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the admin and the distributed token. Airdrops are added as
+    /// rounds with `add_round`.
+    pub fn initialize(env: Env, admin: Address, token: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Token, &token);
+        env.storage().instance().set(&DataKey::RoundCount, &0u32);
+        env.storage().instance().set(&DataKey::TotalClaimed, &0i128);
+
+        env.events()
+            .publish((symbol_short!("init"),), (admin, token));
+    }
+
+    // ── Claims ──────────────────────────────────────────────────────────
+
+    /// Pay leaf `index` of round `round_id`, allocating `amount` to
+    /// `account`, once `proof` shows it belongs to the round's root. When
+    /// streaming, pays only what has unlocked since the last claim. Anyone
+    /// may submit a claim; the tokens always go to `account`.
+    pub fn claim(
+        env: Env,
+        round_id: u32,
+        index: u32,
+        account: Address,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) {
+        let round = Self::get_round(env.clone(), round_id);
+        let root = match &round.source {
+            RoundSource::Merkle(root) => root.clone(),
+            RoundSource::Holders(_) => panic!("not a merkle round"),
+        };
+        assert!(amount > 0, "amount must be positive");
+
+        let leaf = merkle::leaf(&env, index, &account, amount);
+        assert!(merkle::verify(&env, leaf, &proof, &root), "invalid proof");
+
+        Self::_settle(&env, round_id, round, index, &account, amount);
+    }
+
+    /// Pay `account` its allocation in holder round `round_id`, once every
+    /// holder has been ingested. Anyone may submit a claim; the tokens
+    /// always go to `account`.
+    pub fn claim_allocation(env: Env, round_id: u32, account: Address) {
+        let round = Self::get_round(env.clone(), round_id);
+        let (index, amount) = Self::_allocation(&env, round_id, &round, &account);
+        assert!(amount > 0, "not eligible");
+        Self::_settle(&env, round_id, round, index, &account, amount);
+    }
+
+    /// Pay `amount` from the quest pool to `account` for quest `quest_id`,
+    /// once per account and quest, if `signature` is the attestor's
+    /// signature over `xdr((this contract, account, amount, quest_id))`.
+    /// Anyone may submit the claim; the tokens always go to `account`.
+    pub fn claim_with_attestation(
+        env: Env,
+        account: Address,
+        amount: i128,
+        quest_id: u32,
+        signature: BytesN<64>,
+    ) {
+        assert!(amount > 0, "amount must be positive");
+        let key = DataKey::QuestClaimed(quest_id, account.clone());
+        assert!(!env.storage().persistent().has(&key), "already claimed");
+
+        let attestor = Self::attestor(env.clone());
+        let message = (
+            env.current_contract_address(),
+            account.clone(),
+            amount,
+            quest_id,
+        )
+            .to_xdr(&env);
+        env.crypto().ed25519_verify(&attestor, &message, &signature);
+
+        let pool = Self::quest_pool(env.clone());
+        assert!(amount <= pool, "quest pool exhausted");
+        env.storage()
+            .instance()
+            .set(&DataKey::QuestPool, &(pool - amount));
+        env.storage().persistent().set(&key, &true);
+
+        let total = Self::total_claimed(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalClaimed, &(total + amount));
+
+        Self::_pay(&env, &account, amount);
+
+        env.events()
+            .publish((symbol_short!("quest"), account), (quest_id, amount));
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Open a round claimable until `deadline` against `merkle_root`,
+    /// pulling its `total` budget from the admin. Admin only. Returns the
+    /// round id.
+    pub fn add_round(env: Env, merkle_root: BytesN<32>, deadline: u32, total: i128) -> u32 {
+        Self::_require_admin(&env);
+        assert!(
+            deadline > env.ledger().sequence(),
+            "deadline must be in the future"
+        );
+        assert!(total > 0, "total must be positive");
+        Self::_check_fits_stream(&env, deadline);
+
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &Self::admin(env.clone()),
+            &env.current_contract_address(),
+            &total,
+        );
+
+        let id = Self::round_count(env.clone());
+        let round = Round {
+            source: RoundSource::Merkle(merkle_root.clone()),
+            deadline,
+            total,
+            claimed: 0,
+            swept: false,
+        };
+        env.storage().persistent().set(&DataKey::Round(id), &round);
+        env.storage()
+            .instance()
+            .set(&DataKey::RoundCount, &(id + 1));
+
+        env.events()
+            .publish((symbol_short!("round"), id), (merkle_root, deadline, total));
+        id
+    }
+
+    /// Open a round claimable until `deadline` by the holders of `registry`
+    /// with at least `min_balance` at snapshot `snapshot_id`, each getting
+    /// what `formula` allocates them, and pull its `total` budget from the
+    /// admin. Holders must then be read in with `ingest_holders`. With
+    /// `HolderFormula::Fixed` the total must cover every holder of the
+    /// registry. Admin only. Returns the round id.
+    pub fn add_holder_round(
+        env: Env,
+        registry: Address,
+        snapshot_id: u32,
+        formula: HolderFormula,
+        min_balance: i128,
+        deadline: u32,
+        total: i128,
+    ) -> u32 {
+        Self::_require_admin(&env);
+        assert!(
+            deadline > env.ledger().sequence(),
+            "deadline must be in the future"
+        );
+        assert!(total > 0, "total must be positive");
+        assert!(min_balance > 0, "min_balance must be positive");
+        Self::_check_fits_stream(&env, deadline);
+
+        let holders = HolderRegistryClient::new(&env, &registry);
+        // Fails for a snapshot that was never taken
+        holders.snapshot_ledger(&snapshot_id);
+        let holder_count = holders.holder_count();
+        // Every holder may turn out eligible, so the budget must cover all
+        if let HolderFormula::Fixed(amount) = formula {
+            assert!(amount > 0, "amount must be positive");
+            assert!(
+                amount
+                    .checked_mul(holder_count as i128)
+                    .is_some_and(|needed| needed <= total),
+                "total below fixed allocations"
+            );
+        }
+
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &Self::admin(env.clone()),
+            &env.current_contract_address(),
+            &total,
+        );
+
+        let id = Self::round_count(env.clone());
+        let round = Round {
+            source: RoundSource::Holders(HolderDrop {
+                registry: registry.clone(),
+                snapshot_id,
+                formula,
+                min_balance,
+                holder_count,
+                ingested: 0,
+                eligible_count: 0,
+                eligible_balance: 0,
+            }),
+            deadline,
+            total,
+            claimed: 0,
+            swept: false,
+        };
+        env.storage().persistent().set(&DataKey::Round(id), &round);
+        env.storage()
+            .instance()
+            .set(&DataKey::RoundCount, &(id + 1));
+
+        env.events().publish(
+            (symbol_short!("hround"), id),
+            (registry, snapshot_id, deadline, total),
+        );
+        id
+    }
+
+    /// Read the next chunk of holders of holder round `round_id` from the
+    /// registry and record those eligible at the snapshot. Admin only.
+    /// Returns the number of holders read so far, equal to the round's
+    /// `holder_count` once ingestion is complete.
+    pub fn ingest_holders(env: Env, round_id: u32) -> u32 {
+        Self::_require_admin(&env);
+        let mut round = Self::get_round(env.clone(), round_id);
+        let mut drop = match round.source {
+            RoundSource::Holders(drop) => drop,
+            RoundSource::Merkle(_) => panic!("not a holder round"),
+        };
+        assert!(
+            drop.ingested < drop.holder_count,
+            "holders already ingested"
+        );
+        let end = drop.holder_count.min(drop.ingested + INGESTION_CHUNK);
+
+        let registry = HolderRegistryClient::new(&env, &drop.registry);
+        for index in drop.ingested..end {
+            let holder = registry.holder_at(&index);
+            let balance = registry.balance_at(&holder, &drop.snapshot_id);
+            if balance >= drop.min_balance {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Eligible(round_id, holder), &(index, balance));
+                drop.eligible_count += 1;
+                drop.eligible_balance += balance;
+            }
+        }
+        drop.ingested = end;
+        round.source = RoundSource::Holders(drop.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Round(round_id), &round);
+
+        env.events().publish(
+            (symbol_short!("ingest"), round_id),
+            (end, drop.eligible_count, drop.eligible_balance),
+        );
+        end
+    }
+
+    /// Set how claims are paid out. Only possible before the first claim.
+    /// Admin only.
+    pub fn set_claim_mode(env: Env, mode: ClaimMode) {
+        Self::_require_admin(&env);
+        assert!(
+            Self::total_claimed(env.clone()) == 0,
+            "claims already started"
+        );
+        match &mode {
+            ClaimMode::Direct => {}
+            ClaimMode::Vested(terms) => {
+                assert!(
+                    terms.tge_bps as i128 <= BPS_DENOMINATOR,
+                    "tge_bps exceeds 100%"
+                );
+                assert!(
+                    terms.end_ledger > terms.cliff_ledger,
+                    "end_ledger must be after cliff_ledger"
+                );
+            }
+            ClaimMode::Streaming(terms) => {
+                assert!(
+                    terms.end_ledger > terms.start_ledger,
+                    "end_ledger must be after start_ledger"
+                );
+                let now = env.ledger().sequence();
+                for round_id in 0..Self::round_count(env.clone()) {
+                    let round = Self::get_round(env.clone(), round_id);
+                    if !round.swept && round.deadline >= now {
+                        Self::_check_stream_ends_by(terms, round.deadline);
+                    }
+                }
+            }
+        }
+        env.storage().instance().set(&DataKey::ClaimMode, &mode);
+        env.events().publish((symbol_short!("mode"),), mode);
+    }
+
+    /// Set the ed25519 public key whose signatures gate quest claims. Admin
+    /// only.
+    pub fn set_attestor(env: Env, public_key: BytesN<32>) {
+        Self::_require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::Attestor, &public_key);
+        env.events()
+            .publish((symbol_short!("attestor"),), public_key);
+    }
+
+    /// Add `amount` from the admin to the pool paying quest claims. Admin
+    /// only.
+    pub fn fund_quests(env: Env, amount: i128) {
+        Self::_require_admin(&env);
+        assert!(amount > 0, "amount must be positive");
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &Self::admin(env.clone()),
+            &env.current_contract_address(),
+            &amount,
+        );
+        let pool = Self::quest_pool(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::QuestPool, &(pool + amount));
+        env.events().publish((symbol_short!("questfund"),), amount);
+    }
+
+    /// Transfer the next chunk of `batch` from the admin straight to its
+    /// recipients.
+    /// Progress is tracked per batch, so calling again with the same batch
+    /// resumes after the last paid entry and never pays an entry twice.
+    /// Admin only. Returns the index of the next unpaid entry, equal to the
+    /// batch length once it is done.
+    pub fn distribute(env: Env, batch: Vec<(Address, i128)>) -> u32 {
+        Self::_require_admin(&env);
+        let key = DataKey::Distribution(env.crypto().sha256(&batch.clone().to_xdr(&env)).into());
+        let start: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        assert!(start < batch.len(), "batch already distributed");
+        let end = batch.len().min(start + DISTRIBUTION_CHUNK);
+
+        let admin = Self::admin(env.clone());
+        let token = token::Client::new(&env, &Self::token(env.clone()));
+        let mut paid = 0i128;
+        for i in start..end {
+            let (recipient, amount) = batch.get(i).unwrap();
+            assert!(amount > 0, "amount must be positive");
+            token.transfer(&admin, &recipient, &amount);
+            paid += amount;
+        }
+        env.storage().persistent().set(&key, &end);
+        let total = Self::total_distributed(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalDistributed, &(total + paid));
+
+        env.events()
+            .publish((symbol_short!("distrib"),), (start, end, paid));
+        end
+    }
+
+    /// Send what is left of round `round_id`'s budget to `destination`,
+    /// typically the project treasury, once its deadline has passed. Admin
+    /// only. Returns the amount swept.
+    pub fn sweep_unclaimed(env: Env, round_id: u32, destination: Address) -> i128 {
+        Self::_require_admin(&env);
+        let mut round = Self::get_round(env.clone(), round_id);
+        assert!(
+            env.ledger().sequence() > round.deadline,
+            "claim period not over"
+        );
+        assert!(!round.swept, "already swept");
+        round.swept = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Round(round_id), &round);
+
+        let swept = round.total - round.claimed;
+        if swept > 0 {
+            token::Client::new(&env, &Self::token(env.clone())).transfer(
+                &env.current_contract_address(),
+                &destination,
+                &swept,
+            );
+        }
+
+        env.events().publish(
+            (symbol_short!("sweep"), destination),
+            (round_id, round.claimed, swept),
+        );
+        swept
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn is_claimed(env: Env, round_id: u32, index: u32) -> bool {
+        merkle::is_claimed(&env, |word| DataKey::Claimed(round_id, word), index)
+    }
+
+    /// Amount paid so far on leaf `index` of round `round_id` in streaming
+    /// mode.
+    pub fn streamed_amount(env: Env, round_id: u32, index: u32) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Streamed(round_id, index))
+            .unwrap_or(0)
+    }
+
+    pub fn attestor(env: Env) -> BytesN<32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Attestor)
+            .expect("attestor not set")
+    }
+
+    /// Tokens left for quest claims.
+    pub fn quest_pool(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::QuestPool)
+            .unwrap_or(0)
+    }
+
+    pub fn is_quest_claimed(env: Env, quest_id: u32, account: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::QuestClaimed(quest_id, account))
+    }
+
+    pub fn claim_mode(env: Env) -> ClaimMode {
+        env.storage()
+            .instance()
+            .get(&DataKey::ClaimMode)
+            .unwrap_or(ClaimMode::Direct)
+    }
+
+    /// Total amount paid out through claims, across rounds.
+    pub fn total_claimed(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalClaimed)
+            .unwrap_or(0)
+    }
+
+    /// Total amount pushed through `distribute`.
+    pub fn total_distributed(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalDistributed)
+            .unwrap_or(0)
+    }
+
+    /// What `account` is allocated in holder round `round_id`; zero if it
+    /// was not eligible.
+    pub fn allocation_of(env: Env, round_id: u32, account: Address) -> i128 {
+        let round = Self::get_round(env.clone(), round_id);
+        Self::_allocation(&env, round_id, &round, &account).1
+    }
+
+    /// Source, deadline, budget and claim stats of round `round_id`.
+    pub fn get_round(env: Env, round_id: u32) -> Round {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Round(round_id))
+            .expect("no round found")
+    }
+
+    pub fn round_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RoundCount)
+            .expect("not initialized")
+    }
+
+    pub fn token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("not initialized")
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _require_admin(env: &Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized");
+        admin.require_auth();
+    }
+
+    /// Reject a round `deadline` that a streaming claim mode would outlast.
+    fn _check_fits_stream(env: &Env, deadline: u32) {
+        if let ClaimMode::Streaming(terms) = Self::claim_mode(env.clone()) {
+            Self::_check_stream_ends_by(&terms, deadline);
+        }
+    }
+
+    fn _check_stream_ends_by(terms: &StreamTerms, deadline: u32) {
+        assert!(
+            terms.end_ledger <= deadline,
+            "stream ends after round deadline"
+        );
+    }
+
+    /// Pay out `amount` allocated to `account` at `index` of a round,
+    /// according to the claim mode.
+    fn _settle(
+        env: &Env,
+        round_id: u32,
+        mut round: Round,
+        index: u32,
+        account: &Address,
+        amount: i128,
+    ) {
+        assert!(
+            env.ledger().sequence() <= round.deadline,
+            "claim period over"
+        );
+        assert!(
+            !Self::is_claimed(env.clone(), round_id, index),
+            "already claimed"
+        );
+
+        let paid = match Self::claim_mode(env.clone()) {
+            ClaimMode::Streaming(terms) => {
+                let unlocked = Self::_unlocked(env, &terms, amount);
+                let key = DataKey::Streamed(round_id, index);
+                let streamed: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+                assert!(unlocked > streamed, "nothing to claim");
+                env.storage().persistent().set(&key, &unlocked);
+                if unlocked == amount {
+                    merkle::set_claimed(env, |word| DataKey::Claimed(round_id, word), index);
+                }
+                unlocked - streamed
+            }
+            _ => {
+                merkle::set_claimed(env, |word| DataKey::Claimed(round_id, word), index);
+                amount
+            }
+        };
+        assert!(round.claimed + paid <= round.total, "round exhausted");
+        round.claimed += paid;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Round(round_id), &round);
+
+        let total = Self::total_claimed(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalClaimed, &(total + paid));
+
+        Self::_pay(env, account, paid);
+
+        env.events().publish(
+            (symbol_short!("claim"), account.clone()),
+            (round_id, index, paid),
+        );
+    }
+
+    /// Pay `amount` to `account` according to the claim mode.
+    fn _pay(env: &Env, account: &Address, amount: i128) {
+        let token_addr = Self::token(env.clone());
+        let token = token::Client::new(env, &token_addr);
+        let this = env.current_contract_address();
+        match Self::claim_mode(env.clone()) {
+            ClaimMode::Direct | ClaimMode::Streaming(_) => token.transfer(&this, account, &amount),
+            ClaimMode::Vested(terms) => {
+                let tge = soropad_math::apply_bps(amount, terms.tge_bps);
+                if tge > 0 {
+                    token.transfer(&this, account, &tge);
+                }
+                let vested = amount - tge;
+                if vested > 0 {
+                    // The vesting contract holds one schedule per recipient,
+                    // so later payouts top up the one from the first.
+                    let vesting = VestingClient::new(env, &terms.vesting);
+                    let top_up = vesting.has_schedule(account);
+                    Self::_authorize_pull(env, &token_addr, &terms.vesting, vested);
+                    if top_up {
+                        vesting.top_up_from(&this, account, &vested);
+                    } else {
+                        vesting.create_schedule_from(
+                            &this,
+                            account,
+                            &vested,
+                            &terms.cliff_ledger,
+                            &terms.end_ledger,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Registry index and allocation of `account` in a fully ingested
+    /// holder round.
+    fn _allocation(env: &Env, round_id: u32, round: &Round, account: &Address) -> (u32, i128) {
+        let drop = match &round.source {
+            RoundSource::Holders(drop) => drop,
+            RoundSource::Merkle(_) => panic!("not a holder round"),
+        };
+        assert!(drop.ingested == drop.holder_count, "holders not ingested");
+        let eligible: Option<(u32, i128)> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Eligible(round_id, account.clone()));
+        match eligible {
+            None => (0, 0),
+            Some((index, balance)) => match drop.formula {
+                HolderFormula::ProRata => (
+                    index,
+                    soropad_math::mul_div(round.total, balance, drop.eligible_balance),
+                ),
+                HolderFormula::Fixed(amount) => (index, amount),
+            },
+        }
+    }
+
+    /// Part of an `amount` allocation unlocked by now under `terms`.
+    fn _unlocked(env: &Env, terms: &StreamTerms, amount: i128) -> i128 {
+        soropad_math::linear(
+            terms.start_ledger,
+            terms.end_ledger,
+            env.ledger().sequence(),
+            amount,
+        )
+    }
+
+    /// Let `to` pull `amount` of `token` from this contract. Transfers made
+    /// by a contract we call are nested and need explicit authorization.
+    fn _authorize_pull(env: &Env, token: &Address, to: &Address, amount: i128) {
+        env.authorize_as_current_contract(vec![
+            env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token.clone(),
+                    fn_name: Symbol::new(env, "transfer"),
+                    args: (env.current_contract_address(), to.clone(), amount).into_val(env),
+                },
+                sub_invocations: vec![env],
+            }),
+        ]);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{
+        testutils::Address as _, testutils::Ledger, testutils::MockAuth, testutils::MockAuthInvoke,
+    };
+
+    struct Setup {
+        env: Env,
+        client: AirdropContractClient<'static>,
+        admin: Address,
+        token: TokenFixture,
+        accounts: Vec<Address>,
+        amounts: Vec<i128>,
+    }
+
+    /// Round 0: three allocations of 100, 200 and 300 out of a 600 budget,
+    /// claimable until ledger 1 000.
+    fn setup() -> Setup {
+        let env = test_env();
+        let admin = Address::generate(&env);
+        let token = TokenFixture::stellar_asset(&env);
+        let accounts = vec![
+            &env,
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        let amounts = vec![&env, 100i128, 200, 300];
+        let contract_id = env.register_contract(None, AirdropContract);
+        let client = AirdropContractClient::new(&env, &contract_id);
+
+        client.initialize(&admin, &token.address);
+        let s = Setup {
+            token,
+            env,
+            client,
+            admin,
+            accounts,
+            amounts,
+        };
+        let (root, _) = merkle::root_and_proof(&s.env, &leaves(&s), 0);
+        s.token.mint(&s.admin, 600);
+        s.client.add_round(&root, &1_000, &600);
+        s
+    }
+
+    fn leaves(s: &Setup) -> Vec<BytesN<32>> {
+        let mut leaves = Vec::new(&s.env);
+        for i in 0..s.accounts.len() {
+            leaves.push_back(merkle::leaf(
+                &s.env,
+                i,
+                &s.accounts.get(i).unwrap(),
+                s.amounts.get(i).unwrap(),
+            ));
+        }
+        leaves
+    }
+
+    fn claim(s: &Setup, index: u32) {
+        let (_, proof) = merkle::root_and_proof(&s.env, &leaves(s), index);
+        s.client.claim(
+            &0,
+            &index,
+            &s.accounts.get(index).unwrap(),
+            &s.amounts.get(index).unwrap(),
+            &proof,
+        );
+    }
+
+    #[test]
+    fn test_claim_with_proof() {
+        let s = setup();
+        claim(&s, 0);
+        claim(&s, 2);
+        assert_eq!(s.token.balance(&s.accounts.get(0).unwrap()), 100);
+        assert_eq!(s.token.balance(&s.accounts.get(2).unwrap()), 300);
+        assert!(s.client.is_claimed(&0, &0));
+        assert!(!s.client.is_claimed(&0, &1));
+        assert_eq!(s.client.total_claimed(), 400);
+    }
+
+    #[test]
+    #[should_panic(expected = "already claimed")]
+    fn test_double_claim_panics() {
+        let s = setup();
+        claim(&s, 1);
+        claim(&s, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid proof")]
+    fn test_inflated_amount_panics() {
+        let s = setup();
+        let (_, proof) = merkle::root_and_proof(&s.env, &leaves(&s), 0);
+        s.client
+            .claim(&0, &0, &s.accounts.get(0).unwrap(), &1_000, &proof);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid proof")]
+    fn test_wrong_account_panics() {
+        let s = setup();
+        let (_, proof) = merkle::root_and_proof(&s.env, &leaves(&s), 0);
+        s.client
+            .claim(&0, &0, &Address::generate(&s.env), &100, &proof);
+    }
+
+    #[test]
+    #[should_panic(expected = "claim period over")]
+    fn test_claim_after_deadline_panics() {
+        let s = setup();
+        s.env.ledger().set_sequence_number(1_001);
+        claim(&s, 0);
+    }
+
+    #[test]
+    fn test_distribute_in_chunks() {
+        let s = setup();
+        s.token.mint(&s.admin, 1_000);
+        let mut batch = Vec::new(&s.env);
+        for _ in 0..30 {
+            batch.push_back((Address::generate(&s.env), 10i128));
+        }
+
+        assert_eq!(s.client.distribute(&batch), 25);
+        assert_eq!(s.token.balance(&batch.get(24).unwrap().0), 10);
+        assert_eq!(s.token.balance(&batch.get(25).unwrap().0), 0);
+        assert_eq!(s.client.distribute(&batch), 30);
+        assert_eq!(s.token.balance(&batch.get(29).unwrap().0), 10);
+        assert_eq!(s.token.balance(&batch.get(0).unwrap().0), 10);
+        assert_eq!(s.client.total_distributed(), 300);
+        assert!(s.client.try_distribute(&batch).is_err());
+    }
+
+    #[test]
+    fn test_sweep_after_deadline() {
+        let s = setup();
+        claim(&s, 1);
+        let treasury = Address::generate(&s.env);
+        assert!(s.client.try_sweep_unclaimed(&0, &treasury).is_err());
+
+        s.env.ledger().set_sequence_number(1_001);
+        assert_eq!(s.client.sweep_unclaimed(&0, &treasury), 400);
+        assert_eq!(s.token.balance(&treasury), 400);
+        assert_eq!(s.token.balance(&s.client.address), 0);
+        assert!(s.client.try_sweep_unclaimed(&0, &treasury).is_err());
+    }
+
+    #[test]
+    fn test_rounds_are_independent() {
+        let s = setup();
+        claim(&s, 0);
+
+        // Round 1 reuses the allocation tree with a later deadline
+        let (root, proof) = merkle::root_and_proof(&s.env, &leaves(&s), 0);
+        s.token.mint(&s.admin, 100);
+        assert_eq!(s.client.add_round(&root, &2_000, &100), 1);
+        let account = s.accounts.get(0).unwrap();
+        s.client.claim(&1, &0, &account, &100, &proof);
+        assert!(s.client.is_claimed(&1, &0));
+        assert!(!s.client.is_claimed(&0, &1));
+        assert_eq!(s.token.balance(&account), 200);
+
+        s.env.ledger().set_sequence_number(1_001);
+        let treasury = Address::generate(&s.env);
+        assert_eq!(s.client.sweep_unclaimed(&0, &treasury), 500);
+        assert!(s.client.try_sweep_unclaimed(&1, &treasury).is_err());
+
+        let round = s.client.get_round(&0);
+        assert_eq!((round.claimed, round.swept), (100, true));
+        assert_eq!(s.client.total_claimed(), 200);
+    }
+
+    #[test]
+    #[should_panic(expected = "round exhausted")]
+    fn test_claims_capped_by_round_total() {
+        let s = setup();
+        let (root, _) = merkle::root_and_proof(&s.env, &leaves(&s), 0);
+        s.token.mint(&s.admin, 250);
+        s.client.add_round(&root, &1_000, &250);
+        for index in 0..2u32 {
+            let (_, proof) = merkle::root_and_proof(&s.env, &leaves(&s), index);
+            s.client.claim(
+                &1,
+                &index,
+                &s.accounts.get(index).unwrap(),
+                &s.amounts.get(index).unwrap(),
+                &proof,
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sweep_requires_admin() {
+        let s = setup();
+        s.env.ledger().set_sequence_number(1_001);
+        let outsider = Address::generate(&s.env);
+        s.env.mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "sweep_unclaimed",
+                args: (0u32, outsider.clone()).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.sweep_unclaimed(&0, &outsider);
+    }
+
+    // ── Holder rounds ───────────────────────────────────────────────────
+
+    /// Serves a fixed holder list and snapshot balances like the token.
+    #[contract]
+    struct MockRegistry;
+
+    #[contractimpl]
+    impl MockRegistry {
+        pub fn set_holders(env: Env, holders: Vec<(Address, i128)>) {
+            env.storage().instance().set(&0u32, &holders);
+        }
+
+        pub fn snapshot_ledger(_env: Env, snapshot_id: u32) -> u32 {
+            assert!(snapshot_id == 0, "no snapshot found");
+            5
+        }
+
+        pub fn holder_count(env: Env) -> u32 {
+            let holders: Vec<(Address, i128)> = env.storage().instance().get(&0u32).unwrap();
+            holders.len()
+        }
+
+        pub fn holder_at(env: Env, index: u32) -> Address {
+            let holders: Vec<(Address, i128)> = env.storage().instance().get(&0u32).unwrap();
+            holders.get(index).unwrap().0
+        }
+
+        pub fn balance_at(env: Env, id: Address, _snapshot_id: u32) -> i128 {
+            let holders: Vec<(Address, i128)> = env.storage().instance().get(&0u32).unwrap();
+            holders
+                .iter()
+                .find(|(holder, _)| *holder == id)
+                .map(|(_, balance)| balance)
+                .unwrap_or(0)
+        }
+    }
+
+    /// Round 1 over `balances`, funded with `total`.
+    fn holder_setup(
+        balances: &[i128],
+        formula: HolderFormula,
+        total: i128,
+    ) -> (Setup, Vec<Address>) {
+        let s = setup();
+        let mut holders = Vec::new(&s.env);
+        let mut list = Vec::new(&s.env);
+        for balance in balances {
+            let holder = Address::generate(&s.env);
+            holders.push_back(holder.clone());
+            list.push_back((holder, *balance));
+        }
+        let registry =
+            MockRegistryClient::new(&s.env, &s.env.register_contract(None, MockRegistry));
+        registry.set_holders(&list);
+        s.token.mint(&s.admin, total);
+        s.client
+            .add_holder_round(&registry.address, &0, &formula, &10, &1_000, &total);
+        (s, holders)
+    }
+
+    #[test]
+    fn test_pro_rata_holder_round() {
+        let (s, holders) = holder_setup(&[100, 300, 5], HolderFormula::ProRata, 1_000);
+        assert_eq!(s.client.ingest_holders(&1), 3);
+
+        // The third holder is below the minimum balance
+        assert_eq!(s.client.allocation_of(&1, &holders.get(0).unwrap()), 250);
+        assert_eq!(s.client.allocation_of(&1, &holders.get(2).unwrap()), 0);
+
+        s.client.claim_allocation(&1, &holders.get(1).unwrap());
+        assert_eq!(s.token.balance(&holders.get(1).unwrap()), 750);
+        assert!(s.client.is_claimed(&1, &1));
+        assert!(s
+            .client
+            .try_claim_allocation(&1, &holders.get(1).unwrap())
+            .is_err());
+        assert!(s
+            .client
+            .try_claim_allocation(&1, &holders.get(2).unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_holders_ingested_in_chunks() {
+        let balances = [10i128; 30];
+        let (s, holders) = holder_setup(&balances, HolderFormula::Fixed(20), 600);
+        assert_eq!(s.client.ingest_holders(&1), INGESTION_CHUNK);
+        assert!(s
+            .client
+            .try_claim_allocation(&1, &holders.get(0).unwrap())
+            .is_err());
+
+        assert_eq!(s.client.ingest_holders(&1), 30);
+        s.client.claim_allocation(&1, &holders.get(29).unwrap());
+        assert_eq!(s.token.balance(&holders.get(29).unwrap()), 20);
+        match s.client.get_round(&1).source {
+            RoundSource::Holders(drop) => {
+                assert_eq!(drop.eligible_count, 30);
+                assert_eq!(drop.eligible_balance, 300);
+            }
+            RoundSource::Merkle(_) => panic!("expected a holder round"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "total below fixed allocations")]
+    fn test_fixed_holder_round_must_cover_every_holder() {
+        holder_setup(&[10i128; 30], HolderFormula::Fixed(20), 599);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a merkle round")]
+    fn test_proof_claim_on_holder_round_panics() {
+        let (s, holders) = holder_setup(&[100], HolderFormula::ProRata, 100);
+        s.client.ingest_holders(&1);
+        s.client
+            .claim(&1, &0, &holders.get(0).unwrap(), &100, &Vec::new(&s.env));
+    }
+
+    // ── Vested claims ───────────────────────────────────────────────────
+
+    /// Records schedules and top-ups and pulls their tokens like the
+    /// vesting contract.
+    #[contract]
+    struct MockVesting;
+
+    #[contractimpl]
+    impl MockVesting {
+        pub fn init(env: Env, token: Address) {
+            env.storage().instance().set(&0u32, &token);
+        }
+
+        pub fn create_schedule_from(
+            env: Env,
+            creator: Address,
+            recipient: Address,
+            total_amount: i128,
+            cliff_ledger: u32,
+            end_ledger: u32,
+        ) {
+            creator.require_auth();
+            let token: Address = env.storage().instance().get(&0u32).unwrap();
+            token::Client::new(&env, &token).transfer(
+                &creator,
+                &env.current_contract_address(),
+                &total_amount,
+            );
+            env.storage()
+                .persistent()
+                .set(&recipient, &(total_amount, cliff_ledger, end_ledger));
+        }
+
+        pub fn top_up_from(env: Env, creator: Address, recipient: Address, amount: i128) {
+            creator.require_auth();
+            let token: Address = env.storage().instance().get(&0u32).unwrap();
+            token::Client::new(&env, &token).transfer(
+                &creator,
+                &env.current_contract_address(),
+                &amount,
+            );
+            let (total, cliff, end) = Self::schedule(env.clone(), recipient.clone());
+            env.storage()
+                .persistent()
+                .set(&recipient, &(total + amount, cliff, end));
+        }
+
+        pub fn has_schedule(env: Env, recipient: Address) -> bool {
+            env.storage().persistent().has(&recipient)
+        }
+
+        pub fn schedule(env: Env, recipient: Address) -> (i128, u32, u32) {
+            env.storage().persistent().get(&recipient).unwrap()
+        }
+    }
+
+    fn vested_setup(tge_bps: u32) -> (Setup, MockVestingClient<'static>) {
+        let s = setup();
+        let vesting = MockVestingClient::new(&s.env, &s.env.register_contract(None, MockVesting));
+        vesting.init(&s.token.address);
+        s.client.set_claim_mode(&ClaimMode::Vested(VestingTerms {
+            vesting: vesting.address.clone(),
+            tge_bps,
+            cliff_ledger: 100,
+            end_ledger: 500,
+        }));
+        (s, vesting)
+    }
+
+    #[test]
+    fn test_vested_claim() {
+        let (s, vesting) = vested_setup(2_000);
+        claim(&s, 2);
+
+        let account = s.accounts.get(2).unwrap();
+        assert_eq!(s.token.balance(&account), 60);
+        assert_eq!(s.token.balance(&vesting.address), 240);
+        assert_eq!(vesting.schedule(&account), (240, 100, 500));
+        assert_eq!(s.client.total_claimed(), 300);
+    }
+
+    #[test]
+    fn test_vested_claims_top_up_the_schedule() {
+        let (s, vesting) = vested_setup(2_000);
+        claim(&s, 2);
+
+        // Round 1 pays the same account again from the same tree
+        let (root, proof) = merkle::root_and_proof(&s.env, &leaves(&s), 2);
+        s.token.mint(&s.admin, 600);
+        s.client.add_round(&root, &2_000, &600);
+        let account = s.accounts.get(2).unwrap();
+        s.client.claim(&1, &2, &account, &300, &proof);
+
+        assert_eq!(s.token.balance(&account), 120);
+        assert_eq!(s.token.balance(&vesting.address), 480);
+        assert_eq!(vesting.schedule(&account), (480, 100, 500));
+    }
+
+    #[test]
+    fn test_full_tge_skips_vesting() {
+        let (s, vesting) = vested_setup(10_000);
+        claim(&s, 0);
+        assert_eq!(s.token.balance(&s.accounts.get(0).unwrap()), 100);
+        assert_eq!(s.token.balance(&vesting.address), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "claims already started")]
+    fn test_mode_locked_after_first_claim() {
+        let s = setup();
+        claim(&s, 0);
+        s.client.set_claim_mode(&ClaimMode::Direct);
+    }
+
+    // ── Streaming claims ────────────────────────────────────────────────
+
+    #[test]
+    fn test_streamed_claims_pay_accrued() {
+        let s = setup();
+        s.client.set_claim_mode(&ClaimMode::Streaming(StreamTerms {
+            start_ledger: 100,
+            end_ledger: 500,
+        }));
+        let account = s.accounts.get(2).unwrap();
+
+        s.env.ledger().set_sequence_number(200);
+        claim(&s, 2);
+        assert_eq!(s.token.balance(&account), 75);
+        assert!(!s.client.is_claimed(&0, &2));
+
+        s.env.ledger().set_sequence_number(300);
+        claim(&s, 2);
+        assert_eq!(s.token.balance(&account), 150);
+        assert_eq!(s.client.streamed_amount(&0, &2), 150);
+
+        s.env.ledger().set_sequence_number(600);
+        claim(&s, 2);
+        assert_eq!(s.token.balance(&account), 300);
+        assert!(s.client.is_claimed(&0, &2));
+        assert_eq!(s.client.get_round(&0).claimed, 300);
+    }
+
+    #[test]
+    #[should_panic(expected = "stream ends after round deadline")]
+    fn test_stream_outlasting_open_round_rejected() {
+        // Round 0 closes at ledger 1 000
+        let s = setup();
+        s.client.set_claim_mode(&ClaimMode::Streaming(StreamTerms {
+            start_ledger: 100,
+            end_ledger: 1_001,
+        }));
+    }
+
+    #[test]
+    fn test_round_closing_before_stream_end_rejected() {
+        let s = setup();
+        s.client.set_claim_mode(&ClaimMode::Streaming(StreamTerms {
+            start_ledger: 100,
+            end_ledger: 1_000,
+        }));
+        s.token.mint(&s.admin, 1_200);
+        let (root, _) = merkle::root_and_proof(&s.env, &leaves(&s), 0);
+        assert!(s.client.try_add_round(&root, &999, &600).is_err());
+        s.client.add_round(&root, &1_000, &600);
+
+        // Closed rounds no longer constrain the stream
+        s.env.ledger().set_sequence_number(1_001);
+        s.client.set_claim_mode(&ClaimMode::Streaming(StreamTerms {
+            start_ledger: 1_001,
+            end_ledger: 5_000,
+        }));
+    }
+
+    #[test]
+    #[should_panic(expected = "nothing to claim")]
+    fn test_stream_claim_before_start_panics() {
+        let s = setup();
+        s.client.set_claim_mode(&ClaimMode::Streaming(StreamTerms {
+            start_ledger: 100,
+            end_ledger: 500,
+        }));
+        claim(&s, 0);
+    }
+
+    // ── Quest attestations ──────────────────────────────────────────────
+
+    fn attestor_key() -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&[7u8; 32])
+    }
+
+    fn attest(s: &Setup, account: &Address, amount: i128, quest_id: u32) -> BytesN<64> {
+        use ed25519_dalek::Signer;
+        let message = (s.client.address.clone(), account.clone(), amount, quest_id).to_xdr(&s.env);
+        let signature = attestor_key().sign(&message.to_alloc_vec());
+        BytesN::from_array(&s.env, &signature.to_bytes())
+    }
+
+    fn quest_setup() -> Setup {
+        let s = setup();
+        let public_key = BytesN::from_array(&s.env, &attestor_key().verifying_key().to_bytes());
+        s.client.set_attestor(&public_key);
+        s.token.mint(&s.admin, 500);
+        s.client.fund_quests(&500);
+        s
+    }
+
+    #[test]
+    fn test_attested_quest_claim() {
+        let s = quest_setup();
+        let account = Address::generate(&s.env);
+        let signature = attest(&s, &account, 50, 3);
+
+        s.client
+            .claim_with_attestation(&account, &50, &3, &signature);
+        assert_eq!(s.token.balance(&account), 50);
+        assert_eq!(s.client.quest_pool(), 450);
+        assert!(s.client.is_quest_claimed(&3, &account));
+        assert!(s
+            .client
+            .try_claim_with_attestation(&account, &50, &3, &signature)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_forged_attestation_panics() {
+        let s = quest_setup();
+        let account = Address::generate(&s.env);
+        let signature = attest(&s, &account, 50, 3);
+        s.client
+            .claim_with_attestation(&account, &500, &3, &signature);
+    }
+}
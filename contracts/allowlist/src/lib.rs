@@ -0,0 +1,236 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    List(Symbol),
+    Member(Symbol, Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ListInfo {
+    /// Account that maintains the list.
+    pub owner: Address,
+    /// Number of members.
+    pub size: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Allowlist Contract — named address lists shared between sales.
+///
+/// Anyone can `create_list` under an unused name (e.g. `og_community`,
+/// `partner_dao`) and becomes its owner; only the owner adds or removes
+/// members and can hand the list over. Sales reference a list by this
+/// contract's address and the list name and call `is_listed` at
+/// contribution time, so a list maintained once gates every round that
+/// points at it and a removal takes effect everywhere at once.
+#[contract]
+pub struct AllowlistContract;
+
+#[contractimpl]
+impl AllowlistContract {
+    // ── Owners ──────────────────────────────────────────────────────────
+
+    /// Create an empty list called `name`, maintained by `owner`.
+    pub fn create_list(env: Env, owner: Address, name: Symbol) {
+        owner.require_auth();
+        let key = DataKey::List(name.clone());
+        assert!(!env.storage().persistent().has(&key), "list exists");
+        env.storage().persistent().set(
+            &key,
+            &ListInfo {
+                owner: owner.clone(),
+                size: 0,
+            },
+        );
+
+        env.events().publish((symbol_short!("list"), name), owner);
+    }
+
+    /// Hand list `name` over to `new_owner`. Owner only.
+    pub fn transfer_list(env: Env, name: Symbol, new_owner: Address) {
+        let mut info = Self::list(env.clone(), name.clone());
+        info.owner.require_auth();
+        info.owner = new_owner.clone();
+        Self::_save(&env, &name, &info);
+
+        env.events()
+            .publish((symbol_short!("list_own"), name), new_owner);
+    }
+
+    /// Add `accounts` to list `name`. Owner only; existing members are
+    /// skipped.
+    pub fn add(env: Env, name: Symbol, accounts: Vec<Address>) {
+        let mut info = Self::list(env.clone(), name.clone());
+        info.owner.require_auth();
+        for account in accounts.iter() {
+            let key = DataKey::Member(name.clone(), account.clone());
+            if env.storage().persistent().has(&key) {
+                continue;
+            }
+            env.storage().persistent().set(&key, &true);
+            info.size += 1;
+            env.events()
+                .publish((symbol_short!("al_add"), name.clone()), account);
+        }
+        Self::_save(&env, &name, &info);
+    }
+
+    /// Remove `accounts` from list `name`. Owner only; non-members are
+    /// skipped.
+    pub fn remove(env: Env, name: Symbol, accounts: Vec<Address>) {
+        let mut info = Self::list(env.clone(), name.clone());
+        info.owner.require_auth();
+        for account in accounts.iter() {
+            let key = DataKey::Member(name.clone(), account.clone());
+            if !env.storage().persistent().has(&key) {
+                continue;
+            }
+            env.storage().persistent().remove(&key);
+            info.size -= 1;
+            env.events()
+                .publish((symbol_short!("al_rm"), name.clone()), account);
+        }
+        Self::_save(&env, &name, &info);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Whether `account` is on list `name`. False for unknown lists.
+    pub fn is_listed(env: Env, name: Symbol, account: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Member(name, account))
+    }
+
+    pub fn list(env: Env, name: Symbol) -> ListInfo {
+        env.storage()
+            .persistent()
+            .get(&DataKey::List(name))
+            .expect("no list found")
+    }
+
+    pub fn has_list(env: Env, name: Symbol) -> bool {
+        env.storage().persistent().has(&DataKey::List(name))
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _save(env: &Env, name: &Symbol, info: &ListInfo) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::List(name.clone()), info);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        testutils::Address as _, testutils::MockAuth, testutils::MockAuthInvoke, vec, IntoVal,
+    };
+
+    struct Setup {
+        env: Env,
+        client: AllowlistContractClient<'static>,
+        owner: Address,
+        name: Symbol,
+    }
+
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let client =
+            AllowlistContractClient::new(&env, &env.register_contract(None, AllowlistContract));
+        let owner = Address::generate(&env);
+        let name = Symbol::new(&env, "og_community");
+        client.create_list(&owner, &name);
+
+        Setup {
+            env,
+            client,
+            owner,
+            name,
+        }
+    }
+
+    #[test]
+    fn test_add_and_remove_members() {
+        let s = setup();
+        let (alice, bob) = (Address::generate(&s.env), Address::generate(&s.env));
+        s.client.add(
+            &s.name,
+            &vec![&s.env, alice.clone(), bob.clone(), alice.clone()],
+        );
+        assert!(s.client.is_listed(&s.name, &alice));
+        assert!(s.client.is_listed(&s.name, &bob));
+        assert_eq!(s.client.list(&s.name).size, 2);
+
+        s.client
+            .remove(&s.name, &vec![&s.env, alice.clone(), alice.clone()]);
+        assert!(!s.client.is_listed(&s.name, &alice));
+        assert_eq!(s.client.list(&s.name).size, 1);
+    }
+
+    #[test]
+    fn test_lists_are_independent() {
+        let s = setup();
+        let partners = Symbol::new(&s.env, "partner_dao");
+        s.client.create_list(&Address::generate(&s.env), &partners);
+
+        let member = Address::generate(&s.env);
+        s.client.add(&partners, &vec![&s.env, member.clone()]);
+        assert!(s.client.is_listed(&partners, &member));
+        assert!(!s.client.is_listed(&s.name, &member));
+        assert!(!s.client.is_listed(&Symbol::new(&s.env, "unknown"), &member));
+    }
+
+    #[test]
+    #[should_panic(expected = "list exists")]
+    fn test_name_taken() {
+        let s = setup();
+        s.client.create_list(&Address::generate(&s.env), &s.name);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_only_owner_edits() {
+        let s = setup();
+        let outsider = Address::generate(&s.env);
+        let accounts = vec![&s.env, outsider.clone()];
+        s.env.mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "add",
+                args: (s.name.clone(), accounts.clone()).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.add(&s.name, &accounts);
+    }
+
+    #[test]
+    fn test_transfer_list() {
+        let s = setup();
+        let dao = Address::generate(&s.env);
+        s.client.transfer_list(&s.name, &dao);
+        assert_eq!(s.client.list(&s.name).owner, dao);
+        assert_ne!(s.client.list(&s.name).owner, s.owner);
+    }
+}
@@ -0,0 +1,310 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Minter(Address),
+    BadgeCount,
+    Badge(u64),
+    /// Ids of the badges an address holds.
+    Badges(Address),
+    /// Id of the badge of a kind an issuer gave an address.
+    Held(Address, Address, Symbol),
+}
+
+/// A non-transferable receipt issued by a platform contract.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Badge {
+    pub id: u64,
+    pub owner: Address,
+    /// Contract that minted the badge, e.g. the staking contract or a sale.
+    pub issuer: Address,
+    /// What the badge attests, e.g. `tier` or `sale`.
+    pub kind: Symbol,
+    /// Issuer-defined level, e.g. the staking tier reached.
+    pub level: u32,
+    /// Ledger the badge was minted or last updated at.
+    pub issued_ledger: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Badges Contract — soulbound receipts for staking tiers and sales.
+///
+/// Platform contracts registered as minters by the admin issue badges to
+/// addresses: the staking contract for tiers reached, sales for
+/// participation. Partners read `badges_of` to grant perks.
+///
+/// Badges cannot be transferred. An address holds at most one badge per
+/// issuer and kind; minting again updates that badge's level instead of
+/// issuing a second one, and only the issuer can revoke it.
+#[contract]
+pub struct BadgesContract;
+
+#[contractimpl]
+impl BadgesContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, admin: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Allow or stop `minter` issuing badges. Badges it already issued stay.
+    /// Admin only.
+    pub fn set_minter(env: Env, minter: Address, allowed: bool) {
+        Self::admin(env.clone()).require_auth();
+        let key = DataKey::Minter(minter.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("minter"), minter), allowed);
+    }
+
+    // ── Minting ─────────────────────────────────────────────────────────
+
+    /// Give `owner` a `kind` badge from `issuer` at `level`, or update the
+    /// level of the one it already holds. Returns the badge id. Registered
+    /// minters only.
+    pub fn mint(env: Env, issuer: Address, owner: Address, kind: Symbol, level: u32) -> u64 {
+        Self::_require_minter(&env, &issuer);
+
+        let held = DataKey::Held(owner.clone(), issuer.clone(), kind.clone());
+        let id = match env.storage().persistent().get::<_, u64>(&held) {
+            Some(id) => id,
+            None => {
+                let id: u64 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::BadgeCount)
+                    .unwrap_or(0);
+                env.storage()
+                    .instance()
+                    .set(&DataKey::BadgeCount, &(id + 1));
+                env.storage().persistent().set(&held, &id);
+
+                let mut ids = Self::badge_ids_of(env.clone(), owner.clone());
+                ids.push_back(id);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Badges(owner.clone()), &ids);
+                id
+            }
+        };
+
+        let badge = Badge {
+            id,
+            owner: owner.clone(),
+            issuer: issuer.clone(),
+            kind: kind.clone(),
+            level,
+            issued_ledger: env.ledger().sequence(),
+        };
+        env.storage().persistent().set(&DataKey::Badge(id), &badge);
+
+        env.events()
+            .publish((symbol_short!("badge"), owner, issuer), (kind, level));
+        id
+    }
+
+    /// Take `owner`'s `kind` badge from `issuer` away. Issuer only, and only
+    /// while it is still a minter.
+    pub fn revoke(env: Env, issuer: Address, owner: Address, kind: Symbol) {
+        Self::_require_minter(&env, &issuer);
+
+        let held = DataKey::Held(owner.clone(), issuer.clone(), kind.clone());
+        let id: u64 = env
+            .storage()
+            .persistent()
+            .get(&held)
+            .expect("badge not found");
+        env.storage().persistent().remove(&held);
+        env.storage().persistent().remove(&DataKey::Badge(id));
+
+        let mut ids = Self::badge_ids_of(env.clone(), owner.clone());
+        if let Some(i) = ids.first_index_of(id) {
+            ids.remove(i);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Badges(owner.clone()), &ids);
+
+        env.events()
+            .publish((symbol_short!("badge_rvk"), owner, issuer), kind);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Every badge `owner` holds, oldest first.
+    pub fn badges_of(env: Env, owner: Address) -> Vec<Badge> {
+        let mut badges = Vec::new(&env);
+        for id in Self::badge_ids_of(env.clone(), owner).iter() {
+            badges.push_back(Self::get_badge(env.clone(), id));
+        }
+        badges
+    }
+
+    pub fn badge_ids_of(env: Env, owner: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Badges(owner))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// `owner`'s `kind` badge from `issuer`, if it holds one.
+    pub fn badge_of(env: Env, owner: Address, issuer: Address, kind: Symbol) -> Option<Badge> {
+        env.storage()
+            .persistent()
+            .get::<_, u64>(&DataKey::Held(owner, issuer, kind))
+            .map(|id| Self::get_badge(env.clone(), id))
+    }
+
+    pub fn get_badge(env: Env, id: u64) -> Badge {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Badge(id))
+            .expect("badge not found")
+    }
+
+    /// Number of badges ever issued, including revoked ones.
+    pub fn badge_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::BadgeCount)
+            .unwrap_or(0)
+    }
+
+    pub fn is_minter(env: Env, minter: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Minter(minter))
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _require_minter(env: &Env, issuer: &Address) {
+        issuer.require_auth();
+        assert!(Self::is_minter(env.clone(), issuer.clone()), "not a minter");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    const TIER: Symbol = symbol_short!("tier");
+    const SALE: Symbol = symbol_short!("sale");
+
+    struct Setup {
+        env: Env,
+        client: BadgesContractClient<'static>,
+        staking: Address,
+    }
+
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BadgesContract);
+        let client = BadgesContractClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+        let staking = Address::generate(&env);
+        client.set_minter(&staking, &true);
+
+        Setup {
+            env,
+            client,
+            staking,
+        }
+    }
+
+    #[test]
+    fn test_mint_and_query() {
+        let s = setup();
+        let user = Address::generate(&s.env);
+        let sale = Address::generate(&s.env);
+        s.client.set_minter(&sale, &true);
+
+        let tier_id = s.client.mint(&s.staking, &user, &TIER, &2);
+        let sale_id = s.client.mint(&sale, &user, &SALE, &0);
+        assert_ne!(tier_id, sale_id);
+
+        let badges = s.client.badges_of(&user);
+        assert_eq!(badges.len(), 2);
+        assert_eq!(badges.get(0).unwrap().level, 2);
+        assert_eq!(badges.get(1).unwrap().issuer, sale);
+        assert_eq!(
+            s.client.badge_of(&user, &sale, &TIER),
+            None,
+            "kinds are per issuer"
+        );
+    }
+
+    #[test]
+    fn test_mint_again_updates_level() {
+        let s = setup();
+        let user = Address::generate(&s.env);
+
+        let id = s.client.mint(&s.staking, &user, &TIER, &1);
+        assert_eq!(s.client.mint(&s.staking, &user, &TIER, &3), id);
+
+        assert_eq!(s.client.badges_of(&user).len(), 1);
+        assert_eq!(s.client.get_badge(&id).level, 3);
+        assert_eq!(s.client.badge_count(), 1);
+    }
+
+    #[test]
+    fn test_revoke() {
+        let s = setup();
+        let user = Address::generate(&s.env);
+        s.client.mint(&s.staking, &user, &TIER, &1);
+
+        s.client.revoke(&s.staking, &user, &TIER);
+        assert_eq!(s.client.badges_of(&user).len(), 0);
+        assert_eq!(s.client.badge_of(&user, &s.staking, &TIER), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a minter")]
+    fn test_only_minters_mint() {
+        let s = setup();
+        let outsider = Address::generate(&s.env);
+        s.client.mint(&outsider, &outsider, &TIER, &9);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a minter")]
+    fn test_removed_minter_cannot_mint() {
+        let s = setup();
+        s.client.set_minter(&s.staking, &false);
+        s.client
+            .mint(&s.staking, &Address::generate(&s.env), &TIER, &1);
+    }
+}
@@ -0,0 +1,400 @@
+#![no_std]
+
+use launchpad_access as access;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, symbol_short, token, Address, Env, Symbol,
+};
+use soropad_math::mul_div;
+
+/// Reputation signals reported for a bond's team.
+pub const BOND_SIGNAL: Symbol = symbol_short!("bond");
+pub const MILESTONE_SIGNAL: Symbol = symbol_short!("milestone");
+pub const SLASHED_SIGNAL: Symbol = symbol_short!("slashed");
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Token,
+    InsuranceFund,
+    /// Bond posted for a sale.
+    Bond(Address),
+    Reputation,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+pub enum BondStatus {
+    /// Held, partly returned as milestones are delivered.
+    Active,
+    /// Every milestone was delivered and the bond fully returned.
+    Released,
+    /// What was still held went to the insurance fund.
+    Slashed,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Bond {
+    pub team: Address,
+    pub amount: i128,
+    /// Milestones the bond is returned over, in equal parts.
+    pub milestones: u32,
+    pub delivered: u32,
+    /// Part of the bond returned to the team so far.
+    pub released: i128,
+    pub status: BondStatus,
+}
+
+/// Reporting interface of the reputation contract.
+#[contractclient(name = "ReputationClient")]
+pub trait Reputation {
+    fn report(env: Env, reporter: Address, project: Address, signal: Symbol, count: u32);
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Bond Contract — project commitment bonds.
+///
+/// Before its sale goes live, a project team posts a bond against the
+/// sale's address with `post_bond`, promising a number of delivery
+/// milestones. The admin (governance) confirms each milestone as it is
+/// delivered, returning an equal part of the bond to the team; the last
+/// milestone returns the rest.
+///
+/// If governance verifies that the project rugged or was abandoned, it
+/// `slash`es the bond and whatever is still held goes to the insurance
+/// fund, where it can compensate the sale's buyers. Launch tooling and
+/// frontends check `is_bonded` before a sale opens.
+///
+/// With a reputation contract set, posted bonds, confirmed milestones and
+/// slashes are reported as signals for the team.
+#[contract]
+pub struct BondContract;
+
+#[contractimpl]
+impl BondContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the admin (governance), the bond token and the insurance fund
+    /// slashed bonds go to.
+    pub fn initialize(env: Env, admin: Address, token: Address, insurance_fund: Address) {
+        access::init_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Token, &token);
+        env.storage()
+            .instance()
+            .set(&DataKey::InsuranceFund, &insurance_fund);
+
+        env.events()
+            .publish((symbol_short!("init"),), (admin, token, insurance_fund));
+    }
+
+    // ── Teams ───────────────────────────────────────────────────────────
+
+    /// Post a bond of `amount` from `team` for `sale`, returned over
+    /// `milestones` delivered milestones.
+    pub fn post_bond(env: Env, team: Address, sale: Address, amount: i128, milestones: u32) {
+        team.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        assert!(milestones > 0, "milestones must be positive");
+        let key = DataKey::Bond(sale.clone());
+        assert!(!env.storage().persistent().has(&key), "bond exists");
+
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &team,
+            &env.current_contract_address(),
+            &amount,
+        );
+        let bond = Bond {
+            team: team.clone(),
+            amount,
+            milestones,
+            delivered: 0,
+            released: 0,
+            status: BondStatus::Active,
+        };
+        env.storage().persistent().set(&key, &bond);
+        Self::_report(&env, &team, BOND_SIGNAL);
+
+        env.events()
+            .publish((symbol_short!("posted"), sale), (team, amount, milestones));
+    }
+
+    // ── Governance ──────────────────────────────────────────────────────
+
+    /// Confirm the next milestone of `sale`'s project and return its part
+    /// of the bond to the team. Admin only. Returns the amount returned.
+    pub fn confirm_milestone(env: Env, sale: Address) -> i128 {
+        access::require_admin(&env);
+        let mut bond = Self::get_bond(env.clone(), sale.clone());
+        assert!(bond.status == BondStatus::Active, "bond not active");
+
+        bond.delivered += 1;
+        let due = mul_div(bond.amount, bond.delivered as i128, bond.milestones as i128);
+        let returned = due - bond.released;
+        bond.released = due;
+        if bond.delivered == bond.milestones {
+            bond.status = BondStatus::Released;
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Bond(sale.clone()), &bond);
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &env.current_contract_address(),
+            &bond.team,
+            &returned,
+        );
+        Self::_report(&env, &bond.team, MILESTONE_SIGNAL);
+
+        env.events().publish(
+            (symbol_short!("milestone"), sale),
+            (bond.delivered, returned),
+        );
+        returned
+    }
+
+    /// Slash what is still held of `sale`'s bond to the insurance fund, on
+    /// a verified rug or abandonment given as `reason`. Admin only. Returns
+    /// the amount slashed.
+    pub fn slash(env: Env, sale: Address, reason: Symbol) -> i128 {
+        access::require_admin(&env);
+        let mut bond = Self::get_bond(env.clone(), sale.clone());
+        assert!(bond.status == BondStatus::Active, "bond not active");
+
+        let slashed = bond.amount - bond.released;
+        bond.status = BondStatus::Slashed;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Bond(sale.clone()), &bond);
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &env.current_contract_address(),
+            &Self::insurance_fund(env.clone()),
+            &slashed,
+        );
+        Self::_report(&env, &bond.team, SLASHED_SIGNAL);
+
+        env.events()
+            .publish((symbol_short!("slashed"), sale), (reason, slashed));
+        slashed
+    }
+
+    /// Send future slashes to `insurance_fund`. Admin only.
+    pub fn set_insurance_fund(env: Env, insurance_fund: Address) {
+        access::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::InsuranceFund, &insurance_fund);
+        env.events()
+            .publish((symbol_short!("ins_fund"),), insurance_fund);
+    }
+
+    /// Report bond signals to `reputation`, which must list this contract
+    /// as a reporter, or stop with `None`. Admin only.
+    pub fn set_reputation(env: Env, reputation: Option<Address>) {
+        access::require_admin(&env);
+        match &reputation {
+            Some(reputation) => env
+                .storage()
+                .instance()
+                .set(&DataKey::Reputation, reputation),
+            None => env.storage().instance().remove(&DataKey::Reputation),
+        }
+        env.events()
+            .publish((symbol_short!("reputat"),), reputation);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Whether `sale` has an active bond of at least `min_amount`.
+    pub fn is_bonded(env: Env, sale: Address, min_amount: i128) -> bool {
+        match env
+            .storage()
+            .persistent()
+            .get::<_, Bond>(&DataKey::Bond(sale))
+        {
+            Some(bond) => bond.status == BondStatus::Active && bond.amount >= min_amount,
+            None => false,
+        }
+    }
+
+    /// Part of `sale`'s bond still held, 0 once released or slashed.
+    pub fn held(env: Env, sale: Address) -> i128 {
+        match env
+            .storage()
+            .persistent()
+            .get::<_, Bond>(&DataKey::Bond(sale))
+        {
+            Some(bond) if bond.status == BondStatus::Active => bond.amount - bond.released,
+            _ => 0,
+        }
+    }
+
+    pub fn get_bond(env: Env, sale: Address) -> Bond {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Bond(sale))
+            .expect("no bond found")
+    }
+
+    pub fn reputation(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Reputation)
+    }
+
+    pub fn insurance_fund(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::InsuranceFund)
+            .expect("not initialized")
+    }
+
+    pub fn token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("not initialized")
+    }
+
+    pub fn admin(env: Env) -> Address {
+        access::admin(&env)
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _report(env: &Env, team: &Address, signal: Symbol) {
+        if let Some(reputation) = Self::reputation(env.clone()) {
+            ReputationClient::new(env, &reputation).report(
+                &env.current_contract_address(),
+                team,
+                &signal,
+                &1,
+            );
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::testutils::Address as _;
+
+    struct Setup {
+        env: Env,
+        client: BondContractClient<'static>,
+        token: TokenFixture,
+        insurance: Address,
+        team: Address,
+        sale: Address,
+    }
+
+    /// The team has posted a bond of 1 000 over 3 milestones.
+    fn setup() -> Setup {
+        let env = test_env();
+
+        let token = TokenFixture::stellar_asset(&env);
+        let insurance = Address::generate(&env);
+        let client = BondContractClient::new(&env, &env.register_contract(None, BondContract));
+        client.initialize(&Address::generate(&env), &token.address, &insurance);
+
+        let (team, sale) = (Address::generate(&env), Address::generate(&env));
+        token.mint(&team, 1_000);
+        client.post_bond(&team, &sale, &1_000, &3);
+
+        Setup {
+            token,
+            env,
+            client,
+            insurance,
+            team,
+            sale,
+        }
+    }
+
+    #[test]
+    fn test_milestones_return_the_bond() {
+        let s = setup();
+        assert!(s.client.is_bonded(&s.sale, &1_000));
+        assert!(!s.client.is_bonded(&s.sale, &1_001));
+
+        assert_eq!(s.client.confirm_milestone(&s.sale), 333);
+        assert_eq!(s.client.confirm_milestone(&s.sale), 333);
+        assert_eq!(s.client.held(&s.sale), 334);
+        assert_eq!(s.client.confirm_milestone(&s.sale), 334);
+
+        assert_eq!(s.token.balance(&s.team), 1_000);
+        assert_eq!(s.client.get_bond(&s.sale).status, BondStatus::Released);
+        assert!(!s.client.is_bonded(&s.sale, &0));
+        assert!(s.client.try_confirm_milestone(&s.sale).is_err());
+    }
+
+    #[test]
+    fn test_slash_sends_the_rest_to_insurance() {
+        let s = setup();
+        s.client.confirm_milestone(&s.sale);
+        assert_eq!(s.client.slash(&s.sale, &symbol_short!("rug")), 667);
+
+        assert_eq!(s.token.balance(&s.insurance), 667);
+        assert_eq!(s.token.balance(&s.team), 333);
+        assert_eq!(s.client.held(&s.sale), 0);
+        assert!(s.client.try_slash(&s.sale, &symbol_short!("rug")).is_err());
+    }
+
+    #[contract]
+    struct MockReputation;
+
+    #[contractimpl]
+    impl MockReputation {
+        pub fn report(env: Env, _reporter: Address, project: Address, signal: Symbol, count: u32) {
+            let key = (project, signal);
+            let total: u32 = env.storage().instance().get(&key).unwrap_or(0);
+            env.storage().instance().set(&key, &(total + count));
+        }
+
+        pub fn count(env: Env, project: Address, signal: Symbol) -> u32 {
+            env.storage()
+                .instance()
+                .get(&(project, signal))
+                .unwrap_or(0)
+        }
+    }
+
+    #[test]
+    fn test_reports_reputation_signals() {
+        let s = setup();
+        let reputation =
+            MockReputationClient::new(&s.env, &s.env.register_contract(None, MockReputation));
+        s.client.set_reputation(&Some(reputation.address.clone()));
+
+        s.client.confirm_milestone(&s.sale);
+        s.client.slash(&s.sale, &symbol_short!("rug"));
+        assert_eq!(reputation.count(&s.team, &MILESTONE_SIGNAL), 1);
+        assert_eq!(reputation.count(&s.team, &SLASHED_SIGNAL), 1);
+        // Posted before the reputation contract was set
+        assert_eq!(reputation.count(&s.team, &BOND_SIGNAL), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "bond exists")]
+    fn test_one_bond_per_sale() {
+        let s = setup();
+        s.token.mint(&s.team, 1_000);
+        s.client.post_bond(&s.team, &s.sale, &1_000, &1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_only_admin_slashes() {
+        let s = setup();
+        s.env.mock_auths(&[]);
+        s.client.slash(&s.sale, &symbol_short!("rug"));
+    }
+}
@@ -0,0 +1,486 @@
+#![no_std]
+
+use launchpad_access as access;
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, token, xdr::ToXdr, Address, Bytes, BytesN,
+    Env, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Token,
+    Mode,
+    Validators,
+    Threshold,
+    TotalLocked,
+    OutboundCount,
+    Chain(u32),
+    /// Inbound transfer already paid out, by source chain and nonce.
+    Processed(u32, u64),
+}
+
+/// How the token moves across the bridge.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+pub enum BridgeMode {
+    /// The token's home chain: outbound tokens are locked here and inbound
+    /// transfers release them.
+    Lock,
+    /// A wrapped token: outbound tokens are burned and inbound transfers mint
+    /// them. The adapter must be the token's admin.
+    Mint,
+}
+
+/// Transfer to another chain, emitted for the validators to attest.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct OutboundTransfer {
+    pub nonce: u64,
+    pub amount: i128,
+    /// Recipient on the destination chain, in that chain's encoding.
+    pub recipient: Bytes,
+}
+
+/// Transfer from another chain, as signed by the validators.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct InboundTransfer {
+    pub source_chain: u32,
+    /// Nonce of the transfer on the source chain.
+    pub nonce: u64,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+/// A validator's signature, by index into the validator set.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ValidatorSignature {
+    pub index: u32,
+    pub signature: BytesN<64>,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Bridge Adapter Contract — moves a launchpad token to and from other
+/// chains.
+///
+/// `bridge_out` takes tokens from the sender (locking or burning them per
+/// the `BridgeMode`) and emits a `brg_out` event carrying an
+/// `OutboundTransfer` with a fresh nonce, which the validators attest on the
+/// destination chain.
+///
+/// `bridge_in` pays out a transfer from another chain once at least
+/// `threshold` of the configured ed25519 validators have signed
+/// `xdr((adapter, transfer))`. Each source-chain nonce pays out once. In
+/// `BridgeMode::Lock` it can never release more than is locked, so a
+/// compromised validator set cannot drain more than has left the chain.
+///
+/// Only chains enabled by the admin can be sent to or received from.
+#[contract]
+pub struct BridgeAdapterContract;
+
+#[contractimpl]
+impl BridgeAdapterContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the admin, the bridged token, the bridge mode and the validator
+    /// set with its signature threshold.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        token: Address,
+        mode: BridgeMode,
+        validators: Vec<BytesN<32>>,
+        threshold: u32,
+    ) {
+        access::init_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Token, &token);
+        env.storage().instance().set(&DataKey::Mode, &mode);
+        Self::_set_validators(&env, validators, threshold);
+
+        env.events()
+            .publish((symbol_short!("init"),), (admin, token, mode));
+    }
+
+    // ── Transfers ───────────────────────────────────────────────────────
+
+    /// Send `amount` from `from` to `recipient` on `dest_chain`. Returns the
+    /// transfer's nonce.
+    pub fn bridge_out(
+        env: Env,
+        from: Address,
+        amount: i128,
+        dest_chain: u32,
+        recipient: Bytes,
+    ) -> u64 {
+        from.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        assert!(
+            Self::is_chain_enabled(env.clone(), dest_chain),
+            "chain not enabled"
+        );
+
+        let token = token::Client::new(&env, &Self::token(env.clone()));
+        match Self::mode(env.clone()) {
+            BridgeMode::Lock => {
+                token.transfer(&from, &env.current_contract_address(), &amount);
+                let locked = Self::total_locked(env.clone());
+                env.storage()
+                    .instance()
+                    .set(&DataKey::TotalLocked, &(locked + amount));
+            }
+            BridgeMode::Mint => token.burn(&from, &amount),
+        }
+
+        let nonce = Self::outbound_count(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::OutboundCount, &(nonce + 1));
+
+        env.events().publish(
+            (symbol_short!("brg_out"), from, dest_chain),
+            OutboundTransfer {
+                nonce,
+                amount,
+                recipient,
+            },
+        );
+        nonce
+    }
+
+    /// Pay out `transfer` from another chain, given validator `signatures`
+    /// in ascending index order. Anyone may relay; the tokens always go to
+    /// `transfer.recipient`.
+    pub fn bridge_in(env: Env, transfer: InboundTransfer, signatures: Vec<ValidatorSignature>) {
+        assert!(transfer.amount > 0, "amount must be positive");
+        assert!(
+            Self::is_chain_enabled(env.clone(), transfer.source_chain),
+            "chain not enabled"
+        );
+        let key = DataKey::Processed(transfer.source_chain, transfer.nonce);
+        assert!(!env.storage().persistent().has(&key), "already processed");
+        Self::_verify(&env, &transfer, &signatures);
+        env.storage().persistent().set(&key, &true);
+
+        let token = Self::token(env.clone());
+        match Self::mode(env.clone()) {
+            BridgeMode::Lock => {
+                let locked = Self::total_locked(env.clone());
+                assert!(locked >= transfer.amount, "exceeds locked supply");
+                env.storage()
+                    .instance()
+                    .set(&DataKey::TotalLocked, &(locked - transfer.amount));
+                token::Client::new(&env, &token).transfer(
+                    &env.current_contract_address(),
+                    &transfer.recipient,
+                    &transfer.amount,
+                );
+            }
+            BridgeMode::Mint => token::StellarAssetClient::new(&env, &token)
+                .mint(&transfer.recipient, &transfer.amount),
+        }
+
+        env.events().publish(
+            (
+                symbol_short!("brg_in"),
+                transfer.recipient,
+                transfer.source_chain,
+            ),
+            (transfer.nonce, transfer.amount),
+        );
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Replace the validator set and threshold. Admin only.
+    pub fn set_validators(env: Env, validators: Vec<BytesN<32>>, threshold: u32) {
+        access::require_admin(&env);
+        Self::_set_validators(&env, validators, threshold);
+    }
+
+    /// Allow or stop transfers to and from `chain_id`. Admin only.
+    pub fn set_chain(env: Env, chain_id: u32, enabled: bool) {
+        access::require_admin(&env);
+        if enabled {
+            env.storage()
+                .instance()
+                .set(&DataKey::Chain(chain_id), &true);
+        } else {
+            env.storage().instance().remove(&DataKey::Chain(chain_id));
+        }
+        env.events()
+            .publish((symbol_short!("chain"), chain_id), enabled);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn is_chain_enabled(env: Env, chain_id: u32) -> bool {
+        env.storage().instance().has(&DataKey::Chain(chain_id))
+    }
+
+    pub fn is_processed(env: Env, source_chain: u32, nonce: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Processed(source_chain, nonce))
+    }
+
+    /// Tokens locked by outbound transfers and not yet released. Always 0 in
+    /// `BridgeMode::Mint`.
+    pub fn total_locked(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalLocked)
+            .unwrap_or(0)
+    }
+
+    /// Number of outbound transfers, which is also the next nonce.
+    pub fn outbound_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::OutboundCount)
+            .unwrap_or(0)
+    }
+
+    pub fn validators(env: Env) -> Vec<BytesN<32>> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Validators)
+            .expect("not initialized")
+    }
+
+    pub fn threshold(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Threshold)
+            .expect("not initialized")
+    }
+
+    pub fn mode(env: Env) -> BridgeMode {
+        env.storage()
+            .instance()
+            .get(&DataKey::Mode)
+            .expect("not initialized")
+    }
+
+    pub fn token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("not initialized")
+    }
+
+    pub fn admin(env: Env) -> Address {
+        access::admin(&env)
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _set_validators(env: &Env, validators: Vec<BytesN<32>>, threshold: u32) {
+        assert!(
+            threshold > 0 && threshold <= validators.len(),
+            "invalid threshold"
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::Validators, &validators);
+        env.storage()
+            .instance()
+            .set(&DataKey::Threshold, &threshold);
+
+        env.events()
+            .publish((symbol_short!("validator"),), (validators, threshold));
+    }
+
+    /// Check that `signatures` holds at least `threshold` valid signatures
+    /// over `transfer` from distinct validators.
+    fn _verify(env: &Env, transfer: &InboundTransfer, signatures: &Vec<ValidatorSignature>) {
+        let validators = Self::validators(env.clone());
+        assert!(
+            signatures.len() >= Self::threshold(env.clone()),
+            "not enough signatures"
+        );
+
+        let message = (env.current_contract_address(), transfer.clone()).to_xdr(env);
+        let mut next_index = 0;
+        for signature in signatures.iter() {
+            // Ascending indices rule out counting a validator twice
+            assert!(signature.index >= next_index, "signatures out of order");
+            let public_key = validators.get(signature.index).expect("unknown validator");
+            env.crypto()
+                .ed25519_verify(&public_key, &message, &signature.signature);
+            next_index = signature.index + 1;
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::testutils::Address as _;
+
+    const REMOTE: u32 = 1;
+
+    struct Setup {
+        env: Env,
+        client: BridgeAdapterContractClient<'static>,
+        token: TokenFixture,
+    }
+
+    fn validator_key(i: u8) -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&[i + 1; 32])
+    }
+
+    /// Three validators, two signatures needed, `REMOTE` enabled.
+    fn setup(mode: BridgeMode) -> Setup {
+        let env = test_env();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, BridgeAdapterContract);
+        let token_admin = match mode {
+            BridgeMode::Lock => admin.clone(),
+            BridgeMode::Mint => contract_id.clone(),
+        };
+        let token = TokenFixture::stellar_asset_of(&env, token_admin);
+        let client = BridgeAdapterContractClient::new(&env, &contract_id);
+        let validators = Vec::from_array(
+            &env,
+            [0, 1, 2]
+                .map(|i| BytesN::from_array(&env, &validator_key(i).verifying_key().to_bytes())),
+        );
+        client.initialize(&admin, &token.address, &mode, &validators, &2);
+        client.set_chain(&REMOTE, &true);
+
+        Setup { client, token, env }
+    }
+
+    fn funded_user(s: &Setup, amount: i128) -> Address {
+        let user = Address::generate(&s.env);
+        s.token.mint(&user, amount);
+        user
+    }
+
+    fn sign(s: &Setup, transfer: &InboundTransfer, indices: &[u8]) -> Vec<ValidatorSignature> {
+        use ed25519_dalek::Signer;
+        let message = (s.client.address.clone(), transfer.clone()).to_xdr(&s.env);
+        let mut signatures = Vec::new(&s.env);
+        for &i in indices {
+            let signature = validator_key(i).sign(&message.to_alloc_vec());
+            signatures.push_back(ValidatorSignature {
+                index: i as u32,
+                signature: BytesN::from_array(&s.env, &signature.to_bytes()),
+            });
+        }
+        signatures
+    }
+
+    fn inbound(recipient: &Address, nonce: u64, amount: i128) -> InboundTransfer {
+        InboundTransfer {
+            source_chain: REMOTE,
+            nonce,
+            recipient: recipient.clone(),
+            amount,
+        }
+    }
+
+    #[test]
+    fn test_lock_and_release() {
+        let s = setup(BridgeMode::Lock);
+        let user = funded_user(&s, 1_000);
+        let remote_recipient = Bytes::from_array(&s.env, &[0xab; 20]);
+
+        assert_eq!(
+            s.client.bridge_out(&user, &600, &REMOTE, &remote_recipient),
+            0
+        );
+        assert_eq!(s.token.balance(&user), 400);
+        assert_eq!(s.client.total_locked(), 600);
+        assert_eq!(s.client.outbound_count(), 1);
+
+        let transfer = inbound(&user, 7, 250);
+        s.client.bridge_in(&transfer, &sign(&s, &transfer, &[0, 2]));
+        assert_eq!(s.token.balance(&user), 650);
+        assert_eq!(s.client.total_locked(), 350);
+        assert!(s.client.is_processed(&REMOTE, &7));
+
+        // Each nonce pays out once
+        assert!(s
+            .client
+            .try_bridge_in(&transfer, &sign(&s, &transfer, &[0, 1]))
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds locked supply")]
+    fn test_release_capped_by_locked_supply() {
+        let s = setup(BridgeMode::Lock);
+        let user = funded_user(&s, 100);
+        s.client
+            .bridge_out(&user, &100, &REMOTE, &Bytes::new(&s.env));
+        let transfer = inbound(&user, 0, 101);
+        s.client.bridge_in(&transfer, &sign(&s, &transfer, &[0, 1]));
+    }
+
+    #[test]
+    fn test_burn_and_mint() {
+        let s = setup(BridgeMode::Mint);
+        let user = Address::generate(&s.env);
+
+        let transfer = inbound(&user, 0, 500);
+        s.client.bridge_in(&transfer, &sign(&s, &transfer, &[1, 2]));
+        assert_eq!(s.token.balance(&user), 500);
+
+        s.client
+            .bridge_out(&user, &200, &REMOTE, &Bytes::new(&s.env));
+        assert_eq!(s.token.balance(&user), 300);
+        assert_eq!(s.client.total_locked(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "not enough signatures")]
+    fn test_threshold_enforced() {
+        let s = setup(BridgeMode::Mint);
+        let transfer = inbound(&Address::generate(&s.env), 0, 500);
+        s.client.bridge_in(&transfer, &sign(&s, &transfer, &[1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "signatures out of order")]
+    fn test_same_validator_counts_once() {
+        let s = setup(BridgeMode::Mint);
+        let transfer = inbound(&Address::generate(&s.env), 0, 500);
+        s.client.bridge_in(&transfer, &sign(&s, &transfer, &[1, 1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_signature_bound_to_transfer() {
+        let s = setup(BridgeMode::Mint);
+        let user = Address::generate(&s.env);
+        let signed = inbound(&user, 0, 500);
+        let tampered = inbound(&user, 0, 5_000);
+        s.client.bridge_in(&tampered, &sign(&s, &signed, &[0, 1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "chain not enabled")]
+    fn test_disabled_chain() {
+        let s = setup(BridgeMode::Lock);
+        let user = funded_user(&s, 100);
+        s.client.set_chain(&REMOTE, &false);
+        s.client
+            .bridge_out(&user, &100, &REMOTE, &Bytes::new(&s.env));
+    }
+}
@@ -0,0 +1,504 @@
+#![no_std]
+
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractclient, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
+    IntoVal, Symbol,
+};
+use soropad_math::{mul_div, mul_div_ceil};
+
+/// Fixed-point scale of `BuybackConfig::min_rate`.
+///
+/// `min_rate` is the least amount of target-token units accepted for
+/// `RATE_SCALE` payment-token units, so a rate equal to `RATE_SCALE` is 1:1.
+pub const RATE_SCALE: i128 = 10_000_000;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Config,
+    /// Ledger of the last buyback.
+    LastBuyback,
+    TotalSpent,
+    TotalBought,
+    Twap,
+}
+
+/// What happens to the bought tokens.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Destination {
+    Burn,
+    /// Forwarded to the given treasury.
+    Treasury(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct BuybackConfig {
+    /// Liquidity adapter swapped through.
+    pub adapter: Address,
+    /// Token accumulated and spent.
+    pub payment_token: Address,
+    /// Token bought back.
+    pub target_token: Address,
+    /// Most spent per buyback.
+    pub amount_per_period: i128,
+    /// Ledgers between buybacks.
+    pub period: u32,
+    /// Worst accepted rate, in target-token units per `RATE_SCALE` payment
+    /// units.
+    pub min_rate: i128,
+    pub destination: Destination,
+}
+
+/// Minimum output taken from a time-weighted average price.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TwapGuard {
+    /// TWAP contract pricing the target token in payment-token units per
+    /// `RATE_SCALE` target units.
+    pub twap: Address,
+    /// Ledgers the average is taken over.
+    pub window: u32,
+    /// Largest shortfall accepted below the TWAP, in bps.
+    pub max_slippage_bps: u32,
+}
+
+/// Price interface of the TWAP contract.
+#[contractclient(name = "TwapClient")]
+pub trait Twap {
+    fn twap(env: Env, asset: Address, window: u32) -> i128;
+}
+
+/// Swap interface of the liquidity adapter.
+#[contractclient(name = "LiquidityAdapterClient")]
+pub trait LiquidityAdapter {
+    /// Swap exactly `amount_in` of `token_in`, pulled from `from`, for at
+    /// least `min_out` of `token_out` delivered to `from`. Returns the amount
+    /// received.
+    fn swap(
+        env: Env,
+        from: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        min_out: i128,
+    ) -> i128;
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Buyback Vault Contract — steady buybacks of a target token.
+///
+/// Payment tokens accumulate in the vault, from fee splits, `deposit` or
+/// plain transfers. Instead of buying everything at once, the vault spends
+/// at most `amount_per_period` per buyback and at most one buyback per
+/// `period` ledgers, so purchases are spread over time like a TWAP order.
+///
+/// `execute` is open to any keeper: the keeper only chooses when to run a
+/// due buyback, not its terms. Every swap must return at least `min_rate`
+/// target tokens per `RATE_SCALE` spent, a floor set by the admin
+/// (governance), so a keeper cannot push a trade through a manipulated
+/// pool. With a TWAP guard set, the floor also tracks the market: a swap
+/// must return the TWAP amount less `max_slippage_bps`, whichever floor is
+/// higher. Bought tokens are burned or forwarded to the treasury.
+#[contract]
+pub struct BuybackContract;
+
+#[contractimpl]
+impl BuybackContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, admin: Address, config: BuybackConfig) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        Self::_set_config(&env, config);
+
+        env.events().publish((symbol_short!("init"),), admin);
+    }
+
+    // ── Funding ─────────────────────────────────────────────────────────
+
+    /// Add `amount` of the payment token from `from`.
+    pub fn deposit(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        token::Client::new(&env, &Self::get_config(env.clone()).payment_token).transfer(
+            &from,
+            &env.current_contract_address(),
+            &amount,
+        );
+        env.events()
+            .publish((symbol_short!("deposit"), from), amount);
+    }
+
+    // ── Keepers ─────────────────────────────────────────────────────────
+
+    /// Run the buyback that is due: spend up to `amount_per_period` on the
+    /// target token and burn or forward it. Anyone may call. Returns the
+    /// amounts spent and bought.
+    pub fn execute(env: Env) -> (i128, i128) {
+        let config = Self::get_config(env.clone());
+        assert!(
+            env.ledger().sequence() >= Self::next_buyback_ledger(env.clone()),
+            "buyback not due"
+        );
+        let this = env.current_contract_address();
+        let spent = token::Client::new(&env, &config.payment_token)
+            .balance(&this)
+            .min(config.amount_per_period);
+        assert!(spent > 0, "nothing to buy back");
+
+        env.storage()
+            .instance()
+            .set(&DataKey::LastBuyback, &env.ledger().sequence());
+        let min_out = Self::min_out(env.clone(), spent);
+        Self::_authorize_pull(&env, &config.payment_token, &config.adapter, spent);
+        let bought = LiquidityAdapterClient::new(&env, &config.adapter).swap(
+            &this,
+            &config.payment_token,
+            &config.target_token,
+            &spent,
+            &min_out,
+        );
+        assert!(bought >= min_out, "insufficient output");
+
+        let target = token::Client::new(&env, &config.target_token);
+        match &config.destination {
+            Destination::Burn => target.burn(&this, &bought),
+            Destination::Treasury(treasury) => target.transfer(&this, treasury, &bought),
+        }
+        let total_spent = Self::total_spent(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalSpent, &(total_spent + spent));
+        let total_bought = Self::total_bought(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalBought, &(total_bought + bought));
+
+        env.events()
+            .publish((symbol_short!("buyback"),), (spent, bought));
+        (spent, bought)
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Replace the buyback parameters. Admin only.
+    pub fn set_config(env: Env, config: BuybackConfig) {
+        Self::admin(env.clone()).require_auth();
+        Self::_set_config(&env, config);
+    }
+
+    /// Set or remove the TWAP floor on swaps. Admin only.
+    pub fn set_twap(env: Env, guard: Option<TwapGuard>) {
+        Self::admin(env.clone()).require_auth();
+        match &guard {
+            Some(guard) => {
+                assert!(guard.window > 0, "window must be positive");
+                assert!(guard.max_slippage_bps <= 10_000, "slippage above 100%");
+                env.storage().instance().set(&DataKey::Twap, guard);
+            }
+            None => env.storage().instance().remove(&DataKey::Twap),
+        }
+        env.events().publish((symbol_short!("twap"),), guard);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Least amount of target tokens accepted for `spent` payment tokens:
+    /// the `min_rate` floor, or the TWAP floor if a guard is set and it is
+    /// higher.
+    pub fn min_out(env: Env, spent: i128) -> i128 {
+        let config = Self::get_config(env.clone());
+        let floor = mul_div_ceil(spent, config.min_rate, RATE_SCALE);
+        match Self::twap_guard(env.clone()) {
+            Some(guard) => {
+                let price =
+                    TwapClient::new(&env, &guard.twap).twap(&config.target_token, &guard.window);
+                let at_twap = mul_div(spent, RATE_SCALE, price);
+                floor.max(mul_div_ceil(
+                    at_twap,
+                    (10_000 - guard.max_slippage_bps) as i128,
+                    10_000,
+                ))
+            }
+            None => floor,
+        }
+    }
+
+    pub fn twap_guard(env: Env) -> Option<TwapGuard> {
+        env.storage().instance().get(&DataKey::Twap)
+    }
+
+    /// First ledger at which `execute` can run again.
+    pub fn next_buyback_ledger(env: Env) -> u32 {
+        match env
+            .storage()
+            .instance()
+            .get::<_, u32>(&DataKey::LastBuyback)
+        {
+            Some(last) => last.saturating_add(Self::get_config(env).period),
+            None => 0,
+        }
+    }
+
+    /// Payment tokens waiting to be spent.
+    pub fn pending(env: Env) -> i128 {
+        token::Client::new(&env, &Self::get_config(env.clone()).payment_token)
+            .balance(&env.current_contract_address())
+    }
+
+    pub fn total_spent(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalSpent)
+            .unwrap_or(0)
+    }
+
+    pub fn total_bought(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalBought)
+            .unwrap_or(0)
+    }
+
+    pub fn get_config(env: Env) -> BuybackConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .expect("not initialized")
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _set_config(env: &Env, config: BuybackConfig) {
+        assert!(
+            config.payment_token != config.target_token,
+            "same token on both sides"
+        );
+        assert!(
+            config.amount_per_period > 0,
+            "amount per period must be positive"
+        );
+        assert!(config.period > 0, "period must be positive");
+        assert!(config.min_rate > 0, "min rate must be positive");
+        env.storage().instance().set(&DataKey::Config, &config);
+
+        env.events().publish((symbol_short!("config"),), config);
+    }
+
+    /// Let `to` pull exactly `amount` of `token` from the vault within the
+    /// current call.
+    fn _authorize_pull(env: &Env, token: &Address, to: &Address, amount: i128) {
+        env.authorize_as_current_contract(vec![
+            env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token.clone(),
+                    fn_name: Symbol::new(env, "transfer"),
+                    args: (env.current_contract_address(), to.clone(), amount).into_val(env),
+                },
+                sub_invocations: vec![env],
+            }),
+        ]);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger};
+
+    /// DEX stand-in that sells its target-token inventory at the rate stored
+    /// under `rate`, in target units per payment unit.
+    #[contract]
+    struct MockDex;
+
+    #[contractimpl]
+    impl MockDex {
+        pub fn set_rate(env: Env, rate: i128) {
+            env.storage().instance().set(&symbol_short!("rate"), &rate);
+        }
+
+        pub fn swap(
+            env: Env,
+            from: Address,
+            token_in: Address,
+            token_out: Address,
+            amount_in: i128,
+            min_out: i128,
+        ) -> i128 {
+            let this = env.current_contract_address();
+            token::Client::new(&env, &token_in).transfer(&from, &this, &amount_in);
+            let rate: i128 = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("rate"))
+                .unwrap();
+            let out = amount_in * rate;
+            assert!(out >= min_out, "slippage");
+            token::Client::new(&env, &token_out).transfer(&this, &from, &out);
+            out
+        }
+    }
+
+    /// TWAP contract quoting a settable price for every asset.
+    #[contract]
+    struct MockTwap;
+
+    #[contractimpl]
+    impl MockTwap {
+        pub fn set_price(env: Env, price: i128) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("price"), &price);
+        }
+
+        pub fn twap(env: Env, _asset: Address, _window: u32) -> i128 {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("price"))
+                .unwrap()
+        }
+    }
+
+    struct Setup {
+        env: Env,
+        client: BuybackContractClient<'static>,
+        dex: MockDexClient<'static>,
+        payment: TokenFixture,
+        target: TokenFixture,
+    }
+
+    /// Buys up to 100 every 10 ledgers at no worse than 2:1, burning the
+    /// purchase; the vault holds 250 and the DEX sells at 2:1.
+    fn setup() -> Setup {
+        let env = test_env();
+        env.ledger().set_sequence_number(100);
+
+        let payment = TokenFixture::stellar_asset(&env);
+        let target = TokenFixture::stellar_asset(&env);
+        let dex = MockDexClient::new(&env, &env.register_contract(None, MockDex));
+        dex.set_rate(&2);
+        target.mint(&dex.address, 10_000);
+
+        let client =
+            BuybackContractClient::new(&env, &env.register_contract(None, BuybackContract));
+        client.initialize(
+            &Address::generate(&env),
+            &BuybackConfig {
+                adapter: dex.address.clone(),
+                payment_token: payment.address.clone(),
+                target_token: target.address.clone(),
+                amount_per_period: 100,
+                period: 10,
+                min_rate: 2 * RATE_SCALE,
+                destination: Destination::Burn,
+            },
+        );
+        payment.mint(&client.address, 250);
+
+        Setup {
+            payment,
+            target,
+            env,
+            client,
+            dex,
+        }
+    }
+
+    #[test]
+    fn test_buybacks_are_spread_over_periods() {
+        let s = setup();
+        assert_eq!(s.client.execute(), (100, 200));
+        assert!(s.client.try_execute().is_err());
+        assert_eq!(s.client.next_buyback_ledger(), 110);
+
+        s.env.ledger().set_sequence_number(110);
+        s.client.execute();
+        s.env.ledger().set_sequence_number(120);
+        assert_eq!(s.client.execute(), (50, 100));
+        assert_eq!(s.client.pending(), 0);
+
+        assert_eq!(s.client.total_spent(), 250);
+        assert_eq!(s.client.total_bought(), 500);
+        assert_eq!(s.target.balance(&s.client.address), 0);
+        assert_eq!(s.target.balance(&s.dex.address), 9_500);
+    }
+
+    #[test]
+    fn test_forward_to_treasury() {
+        let s = setup();
+        let treasury = Address::generate(&s.env);
+        let mut config = s.client.get_config();
+        config.destination = Destination::Treasury(treasury.clone());
+        s.client.set_config(&config);
+
+        s.client.execute();
+        assert_eq!(s.target.balance(&treasury), 200);
+        assert_eq!(s.payment.balance(&s.client.address), 150);
+    }
+
+    #[test]
+    #[should_panic(expected = "slippage")]
+    fn test_keeper_cannot_accept_a_worse_rate() {
+        let s = setup();
+        s.dex.set_rate(&1);
+        s.client.execute();
+    }
+
+    #[test]
+    fn test_twap_raises_floor() {
+        let s = setup();
+        let twap = MockTwapClient::new(&s.env, &s.env.register_contract(None, MockTwap));
+        // The market averages 4 target tokens per payment token
+        twap.set_price(&(RATE_SCALE / 4));
+        s.client.set_twap(&Some(TwapGuard {
+            twap: twap.address.clone(),
+            window: 100,
+            max_slippage_bps: 1_000,
+        }));
+        assert_eq!(s.client.min_out(&100), 360);
+
+        // Below the TWAP floor, though above `min_rate`
+        s.dex.set_rate(&3);
+        assert!(s.client.try_execute().is_err());
+        s.dex.set_rate(&4);
+        assert_eq!(s.client.execute(), (100, 400));
+    }
+
+    #[test]
+    #[should_panic(expected = "nothing to buy back")]
+    fn test_empty_vault() {
+        let s = setup();
+        s.env.as_contract(&s.client.address, || {
+            s.payment
+                .client
+                .transfer(&s.client.address, &Address::generate(&s.env), &250);
+        });
+        s.client.execute();
+    }
+}
@@ -0,0 +1,301 @@
+#![no_std]
+
+use launchpad_access as access;
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+/// Platform fee on sales, in basis points (`U32`).
+pub const FEE_BPS: Symbol = symbol_short!("fee_bps");
+/// Recipient of platform fees (`Address`).
+pub const FEE_RECIPIENT: Symbol = symbol_short!("fee_to");
+/// Guardian allowed to pause platform contracts (`Address`).
+pub const GUARDIAN: Symbol = symbol_short!("guardian");
+/// Price oracle adapter (`Address`).
+pub const PRICE_ORACLE: Symbol = symbol_short!("oracle");
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Bumped on every change.
+    Version,
+    /// Every key with a value, in the order they were first set.
+    Keys,
+    Entry(Symbol),
+    PaymentToken(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum ConfigValue {
+    U32(u32),
+    I128(i128),
+    Address(Address),
+    Bool(bool),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Entry {
+    pub value: ConfigValue,
+    /// Configuration version that set the value.
+    pub version: u32,
+    pub updated_ledger: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Config Contract — one source of truth for platform-wide parameters.
+///
+/// Parameters shared across contracts — the default fee and its recipient,
+/// the guardian, oracle addresses — are stored here under a key, along
+/// with the payment tokens sales may accept, instead of being copied into
+/// every contract and drifting apart. Other contracts read them with `get`
+/// and `is_payment_token_allowed`; the well-known keys and their value
+/// types are the constants of this crate.
+///
+/// Only the admin, which should be governance through the timelock, can
+/// change anything. Every change bumps the configuration `version`, and
+/// each entry records the version and ledger that last set it, so readers
+/// can tell when a parameter changed.
+#[contract]
+pub struct ConfigContract;
+
+#[contractimpl]
+impl ConfigContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, admin: Address) {
+        access::init_admin(&env, &admin);
+        env.events().publish((symbol_short!("init"),), admin);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Set `key` to `value`. Admin only. Returns the new version.
+    pub fn set(env: Env, key: Symbol, value: ConfigValue) -> u32 {
+        access::require_admin(&env);
+        if key == FEE_BPS {
+            match &value {
+                ConfigValue::U32(bps) => assert!(*bps <= 10_000, "fee_bps exceeds 100%"),
+                _ => panic!("wrong value type"),
+            }
+        }
+        let mut keys = Self::keys(env.clone());
+        if !keys.contains(&key) {
+            keys.push_back(key.clone());
+            env.storage().instance().set(&DataKey::Keys, &keys);
+        }
+        let version = Self::_bump(&env);
+        let entry = Entry {
+            value,
+            version,
+            updated_ledger: env.ledger().sequence(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Entry(key.clone()), &entry);
+
+        env.events()
+            .publish((symbol_short!("set"), key), (version, entry.value));
+        version
+    }
+
+    /// Clear `key`. Admin only. Returns the new version.
+    pub fn remove(env: Env, key: Symbol) -> u32 {
+        access::require_admin(&env);
+        let mut keys = Self::keys(env.clone());
+        let index = keys.first_index_of(&key).expect("not configured");
+        keys.remove(index);
+        env.storage().instance().set(&DataKey::Keys, &keys);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Entry(key.clone()));
+        let version = Self::_bump(&env);
+
+        env.events()
+            .publish((symbol_short!("remove"), key), version);
+        version
+    }
+
+    /// Allow or disallow `token` as a sale payment token. Admin only.
+    /// Returns the new version.
+    pub fn set_payment_token(env: Env, token: Address, allowed: bool) -> u32 {
+        access::require_admin(&env);
+        let key = DataKey::PaymentToken(token.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        let version = Self::_bump(&env);
+
+        env.events()
+            .publish((symbol_short!("pay_tok"), token), (version, allowed));
+        version
+    }
+
+    /// Nominate `new_admin`, who takes over once they call `accept_admin`.
+    /// Admin only.
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        access::propose_admin(&env, &new_admin);
+    }
+
+    /// Complete an admin transfer. Pending admin only.
+    pub fn accept_admin(env: Env) {
+        access::accept_admin(&env);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn get(env: Env, key: Symbol) -> Option<ConfigValue> {
+        Self::entry(env, key).map(|entry| entry.value)
+    }
+
+    pub fn entry(env: Env, key: Symbol) -> Option<Entry> {
+        env.storage().persistent().get(&DataKey::Entry(key))
+    }
+
+    pub fn get_u32(env: Env, key: Symbol) -> u32 {
+        match Self::get(env, key).expect("not configured") {
+            ConfigValue::U32(value) => value,
+            _ => panic!("wrong value type"),
+        }
+    }
+
+    pub fn get_i128(env: Env, key: Symbol) -> i128 {
+        match Self::get(env, key).expect("not configured") {
+            ConfigValue::I128(value) => value,
+            _ => panic!("wrong value type"),
+        }
+    }
+
+    pub fn get_address(env: Env, key: Symbol) -> Address {
+        match Self::get(env, key).expect("not configured") {
+            ConfigValue::Address(value) => value,
+            _ => panic!("wrong value type"),
+        }
+    }
+
+    pub fn get_bool(env: Env, key: Symbol) -> bool {
+        match Self::get(env, key).expect("not configured") {
+            ConfigValue::Bool(value) => value,
+            _ => panic!("wrong value type"),
+        }
+    }
+
+    pub fn is_payment_token_allowed(env: Env, token: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::PaymentToken(token))
+    }
+
+    /// Every key with a value.
+    pub fn keys(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Keys)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn version(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    }
+
+    pub fn admin(env: Env) -> Address {
+        access::admin(&env)
+    }
+
+    pub fn pending_admin(env: Env) -> Option<Address> {
+        access::pending_admin(&env)
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _bump(env: &Env) -> u32 {
+        let version = Self::version(env.clone()) + 1;
+        env.storage().instance().set(&DataKey::Version, &version);
+        version
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    fn setup() -> (Env, ConfigContractClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = ConfigContractClient::new(&env, &env.register_contract(None, ConfigContract));
+        client.initialize(&Address::generate(&env));
+        (env, client)
+    }
+
+    #[test]
+    fn test_set_and_read_versioned_values() {
+        let (env, client) = setup();
+        let guardian = Address::generate(&env);
+        assert_eq!(client.set(&FEE_BPS, &ConfigValue::U32(250)), 1);
+        env.ledger().set_sequence_number(50);
+        assert_eq!(
+            client.set(&GUARDIAN, &ConfigValue::Address(guardian.clone())),
+            2
+        );
+        client.set(&FEE_BPS, &ConfigValue::U32(200));
+
+        assert_eq!(client.get_u32(&FEE_BPS), 200);
+        assert_eq!(client.get_address(&GUARDIAN), guardian);
+        let entry = client.entry(&FEE_BPS).unwrap();
+        assert_eq!((entry.version, entry.updated_ledger), (3, 50));
+        assert_eq!(client.keys().len(), 2);
+
+        client.remove(&GUARDIAN);
+        assert_eq!(client.get(&GUARDIAN), None);
+        assert_eq!(client.version(), 4);
+        assert!(client.try_get_u32(&GUARDIAN).is_err());
+    }
+
+    #[test]
+    fn test_payment_token_allow_list() {
+        let (env, client) = setup();
+        let usdc = Address::generate(&env);
+        assert!(!client.is_payment_token_allowed(&usdc));
+        client.set_payment_token(&usdc, &true);
+        assert!(client.is_payment_token_allowed(&usdc));
+        client.set_payment_token(&usdc, &false);
+        assert!(!client.is_payment_token_allowed(&usdc));
+        assert_eq!(client.version(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong value type")]
+    fn test_typed_getter_checks_type() {
+        let (_, client) = setup();
+        client.set(&PRICE_ORACLE, &ConfigValue::Bool(true));
+        client.get_address(&PRICE_ORACLE);
+    }
+
+    #[test]
+    #[should_panic(expected = "fee_bps exceeds 100%")]
+    fn test_fee_bps_validated() {
+        let (_, client) = setup();
+        client.set(&FEE_BPS, &ConfigValue::U32(10_001));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_only_admin_sets() {
+        let (env, client) = setup();
+        env.mock_auths(&[]);
+        client.set(&FEE_BPS, &ConfigValue::U32(100));
+    }
+}
@@ -0,0 +1,361 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    CampaignCount,
+    Campaign(u32),
+    /// Amount a backer has pledged to a campaign and not reclaimed.
+    Pledge(u32, Address),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+pub enum CampaignStatus {
+    /// Taking pledges until the deadline.
+    Open,
+    /// Past the deadline with the goal met; the creator can withdraw.
+    Succeeded,
+    /// Past the deadline short of the goal; backers can reclaim.
+    Failed,
+    /// The creator has withdrawn the pledges.
+    Withdrawn,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Campaign {
+    pub id: u32,
+    pub creator: Address,
+    /// Token pledged.
+    pub token: Address,
+    pub goal: i128,
+    /// Last ledger on which pledges are taken.
+    pub deadline: u32,
+    pub pledged: i128,
+    pub withdrawn: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Crowdfund Contract — all-or-nothing pledge campaigns.
+///
+/// Unlike the token sale, a campaign sells nothing: it is a community
+/// funding round for a project that has no token yet. Anyone can
+/// `create_campaign` with a payment token, a goal and a deadline. Backers
+/// `pledge` until the deadline, and may `unpledge` while it is open.
+///
+/// Once the deadline has passed, the campaign either met its goal and the
+/// creator can `withdraw` everything pledged, or it did not and every
+/// backer can `reclaim` their pledge. Neither side can take the funds in
+/// the other case.
+#[contract]
+pub struct CrowdfundContract;
+
+#[contractimpl]
+impl CrowdfundContract {
+    // ── Creators ────────────────────────────────────────────────────────
+
+    /// Start a campaign raising `goal` of `token` until `deadline`.
+    /// Returns the campaign id.
+    pub fn create_campaign(
+        env: Env,
+        creator: Address,
+        token: Address,
+        goal: i128,
+        deadline: u32,
+    ) -> u32 {
+        creator.require_auth();
+        assert!(goal > 0, "goal must be positive");
+        assert!(deadline >= env.ledger().sequence(), "deadline in the past");
+
+        let id = Self::campaign_count(env.clone());
+        let campaign = Campaign {
+            id,
+            creator: creator.clone(),
+            token,
+            goal,
+            deadline,
+            pledged: 0,
+            withdrawn: false,
+        };
+        Self::_save(&env, &campaign);
+        env.storage()
+            .instance()
+            .set(&DataKey::CampaignCount, &(id + 1));
+
+        env.events()
+            .publish((symbol_short!("created"), id), (creator, goal, deadline));
+        id
+    }
+
+    /// Withdraw every pledge of a campaign that met its goal. Creator only.
+    /// Returns the amount withdrawn.
+    pub fn withdraw(env: Env, campaign_id: u32) -> i128 {
+        let mut campaign = Self::get_campaign(env.clone(), campaign_id);
+        campaign.creator.require_auth();
+        assert!(
+            Self::status(env.clone(), campaign_id) == CampaignStatus::Succeeded,
+            "campaign not succeeded"
+        );
+
+        campaign.withdrawn = true;
+        Self::_save(&env, &campaign);
+        token::Client::new(&env, &campaign.token).transfer(
+            &env.current_contract_address(),
+            &campaign.creator,
+            &campaign.pledged,
+        );
+
+        env.events()
+            .publish((symbol_short!("withdrawn"), campaign_id), campaign.pledged);
+        campaign.pledged
+    }
+
+    // ── Backers ─────────────────────────────────────────────────────────
+
+    /// Pledge `amount` from `backer` to an open campaign.
+    pub fn pledge(env: Env, campaign_id: u32, backer: Address, amount: i128) {
+        backer.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        let mut campaign = Self::get_campaign(env.clone(), campaign_id);
+        assert!(
+            Self::status(env.clone(), campaign_id) == CampaignStatus::Open,
+            "campaign closed"
+        );
+
+        token::Client::new(&env, &campaign.token).transfer(
+            &backer,
+            &env.current_contract_address(),
+            &amount,
+        );
+        let pledged = Self::pledge_of(env.clone(), campaign_id, backer.clone());
+        Self::_set_pledge(&env, campaign_id, &backer, pledged + amount);
+        campaign.pledged += amount;
+        Self::_save(&env, &campaign);
+
+        env.events()
+            .publish((symbol_short!("pledged"), campaign_id, backer), amount);
+    }
+
+    /// Take back `amount` of `backer`'s pledge while the campaign is open.
+    pub fn unpledge(env: Env, campaign_id: u32, backer: Address, amount: i128) {
+        backer.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        let mut campaign = Self::get_campaign(env.clone(), campaign_id);
+        assert!(
+            Self::status(env.clone(), campaign_id) == CampaignStatus::Open,
+            "campaign closed"
+        );
+        let pledged = Self::pledge_of(env.clone(), campaign_id, backer.clone());
+        assert!(amount <= pledged, "insufficient pledge");
+
+        Self::_set_pledge(&env, campaign_id, &backer, pledged - amount);
+        campaign.pledged -= amount;
+        Self::_save(&env, &campaign);
+        token::Client::new(&env, &campaign.token).transfer(
+            &env.current_contract_address(),
+            &backer,
+            &amount,
+        );
+
+        env.events()
+            .publish((symbol_short!("unpledged"), campaign_id, backer), amount);
+    }
+
+    /// Reclaim `backer`'s whole pledge to a campaign that missed its goal.
+    /// Returns the amount reclaimed.
+    pub fn reclaim(env: Env, campaign_id: u32, backer: Address) -> i128 {
+        backer.require_auth();
+        let campaign = Self::get_campaign(env.clone(), campaign_id);
+        assert!(
+            Self::status(env.clone(), campaign_id) == CampaignStatus::Failed,
+            "campaign not failed"
+        );
+        let pledged = Self::pledge_of(env.clone(), campaign_id, backer.clone());
+        assert!(pledged > 0, "nothing to reclaim");
+
+        Self::_set_pledge(&env, campaign_id, &backer, 0);
+        token::Client::new(&env, &campaign.token).transfer(
+            &env.current_contract_address(),
+            &backer,
+            &pledged,
+        );
+
+        env.events()
+            .publish((symbol_short!("reclaimed"), campaign_id, backer), pledged);
+        pledged
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn status(env: Env, campaign_id: u32) -> CampaignStatus {
+        let campaign = Self::get_campaign(env.clone(), campaign_id);
+        if campaign.withdrawn {
+            CampaignStatus::Withdrawn
+        } else if env.ledger().sequence() <= campaign.deadline {
+            CampaignStatus::Open
+        } else if campaign.pledged >= campaign.goal {
+            CampaignStatus::Succeeded
+        } else {
+            CampaignStatus::Failed
+        }
+    }
+
+    pub fn pledge_of(env: Env, campaign_id: u32, backer: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Pledge(campaign_id, backer))
+            .unwrap_or(0)
+    }
+
+    /// Campaigns `start` to `start + limit - 1`, oldest first.
+    pub fn campaigns(env: Env, start: u32, limit: u32) -> Vec<Campaign> {
+        let end = start
+            .saturating_add(limit)
+            .min(Self::campaign_count(env.clone()));
+        let mut campaigns = Vec::new(&env);
+        for id in start..end {
+            campaigns.push_back(Self::get_campaign(env.clone(), id));
+        }
+        campaigns
+    }
+
+    pub fn get_campaign(env: Env, campaign_id: u32) -> Campaign {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Campaign(campaign_id))
+            .expect("no campaign found")
+    }
+
+    pub fn campaign_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CampaignCount)
+            .unwrap_or(0)
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _save(env: &Env, campaign: &Campaign) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Campaign(campaign.id), campaign);
+    }
+
+    fn _set_pledge(env: &Env, campaign_id: u32, backer: &Address, amount: i128) {
+        let key = DataKey::Pledge(campaign_id, backer.clone());
+        if amount == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &amount);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger};
+
+    struct Setup {
+        env: Env,
+        client: CrowdfundContractClient<'static>,
+        token: TokenFixture,
+        creator: Address,
+        campaign: u32,
+    }
+
+    /// A campaign for 1 000 open until ledger 100.
+    fn setup() -> Setup {
+        let env = test_env();
+        env.ledger().set_sequence_number(10);
+
+        let token = TokenFixture::stellar_asset(&env);
+        let client =
+            CrowdfundContractClient::new(&env, &env.register_contract(None, CrowdfundContract));
+        let creator = Address::generate(&env);
+        let campaign = client.create_campaign(&creator, &token.address, &1_000, &100);
+
+        Setup {
+            token,
+            env,
+            client,
+            creator,
+            campaign,
+        }
+    }
+
+    fn backer(s: &Setup, amount: i128) -> Address {
+        let backer = Address::generate(&s.env);
+        s.token.mint(&backer, amount);
+        backer
+    }
+
+    #[test]
+    fn test_creator_withdraws_when_goal_met() {
+        let s = setup();
+        let (alice, bob) = (backer(&s, 600), backer(&s, 600));
+        s.client.pledge(&s.campaign, &alice, &600);
+        s.client.pledge(&s.campaign, &bob, &600);
+        s.client.unpledge(&s.campaign, &bob, &100);
+
+        // Not before the deadline
+        assert!(s.client.try_withdraw(&s.campaign).is_err());
+        s.env.ledger().set_sequence_number(101);
+        assert_eq!(s.client.status(&s.campaign), CampaignStatus::Succeeded);
+        assert!(s.client.try_reclaim(&s.campaign, &alice).is_err());
+
+        assert_eq!(s.client.withdraw(&s.campaign), 1_100);
+        assert_eq!(s.token.balance(&s.creator), 1_100);
+        assert_eq!(s.client.status(&s.campaign), CampaignStatus::Withdrawn);
+        assert!(s.client.try_withdraw(&s.campaign).is_err());
+    }
+
+    #[test]
+    fn test_backers_reclaim_when_goal_missed() {
+        let s = setup();
+        let alice = backer(&s, 900);
+        s.client.pledge(&s.campaign, &alice, &900);
+        s.env.ledger().set_sequence_number(101);
+
+        assert_eq!(s.client.status(&s.campaign), CampaignStatus::Failed);
+        assert!(s.client.try_withdraw(&s.campaign).is_err());
+        assert_eq!(s.client.reclaim(&s.campaign, &alice), 900);
+        assert_eq!(s.token.balance(&alice), 900);
+        assert!(s.client.try_reclaim(&s.campaign, &alice).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "campaign closed")]
+    fn test_no_pledges_after_deadline() {
+        let s = setup();
+        let alice = backer(&s, 100);
+        s.env.ledger().set_sequence_number(101);
+        s.client.pledge(&s.campaign, &alice, &100);
+    }
+
+    #[test]
+    fn test_campaigns_are_listed() {
+        let s = setup();
+        s.client
+            .create_campaign(&s.creator, &s.token.address, &50, &20);
+        assert_eq!(s.client.campaign_count(), 2);
+        let campaigns = s.client.campaigns(&1, &10);
+        assert_eq!(campaigns.len(), 1);
+        assert_eq!(campaigns.get(0).unwrap().goal, 50);
+    }
+}
@@ -0,0 +1,422 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env};
+
+const BPS_DENOMINATOR: i128 = 10_000;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    EscrowCount,
+    Escrow(u32),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum EscrowStatus {
+    /// Created, waiting for the payer's deposit.
+    AwaitingDeposit,
+    Funded,
+    /// Frozen until the arbiter resolves it.
+    Disputed,
+    /// Paid out in full or in part; nothing is held any more.
+    Closed,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Escrow {
+    pub id: u32,
+    pub payer: Address,
+    pub payee: Address,
+    pub arbiter: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Last ledger on which the payer can deposit.
+    pub deposit_deadline: u32,
+    /// Ledger from which an undisputed escrow can be released to the payee
+    /// by anyone.
+    pub release_ledger: u32,
+    pub status: EscrowStatus,
+    /// Amount the payee received when the escrow closed.
+    pub paid_to_payee: i128,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Escrow Contract — arbitrated payments for launchpad service agreements.
+///
+/// A payee, such as an auditor or marketing agency, `create`s an escrow
+/// naming the payer, an arbiter both sides trust, the amount and the
+/// ledgers involved. The payer funds it with `deposit` before the deposit
+/// deadline, which also accepts the terms.
+///
+/// Once funded, the payer can `release` the funds early, the payee can
+/// `refund` them, and either can `dispute`. Disputed funds stay put until
+/// the arbiter `resolve`s them with any release/refund split. An undisputed
+/// escrow pays the payee automatically: after `release_ledger` anyone can
+/// call `claim_timeout`.
+#[contract]
+pub struct EscrowContract;
+
+#[contractimpl]
+impl EscrowContract {
+    // ── Setup ───────────────────────────────────────────────────────────
+
+    /// Propose an escrow of `amount` of `token` from `payer` to `payee`.
+    /// Payee only. Returns the escrow id.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        env: Env,
+        payee: Address,
+        payer: Address,
+        arbiter: Address,
+        token: Address,
+        amount: i128,
+        deposit_deadline: u32,
+        release_ledger: u32,
+    ) -> u32 {
+        payee.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        assert!(payer != payee, "payer is payee");
+        assert!(
+            arbiter != payer && arbiter != payee,
+            "arbiter must be independent"
+        );
+        assert!(
+            deposit_deadline >= env.ledger().sequence(),
+            "deposit deadline passed"
+        );
+        assert!(
+            release_ledger > deposit_deadline,
+            "release must be after deposit deadline"
+        );
+
+        let id = Self::escrow_count(env.clone());
+        let escrow = Escrow {
+            id,
+            payer: payer.clone(),
+            payee: payee.clone(),
+            arbiter,
+            token,
+            amount,
+            deposit_deadline,
+            release_ledger,
+            status: EscrowStatus::AwaitingDeposit,
+            paid_to_payee: 0,
+        };
+        Self::_save(&env, &escrow);
+        env.storage()
+            .instance()
+            .set(&DataKey::EscrowCount, &(id + 1));
+
+        env.events()
+            .publish((symbol_short!("create"), payer, payee), (id, amount));
+        id
+    }
+
+    /// Fund escrow `escrow_id`, accepting its terms. Payer only, on or
+    /// before the deposit deadline.
+    pub fn deposit(env: Env, escrow_id: u32) {
+        let mut escrow = Self::get_escrow(env.clone(), escrow_id);
+        escrow.payer.require_auth();
+        assert!(
+            escrow.status == EscrowStatus::AwaitingDeposit,
+            "already funded"
+        );
+        assert!(
+            env.ledger().sequence() <= escrow.deposit_deadline,
+            "deposit deadline passed"
+        );
+
+        token::Client::new(&env, &escrow.token).transfer(
+            &escrow.payer,
+            &env.current_contract_address(),
+            &escrow.amount,
+        );
+        escrow.status = EscrowStatus::Funded;
+        Self::_save(&env, &escrow);
+
+        env.events()
+            .publish((symbol_short!("deposit"), escrow.payer), escrow_id);
+    }
+
+    // ── Settlement ──────────────────────────────────────────────────────
+
+    /// Pay the whole escrow to the payee. Payer only.
+    pub fn release(env: Env, escrow_id: u32) {
+        let escrow = Self::_funded(&env, escrow_id);
+        escrow.payer.require_auth();
+        let amount = escrow.amount;
+        Self::_close(&env, escrow, amount);
+    }
+
+    /// Return the whole escrow to the payer. Payee only.
+    pub fn refund(env: Env, escrow_id: u32) {
+        let escrow = Self::_funded(&env, escrow_id);
+        escrow.payee.require_auth();
+        Self::_close(&env, escrow, 0);
+    }
+
+    /// Freeze a funded escrow until the arbiter resolves it. Payer or
+    /// payee, before the release ledger.
+    pub fn dispute(env: Env, caller: Address, escrow_id: u32) {
+        caller.require_auth();
+        let mut escrow = Self::_funded(&env, escrow_id);
+        assert!(
+            caller == escrow.payer || caller == escrow.payee,
+            "not a party"
+        );
+        assert!(
+            env.ledger().sequence() < escrow.release_ledger,
+            "escrow timed out"
+        );
+        escrow.status = EscrowStatus::Disputed;
+        Self::_save(&env, &escrow);
+
+        env.events()
+            .publish((symbol_short!("dispute"), caller), escrow_id);
+    }
+
+    /// Settle a disputed escrow, paying `payee_bps` of it to the payee and
+    /// the rest back to the payer. Arbiter only.
+    pub fn resolve(env: Env, escrow_id: u32, payee_bps: u32) {
+        let escrow = Self::get_escrow(env.clone(), escrow_id);
+        escrow.arbiter.require_auth();
+        assert!(escrow.status == EscrowStatus::Disputed, "not disputed");
+        assert!(payee_bps as i128 <= BPS_DENOMINATOR, "split exceeds 100%");
+        let to_payee = escrow.amount * payee_bps as i128 / BPS_DENOMINATOR;
+        Self::_close(&env, escrow, to_payee);
+    }
+
+    /// Release an undisputed escrow to the payee once the release ledger
+    /// is reached. Anyone may call.
+    pub fn claim_timeout(env: Env, escrow_id: u32) {
+        let escrow = Self::_funded(&env, escrow_id);
+        assert!(
+            env.ledger().sequence() >= escrow.release_ledger,
+            "release ledger not reached"
+        );
+        let amount = escrow.amount;
+        Self::_close(&env, escrow, amount);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn get_escrow(env: Env, escrow_id: u32) -> Escrow {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Escrow(escrow_id))
+            .expect("no escrow found")
+    }
+
+    pub fn status(env: Env, escrow_id: u32) -> EscrowStatus {
+        Self::get_escrow(env, escrow_id).status
+    }
+
+    pub fn escrow_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::EscrowCount)
+            .unwrap_or(0)
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _funded(env: &Env, escrow_id: u32) -> Escrow {
+        let escrow = Self::get_escrow(env.clone(), escrow_id);
+        assert!(escrow.status == EscrowStatus::Funded, "escrow not funded");
+        escrow
+    }
+
+    fn _save(env: &Env, escrow: &Escrow) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(escrow.id), escrow);
+    }
+
+    /// Pay `to_payee` to the payee and the rest to the payer.
+    fn _close(env: &Env, mut escrow: Escrow, to_payee: i128) {
+        escrow.status = EscrowStatus::Closed;
+        escrow.paid_to_payee = to_payee;
+        Self::_save(env, &escrow);
+
+        let client = token::Client::new(env, &escrow.token);
+        let to_payer = escrow.amount - to_payee;
+        if to_payee > 0 {
+            client.transfer(&env.current_contract_address(), &escrow.payee, &to_payee);
+        }
+        if to_payer > 0 {
+            client.transfer(&env.current_contract_address(), &escrow.payer, &to_payer);
+        }
+
+        env.events()
+            .publish((symbol_short!("close"), escrow.id), (to_payee, to_payer));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{
+        testutils::Address as _, testutils::Ledger, testutils::MockAuth, testutils::MockAuthInvoke,
+        IntoVal,
+    };
+
+    struct Setup {
+        env: Env,
+        client: EscrowContractClient<'static>,
+        token: TokenFixture,
+        payer: Address,
+        payee: Address,
+        arbiter: Address,
+    }
+
+    /// The payer holds 10 000 tokens; the ledger starts at 10.
+    fn setup() -> Setup {
+        let env = test_env();
+        env.ledger().set_sequence_number(10);
+
+        let token = TokenFixture::stellar_asset(&env);
+        let payer = Address::generate(&env);
+        token.mint(&payer, 10_000);
+        let contract_id = env.register_contract(None, EscrowContract);
+
+        Setup {
+            client: EscrowContractClient::new(&env, &contract_id),
+            token,
+            payee: Address::generate(&env),
+            arbiter: Address::generate(&env),
+            env,
+            payer,
+        }
+    }
+
+    /// 1 000 tokens, deposit by ledger 50, auto-release at ledger 500.
+    fn create(s: &Setup) -> u32 {
+        s.client.create(
+            &s.payee,
+            &s.payer,
+            &s.arbiter,
+            &s.token.address,
+            &1_000,
+            &50,
+            &500,
+        )
+    }
+
+    fn funded(s: &Setup) -> u32 {
+        let id = create(s);
+        s.client.deposit(&id);
+        id
+    }
+
+    #[test]
+    fn test_deposit_and_release() {
+        let s = setup();
+        let id = create(&s);
+        assert_eq!(s.client.status(&id), EscrowStatus::AwaitingDeposit);
+        s.client.deposit(&id);
+        assert_eq!(s.client.status(&id), EscrowStatus::Funded);
+        assert_eq!(s.token.balance(&s.client.address), 1_000);
+
+        s.client.release(&id);
+        assert_eq!(s.token.balance(&s.payee), 1_000);
+        assert_eq!(s.client.get_escrow(&id).paid_to_payee, 1_000);
+        assert_eq!(s.client.status(&id), EscrowStatus::Closed);
+        assert!(s.client.try_release(&id).is_err());
+    }
+
+    #[test]
+    fn test_payee_refund() {
+        let s = setup();
+        let id = funded(&s);
+        s.client.refund(&id);
+        assert_eq!(s.token.balance(&s.payer), 10_000);
+        assert_eq!(s.token.balance(&s.payee), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "deposit deadline passed")]
+    fn test_late_deposit_panics() {
+        let s = setup();
+        let id = create(&s);
+        s.env.ledger().set_sequence_number(51);
+        s.client.deposit(&id);
+    }
+
+    #[test]
+    fn test_arbiter_splits_dispute() {
+        let s = setup();
+        let id = funded(&s);
+        s.client.dispute(&s.payer, &id);
+        assert_eq!(s.client.status(&id), EscrowStatus::Disputed);
+
+        // A disputed escrow does not time out
+        s.env.ledger().set_sequence_number(500);
+        assert!(s.client.try_claim_timeout(&id).is_err());
+
+        s.client.resolve(&id, &3_000);
+        assert_eq!(s.token.balance(&s.payee), 300);
+        assert_eq!(s.token.balance(&s.payer), 9_700);
+        assert_eq!(s.client.status(&id), EscrowStatus::Closed);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a party")]
+    fn test_outsider_cannot_dispute() {
+        let s = setup();
+        let id = funded(&s);
+        s.client.dispute(&s.arbiter, &id);
+    }
+
+    #[test]
+    #[should_panic(expected = "not disputed")]
+    fn test_resolve_requires_dispute() {
+        let s = setup();
+        let id = funded(&s);
+        s.client.resolve(&id, &5_000);
+    }
+
+    #[test]
+    fn test_auto_release_on_timeout() {
+        let s = setup();
+        let id = funded(&s);
+        s.env.ledger().set_sequence_number(499);
+        assert!(s.client.try_claim_timeout(&id).is_err());
+
+        s.env.ledger().set_sequence_number(500);
+        assert!(s.client.try_dispute(&s.payer, &id).is_err());
+        s.client.claim_timeout(&id);
+        assert_eq!(s.token.balance(&s.payee), 1_000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_only_arbiter_can_resolve() {
+        let s = setup();
+        let id = funded(&s);
+        s.client.dispute(&s.payee, &id);
+        s.env.mock_auths(&[MockAuth {
+            address: &s.payee,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "resolve",
+                args: (id, 10_000u32).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.resolve(&id, &10_000);
+    }
+}
@@ -0,0 +1,298 @@
+#![no_std]
+
+use launchpad_access as access;
+use launchpad_rate_limit::{self as rate_limit, RateLimit};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, token, xdr::ToXdr, Address, BytesN, Env,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Token,
+    DripAmount,
+    Window,
+    Attestor,
+    TotalDispensed,
+    /// Drips received by an address, as rate-limit usage.
+    Drips(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Faucet Contract — self-serve test funds for demos and integrators.
+///
+/// The faucet pays `drip_amount` of its test token to any address that asks,
+/// at most once per window of `window` ledgers. Windows are fixed: window
+/// `n` covers ledgers `[n * window, (n + 1) * window)`. Anyone can top the
+/// faucet up by transferring tokens to it.
+///
+/// To keep bots from draining it, the admin can set an attestor key (e.g. a
+/// captcha backend). Every drip then needs the attestor's ed25519 signature
+/// over `xdr((faucet, recipient, window_index))`, which is only good for that
+/// recipient in that window.
+#[contract]
+pub struct FaucetContract;
+
+#[contractimpl]
+impl FaucetContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the admin, the dispensed token, the amount paid per drip and the
+    /// window length in ledgers.
+    pub fn initialize(env: Env, admin: Address, token: Address, drip_amount: i128, window: u32) {
+        access::init_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::Token, &token);
+        Self::_set_drip(&env, drip_amount, window);
+
+        env.events()
+            .publish((symbol_short!("init"),), (admin, token));
+    }
+
+    // ── Drips ───────────────────────────────────────────────────────────
+
+    /// Pay `drip_amount` to `recipient` if it has not had a drip in the
+    /// current window. `attestation` is required once an attestor is set.
+    /// Anyone may submit; the tokens always go to `recipient`.
+    pub fn drip(env: Env, recipient: Address, attestation: Option<BytesN<64>>) -> i128 {
+        let window = Self::current_window(env.clone());
+        let key = DataKey::Drips(recipient.clone());
+        assert!(
+            rate_limit::try_consume(&env, &key, &Self::_limit(&env), 1),
+            "already dripped this window"
+        );
+
+        if let Some(attestor) = Self::attestor(env.clone()) {
+            let signature = attestation.expect("attestation required");
+            let message = (env.current_contract_address(), recipient.clone(), window).to_xdr(&env);
+            env.crypto().ed25519_verify(&attestor, &message, &signature);
+        }
+
+        let amount = Self::drip_amount(env.clone());
+        let token = token::Client::new(&env, &Self::token(env.clone()));
+        let this = env.current_contract_address();
+        assert!(token.balance(&this) >= amount, "faucet empty");
+
+        let total = Self::total_dispensed(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalDispensed, &(total + amount));
+        token.transfer(&this, &recipient, &amount);
+
+        env.events()
+            .publish((symbol_short!("drip"), recipient), (window, amount));
+        amount
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Change the drip amount and window length. Addresses that already
+    /// dripped keep their window index, so a new length can let them drip
+    /// again early. Admin only.
+    pub fn set_drip(env: Env, drip_amount: i128, window: u32) {
+        access::require_admin(&env);
+        Self::_set_drip(&env, drip_amount, window);
+    }
+
+    /// Require drips to carry a signature from `public_key`, or drop the
+    /// requirement with `None`. Admin only.
+    pub fn set_attestor(env: Env, public_key: Option<BytesN<32>>) {
+        access::require_admin(&env);
+        match &public_key {
+            Some(key) => env.storage().instance().set(&DataKey::Attestor, key),
+            None => env.storage().instance().remove(&DataKey::Attestor),
+        }
+        env.events()
+            .publish((symbol_short!("attestor"),), public_key);
+    }
+
+    /// Take `amount` of the faucet's tokens back out to `to`. Admin only.
+    pub fn withdraw(env: Env, to: Address, amount: i128) {
+        access::require_admin(&env);
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount,
+        );
+        env.events()
+            .publish((symbol_short!("withdraw"), to), amount);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Index of the window the current ledger falls in.
+    pub fn current_window(env: Env) -> u32 {
+        Self::_limit(&env).window_index(env.ledger().sequence())
+    }
+
+    /// Whether `recipient` can drip in the current window, attestation
+    /// aside.
+    pub fn can_drip(env: Env, recipient: Address) -> bool {
+        rate_limit::available(&env, &DataKey::Drips(recipient), &Self::_limit(&env)) > 0
+    }
+
+    /// First ledger of the next window.
+    pub fn next_window_ledger(env: Env) -> u32 {
+        Self::_limit(&env).next_window_ledger(env.ledger().sequence())
+    }
+
+    pub fn total_dispensed(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalDispensed)
+            .unwrap_or(0)
+    }
+
+    pub fn attestor(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::Attestor)
+    }
+
+    pub fn drip_amount(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::DripAmount)
+            .expect("not initialized")
+    }
+
+    pub fn window(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Window)
+            .expect("not initialized")
+    }
+
+    pub fn token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("not initialized")
+    }
+
+    pub fn admin(env: Env) -> Address {
+        access::admin(&env)
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    /// One drip per address per fixed window.
+    fn _limit(env: &Env) -> RateLimit {
+        RateLimit::fixed(1, Self::window(env.clone()))
+    }
+
+    fn _set_drip(env: &Env, drip_amount: i128, window: u32) {
+        assert!(drip_amount > 0, "drip amount must be positive");
+        assert!(window > 0, "window must be positive");
+        env.storage()
+            .instance()
+            .set(&DataKey::DripAmount, &drip_amount);
+        env.storage().instance().set(&DataKey::Window, &window);
+
+        env.events()
+            .publish((symbol_short!("drip_cfg"),), (drip_amount, window));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger};
+
+    struct Setup {
+        env: Env,
+        client: FaucetContractClient<'static>,
+        token: TokenFixture,
+    }
+
+    /// Drips of 100 per 1 000-ledger window; the faucet holds 250. The
+    /// ledger starts at 1 500, in window 1.
+    fn setup() -> Setup {
+        let env = test_env();
+        env.ledger().set_sequence_number(1_500);
+
+        let admin = Address::generate(&env);
+        let token = TokenFixture::stellar_asset(&env);
+        let contract_id = env.register_contract(None, FaucetContract);
+        let client = FaucetContractClient::new(&env, &contract_id);
+        client.initialize(&admin, &token.address, &100, &1_000);
+        token.mint(&contract_id, 250);
+
+        Setup { client, token, env }
+    }
+
+    fn attestor_key() -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&[9u8; 32])
+    }
+
+    fn attest(s: &Setup, recipient: &Address, window: u32) -> BytesN<64> {
+        use ed25519_dalek::Signer;
+        let message = (s.client.address.clone(), recipient.clone(), window).to_xdr(&s.env);
+        let signature = attestor_key().sign(&message.to_alloc_vec());
+        BytesN::from_array(&s.env, &signature.to_bytes())
+    }
+
+    #[test]
+    fn test_drip_once_per_window() {
+        let s = setup();
+        let user = Address::generate(&s.env);
+        assert_eq!(s.client.current_window(), 1);
+
+        s.client.drip(&user, &None);
+        assert_eq!(s.token.balance(&user), 100);
+        assert!(!s.client.can_drip(&user));
+        assert!(s.client.try_drip(&user, &None).is_err());
+
+        s.env
+            .ledger()
+            .set_sequence_number(s.client.next_window_ledger());
+        assert!(s.client.can_drip(&user));
+        s.client.drip(&user, &None);
+        assert_eq!(s.token.balance(&user), 200);
+        assert_eq!(s.client.total_dispensed(), 200);
+    }
+
+    #[test]
+    #[should_panic(expected = "faucet empty")]
+    fn test_empty_faucet() {
+        let s = setup();
+        for _ in 0..3 {
+            s.client.drip(&Address::generate(&s.env), &None);
+        }
+    }
+
+    #[test]
+    fn test_attested_drip() {
+        let s = setup();
+        let public_key = BytesN::from_array(&s.env, &attestor_key().verifying_key().to_bytes());
+        s.client.set_attestor(&Some(public_key));
+        let user = Address::generate(&s.env);
+        assert!(s.client.try_drip(&user, &None).is_err());
+
+        // A signature for another window does not work
+        let stale = attest(&s, &user, 0);
+        assert!(s.client.try_drip(&user, &Some(stale)).is_err());
+
+        s.client.drip(&user, &Some(attest(&s, &user, 1)));
+        assert_eq!(s.token.balance(&user), 100);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_attestation_is_bound_to_recipient() {
+        let s = setup();
+        let public_key = BytesN::from_array(&s.env, &attestor_key().verifying_key().to_bytes());
+        s.client.set_attestor(&Some(public_key));
+        let (user, other) = (Address::generate(&s.env), Address::generate(&s.env));
+        s.client.drip(&other, &Some(attest(&s, &user, 1)));
+    }
+}
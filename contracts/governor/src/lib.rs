@@ -0,0 +1,1535 @@
+#![no_std]
+
+use launchpad_events as events;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, symbol_short, token, xdr::ToXdr, Address,
+    BytesN, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+/// Denominator for all basis-point values.
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Token,
+    Timelock,
+    Params,
+    Council,
+    /// Last ledger on which the council can veto.
+    CouncilExpiry,
+    /// Parameters overriding the defaults for one kind of proposal.
+    TypeParams(Symbol),
+    ProposalCount,
+    Proposal(u32),
+    Actions(u32),
+    /// Settled state of a proposal, once queued.
+    State(u32),
+    /// Timelock operation of a queued proposal.
+    Operation(u32),
+    Receipt(u32, Address),
+    /// Next signed-vote nonce of a voter.
+    Nonce(Address),
+    /// Function of a contract emergency proposals may call.
+    EmergencyFunction(Address, Symbol),
+}
+
+/// A contract call made when a proposal is executed.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Action {
+    pub contract: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+}
+
+/// Rules proposals are opened and decided by.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct GovernanceParams {
+    /// Ledgers a proposal stays open for voting.
+    pub voting_period: u32,
+    /// Share of the supply that has to vote, abstentions included.
+    pub quorum_bps: u32,
+    /// Voting power needed to open a proposal.
+    pub proposal_threshold: i128,
+    /// Share of the for and against votes that has to be for. A tie at
+    /// exactly this share fails.
+    pub approval_bps: u32,
+    /// Tokens locked by the proposer while the proposal runs.
+    pub deposit: i128,
+    /// Share of the supply that has to vote for the deposit to be
+    /// refunded; below it the proposal counts as spam.
+    pub floor_bps: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Proposal {
+    pub id: u32,
+    pub proposer: Address,
+    /// Kind of proposal, selecting the parameters it runs under.
+    pub kind: Symbol,
+    pub description: String,
+    /// Ledger whose closing balances are the voting power, the one before
+    /// the proposal was created.
+    pub snapshot_ledger: u32,
+    pub start_ledger: u32,
+    /// Last ledger on which votes are accepted.
+    pub end_ledger: u32,
+    /// Votes needed, fixed from the supply at the snapshot ledger.
+    pub quorum: i128,
+    pub approval_bps: u32,
+    /// Deposit still held for the proposal; zero once settled.
+    pub deposit: i128,
+    /// Votes needed to get the deposit back.
+    pub floor: i128,
+    pub for_votes: i128,
+    pub against_votes: i128,
+    pub abstain_votes: i128,
+}
+
+/// Lifecycle of a proposal.
+///
+/// `Active`, `Defeated` and `Succeeded` follow from the ledger and the
+/// tally; `queue` and `veto` store the settled state, and a queued proposal
+/// is `Executed` once the timelock has run it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+pub enum ProposalState {
+    Active,
+    Defeated,
+    Succeeded,
+    Queued,
+    Executed,
+    /// Cancelled by the security council while queued.
+    Vetoed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+pub enum VoteType {
+    Against,
+    For,
+    Abstain,
+}
+
+/// How an account voted on a proposal and with what weight.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Receipt {
+    pub support: VoteType,
+    pub weight: i128,
+}
+
+/// Checkpointed voting power of the governance token.
+#[contractclient(name = "VotesClient")]
+pub trait Votes {
+    fn get_past_votes(env: Env, account: Address, ledger: u32) -> i128;
+    fn get_past_total_supply(env: Env, ledger: u32) -> i128;
+    fn delegates(env: Env, account: Address) -> Address;
+    fn delegated_votes(env: Env, account: Address) -> i128;
+}
+
+/// Executor that holds passed proposals for its delay.
+#[contractclient(name = "TimelockClient")]
+pub trait Timelock {
+    fn schedule(env: Env, actions: Vec<Action>) -> u32;
+    fn execute(env: Env, operation_id: u32);
+    fn is_done(env: Env, operation_id: u32) -> bool;
+    fn cancel(env: Env, operation_id: u32);
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Governor Contract — token-weighted on-chain governance.
+///
+/// Token holders with enough voting power `propose` a list of contract
+/// calls and vote on it for the voting period with `cast_vote`. A proposal
+/// that reached quorum and the approval threshold is `queue`d and then
+/// `execute`d by anyone. A vote weighs the voter's voting power as it stood
+/// before the proposal was created, read from the token's checkpoints, so
+/// tokens bought while a proposal is open cannot swing it. Voting power is
+/// the balance delegated to the voter on the token, its own included unless
+/// delegated away; re-delegating while a proposal is open does not change
+/// its weights.
+///
+/// Queued proposals are scheduled on the timelock and can only be executed
+/// once its delay has passed. Contracts handed over to governance make the
+/// timelock their admin, so no passed proposal takes effect instantly.
+///
+/// The voting period, quorum and thresholds can be overridden per kind of
+/// proposal, and only governance itself can change them: their setters
+/// need the timelock's authorization.
+///
+/// Opening a proposal locks a deposit from the proposer. Once voting ends,
+/// `settle_deposit` refunds it if turnout reached the participation floor,
+/// and otherwise slashes it to the timelock, which holds the treasury.
+///
+/// Holders of Stellar accounts can also vote without paying fees: they sign
+/// `xdr((governor, voter, proposal_id, support, nonce))` with their account
+/// key and any relayer submits it through `cast_vote_by_sig`.
+///
+/// Proposals of the `emergency` kind respond to incidents: they run under
+/// their own shorter voting window and higher approval threshold, and may
+/// only call functions whitelisted on specific contracts, such as `pause`
+/// on the token or `cancel` on a sale.
+///
+/// Governance can appoint a security council that may `veto` queued
+/// proposals while they wait in the timelock. The council cannot create or
+/// pass proposals, and its veto power ends on its own at the expiry ledger
+/// set with it.
+///
+/// The action builders (`transfer_fee_action`, `platform_fee_action`, ...)
+/// return ready-made actions for the workspace's governed setters, so
+/// frontends can assemble proposals without hand-encoding arguments.
+#[contract]
+pub struct GovernorContract;
+
+#[contractimpl]
+impl GovernorContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the governance token, the timelock executing passed proposals
+    /// and the default governance parameters. The governor must be the
+    /// timelock's admin.
+    pub fn initialize(env: Env, token: Address, timelock: Address, params: GovernanceParams) {
+        if env.storage().instance().has(&DataKey::Token) {
+            panic!("already initialized");
+        }
+        Self::_validate_params(&params);
+
+        env.storage().instance().set(&DataKey::Token, &token);
+        env.storage().instance().set(&DataKey::Timelock, &timelock);
+        env.storage().instance().set(&DataKey::Params, &params);
+        env.storage().instance().set(&DataKey::ProposalCount, &0u32);
+
+        env.events()
+            .publish((symbol_short!("init"),), (token, timelock));
+    }
+
+    // ── Proposals ───────────────────────────────────────────────────────
+
+    /// Open a proposal of kind `kind` to make `actions` and start its
+    /// voting period. The proposer needs the kind's proposal threshold in
+    /// voting power. Returns the proposal id.
+    pub fn propose(
+        env: Env,
+        proposer: Address,
+        kind: Symbol,
+        actions: Vec<Action>,
+        description: String,
+    ) -> u32 {
+        proposer.require_auth();
+        assert!(!actions.is_empty(), "no actions");
+
+        if kind == Self::_emergency() {
+            assert!(
+                env.storage()
+                    .persistent()
+                    .has(&DataKey::TypeParams(kind.clone())),
+                "emergency class not configured"
+            );
+            for action in actions.iter() {
+                assert!(
+                    Self::is_emergency_function(env.clone(), action.contract, action.function),
+                    "action not allowed in emergency"
+                );
+            }
+        }
+
+        let params = Self::type_params(env.clone(), kind.clone());
+        let start_ledger = env.ledger().sequence();
+        let snapshot_ledger = start_ledger - 1;
+        let votes = VotesClient::new(&env, &Self::token(env.clone()));
+        assert!(
+            votes.get_past_votes(&proposer, &snapshot_ledger) >= params.proposal_threshold,
+            "votes below proposal threshold"
+        );
+        let supply = votes.get_past_total_supply(&snapshot_ledger);
+
+        if params.deposit > 0 {
+            token::Client::new(&env, &Self::token(env.clone())).transfer(
+                &proposer,
+                &env.current_contract_address(),
+                &params.deposit,
+            );
+        }
+
+        let id = Self::proposal_count(env.clone());
+        let proposal = Proposal {
+            id,
+            proposer: proposer.clone(),
+            kind,
+            description,
+            snapshot_ledger,
+            start_ledger,
+            end_ledger: start_ledger + params.voting_period,
+            quorum: supply * params.quorum_bps as i128 / BPS_DENOMINATOR,
+            approval_bps: params.approval_bps,
+            deposit: params.deposit,
+            floor: supply * params.floor_bps as i128 / BPS_DENOMINATOR,
+            for_votes: 0,
+            against_votes: 0,
+            abstain_votes: 0,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(id), &proposal);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Actions(id), &actions);
+        env.storage()
+            .instance()
+            .set(&DataKey::ProposalCount, &(id + 1));
+
+        events::proposal_created(
+            &env,
+            &proposer,
+            events::ProposalCreated {
+                proposal_id: id,
+                snapshot_ledger: proposal.snapshot_ledger,
+                end_ledger: proposal.end_ledger,
+            },
+        );
+        id
+    }
+
+    /// Vote on an active proposal with the voter's voting power at its
+    /// snapshot ledger. Returns the weight counted.
+    pub fn cast_vote(env: Env, voter: Address, proposal_id: u32, support: VoteType) -> i128 {
+        voter.require_auth();
+        Self::_cast_vote(&env, voter, proposal_id, support)
+    }
+
+    /// Vote on behalf of `voter`, a Stellar account, with its ed25519
+    /// `signature` over `xdr((this contract, voter, proposal_id, support,
+    /// nonce))`. `nonce` must be the voter's next nonce, so every signature
+    /// is used once. Anyone may submit. Returns the weight counted.
+    pub fn cast_vote_by_sig(
+        env: Env,
+        voter: Address,
+        proposal_id: u32,
+        support: VoteType,
+        signature: BytesN<64>,
+        nonce: u32,
+    ) -> i128 {
+        assert!(
+            nonce == Self::nonce(env.clone(), voter.clone()),
+            "invalid nonce"
+        );
+        let message = (
+            env.current_contract_address(),
+            voter.clone(),
+            proposal_id,
+            support,
+            nonce,
+        )
+            .to_xdr(&env);
+        env.crypto()
+            .ed25519_verify(&Self::_account_key(&env, &voter), &message, &signature);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Nonce(voter.clone()), &(nonce + 1));
+
+        Self::_cast_vote(&env, voter, proposal_id, support)
+    }
+
+    fn _cast_vote(env: &Env, voter: Address, proposal_id: u32, support: VoteType) -> i128 {
+        assert!(
+            Self::state(env.clone(), proposal_id) == ProposalState::Active,
+            "voting closed"
+        );
+        let key = DataKey::Receipt(proposal_id, voter.clone());
+        assert!(!env.storage().persistent().has(&key), "already voted");
+
+        let mut proposal = Self::get_proposal(env.clone(), proposal_id);
+        let weight = Self::voting_power(env.clone(), proposal_id, voter.clone());
+        assert!(weight > 0, "no voting power");
+
+        match support {
+            VoteType::Against => proposal.against_votes += weight,
+            VoteType::For => proposal.for_votes += weight,
+            VoteType::Abstain => proposal.abstain_votes += weight,
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(proposal_id), &proposal);
+        env.storage()
+            .persistent()
+            .set(&key, &Receipt { support, weight });
+
+        events::vote_cast(
+            env,
+            &voter,
+            events::VoteCast {
+                proposal_id,
+                support: support as u32,
+                weight,
+            },
+        );
+        weight
+    }
+
+    /// Refund the deposit of a proposal whose voting has ended to its
+    /// proposer if turnout reached the participation floor, or slash it to
+    /// the timelock otherwise. Anyone may call. Returns whether it was
+    /// refunded.
+    pub fn settle_deposit(env: Env, proposal_id: u32) -> bool {
+        assert!(
+            Self::state(env.clone(), proposal_id) != ProposalState::Active,
+            "voting not over"
+        );
+        let mut proposal = Self::get_proposal(env.clone(), proposal_id);
+        let deposit = proposal.deposit;
+        assert!(deposit > 0, "no deposit held");
+        proposal.deposit = 0;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(proposal_id), &proposal);
+
+        let turnout = proposal.for_votes + proposal.against_votes + proposal.abstain_votes;
+        let refunded = turnout >= proposal.floor;
+        let recipient = if refunded {
+            proposal.proposer
+        } else {
+            Self::timelock(env.clone())
+        };
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &env.current_contract_address(),
+            &recipient,
+            &deposit,
+        );
+
+        env.events().publish(
+            (symbol_short!("deposit"), recipient),
+            (proposal_id, deposit, refunded),
+        );
+        refunded
+    }
+
+    /// Schedule a succeeded proposal on the timelock. Anyone may call.
+    pub fn queue(env: Env, proposal_id: u32) {
+        assert!(
+            Self::state(env.clone(), proposal_id) == ProposalState::Succeeded,
+            "proposal not succeeded"
+        );
+        let operation_id = TimelockClient::new(&env, &Self::timelock(env.clone()))
+            .schedule(&Self::get_actions(env.clone(), proposal_id));
+        env.storage()
+            .persistent()
+            .set(&DataKey::State(proposal_id), &ProposalState::Queued);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Operation(proposal_id), &operation_id);
+
+        events::proposal_queued(
+            &env,
+            events::ProposalQueued {
+                proposal_id,
+                operation_id,
+            },
+        );
+    }
+
+    /// Have the timelock make the calls of a queued proposal once its delay
+    /// has passed. Anyone may call. Proposals calling the governor itself
+    /// must be executed on the timelock directly.
+    pub fn execute(env: Env, proposal_id: u32) {
+        assert!(
+            Self::state(env.clone(), proposal_id) == ProposalState::Queued,
+            "proposal not queued"
+        );
+        TimelockClient::new(&env, &Self::timelock(env.clone()))
+            .execute(&Self::operation_id(env.clone(), proposal_id));
+
+        events::proposal_executed(&env, proposal_id);
+    }
+
+    /// Cancel a queued proposal before the timelock runs it. Council only,
+    /// until the council's expiry ledger.
+    pub fn veto(env: Env, proposal_id: u32) {
+        let council = Self::council(env.clone()).expect("no active council");
+        council.require_auth();
+        assert!(
+            Self::state(env.clone(), proposal_id) == ProposalState::Queued,
+            "proposal not queued"
+        );
+        TimelockClient::new(&env, &Self::timelock(env.clone()))
+            .cancel(&Self::operation_id(env.clone(), proposal_id));
+        env.storage()
+            .persistent()
+            .set(&DataKey::State(proposal_id), &ProposalState::Vetoed);
+
+        env.events()
+            .publish((symbol_short!("veto"), council), proposal_id);
+    }
+
+    // ── Governance settings ─────────────────────────────────────────────
+
+    /// Replace the default governance parameters. Governance only.
+    pub fn set_params(env: Env, params: GovernanceParams) {
+        Self::_require_governance(&env);
+        Self::_validate_params(&params);
+        env.storage().instance().set(&DataKey::Params, &params);
+        env.events().publish((symbol_short!("params"),), params);
+    }
+
+    /// Run proposals of kind `kind` under `params` instead of the defaults.
+    /// Emergency parameters must vote faster and demand a higher approval
+    /// than the defaults. Governance only.
+    pub fn set_type_params(env: Env, kind: Symbol, params: GovernanceParams) {
+        Self::_require_governance(&env);
+        Self::_validate_params(&params);
+        if kind == Self::_emergency() {
+            let defaults = Self::params(env.clone());
+            assert!(
+                params.voting_period < defaults.voting_period,
+                "emergency voting_period must be shorter"
+            );
+            assert!(
+                params.approval_bps > defaults.approval_bps,
+                "emergency approval_bps must be higher"
+            );
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::TypeParams(kind.clone()), &params);
+        env.events()
+            .publish((symbol_short!("typeparam"), kind), params);
+    }
+
+    /// Allow or disallow emergency proposals to call `function` on
+    /// `contract`, and on no other contract. Governance only.
+    pub fn set_emergency_function(env: Env, contract: Address, function: Symbol, allowed: bool) {
+        Self::_require_governance(&env);
+        let key = DataKey::EmergencyFunction(contract.clone(), function.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("emerg_fn"), contract, function), allowed);
+    }
+
+    /// Let `council` veto queued proposals until `expiry_ledger`, replacing
+    /// any previous council. Governance only.
+    pub fn set_council(env: Env, council: Address, expiry_ledger: u32) {
+        Self::_require_governance(&env);
+        assert!(
+            expiry_ledger > env.ledger().sequence(),
+            "expiry must be in the future"
+        );
+        env.storage().instance().set(&DataKey::Council, &council);
+        env.storage()
+            .instance()
+            .set(&DataKey::CouncilExpiry, &expiry_ledger);
+        env.events()
+            .publish((symbol_short!("council"), council), expiry_ledger);
+    }
+
+    /// Drop the override for proposals of kind `kind`. Governance only.
+    pub fn remove_type_params(env: Env, kind: Symbol) {
+        Self::_require_governance(&env);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::TypeParams(kind.clone()));
+        env.events().publish((symbol_short!("typeparam"), kind), ());
+    }
+
+    // ── Action builders ─────────────────────────────────────────────────
+
+    /// Action making the token charge `fee_bps` of every transfer to
+    /// `recipient`.
+    pub fn transfer_fee_action(
+        env: Env,
+        token: Address,
+        fee_bps: u32,
+        recipient: Address,
+    ) -> Action {
+        Self::_action(
+            &env,
+            token,
+            "set_transfer_fee",
+            (fee_bps, recipient).into_val(&env),
+        )
+    }
+
+    /// Action exempting `account`, typically a platform contract escrowing
+    /// the token, from its transfer fee, or charging it again.
+    pub fn fee_exempt_action(env: Env, token: Address, account: Address, exempt: bool) -> Action {
+        Self::_action(
+            &env,
+            token,
+            "set_fee_exempt",
+            (account, exempt).into_val(&env),
+        )
+    }
+
+    /// Action lowering the token's supply cap to `max_supply`.
+    pub fn max_supply_action(env: Env, token: Address, max_supply: i128) -> Action {
+        Self::_action(
+            &env,
+            token,
+            "reduce_max_supply",
+            (max_supply,).into_val(&env),
+        )
+    }
+
+    /// Action handing the admin role of `token` to `new_admin`, who then has
+    /// to accept it.
+    pub fn propose_admin_action(env: Env, token: Address, new_admin: Address) -> Action {
+        Self::_action(&env, token, "propose_admin", (new_admin,).into_val(&env))
+    }
+
+    /// Action accepting the admin role of `token` offered to the timelock.
+    pub fn accept_admin_action(env: Env, token: Address) -> Action {
+        Self::_action(&env, token, "accept_admin", Vec::new(&env))
+    }
+
+    /// Action setting the sale factory's platform fee for future sales.
+    pub fn platform_fee_action(
+        env: Env,
+        factory: Address,
+        fee_bps: u32,
+        fee_recipient: Address,
+    ) -> Action {
+        Self::_action(
+            &env,
+            factory,
+            "set_platform_fee",
+            (fee_bps, fee_recipient).into_val(&env),
+        )
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn state(env: Env, proposal_id: u32) -> ProposalState {
+        let proposal = Self::get_proposal(env.clone(), proposal_id);
+        if let Some(state) = env.storage().persistent().get(&DataKey::State(proposal_id)) {
+            if state == ProposalState::Vetoed {
+                return state;
+            }
+            let operation_id = Self::operation_id(env.clone(), proposal_id);
+            return if TimelockClient::new(&env, &Self::timelock(env.clone())).is_done(&operation_id)
+            {
+                ProposalState::Executed
+            } else {
+                state
+            };
+        }
+        let turnout = proposal.for_votes + proposal.against_votes + proposal.abstain_votes;
+        let decided = proposal.for_votes + proposal.against_votes;
+        if env.ledger().sequence() <= proposal.end_ledger {
+            ProposalState::Active
+        } else if turnout >= proposal.quorum
+            && proposal.for_votes * BPS_DENOMINATOR > decided * proposal.approval_bps as i128
+        {
+            ProposalState::Succeeded
+        } else {
+            ProposalState::Defeated
+        }
+    }
+
+    pub fn get_proposal(env: Env, proposal_id: u32) -> Proposal {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+            .expect("no proposal found")
+    }
+
+    pub fn get_actions(env: Env, proposal_id: u32) -> Vec<Action> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Actions(proposal_id))
+            .expect("no proposal found")
+    }
+
+    /// Weight `account` votes with on proposal `proposal_id`.
+    pub fn voting_power(env: Env, proposal_id: u32, account: Address) -> i128 {
+        let proposal = Self::get_proposal(env.clone(), proposal_id);
+        VotesClient::new(&env, &Self::token(env.clone()))
+            .get_past_votes(&account, &proposal.snapshot_ledger)
+    }
+
+    /// Address `account` delegates its voting power to on the token.
+    pub fn delegates(env: Env, account: Address) -> Address {
+        VotesClient::new(&env, &Self::token(env.clone())).delegates(&account)
+    }
+
+    /// Voting power `account` would bring to a proposal opened now.
+    pub fn delegated_votes(env: Env, account: Address) -> i128 {
+        VotesClient::new(&env, &Self::token(env.clone())).delegated_votes(&account)
+    }
+
+    pub fn is_emergency_function(env: Env, contract: Address, function: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::EmergencyFunction(contract, function))
+    }
+
+    /// Nonce the next signed vote of `voter` must carry.
+    pub fn nonce(env: Env, voter: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Nonce(voter))
+            .unwrap_or(0)
+    }
+
+    /// How `voter` voted on proposal `proposal_id`.
+    pub fn get_receipt(env: Env, proposal_id: u32, voter: Address) -> Receipt {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Receipt(proposal_id, voter))
+            .expect("no vote found")
+    }
+
+    pub fn has_voted(env: Env, proposal_id: u32, voter: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Receipt(proposal_id, voter))
+    }
+
+    /// Timelock operation a queued proposal was scheduled as.
+    pub fn operation_id(env: Env, proposal_id: u32) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Operation(proposal_id))
+            .expect("proposal not queued")
+    }
+
+    pub fn proposal_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProposalCount)
+            .expect("not initialized")
+    }
+
+    /// Security council able to veto, unless none was appointed or its
+    /// veto power has expired.
+    pub fn council(env: Env) -> Option<Address> {
+        if env.ledger().sequence() > Self::council_expiry(env.clone()) {
+            return None;
+        }
+        env.storage().instance().get(&DataKey::Council)
+    }
+
+    /// Last ledger on which the council can veto.
+    pub fn council_expiry(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CouncilExpiry)
+            .unwrap_or(0)
+    }
+
+    /// Default governance parameters.
+    pub fn params(env: Env) -> GovernanceParams {
+        env.storage()
+            .instance()
+            .get(&DataKey::Params)
+            .expect("not initialized")
+    }
+
+    /// Parameters proposals of kind `kind` run under.
+    pub fn type_params(env: Env, kind: Symbol) -> GovernanceParams {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TypeParams(kind))
+            .unwrap_or_else(|| Self::params(env.clone()))
+    }
+
+    pub fn token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("not initialized")
+    }
+
+    pub fn timelock(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Timelock)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    /// Passed proposals reach the governor through the timelock.
+    fn _require_governance(env: &Env) {
+        Self::timelock(env.clone()).require_auth();
+    }
+
+    fn _emergency() -> Symbol {
+        symbol_short!("emergency")
+    }
+
+    /// The ed25519 public key behind a Stellar account address.
+    fn _account_key(env: &Env, account: &Address) -> BytesN<32> {
+        // ScVal::Address(ScAddress::Account(PublicKey::Ed25519(key))): three
+        // 4-byte tags, the middle one 0 for accounts, then the key
+        let xdr = account.clone().to_xdr(env);
+        assert!(
+            xdr.len() == 44 && xdr.get(7) == Some(0),
+            "voter is not an account"
+        );
+        xdr.slice(12..44).try_into().unwrap()
+    }
+
+    fn _action(env: &Env, contract: Address, function: &str, args: Vec<Val>) -> Action {
+        Action {
+            contract,
+            function: Symbol::new(env, function),
+            args,
+        }
+    }
+
+    fn _validate_params(params: &GovernanceParams) {
+        assert!(params.voting_period > 0, "voting_period must be positive");
+        assert!(
+            params.quorum_bps as i128 <= BPS_DENOMINATOR,
+            "quorum_bps exceeds 100%"
+        );
+        assert!(
+            params.approval_bps as i128 <= BPS_DENOMINATOR,
+            "approval_bps exceeds 100%"
+        );
+        assert!(
+            params.proposal_threshold >= 0,
+            "proposal_threshold must not be negative"
+        );
+        assert!(params.deposit >= 0, "deposit must not be negative");
+        assert!(
+            params.floor_bps as i128 <= BPS_DENOMINATOR,
+            "floor_bps exceeds 100%"
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        testutils::Address as _, testutils::Ledger, testutils::MockAuth, testutils::MockAuthInvoke,
+        vec, Bytes, IntoVal,
+    };
+
+    /// Voting token whose balances are set directly and checkpointed per
+    /// ledger.
+    #[contract]
+    struct MockToken;
+
+    #[contractimpl]
+    impl MockToken {
+        pub fn set_balance(env: Env, id: Address, amount: i128) {
+            let mut checkpoints: Vec<(u32, i128)> = env
+                .storage()
+                .persistent()
+                .get(&id)
+                .unwrap_or(Vec::new(&env));
+            checkpoints.push_back((env.ledger().sequence(), amount));
+            env.storage().persistent().set(&id, &checkpoints);
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            Self::get_past_votes(env, id, u32::MAX)
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            from.require_auth();
+            let from_balance = Self::balance(env.clone(), from.clone());
+            let to_balance = Self::balance(env.clone(), to.clone());
+            Self::set_balance(env.clone(), from, from_balance - amount);
+            Self::set_balance(env, to, to_balance + amount);
+        }
+
+        pub fn get_past_votes(env: Env, account: Address, ledger: u32) -> i128 {
+            let checkpoints: Vec<(u32, i128)> = env
+                .storage()
+                .persistent()
+                .get(&account)
+                .unwrap_or(Vec::new(&env));
+            checkpoints
+                .iter()
+                .filter(|(at, _)| *at <= ledger)
+                .last()
+                .map(|(_, amount)| amount)
+                .unwrap_or(0)
+        }
+
+        pub fn get_past_total_supply(_env: Env, _ledger: u32) -> i128 {
+            1_000
+        }
+
+        pub fn delegates(_env: Env, account: Address) -> Address {
+            account
+        }
+
+        pub fn delegated_votes(env: Env, account: Address) -> i128 {
+            Self::get_past_votes(env, account, u32::MAX)
+        }
+    }
+
+    /// Runs scheduled actions on request, without a delay.
+    #[contract]
+    struct MockTimelock;
+
+    #[contractimpl]
+    impl MockTimelock {
+        pub fn schedule(env: Env, actions: Vec<Action>) -> u32 {
+            let id: u32 = env.storage().instance().get(&0u32).unwrap_or(0);
+            env.storage().instance().set(&0u32, &(id + 1));
+            env.storage().persistent().set(&id, &actions);
+            id
+        }
+
+        pub fn execute(env: Env, operation_id: u32) {
+            let actions: Vec<Action> = env.storage().persistent().get(&operation_id).unwrap();
+            for action in actions.iter() {
+                env.invoke_contract::<Val>(&action.contract, &action.function, action.args);
+            }
+            env.storage().temporary().set(&operation_id, &true);
+        }
+
+        pub fn is_done(env: Env, operation_id: u32) -> bool {
+            env.storage().temporary().has(&operation_id)
+        }
+
+        pub fn cancel(env: Env, operation_id: u32) {
+            env.storage().persistent().remove(&operation_id);
+        }
+    }
+
+    /// Stands in for a governed contract.
+    #[contract]
+    struct MockTarget;
+
+    #[contractimpl]
+    impl MockTarget {
+        pub fn set_value(env: Env, value: u32) {
+            env.storage().instance().set(&0u32, &value);
+        }
+
+        pub fn pause(env: Env) {
+            env.storage().instance().set(&3u32, &true);
+        }
+
+        pub fn is_paused(env: Env) -> bool {
+            env.storage().instance().has(&3u32)
+        }
+
+        pub fn value(env: Env) -> u32 {
+            env.storage().instance().get(&0u32).unwrap_or(0)
+        }
+
+        pub fn set_transfer_fee(env: Env, fee_bps: u32, recipient: Address) {
+            env.storage().instance().set(&1u32, &(fee_bps, recipient));
+        }
+
+        pub fn set_platform_fee(env: Env, fee_bps: u32, fee_recipient: Address) {
+            env.storage()
+                .instance()
+                .set(&2u32, &(fee_bps, fee_recipient));
+        }
+
+        pub fn fee(env: Env, kind: u32) -> (u32, Address) {
+            env.storage().instance().get(&kind).unwrap()
+        }
+    }
+
+    struct Setup {
+        env: Env,
+        client: GovernorContractClient<'static>,
+        token: MockTokenClient<'static>,
+        target: MockTargetClient<'static>,
+        alice: Address,
+        bob: Address,
+    }
+
+    /// Alice holds 600 votes and Bob 400 from ledger 10, out of a supply of
+    /// 1 000. Proposals run for 100 ledgers, need 100 votes to open, a 20%
+    /// quorum and a simple majority. Setup leaves the ledger at 11.
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_sequence_number(10);
+
+        let token = MockTokenClient::new(&env, &env.register_contract(None, MockToken));
+        let target = MockTargetClient::new(&env, &env.register_contract(None, MockTarget));
+        let contract_id = env.register_contract(None, GovernorContract);
+        let client = GovernorContractClient::new(&env, &contract_id);
+        let timelock = env.register_contract(None, MockTimelock);
+        client.initialize(&token.address, &timelock, &params());
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        token.set_balance(&alice, &600);
+        token.set_balance(&bob, &400);
+        env.ledger().set_sequence_number(11);
+
+        Setup {
+            env,
+            client,
+            token,
+            target,
+            alice,
+            bob,
+        }
+    }
+
+    fn params() -> GovernanceParams {
+        GovernanceParams {
+            voting_period: 100,
+            quorum_bps: 2_000,
+            proposal_threshold: 100,
+            approval_bps: 5_000,
+            deposit: 0,
+            floor_bps: 0,
+        }
+    }
+
+    fn propose(s: &Setup) -> u32 {
+        propose_kind(s, symbol_short!("standard"))
+    }
+
+    fn set_value(s: &Setup) -> Action {
+        Action {
+            contract: s.target.address.clone(),
+            function: Symbol::new(&s.env, "set_value"),
+            args: vec![&s.env, 7u32.into_val(&s.env)],
+        }
+    }
+
+    fn propose_kind(s: &Setup, kind: Symbol) -> u32 {
+        s.client.propose(
+            &s.alice,
+            &kind,
+            &vec![&s.env, set_value(s)],
+            &String::from_str(&s.env, "Set value to 7"),
+        )
+    }
+
+    #[test]
+    fn test_passed_proposal_executes() {
+        let s = setup();
+        let id = propose(&s);
+        assert_eq!(s.client.state(&id), ProposalState::Active);
+
+        assert_eq!(s.client.cast_vote(&s.alice, &id, &VoteType::For), 600);
+        s.client.cast_vote(&s.bob, &id, &VoteType::Against);
+        assert_eq!(
+            s.client.get_receipt(&id, &s.bob),
+            Receipt {
+                support: VoteType::Against,
+                weight: 400
+            }
+        );
+
+        s.env.ledger().set_sequence_number(112);
+        assert_eq!(s.client.state(&id), ProposalState::Succeeded);
+        s.client.queue(&id);
+        assert_eq!(s.client.state(&id), ProposalState::Queued);
+        s.client.execute(&id);
+        assert_eq!(s.target.value(), 7);
+        assert_eq!(s.client.state(&id), ProposalState::Executed);
+    }
+
+    #[test]
+    fn test_rejected_proposal_is_defeated() {
+        let s = setup();
+        let id = propose(&s);
+        s.client.cast_vote(&s.alice, &id, &VoteType::Against);
+        s.client.cast_vote(&s.bob, &id, &VoteType::For);
+
+        s.env.ledger().set_sequence_number(112);
+        assert_eq!(s.client.state(&id), ProposalState::Defeated);
+        assert!(s.client.try_queue(&id).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "already voted")]
+    fn test_double_vote_panics() {
+        let s = setup();
+        let id = propose(&s);
+        s.client.cast_vote(&s.alice, &id, &VoteType::For);
+        s.client.cast_vote(&s.alice, &id, &VoteType::Abstain);
+    }
+
+    #[test]
+    #[should_panic(expected = "voting closed")]
+    fn test_vote_after_period_panics() {
+        let s = setup();
+        let id = propose(&s);
+        s.env.ledger().set_sequence_number(112);
+        s.client.cast_vote(&s.alice, &id, &VoteType::For);
+    }
+
+    #[test]
+    #[should_panic(expected = "proposal not queued")]
+    fn test_execute_without_queue_panics() {
+        let s = setup();
+        let id = propose(&s);
+        s.client.cast_vote(&s.alice, &id, &VoteType::For);
+        s.env.ledger().set_sequence_number(112);
+        s.client.execute(&id);
+    }
+
+    // ── Snapshot voting power ───────────────────────────────────────────
+
+    #[test]
+    fn test_transfer_after_proposal_keeps_weight() {
+        let s = setup();
+        let id = propose(&s);
+        s.token.set_balance(&s.alice, &0);
+        s.token.set_balance(&s.bob, &1_000);
+
+        assert_eq!(s.client.cast_vote(&s.alice, &id, &VoteType::For), 600);
+        assert_eq!(s.client.cast_vote(&s.bob, &id, &VoteType::Against), 400);
+    }
+
+    #[test]
+    fn test_transfer_in_proposal_ledger_not_counted() {
+        let s = setup();
+        let buyer = Address::generate(&s.env);
+        s.token.set_balance(&buyer, &5_000);
+        let id = propose(&s);
+
+        assert_eq!(s.client.voting_power(&id, &buyer), 0);
+        assert!(s
+            .client
+            .try_cast_vote(&buyer, &id, &VoteType::Against)
+            .is_err());
+    }
+
+    #[test]
+    fn test_delegation_getters_read_token() {
+        let s = setup();
+        s.token.set_balance(&s.alice, &700);
+        assert_eq!(s.client.delegates(&s.alice), s.alice);
+        assert_eq!(s.client.delegated_votes(&s.alice), 700);
+    }
+
+    #[test]
+    #[should_panic(expected = "no voting power")]
+    fn test_tokens_bought_while_open_cannot_vote() {
+        let s = setup();
+        let id = propose(&s);
+        let buyer = Address::generate(&s.env);
+        s.env.ledger().set_sequence_number(20);
+        s.token.set_balance(&buyer, &5_000);
+        s.client.cast_vote(&buyer, &id, &VoteType::Against);
+    }
+
+    // ── Quorum and thresholds ───────────────────────────────────────────
+
+    #[test]
+    fn test_proposal_below_quorum_is_defeated() {
+        let s = setup();
+        let carol = Address::generate(&s.env);
+        s.token.set_balance(&carol, &150);
+        s.env.ledger().set_sequence_number(12);
+        let id = propose(&s);
+        s.client.cast_vote(&carol, &id, &VoteType::For);
+
+        s.env.ledger().set_sequence_number(113);
+        assert_eq!(s.client.get_proposal(&id).quorum, 200);
+        assert_eq!(s.client.state(&id), ProposalState::Defeated);
+    }
+
+    #[test]
+    fn test_abstentions_count_toward_quorum() {
+        let s = setup();
+        let carol = Address::generate(&s.env);
+        s.token.set_balance(&carol, &150);
+        s.env.ledger().set_sequence_number(12);
+        let id = propose(&s);
+        s.client.cast_vote(&carol, &id, &VoteType::For);
+        s.client.cast_vote(&s.bob, &id, &VoteType::Abstain);
+
+        s.env.ledger().set_sequence_number(113);
+        assert_eq!(s.client.state(&id), ProposalState::Succeeded);
+    }
+
+    #[test]
+    #[should_panic(expected = "votes below proposal threshold")]
+    fn test_propose_below_threshold_panics() {
+        let s = setup();
+        s.client.propose(
+            &Address::generate(&s.env),
+            &symbol_short!("standard"),
+            &vec![&s.env, set_value(&s)],
+            &String::from_str(&s.env, "Spam"),
+        );
+    }
+
+    #[test]
+    fn test_type_params_override_defaults() {
+        let s = setup();
+        let kind = symbol_short!("treasury");
+        s.client.set_type_params(
+            &kind,
+            &GovernanceParams {
+                approval_bps: 6_667,
+                ..params()
+            },
+        );
+        assert_eq!(s.client.type_params(&kind).approval_bps, 6_667);
+        assert_eq!(s.client.type_params(&symbol_short!("standard")), params());
+
+        // 60% for clears the default majority but not the treasury bar
+        let treasury = propose_kind(&s, kind.clone());
+        let standard = propose(&s);
+        for id in [treasury, standard] {
+            s.client.cast_vote(&s.alice, &id, &VoteType::For);
+            s.client.cast_vote(&s.bob, &id, &VoteType::Against);
+        }
+        s.env.ledger().set_sequence_number(112);
+        assert_eq!(s.client.state(&treasury), ProposalState::Defeated);
+        assert_eq!(s.client.state(&standard), ProposalState::Succeeded);
+
+        s.client.remove_type_params(&kind);
+        assert_eq!(s.client.type_params(&kind), params());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_params_only_settable_by_governance() {
+        let s = setup();
+        let new_params = GovernanceParams {
+            quorum_bps: 0,
+            ..params()
+        };
+        s.env.mock_auths(&[MockAuth {
+            address: &s.alice,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "set_params",
+                args: (new_params.clone(),).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.set_params(&new_params);
+    }
+
+    // ── Action builders ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_built_actions_execute() {
+        let s = setup();
+        let treasury = Address::generate(&s.env);
+        let target = s.target.address.clone();
+        let actions = vec![
+            &s.env,
+            s.client.transfer_fee_action(&target, &100, &treasury),
+            s.client.platform_fee_action(&target, &300, &treasury),
+        ];
+        let id = s.client.propose(
+            &s.alice,
+            &symbol_short!("standard"),
+            &actions,
+            &String::from_str(&s.env, "Fees"),
+        );
+        s.client.cast_vote(&s.alice, &id, &VoteType::For);
+        s.env.ledger().set_sequence_number(112);
+        s.client.queue(&id);
+        s.client.execute(&id);
+
+        assert_eq!(s.target.fee(&1), (100, treasury.clone()));
+        assert_eq!(s.target.fee(&2), (300, treasury));
+    }
+
+    #[test]
+    fn test_admin_handover_actions() {
+        let s = setup();
+        let token = Address::generate(&s.env);
+        let propose = s.client.propose_admin_action(&token, &s.alice);
+        assert_eq!(propose.function, Symbol::new(&s.env, "propose_admin"));
+        assert_eq!(propose.args, vec![&s.env, s.alice.into_val(&s.env)]);
+
+        let accept = s.client.accept_admin_action(&token);
+        assert_eq!(accept.function, Symbol::new(&s.env, "accept_admin"));
+        assert!(accept.args.is_empty());
+        assert_eq!(
+            s.client.max_supply_action(&token, &5).args,
+            vec![&s.env, 5i128.into_val(&s.env)]
+        );
+    }
+
+    // ── Security council ────────────────────────────────────────────────
+
+    /// A passed and queued proposal, with a council active until ledger 500.
+    fn queued(s: &Setup) -> (u32, Address) {
+        let council = Address::generate(&s.env);
+        s.client.set_council(&council, &500);
+        let id = propose(s);
+        s.client.cast_vote(&s.alice, &id, &VoteType::For);
+        s.env.ledger().set_sequence_number(112);
+        s.client.queue(&id);
+        (id, council)
+    }
+
+    #[test]
+    fn test_council_vetoes_queued_proposal() {
+        let s = setup();
+        let (id, council) = queued(&s);
+        assert_eq!(s.client.council(), Some(council));
+
+        s.client.veto(&id);
+        assert_eq!(s.client.state(&id), ProposalState::Vetoed);
+        assert!(s.client.try_execute(&id).is_err());
+        assert_eq!(s.target.value(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "no active council")]
+    fn test_council_veto_expires() {
+        let s = setup();
+        let (id, _) = queued(&s);
+        s.env.ledger().set_sequence_number(501);
+        assert_eq!(s.client.council(), None);
+        s.client.veto(&id);
+    }
+
+    #[test]
+    #[should_panic(expected = "proposal not queued")]
+    fn test_council_cannot_veto_active_proposal() {
+        let s = setup();
+        s.client.set_council(&Address::generate(&s.env), &500);
+        let id = propose(&s);
+        s.client.veto(&id);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_only_council_can_veto() {
+        let s = setup();
+        let (id, _) = queued(&s);
+        s.env.mock_auths(&[MockAuth {
+            address: &s.alice,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "veto",
+                args: (id,).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.veto(&id);
+    }
+
+    // ── Votes by signature ──────────────────────────────────────────────
+
+    fn voter_key() -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&[9u8; 32])
+    }
+
+    /// The Stellar account address of `voter_key`, holding 300 votes.
+    fn signer(s: &Setup) -> Address {
+        use soroban_sdk::xdr::FromXdr;
+        let mut xdr = Bytes::from_array(&s.env, &[0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0]);
+        xdr.append(&Bytes::from_array(
+            &s.env,
+            &voter_key().verifying_key().to_bytes(),
+        ));
+        let voter = Address::from_xdr(&s.env, &xdr).unwrap();
+        s.env.ledger().set_sequence_number(10);
+        s.token.set_balance(&voter, &300);
+        s.env.ledger().set_sequence_number(11);
+        voter
+    }
+
+    fn sign(s: &Setup, voter: &Address, id: u32, support: VoteType, nonce: u32) -> BytesN<64> {
+        use ed25519_dalek::Signer;
+        let message = (s.client.address.clone(), voter.clone(), id, support, nonce).to_xdr(&s.env);
+        let signature = voter_key().sign(&message.to_alloc_vec());
+        BytesN::from_array(&s.env, &signature.to_bytes())
+    }
+
+    #[test]
+    fn test_vote_by_signature() {
+        let s = setup();
+        let voter = signer(&s);
+        let id = propose(&s);
+        let signature = sign(&s, &voter, id, VoteType::For, 0);
+
+        let weight = s
+            .client
+            .cast_vote_by_sig(&voter, &id, &VoteType::For, &signature, &0);
+        assert_eq!(weight, 300);
+        assert_eq!(s.client.get_proposal(&id).for_votes, 300);
+        assert_eq!(s.client.nonce(&voter), 1);
+
+        // The same signature cannot be replayed on another proposal
+        let other = propose(&s);
+        assert!(s
+            .client
+            .try_cast_vote_by_sig(&voter, &other, &VoteType::For, &signature, &0)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vote_by_signature_with_altered_support_panics() {
+        let s = setup();
+        let voter = signer(&s);
+        let id = propose(&s);
+        let signature = sign(&s, &voter, id, VoteType::For, 0);
+        s.client
+            .cast_vote_by_sig(&voter, &id, &VoteType::Against, &signature, &0);
+    }
+
+    #[test]
+    #[should_panic(expected = "voter is not an account")]
+    fn test_contract_voter_cannot_sign() {
+        let s = setup();
+        let id = propose(&s);
+        let signature = sign(&s, &s.target.address, id, VoteType::For, 0);
+        s.client
+            .cast_vote_by_sig(&s.target.address, &id, &VoteType::For, &signature, &0);
+    }
+
+    // ── Proposal deposits ───────────────────────────────────────────────
+
+    /// Proposals lock a 50 deposit, refunded from a 10% turnout.
+    fn deposit_setup() -> Setup {
+        let s = setup();
+        s.client.set_params(&GovernanceParams {
+            deposit: 50,
+            floor_bps: 1_000,
+            ..params()
+        });
+        s
+    }
+
+    #[test]
+    fn test_deposit_refunded_after_turnout() {
+        let s = deposit_setup();
+        let id = propose(&s);
+        assert_eq!(s.token.balance(&s.alice), 550);
+        assert_eq!(s.client.get_proposal(&id).floor, 100);
+
+        // A defeated proposal still gets its deposit back
+        s.client.cast_vote(&s.bob, &id, &VoteType::Against);
+        s.env.ledger().set_sequence_number(112);
+        assert!(s.client.settle_deposit(&id));
+        assert_eq!(s.token.balance(&s.alice), 600);
+        assert!(s.client.try_settle_deposit(&id).is_err());
+    }
+
+    #[test]
+    fn test_spam_deposit_slashed_to_treasury() {
+        let s = deposit_setup();
+        let id = propose(&s);
+        s.env.ledger().set_sequence_number(112);
+        assert!(!s.client.settle_deposit(&id));
+        assert_eq!(s.token.balance(&s.client.timelock()), 50);
+        assert_eq!(s.token.balance(&s.alice), 550);
+    }
+
+    #[test]
+    #[should_panic(expected = "voting not over")]
+    fn test_settle_deposit_while_active_panics() {
+        let s = deposit_setup();
+        let id = propose(&s);
+        s.client.settle_deposit(&id);
+    }
+
+    // ── Emergency proposals ─────────────────────────────────────────────
+
+    /// Emergency proposals run for 10 ledgers, need 75% approval and may
+    /// call `pause` on the target.
+    fn emergency_setup() -> Setup {
+        let s = setup();
+        s.client.set_type_params(
+            &symbol_short!("emergency"),
+            &GovernanceParams {
+                voting_period: 10,
+                approval_bps: 7_500,
+                ..params()
+            },
+        );
+        s.client
+            .set_emergency_function(&s.target.address, &symbol_short!("pause"), &true);
+        s
+    }
+
+    fn propose_pause(s: &Setup) -> u32 {
+        propose_pause_of(s, &s.target.address)
+    }
+
+    fn propose_pause_of(s: &Setup, contract: &Address) -> u32 {
+        let pause = Action {
+            contract: contract.clone(),
+            function: symbol_short!("pause"),
+            args: Vec::new(&s.env),
+        };
+        s.client.propose(
+            &s.alice,
+            &symbol_short!("emergency"),
+            &vec![&s.env, pause],
+            &String::from_str(&s.env, "Pause"),
+        )
+    }
+
+    #[test]
+    fn test_emergency_proposal_fast_track() {
+        let s = emergency_setup();
+        let id = propose_pause(&s);
+        assert_eq!(s.client.get_proposal(&id).end_ledger, 21);
+        s.client.cast_vote(&s.alice, &id, &VoteType::For);
+
+        s.env.ledger().set_sequence_number(22);
+        s.client.queue(&id);
+        s.client.execute(&id);
+        assert!(s.target.is_paused());
+    }
+
+    #[test]
+    fn test_emergency_needs_higher_approval() {
+        let s = emergency_setup();
+        let id = propose_pause(&s);
+        s.client.cast_vote(&s.alice, &id, &VoteType::For);
+        s.client.cast_vote(&s.bob, &id, &VoteType::Against);
+        s.env.ledger().set_sequence_number(22);
+        assert_eq!(s.client.state(&id), ProposalState::Defeated);
+    }
+
+    #[test]
+    #[should_panic(expected = "action not allowed in emergency")]
+    fn test_emergency_rejects_other_actions() {
+        let s = emergency_setup();
+        propose_kind(&s, symbol_short!("emergency"));
+    }
+
+    #[test]
+    #[should_panic(expected = "action not allowed in emergency")]
+    fn test_emergency_function_only_on_whitelisted_contract() {
+        let s = emergency_setup();
+        assert!(!s
+            .client
+            .is_emergency_function(&s.token.address, &symbol_short!("pause")));
+        propose_pause_of(&s, &s.token.address);
+    }
+
+    #[test]
+    #[should_panic(expected = "emergency voting_period must be shorter")]
+    fn test_emergency_params_must_be_faster() {
+        let s = setup();
+        s.client.set_type_params(
+            &symbol_short!("emergency"),
+            &GovernanceParams {
+                approval_bps: 7_500,
+                ..params()
+            },
+        );
+    }
+}
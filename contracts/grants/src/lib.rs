@@ -0,0 +1,409 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Token,
+    Reviewers,
+    /// Reviewer approvals a milestone needs.
+    Threshold,
+    GrantCount,
+    Grant(u32),
+    /// Set once a reviewer approved a milestone of a grant.
+    Approval(u32, u32, Address),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+pub enum MilestoneStatus {
+    Pending,
+    Paid,
+    /// Not approved by its deadline; the funds went back to the admin.
+    Expired,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Milestone {
+    pub amount: i128,
+    /// Last ledger on which the milestone can be approved.
+    pub deadline: u32,
+    pub approvals: u32,
+    pub status: MilestoneStatus,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Grant {
+    pub id: u32,
+    pub grantee: Address,
+    pub milestones: Vec<Milestone>,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Grants Contract — ecosystem grants paid per milestone.
+///
+/// Unlike vesting, nothing is released with time. The admin (the grant
+/// program) creates a grant as a list of milestones, each an amount and an
+/// approval deadline, and funds all of it up front. Members of the reviewer
+/// committee approve a milestone once it is delivered; when `threshold` of
+/// them have, its amount is paid to the grantee.
+///
+/// A milestone still unapproved after its deadline expires: anyone can
+/// `expire_milestone` to return its amount to the admin. The admin can
+/// change the committee and threshold at any time; approvals already given
+/// still count.
+#[contract]
+pub struct GrantsContract;
+
+#[contractimpl]
+impl GrantsContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the admin, the grant token and the reviewer committee.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        token: Address,
+        reviewers: Vec<Address>,
+        threshold: u32,
+    ) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Token, &token);
+        Self::_set_committee(&env, reviewers, threshold);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Create a grant to `grantee` paying `amounts[i]` once milestone `i` is
+    /// approved, no later than `deadlines[i]`. The admin funds the whole
+    /// grant. Admin only. Returns the grant id.
+    pub fn create_grant(
+        env: Env,
+        grantee: Address,
+        amounts: Vec<i128>,
+        deadlines: Vec<u32>,
+    ) -> u32 {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+        assert!(!amounts.is_empty(), "no milestones");
+        assert!(amounts.len() == deadlines.len(), "length mismatch");
+
+        let mut milestones = Vec::new(&env);
+        let mut total: i128 = 0;
+        for (amount, deadline) in amounts.iter().zip(deadlines.iter()) {
+            assert!(amount > 0, "amount must be positive");
+            assert!(deadline >= env.ledger().sequence(), "deadline in the past");
+            total += amount;
+            milestones.push_back(Milestone {
+                amount,
+                deadline,
+                approvals: 0,
+                status: MilestoneStatus::Pending,
+            });
+        }
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &admin,
+            &env.current_contract_address(),
+            &total,
+        );
+
+        let id = Self::grant_count(env.clone());
+        let grant = Grant {
+            id,
+            grantee: grantee.clone(),
+            milestones,
+        };
+        env.storage().persistent().set(&DataKey::Grant(id), &grant);
+        env.storage()
+            .instance()
+            .set(&DataKey::GrantCount, &(id + 1));
+
+        env.events()
+            .publish((symbol_short!("grant"), id), (grantee, total));
+        id
+    }
+
+    /// Replace the reviewer committee and threshold. Admin only.
+    pub fn set_committee(env: Env, reviewers: Vec<Address>, threshold: u32) {
+        Self::admin(env.clone()).require_auth();
+        Self::_set_committee(&env, reviewers, threshold);
+    }
+
+    // ── Reviewers ───────────────────────────────────────────────────────
+
+    /// Approve milestone `index` of a grant as `reviewer`, paying it out
+    /// once the threshold is reached. Committee members only.
+    pub fn approve_milestone(env: Env, grant_id: u32, index: u32, reviewer: Address) {
+        reviewer.require_auth();
+        assert!(
+            Self::reviewers(env.clone()).contains(&reviewer),
+            "not a reviewer"
+        );
+        let mut grant = Self::get_grant(env.clone(), grant_id);
+        let mut milestone = grant.milestones.get(index).expect("no milestone found");
+        assert!(
+            milestone.status == MilestoneStatus::Pending,
+            "milestone not pending"
+        );
+        assert!(
+            env.ledger().sequence() <= milestone.deadline,
+            "milestone expired"
+        );
+        let key = DataKey::Approval(grant_id, index, reviewer.clone());
+        assert!(!env.storage().persistent().has(&key), "already approved");
+        env.storage().persistent().set(&key, &true);
+
+        milestone.approvals += 1;
+        let paid = milestone.approvals >= Self::threshold(env.clone());
+        if paid {
+            milestone.status = MilestoneStatus::Paid;
+        }
+        grant.milestones.set(index, milestone.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Grant(grant_id), &grant);
+
+        env.events()
+            .publish((symbol_short!("approve"), grant_id, index), reviewer);
+        if paid {
+            token::Client::new(&env, &Self::token(env.clone())).transfer(
+                &env.current_contract_address(),
+                &grant.grantee,
+                &milestone.amount,
+            );
+            env.events()
+                .publish((symbol_short!("paid"), grant_id, index), milestone.amount);
+        }
+    }
+
+    // ── Expiry ──────────────────────────────────────────────────────────
+
+    /// Expire milestone `index` of a grant once its deadline has passed
+    /// unapproved, returning its amount to the admin. Anyone may call.
+    pub fn expire_milestone(env: Env, grant_id: u32, index: u32) -> i128 {
+        let mut grant = Self::get_grant(env.clone(), grant_id);
+        let mut milestone = grant.milestones.get(index).expect("no milestone found");
+        assert!(
+            milestone.status == MilestoneStatus::Pending,
+            "milestone not pending"
+        );
+        assert!(
+            env.ledger().sequence() > milestone.deadline,
+            "deadline not reached"
+        );
+
+        milestone.status = MilestoneStatus::Expired;
+        grant.milestones.set(index, milestone.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Grant(grant_id), &grant);
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &env.current_contract_address(),
+            &Self::admin(env.clone()),
+            &milestone.amount,
+        );
+
+        env.events().publish(
+            (symbol_short!("expired"), grant_id, index),
+            milestone.amount,
+        );
+        milestone.amount
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn has_approved(env: Env, grant_id: u32, index: u32, reviewer: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Approval(grant_id, index, reviewer))
+    }
+
+    /// Grants `start` to `start + limit - 1`, oldest first.
+    pub fn grants(env: Env, start: u32, limit: u32) -> Vec<Grant> {
+        let end = start
+            .saturating_add(limit)
+            .min(Self::grant_count(env.clone()));
+        let mut grants = Vec::new(&env);
+        for id in start..end {
+            grants.push_back(Self::get_grant(env.clone(), id));
+        }
+        grants
+    }
+
+    pub fn get_grant(env: Env, grant_id: u32) -> Grant {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Grant(grant_id))
+            .expect("no grant found")
+    }
+
+    pub fn grant_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::GrantCount)
+            .unwrap_or(0)
+    }
+
+    pub fn reviewers(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Reviewers)
+            .expect("not initialized")
+    }
+
+    pub fn threshold(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Threshold)
+            .expect("not initialized")
+    }
+
+    pub fn token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("not initialized")
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _set_committee(env: &Env, reviewers: Vec<Address>, threshold: u32) {
+        assert!(
+            threshold > 0 && threshold <= reviewers.len(),
+            "invalid threshold"
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::Reviewers, &reviewers);
+        env.storage()
+            .instance()
+            .set(&DataKey::Threshold, &threshold);
+
+        env.events()
+            .publish((symbol_short!("committee"),), (reviewers, threshold));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger, vec};
+
+    struct Setup {
+        env: Env,
+        client: GrantsContractClient<'static>,
+        token: TokenFixture,
+        admin: Address,
+        reviewers: Vec<Address>,
+        grantee: Address,
+        grant: u32,
+    }
+
+    /// A 2-of-3 committee and a grant of 100 by ledger 200 and 300 by
+    /// ledger 400.
+    fn setup() -> Setup {
+        let env = test_env();
+        env.ledger().set_sequence_number(100);
+
+        let admin = Address::generate(&env);
+        let token = TokenFixture::stellar_asset(&env);
+        token.mint(&admin, 1_000);
+        let reviewers = vec![
+            &env,
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        let client = GrantsContractClient::new(&env, &env.register_contract(None, GrantsContract));
+        client.initialize(&admin, &token.address, &reviewers, &2);
+
+        let grantee = Address::generate(&env);
+        let grant = client.create_grant(&grantee, &vec![&env, 100, 300], &vec![&env, 200, 400]);
+
+        Setup {
+            token,
+            env,
+            client,
+            admin,
+            reviewers,
+            grantee,
+            grant,
+        }
+    }
+
+    #[test]
+    fn test_threshold_approvals_pay_milestone() {
+        let s = setup();
+        let (first, second) = (s.reviewers.get(0).unwrap(), s.reviewers.get(1).unwrap());
+        s.client.approve_milestone(&s.grant, &0, &first);
+        assert_eq!(s.token.balance(&s.grantee), 0);
+        assert!(s
+            .client
+            .try_approve_milestone(&s.grant, &0, &first)
+            .is_err());
+
+        s.client.approve_milestone(&s.grant, &0, &second);
+        assert_eq!(s.token.balance(&s.grantee), 100);
+        let milestone = s.client.get_grant(&s.grant).milestones.get(0).unwrap();
+        assert_eq!(milestone.status, MilestoneStatus::Paid);
+        assert_eq!(s.token.balance(&s.client.address), 300);
+    }
+
+    #[test]
+    fn test_unapproved_milestone_expires_to_admin() {
+        let s = setup();
+        s.client
+            .approve_milestone(&s.grant, &1, &s.reviewers.get(0).unwrap());
+        assert!(s.client.try_expire_milestone(&s.grant, &1).is_err());
+
+        s.env.ledger().set_sequence_number(401);
+        assert!(s
+            .client
+            .try_approve_milestone(&s.grant, &1, &s.reviewers.get(1).unwrap())
+            .is_err());
+        assert_eq!(s.client.expire_milestone(&s.grant, &1), 300);
+        assert_eq!(s.token.balance(&s.admin), 900);
+        assert_eq!(s.token.balance(&s.grantee), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a reviewer")]
+    fn test_only_reviewers_approve() {
+        let s = setup();
+        s.client
+            .approve_milestone(&s.grant, &0, &Address::generate(&s.env));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid threshold")]
+    fn test_threshold_within_committee() {
+        let s = setup();
+        s.client.set_committee(&s.reviewers, &4);
+    }
+}
@@ -0,0 +1,195 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Guardian,
+    GlobalPause,
+    Paused(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Guardian Contract — shared pause switch for the launchpad contracts.
+///
+/// The token, vesting, sale and staking contracts can point at this
+/// registry; each then asks `is_paused` with its own address before any
+/// state-changing user action. The guardian, normally a multisig, can
+/// freeze one contract with `pause` or the whole platform in a single
+/// transaction with `pause_all`.
+#[contract]
+pub struct GuardianContract;
+
+#[contractimpl]
+impl GuardianContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, guardian: Address) {
+        if env.storage().instance().has(&DataKey::Guardian) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Guardian, &guardian);
+
+        env.events().publish((symbol_short!("init"),), guardian);
+    }
+
+    // ── Guardian actions ────────────────────────────────────────────────
+
+    /// Freeze every contract using this registry. Guardian only.
+    pub fn pause_all(env: Env) {
+        Self::_require_guardian(&env);
+        env.storage().instance().set(&DataKey::GlobalPause, &true);
+
+        env.events().publish((symbol_short!("pause_all"),), true);
+    }
+
+    /// Lift the platform-wide freeze. Contracts paused individually stay
+    /// paused. Guardian only.
+    pub fn unpause_all(env: Env) {
+        Self::_require_guardian(&env);
+        env.storage().instance().remove(&DataKey::GlobalPause);
+
+        env.events().publish((symbol_short!("pause_all"),), false);
+    }
+
+    /// Freeze `contract` alone. Guardian only.
+    pub fn pause(env: Env, contract: Address) {
+        Self::_require_guardian(&env);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Paused(contract.clone()), &true);
+
+        env.events()
+            .publish((symbol_short!("pause"), contract), true);
+    }
+
+    /// Unfreeze `contract`. Guardian only.
+    pub fn unpause(env: Env, contract: Address) {
+        Self::_require_guardian(&env);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Paused(contract.clone()));
+
+        env.events()
+            .publish((symbol_short!("pause"), contract), false);
+    }
+
+    /// Hand the guardian role to `guardian`. Guardian only.
+    pub fn set_guardian(env: Env, guardian: Address) {
+        Self::_require_guardian(&env);
+        env.storage().instance().set(&DataKey::Guardian, &guardian);
+
+        env.events().publish((symbol_short!("guardian"),), guardian);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Whether `contract` is frozen, platform-wide or on its own.
+    pub fn is_paused(env: Env, contract: Address) -> bool {
+        Self::is_globally_paused(env.clone())
+            || env.storage().persistent().has(&DataKey::Paused(contract))
+    }
+
+    pub fn is_globally_paused(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::GlobalPause)
+    }
+
+    pub fn guardian(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Guardian)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _require_guardian(env: &Env) {
+        Self::guardian(env.clone()).require_auth();
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        testutils::Address as _, testutils::MockAuth, testutils::MockAuthInvoke, IntoVal,
+    };
+
+    fn setup() -> (Env, GuardianContractClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, GuardianContract);
+        let client = GuardianContractClient::new(&env, &contract_id);
+        let guardian = Address::generate(&env);
+        client.initialize(&guardian);
+
+        (env, client, guardian)
+    }
+
+    #[test]
+    fn test_pause_single_contract() {
+        let (env, client, _) = setup();
+        let sale = Address::generate(&env);
+        let token = Address::generate(&env);
+        client.pause(&sale);
+        assert!(client.is_paused(&sale));
+        assert!(!client.is_paused(&token));
+
+        client.unpause(&sale);
+        assert!(!client.is_paused(&sale));
+    }
+
+    #[test]
+    fn test_pause_all() {
+        let (env, client, _) = setup();
+        let sale = Address::generate(&env);
+        let token = Address::generate(&env);
+        client.pause(&sale);
+        client.pause_all();
+        assert!(client.is_globally_paused());
+        assert!(client.is_paused(&token));
+
+        // Individually paused contracts stay paused
+        client.unpause_all();
+        assert!(!client.is_paused(&token));
+        assert!(client.is_paused(&sale));
+    }
+
+    #[test]
+    fn test_set_guardian() {
+        let (env, client, _) = setup();
+        let multisig = Address::generate(&env);
+        client.set_guardian(&multisig);
+        assert_eq!(client.guardian(), multisig);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_non_guardian_cannot_pause_all() {
+        let (env, client, _) = setup();
+        let outsider = Address::generate(&env);
+        env.mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "pause_all",
+                args: ().into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        client.pause_all();
+    }
+}
@@ -0,0 +1,464 @@
+#![no_std]
+
+use launchpad_merkle as merkle;
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, token, Address, BytesN, Env, String, Vec,
+};
+use soropad_math::mul_div;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Token,
+    /// Amount set aside for open incidents.
+    Reserved,
+    IncidentCount,
+    Incident(u32),
+    /// Claimed bitmap of an incident, 128 leaves per word.
+    Claimed(u32, u32),
+}
+
+/// An approved incident and the compensation set aside for it.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Incident {
+    pub id: u32,
+    pub description: String,
+    /// Root of the loss list, with leaves `sha256(xdr((index, account, loss)))`.
+    pub merkle_root: BytesN<32>,
+    /// Sum of every loss in the list.
+    pub total_losses: i128,
+    /// Amount set aside for compensation; losses are paid pro rata out of it.
+    pub pool: i128,
+    /// Most any single account can receive.
+    pub account_cap: i128,
+    /// Last ledger on which compensation can be claimed.
+    pub deadline: u32,
+    pub claimed: i128,
+    pub closed: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Insurance Fund Contract — compensation for users hit by an incident.
+///
+/// The fund is fed with a slice of platform fees, typically as a payee of
+/// the fee splitter, or through `deposit`. After an incident, governance
+/// (the admin, normally the timelock) `approve_incident`s a claim: the Merkle
+/// root of the affected accounts' losses, their total, the most the fund
+/// pays out for it, a per-account cap and a claim deadline. That payout is
+/// reserved on approval, so later incidents cannot draw on it.
+///
+/// Each affected account then `claim`s `loss × pool / total_losses`, capped
+/// at `account_cap`, with a proof against the root; leaves use the same
+/// format as the airdrop. After the deadline anyone can `close_incident` to
+/// return the unclaimed part of the pool to the fund.
+#[contract]
+pub struct InsuranceContract;
+
+#[contractimpl]
+impl InsuranceContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the admin (governance) and the token the fund holds.
+    pub fn initialize(env: Env, admin: Address, token: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Token, &token);
+
+        env.events()
+            .publish((symbol_short!("init"),), (admin, token));
+    }
+
+    // ── Funding ─────────────────────────────────────────────────────────
+
+    /// Add `amount` to the fund from `from`. Plain transfers to the fund
+    /// count too.
+    pub fn deposit(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &from,
+            &env.current_contract_address(),
+            &amount,
+        );
+        env.events()
+            .publish((symbol_short!("deposit"), from), amount);
+    }
+
+    // ── Incidents ───────────────────────────────────────────────────────
+
+    /// Approve compensation for an incident: pay the losses under
+    /// `merkle_root`, summing to `total_losses`, pro rata out of at most
+    /// `max_payout`, at most `account_cap` each, until `deadline`. Reserves
+    /// the payout, or whatever the fund has if less. Admin only. Returns the
+    /// incident id.
+    pub fn approve_incident(
+        env: Env,
+        description: String,
+        merkle_root: BytesN<32>,
+        total_losses: i128,
+        max_payout: i128,
+        account_cap: i128,
+        deadline: u32,
+    ) -> u32 {
+        Self::admin(env.clone()).require_auth();
+        assert!(
+            total_losses > 0 && max_payout > 0 && account_cap > 0,
+            "amounts must be positive"
+        );
+        assert!(deadline > env.ledger().sequence(), "deadline in the past");
+
+        let pool = max_payout
+            .min(total_losses)
+            .min(Self::available(env.clone()));
+        assert!(pool > 0, "fund is empty");
+        let reserved = Self::reserved(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::Reserved, &(reserved + pool));
+
+        let id = Self::incident_count(env.clone());
+        let incident = Incident {
+            id,
+            description,
+            merkle_root,
+            total_losses,
+            pool,
+            account_cap,
+            deadline,
+            claimed: 0,
+            closed: false,
+        };
+        Self::_save(&env, &incident);
+        env.storage()
+            .instance()
+            .set(&DataKey::IncidentCount, &(id + 1));
+
+        env.events()
+            .publish((symbol_short!("incident"), id), (total_losses, pool));
+        id
+    }
+
+    /// Pay `account` its compensation for leaf `index` of incident
+    /// `incident_id`, recording a loss of `loss`. Anyone may submit; the
+    /// tokens always go to `account`. Returns the amount paid.
+    pub fn claim(
+        env: Env,
+        incident_id: u32,
+        index: u32,
+        account: Address,
+        loss: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> i128 {
+        let mut incident = Self::get_incident(env.clone(), incident_id);
+        assert!(!incident.closed, "incident closed");
+        assert!(
+            env.ledger().sequence() <= incident.deadline,
+            "claim deadline passed"
+        );
+        assert!(
+            !Self::is_claimed(env.clone(), incident_id, index),
+            "already claimed"
+        );
+        assert!(loss > 0, "loss must be positive");
+        let leaf = merkle::leaf(&env, index, &account, loss);
+        assert!(
+            merkle::verify(&env, leaf, &proof, &incident.merkle_root),
+            "invalid proof"
+        );
+
+        let payout = Self::_payout(&incident, loss).min(incident.pool - incident.claimed);
+        incident.claimed += payout;
+        Self::_save(&env, &incident);
+        merkle::set_claimed(&env, |word| DataKey::Claimed(incident_id, word), index);
+        let reserved = Self::reserved(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::Reserved, &(reserved - payout));
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &env.current_contract_address(),
+            &account,
+            &payout,
+        );
+
+        env.events().publish(
+            (symbol_short!("claim"), incident_id, account),
+            (loss, payout),
+        );
+        payout
+    }
+
+    /// Release what is left of incident `incident_id`'s pool back to the
+    /// fund once its deadline has passed. Anyone may call. Returns the
+    /// amount released.
+    pub fn close_incident(env: Env, incident_id: u32) -> i128 {
+        let mut incident = Self::get_incident(env.clone(), incident_id);
+        assert!(!incident.closed, "incident closed");
+        assert!(
+            env.ledger().sequence() > incident.deadline,
+            "claim period not over"
+        );
+
+        let released = incident.pool - incident.claimed;
+        incident.closed = true;
+        Self::_save(&env, &incident);
+        let reserved = Self::reserved(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::Reserved, &(reserved - released));
+
+        env.events()
+            .publish((symbol_short!("closed"), incident_id), released);
+        released
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// What a loss of `loss` is compensated with in incident `incident_id`.
+    pub fn payout_for(env: Env, incident_id: u32, loss: i128) -> i128 {
+        Self::_payout(&Self::get_incident(env, incident_id), loss)
+    }
+
+    /// Fund balance not reserved for open incidents.
+    pub fn available(env: Env) -> i128 {
+        let balance = token::Client::new(&env, &Self::token(env.clone()))
+            .balance(&env.current_contract_address());
+        balance - Self::reserved(env)
+    }
+
+    pub fn reserved(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Reserved)
+            .unwrap_or(0)
+    }
+
+    pub fn is_claimed(env: Env, incident_id: u32, index: u32) -> bool {
+        merkle::is_claimed(&env, |word| DataKey::Claimed(incident_id, word), index)
+    }
+
+    /// Incidents `start` to `start + limit - 1`, oldest first.
+    pub fn incidents(env: Env, start: u32, limit: u32) -> Vec<Incident> {
+        let end = start
+            .saturating_add(limit)
+            .min(Self::incident_count(env.clone()));
+        let mut incidents = Vec::new(&env);
+        for id in start..end {
+            incidents.push_back(Self::get_incident(env.clone(), id));
+        }
+        incidents
+    }
+
+    pub fn get_incident(env: Env, incident_id: u32) -> Incident {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Incident(incident_id))
+            .expect("no incident found")
+    }
+
+    pub fn incident_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::IncidentCount)
+            .unwrap_or(0)
+    }
+
+    pub fn token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("not initialized")
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _save(env: &Env, incident: &Incident) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Incident(incident.id), incident);
+    }
+
+    fn _payout(incident: &Incident, loss: i128) -> i128 {
+        mul_div(loss, incident.pool, incident.total_losses).min(incident.account_cap)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger, vec};
+
+    struct Setup {
+        env: Env,
+        client: InsuranceContractClient<'static>,
+        token: TokenFixture,
+        accounts: Vec<Address>,
+        losses: Vec<i128>,
+        root: BytesN<32>,
+    }
+
+    fn leaves(env: &Env, accounts: &Vec<Address>, losses: &Vec<i128>) -> Vec<BytesN<32>> {
+        let mut leaves = Vec::new(env);
+        for i in 0..accounts.len() {
+            leaves.push_back(merkle::leaf(
+                env,
+                i,
+                &accounts.get(i).unwrap(),
+                losses.get(i).unwrap(),
+            ));
+        }
+        leaves
+    }
+
+    fn proof(s: &Setup, index: u32) -> Vec<BytesN<32>> {
+        merkle::root_and_proof(&s.env, &leaves(&s.env, &s.accounts, &s.losses), index).1
+    }
+
+    /// A fund of 1 000 and an incident list of losses 400, 1 200 and 400.
+    fn setup() -> Setup {
+        let env = test_env();
+        env.ledger().set_sequence_number(100);
+
+        let token = TokenFixture::stellar_asset(&env);
+        let client =
+            InsuranceContractClient::new(&env, &env.register_contract(None, InsuranceContract));
+        client.initialize(&Address::generate(&env), &token.address);
+        let funder = Address::generate(&env);
+        token.mint(&funder, 1_000);
+        client.deposit(&funder, &1_000);
+
+        let accounts = vec![
+            &env,
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        let losses = vec![&env, 400i128, 1_200, 400];
+        let (root, _) = merkle::root_and_proof(&env, &leaves(&env, &accounts, &losses), 0);
+
+        Setup {
+            token,
+            env,
+            client,
+            accounts,
+            losses,
+            root,
+        }
+    }
+
+    /// Losses total 2 000; pays out of up to `max_payout`, 500 at most
+    /// each, until ledger 200.
+    fn approve(s: &Setup, max_payout: i128) -> u32 {
+        s.client.approve_incident(
+            &String::from_str(&s.env, "sale exploit"),
+            &s.root,
+            &2_000,
+            &max_payout,
+            &500,
+            &200,
+        )
+    }
+
+    fn claim(s: &Setup, id: u32, index: u32) -> i128 {
+        s.client.claim(
+            &id,
+            &index,
+            &s.accounts.get(index).unwrap(),
+            &s.losses.get(index).unwrap(),
+            &proof(s, index),
+        )
+    }
+
+    #[test]
+    fn test_pro_rata_claims_with_cap() {
+        let s = setup();
+        let id = approve(&s, 1_000);
+        assert_eq!(s.client.reserved(), 1_000);
+        assert_eq!(s.client.available(), 0);
+
+        // Half of every loss is covered; 600 is capped at 500
+        assert_eq!(claim(&s, id, 0), 200);
+        assert_eq!(claim(&s, id, 1), 500);
+        assert_eq!(claim(&s, id, 2), 200);
+        assert_eq!(s.token.balance(&s.accounts.get(1).unwrap()), 500);
+        assert!(s.client.is_claimed(&id, &1));
+        assert_eq!(s.client.reserved(), 100);
+    }
+
+    #[test]
+    fn test_close_returns_unclaimed_to_fund() {
+        let s = setup();
+        let id = approve(&s, 600);
+        claim(&s, id, 0);
+        assert!(s.client.try_close_incident(&id).is_err());
+
+        s.env.ledger().set_sequence_number(201);
+        let late = s.accounts.get(1).unwrap();
+        assert!(s
+            .client
+            .try_claim(&id, &1, &late, &1_200, &proof(&s, 1))
+            .is_err());
+        assert_eq!(s.client.close_incident(&id), 480);
+        assert_eq!(s.client.reserved(), 0);
+        assert_eq!(s.client.available(), 880);
+    }
+
+    #[test]
+    fn test_payout_limited_to_available_funds() {
+        let s = setup();
+        approve(&s, 700);
+        let second = approve(&s, 700);
+        assert_eq!(s.client.get_incident(&second).pool, 300);
+        assert!(s
+            .client
+            .try_approve_incident(
+                &String::from_str(&s.env, "again"),
+                &s.root,
+                &2_000,
+                &100,
+                &100,
+                &200,
+            )
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "already claimed")]
+    fn test_double_claim_panics() {
+        let s = setup();
+        let id = approve(&s, 1_000);
+        claim(&s, id, 0);
+        claim(&s, id, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid proof")]
+    fn test_inflated_loss_rejected() {
+        let s = setup();
+        let id = approve(&s, 1_000);
+        s.client
+            .claim(&id, &0, &s.accounts.get(0).unwrap(), &4_000, &proof(&s, 0));
+    }
+}
@@ -0,0 +1,234 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Verifier(Address),
+    Attestation(Address),
+}
+
+/// A verifier's statement that an account passed KYC at `level`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Attestation {
+    pub level: u32,
+    pub verifier: Address,
+    /// Last ledger on which the attestation counts.
+    pub expiry_ledger: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// KYC Registry Contract — wallet verification levels for the platform.
+///
+/// The admin approves verifiers (KYC providers). A verifier `attest`s that
+/// an account passed checks at some level until an expiry ledger; a later
+/// attestation replaces the earlier one. Sales and tokens in restricted
+/// mode call `is_verified(account, level)`, which holds while the account
+/// has an unexpired attestation of at least `level` from a verifier that is
+/// still approved, so removing a verifier voids everything it attested.
+///
+/// Levels are platform-defined and ordered: a higher level implies every
+/// lower one.
+#[contract]
+pub struct KycRegistryContract;
+
+#[contractimpl]
+impl KycRegistryContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, admin: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Approve or remove `verifier`. Admin only.
+    pub fn set_verifier(env: Env, verifier: Address, allowed: bool) {
+        Self::admin(env.clone()).require_auth();
+        let key = DataKey::Verifier(verifier.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("verifier"), verifier), allowed);
+    }
+
+    // ── Verifier actions ────────────────────────────────────────────────
+
+    /// Record that `account` is verified at `level` until `expiry_ledger`.
+    /// Approved verifiers only.
+    pub fn attest(env: Env, verifier: Address, account: Address, level: u32, expiry_ledger: u32) {
+        verifier.require_auth();
+        assert!(
+            Self::is_verifier(env.clone(), verifier.clone()),
+            "not a verifier"
+        );
+        assert!(level > 0, "level must be positive");
+        assert!(
+            expiry_ledger > env.ledger().sequence(),
+            "expiry in the past"
+        );
+
+        let attestation = Attestation {
+            level,
+            verifier: verifier.clone(),
+            expiry_ledger,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Attestation(account.clone()), &attestation);
+
+        env.events().publish(
+            (symbol_short!("attest"), account, verifier),
+            (level, expiry_ledger),
+        );
+    }
+
+    /// Void the attestation of `account`. The admin or any approved
+    /// verifier.
+    pub fn revoke(env: Env, caller: Address, account: Address) {
+        caller.require_auth();
+        assert!(
+            caller == Self::admin(env.clone()) || Self::is_verifier(env.clone(), caller.clone()),
+            "not admin or verifier"
+        );
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Attestation(account.clone()));
+
+        env.events()
+            .publish((symbol_short!("kyc_rvk"), account), caller);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Whether `account` is currently verified at `level` or above.
+    pub fn is_verified(env: Env, account: Address, level: u32) -> bool {
+        Self::level_of(env, account) >= level
+    }
+
+    /// Level `account` is currently verified at; 0 if none.
+    pub fn level_of(env: Env, account: Address) -> u32 {
+        match Self::attestation(env.clone(), account) {
+            Some(a)
+                if a.expiry_ledger >= env.ledger().sequence()
+                    && Self::is_verifier(env.clone(), a.verifier.clone()) =>
+            {
+                a.level
+            }
+            _ => 0,
+        }
+    }
+
+    /// The stored attestation of `account`, valid or not.
+    pub fn attestation(env: Env, account: Address) -> Option<Attestation> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Attestation(account))
+    }
+
+    pub fn is_verifier(env: Env, verifier: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Verifier(verifier))
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger};
+
+    struct Setup {
+        env: Env,
+        client: KycRegistryContractClient<'static>,
+        verifier: Address,
+    }
+
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_sequence_number(100);
+
+        let client =
+            KycRegistryContractClient::new(&env, &env.register_contract(None, KycRegistryContract));
+        client.initialize(&Address::generate(&env));
+        let verifier = Address::generate(&env);
+        client.set_verifier(&verifier, &true);
+
+        Setup {
+            env,
+            client,
+            verifier,
+        }
+    }
+
+    #[test]
+    fn test_attest_levels_and_expiry() {
+        let s = setup();
+        let user = Address::generate(&s.env);
+        assert!(!s.client.is_verified(&user, &1));
+
+        s.client.attest(&s.verifier, &user, &2, &200);
+        assert!(s.client.is_verified(&user, &1));
+        assert!(s.client.is_verified(&user, &2));
+        assert!(!s.client.is_verified(&user, &3));
+
+        s.env.ledger().set_sequence_number(201);
+        assert_eq!(s.client.level_of(&user), 0);
+    }
+
+    #[test]
+    fn test_revoke() {
+        let s = setup();
+        let user = Address::generate(&s.env);
+        s.client.attest(&s.verifier, &user, &1, &200);
+
+        s.client.revoke(&s.verifier, &user);
+        assert!(!s.client.is_verified(&user, &1));
+        assert_eq!(s.client.attestation(&user), None);
+    }
+
+    #[test]
+    fn test_removed_verifier_voids_attestations() {
+        let s = setup();
+        let user = Address::generate(&s.env);
+        s.client.attest(&s.verifier, &user, &1, &200);
+
+        s.client.set_verifier(&s.verifier, &false);
+        assert!(!s.client.is_verified(&user, &1));
+        assert!(s.client.try_attest(&s.verifier, &user, &1, &200).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "not a verifier")]
+    fn test_only_verifiers_attest() {
+        let s = setup();
+        let outsider = Address::generate(&s.env);
+        s.client.attest(&outsider, &outsider, &3, &200);
+    }
+}
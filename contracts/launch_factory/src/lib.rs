@@ -0,0 +1,883 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, symbol_short, token, xdr::ToXdr, Address,
+    BytesN, Env, Map, String, Symbol, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    TokenWasmHash,
+    VestingWasmHash,
+    SaleFactory,
+    Registry,
+    LaunchCount,
+    Launch(u32),
+    PauseRegistry,
+    RequiredFeeRecipient,
+    WasmRegistry,
+}
+
+/// Everything needed to launch a project in one call.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct LaunchConfig {
+    pub token: TokenParams,
+    /// Team and investor schedules funded from the new supply.
+    pub vesting: Vec<VestingAllocation>,
+    /// Extra addresses (such as an airdrop) allowed to create vesting
+    /// schedules with their own tokens.
+    pub vesting_creators: Vec<Address>,
+    /// Tokens moved into the sale for buyers to claim.
+    pub sale_allocation: i128,
+    /// The sale's parameters. `token` and `project.token` are filled in
+    /// with the new token.
+    pub sale: SaleParams,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TokenParams {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u32,
+    /// Minted at launch and split between vesting, the sale and the
+    /// creator.
+    pub total_supply: i128,
+    pub max_supply: Option<i128>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct VestingAllocation {
+    pub recipient: Address,
+    pub amount: i128,
+    pub cliff_ledger: u32,
+    pub end_ledger: u32,
+}
+
+/// Registry entry for a launch.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LaunchRecord {
+    pub creator: Address,
+    pub token: Address,
+    pub vesting: Address,
+    pub sale: Address,
+    pub created_ledger: u32,
+}
+
+/// Every permission `launch` set up, published as the `wiring` event so
+/// indexers and auditors can check a launch without replaying it.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct WiringManifest {
+    pub token: Address,
+    pub vesting: Address,
+    pub sale: Address,
+    /// Admin of the token and the vesting contract, and of the sale.
+    pub creator: Address,
+    /// Tokens the sale was funded with; it holds no mint rights.
+    pub sale_allocation: i128,
+    /// Addresses allowed to create vesting schedules, the sale included.
+    pub vesting_creators: Vec<Address>,
+    /// Pause registry the token and vesting obey, if one is set.
+    pub pause_registry: Option<Address>,
+    /// Guardian of the sale.
+    pub guardian: Address,
+    /// Where the sale's platform fee goes.
+    pub fee_recipient: Address,
+}
+
+/// Mirror of the sale factory's `SaleParams`.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct SaleParams {
+    pub token: Address,
+    pub payment_token: Address,
+    pub price: i128,
+    pub soft_cap: i128,
+    pub hard_cap: i128,
+    pub min_contribution: i128,
+    pub max_contribution: i128,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+    pub mode: SaleMode,
+    pub payout: Payout,
+    pub tier_pricing: Map<u32, TierPrice>,
+    pub tier_source: TierSource,
+    pub rounds: Vec<Round>,
+    pub extension: Extension,
+    pub liquidity: Liquidity,
+    pub project: ProjectInfo,
+}
+
+/// Mirror of the sale contract's `SaleMode`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum SaleMode {
+    FixedPrice,
+    BondingCurve(Curve),
+    Lbp(LbpConfig),
+}
+
+/// Mirror of the sale contract's `Curve`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Curve {
+    Linear(LinearCurve),
+    Exponential(ExponentialCurve),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LinearCurve {
+    pub base_price: i128,
+    pub slope: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ExponentialCurve {
+    pub base_price: i128,
+    pub growth_bps: u32,
+    pub step: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LbpConfig {
+    pub virtual_reserve: i128,
+    pub start_weight_bps: u32,
+    pub end_weight_bps: u32,
+}
+
+/// Mirror of the sale contract's `Payout`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Payout {
+    Immediate,
+    Milestones(MilestonePlan),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct MilestonePlan {
+    pub approver: Address,
+    pub tranches_bps: Vec<u32>,
+}
+
+/// Mirror of the sale contract's `TierPrice`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum TierPrice {
+    Price(i128),
+    DiscountBps(u32),
+}
+
+/// Mirror of the sale contract's `TierSource`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum TierSource {
+    Admin,
+    Staking(Address),
+    StakingSnapshot(Address, u32),
+}
+
+/// Mirror of the sale contract's `Round`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Round {
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+}
+
+/// Mirror of the sale contract's `Extension`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Extension {
+    Disabled,
+    Allowed(ExtensionRule),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ExtensionRule {
+    pub max_extensions: u32,
+    pub max_ledgers: u32,
+    pub only_below_soft_cap: bool,
+}
+
+/// Mirror of the sale contract's `Liquidity`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Liquidity {
+    Disabled,
+    Enabled(LiquidityConfig),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LiquidityConfig {
+    pub adapter: Address,
+    pub locker: Address,
+    pub share_bps: u32,
+    pub lock_ledgers: u32,
+}
+
+/// Mirror of the sale contract's `ProjectInfo`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ProjectInfo {
+    pub name: String,
+    pub token: Address,
+    pub website_hash: BytesN<32>,
+    pub docs_hash: BytesN<32>,
+    pub socials_hash: BytesN<32>,
+    pub audited_by: Vec<String>,
+}
+
+/// The subset of the token contract interface the factory calls.
+#[contractclient(name = "TokenClient")]
+pub trait TokenInterface {
+    fn initialize(
+        env: Env,
+        admin: Address,
+        decimal: u32,
+        name: String,
+        symbol: String,
+        initial_supply: i128,
+        max_supply: Option<i128>,
+    );
+    fn propose_admin(env: Env, new_admin: Address);
+    fn accept_admin(env: Env);
+    fn set_pause_registry(env: Env, registry: Address);
+}
+
+/// The subset of the vesting contract interface the factory calls.
+#[contractclient(name = "VestingClient")]
+pub trait VestingInterface {
+    fn initialize(env: Env, admin: Address, token_contract: Address);
+    fn set_creator(env: Env, creator: Address, allowed: bool);
+    fn set_pause_registry(env: Env, registry: Address);
+    fn create_schedule(
+        env: Env,
+        recipient: Address,
+        total_amount: i128,
+        cliff_ledger: u32,
+        end_ledger: u32,
+    );
+}
+
+/// The subset of the sale factory interface the launch factory calls.
+#[contractclient(name = "SaleFactoryClient")]
+pub trait SaleFactoryInterface {
+    fn deploy_sale(env: Env, deployer: Address, salt: BytesN<32>, params: SaleParams) -> Address;
+    fn fee_recipient(env: Env) -> Address;
+    fn guardian(env: Env) -> Address;
+}
+
+/// Recording interface of the wasm registry.
+#[contractclient(name = "WasmRegistryClient")]
+pub trait WasmRegistryInterface {
+    fn record(env: Env, recorder: Address, contract: Address, wasm_hash: BytesN<32>);
+}
+
+/// The subset of the project registry interface the factory calls.
+#[contractclient(name = "RegistryClient")]
+pub trait RegistryInterface {
+    fn register(
+        env: Env,
+        registrar: Address,
+        creator: Address,
+        token: Address,
+        vesting: Address,
+        sale: Address,
+    ) -> u32;
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Launch Factory — deploys a project's token, vesting and sale in one call.
+///
+/// `launch` takes a single `LaunchConfig` and, within one transaction:
+/// 1. deploys the token with the factory as temporary admin and mints the
+///    whole supply to it;
+/// 2. deploys vesting administered by the creator, authorizes the listed
+///    creators and funds every vesting allocation;
+/// 3. deploys the sale through the sale factory, so it inherits the
+///    platform defaults and lands in the sale registry, and funds it with
+///    the sale allocation;
+/// 4. sends the rest of the supply to the creator and hands them the token
+///    admin role.
+///
+/// Along the way the permission graph is wired: the sale may create
+/// vesting schedules, the token and vesting obey the pause registry if one
+/// is set, and, if a required fee recipient is set, the launch reverts
+/// unless the sale would pay its platform fee there. The sale is funded
+/// with its allocation rather than mint rights, and its guardian comes
+/// from the sale factory. The result is published as a `WiringManifest`.
+///
+/// Any failure reverts the whole launch, so a project can never end up
+/// half-configured. The creator must authorize the vesting, sale and
+/// admin-handover calls made on their behalf.
+///
+/// When a project registry is set, every launch is also recorded there,
+/// and with a wasm registry set, the code hashes of the token and vesting
+/// contracts are recorded in it.
+#[contract]
+pub struct LaunchFactory;
+
+#[contractimpl]
+impl LaunchFactory {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the admin, the token and vesting wasm hashes to deploy and the
+    /// sale factory used for sales.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        token_wasm_hash: BytesN<32>,
+        vesting_wasm_hash: BytesN<32>,
+        sale_factory: Address,
+    ) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::TokenWasmHash, &token_wasm_hash);
+        env.storage()
+            .instance()
+            .set(&DataKey::VestingWasmHash, &vesting_wasm_hash);
+        env.storage()
+            .instance()
+            .set(&DataKey::SaleFactory, &sale_factory);
+        env.storage().instance().set(&DataKey::LaunchCount, &0u32);
+
+        env.events()
+            .publish((symbol_short!("init"),), (admin, sale_factory));
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Point future launches at new token and vesting wasms. Admin only.
+    pub fn set_wasm_hashes(env: Env, token_wasm_hash: BytesN<32>, vesting_wasm_hash: BytesN<32>) {
+        Self::_require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::TokenWasmHash, &token_wasm_hash);
+        env.storage()
+            .instance()
+            .set(&DataKey::VestingWasmHash, &vesting_wasm_hash);
+        env.events().publish(
+            (symbol_short!("wasm"),),
+            (token_wasm_hash, vesting_wasm_hash),
+        );
+    }
+
+    /// Record future launches in `registry`, or stop with `None`. The
+    /// factory must be an allowed registrar there. Admin only.
+    pub fn set_registry(env: Env, registry: Option<Address>) {
+        Self::_require_admin(&env);
+        match &registry {
+            Some(registry) => env.storage().instance().set(&DataKey::Registry, registry),
+            None => env.storage().instance().remove(&DataKey::Registry),
+        }
+        env.events().publish((symbol_short!("registry"),), registry);
+    }
+
+    /// Record the token and vesting code hashes of future launches in the
+    /// wasm registry, which must list this contract as a recorder, or stop
+    /// with `None`. Admin only.
+    pub fn set_wasm_registry(env: Env, registry: Option<Address>) {
+        Self::_require_admin(&env);
+        match &registry {
+            Some(registry) => env
+                .storage()
+                .instance()
+                .set(&DataKey::WasmRegistry, registry),
+            None => env.storage().instance().remove(&DataKey::WasmRegistry),
+        }
+        env.events().publish((symbol_short!("wasm_reg"),), registry);
+    }
+
+    /// Make the token and vesting of future launches obey `registry`, or
+    /// stop with `None`. Admin only.
+    pub fn set_pause_registry(env: Env, registry: Option<Address>) {
+        Self::_require_admin(&env);
+        match &registry {
+            Some(registry) => env
+                .storage()
+                .instance()
+                .set(&DataKey::PauseRegistry, registry),
+            None => env.storage().instance().remove(&DataKey::PauseRegistry),
+        }
+        env.events()
+            .publish((symbol_short!("pause_reg"),), registry);
+    }
+
+    /// Refuse future launches unless the sale factory's fee recipient is
+    /// `recipient` (typically the platform treasury), or stop checking with
+    /// `None`. Admin only.
+    ///
+    /// This is a precondition check, not a setting: the fee recipient
+    /// belongs to the sale factory's platform defaults, which only its own
+    /// admin can change. The check keeps launches from going out while the
+    /// two factories disagree on where fees go.
+    pub fn set_required_fee_recipient(env: Env, recipient: Option<Address>) {
+        Self::_require_admin(&env);
+        match &recipient {
+            Some(recipient) => env
+                .storage()
+                .instance()
+                .set(&DataKey::RequiredFeeRecipient, recipient),
+            None => env
+                .storage()
+                .instance()
+                .remove(&DataKey::RequiredFeeRecipient),
+        }
+        env.events()
+            .publish((symbol_short!("fee_check"),), recipient);
+    }
+
+    // ── Launch ──────────────────────────────────────────────────────────
+
+    /// Deploy and wire up the token, vesting and sale of a new project
+    /// owned by `creator`. Returns the launch index.
+    ///
+    /// Contract addresses are derived from `creator` and `salt`, like the
+    /// sale factory's, so creators cannot collide on each other's salt.
+    pub fn launch(env: Env, creator: Address, salt: BytesN<32>, config: LaunchConfig) -> u32 {
+        creator.require_auth();
+        Self::_validate_config(&config);
+        let factory = env.current_contract_address();
+        let pause_registry = Self::pause_registry(env.clone());
+        let sale_factory = SaleFactoryClient::new(&env, &Self::sale_factory(env.clone()));
+        let fee_recipient = sale_factory.fee_recipient();
+        if let Some(required) = Self::required_fee_recipient(env.clone()) {
+            assert!(
+                fee_recipient == required,
+                "sale factory pays fees to another recipient"
+            );
+        }
+
+        // Token, with the whole supply minted to the factory
+        let token_wasm_hash = Self::token_wasm_hash(env.clone());
+        let token = env
+            .deployer()
+            .with_current_contract(Self::_salt(&env, &creator, &salt, "token"))
+            .deploy(token_wasm_hash.clone());
+        let token_client = TokenClient::new(&env, &token);
+        token_client.initialize(
+            &factory,
+            &config.token.decimals,
+            &config.token.name,
+            &config.token.symbol,
+            &config.token.total_supply,
+            &config.token.max_supply,
+        );
+        let transfers = token::Client::new(&env, &token);
+        if let Some(registry) = &pause_registry {
+            token_client.set_pause_registry(registry);
+        }
+
+        // Vesting, administered by the creator
+        let vesting_wasm_hash = Self::vesting_wasm_hash(env.clone());
+        let vesting = env
+            .deployer()
+            .with_current_contract(Self::_salt(&env, &creator, &salt, "vesting"))
+            .deploy(vesting_wasm_hash.clone());
+        let vesting_client = VestingClient::new(&env, &vesting);
+        vesting_client.initialize(&creator, &token);
+        for vesting_creator in config.vesting_creators.iter() {
+            vesting_client.set_creator(&vesting_creator, &true);
+        }
+        if let Some(registry) = &pause_registry {
+            vesting_client.set_pause_registry(registry);
+        }
+        let mut distributed = 0;
+        for allocation in config.vesting.iter() {
+            transfers.transfer(&factory, &vesting, &allocation.amount);
+            vesting_client.create_schedule(
+                &allocation.recipient,
+                &allocation.amount,
+                &allocation.cliff_ledger,
+                &allocation.end_ledger,
+            );
+            distributed += allocation.amount;
+        }
+
+        // Sale, through the sale factory
+        let mut params = config.sale;
+        params.token = token.clone();
+        params.project.token = token.clone();
+        let sale = sale_factory.deploy_sale(&creator, &salt, &params);
+        transfers.transfer(&factory, &sale, &config.sale_allocation);
+        distributed += config.sale_allocation;
+        vesting_client.set_creator(&sale, &true);
+        let mut vesting_creators = config.vesting_creators.clone();
+        vesting_creators.push_back(sale.clone());
+
+        // Remaining supply and the token admin role go to the creator
+        let remainder = config.token.total_supply - distributed;
+        if remainder > 0 {
+            transfers.transfer(&factory, &creator, &remainder);
+        }
+        token_client.propose_admin(&creator);
+        token_client.accept_admin();
+
+        if let Some(registry) = Self::registry(env.clone()) {
+            RegistryClient::new(&env, &registry)
+                .register(&factory, &creator, &token, &vesting, &sale);
+        }
+        if let Some(registry) = Self::wasm_registry(env.clone()) {
+            let registry = WasmRegistryClient::new(&env, &registry);
+            registry.record(&factory, &token, &token_wasm_hash);
+            registry.record(&factory, &vesting, &vesting_wasm_hash);
+        }
+
+        let index = Self::launch_count(env.clone());
+        let record = LaunchRecord {
+            creator: creator.clone(),
+            token: token.clone(),
+            vesting: vesting.clone(),
+            sale: sale.clone(),
+            created_ledger: env.ledger().sequence(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Launch(index), &record);
+        env.storage()
+            .instance()
+            .set(&DataKey::LaunchCount, &(index + 1));
+
+        env.events().publish(
+            (symbol_short!("launch"), creator.clone()),
+            (index, token.clone(), vesting.clone(), sale.clone()),
+        );
+        let manifest = WiringManifest {
+            token,
+            vesting,
+            sale,
+            creator,
+            sale_allocation: config.sale_allocation,
+            vesting_creators,
+            pause_registry,
+            guardian: sale_factory.guardian(),
+            fee_recipient,
+        };
+        env.events()
+            .publish((symbol_short!("wiring"), index), manifest);
+        index
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    pub fn token_wasm_hash(env: Env) -> BytesN<32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::TokenWasmHash)
+            .expect("not initialized")
+    }
+
+    pub fn vesting_wasm_hash(env: Env) -> BytesN<32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::VestingWasmHash)
+            .expect("not initialized")
+    }
+
+    pub fn sale_factory(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::SaleFactory)
+            .expect("not initialized")
+    }
+
+    pub fn registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Registry)
+    }
+
+    pub fn wasm_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::WasmRegistry)
+    }
+
+    pub fn pause_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PauseRegistry)
+    }
+
+    /// Fee recipient launches require of the sale factory, if any.
+    pub fn required_fee_recipient(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::RequiredFeeRecipient)
+    }
+
+    /// Number of launches made through this factory.
+    pub fn launch_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::LaunchCount)
+            .unwrap_or(0)
+    }
+
+    /// Registry entry of the `index`-th launch.
+    pub fn get_launch(env: Env, index: u32) -> LaunchRecord {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Launch(index))
+            .expect("no launch found")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _require_admin(env: &Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized");
+        admin.require_auth();
+    }
+
+    fn _salt(env: &Env, creator: &Address, salt: &BytesN<32>, part: &str) -> BytesN<32> {
+        env.crypto()
+            .sha256(&(creator.clone(), salt.clone(), Symbol::new(env, part)).to_xdr(env))
+            .into()
+    }
+
+    fn _validate_config(config: &LaunchConfig) {
+        let supply = config.token.total_supply;
+        assert!(supply > 0, "total_supply must be positive");
+        if let Some(cap) = config.token.max_supply {
+            assert!(supply <= cap, "total_supply exceeds max_supply");
+        }
+        assert!(
+            config.sale_allocation > 0,
+            "sale_allocation must be positive"
+        );
+
+        let mut allocated = config.sale_allocation;
+        for allocation in config.vesting.iter() {
+            assert!(allocation.amount > 0, "vesting amount must be positive");
+            allocated += allocation.amount;
+        }
+        assert!(allocated <= supply, "allocations exceed total supply");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        testutils::Address as _, testutils::MockAuth, testutils::MockAuthInvoke, vec, IntoVal,
+    };
+
+    // Launching deploys wasm, which native unit tests don't have, so these
+    // tests cover the factory's configuration and validation. `launch`
+    // itself runs in tests/integration/tests/launch_factory.rs.
+
+    fn setup() -> (Env, LaunchFactoryClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, LaunchFactory);
+        let client = LaunchFactoryClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(
+            &admin,
+            &BytesN::from_array(&env, &[1u8; 32]),
+            &BytesN::from_array(&env, &[2u8; 32]),
+            &Address::generate(&env),
+        );
+
+        (env, client, admin)
+    }
+
+    /// 1 000 000 supply: 200 000 vesting, 500 000 for sale.
+    fn config(env: &Env) -> LaunchConfig {
+        let placeholder = Address::generate(env);
+        LaunchConfig {
+            token: TokenParams {
+                name: String::from_str(env, "Demo"),
+                symbol: String::from_str(env, "DEMO"),
+                decimals: 7,
+                total_supply: 1_000_000,
+                max_supply: Some(2_000_000),
+            },
+            vesting: vec![
+                env,
+                VestingAllocation {
+                    recipient: Address::generate(env),
+                    amount: 200_000,
+                    cliff_ledger: 1_000,
+                    end_ledger: 5_000,
+                },
+            ],
+            vesting_creators: Vec::new(env),
+            sale_allocation: 500_000,
+            sale: SaleParams {
+                token: placeholder.clone(),
+                payment_token: Address::generate(env),
+                price: 10_000_000,
+                soft_cap: 1_000,
+                hard_cap: 5_000,
+                min_contribution: 10,
+                max_contribution: 3_000,
+                start_ledger: 100,
+                end_ledger: 200,
+                mode: SaleMode::FixedPrice,
+                payout: Payout::Immediate,
+                tier_pricing: Map::new(env),
+                tier_source: TierSource::Admin,
+                rounds: Vec::new(env),
+                extension: Extension::Disabled,
+                liquidity: Liquidity::Disabled,
+                project: ProjectInfo {
+                    name: String::from_str(env, "Demo"),
+                    token: placeholder,
+                    website_hash: BytesN::from_array(env, &[1u8; 32]),
+                    docs_hash: BytesN::from_array(env, &[2u8; 32]),
+                    socials_hash: BytesN::from_array(env, &[3u8; 32]),
+                    audited_by: Vec::new(env),
+                },
+            },
+        }
+    }
+
+    fn launch(env: &Env, client: &LaunchFactoryClient, config: &LaunchConfig) {
+        client.launch(
+            &Address::generate(env),
+            &BytesN::from_array(env, &[9u8; 32]),
+            config,
+        );
+    }
+
+    #[test]
+    fn test_initialize_and_getters() {
+        let (env, client, admin) = setup();
+        assert_eq!(client.admin(), admin);
+        assert_eq!(
+            client.token_wasm_hash(),
+            BytesN::from_array(&env, &[1u8; 32])
+        );
+        assert_eq!(
+            client.vesting_wasm_hash(),
+            BytesN::from_array(&env, &[2u8; 32])
+        );
+        assert_eq!(client.launch_count(), 0);
+        assert_eq!(client.registry(), None);
+    }
+
+    #[test]
+    fn test_set_registry() {
+        let (env, client, _) = setup();
+        let registry = Address::generate(&env);
+        client.set_registry(&Some(registry.clone()));
+        assert_eq!(client.registry(), Some(registry));
+        client.set_registry(&None);
+        assert_eq!(client.registry(), None);
+    }
+
+    #[test]
+    fn test_set_wiring_targets() {
+        let (env, client, _) = setup();
+        let (registry, treasury) = (Address::generate(&env), Address::generate(&env));
+        client.set_pause_registry(&Some(registry.clone()));
+        client.set_required_fee_recipient(&Some(treasury.clone()));
+        assert_eq!(client.pause_registry(), Some(registry));
+        assert_eq!(client.required_fee_recipient(), Some(treasury));
+
+        client.set_pause_registry(&None);
+        client.set_required_fee_recipient(&None);
+        assert_eq!(client.pause_registry(), None);
+        assert_eq!(client.required_fee_recipient(), None);
+
+        let wasm_registry = Address::generate(&env);
+        client.set_wasm_registry(&Some(wasm_registry.clone()));
+        assert_eq!(client.wasm_registry(), Some(wasm_registry));
+    }
+
+    #[test]
+    #[should_panic(expected = "already initialized")]
+    fn test_double_init_panics() {
+        let (env, client, admin) = setup();
+        let hash = BytesN::from_array(&env, &[0u8; 32]);
+        client.initialize(&admin, &hash, &hash, &Address::generate(&env));
+    }
+
+    #[test]
+    fn test_set_wasm_hashes() {
+        let (env, client, _) = setup();
+        let token_hash = BytesN::from_array(&env, &[5u8; 32]);
+        let vesting_hash = BytesN::from_array(&env, &[6u8; 32]);
+        client.set_wasm_hashes(&token_hash, &vesting_hash);
+        assert_eq!(client.token_wasm_hash(), token_hash);
+        assert_eq!(client.vesting_wasm_hash(), vesting_hash);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_non_admin_cannot_set_wasm_hashes() {
+        let (env, client, _) = setup();
+        let hash = BytesN::from_array(&env, &[5u8; 32]);
+        let outsider = Address::generate(&env);
+        env.mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_wasm_hashes",
+                args: (hash.clone(), hash.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        client.set_wasm_hashes(&hash, &hash);
+    }
+
+    #[test]
+    #[should_panic(expected = "allocations exceed total supply")]
+    fn test_over_allocation_panics() {
+        let (env, client, _) = setup();
+        let mut config = config(&env);
+        config.sale_allocation = 900_000;
+        launch(&env, &client, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "total_supply exceeds max_supply")]
+    fn test_supply_above_cap_panics() {
+        let (env, client, _) = setup();
+        let mut config = config(&env);
+        config.token.max_supply = Some(999_999);
+        launch(&env, &client, &config);
+    }
+
+    #[test]
+    #[should_panic(expected = "sale_allocation must be positive")]
+    fn test_empty_sale_allocation_panics() {
+        let (env, client, _) = setup();
+        let mut config = config(&env);
+        config.sale_allocation = 0;
+        launch(&env, &client, &config);
+    }
+}
@@ -0,0 +1,381 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, symbol_short, token, Address, Env,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Vesting,
+    /// Token the schedule vests.
+    Underlying,
+    Receipt,
+    /// Holder whose schedule was wrapped.
+    WrappedBy,
+}
+
+/// Mirror of the vesting contract's schedule.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct VestingSchedule {
+    pub recipient: Address,
+    pub total_amount: i128,
+    pub cliff_ledger: u32,
+    pub end_ledger: u32,
+    pub released: i128,
+    pub revoked: bool,
+}
+
+/// Custody interface of the vesting contract.
+#[contractclient(name = "VestingClient")]
+pub trait Vesting {
+    fn transfer_schedule(env: Env, caller: Address, from: Address, to: Address);
+
+    fn release(env: Env, recipient: Address);
+
+    fn vested_amount(env: Env, recipient: Address) -> i128;
+
+    fn get_schedule(env: Env, recipient: Address) -> VestingSchedule;
+
+    fn admin(env: Env) -> Address;
+}
+
+/// Minting interface of the receipt token, which must have this contract
+/// as its admin.
+#[contractclient(name = "ReceiptClient")]
+pub trait Receipt {
+    fn mint(env: Env, to: Address, amount: i128);
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Liquid Vesting Contract — a vesting schedule as a transferable token.
+///
+/// Each instance wraps one schedule, since the vesting contract holds one
+/// schedule per address. The holder `wrap`s their schedule: it moves into
+/// this contract's custody and the holder is minted one receipt token for
+/// every token still unreleased. Receipts are ordinary tokens, so they can
+/// be traded, pooled or posted as collateral while the allocation is still
+/// locked.
+///
+/// Anyone holding receipts can `redeem` them 1:1 for underlying tokens
+/// once that much has unlocked; redeeming releases whatever has vested
+/// first. Wrapping moves the schedule with `transfer_schedule`, so it only
+/// works for projects whose vesting admin opted in with
+/// `set_transferable`. If the project revokes the schedule, only what had
+/// vested backs the receipts, and they are redeemed first come, first
+/// served.
+#[contract]
+pub struct LiquidVestingContract;
+
+#[contractimpl]
+impl LiquidVestingContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Wrap a schedule of `vesting`, which vests `underlying`, into
+    /// `receipt`. Needs the vesting admin's authorization, so nobody but
+    /// the project can set up a wrapper for its schedules.
+    pub fn initialize(env: Env, vesting: Address, underlying: Address, receipt: Address) {
+        if env.storage().instance().has(&DataKey::Vesting) {
+            panic!("already initialized");
+        }
+        VestingClient::new(&env, &vesting).admin().require_auth();
+        env.storage().instance().set(&DataKey::Vesting, &vesting);
+        env.storage()
+            .instance()
+            .set(&DataKey::Underlying, &underlying);
+        env.storage().instance().set(&DataKey::Receipt, &receipt);
+    }
+
+    // ── Wrapping ────────────────────────────────────────────────────────
+
+    /// Take custody of `holder`'s schedule and mint them a receipt for
+    /// every unreleased token. Returns the receipts minted.
+    pub fn wrap(env: Env, holder: Address) -> i128 {
+        holder.require_auth();
+        assert!(
+            !env.storage().instance().has(&DataKey::WrappedBy),
+            "already wrapped"
+        );
+        let this = env.current_contract_address();
+        let vesting = VestingClient::new(&env, &Self::vesting(env.clone()));
+        vesting.transfer_schedule(&holder, &holder, &this);
+
+        let schedule = vesting.get_schedule(&this);
+        assert!(!schedule.revoked, "schedule has been revoked");
+        let minted = schedule.total_amount - schedule.released;
+        assert!(minted > 0, "nothing to wrap");
+        env.storage().instance().set(&DataKey::WrappedBy, &holder);
+        ReceiptClient::new(&env, &Self::receipt(env.clone())).mint(&holder, &minted);
+
+        env.events()
+            .publish((symbol_short!("wrap"), holder), minted);
+        minted
+    }
+
+    /// Burn `amount` of `holder`'s receipts for as many underlying tokens,
+    /// releasing whatever has vested first.
+    pub fn redeem(env: Env, holder: Address, amount: i128) {
+        holder.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        assert!(amount <= Self::redeemable(env.clone()), "not yet unlocked");
+        let this = env.current_contract_address();
+        let underlying = token::Client::new(&env, &Self::underlying(env.clone()));
+        if underlying.balance(&this) < amount {
+            VestingClient::new(&env, &Self::vesting(env.clone())).release(&this);
+        }
+
+        token::Client::new(&env, &Self::receipt(env.clone())).burn(&holder, &amount);
+        underlying.transfer(&this, &holder, &amount);
+
+        env.events()
+            .publish((symbol_short!("redeem"), holder), amount);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Underlying tokens receipts can be redeemed for right now: what is
+    /// held here plus what has vested and not been released.
+    pub fn redeemable(env: Env) -> i128 {
+        let this = env.current_contract_address();
+        let held = token::Client::new(&env, &Self::underlying(env.clone())).balance(&this);
+        if Self::wrapped_by(env.clone()).is_none() {
+            return held;
+        }
+        let vesting = VestingClient::new(&env, &Self::vesting(env.clone()));
+        let schedule = vesting.get_schedule(&this);
+        if schedule.revoked {
+            return held;
+        }
+        held + vesting.vested_amount(&this) - schedule.released
+    }
+
+    pub fn wrapped_by(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::WrappedBy)
+    }
+
+    pub fn vesting(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Vesting)
+            .expect("not initialized")
+    }
+
+    pub fn underlying(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Underlying)
+            .expect("not initialized")
+    }
+
+    pub fn receipt(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Receipt)
+            .expect("not initialized")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::testutils::Address as _;
+
+    /// Custody and release rules of the vesting contract, with the vested
+    /// amount set by hand.
+    #[contract]
+    struct MockVesting;
+
+    #[contracttype]
+    enum MockKey {
+        Admin,
+        Token,
+        Vested(Address),
+        Schedule(Address),
+    }
+
+    #[contractimpl]
+    impl MockVesting {
+        pub fn create(env: Env, token: Address, recipient: Address, total_amount: i128) {
+            let schedule = VestingSchedule {
+                recipient: recipient.clone(),
+                total_amount,
+                cliff_ledger: 0,
+                end_ledger: 1,
+                released: 0,
+                revoked: false,
+            };
+            env.storage().instance().set(&MockKey::Token, &token);
+            env.storage()
+                .instance()
+                .set(&MockKey::Schedule(recipient), &schedule);
+        }
+
+        pub fn set_admin(env: Env, admin: Address) {
+            env.storage().instance().set(&MockKey::Admin, &admin);
+        }
+
+        pub fn admin(env: Env) -> Address {
+            env.storage().instance().get(&MockKey::Admin).unwrap()
+        }
+
+        pub fn set_vested(env: Env, recipient: Address, vested: i128) {
+            env.storage()
+                .instance()
+                .set(&MockKey::Vested(recipient), &vested);
+        }
+
+        pub fn transfer_schedule(env: Env, caller: Address, from: Address, to: Address) {
+            caller.require_auth();
+            assert!(caller == from);
+            let mut schedule = Self::get_schedule(env.clone(), from.clone());
+            schedule.recipient = to.clone();
+            let vested = Self::vested_amount(env.clone(), from.clone());
+            env.storage()
+                .instance()
+                .remove(&MockKey::Schedule(from.clone()));
+            env.storage()
+                .instance()
+                .set(&MockKey::Schedule(to.clone()), &schedule);
+            Self::set_vested(env, to, vested);
+        }
+
+        pub fn release(env: Env, recipient: Address) {
+            let mut schedule = Self::get_schedule(env.clone(), recipient.clone());
+            let releasable =
+                Self::vested_amount(env.clone(), recipient.clone()) - schedule.released;
+            assert!(releasable > 0, "nothing to release");
+            schedule.released += releasable;
+            env.storage()
+                .instance()
+                .set(&MockKey::Schedule(recipient.clone()), &schedule);
+            let token: Address = env.storage().instance().get(&MockKey::Token).unwrap();
+            token::Client::new(&env, &token).transfer(
+                &env.current_contract_address(),
+                &recipient,
+                &releasable,
+            );
+        }
+
+        pub fn vested_amount(env: Env, recipient: Address) -> i128 {
+            env.storage()
+                .instance()
+                .get(&MockKey::Vested(recipient))
+                .unwrap_or(0)
+        }
+
+        pub fn get_schedule(env: Env, recipient: Address) -> VestingSchedule {
+            env.storage()
+                .instance()
+                .get(&MockKey::Schedule(recipient))
+                .expect("no schedule found")
+        }
+    }
+
+    struct Setup {
+        env: Env,
+        client: LiquidVestingContractClient<'static>,
+        vesting: MockVestingClient<'static>,
+        underlying: TokenFixture,
+        receipt: TokenFixture,
+        holder: Address,
+    }
+
+    /// The holder has a 1 000-token schedule; the receipt token is a
+    /// Stellar asset administered by the wrapper.
+    fn setup() -> Setup {
+        let env = test_env();
+
+        let underlying = TokenFixture::stellar_asset(&env);
+        let vesting = MockVestingClient::new(&env, &env.register_contract(None, MockVesting));
+        underlying.mint(&vesting.address, 1_000);
+        let holder = Address::generate(&env);
+        vesting.create(&underlying.address, &holder, &1_000);
+        vesting.set_admin(&Address::generate(&env));
+
+        let client = LiquidVestingContractClient::new(
+            &env,
+            &env.register_contract(None, LiquidVestingContract),
+        );
+        let receipt = TokenFixture::stellar_asset_of(&env, client.address.clone());
+        client.initialize(&vesting.address, &underlying.address, &receipt.address);
+
+        Setup {
+            underlying,
+            receipt,
+            env,
+            client,
+            vesting,
+            holder,
+        }
+    }
+
+    #[test]
+    fn test_receipts_redeem_as_schedule_unlocks() {
+        let s = setup();
+        assert_eq!(s.client.wrap(&s.holder), 1_000);
+        assert_eq!(s.receipt.balance(&s.holder), 1_000);
+        assert_eq!(
+            s.vesting.get_schedule(&s.client.address).recipient,
+            s.client.address
+        );
+
+        // Receipts change hands; the new owner redeems what has unlocked
+        let buyer = Address::generate(&s.env);
+        s.receipt.client.transfer(&s.holder, &buyer, &600);
+        s.vesting.set_vested(&s.client.address, &400);
+        assert_eq!(s.client.redeemable(), 400);
+        s.client.redeem(&buyer, &300);
+        s.client.redeem(&s.holder, &100);
+
+        assert_eq!(s.underlying.balance(&buyer), 300);
+        assert_eq!(s.underlying.balance(&s.holder), 100);
+        assert_eq!(s.receipt.balance(&buyer), 300);
+        assert_eq!(s.client.redeemable(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "not yet unlocked")]
+    fn test_cannot_redeem_locked_tokens() {
+        let s = setup();
+        s.client.wrap(&s.holder);
+        s.vesting.set_vested(&s.client.address, &100);
+        s.client.redeem(&s.holder, &101);
+    }
+
+    #[test]
+    #[should_panic(expected = "already wrapped")]
+    fn test_wraps_one_schedule() {
+        let s = setup();
+        s.client.wrap(&s.holder);
+        s.client.wrap(&s.holder);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_initialize_needs_vesting_admin() {
+        let s = setup();
+        let client = LiquidVestingContractClient::new(
+            &s.env,
+            &s.env.register_contract(None, LiquidVestingContract),
+        );
+        s.env.mock_auths(&[]);
+        client.initialize(
+            &s.vesting.address,
+            &s.underlying.address,
+            &s.receipt.address,
+        );
+    }
+}
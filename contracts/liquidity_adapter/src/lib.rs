@@ -0,0 +1,458 @@
+#![no_std]
+
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractclient, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
+    IntoVal, Symbol,
+};
+
+pub mod soroswap;
+
+use soroswap::RouterClient;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Router,
+}
+
+// ---------------------------------------------------------------------------
+// Interface
+// ---------------------------------------------------------------------------
+
+/// DEX-agnostic liquidity interface. The sale's auto-LP flow and buyback
+/// call any adapter implementing it, so neither hard-codes a DEX.
+///
+/// Every function pulls its input from `from` with a plain token
+/// `transfer` and delivers its output back to `from`.
+#[contractclient(name = "LiquidityAdapterClient")]
+pub trait LiquidityAdapter {
+    /// Deposit up to `amount_a` of `token_a` and `amount_b` of `token_b`,
+    /// minting LP tokens to `from`. Whatever the pool does not take is
+    /// returned. Returns the LP amount.
+    fn add_liquidity(
+        env: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        amount_a: i128,
+        amount_b: i128,
+    ) -> i128;
+
+    /// Burn `lp_amount` LP tokens of the `token_a`/`token_b` pool for at
+    /// least `min_a` and `min_b` of the underlying tokens. Returns the
+    /// amounts received.
+    fn remove_liquidity(
+        env: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        lp_amount: i128,
+        min_a: i128,
+        min_b: i128,
+    ) -> (i128, i128);
+
+    /// Swap exactly `amount_in` of `token_in` for at least `min_out` of
+    /// `token_out`. Returns the amount received.
+    fn swap(
+        env: Env,
+        from: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        min_out: i128,
+    ) -> i128;
+
+    /// LP token of the `token_a`/`token_b` pool.
+    fn lp_token(env: Env, token_a: Address, token_b: Address) -> Address;
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Soroswap Adapter — `LiquidityAdapter` backed by a Soroswap router.
+///
+/// Inputs are pulled from the caller into the adapter, which then acts as
+/// the router's `to` account: it pre-authorizes the router's exact token
+/// pulls, and forwards LP tokens, swap output and unused deposits back to
+/// the caller. The adapter never holds funds between calls.
+#[contract]
+pub struct SoroswapAdapter;
+
+#[contractimpl]
+impl SoroswapAdapter {
+    /// Set the Soroswap router to trade through.
+    pub fn initialize(env: Env, router: Address) {
+        if env.storage().instance().has(&DataKey::Router) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Router, &router);
+
+        env.events().publish((symbol_short!("init"),), router);
+    }
+
+    pub fn router(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Router)
+            .expect("not initialized")
+    }
+}
+
+#[contractimpl]
+impl LiquidityAdapter for SoroswapAdapter {
+    fn add_liquidity(
+        env: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        amount_a: i128,
+        amount_b: i128,
+    ) -> i128 {
+        from.require_auth();
+        assert!(amount_a > 0 && amount_b > 0, "amounts must be positive");
+        let this = env.current_contract_address();
+        let router = RouterClient::new(&env, &Self::router(env.clone()));
+        token::Client::new(&env, &token_a).transfer(&from, &this, &amount_a);
+        token::Client::new(&env, &token_b).transfer(&from, &this, &amount_b);
+
+        let (reserve_a, reserve_b) = match router.try_router_get_reserves(&token_a, &token_b) {
+            Ok(Ok(reserves)) => reserves,
+            _ => (0, 0),
+        };
+        let (used_a, used_b) = soroswap::optimal_amounts(amount_a, amount_b, reserve_a, reserve_b);
+        let pair = router.router_pair_for(&token_a, &token_b);
+        _authorize_transfer(&env, &token_a, &pair, used_a);
+        _authorize_transfer(&env, &token_b, &pair, used_b);
+        let (_, _, lp_amount) = router.add_liquidity(
+            &token_a,
+            &token_b,
+            &used_a,
+            &used_b,
+            &used_a,
+            &used_b,
+            &this,
+            &env.ledger().timestamp(),
+        );
+
+        token::Client::new(&env, &pair).transfer(&this, &from, &lp_amount);
+        _return_unused(&env, &token_a, &from, amount_a - used_a);
+        _return_unused(&env, &token_b, &from, amount_b - used_b);
+
+        env.events()
+            .publish((symbol_short!("add_liq"), from), (pair, lp_amount));
+        lp_amount
+    }
+
+    fn remove_liquidity(
+        env: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        lp_amount: i128,
+        min_a: i128,
+        min_b: i128,
+    ) -> (i128, i128) {
+        from.require_auth();
+        assert!(lp_amount > 0, "amount must be positive");
+        let this = env.current_contract_address();
+        let router = RouterClient::new(&env, &Self::router(env.clone()));
+        let pair = router.router_pair_for(&token_a, &token_b);
+        token::Client::new(&env, &pair).transfer(&from, &this, &lp_amount);
+
+        _authorize_transfer(&env, &pair, &pair, lp_amount);
+        let (out_a, out_b) = router.remove_liquidity(
+            &token_a,
+            &token_b,
+            &lp_amount,
+            &min_a,
+            &min_b,
+            &this,
+            &env.ledger().timestamp(),
+        );
+        token::Client::new(&env, &token_a).transfer(&this, &from, &out_a);
+        token::Client::new(&env, &token_b).transfer(&this, &from, &out_b);
+
+        env.events()
+            .publish((symbol_short!("rm_liq"), from), (pair, lp_amount));
+        (out_a, out_b)
+    }
+
+    fn swap(
+        env: Env,
+        from: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        min_out: i128,
+    ) -> i128 {
+        from.require_auth();
+        assert!(amount_in > 0, "amount must be positive");
+        let this = env.current_contract_address();
+        let router = RouterClient::new(&env, &Self::router(env.clone()));
+        token::Client::new(&env, &token_in).transfer(&from, &this, &amount_in);
+
+        let pair = router.router_pair_for(&token_in, &token_out);
+        _authorize_transfer(&env, &token_in, &pair, amount_in);
+        let amounts = router.swap_exact_tokens_for_tokens(
+            &amount_in,
+            &min_out,
+            &vec![&env, token_in, token_out.clone()],
+            &this,
+            &env.ledger().timestamp(),
+        );
+        let out = amounts.last().expect("empty swap result");
+        assert!(out >= min_out, "insufficient output");
+        token::Client::new(&env, &token_out).transfer(&this, &from, &out);
+
+        env.events()
+            .publish((symbol_short!("swap"), from), (amount_in, out));
+        out
+    }
+
+    fn lp_token(env: Env, token_a: Address, token_b: Address) -> Address {
+        RouterClient::new(&env, &Self::router(env.clone())).router_pair_for(&token_a, &token_b)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Internals
+// ---------------------------------------------------------------------------
+
+/// Let the router move exactly `amount` of `token` from the adapter to
+/// `to` within the current call.
+fn _authorize_transfer(env: &Env, token: &Address, to: &Address, amount: i128) {
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (env.current_contract_address(), to.clone(), amount).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+}
+
+fn _return_unused(env: &Env, token: &Address, to: &Address, amount: i128) {
+    if amount > 0 {
+        token::Client::new(env, token).transfer(&env.current_contract_address(), to, &amount);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, Vec};
+
+    /// A single-pool Soroswap router. Deposits go to the pair, like the real
+    /// router's, and LP tokens are minted 1:1 with the `token_a` deposited.
+    /// As admin of every token, it mints whatever it pays out.
+    #[contract]
+    struct MockRouter;
+
+    #[contractimpl]
+    impl MockRouter {
+        pub fn set_pair(env: Env, pair: Address) {
+            env.storage().instance().set(&0u32, &pair);
+        }
+
+        pub fn router_pair_for(env: Env, _token_a: Address, _token_b: Address) -> Address {
+            env.storage().instance().get(&0u32).unwrap()
+        }
+
+        pub fn router_get_reserves(env: Env, token_a: Address, token_b: Address) -> (i128, i128) {
+            let pair = Self::router_pair_for(env.clone(), token_a.clone(), token_b.clone());
+            let reserve_a = token::Client::new(&env, &token_a).balance(&pair);
+            let reserve_b = token::Client::new(&env, &token_b).balance(&pair);
+            assert!(reserve_a > 0, "pair not found");
+            (reserve_a, reserve_b)
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        pub fn add_liquidity(
+            env: Env,
+            token_a: Address,
+            token_b: Address,
+            amount_a_desired: i128,
+            amount_b_desired: i128,
+            _amount_a_min: i128,
+            _amount_b_min: i128,
+            to: Address,
+            _deadline: u64,
+        ) -> (i128, i128, i128) {
+            to.require_auth();
+            let pair = Self::router_pair_for(env.clone(), token_a.clone(), token_b.clone());
+            token::Client::new(&env, &token_a).transfer(&to, &pair, &amount_a_desired);
+            token::Client::new(&env, &token_b).transfer(&to, &pair, &amount_b_desired);
+            StellarAssetClient::new(&env, &pair).mint(&to, &amount_a_desired);
+            (amount_a_desired, amount_b_desired, amount_a_desired)
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        pub fn remove_liquidity(
+            env: Env,
+            token_a: Address,
+            token_b: Address,
+            liquidity: i128,
+            _amount_a_min: i128,
+            _amount_b_min: i128,
+            to: Address,
+            _deadline: u64,
+        ) -> (i128, i128) {
+            to.require_auth();
+            let pair = Self::router_pair_for(env.clone(), token_a.clone(), token_b.clone());
+            token::Client::new(&env, &pair).transfer(&to, &pair, &liquidity);
+            StellarAssetClient::new(&env, &token_a).mint(&to, &liquidity);
+            StellarAssetClient::new(&env, &token_b).mint(&to, &(liquidity * 2));
+            (liquidity, liquidity * 2)
+        }
+
+        /// Pays out two `token_out` per `token_in`.
+        pub fn swap_exact_tokens_for_tokens(
+            env: Env,
+            amount_in: i128,
+            _amount_out_min: i128,
+            path: Vec<Address>,
+            to: Address,
+            _deadline: u64,
+        ) -> Vec<i128> {
+            to.require_auth();
+            let token_in = path.get(0).unwrap();
+            let token_out = path.get(1).unwrap();
+            let pair = Self::router_pair_for(env.clone(), token_in.clone(), token_out.clone());
+            token::Client::new(&env, &token_in).transfer(&to, &pair, &amount_in);
+            StellarAssetClient::new(&env, &token_out).mint(&to, &(amount_in * 2));
+            vec![&env, amount_in, amount_in * 2]
+        }
+    }
+
+    struct Setup {
+        env: Env,
+        adapter: LiquidityAdapterClient<'static>,
+        token_a: TokenFixture,
+        token_b: TokenFixture,
+        pair: TokenFixture,
+        user: Address,
+    }
+
+    /// The user holds 10 000 of each token; the pool is empty.
+    fn setup() -> Setup {
+        let env = test_env();
+
+        let router_id = env.register_contract(None, MockRouter);
+        let pair = TokenFixture::stellar_asset_of(&env, router_id.clone());
+        MockRouterClient::new(&env, &router_id).set_pair(&pair.address);
+        let adapter_id = env.register_contract(None, SoroswapAdapter);
+        SoroswapAdapterClient::new(&env, &adapter_id).initialize(&router_id);
+
+        let user = Address::generate(&env);
+        let token_a = TokenFixture::stellar_asset_of(&env, router_id.clone());
+        let token_b = TokenFixture::stellar_asset_of(&env, router_id.clone());
+        token_a.mint(&user, 10_000);
+        token_b.mint(&user, 10_000);
+
+        Setup {
+            adapter: LiquidityAdapterClient::new(&env, &adapter_id),
+            token_a,
+            token_b,
+            pair,
+            env,
+            user,
+        }
+    }
+
+    fn add(s: &Setup, amount_a: i128, amount_b: i128) -> i128 {
+        s.adapter.add_liquidity(
+            &s.user,
+            &s.token_a.address,
+            &s.token_b.address,
+            &amount_a,
+            &amount_b,
+        )
+    }
+
+    #[test]
+    fn test_optimal_amounts() {
+        assert_eq!(soroswap::optimal_amounts(100, 300, 0, 0), (100, 300));
+        assert_eq!(
+            soroswap::optimal_amounts(100, 300, 1_000, 2_000),
+            (100, 200)
+        );
+        assert_eq!(soroswap::optimal_amounts(100, 100, 1_000, 2_000), (50, 100));
+    }
+
+    #[test]
+    fn test_add_liquidity_returns_unused() {
+        let s = setup();
+        assert_eq!(add(&s, 1_000, 2_000), 1_000);
+        assert_eq!(s.pair.balance(&s.user), 1_000);
+
+        // The pool is now 1:2, so 600 of token B is not needed
+        assert_eq!(add(&s, 500, 1_600), 500);
+        assert_eq!(s.token_a.balance(&s.user), 8_500);
+        assert_eq!(s.token_b.balance(&s.user), 7_000);
+        assert_eq!(s.token_b.balance(&s.adapter.address), 0);
+        assert_eq!(
+            s.adapter.lp_token(&s.token_a.address, &s.token_b.address),
+            s.pair.address
+        );
+    }
+
+    #[test]
+    fn test_remove_liquidity() {
+        let s = setup();
+        add(&s, 1_000, 2_000);
+        let out = s.adapter.remove_liquidity(
+            &s.user,
+            &s.token_a.address,
+            &s.token_b.address,
+            &400,
+            &0,
+            &0,
+        );
+        assert_eq!(out, (400, 800));
+        assert_eq!(s.pair.balance(&s.user), 600);
+        assert_eq!(s.token_a.balance(&s.user), 9_400);
+        assert_eq!(s.token_b.balance(&s.user), 8_800);
+    }
+
+    #[test]
+    fn test_swap() {
+        let s = setup();
+        add(&s, 1_000, 2_000);
+        let out = s
+            .adapter
+            .swap(&s.user, &s.token_a.address, &s.token_b.address, &100, &150);
+        assert_eq!(out, 200);
+        assert_eq!(s.token_a.balance(&s.user), 8_900);
+        assert_eq!(s.token_b.balance(&s.user), 8_200);
+    }
+
+    #[test]
+    #[should_panic(expected = "insufficient output")]
+    fn test_swap_below_min_out_panics() {
+        let s = setup();
+        add(&s, 1_000, 2_000);
+        s.adapter
+            .swap(&s.user, &s.token_a.address, &s.token_b.address, &100, &201);
+    }
+
+    #[test]
+    #[should_panic(expected = "already initialized")]
+    fn test_double_init_panics() {
+        let s = setup();
+        SoroswapAdapterClient::new(&s.env, &s.adapter.address).initialize(&s.pair.address);
+    }
+}
@@ -0,0 +1,73 @@
+//! Soroswap router interface and the pool maths the adapter mirrors.
+//!
+//! The router pulls tokens from the adapter with exact `transfer` calls, and
+//! a contract can only pre-authorize calls whose arguments it knows. The
+//! adapter therefore works out the amounts the router will take with the
+//! router's own formula before calling it.
+
+use soroban_sdk::{contractclient, Address, Env, Vec};
+
+/// The subset of the Soroswap router interface the adapter calls.
+#[contractclient(name = "RouterClient")]
+pub trait SoroswapRouter {
+    #[allow(clippy::too_many_arguments)]
+    fn add_liquidity(
+        env: Env,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> (i128, i128, i128);
+
+    #[allow(clippy::too_many_arguments)]
+    fn remove_liquidity(
+        env: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> (i128, i128);
+
+    fn swap_exact_tokens_for_tokens(
+        env: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Vec<i128>;
+
+    /// Pair contract of `token_a`/`token_b`, which is also its LP token.
+    fn router_pair_for(env: Env, token_a: Address, token_b: Address) -> Address;
+
+    /// Reserves of the pair, ordered like the arguments. Fails if the pair
+    /// does not exist yet.
+    fn router_get_reserves(env: Env, token_a: Address, token_b: Address) -> (i128, i128);
+}
+
+/// Amounts the router deposits for the desired amounts, given the pool's
+/// reserves: all of one side and the matching part of the other, or both in
+/// full for an empty pool.
+pub(crate) fn optimal_amounts(
+    amount_a: i128,
+    amount_b: i128,
+    reserve_a: i128,
+    reserve_b: i128,
+) -> (i128, i128) {
+    if reserve_a == 0 && reserve_b == 0 {
+        return (amount_a, amount_b);
+    }
+    let b_optimal = amount_a * reserve_b / reserve_a;
+    if b_optimal <= amount_b {
+        (amount_a, b_optimal)
+    } else {
+        (amount_b * reserve_a / reserve_b, amount_b)
+    }
+}
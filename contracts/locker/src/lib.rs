@@ -0,0 +1,399 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, token, Address, Env, String, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    LockCount,
+    Lock(u64),
+    BeneficiaryLocks(Address),
+    TokenLocks(Address),
+    /// Amount of a token held by locks not yet withdrawn.
+    TokenLocked(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Lock {
+    pub id: u64,
+    /// Account the tokens were pulled from.
+    pub funder: Address,
+    /// Only account that can extend, describe and withdraw the lock.
+    pub beneficiary: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub created_ledger: u32,
+    /// First ledger on which the owner can withdraw.
+    pub unlock_ledger: u32,
+    /// Free-form label shown to buyers, e.g. "team allocation" or "XLM/TKN LP".
+    pub description: String,
+    pub withdrawn: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Locker Contract — provable token and liquidity locks.
+///
+/// Anyone can `lock` any token until a ledger of their choosing: team
+/// allocations, LP positions, treasury reserves. The tokens sit in this
+/// contract until `unlock_ledger`, after which only the beneficiary can
+/// `withdraw` them. `extend_lock` pushes a lock further out; nothing
+/// shortens one.
+///
+/// `lock` matches the locker interface sales use for their LP tokens, so a
+/// sale can lock liquidity here on behalf of its admin.
+///
+/// Every lock is public, so buyers can check how much of a token is locked,
+/// by whom and until when, without trusting the project's word for it.
+#[contract]
+pub struct LockerContract;
+
+#[contractimpl]
+impl LockerContract {
+    // ── Locking ─────────────────────────────────────────────────────────
+
+    /// Move `amount` of `token` from `from` into a new lock for
+    /// `beneficiary` that opens at `unlock_ledger`. Returns the lock id.
+    pub fn lock(
+        env: Env,
+        from: Address,
+        token: Address,
+        amount: i128,
+        unlock_ledger: u32,
+        beneficiary: Address,
+    ) -> u64 {
+        from.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        assert!(
+            unlock_ledger > env.ledger().sequence(),
+            "unlock ledger must be in the future"
+        );
+
+        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+
+        let id = Self::lock_count(env.clone());
+        let lock = Lock {
+            id,
+            funder: from,
+            beneficiary: beneficiary.clone(),
+            token: token.clone(),
+            amount,
+            created_ledger: env.ledger().sequence(),
+            unlock_ledger,
+            description: String::from_str(&env, ""),
+            withdrawn: false,
+        };
+        env.storage().persistent().set(&DataKey::Lock(id), &lock);
+        env.storage().instance().set(&DataKey::LockCount, &(id + 1));
+        Self::_push_id(&env, DataKey::BeneficiaryLocks(beneficiary.clone()), id);
+        Self::_push_id(&env, DataKey::TokenLocks(token.clone()), id);
+        Self::_add_locked(&env, &token, amount);
+
+        env.events().publish(
+            (symbol_short!("lock"), beneficiary, token),
+            (id, amount, unlock_ledger),
+        );
+        id
+    }
+
+    // ── Beneficiary actions ─────────────────────────────────────────────
+
+    /// Label lock `lock_id` for buyers, e.g. "team allocation" or
+    /// "XLM/TKN LP". Beneficiary only.
+    pub fn describe(env: Env, lock_id: u64, description: String) {
+        let mut lock = Self::get_lock(env.clone(), lock_id);
+        lock.beneficiary.require_auth();
+        lock.description = description;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Lock(lock_id), &lock);
+    }
+
+    /// Push the unlock ledger of lock `lock_id` further out. Beneficiary
+    /// only.
+    pub fn extend_lock(env: Env, lock_id: u64, unlock_ledger: u32) {
+        let mut lock = Self::get_lock(env.clone(), lock_id);
+        lock.beneficiary.require_auth();
+        assert!(!lock.withdrawn, "already withdrawn");
+        assert!(
+            unlock_ledger > lock.unlock_ledger,
+            "lock can only be extended"
+        );
+        lock.unlock_ledger = unlock_ledger;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Lock(lock_id), &lock);
+
+        env.events().publish(
+            (symbol_short!("extend"), lock.beneficiary),
+            (lock_id, unlock_ledger),
+        );
+    }
+
+    /// Pay the tokens of an expired lock to its beneficiary. Beneficiary
+    /// only.
+    pub fn withdraw(env: Env, lock_id: u64) {
+        let mut lock = Self::get_lock(env.clone(), lock_id);
+        lock.beneficiary.require_auth();
+        assert!(!lock.withdrawn, "already withdrawn");
+        assert!(
+            env.ledger().sequence() >= lock.unlock_ledger,
+            "still locked"
+        );
+        lock.withdrawn = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Lock(lock_id), &lock);
+        Self::_add_locked(&env, &lock.token, -lock.amount);
+
+        token::Client::new(&env, &lock.token).transfer(
+            &env.current_contract_address(),
+            &lock.beneficiary,
+            &lock.amount,
+        );
+
+        env.events().publish(
+            (symbol_short!("withdraw"), lock.beneficiary),
+            (lock_id, lock.amount),
+        );
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn get_lock(env: Env, lock_id: u64) -> Lock {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Lock(lock_id))
+            .expect("no lock found")
+    }
+
+    /// Whether lock `lock_id` still holds its tokens and cannot be
+    /// withdrawn yet.
+    pub fn is_locked(env: Env, lock_id: u64) -> bool {
+        let lock = Self::get_lock(env.clone(), lock_id);
+        !lock.withdrawn && env.ledger().sequence() < lock.unlock_ledger
+    }
+
+    /// Ids of every lock held for `beneficiary`, oldest first.
+    pub fn locks_of(env: Env, beneficiary: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BeneficiaryLocks(beneficiary))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Ids of every lock of `token`, oldest first.
+    pub fn locks_for_token(env: Env, token: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TokenLocks(token))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Amount of `token` held by locks that have not been withdrawn.
+    pub fn total_locked(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TokenLocked(token))
+            .unwrap_or(0)
+    }
+
+    pub fn lock_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::LockCount)
+            .unwrap_or(0)
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _push_id(env: &Env, key: DataKey, id: u64) {
+        let mut ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        ids.push_back(id);
+        env.storage().persistent().set(&key, &ids);
+    }
+
+    fn _add_locked(env: &Env, token: &Address, delta: i128) {
+        let locked = Self::total_locked(env.clone(), token.clone()) + delta;
+        env.storage()
+            .persistent()
+            .set(&DataKey::TokenLocked(token.clone()), &locked);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{
+        testutils::Address as _, testutils::Ledger, testutils::MockAuth, testutils::MockAuthInvoke,
+        IntoVal,
+    };
+
+    struct Setup {
+        env: Env,
+        client: LockerContractClient<'static>,
+        token: TokenFixture,
+        owner: Address,
+    }
+
+    /// The owner holds 10 000 tokens; the ledger starts at 10.
+    fn setup() -> Setup {
+        let env = test_env();
+        env.ledger().set_sequence_number(10);
+
+        let token = TokenFixture::stellar_asset(&env);
+        let owner = Address::generate(&env);
+        token.mint(&owner, 10_000);
+        let contract_id = env.register_contract(None, LockerContract);
+
+        Setup {
+            client: LockerContractClient::new(&env, &contract_id),
+            token,
+            env,
+            owner,
+        }
+    }
+
+    fn lock(s: &Setup, amount: i128, unlock_ledger: u32) -> u64 {
+        s.client.lock(
+            &s.owner,
+            &s.token.address,
+            &amount,
+            &unlock_ledger,
+            &s.owner,
+        )
+    }
+
+    #[test]
+    fn test_lock_and_withdraw() {
+        let s = setup();
+        let id = lock(&s, 4_000, 100);
+        assert_eq!(s.token.balance(&s.owner), 6_000);
+        assert_eq!(s.token.balance(&s.client.address), 4_000);
+        assert!(s.client.is_locked(&id));
+        assert_eq!(s.client.total_locked(&s.token.address), 4_000);
+
+        let description = String::from_str(&s.env, "team allocation");
+        s.client.describe(&id, &description);
+        let lock = s.client.get_lock(&id);
+        assert_eq!(lock.created_ledger, 10);
+        assert_eq!(lock.description, description);
+
+        s.env.ledger().set_sequence_number(100);
+        assert!(!s.client.is_locked(&id));
+        s.client.withdraw(&id);
+        assert_eq!(s.token.balance(&s.owner), 10_000);
+        assert_eq!(s.client.total_locked(&s.token.address), 0);
+        assert!(s.client.get_lock(&id).withdrawn);
+        assert!(s.client.try_withdraw(&id).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "still locked")]
+    fn test_withdraw_before_unlock_panics() {
+        let s = setup();
+        let id = lock(&s, 4_000, 100);
+        s.env.ledger().set_sequence_number(99);
+        s.client.withdraw(&id);
+    }
+
+    #[test]
+    fn test_extend_lock() {
+        let s = setup();
+        let id = lock(&s, 4_000, 100);
+        s.client.extend_lock(&id, &500);
+        assert_eq!(s.client.get_lock(&id).unlock_ledger, 500);
+
+        s.env.ledger().set_sequence_number(100);
+        assert!(s.client.is_locked(&id));
+        assert!(s.client.try_withdraw(&id).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "lock can only be extended")]
+    fn test_shortening_lock_panics() {
+        let s = setup();
+        let id = lock(&s, 4_000, 100);
+        s.client.extend_lock(&id, &50);
+    }
+
+    #[test]
+    #[should_panic(expected = "unlock ledger must be in the future")]
+    fn test_lock_in_past_panics() {
+        let s = setup();
+        lock(&s, 4_000, 10);
+    }
+
+    #[test]
+    fn test_lock_queries() {
+        let s = setup();
+        let first = lock(&s, 1_000, 100);
+        let second = lock(&s, 2_000, 200);
+        assert_eq!(s.client.lock_count(), 2);
+        assert_eq!(s.client.locks_of(&s.owner).len(), 2);
+        assert_eq!(
+            s.client.locks_for_token(&s.token.address).get(1),
+            Some(second)
+        );
+        assert_eq!(s.client.total_locked(&s.token.address), 3_000);
+
+        s.env.ledger().set_sequence_number(100);
+        s.client.withdraw(&first);
+        assert_eq!(s.client.total_locked(&s.token.address), 2_000);
+        assert!(s.client.locks_of(&Address::generate(&s.env)).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_only_owner_can_withdraw() {
+        let s = setup();
+        let id = lock(&s, 4_000, 100);
+        s.env.ledger().set_sequence_number(100);
+        let outsider = Address::generate(&s.env);
+        s.env.mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "withdraw",
+                args: (id,).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.withdraw(&id);
+    }
+
+    #[test]
+    fn test_lock_for_beneficiary() {
+        let s = setup();
+        let beneficiary = Address::generate(&s.env);
+        let id = s
+            .client
+            .lock(&s.owner, &s.token.address, &4_000, &100, &beneficiary);
+        assert_eq!(s.client.get_lock(&id).funder, s.owner);
+        assert!(s.client.locks_of(&s.owner).is_empty());
+        assert_eq!(s.client.locks_of(&beneficiary).get(0), Some(id));
+
+        s.env.ledger().set_sequence_number(100);
+        s.client.withdraw(&id);
+        assert_eq!(s.token.balance(&beneficiary), 4_000);
+        assert_eq!(s.token.balance(&s.owner), 6_000);
+    }
+}
@@ -0,0 +1,409 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Val, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Signers,
+    Threshold,
+    TransactionCount,
+    Transaction(u32),
+    Confirmed(u32, Address),
+}
+
+/// A contract call made when a transaction is executed.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Action {
+    pub contract: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+}
+
+/// What a transaction does once enough signers confirmed it. Changes to the
+/// signer set are applied by the multisig itself, as a contract cannot
+/// call itself.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Operation {
+    Call(Action),
+    AddSigner(Address),
+    RemoveSigner(Address),
+    SetThreshold(u32),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Transaction {
+    pub id: u32,
+    pub proposer: Address,
+    pub operation: Operation,
+    pub executed: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Multisig Contract — M-of-N admin for the launchpad contracts.
+///
+/// Set as the admin of the token, vesting and sale contracts, it replaces a
+/// single admin key: any signer can `submit` a transaction, which counts as
+/// its own confirmation, other signers `confirm` it (or take it back with
+/// `revoke_confirmation`), and once `threshold` current signers have
+/// confirmed, anyone can `execute` it.
+///
+/// A transaction either calls another contract, whose admin checks pass
+/// because the multisig is the caller, or changes the signer set or the
+/// threshold.
+#[contract]
+pub struct MultisigContract;
+
+#[contractimpl]
+impl MultisigContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the signers and how many of them must confirm a transaction.
+    pub fn initialize(env: Env, signers: Vec<Address>, threshold: u32) {
+        if env.storage().instance().has(&DataKey::Signers) {
+            panic!("already initialized");
+        }
+        for (i, signer) in signers.iter().enumerate() {
+            assert!(
+                signers.first_index_of(&signer) == Some(i as u32),
+                "duplicate signer"
+            );
+        }
+        Self::_validate_threshold(threshold, signers.len());
+
+        env.storage().instance().set(&DataKey::Signers, &signers);
+        env.storage()
+            .instance()
+            .set(&DataKey::Threshold, &threshold);
+        env.storage()
+            .instance()
+            .set(&DataKey::TransactionCount, &0u32);
+
+        env.events()
+            .publish((symbol_short!("init"),), (signers, threshold));
+    }
+
+    // ── Signer actions ──────────────────────────────────────────────────
+
+    /// Propose `operation` and confirm it as `signer`. Returns the
+    /// transaction id.
+    pub fn submit(env: Env, signer: Address, operation: Operation) -> u32 {
+        Self::_require_signer(&env, &signer);
+
+        let id = Self::transaction_count(env.clone());
+        let transaction = Transaction {
+            id,
+            proposer: signer.clone(),
+            operation,
+            executed: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Transaction(id), &transaction);
+        env.storage()
+            .instance()
+            .set(&DataKey::TransactionCount, &(id + 1));
+
+        env.events()
+            .publish((symbol_short!("submit"), signer.clone()), id);
+        Self::_confirm(&env, &signer, id);
+        id
+    }
+
+    /// Confirm transaction `tx_id` as `signer`.
+    pub fn confirm(env: Env, signer: Address, tx_id: u32) {
+        Self::_require_signer(&env, &signer);
+        Self::_confirm(&env, &signer, tx_id);
+    }
+
+    /// Withdraw `signer`'s confirmation of a pending transaction.
+    pub fn revoke_confirmation(env: Env, signer: Address, tx_id: u32) {
+        Self::_require_signer(&env, &signer);
+        let transaction = Self::get_transaction(env.clone(), tx_id);
+        assert!(!transaction.executed, "already executed");
+        let key = DataKey::Confirmed(tx_id, signer.clone());
+        assert!(env.storage().persistent().has(&key), "not confirmed");
+        env.storage().persistent().remove(&key);
+
+        env.events()
+            .publish((symbol_short!("revoke"), signer), tx_id);
+    }
+
+    // ── Execution ───────────────────────────────────────────────────────
+
+    /// Carry out transaction `tx_id` once enough current signers have
+    /// confirmed it. Anyone may call.
+    pub fn execute(env: Env, tx_id: u32) {
+        let mut transaction = Self::get_transaction(env.clone(), tx_id);
+        assert!(!transaction.executed, "already executed");
+        assert!(
+            Self::confirmations(env.clone(), tx_id) >= Self::threshold(env.clone()),
+            "not enough confirmations"
+        );
+        transaction.executed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Transaction(tx_id), &transaction);
+
+        match transaction.operation {
+            Operation::Call(action) => {
+                env.invoke_contract::<Val>(&action.contract, &action.function, action.args);
+            }
+            Operation::AddSigner(signer) => {
+                let mut signers = Self::signers(env.clone());
+                assert!(!signers.contains(&signer), "already a signer");
+                signers.push_back(signer);
+                env.storage().instance().set(&DataKey::Signers, &signers);
+            }
+            Operation::RemoveSigner(signer) => {
+                let mut signers = Self::signers(env.clone());
+                let index = signers.first_index_of(&signer).expect("not a signer");
+                signers.remove(index);
+                Self::_validate_threshold(Self::threshold(env.clone()), signers.len());
+                env.storage().instance().set(&DataKey::Signers, &signers);
+            }
+            Operation::SetThreshold(threshold) => {
+                Self::_validate_threshold(threshold, Self::signers(env.clone()).len());
+                env.storage()
+                    .instance()
+                    .set(&DataKey::Threshold, &threshold);
+            }
+        }
+
+        env.events().publish((symbol_short!("execute"),), tx_id);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn get_transaction(env: Env, tx_id: u32) -> Transaction {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Transaction(tx_id))
+            .expect("no transaction found")
+    }
+
+    /// Number of current signers that confirmed transaction `tx_id`.
+    pub fn confirmations(env: Env, tx_id: u32) -> u32 {
+        let mut count = 0;
+        for signer in Self::signers(env.clone()).iter() {
+            if Self::is_confirmed(env.clone(), tx_id, signer) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    pub fn is_confirmed(env: Env, tx_id: u32, signer: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Confirmed(tx_id, signer))
+    }
+
+    pub fn transaction_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TransactionCount)
+            .expect("not initialized")
+    }
+
+    pub fn signers(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Signers)
+            .expect("not initialized")
+    }
+
+    pub fn threshold(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Threshold)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _require_signer(env: &Env, signer: &Address) {
+        signer.require_auth();
+        assert!(Self::signers(env.clone()).contains(signer), "not a signer");
+    }
+
+    fn _confirm(env: &Env, signer: &Address, tx_id: u32) {
+        let transaction = Self::get_transaction(env.clone(), tx_id);
+        assert!(!transaction.executed, "already executed");
+        let key = DataKey::Confirmed(tx_id, signer.clone());
+        assert!(!env.storage().persistent().has(&key), "already confirmed");
+        env.storage().persistent().set(&key, &true);
+
+        env.events()
+            .publish((symbol_short!("confirm"), signer.clone()), tx_id);
+    }
+
+    fn _validate_threshold(threshold: u32, signer_count: u32) {
+        assert!(threshold > 0, "threshold must be positive");
+        assert!(threshold <= signer_count, "threshold exceeds signers");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, vec, IntoVal};
+
+    /// A contract administered by the multisig.
+    #[contract]
+    struct MockTarget;
+
+    #[contractimpl]
+    impl MockTarget {
+        pub fn init(env: Env, admin: Address) {
+            env.storage().instance().set(&0u32, &admin);
+        }
+
+        pub fn set_value(env: Env, value: u32) {
+            let admin: Address = env.storage().instance().get(&0u32).unwrap();
+            admin.require_auth();
+            env.storage().instance().set(&1u32, &value);
+        }
+
+        pub fn value(env: Env) -> u32 {
+            env.storage().instance().get(&1u32).unwrap_or(0)
+        }
+    }
+
+    struct Setup {
+        env: Env,
+        client: MultisigContractClient<'static>,
+        target: MockTargetClient<'static>,
+        signers: Vec<Address>,
+    }
+
+    /// Two of three signers; the target's admin is the multisig.
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let signers = vec![
+            &env,
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        let contract_id = env.register_contract(None, MultisigContract);
+        let client = MultisigContractClient::new(&env, &contract_id);
+        client.initialize(&signers, &2);
+        let target = MockTargetClient::new(&env, &env.register_contract(None, MockTarget));
+        target.init(&contract_id);
+
+        Setup {
+            env,
+            client,
+            target,
+            signers,
+        }
+    }
+
+    fn signer(s: &Setup, i: u32) -> Address {
+        s.signers.get(i).unwrap()
+    }
+
+    fn set_value(s: &Setup, value: u32) -> Operation {
+        Operation::Call(Action {
+            contract: s.target.address.clone(),
+            function: Symbol::new(&s.env, "set_value"),
+            args: vec![&s.env, value.into_val(&s.env)],
+        })
+    }
+
+    #[test]
+    fn test_call_executes_at_threshold() {
+        let s = setup();
+        let id = s.client.submit(&signer(&s, 0), &set_value(&s, 7));
+        assert_eq!(s.client.confirmations(&id), 1);
+        assert!(s.client.try_execute(&id).is_err());
+
+        s.client.confirm(&signer(&s, 2), &id);
+        s.client.execute(&id);
+        assert_eq!(s.target.value(), 7);
+        assert!(s.client.get_transaction(&id).executed);
+        assert!(s.client.try_execute(&id).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "not enough confirmations")]
+    fn test_revoked_confirmation_blocks_execution() {
+        let s = setup();
+        let id = s.client.submit(&signer(&s, 0), &set_value(&s, 7));
+        s.client.confirm(&signer(&s, 1), &id);
+        s.client.revoke_confirmation(&signer(&s, 1), &id);
+        s.client.execute(&id);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a signer")]
+    fn test_outsider_cannot_confirm() {
+        let s = setup();
+        let id = s.client.submit(&signer(&s, 0), &set_value(&s, 7));
+        s.client.confirm(&Address::generate(&s.env), &id);
+    }
+
+    #[test]
+    #[should_panic(expected = "already confirmed")]
+    fn test_double_confirmation_panics() {
+        let s = setup();
+        let id = s.client.submit(&signer(&s, 0), &set_value(&s, 7));
+        s.client.confirm(&signer(&s, 0), &id);
+    }
+
+    #[test]
+    fn test_signer_set_changes() {
+        let s = setup();
+        let newcomer = Address::generate(&s.env);
+        let id = s
+            .client
+            .submit(&signer(&s, 0), &Operation::AddSigner(newcomer.clone()));
+        s.client.confirm(&signer(&s, 1), &id);
+        s.client.execute(&id);
+        assert_eq!(s.client.signers().len(), 4);
+
+        let id = s.client.submit(&newcomer, &Operation::SetThreshold(3));
+        s.client.confirm(&signer(&s, 0), &id);
+        s.client.execute(&id);
+        assert_eq!(s.client.threshold(), 3);
+
+        // A removed signer's confirmation no longer counts
+        let call = s.client.submit(&signer(&s, 2), &set_value(&s, 7));
+        s.client.confirm(&newcomer, &call);
+        let id = s
+            .client
+            .submit(&signer(&s, 0), &Operation::RemoveSigner(signer(&s, 2)));
+        s.client.confirm(&signer(&s, 1), &id);
+        s.client.confirm(&newcomer, &id);
+        s.client.execute(&id);
+        assert_eq!(s.client.confirmations(&call), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "threshold exceeds signers")]
+    fn test_threshold_above_signers_panics() {
+        let s = setup();
+        let id = s.client.submit(&signer(&s, 0), &Operation::SetThreshold(4));
+        s.client.confirm(&signer(&s, 1), &id);
+        s.client.execute(&id);
+    }
+}
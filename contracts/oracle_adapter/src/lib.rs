@@ -0,0 +1,260 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, symbol_short, Address, Env, Symbol,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Feed(Address),
+}
+
+/// Where the price of an asset comes from.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Feed {
+    pub oracle: Address,
+    /// How the oracle names the asset.
+    pub oracle_asset: Asset,
+    /// Oldest price, in seconds, that is still accepted.
+    pub max_age: u64,
+}
+
+/// Mirror of the SEP-40 (Reflector) oracle `Asset`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// Mirror of the SEP-40 (Reflector) oracle `PriceData`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// The subset of the SEP-40 oracle interface the adapter reads.
+#[contractclient(name = "OracleClient")]
+pub trait PriceFeed {
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+    fn decimals(env: Env) -> u32;
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Oracle Adapter — one price interface over external oracle feeds.
+///
+/// The admin maps each asset to an oracle contract following the SEP-40
+/// interface used by Reflector, plus the name the oracle uses for it and
+/// how old a price may be. Consumers then call `price(asset)` and get
+/// `(price, decimals, timestamp)` whatever the underlying feed, and never
+/// see a price older than the feed's `max_age`.
+#[contract]
+pub struct OracleAdapter;
+
+#[contractimpl]
+impl OracleAdapter {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, admin: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+
+        env.events().publish((symbol_short!("init"),), admin);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Price `asset` from `feed`, replacing any previous feed. Admin only.
+    pub fn set_feed(env: Env, asset: Address, feed: Feed) {
+        Self::_require_admin(&env);
+        assert!(feed.max_age > 0, "max_age must be positive");
+        env.storage()
+            .persistent()
+            .set(&DataKey::Feed(asset.clone()), &feed);
+
+        env.events().publish((symbol_short!("feed"), asset), feed);
+    }
+
+    /// Stop pricing `asset`. Admin only.
+    pub fn remove_feed(env: Env, asset: Address) {
+        Self::_require_admin(&env);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Feed(asset.clone()));
+
+        env.events().publish((symbol_short!("unfeed"), asset), ());
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Latest price of `asset` as `(price, decimals, timestamp)`. Panics if
+    /// the asset has no feed, the oracle has no price or the price is older
+    /// than the feed's `max_age`.
+    pub fn price(env: Env, asset: Address) -> (i128, u32, u64) {
+        let feed = Self::get_feed(env.clone(), asset).expect("no feed for asset");
+        let oracle = OracleClient::new(&env, &feed.oracle);
+        let data = oracle.lastprice(&feed.oracle_asset).expect("no price");
+        assert!(data.price > 0, "invalid price");
+        let now = env.ledger().timestamp();
+        assert!(
+            data.timestamp <= now && now - data.timestamp <= feed.max_age,
+            "stale price"
+        );
+        (data.price, oracle.decimals(), data.timestamp)
+    }
+
+    pub fn get_feed(env: Env, asset: Address) -> Option<Feed> {
+        env.storage().persistent().get(&DataKey::Feed(asset))
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _require_admin(env: &Env) {
+        Self::admin(env.clone()).require_auth();
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        testutils::Address as _, testutils::Ledger, testutils::MockAuth, testutils::MockAuthInvoke,
+        IntoVal,
+    };
+
+    /// A SEP-40 oracle with one settable price and 14 decimals.
+    #[contract]
+    struct MockOracle;
+
+    #[contractimpl]
+    impl MockOracle {
+        pub fn set_price(env: Env, asset: Asset, price: i128, timestamp: u64) {
+            env.storage()
+                .instance()
+                .set(&asset, &PriceData { price, timestamp });
+        }
+
+        pub fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
+            env.storage().instance().get(&asset)
+        }
+
+        pub fn decimals(_env: Env) -> u32 {
+            14
+        }
+    }
+
+    struct Setup {
+        env: Env,
+        client: OracleAdapterClient<'static>,
+        oracle: MockOracleClient<'static>,
+        asset: Address,
+        oracle_asset: Asset,
+    }
+
+    /// One asset fed by the mock oracle, accepting prices up to 300 s old.
+    /// The ledger clock is at 10 000.
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(10_000);
+
+        let contract_id = env.register_contract(None, OracleAdapter);
+        let client = OracleAdapterClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+        let oracle = MockOracleClient::new(&env, &env.register_contract(None, MockOracle));
+        let asset = Address::generate(&env);
+        let oracle_asset = Asset::Other(Symbol::new(&env, "USDC"));
+        client.set_feed(
+            &asset,
+            &Feed {
+                oracle: oracle.address.clone(),
+                oracle_asset: oracle_asset.clone(),
+                max_age: 300,
+            },
+        );
+
+        Setup {
+            env,
+            client,
+            oracle,
+            asset,
+            oracle_asset,
+        }
+    }
+
+    #[test]
+    fn test_price_is_normalized() {
+        let s = setup();
+        s.oracle
+            .set_price(&s.oracle_asset, &99_000_000_000_000, &9_800);
+        assert_eq!(s.client.price(&s.asset), (99_000_000_000_000, 14, 9_800));
+    }
+
+    #[test]
+    #[should_panic(expected = "stale price")]
+    fn test_stale_price_panics() {
+        let s = setup();
+        s.oracle.set_price(&s.oracle_asset, &1, &9_699);
+        s.client.price(&s.asset);
+    }
+
+    #[test]
+    #[should_panic(expected = "no price")]
+    fn test_missing_price_panics() {
+        let s = setup();
+        s.client.price(&s.asset);
+    }
+
+    #[test]
+    #[should_panic(expected = "no feed for asset")]
+    fn test_removed_feed_panics() {
+        let s = setup();
+        s.oracle.set_price(&s.oracle_asset, &1, &10_000);
+        s.client.remove_feed(&s.asset);
+        assert_eq!(s.client.get_feed(&s.asset), None);
+        s.client.price(&s.asset);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_non_admin_cannot_set_feed() {
+        let s = setup();
+        let feed = s.client.get_feed(&s.asset).unwrap();
+        let outsider = Address::generate(&s.env);
+        s.env.mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "set_feed",
+                args: (s.asset.clone(), feed.clone()).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.set_feed(&s.asset, &feed);
+    }
+}
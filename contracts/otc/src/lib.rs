@@ -0,0 +1,360 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env, Vec};
+use soropad_math::mul_div_ceil;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    OfferCount,
+    Offer(u32),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum OfferStatus {
+    Open,
+    /// Sold out; nothing is held any more.
+    Filled,
+    /// Withdrawn by the maker, who got the unsold remainder back.
+    Cancelled,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Offer {
+    pub id: u32,
+    pub maker: Address,
+    /// Token the maker sells, escrowed in this contract.
+    pub sell_token: Address,
+    pub sell_amount: i128,
+    /// Token the maker asks for.
+    pub buy_token: Address,
+    /// Price of the whole `sell_amount`; partial fills pay pro rata.
+    pub buy_amount: i128,
+    /// Addresses allowed to fill; empty means anyone.
+    pub takers: Vec<Address>,
+    /// Last ledger on which the offer can be filled.
+    pub expiry_ledger: u32,
+    /// Amount of `sell_token` sold so far.
+    pub filled: i128,
+    pub status: OfferStatus,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// OTC Contract — large trades at a fixed rate, off the DEX.
+///
+/// A maker `create_offer`s `sell_amount` of one token for `buy_amount` of
+/// another, escrowing the sold side here. Takers `fill` all or part of it
+/// before `expiry_ledger`: their payment goes straight to the maker and the
+/// sold tokens to them in the same call, so neither side can be left short.
+/// Partial fills pay pro rata, rounded up in the maker's favour.
+///
+/// An offer can be restricted to a list of takers, e.g. a fund negotiated
+/// with off-chain. The maker can `cancel` at any time, including after
+/// expiry, to take back whatever is unsold.
+#[contract]
+pub struct OtcContract;
+
+#[contractimpl]
+impl OtcContract {
+    // ── Makers ──────────────────────────────────────────────────────────
+
+    /// Offer `sell_amount` of `sell_token` for `buy_amount` of `buy_token`
+    /// to `takers` (anyone if empty) until `expiry_ledger`. Moves the sold
+    /// tokens into escrow. Returns the offer id.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_offer(
+        env: Env,
+        maker: Address,
+        sell_token: Address,
+        sell_amount: i128,
+        buy_token: Address,
+        buy_amount: i128,
+        takers: Vec<Address>,
+        expiry_ledger: u32,
+    ) -> u32 {
+        maker.require_auth();
+        assert!(
+            sell_amount > 0 && buy_amount > 0,
+            "amounts must be positive"
+        );
+        assert!(sell_token != buy_token, "same token on both sides");
+        assert!(
+            expiry_ledger >= env.ledger().sequence(),
+            "expiry in the past"
+        );
+
+        token::Client::new(&env, &sell_token).transfer(
+            &maker,
+            &env.current_contract_address(),
+            &sell_amount,
+        );
+
+        let id = Self::offer_count(env.clone());
+        let offer = Offer {
+            id,
+            maker: maker.clone(),
+            sell_token,
+            sell_amount,
+            buy_token,
+            buy_amount,
+            takers,
+            expiry_ledger,
+            filled: 0,
+            status: OfferStatus::Open,
+        };
+        Self::_save(&env, &offer);
+        env.storage()
+            .instance()
+            .set(&DataKey::OfferCount, &(id + 1));
+
+        env.events().publish(
+            (symbol_short!("offer"), maker),
+            (id, sell_amount, buy_amount),
+        );
+        id
+    }
+
+    /// Withdraw offer `offer_id` and return the unsold tokens. Maker only.
+    pub fn cancel(env: Env, offer_id: u32) {
+        let mut offer = Self::get_offer(env.clone(), offer_id);
+        offer.maker.require_auth();
+        assert!(offer.status == OfferStatus::Open, "offer not open");
+
+        let remaining = offer.sell_amount - offer.filled;
+        offer.status = OfferStatus::Cancelled;
+        Self::_save(&env, &offer);
+        token::Client::new(&env, &offer.sell_token).transfer(
+            &env.current_contract_address(),
+            &offer.maker,
+            &remaining,
+        );
+
+        env.events().publish(
+            (symbol_short!("cancel"), offer.maker),
+            (offer_id, remaining),
+        );
+    }
+
+    // ── Takers ──────────────────────────────────────────────────────────
+
+    /// Buy `amount` of the sold token from offer `offer_id`. Returns the
+    /// payment sent to the maker.
+    pub fn fill(env: Env, taker: Address, offer_id: u32, amount: i128) -> i128 {
+        taker.require_auth();
+        let mut offer = Self::get_offer(env.clone(), offer_id);
+        assert!(offer.status == OfferStatus::Open, "offer not open");
+        assert!(
+            env.ledger().sequence() <= offer.expiry_ledger,
+            "offer expired"
+        );
+        assert!(
+            offer.takers.is_empty() || offer.takers.contains(&taker),
+            "taker not allowed"
+        );
+        assert!(amount > 0, "amount must be positive");
+        assert!(
+            amount <= offer.sell_amount - offer.filled,
+            "exceeds remaining"
+        );
+
+        let payment = Self::_payment(&offer, amount);
+        offer.filled += amount;
+        if offer.filled == offer.sell_amount {
+            offer.status = OfferStatus::Filled;
+        }
+        Self::_save(&env, &offer);
+
+        token::Client::new(&env, &offer.buy_token).transfer(&taker, &offer.maker, &payment);
+        token::Client::new(&env, &offer.sell_token).transfer(
+            &env.current_contract_address(),
+            &taker,
+            &amount,
+        );
+
+        env.events().publish(
+            (symbol_short!("fill"), offer.maker, taker),
+            (offer_id, amount, payment),
+        );
+        payment
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Payment needed to buy `amount` from offer `offer_id`.
+    pub fn quote(env: Env, offer_id: u32, amount: i128) -> i128 {
+        Self::_payment(&Self::get_offer(env, offer_id), amount)
+    }
+
+    /// Amount still for sale on offer `offer_id`.
+    pub fn remaining(env: Env, offer_id: u32) -> i128 {
+        let offer = Self::get_offer(env, offer_id);
+        match offer.status {
+            OfferStatus::Open => offer.sell_amount - offer.filled,
+            _ => 0,
+        }
+    }
+
+    /// Offers `start` to `start + limit - 1`, oldest first.
+    pub fn offers(env: Env, start: u32, limit: u32) -> Vec<Offer> {
+        let end = start
+            .saturating_add(limit)
+            .min(Self::offer_count(env.clone()));
+        let mut offers = Vec::new(&env);
+        for id in start..end {
+            offers.push_back(Self::get_offer(env.clone(), id));
+        }
+        offers
+    }
+
+    pub fn get_offer(env: Env, offer_id: u32) -> Offer {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Offer(offer_id))
+            .expect("no offer found")
+    }
+
+    pub fn offer_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::OfferCount)
+            .unwrap_or(0)
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _save(env: &Env, offer: &Offer) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Offer(offer.id), offer);
+    }
+
+    fn _payment(offer: &Offer, amount: i128) -> i128 {
+        mul_div_ceil(amount, offer.buy_amount, offer.sell_amount)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger, vec};
+
+    struct Setup {
+        env: Env,
+        client: OtcContractClient<'static>,
+        sell: TokenFixture,
+        buy: TokenFixture,
+        maker: Address,
+    }
+
+    fn setup() -> Setup {
+        let env = test_env();
+        env.ledger().set_sequence_number(100);
+
+        let sell = TokenFixture::stellar_asset(&env);
+        let buy = TokenFixture::stellar_asset(&env);
+        let client = OtcContractClient::new(&env, &env.register_contract(None, OtcContract));
+        let maker = Address::generate(&env);
+        sell.mint(&maker, 10_000);
+
+        Setup {
+            sell,
+            buy,
+            env,
+            client,
+            maker,
+        }
+    }
+
+    fn funded_taker(s: &Setup, amount: i128) -> Address {
+        let taker = Address::generate(&s.env);
+        s.buy.mint(&taker, amount);
+        taker
+    }
+
+    /// 9 000 sell tokens for 3 000 buy tokens, open to anyone until 200.
+    fn open_offer(s: &Setup, takers: Vec<Address>) -> u32 {
+        s.client.create_offer(
+            &s.maker,
+            &s.sell.address,
+            &9_000,
+            &s.buy.address,
+            &3_000,
+            &takers,
+            &200,
+        )
+    }
+
+    #[test]
+    fn test_partial_and_full_fills() {
+        let s = setup();
+        let id = open_offer(&s, vec![&s.env]);
+        assert_eq!(s.sell.balance(&s.client.address), 9_000);
+
+        let taker = funded_taker(&s, 3_000);
+        assert_eq!(s.client.fill(&taker, &id, &3_000), 1_000);
+        assert_eq!(s.client.remaining(&id), 6_000);
+        assert_eq!(s.client.fill(&taker, &id, &6_000), 2_000);
+
+        assert_eq!(s.sell.balance(&taker), 9_000);
+        assert_eq!(s.buy.balance(&s.maker), 3_000);
+        assert_eq!(s.client.get_offer(&id).status, OfferStatus::Filled);
+    }
+
+    #[test]
+    fn test_partial_fill_rounds_for_maker() {
+        let s = setup();
+        let id = open_offer(&s, vec![&s.env]);
+        // 1 sell token is worth a third of a buy token
+        assert_eq!(s.client.quote(&id, &1), 1);
+    }
+
+    #[test]
+    fn test_whitelisted_taker() {
+        let s = setup();
+        let fund = funded_taker(&s, 3_000);
+        let id = open_offer(&s, vec![&s.env, fund.clone()]);
+
+        let outsider = funded_taker(&s, 3_000);
+        assert!(s.client.try_fill(&outsider, &id, &900).is_err());
+        s.client.fill(&fund, &id, &900);
+        assert_eq!(s.sell.balance(&fund), 900);
+    }
+
+    #[test]
+    #[should_panic(expected = "offer expired")]
+    fn test_expired_offer() {
+        let s = setup();
+        let id = open_offer(&s, vec![&s.env]);
+        s.env.ledger().set_sequence_number(201);
+        s.client.fill(&funded_taker(&s, 3_000), &id, &900);
+    }
+
+    #[test]
+    fn test_cancel_returns_unsold() {
+        let s = setup();
+        let id = open_offer(&s, vec![&s.env]);
+        s.client.fill(&funded_taker(&s, 3_000), &id, &3_000);
+
+        s.client.cancel(&id);
+        assert_eq!(s.sell.balance(&s.maker), 7_000);
+        assert_eq!(s.client.remaining(&id), 0);
+        assert!(s
+            .client
+            .try_fill(&funded_taker(&s, 3_000), &id, &3_000)
+            .is_err());
+    }
+}
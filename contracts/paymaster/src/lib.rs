@@ -0,0 +1,304 @@
+#![no_std]
+
+use launchpad_access as access;
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, token, Address, Env, Symbol,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// The native XLM token fees are reimbursed in.
+    FeeToken,
+    Relayer(Address),
+    Operation(Symbol),
+    /// Sponsorships an account has used for an operation.
+    Used(Symbol, Address),
+}
+
+/// Terms an operation is sponsored on.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Sponsorship {
+    /// Largest fee reimbursed for one submission, in stroops.
+    pub max_fee: i128,
+    /// Submissions sponsored per account.
+    pub quota: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Paymaster Contract — fee sponsorship for new users.
+///
+/// Transaction fees are paid by whoever submits a transaction, so the
+/// paymaster works through approved relayers. A relayer wraps a user's
+/// transaction in a fee bump, paying its fee, and adds a `sponsor` call
+/// that the user signs together with the operation itself. The paymaster
+/// then reimburses the relayer from the XLM it holds.
+///
+/// Only operations the admin lists are sponsored, e.g. `first_claim` or
+/// `first_contrib`, each with a fee cap and a per-account quota. Because
+/// the user has to authorize `sponsor`, a relayer cannot spend a user's
+/// quota without the user, and the quota bounds what any one account can
+/// cost the platform.
+#[contract]
+pub struct PaymasterContract;
+
+#[contractimpl]
+impl PaymasterContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the admin and the native XLM token fees are reimbursed in.
+    pub fn initialize(env: Env, admin: Address, fee_token: Address) {
+        access::init_admin(&env, &admin);
+        env.storage().instance().set(&DataKey::FeeToken, &fee_token);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Approve or remove `relayer`. Admin only.
+    pub fn set_relayer(env: Env, relayer: Address, allowed: bool) {
+        access::require_admin(&env);
+        let key = DataKey::Relayer(relayer.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("relayer"), relayer), allowed);
+    }
+
+    /// Sponsor `quota` submissions of `operation` per account, up to
+    /// `max_fee` each. Accounts keep the sponsorships already used. Admin
+    /// only.
+    pub fn set_operation(env: Env, operation: Symbol, max_fee: i128, quota: u32) {
+        access::require_admin(&env);
+        assert!(max_fee > 0, "max fee must be positive");
+        assert!(quota > 0, "quota must be positive");
+        let sponsorship = Sponsorship { max_fee, quota };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Operation(operation.clone()), &sponsorship);
+        env.events()
+            .publish((symbol_short!("op_set"), operation), sponsorship);
+    }
+
+    /// Stop sponsoring `operation`. Admin only.
+    pub fn remove_operation(env: Env, operation: Symbol) {
+        access::require_admin(&env);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Operation(operation.clone()));
+        env.events()
+            .publish((symbol_short!("op_rm"), operation), ());
+    }
+
+    /// Send `amount` XLM of the paymaster's funds to `to`. Admin only.
+    pub fn withdraw(env: Env, to: Address, amount: i128) {
+        access::require_admin(&env);
+        assert!(amount > 0, "amount must be positive");
+        token::Client::new(&env, &Self::fee_token(env.clone())).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount,
+        );
+        env.events()
+            .publish((symbol_short!("withdraw"), to), amount);
+    }
+
+    // ── Funding ─────────────────────────────────────────────────────────
+
+    /// Add `amount` XLM from `from` to the sponsorship budget.
+    pub fn fund(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        token::Client::new(&env, &Self::fee_token(env.clone())).transfer(
+            &from,
+            &env.current_contract_address(),
+            &amount,
+        );
+        env.events().publish((symbol_short!("fund"), from), amount);
+    }
+
+    // ── Relayers ────────────────────────────────────────────────────────
+
+    /// Reimburse `relayer` the `fee` it paid to submit `user`'s
+    /// `operation`, using one of `user`'s sponsorships. Needs both the
+    /// relayer's and the user's authorization.
+    pub fn sponsor(env: Env, relayer: Address, user: Address, operation: Symbol, fee: i128) {
+        relayer.require_auth();
+        user.require_auth();
+        assert!(
+            Self::is_relayer(env.clone(), relayer.clone()),
+            "not a relayer"
+        );
+        let sponsorship =
+            Self::operation(env.clone(), operation.clone()).expect("operation not sponsored");
+        assert!(fee > 0, "fee must be positive");
+        assert!(fee <= sponsorship.max_fee, "fee above cap");
+        let used = Self::used(env.clone(), operation.clone(), user.clone());
+        assert!(used < sponsorship.quota, "quota used up");
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Used(operation.clone(), user.clone()), &(used + 1));
+        token::Client::new(&env, &Self::fee_token(env.clone())).transfer(
+            &env.current_contract_address(),
+            &relayer,
+            &fee,
+        );
+
+        env.events()
+            .publish((symbol_short!("sponsor"), user, operation), (relayer, fee));
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Sponsored submissions of `operation` `user` has left.
+    pub fn remaining(env: Env, operation: Symbol, user: Address) -> u32 {
+        match Self::operation(env.clone(), operation.clone()) {
+            Some(sponsorship) => sponsorship
+                .quota
+                .saturating_sub(Self::used(env, operation, user)),
+            None => 0,
+        }
+    }
+
+    pub fn used(env: Env, operation: Symbol, user: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Used(operation, user))
+            .unwrap_or(0)
+    }
+
+    pub fn operation(env: Env, operation: Symbol) -> Option<Sponsorship> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Operation(operation))
+    }
+
+    pub fn is_relayer(env: Env, relayer: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Relayer(relayer))
+    }
+
+    /// XLM left to sponsor with.
+    pub fn budget(env: Env) -> i128 {
+        token::Client::new(&env, &Self::fee_token(env.clone()))
+            .balance(&env.current_contract_address())
+    }
+
+    pub fn fee_token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeeToken)
+            .expect("not initialized")
+    }
+
+    pub fn admin(env: Env) -> Address {
+        access::admin(&env)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{
+        testutils::Address as _, testutils::MockAuth, testutils::MockAuthInvoke, IntoVal,
+    };
+
+    const FIRST_CLAIM: Symbol = symbol_short!("1st_claim");
+
+    struct Setup {
+        env: Env,
+        client: PaymasterContractClient<'static>,
+        xlm: TokenFixture,
+        relayer: Address,
+    }
+
+    /// A 10 000-stroop budget sponsoring one first claim per account at up
+    /// to 500 stroops.
+    fn setup() -> Setup {
+        let env = test_env();
+
+        let xlm = TokenFixture::stellar_asset(&env);
+        let client =
+            PaymasterContractClient::new(&env, &env.register_contract(None, PaymasterContract));
+        client.initialize(&Address::generate(&env), &xlm.address);
+        let relayer = Address::generate(&env);
+        client.set_relayer(&relayer, &true);
+        client.set_operation(&FIRST_CLAIM, &500, &1);
+
+        let funder = Address::generate(&env);
+        xlm.mint(&funder, 10_000);
+        client.fund(&funder, &10_000);
+
+        Setup {
+            xlm,
+            env,
+            client,
+            relayer,
+        }
+    }
+
+    #[test]
+    fn test_sponsor_reimburses_relayer_within_quota() {
+        let s = setup();
+        let user = Address::generate(&s.env);
+        assert_eq!(s.client.remaining(&FIRST_CLAIM, &user), 1);
+
+        s.client.sponsor(&s.relayer, &user, &FIRST_CLAIM, &300);
+        assert_eq!(s.xlm.balance(&s.relayer), 300);
+        assert_eq!(s.client.budget(), 9_700);
+        assert_eq!(s.client.remaining(&FIRST_CLAIM, &user), 0);
+        assert!(s
+            .client
+            .try_sponsor(&s.relayer, &user, &FIRST_CLAIM, &300)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "fee above cap")]
+    fn test_fee_capped() {
+        let s = setup();
+        let user = Address::generate(&s.env);
+        s.client.sponsor(&s.relayer, &user, &FIRST_CLAIM, &501);
+    }
+
+    #[test]
+    #[should_panic(expected = "operation not sponsored")]
+    fn test_unlisted_operation_rejected() {
+        let s = setup();
+        let user = Address::generate(&s.env);
+        s.client
+            .sponsor(&s.relayer, &user, &symbol_short!("swap"), &100);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sponsor_needs_user_auth() {
+        let s = setup();
+        let user = Address::generate(&s.env);
+        s.env.mock_auths(&[MockAuth {
+            address: &s.relayer,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "sponsor",
+                args: (s.relayer.clone(), user.clone(), FIRST_CLAIM, 300i128).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.sponsor(&s.relayer, &user, &FIRST_CLAIM, &300);
+    }
+}
@@ -0,0 +1,324 @@
+#![no_std]
+
+use launchpad_access as access;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, symbol_short, Address, Env, Symbol,
+};
+use soropad_math::mul_div;
+
+/// Fixed-point scale of activity rates: a rate equal to `RATE_SCALE` awards
+/// one point per unit.
+pub const RATE_SCALE: i128 = 10_000_000;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Awarder(Address),
+    /// Points per `RATE_SCALE` units of an activity.
+    Rate(Symbol),
+    Balance(Address),
+    /// Points ever earned by an account, redeemed or not.
+    Earned(Address),
+    Redemption(Symbol),
+    /// Times an account has taken a redemption.
+    Redeemed(Symbol, Address),
+}
+
+/// Something points can be spent on.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Redemption {
+    pub cost: i128,
+    /// Contract told about every redemption through `PointsHook::on_redeem`.
+    pub hook: Option<Address>,
+}
+
+/// Callback of a redemption hook, e.g. a sale granting a guaranteed
+/// allocation. The points contract is the invoker, so a hook can check it
+/// with `require_auth`.
+#[contractclient(name = "PointsHookClient")]
+pub trait PointsHook {
+    fn on_redeem(env: Env, account: Address, redemption: Symbol);
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Points Contract — non-transferable loyalty points for participation.
+///
+/// Platform contracts approved as awarders `record` activity on an
+/// account's behalf: the sale records contributions when they are claimed,
+/// staking records locks in token-ledgers, and so on. Each activity earns
+/// points at a rate set by the admin; activities without a rate earn
+/// nothing, so awarders never fail because of it. Points cannot be
+/// transferred.
+///
+/// The admin defines redemptions: a named cost in points and an optional
+/// hook contract. `redeem` spends the points, counts the redemption, and
+/// calls the hook's `on_redeem`, which can grant whatever the redemption
+/// stands for, such as a guaranteed allocation in a later sale. Contracts
+/// without a hook can read `redeemed_count` instead.
+#[contract]
+pub struct PointsContract;
+
+#[contractimpl]
+impl PointsContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, admin: Address) {
+        access::init_admin(&env, &admin);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Approve or remove `awarder`. Admin only.
+    pub fn set_awarder(env: Env, awarder: Address, allowed: bool) {
+        access::require_admin(&env);
+        let key = DataKey::Awarder(awarder.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("awarder"), awarder), allowed);
+    }
+
+    /// Award `rate` points per `RATE_SCALE` units of `activity`; 0 stops
+    /// awarding it. Admin only.
+    pub fn set_rate(env: Env, activity: Symbol, rate: i128) {
+        access::require_admin(&env);
+        assert!(rate >= 0, "rate must be non-negative");
+        env.storage()
+            .persistent()
+            .set(&DataKey::Rate(activity.clone()), &rate);
+        env.events()
+            .publish((symbol_short!("rate"), activity), rate);
+    }
+
+    /// Offer redemption `name` for `cost` points, calling `hook` on each
+    /// redemption. Replaces any earlier terms. Admin only.
+    pub fn set_redemption(env: Env, name: Symbol, cost: i128, hook: Option<Address>) {
+        access::require_admin(&env);
+        assert!(cost > 0, "cost must be positive");
+        let redemption = Redemption { cost, hook };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Redemption(name.clone()), &redemption);
+        env.events()
+            .publish((symbol_short!("rdm_set"), name), redemption);
+    }
+
+    /// Withdraw redemption `name`. Admin only.
+    pub fn remove_redemption(env: Env, name: Symbol) {
+        access::require_admin(&env);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Redemption(name.clone()));
+        env.events().publish((symbol_short!("rdm_rm"), name), ());
+    }
+
+    // ── Awarders ────────────────────────────────────────────────────────
+
+    /// Record `units` of `activity` by `account` and award the points they
+    /// earn. Approved awarders only. Returns the points awarded.
+    pub fn record(
+        env: Env,
+        awarder: Address,
+        account: Address,
+        activity: Symbol,
+        units: i128,
+    ) -> i128 {
+        awarder.require_auth();
+        assert!(
+            Self::is_awarder(env.clone(), awarder.clone()),
+            "not an awarder"
+        );
+        assert!(units >= 0, "units must be non-negative");
+
+        let points = mul_div(units, Self::rate(env.clone(), activity.clone()), RATE_SCALE);
+        if points == 0 {
+            return 0;
+        }
+        let balance = Self::points_of(env.clone(), account.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Balance(account.clone()), &(balance + points));
+        let earned = Self::earned_of(env.clone(), account.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Earned(account.clone()), &(earned + points));
+
+        env.events().publish(
+            (symbol_short!("points"), account, activity),
+            (awarder, points),
+        );
+        points
+    }
+
+    // ── Holders ─────────────────────────────────────────────────────────
+
+    /// Spend points on redemption `name` and notify its hook.
+    pub fn redeem(env: Env, account: Address, name: Symbol) {
+        account.require_auth();
+        let redemption = Self::redemption(env.clone(), name.clone()).expect("no redemption found");
+        let balance = Self::points_of(env.clone(), account.clone());
+        assert!(balance >= redemption.cost, "insufficient points");
+
+        env.storage().persistent().set(
+            &DataKey::Balance(account.clone()),
+            &(balance - redemption.cost),
+        );
+        let count = Self::redeemed_count(env.clone(), name.clone(), account.clone());
+        env.storage().persistent().set(
+            &DataKey::Redeemed(name.clone(), account.clone()),
+            &(count + 1),
+        );
+        if let Some(hook) = &redemption.hook {
+            PointsHookClient::new(&env, hook).on_redeem(&account, &name);
+        }
+
+        env.events()
+            .publish((symbol_short!("redeem"), account, name), redemption.cost);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Points `account` can spend.
+    pub fn points_of(env: Env, account: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Balance(account))
+            .unwrap_or(0)
+    }
+
+    /// Points `account` has ever earned.
+    pub fn earned_of(env: Env, account: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Earned(account))
+            .unwrap_or(0)
+    }
+
+    pub fn redeemed_count(env: Env, name: Symbol, account: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Redeemed(name, account))
+            .unwrap_or(0)
+    }
+
+    pub fn redemption(env: Env, name: Symbol) -> Option<Redemption> {
+        env.storage().persistent().get(&DataKey::Redemption(name))
+    }
+
+    pub fn rate(env: Env, activity: Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Rate(activity))
+            .unwrap_or(0)
+    }
+
+    pub fn is_awarder(env: Env, awarder: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Awarder(awarder))
+    }
+
+    pub fn admin(env: Env) -> Address {
+        access::admin(&env)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    const CONTRIB: Symbol = symbol_short!("contrib");
+
+    struct Setup {
+        env: Env,
+        client: PointsContractClient<'static>,
+        awarder: Address,
+    }
+
+    /// One point per 10 units contributed.
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let client = PointsContractClient::new(&env, &env.register_contract(None, PointsContract));
+        client.initialize(&Address::generate(&env));
+        let awarder = Address::generate(&env);
+        client.set_awarder(&awarder, &true);
+        client.set_rate(&CONTRIB, &(RATE_SCALE / 10));
+
+        Setup {
+            env,
+            client,
+            awarder,
+        }
+    }
+
+    #[contract]
+    struct MockSale;
+
+    #[contractimpl]
+    impl MockSale {
+        pub fn on_redeem(env: Env, account: Address, redemption: Symbol) {
+            env.storage().instance().set(&account, &redemption);
+        }
+
+        pub fn granted(env: Env, account: Address) -> Option<Symbol> {
+            env.storage().instance().get(&account)
+        }
+    }
+
+    #[test]
+    fn test_record_awards_by_rate() {
+        let s = setup();
+        let user = Address::generate(&s.env);
+        assert_eq!(s.client.record(&s.awarder, &user, &CONTRIB, &1_000), 100);
+        assert_eq!(
+            s.client
+                .record(&s.awarder, &user, &symbol_short!("unrated"), &1_000),
+            0
+        );
+        assert_eq!(s.client.points_of(&user), 100);
+        assert_eq!(s.client.earned_of(&user), 100);
+    }
+
+    #[test]
+    fn test_redeem_spends_points_and_calls_hook() {
+        let s = setup();
+        let sale = MockSaleClient::new(&s.env, &s.env.register_contract(None, MockSale));
+        let guaranteed = symbol_short!("guarant");
+        s.client
+            .set_redemption(&guaranteed, &80, &Some(sale.address.clone()));
+
+        let user = Address::generate(&s.env);
+        s.client.record(&s.awarder, &user, &CONTRIB, &1_000);
+        s.client.redeem(&user, &guaranteed);
+
+        assert_eq!(s.client.points_of(&user), 20);
+        assert_eq!(s.client.earned_of(&user), 100);
+        assert_eq!(s.client.redeemed_count(&guaranteed, &user), 1);
+        assert_eq!(sale.granted(&user), Some(guaranteed.clone()));
+        assert!(s.client.try_redeem(&user, &guaranteed).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "not an awarder")]
+    fn test_only_awarders_record() {
+        let s = setup();
+        let outsider = Address::generate(&s.env);
+        s.client.record(&outsider, &outsider, &CONTRIB, &1_000);
+    }
+}
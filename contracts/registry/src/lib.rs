@@ -0,0 +1,403 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    /// Factory version of an allowed registrar.
+    Registrar(Address),
+    ProjectCount,
+    Project(u32),
+    ProjectByToken(Address),
+    CreatorProjects(Address),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum ProjectStatus {
+    Active,
+    Completed,
+    Cancelled,
+}
+
+/// Admin-curated flags shown next to a project.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum Flag {
+    Verified,
+    Audited,
+    /// Hidden from listings, e.g. after a scam report.
+    Delisted,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Project {
+    pub id: u32,
+    pub creator: Address,
+    pub token: Address,
+    pub vesting: Address,
+    pub sale: Address,
+    /// Factory that registered the launch, and its version at the time.
+    pub factory: Address,
+    pub factory_version: u32,
+    pub status: ProjectStatus,
+    pub verified: bool,
+    pub audited: bool,
+    pub delisted: bool,
+    pub registered_ledger: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Registry Contract — the canonical index of launchpad projects.
+///
+/// Allowed registrars, normally the launch factory, `register` every launch
+/// they make. The admin curates the index with the `verified`, `audited`
+/// and `delisted` flags, and the admin or a project's creator keeps its
+/// status up to date.
+///
+/// Explorers and the frontend page through `projects` or look a project up
+/// by token, and follow the events to stay in sync.
+#[contract]
+pub struct RegistryContract;
+
+#[contractimpl]
+impl RegistryContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, admin: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::ProjectCount, &0u32);
+
+        env.events().publish((symbol_short!("init"),), admin);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Allow `registrar` to register projects as factory `version`, or
+    /// revoke it with `None`. Admin only.
+    pub fn set_registrar(env: Env, registrar: Address, version: Option<u32>) {
+        Self::_require_admin(&env);
+        let key = DataKey::Registrar(registrar.clone());
+        match version {
+            Some(version) => env.storage().persistent().set(&key, &version),
+            None => env.storage().persistent().remove(&key),
+        }
+
+        env.events()
+            .publish((symbol_short!("registrar"), registrar), version);
+    }
+
+    /// Set or clear `flag` on project `project_id`. Admin only.
+    pub fn set_flag(env: Env, project_id: u32, flag: Flag, value: bool) {
+        Self::_require_admin(&env);
+        let mut project = Self::get_project(env.clone(), project_id);
+        match flag {
+            Flag::Verified => project.verified = value,
+            Flag::Audited => project.audited = value,
+            Flag::Delisted => project.delisted = value,
+        }
+        Self::_save(&env, &project);
+
+        env.events()
+            .publish((symbol_short!("flag"), project_id), (flag, value));
+    }
+
+    // ── Registration ────────────────────────────────────────────────────
+
+    /// Record a launch. Allowed registrars only. Returns the project id.
+    pub fn register(
+        env: Env,
+        registrar: Address,
+        creator: Address,
+        token: Address,
+        vesting: Address,
+        sale: Address,
+    ) -> u32 {
+        registrar.require_auth();
+        let factory_version: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Registrar(registrar.clone()))
+            .expect("not a registrar");
+        let token_key = DataKey::ProjectByToken(token.clone());
+        assert!(
+            !env.storage().persistent().has(&token_key),
+            "token already registered"
+        );
+
+        let id = Self::project_count(env.clone());
+        let project = Project {
+            id,
+            creator: creator.clone(),
+            token: token.clone(),
+            vesting,
+            sale,
+            factory: registrar,
+            factory_version,
+            status: ProjectStatus::Active,
+            verified: false,
+            audited: false,
+            delisted: false,
+            registered_ledger: env.ledger().sequence(),
+        };
+        Self::_save(&env, &project);
+        env.storage().persistent().set(&token_key, &id);
+        let creator_key = DataKey::CreatorProjects(creator.clone());
+        let mut ids: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&creator_key)
+            .unwrap_or(Vec::new(&env));
+        ids.push_back(id);
+        env.storage().persistent().set(&creator_key, &ids);
+        env.storage()
+            .instance()
+            .set(&DataKey::ProjectCount, &(id + 1));
+
+        env.events()
+            .publish((symbol_short!("register"), creator, token), id);
+        id
+    }
+
+    /// Update the status of project `project_id`. Admin or the project's
+    /// creator.
+    pub fn set_status(env: Env, caller: Address, project_id: u32, status: ProjectStatus) {
+        caller.require_auth();
+        let mut project = Self::get_project(env.clone(), project_id);
+        assert!(
+            caller == project.creator || caller == Self::admin(env.clone()),
+            "not admin or creator"
+        );
+        project.status = status;
+        Self::_save(&env, &project);
+
+        env.events()
+            .publish((symbol_short!("status"), project_id), status);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn get_project(env: Env, project_id: u32) -> Project {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Project(project_id))
+            .expect("no project found")
+    }
+
+    pub fn project_by_token(env: Env, token: Address) -> Option<Project> {
+        let id: Option<u32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ProjectByToken(token));
+        id.map(|id| Self::get_project(env, id))
+    }
+
+    /// Up to `limit` projects in registration order, starting at id
+    /// `start`. Delisted projects are skipped unless `include_delisted`.
+    pub fn projects(env: Env, start: u32, limit: u32, include_delisted: bool) -> Vec<Project> {
+        let count = Self::project_count(env.clone());
+        let end = start.saturating_add(limit).min(count);
+        let mut result = Vec::new(&env);
+        for id in start..end {
+            let project = Self::get_project(env.clone(), id);
+            if include_delisted || !project.delisted {
+                result.push_back(project);
+            }
+        }
+        result
+    }
+
+    /// Ids of the projects launched by `creator`, oldest first.
+    pub fn projects_of(env: Env, creator: Address) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CreatorProjects(creator))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn project_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProjectCount)
+            .unwrap_or(0)
+    }
+
+    /// Factory version `registrar` registers as, if it is allowed.
+    pub fn registrar_version(env: Env, registrar: Address) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Registrar(registrar))
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _require_admin(env: &Env) {
+        Self::admin(env.clone()).require_auth();
+    }
+
+    fn _save(env: &Env, project: &Project) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Project(project.id), project);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        testutils::Address as _, testutils::MockAuth, testutils::MockAuthInvoke, IntoVal,
+    };
+
+    struct Setup {
+        env: Env,
+        client: RegistryContractClient<'static>,
+        registrar: Address,
+        creator: Address,
+    }
+
+    /// One registrar allowed as factory version 2.
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, RegistryContract);
+        let client = RegistryContractClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+        let registrar = Address::generate(&env);
+        client.set_registrar(&registrar, &Some(2));
+
+        Setup {
+            creator: Address::generate(&env),
+            env,
+            client,
+            registrar,
+        }
+    }
+
+    fn register(s: &Setup) -> (u32, Address) {
+        let token = Address::generate(&s.env);
+        let id = s.client.register(
+            &s.registrar,
+            &s.creator,
+            &token,
+            &Address::generate(&s.env),
+            &Address::generate(&s.env),
+        );
+        (id, token)
+    }
+
+    #[test]
+    fn test_register_and_lookup() {
+        let s = setup();
+        let (id, token) = register(&s);
+        let project = s.client.get_project(&id);
+        assert_eq!(project.factory, s.registrar);
+        assert_eq!(project.factory_version, 2);
+        assert_eq!(project.status, ProjectStatus::Active);
+        assert_eq!(s.client.project_by_token(&token), Some(project));
+        assert_eq!(s.client.project_by_token(&Address::generate(&s.env)), None);
+        assert_eq!(s.client.projects_of(&s.creator).get(0), Some(id));
+    }
+
+    #[test]
+    #[should_panic(expected = "not a registrar")]
+    fn test_unknown_registrar_panics() {
+        let s = setup();
+        s.client.set_registrar(&s.registrar, &None);
+        register(&s);
+    }
+
+    #[test]
+    #[should_panic(expected = "token already registered")]
+    fn test_duplicate_token_panics() {
+        let s = setup();
+        let (_, token) = register(&s);
+        s.client.register(
+            &s.registrar,
+            &s.creator,
+            &token,
+            &Address::generate(&s.env),
+            &Address::generate(&s.env),
+        );
+    }
+
+    #[test]
+    fn test_flags_and_pagination() {
+        let s = setup();
+        for _ in 0..5 {
+            register(&s);
+        }
+        s.client.set_flag(&1, &Flag::Verified, &true);
+        s.client.set_flag(&1, &Flag::Audited, &true);
+        s.client.set_flag(&2, &Flag::Delisted, &true);
+        let project = s.client.get_project(&1);
+        assert!(project.verified && project.audited && !project.delisted);
+
+        let page = s.client.projects(&0, &3, &false);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get(1).unwrap().id, 1);
+        assert_eq!(s.client.projects(&0, &3, &true).len(), 3);
+        assert_eq!(s.client.projects(&3, &10, &true).len(), 2);
+        assert!(s.client.projects(&9, &10, &true).is_empty());
+    }
+
+    #[test]
+    fn test_creator_sets_status() {
+        let s = setup();
+        let (id, _) = register(&s);
+        s.client
+            .set_status(&s.creator, &id, &ProjectStatus::Completed);
+        assert_eq!(s.client.get_project(&id).status, ProjectStatus::Completed);
+    }
+
+    #[test]
+    #[should_panic(expected = "not admin or creator")]
+    fn test_outsider_cannot_set_status() {
+        let s = setup();
+        let (id, _) = register(&s);
+        s.client
+            .set_status(&Address::generate(&s.env), &id, &ProjectStatus::Cancelled);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_non_admin_cannot_flag() {
+        let s = setup();
+        let (id, _) = register(&s);
+        s.env.mock_auths(&[MockAuth {
+            address: &s.creator,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "set_flag",
+                args: (id, Flag::Verified, true).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.set_flag(&id, &Flag::Verified, &true);
+    }
+}
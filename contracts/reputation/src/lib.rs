@@ -0,0 +1,218 @@
+#![no_std]
+
+use launchpad_access as access;
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+/// Signal of a delivery milestone confirmed by the bond contract.
+pub const MILESTONE_SIGNAL: Symbol = symbol_short!("milestone");
+/// Signal of a commitment bond posted.
+pub const BOND_SIGNAL: Symbol = symbol_short!("bond");
+/// Signal of a bond slashed for a rug or abandonment.
+pub const SLASHED_SIGNAL: Symbol = symbol_short!("slashed");
+/// Signal of a sale that finalized successfully.
+pub const SALE_SIGNAL: Symbol = symbol_short!("sale");
+/// Signal of a governance action, such as a vote or a passed proposal.
+pub const GOVERNANCE_SIGNAL: Symbol = symbol_short!("gov");
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Reporter(Address),
+    /// Signals with a weight, in the order they were first weighted.
+    Signals,
+    Weight(Symbol),
+    /// Times a signal was reported for a project.
+    Count(Address, Symbol),
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Reputation Contract — a score per project built from on-chain signals.
+///
+/// A project is identified by its team's address. Approved reporters, the
+/// platform contracts that observe a signal (the bond contract for bonds,
+/// milestones and slashes, and so on), `report` it for a project. Only
+/// objective events are counted; nothing is rated by hand.
+///
+/// The admin weighs each signal kind, negatively for bad ones such as a
+/// slashed bond. A project's `score` is the sum of its signal counts times
+/// their current weights, so reweighing applies to past signals too. The
+/// frontend shows it, and the sale factory discounts its platform fee for
+/// well-scored projects.
+#[contract]
+pub struct ReputationContract;
+
+#[contractimpl]
+impl ReputationContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, admin: Address) {
+        access::init_admin(&env, &admin);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Approve or remove `reporter`. Admin only.
+    pub fn set_reporter(env: Env, reporter: Address, allowed: bool) {
+        access::require_admin(&env);
+        let key = DataKey::Reporter(reporter.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("reporter"), reporter), allowed);
+    }
+
+    /// Count each `signal` as `weight` points of score; 0 ignores it.
+    /// Admin only.
+    pub fn set_weight(env: Env, signal: Symbol, weight: i128) {
+        access::require_admin(&env);
+        let mut signals = Self::signals(env.clone());
+        if !signals.contains(&signal) {
+            signals.push_back(signal.clone());
+            env.storage().instance().set(&DataKey::Signals, &signals);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::Weight(signal.clone()), &weight);
+        env.events()
+            .publish((symbol_short!("weight"), signal), weight);
+    }
+
+    // ── Reporters ───────────────────────────────────────────────────────
+
+    /// Record `count` occurrences of `signal` for `project`. Approved
+    /// reporters only.
+    pub fn report(env: Env, reporter: Address, project: Address, signal: Symbol, count: u32) {
+        reporter.require_auth();
+        assert!(
+            Self::is_reporter(env.clone(), reporter.clone()),
+            "not a reporter"
+        );
+        assert!(count > 0, "count must be positive");
+
+        let total = Self::signal_count(env.clone(), project.clone(), signal.clone()) + count;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Count(project.clone(), signal.clone()), &total);
+
+        env.events().publish(
+            (symbol_short!("signal"), project, signal),
+            (reporter, count),
+        );
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Sum of `project`'s signal counts times their weights.
+    pub fn score(env: Env, project: Address) -> i128 {
+        let mut score = 0;
+        for signal in Self::signals(env.clone()).iter() {
+            let count = Self::signal_count(env.clone(), project.clone(), signal.clone());
+            score += count as i128 * Self::weight(env.clone(), signal);
+        }
+        score
+    }
+
+    pub fn signal_count(env: Env, project: Address, signal: Symbol) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Count(project, signal))
+            .unwrap_or(0)
+    }
+
+    pub fn weight(env: Env, signal: Symbol) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Weight(signal))
+            .unwrap_or(0)
+    }
+
+    /// Every signal that has been weighted.
+    pub fn signals(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Signals)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn is_reporter(env: Env, reporter: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Reporter(reporter))
+    }
+
+    pub fn admin(env: Env) -> Address {
+        access::admin(&env)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    struct Setup {
+        env: Env,
+        client: ReputationContractClient<'static>,
+        reporter: Address,
+    }
+
+    /// Milestones weigh 10, sales 50 and slashes -200.
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let client =
+            ReputationContractClient::new(&env, &env.register_contract(None, ReputationContract));
+        client.initialize(&Address::generate(&env));
+        let reporter = Address::generate(&env);
+        client.set_reporter(&reporter, &true);
+        client.set_weight(&MILESTONE_SIGNAL, &10);
+        client.set_weight(&SALE_SIGNAL, &50);
+        client.set_weight(&SLASHED_SIGNAL, &-200);
+
+        Setup {
+            env,
+            client,
+            reporter,
+        }
+    }
+
+    #[test]
+    fn test_score_weighs_signals() {
+        let s = setup();
+        let project = Address::generate(&s.env);
+        s.client
+            .report(&s.reporter, &project, &MILESTONE_SIGNAL, &3);
+        s.client.report(&s.reporter, &project, &SALE_SIGNAL, &1);
+        s.client.report(&s.reporter, &project, &BOND_SIGNAL, &1);
+        assert_eq!(s.client.score(&project), 80);
+
+        // Unweighted signals still count once they get a weight
+        s.client.set_weight(&BOND_SIGNAL, &20);
+        assert_eq!(s.client.score(&project), 100);
+
+        s.client.report(&s.reporter, &project, &SLASHED_SIGNAL, &1);
+        assert_eq!(s.client.score(&project), -100);
+        assert_eq!(s.client.signal_count(&project, &MILESTONE_SIGNAL), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a reporter")]
+    fn test_only_reporters_report() {
+        let s = setup();
+        let outsider = Address::generate(&s.env);
+        s.client.report(&outsider, &outsider, &SALE_SIGNAL, &1);
+    }
+}
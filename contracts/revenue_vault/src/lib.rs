@@ -0,0 +1,351 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env};
+use soropad_math::mul_div;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Token,
+    Cooldown,
+    TotalShares,
+    Shares(Address),
+    /// First ledger on which an account can withdraw, set by its last
+    /// deposit.
+    CooldownEnd(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Revenue Vault Contract — platform-token staking that earns fee revenue.
+///
+/// Holders `deposit` the platform token and get vault shares (the xToken)
+/// in return. Platform revenue, converted to the platform token (e.g. by
+/// the buyback vault forwarding to it), is added with `add_revenue` or a
+/// plain transfer; it raises the vault's assets but not its shares, so
+/// every share is worth more and `withdraw` pays out the holder's cut.
+///
+/// Shares and assets are converted with one virtual share and one virtual
+/// asset, rounding in the vault's favour, so the first depositor cannot
+/// inflate the share price against later ones.
+///
+/// To stop deposits timed just before a distribution and withdrawn just
+/// after it, an account cannot withdraw until `cooldown` ledgers after its
+/// last deposit. Each deposit fixes its own cooldown end, so changing the
+/// cooldown only affects later deposits.
+#[contract]
+pub struct RevenueVaultContract;
+
+#[contractimpl]
+impl RevenueVaultContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the admin, the platform token and the withdrawal cooldown in
+    /// ledgers.
+    pub fn initialize(env: Env, admin: Address, token: Address, cooldown: u32) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Token, &token);
+        env.storage().instance().set(&DataKey::Cooldown, &cooldown);
+
+        env.events()
+            .publish((symbol_short!("init"),), (admin, token, cooldown));
+    }
+
+    // ── Holders ─────────────────────────────────────────────────────────
+
+    /// Deposit `assets` platform tokens from `from` for vault shares.
+    /// Restarts `from`'s cooldown. Returns the shares minted.
+    pub fn deposit(env: Env, from: Address, assets: i128) -> i128 {
+        from.require_auth();
+        assert!(assets > 0, "amount must be positive");
+        let shares = Self::preview_deposit(env.clone(), assets);
+        assert!(shares > 0, "deposit too small");
+
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &from,
+            &env.current_contract_address(),
+            &assets,
+        );
+        Self::_set_shares(
+            &env,
+            &from,
+            Self::shares_of(env.clone(), from.clone()) + shares,
+        );
+        let cooldown_end = env
+            .ledger()
+            .sequence()
+            .saturating_add(Self::cooldown(env.clone()));
+        env.storage()
+            .persistent()
+            .set(&DataKey::CooldownEnd(from.clone()), &cooldown_end);
+
+        env.events()
+            .publish((symbol_short!("deposit"), from), (assets, shares));
+        shares
+    }
+
+    /// Burn `shares` of `owner`'s vault shares for platform tokens, once
+    /// the cooldown since its last deposit is over. Returns the tokens paid.
+    pub fn withdraw(env: Env, owner: Address, shares: i128) -> i128 {
+        owner.require_auth();
+        assert!(shares > 0, "amount must be positive");
+        assert!(
+            env.ledger().sequence() >= Self::cooldown_end(env.clone(), owner.clone()),
+            "cooldown active"
+        );
+        let held = Self::shares_of(env.clone(), owner.clone());
+        assert!(shares <= held, "insufficient shares");
+
+        let assets = Self::preview_withdraw(env.clone(), shares);
+        Self::_set_shares(&env, &owner, held - shares);
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &env.current_contract_address(),
+            &owner,
+            &assets,
+        );
+
+        env.events()
+            .publish((symbol_short!("withdraw"), owner), (shares, assets));
+        assets
+    }
+
+    // ── Revenue ─────────────────────────────────────────────────────────
+
+    /// Add `amount` platform tokens of revenue from `from`, raising the
+    /// value of every share.
+    pub fn add_revenue(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &from,
+            &env.current_contract_address(),
+            &amount,
+        );
+        env.events()
+            .publish((symbol_short!("revenue"), from), amount);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Change the withdrawal cooldown of future deposits. Pending cooldowns
+    /// keep the end they started with. Admin only.
+    pub fn set_cooldown(env: Env, cooldown: u32) {
+        Self::admin(env.clone()).require_auth();
+        env.storage().instance().set(&DataKey::Cooldown, &cooldown);
+        env.events().publish((symbol_short!("cooldown"),), cooldown);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Shares a deposit of `assets` would mint right now.
+    pub fn preview_deposit(env: Env, assets: i128) -> i128 {
+        mul_div(
+            assets,
+            Self::total_shares(env.clone()) + 1,
+            Self::total_assets(env) + 1,
+        )
+    }
+
+    /// Platform tokens a withdrawal of `shares` would pay right now.
+    pub fn preview_withdraw(env: Env, shares: i128) -> i128 {
+        mul_div(
+            shares,
+            Self::total_assets(env.clone()) + 1,
+            Self::total_shares(env) + 1,
+        )
+    }
+
+    /// Platform tokens `owner`'s shares are worth right now.
+    pub fn assets_of(env: Env, owner: Address) -> i128 {
+        Self::preview_withdraw(env.clone(), Self::shares_of(env, owner))
+    }
+
+    /// First ledger on which `owner` can withdraw.
+    pub fn cooldown_end(env: Env, owner: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CooldownEnd(owner))
+            .unwrap_or(0)
+    }
+
+    /// Platform tokens held, deposits and revenue alike.
+    pub fn total_assets(env: Env) -> i128 {
+        token::Client::new(&env, &Self::token(env.clone())).balance(&env.current_contract_address())
+    }
+
+    pub fn total_shares(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalShares)
+            .unwrap_or(0)
+    }
+
+    pub fn shares_of(env: Env, owner: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Shares(owner))
+            .unwrap_or(0)
+    }
+
+    pub fn cooldown(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Cooldown)
+            .expect("not initialized")
+    }
+
+    pub fn token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("not initialized")
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _set_shares(env: &Env, owner: &Address, shares: i128) {
+        let total = Self::total_shares(env.clone()) - Self::shares_of(env.clone(), owner.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalShares, &(total + shares));
+        env.storage()
+            .persistent()
+            .set(&DataKey::Shares(owner.clone()), &shares);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{jump_to, test_env, TokenFixture};
+    use soroban_sdk::testutils::Address as _;
+
+    struct Setup {
+        env: Env,
+        client: RevenueVaultContractClient<'static>,
+        token: TokenFixture,
+    }
+
+    /// A 100-ledger cooldown, starting at ledger 1 000.
+    fn setup() -> Setup {
+        let env = test_env();
+        jump_to(&env, 1_000);
+
+        let token = TokenFixture::stellar_asset(&env);
+        let client = RevenueVaultContractClient::new(
+            &env,
+            &env.register_contract(None, RevenueVaultContract),
+        );
+        client.initialize(&Address::generate(&env), &token.address, &100);
+
+        Setup { env, client, token }
+    }
+
+    fn funded(s: &Setup, amount: i128) -> Address {
+        s.token.funded(amount)
+    }
+
+    #[test]
+    fn test_revenue_raises_share_value() {
+        let s = setup();
+        let (alice, bob) = (funded(&s, 1_000), funded(&s, 1_000));
+        assert_eq!(s.client.deposit(&alice, &1_000), 1_000);
+
+        s.client.add_revenue(&funded(&s, 1_000), &1_000);
+        assert_eq!(s.client.assets_of(&alice), 1_999);
+
+        // Bob buys in at the new price and only shares later revenue
+        assert_eq!(s.client.preview_deposit(&1_000), 500);
+        s.client.deposit(&bob, &1_000);
+        s.client.add_revenue(&funded(&s, 1_500), &1_500);
+
+        jump_to(&s.env, 1_100);
+        assert_eq!(s.client.withdraw(&alice, &1_000), 2_998);
+        assert_eq!(s.client.withdraw(&bob, &500), 1_500);
+        assert_eq!(s.client.total_shares(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "cooldown active")]
+    fn test_cooldown_blocks_quick_exit() {
+        let s = setup();
+        let sandwich = funded(&s, 1_000);
+        s.client.deposit(&sandwich, &1_000);
+        s.client.add_revenue(&funded(&s, 100), &100);
+        jump_to(&s.env, 1_099);
+        s.client.withdraw(&sandwich, &1_000);
+    }
+
+    #[test]
+    fn test_new_deposit_restarts_cooldown() {
+        let s = setup();
+        let alice = funded(&s, 2_000);
+        s.client.deposit(&alice, &1_000);
+        jump_to(&s.env, 1_100);
+        s.client.deposit(&alice, &1_000);
+        assert_eq!(s.client.cooldown_end(&alice), 1_200);
+        assert!(s.client.try_withdraw(&alice, &1).is_err());
+    }
+
+    #[test]
+    fn test_cooldown_change_spares_pending_withdrawals() {
+        let s = setup();
+        let alice = funded(&s, 2_000);
+        s.client.deposit(&alice, &1_000);
+        s.client.set_cooldown(&10_000);
+        assert_eq!(s.client.cooldown_end(&alice), 1_100);
+
+        jump_to(&s.env, 1_100);
+        s.client.withdraw(&alice, &500);
+        s.client.deposit(&alice, &1_000);
+        assert_eq!(s.client.cooldown_end(&alice), 11_100);
+    }
+
+    #[test]
+    fn test_donation_does_not_steal_next_deposit() {
+        let s = setup();
+        let (attacker, victim) = (funded(&s, 10_001), funded(&s, 10_000));
+        s.client.deposit(&attacker, &1);
+        s.token
+            .client
+            .transfer(&attacker, &s.client.address, &10_000);
+
+        // The victim still gets shares, and the attacker loses more than
+        // the victim does
+        assert!(s.client.deposit(&victim, &10_000) > 0);
+        let victim_loss = 10_000 - s.client.assets_of(&victim);
+        let attacker_loss = 10_001 - s.client.assets_of(&attacker);
+        assert!(attacker_loss >= victim_loss);
+    }
+
+    #[test]
+    #[should_panic(expected = "insufficient shares")]
+    fn test_withdraw_more_than_held() {
+        let s = setup();
+        let alice = funded(&s, 1_000);
+        s.client.deposit(&alice, &1_000);
+        jump_to(&s.env, 1_100);
+        s.client.withdraw(&alice, &1_001);
+    }
+}
@@ -0,0 +1,267 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, Env, InvokeError, Symbol, Val, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    /// Contract the router may call.
+    Target(Address),
+}
+
+/// One step of a batch.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Call {
+    pub contract: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+    /// Carry on with the batch if this step fails, instead of reverting it.
+    pub allow_failure: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Router Contract — common multi-step flows in one transaction.
+///
+/// A user signs one `execute` call holding a sequence of calls to platform
+/// contracts, e.g. approve, contribute and stake, or claim vesting and
+/// stake. The router makes them in order and reports each step as
+/// `(success, return value)`, the value being void for a failed step. The
+/// user's signature on `execute` covers every call in the batch, so the
+/// contracts called can still `require_auth` the user as if it had called
+/// them directly.
+///
+/// A batch is atomic: if any step fails the whole transaction reverts,
+/// unless that step is marked `allow_failure`, in which case its effects
+/// alone are rolled back and its result reports the failure. Only targets
+/// approved by the admin can be called, so the router cannot be used to
+/// reach arbitrary contracts under the platform's name.
+#[contract]
+pub struct RouterContract;
+
+#[contractimpl]
+impl RouterContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, admin: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Approve or remove `contract` as a target. Admin only.
+    pub fn set_target(env: Env, contract: Address, allowed: bool) {
+        Self::admin(env.clone()).require_auth();
+        let key = DataKey::Target(contract.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("target"), contract), allowed);
+    }
+
+    pub fn set_admin(env: Env, admin: Address) {
+        Self::admin(env.clone()).require_auth();
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    // ── Users ───────────────────────────────────────────────────────────
+
+    /// Make `calls` in order on behalf of `user` and return the success
+    /// and return value of each. Reverts on the first failing step not marked `allow_failure`.
+    pub fn execute(env: Env, user: Address, calls: Vec<Call>) -> Vec<(bool, Val)> {
+        user.require_auth();
+        assert!(!calls.is_empty(), "no calls");
+
+        let mut results = Vec::new(&env);
+        for (step, call) in calls.iter().enumerate() {
+            assert!(
+                Self::is_target(env.clone(), call.contract.clone()),
+                "target not allowed"
+            );
+            let result = if call.allow_failure {
+                match env.try_invoke_contract::<Val, InvokeError>(
+                    &call.contract,
+                    &call.function,
+                    call.args,
+                ) {
+                    Ok(Ok(value)) => (true, value),
+                    _ => (false, Val::VOID.into()),
+                }
+            } else {
+                let value = env.invoke_contract::<Val>(&call.contract, &call.function, call.args);
+                (true, value)
+            };
+            env.events().publish(
+                (symbol_short!("step"), user.clone(), call.contract),
+                (step as u32, call.function, result.0),
+            );
+            results.push_back(result);
+        }
+        results
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn is_target(env: Env, contract: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Target(contract))
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, vec, IntoVal, TryFromVal};
+
+    /// Stand-in for a platform contract: per-user counters that only the
+    /// user can raise.
+    #[contract]
+    struct MockCounter;
+
+    #[contractimpl]
+    impl MockCounter {
+        pub fn add(env: Env, user: Address, amount: u32) -> u32 {
+            user.require_auth();
+            let total = Self::total(env.clone(), user.clone()) + amount;
+            env.storage().persistent().set(&user, &total);
+            total
+        }
+
+        pub fn fail(env: Env, user: Address) {
+            env.storage().persistent().set(&user, &1_000u32);
+            panic!("step failed");
+        }
+
+        pub fn total(env: Env, user: Address) -> u32 {
+            env.storage().persistent().get(&user).unwrap_or(0)
+        }
+    }
+
+    struct Setup {
+        env: Env,
+        client: RouterContractClient<'static>,
+        counter: MockCounterClient<'static>,
+        user: Address,
+    }
+
+    /// The router with the counter approved as a target.
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let client = RouterContractClient::new(&env, &env.register_contract(None, RouterContract));
+        client.initialize(&Address::generate(&env));
+        let counter = MockCounterClient::new(&env, &env.register_contract(None, MockCounter));
+        client.set_target(&counter.address, &true);
+
+        Setup {
+            user: Address::generate(&env),
+            env,
+            client,
+            counter,
+        }
+    }
+
+    fn call(s: &Setup, function: &str, args: Vec<Val>, allow_failure: bool) -> Call {
+        Call {
+            contract: s.counter.address.clone(),
+            function: Symbol::new(&s.env, function),
+            args,
+            allow_failure,
+        }
+    }
+
+    #[test]
+    fn test_execute_runs_steps_in_order() {
+        let s = setup();
+        let args = |amount: u32| vec![&s.env, s.user.into_val(&s.env), amount.into_val(&s.env)];
+        let results = s.client.execute(
+            &s.user,
+            &vec![
+                &s.env,
+                call(&s, "add", args(5), false),
+                call(&s, "add", args(7), false),
+            ],
+        );
+
+        assert_eq!(results.len(), 2);
+        let (success, value) = results.get(1).unwrap();
+        assert!(success);
+        assert_eq!(u32::try_from_val(&s.env, &value).unwrap(), 12);
+        assert_eq!(s.counter.total(&s.user), 12);
+    }
+
+    #[test]
+    fn test_failing_step_reverts_batch() {
+        let s = setup();
+        let add = call(
+            &s,
+            "add",
+            vec![&s.env, s.user.into_val(&s.env), 5u32.into_val(&s.env)],
+            false,
+        );
+        let fail = call(&s, "fail", vec![&s.env, s.user.into_val(&s.env)], false);
+        assert!(s
+            .client
+            .try_execute(&s.user, &vec![&s.env, add, fail])
+            .is_err());
+        assert_eq!(s.counter.total(&s.user), 0);
+    }
+
+    #[test]
+    fn test_allowed_failure_is_reported() {
+        let s = setup();
+        let fail = call(&s, "fail", vec![&s.env, s.user.into_val(&s.env)], true);
+        let add = call(
+            &s,
+            "add",
+            vec![&s.env, s.user.into_val(&s.env), 5u32.into_val(&s.env)],
+            false,
+        );
+        let results = s.client.execute(&s.user, &vec![&s.env, fail, add]);
+
+        assert!(!results.get(0).unwrap().0);
+        assert!(results.get(1).unwrap().0);
+        assert_eq!(s.counter.total(&s.user), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "target not allowed")]
+    fn test_unapproved_target_rejected() {
+        let s = setup();
+        s.client.set_target(&s.counter.address, &false);
+        let add = call(
+            &s,
+            "add",
+            vec![&s.env, s.user.into_val(&s.env), 5u32.into_val(&s.env)],
+            false,
+        );
+        s.client.execute(&s.user, &vec![&s.env, add]);
+    }
+}
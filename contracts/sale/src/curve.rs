@@ -0,0 +1,206 @@
+//! Bonding-curve pricing for `SaleMode::BondingCurve`.
+//!
+//! Prices are quoted in payment-token units per `PRICE_SCALE` sale-token
+//! units, like `SaleConfig::price`. Costs are derived from the area under the
+//! curve so that buying and then selling the same amount is always
+//! reserve-neutral: buys round up and sells round down.
+
+use crate::{Curve, ExponentialCurve, PRICE_SCALE};
+use soropad_math::{apply_bps, mul_div, mul_div_ceil};
+
+/// Most steps an exponential curve can be walked. Quotes past the last step
+/// revert, which keeps the cost of every quote bounded however small `step`
+/// is.
+pub(crate) const MAX_CURVE_STEPS: i128 = 1_000;
+
+/// Spot price once `sold` tokens are outstanding.
+pub(crate) fn spot_price(curve: &Curve, sold: i128) -> i128 {
+    match curve {
+        Curve::Linear(c) => c
+            .base_price
+            .checked_add(mul_div(c.slope, sold, PRICE_SCALE))
+            .expect("curve overflow"),
+        Curve::Exponential(c) => {
+            let mut price = c.base_price;
+            for _ in 0..steps(c, sold) {
+                price = grow(c, price);
+            }
+            price
+        }
+    }
+}
+
+/// Payment owed for buying `amount` tokens when `sold` are outstanding.
+pub(crate) fn buy_cost(curve: &Curve, sold: i128, amount: i128) -> i128 {
+    let area = integral(curve, sold + amount) - integral(curve, sold);
+    mul_div_ceil(area, 1, PRICE_SCALE)
+}
+
+/// Payment returned for selling `amount` tokens back when `sold` are
+/// outstanding.
+pub(crate) fn sell_proceeds(curve: &Curve, sold: i128, amount: i128) -> i128 {
+    let area = integral(curve, sold) - integral(curve, sold - amount);
+    area / PRICE_SCALE
+}
+
+/// Area under the curve between 0 and `sold`, in payment units × `PRICE_SCALE`.
+fn integral(curve: &Curve, sold: i128) -> i128 {
+    match curve {
+        // base * s + slope * s² / (2 * PRICE_SCALE), with a wide intermediate
+        // product so the division stays exact without overflowing.
+        Curve::Linear(c) => {
+            let squared = sold.checked_mul(sold).expect("curve overflow");
+            c.base_price
+                .checked_mul(sold)
+                .and_then(|base| base.checked_add(mul_div(c.slope, squared, 2 * PRICE_SCALE)))
+                .expect("curve overflow")
+        }
+        // Piecewise-constant price that grows by `growth_bps` every `step`.
+        Curve::Exponential(c) => {
+            let full = steps(c, sold);
+            let mut price = c.base_price;
+            let mut area = 0i128;
+            for _ in 0..full {
+                area = price
+                    .checked_mul(c.step)
+                    .and_then(|step| area.checked_add(step))
+                    .expect("curve overflow");
+                price = grow(c, price);
+            }
+            price
+                .checked_mul(sold - full * c.step)
+                .and_then(|rest| area.checked_add(rest))
+                .expect("curve overflow")
+        }
+    }
+}
+
+/// Whole steps below `sold`, at most `MAX_CURVE_STEPS`.
+fn steps(c: &ExponentialCurve, sold: i128) -> i128 {
+    let steps = sold / c.step;
+    assert!(steps <= MAX_CURVE_STEPS, "curve step limit exceeded");
+    steps
+}
+
+/// Price one step further along the curve.
+fn grow(c: &ExponentialCurve, price: i128) -> i128 {
+    price
+        .checked_add(apply_bps(price, c.growth_bps))
+        .expect("curve overflow")
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use crate::LinearCurve;
+    use proptest::prelude::*;
+    use soropad_math::checked_mul_div;
+    use std::{boxed::Box, panic};
+
+    /// `quote()`, or `None` if it reverts. Reverting quotes are expected
+    /// here, so their panic messages are silenced.
+    fn try_quote(quote: impl FnOnce() -> i128 + panic::UnwindSafe) -> Option<i128> {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let result = panic::catch_unwind(quote).ok();
+        panic::set_hook(hook);
+        result
+    }
+
+    /// Positive values at and just below `i128::MAX`, around half of it,
+    /// around its square root (where `sold²` overflows) and around
+    /// `i128::MAX / PRICE_SCALE`, plus ordinary ones.
+    fn boundary() -> impl Strategy<Value = i128> {
+        const ROOT: i128 = 13_043_817_825_332_782_212;
+        prop_oneof![
+            1 => Just(i128::MAX),
+            1 => (i128::MAX - 1_000)..i128::MAX,
+            1 => (i128::MAX / 2 - 1_000)..=(i128::MAX / 2 + 1_000),
+            1 => (ROOT - 1_000)..=(ROOT + 1_000),
+            1 => (i128::MAX / PRICE_SCALE - 1_000)..=(i128::MAX / PRICE_SCALE + 1_000),
+            2 => 1i128..=1_000,
+            3 => 1i128..=1_000_000_000_000_000_000,
+        ]
+    }
+
+    /// Curves `initialize` accepts, with boundary-biased parameters.
+    fn any_curve() -> impl Strategy<Value = Curve> {
+        prop_oneof![
+            (boundary(), prop_oneof![Just(0i128), boundary()]).prop_map(|(base_price, slope)| {
+                Curve::Linear(LinearCurve { base_price, slope })
+            }),
+            (
+                boundary(),
+                prop_oneof![1u32..=10_000, Just(u32::MAX)],
+                boundary()
+            )
+                .prop_map(|(base_price, growth_bps, step)| {
+                    Curve::Exponential(ExponentialCurve {
+                        base_price,
+                        growth_bps,
+                        step,
+                    })
+                }),
+        ]
+    }
+
+    /// What `amount` tokens cost at `price`, rounded down.
+    fn at_price(amount: i128, price: Option<i128>) -> Option<i128> {
+        price.and_then(|price| checked_mul_div(amount, price, PRICE_SCALE))
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(256))]
+
+        /// A buy costs between `amount` at the spot price before and after
+        /// it, allowing for rounding, and reverts whenever that cost does
+        /// not fit in `i128`.
+        #[test]
+        fn prop_buy_cost_never_wraps(
+            curve in any_curve(),
+            sold in prop_oneof![Just(0i128), boundary()],
+            amount in boundary(),
+        ) {
+            let cost = try_quote(|| buy_cost(&curve, sold, amount));
+            let low = at_price(amount, try_quote(|| spot_price(&curve, sold)));
+            let high = at_price(
+                amount,
+                try_quote(|| spot_price(&curve, sold + amount)).and_then(|p| p.checked_add(1)),
+            );
+
+            if let Some(cost) = cost {
+                let low = low.expect("cost fits, so the lower bound does");
+                prop_assert!(cost >= 0);
+                prop_assert!(cost >= low - 1, "{} below {}", cost, low);
+                if let Some(high) = high {
+                    prop_assert!(cost <= high + 2, "{} above {}", cost, high);
+                }
+            }
+        }
+
+        /// Selling back returns what buying the same tokens cost, less at
+        /// most the rounding, and reverts whenever that buy would.
+        #[test]
+        fn prop_sell_proceeds_never_wrap(
+            curve in any_curve(),
+            sold in boundary(),
+            share in 1i128..=1_000,
+        ) {
+            let amount = (sold / 1_000 * share).max(1);
+            let proceeds = try_quote(|| sell_proceeds(&curve, sold, amount));
+            let cost = try_quote(|| buy_cost(&curve, sold - amount, amount));
+
+            if let Some(proceeds) = proceeds {
+                let cost = cost.expect("proceeds fit, so the cost does");
+                prop_assert!(proceeds >= 0);
+                prop_assert!(proceeds <= cost && cost <= proceeds + 1);
+            }
+        }
+    }
+}
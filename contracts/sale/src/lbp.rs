@@ -0,0 +1,180 @@
+//! Liquidity-bootstrapping pricing for `SaleMode::Lbp`.
+//!
+//! The sale behaves like a two-asset weighted pool holding the unsold tokens
+//! and the raise plus a virtual reserve. The token weight shifts linearly
+//! from `start_weight_bps` to `end_weight_bps` over the sale window, so the
+//! price decays over time unless buys push it back up.
+//!
+//! The token weight never drops below `MIN_TOKEN_WEIGHT_BPS`, which keeps
+//! `tokens_out` at or below what the exact weighted-pool formula pays.
+
+use crate::{LbpConfig, BPS_DENOMINATOR, PRICE_SCALE};
+use soropad_math::{linear, mul_div};
+
+/// Lowest token weight an LBP sale may use: half the pool.
+pub(crate) const MIN_TOKEN_WEIGHT_BPS: i128 = BPS_DENOMINATOR / 2;
+
+/// Token weight (in bps) at `ledger`, clamped to the sale window.
+pub(crate) fn token_weight(
+    config: &LbpConfig,
+    start_ledger: u32,
+    end_ledger: u32,
+    ledger: u32,
+) -> i128 {
+    let start = config.start_weight_bps as i128;
+    let end = config.end_weight_bps as i128;
+    start + linear(start_ledger, end_ledger, ledger, end - start)
+}
+
+/// Spot price in payment units per `PRICE_SCALE` tokens:
+/// `(reserve / w_reserve) / (tokens / w_token)`.
+pub(crate) fn spot_price(weight: i128, token_balance: i128, reserve_balance: i128) -> i128 {
+    assert!(token_balance > 0, "sold out");
+    let denominator = token_balance
+        .checked_mul(BPS_DENOMINATOR - weight)
+        .expect("lbp overflow");
+    mul_div(reserve_balance, weight * PRICE_SCALE, denominator)
+}
+
+/// Tokens received for paying `amount` into the pool.
+///
+/// First-order approximation of the weighted-pool swap formula
+/// `tokens * (1 - (reserve / (reserve + amount)) ^ (w_reserve / w_token))`:
+/// `tokens * amount * w_reserve / ((reserve + amount) * w_token)`. The
+/// marginal price equals `spot_price` and the average price rises with the
+/// size of the buy.
+///
+/// While `w_token >= w_reserve` the exponent is at most one, and by
+/// Bernoulli's inequality the approximation never pays out more than the
+/// exact formula; at exactly half the pool the two agree. Below half it
+/// would over-deliver, hence `MIN_TOKEN_WEIGHT_BPS`.
+pub(crate) fn tokens_out(
+    weight: i128,
+    token_balance: i128,
+    reserve_balance: i128,
+    amount: i128,
+) -> i128 {
+    let denominator = reserve_balance
+        .checked_add(amount)
+        .and_then(|reserve| reserve.checked_mul(weight))
+        .expect("lbp overflow");
+    mul_div(
+        token_balance,
+        amount
+            .checked_mul(BPS_DENOMINATOR - weight)
+            .expect("lbp overflow"),
+        denominator,
+    )
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use proptest::prelude::*;
+    use std::{boxed::Box, panic};
+
+    const TOKENS: i128 = 10_000_000_000;
+    const RESERVE: i128 = 1_000_000_000;
+    const AMOUNTS: [i128; 5] = [1, 1_000, 1_000_000, 1_000_000_000, 1_000_000_000_000];
+
+    /// Exact weighted-pool out-given-in, in floating point.
+    fn exact_tokens_out(weight: i128, amount: i128) -> f64 {
+        let exponent = (BPS_DENOMINATOR - weight) as f64 / weight as f64;
+        let ratio = RESERVE as f64 / (RESERVE + amount) as f64;
+        TOKENS as f64 * (1.0 - ratio.powf(exponent))
+    }
+
+    #[test]
+    fn test_tokens_out_never_exceeds_exact_formula() {
+        for weight in [MIN_TOKEN_WEIGHT_BPS, 5_001, 6_000, 8_000, 9_600, 9_999] {
+            for amount in AMOUNTS {
+                let out = tokens_out(weight, TOKENS, RESERVE, amount);
+                let exact = exact_tokens_out(weight, amount);
+                assert!(
+                    out as f64 <= exact + 1.0,
+                    "weight {weight}, amount {amount}: {out} > {exact}"
+                );
+                assert!(out < TOKENS);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tokens_out_is_exact_at_half_weight() {
+        for amount in AMOUNTS {
+            let out = tokens_out(MIN_TOKEN_WEIGHT_BPS, TOKENS, RESERVE, amount);
+            assert!((out as f64 - exact_tokens_out(MIN_TOKEN_WEIGHT_BPS, amount)).abs() <= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_tokens_out_would_over_deliver_below_half_weight() {
+        for weight in [1_000, 2_500, 4_000] {
+            let out = tokens_out(weight, TOKENS, RESERVE, RESERVE);
+            assert!(out as f64 > exact_tokens_out(weight, RESERVE) + 1.0);
+        }
+    }
+
+    /// `tokens_out(..)`, or `None` if it reverts, with the panic message
+    /// silenced.
+    fn try_tokens_out(weight: i128, tokens: i128, reserve: i128, amount: i128) -> Option<i128> {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let result = panic::catch_unwind(|| tokens_out(weight, tokens, reserve, amount)).ok();
+        panic::set_hook(hook);
+        result
+    }
+
+    /// Positive values at and just below `i128::MAX`, around half of it and
+    /// around `i128::MAX / BPS_DENOMINATOR`, plus ordinary ones.
+    fn boundary() -> impl Strategy<Value = i128> {
+        prop_oneof![
+            1 => Just(i128::MAX),
+            1 => (i128::MAX - 1_000)..i128::MAX,
+            1 => (i128::MAX / 2 - 1_000)..=(i128::MAX / 2 + 1_000),
+            1 => (i128::MAX / BPS_DENOMINATOR - 1_000)..=(i128::MAX / BPS_DENOMINATOR + 1_000),
+            2 => 1i128..=1_000,
+            3 => 1i128..=1_000_000_000_000_000_000,
+        ]
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(256))]
+
+        /// A buy never takes the whole pool and never gets fewer tokens
+        /// for paying more. It reverts only when an intermediate product
+        /// leaves `i128`.
+        #[test]
+        fn prop_tokens_out_never_wraps(
+            weight in MIN_TOKEN_WEIGHT_BPS..BPS_DENOMINATOR,
+            tokens in boundary(),
+            reserve in boundary(),
+            amount in boundary(),
+            extra in prop_oneof![Just(1i128), boundary()],
+        ) {
+            let overflows = reserve
+                .checked_add(amount)
+                .and_then(|total| total.checked_mul(weight))
+                .is_none()
+                || amount.checked_mul(BPS_DENOMINATOR - weight).is_none();
+            let out = try_tokens_out(weight, tokens, reserve, amount);
+            prop_assert_eq!(out.is_none(), overflows);
+
+            if let Some(out) = out {
+                prop_assert!(out >= 0 && out < tokens);
+                let more = amount
+                    .checked_add(extra)
+                    .and_then(|larger| try_tokens_out(weight, tokens, reserve, larger));
+                if let Some(more) = more {
+                    prop_assert!(more >= out);
+                }
+            }
+        }
+    }
+}
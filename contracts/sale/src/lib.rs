@@ -0,0 +1,3539 @@
+#![no_std]
+
+use launchpad_events as events;
+use launchpad_rate_limit::{self as rate_limit, RateLimit};
+use launchpad_ttl as ttl;
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractclient, contractimpl, contracttype, symbol_short, token, vec, Address, Bytes,
+    BytesN, Env, IntoVal, Map, String, Symbol, Vec,
+};
+
+mod curve;
+mod lbp;
+
+/// Fixed-point scale used by `SaleConfig::price`.
+///
+/// `price` is the amount of payment-token units charged for `PRICE_SCALE`
+/// sale-token units, so a price equal to `PRICE_SCALE` is a 1:1 rate.
+pub const PRICE_SCALE: i128 = 10_000_000;
+
+/// Denominator for all basis-point values.
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Kind of the participation badge issued on `claim`.
+pub const SALE_BADGE: Symbol = symbol_short!("sale");
+
+/// Points activity recorded for claimed contributions, in payment units.
+pub const CONTRIBUTION_ACTIVITY: Symbol = symbol_short!("contrib");
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Config,
+    TotalRaised,
+    TokensSold,
+    State,
+    Unsettled,
+    Nonce(Address),
+    LiquidityLock,
+    Reserve,
+    Escrow,
+    BuybackPending,
+    ContributorCount,
+    ContributorAt(u32),
+    Purchase(Address),
+    ClawedBack(Address),
+    Tier(Address),
+    Whitelisted(u32, Address),
+    RoundAllowlist(u32),
+    Paused,
+    Project,
+    Extensions,
+    PauseRegistry,
+    Badges,
+    Kyc,
+    BuyerLimit,
+    /// Contributions and buys by a buyer, as rate-limit usage.
+    BuyerUsage(Address),
+    Points,
+}
+
+/// How the sale prices its tokens.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum SaleMode {
+    /// Buyers `contribute` at `SaleConfig::price` and claim after finalize.
+    FixedPrice,
+    /// Continuous sale: buyers `buy` and `sell` against the curve, which
+    /// accumulates a reserve backing every outstanding token.
+    BondingCurve(Curve),
+    /// Liquidity-bootstrapping raise: contributions are priced by a
+    /// weight-shifting virtual pool, then settled like a fixed-price sale.
+    Lbp(LbpConfig),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Curve {
+    Linear(LinearCurve),
+    Exponential(ExponentialCurve),
+}
+
+/// `price(sold) = base_price + slope * sold / PRICE_SCALE`
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LinearCurve {
+    pub base_price: i128,
+    pub slope: i128,
+}
+
+/// Price grows by `growth_bps` for every `step` tokens sold.
+///
+/// Quotes walk the curve one step at a time and revert past 1 000 steps, so
+/// `step` should cover the whole inventory in at most that many.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ExponentialCurve {
+    pub base_price: i128,
+    pub growth_bps: u32,
+    pub step: i128,
+}
+
+/// Weight schedule of an LBP sale. Weights are the token side's share of
+/// the virtual pool in bps; the reserve side holds the rest. Both must be at
+/// least 5 000 and below 10 000.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LbpConfig {
+    /// Payment-token balance the pool starts with, setting the opening price.
+    pub virtual_reserve: i128,
+    pub start_weight_bps: u32,
+    pub end_weight_bps: u32,
+}
+
+/// How a successful raise reaches the admin.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Payout {
+    /// Net raise is paid out at finalize.
+    Immediate,
+    /// Net raise is escrowed and released per milestone.
+    Milestones(MilestonePlan),
+}
+
+/// Milestone plan for a successful raise. The net raise is escrowed and paid
+/// to the admin one tranche per milestone approved by `approver` (an admin
+/// committee, multisig or governance contract).
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct MilestonePlan {
+    pub approver: Address,
+    /// Share of the net raise released per milestone; must sum to 100 %.
+    pub tranches_bps: Vec<u32>,
+}
+
+/// Progress of the milestone escrow after finalization.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct EscrowState {
+    /// Raise held after the platform fee.
+    pub net_raise: i128,
+    pub released: i128,
+    pub tranches_released: u32,
+    pub abandoned: bool,
+}
+
+/// Platform-fee buyback applied when a raise is finalized.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Buyback {
+    Disabled,
+    Enabled(BuybackConfig),
+}
+
+/// Share of the platform fee spent buying and burning the platform token.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct BuybackConfig {
+    /// DEX adapter implementing `DexAdapter::swap`.
+    pub adapter: Address,
+    pub platform_token: Address,
+    pub share_bps: u32,
+}
+
+/// Swap interface of the DEX adapter used for buybacks.
+#[contractclient(name = "DexAdapterClient")]
+pub trait DexAdapter {
+    /// Swap exactly `amount_in` of `token_in`, pulled from `from`, for at
+    /// least `min_out` of `token_out` delivered to `from`. Returns the amount
+    /// received.
+    fn swap(
+        env: Env,
+        from: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        min_out: i128,
+    ) -> i128;
+}
+
+/// Price a tier pays in place of the public `SaleConfig::price`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum TierPrice {
+    /// Payment-token units per `PRICE_SCALE` sale-token units.
+    Price(i128),
+    /// Discount off the public price, in bps.
+    DiscountBps(u32),
+}
+
+/// Post-sale liquidity provisioning applied when a raise is finalized.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Liquidity {
+    Disabled,
+    Enabled(LiquidityConfig),
+}
+
+/// Share of the net raise paired with sale tokens at the average sale price
+/// and deposited through the AMM adapter at finalize. The LP tokens are locked
+/// in the locker for `lock_ledgers`, with the admin as beneficiary.
+///
+/// `share_bps` of every token sold is kept out of the sale, so a sold-out
+/// sale still has tokens to pair. Whatever the pool refunds goes to the
+/// admin with the rest of the raise.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LiquidityConfig {
+    /// AMM adapter implementing `AmmAdapter`.
+    pub adapter: Address,
+    /// Locker implementing `Locker`.
+    pub locker: Address,
+    pub share_bps: u32,
+    pub lock_ledgers: u32,
+}
+
+/// LP position locked at finalize.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LiquidityLock {
+    pub lp_token: Address,
+    pub amount: i128,
+    pub lock_id: u64,
+    pub unlock_ledger: u32,
+}
+
+/// Liquidity interface of the AMM adapter used at finalize.
+#[contractclient(name = "AmmAdapterClient")]
+pub trait AmmAdapter {
+    /// Deposit `amount_a` of `token_a` and `amount_b` of `token_b`, pulled
+    /// from `from`, minting LP tokens to `from`. Returns the LP amount.
+    fn add_liquidity(
+        env: Env,
+        from: Address,
+        token_a: Address,
+        token_b: Address,
+        amount_a: i128,
+        amount_b: i128,
+    ) -> i128;
+
+    /// LP token of the `token_a`/`token_b` pool.
+    fn lp_token(env: Env, token_a: Address, token_b: Address) -> Address;
+}
+
+/// Interface of the locker holding LP positions.
+#[contractclient(name = "LockerClient")]
+pub trait Locker {
+    /// Lock `amount` of `token`, pulled from `from`, until `unlock_ledger`;
+    /// only `beneficiary` can withdraw it afterwards. Returns the lock id.
+    fn lock(
+        env: Env,
+        from: Address,
+        token: Address,
+        amount: i128,
+        unlock_ledger: u32,
+        beneficiary: Address,
+    ) -> u64;
+}
+
+/// Private round restricted to the addresses whitelisted for it. Outside
+/// every round the sale is open to anyone.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Round {
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+}
+
+/// Whether and how the admin may push back `end_ledger`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Extension {
+    Disabled,
+    Allowed(ExtensionRule),
+}
+
+/// Limits on deadline extensions, fixed at init so buyers know up front how
+/// far the sale can run.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ExtensionRule {
+    pub max_extensions: u32,
+    /// Longest single extension, in ledgers.
+    pub max_ledgers: u32,
+    /// Only extend while the raise is still below the soft cap.
+    pub only_below_soft_cap: bool,
+}
+
+/// Lifecycle of a sale:
+/// `Pending → Active → {Succeeded, Failed, Cancelled} → {Claiming, Refunding}
+/// → Closed`.
+///
+/// `Pending`, `Active`, `Succeeded` and `Failed` follow from the ledger and
+/// the raise; `finalize` and `cancel` store the settled state, and the sale
+/// is `Closed` once every contributor has claimed or been refunded. A
+/// cancelled sale refunds like `Refunding`. Bonding-curve sales go from
+/// `Active` straight to `Closed`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+pub enum SaleState {
+    Pending,
+    Active,
+    Succeeded,
+    Failed,
+    Cancelled,
+    Claiming,
+    Refunding,
+    Closed,
+}
+
+/// Where buyer tiers for `SaleConfig::tier_pricing` come from.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum TierSource {
+    /// Assigned by the admin with `set_tier`.
+    Admin,
+    /// Read live from the staking contract's `tier_of`.
+    Staking(Address),
+    /// Read from a staking snapshot taken before the sale was announced, so
+    /// staking just before the sale does not change allocations.
+    StakingSnapshot(Address, u32),
+}
+
+/// Platform-wide pause switch shared by the launchpad contracts.
+#[contractclient(name = "PauseRegistryClient")]
+pub trait PauseRegistry {
+    fn is_paused(env: Env, contract: Address) -> bool;
+}
+
+/// Verification lookup of the KYC registry.
+#[contractclient(name = "KycRegistryClient")]
+pub trait KycRegistry {
+    fn is_verified(env: Env, account: Address, level: u32) -> bool;
+}
+
+/// Membership lookup of the shared allowlist contract.
+#[contractclient(name = "AllowlistClient")]
+pub trait Allowlist {
+    fn is_listed(env: Env, name: Symbol, account: Address) -> bool;
+}
+
+/// Minting interface of the badges contract.
+#[contractclient(name = "BadgesClient")]
+pub trait Badges {
+    fn mint(env: Env, issuer: Address, owner: Address, kind: Symbol, level: u32) -> u64;
+}
+
+/// Activity interface of the points contract.
+#[contractclient(name = "PointsClient")]
+pub trait Points {
+    fn record(env: Env, awarder: Address, account: Address, activity: Symbol, units: i128) -> i128;
+}
+
+/// Tier interface of the staking contract.
+#[contractclient(name = "StakingClient")]
+pub trait Staking {
+    /// Tier of `staker`; 0 means no tier.
+    fn tier_of(env: Env, staker: Address) -> u32;
+
+    /// Tier of `staker` at snapshot `snapshot_id`; 0 means no tier.
+    fn tier_at(env: Env, staker: Address, snapshot_id: u32) -> u32;
+}
+
+/// Parameters of a single sale instance.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct SaleConfig {
+    /// Token being sold. The sale contract must hold enough of it to cover
+    /// every purchase.
+    pub token: Address,
+    /// Token buyers pay with. May be the native XLM Stellar Asset Contract,
+    /// in which case amounts are in stroops (7 decimals).
+    pub payment_token: Address,
+    /// Payment-token units per `PRICE_SCALE` sale-token units. Both sides are
+    /// in base units, so the decimals of the two tokens are already priced
+    /// in.
+    pub price: i128,
+    pub soft_cap: i128,
+    pub hard_cap: i128,
+    pub min_contribution: i128,
+    pub max_contribution: i128,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+    /// Platform fee taken from the raise on successful finalization, or from
+    /// every bonding-curve trade.
+    pub fee_bps: u32,
+    pub fee_recipient: Address,
+    pub guardian: Address,
+    pub mode: SaleMode,
+    pub payout: Payout,
+    pub buyback: Buyback,
+    /// Pricing per buyer tier. Buyers without a tier, or whose tier is not
+    /// listed, pay the public price. Fixed-price sales only.
+    pub tier_pricing: Map<u32, TierPrice>,
+    pub tier_source: TierSource,
+    /// Whitelisted rounds, in order and without overlap, inside the sale
+    /// window.
+    pub rounds: Vec<Round>,
+    pub extension: Extension,
+    pub liquidity: Liquidity,
+}
+
+/// Project metadata published with the sale, so explorers can render a
+/// project page without trusting off-chain JSON. Hashes are SHA-256 digests of
+/// the referenced content.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ProjectInfo {
+    pub name: String,
+    /// Must match `SaleConfig::token`.
+    pub token: Address,
+    pub website_hash: BytesN<32>,
+    pub docs_hash: BytesN<32>,
+    pub socials_hash: BytesN<32>,
+    /// Auditors of the project's contracts; empty if unaudited.
+    pub audited_by: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Purchase {
+    pub contributed: i128,
+    pub tokens: i128,
+    pub claimed: bool,
+}
+
+/// How `_contribute` pulls the payment from the buyer.
+enum Funding {
+    Transfer,
+    Allowance,
+    /// Approve the sale until the given ledger, then pull.
+    Permit(u32),
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Token Sale Contract — fixed-price raise with soft/hard caps.
+///
+/// Buyers contribute the payment token while the sale is open. On
+/// finalization a raise that met the soft cap is paid out to the admin (minus
+/// the platform fee) and buyers claim their tokens; otherwise buyers reclaim
+/// their contributions.
+///
+/// With `Payout::Milestones` the net raise stays escrowed after finalize and
+/// is released tranche by tranche as the approver signs off milestones; if
+/// the project is abandoned, contributors claw back the unreleased remainder.
+///
+/// In `SaleMode::BondingCurve` the sale instead stays open between
+/// `start_ledger` and `end_ledger` as a continuous market: tokens are bought
+/// from and sold back to the curve immediately and the caps do not apply.
+///
+/// With a badges contract set, every buyer who claims also receives a
+/// soulbound `sale` participation badge from this sale, and with a points
+/// contract set, its contribution is recorded as `contrib` activity.
+///
+/// Private rounds admit buyers whitelisted here and, with
+/// `set_round_allowlist`, everyone on a named list of the shared allowlist
+/// contract, so community lists are maintained once across sales.
+///
+/// With `set_kyc`, only buyers verified at the required level in the KYC
+/// registry can contribute or buy. `set_buyer_limit` caps how often each
+/// buyer can do so per window of ledgers.
+///
+/// Purchases and the instance are bumped by the policy in `launchpad-ttl`
+/// whenever a purchase is written, so a buyer's record stays live from
+/// contribution to claim.
+#[contract]
+pub struct SaleContract;
+
+#[contractimpl]
+impl SaleContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Configure the sale. `admin` is the project account that receives the
+    /// raise and any unsold tokens.
+    ///
+    /// The admin must transfer the tokens for sale to this contract's address
+    /// before contributions open.
+    pub fn initialize(env: Env, admin: Address, config: SaleConfig, project: ProjectInfo) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+
+        match &config.mode {
+            SaleMode::FixedPrice => assert!(config.price > 0, "price must be positive"),
+            SaleMode::BondingCurve(curve) => Self::_validate_curve(curve),
+            SaleMode::Lbp(lbp) => Self::_validate_lbp(lbp),
+        }
+        assert!(config.soft_cap >= 0, "soft_cap must be non-negative");
+        assert!(config.hard_cap > 0, "hard_cap must be positive");
+        assert!(
+            config.soft_cap <= config.hard_cap,
+            "soft_cap exceeds hard_cap"
+        );
+        assert!(
+            config.min_contribution > 0,
+            "min_contribution must be positive"
+        );
+        assert!(
+            config.min_contribution <= config.max_contribution,
+            "min_contribution exceeds max_contribution"
+        );
+        assert!(
+            config.end_ledger > config.start_ledger,
+            "end_ledger must be after start_ledger"
+        );
+        assert!(
+            config.fee_bps as i128 <= BPS_DENOMINATOR,
+            "fee_bps exceeds 100%"
+        );
+        if let Buyback::Enabled(buyback) = &config.buyback {
+            assert!(
+                buyback.share_bps as i128 <= BPS_DENOMINATOR,
+                "share_bps exceeds 100%"
+            );
+        }
+        if let Liquidity::Enabled(liquidity) = &config.liquidity {
+            Self::_require_raise(&config);
+            assert!(
+                liquidity.share_bps as i128 <= BPS_DENOMINATOR,
+                "share_bps exceeds 100%"
+            );
+            assert!(liquidity.lock_ledgers > 0, "lock_ledgers must be positive");
+        }
+        if !config.tier_pricing.is_empty() {
+            assert!(
+                config.mode == SaleMode::FixedPrice,
+                "tier pricing requires fixed-price mode"
+            );
+            for (_, tier_price) in config.tier_pricing.iter() {
+                match tier_price {
+                    TierPrice::Price(price) => assert!(price > 0, "price must be positive"),
+                    TierPrice::DiscountBps(bps) => assert!(
+                        (bps as i128) < BPS_DENOMINATOR,
+                        "discount must be below 100%"
+                    ),
+                }
+            }
+        }
+        let mut round_floor = config.start_ledger;
+        for round in config.rounds.iter() {
+            assert!(
+                round.start_ledger >= round_floor
+                    && round.end_ledger > round.start_ledger
+                    && round.end_ledger <= config.end_ledger,
+                "invalid round window"
+            );
+            round_floor = round.end_ledger;
+        }
+        if let Payout::Milestones(plan) = &config.payout {
+            Self::_require_raise(&config);
+            assert!(!plan.tranches_bps.is_empty(), "no milestone tranches");
+            let total: i128 = plan.tranches_bps.iter().map(|bps| bps as i128).sum();
+            assert!(total == BPS_DENOMINATOR, "tranches must sum to 100%");
+        }
+        if let (Extension::Allowed(_), SaleMode::Lbp(_)) = (&config.extension, &config.mode) {
+            panic!("extensions not supported in lbp mode");
+        }
+        assert!(project.token == config.token, "project token mismatch");
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Config, &config);
+        // Read by explorers only, so kept out of instance storage.
+        env.storage().persistent().set(&DataKey::Project, &project);
+        ttl::extend_persistent(&env, &DataKey::Project);
+        env.storage().instance().set(&DataKey::TotalRaised, &0i128);
+        env.storage().instance().set(&DataKey::TokensSold, &0i128);
+
+        env.events().publish(
+            (symbol_short!("init"),),
+            (admin, config.token, config.payment_token),
+        );
+    }
+
+    // ── Buyer actions ───────────────────────────────────────────────────
+
+    /// Contribute `amount` of the payment token while the sale is open.
+    pub fn contribute(env: Env, buyer: Address, amount: i128) {
+        buyer.require_auth();
+        Self::_contribute(&env, buyer, amount, Funding::Transfer);
+    }
+
+    /// Contribute `amount` pulled through a payment-token allowance, so no
+    /// separate approve transaction is needed.
+    ///
+    /// With `permit` set to an expiration ledger, the buyer's authorization
+    /// of this call also covers an `approve` of `amount` to the sale, which is
+    /// consumed right away. With `None`, an allowance granted earlier is used.
+    pub fn contribute_with_permit(env: Env, buyer: Address, amount: i128, permit: Option<u32>) {
+        let funding = match permit {
+            Some(expiration_ledger) => Funding::Permit(expiration_ledger),
+            None => Funding::Allowance,
+        };
+        buyer.require_auth();
+        Self::_contribute(&env, buyer, amount, funding);
+    }
+
+    /// Contribute `amount` for `buyer` in a transaction submitted and paid
+    /// for by `relayer`, so buyers holding no XLM can take part.
+    ///
+    /// The buyer's signature travels in the authorization entry for
+    /// `(relayer, amount, nonce, deadline)`, which also covers the payment
+    /// transfer. `nonce` must equal `nonce(buyer)` and the call must land
+    /// before ledger `deadline`, so a signed contribution can be used once
+    /// and only by the chosen relayer.
+    pub fn contribute_on_behalf(
+        env: Env,
+        relayer: Address,
+        buyer: Address,
+        amount: i128,
+        nonce: u64,
+        deadline: u32,
+    ) {
+        relayer.require_auth();
+        buyer.require_auth_for_args((relayer.clone(), amount, nonce, deadline).into_val(&env));
+        assert!(env.ledger().sequence() < deadline, "signature expired");
+
+        let key = DataKey::Nonce(buyer.clone());
+        let expected: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+        assert!(nonce == expected, "invalid nonce");
+        env.storage().persistent().set(&key, &(expected + 1));
+        ttl::extend_persistent(&env, &key);
+
+        Self::_contribute(&env, buyer.clone(), amount, Funding::Transfer);
+        env.events()
+            .publish((symbol_short!("relay"), relayer), (buyer, nonce));
+    }
+
+    /// Claim purchased tokens after a successful finalization.
+    pub fn claim(env: Env, buyer: Address) {
+        Self::_check_not_paused(&env);
+        match Self::_state(&env) {
+            SaleState::Claiming => {}
+            // Everyone has claimed; the purchase check below reports it.
+            SaleState::Closed if Self::_stored_state(&env) == Some(SaleState::Claiming) => {}
+            SaleState::Refunding | SaleState::Cancelled => panic!("sale did not succeed"),
+            SaleState::Closed => panic!("sale closed"),
+            _ => panic!("sale not finalized"),
+        }
+
+        let key = DataKey::Purchase(buyer.clone());
+        let mut purchase: Purchase = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .expect("no purchase found");
+        assert!(!purchase.claimed, "already claimed");
+
+        purchase.claimed = true;
+        Self::_set_purchase(&env, &buyer, &purchase);
+
+        let config = Self::_config(&env);
+        token::Client::new(&env, &config.token).transfer(
+            &env.current_contract_address(),
+            &buyer,
+            &purchase.tokens,
+        );
+
+        Self::_settle_one(&env);
+
+        if let Some(badges) = Self::badges(env.clone()) {
+            BadgesClient::new(&env, &badges).mint(
+                &env.current_contract_address(),
+                &buyer,
+                &SALE_BADGE,
+                &0,
+            );
+        }
+        if let Some(points) = Self::points(env.clone()) {
+            PointsClient::new(&env, &points).record(
+                &env.current_contract_address(),
+                &buyer,
+                &CONTRIBUTION_ACTIVITY,
+                &purchase.contributed,
+            );
+        }
+
+        events::claimed(&env, &buyer, purchase.tokens);
+    }
+
+    /// Reclaim a contribution after the sale failed or was cancelled.
+    pub fn refund(env: Env, buyer: Address) {
+        assert!(
+            matches!(
+                Self::_state(&env),
+                SaleState::Refunding | SaleState::Cancelled
+            ),
+            "refunds not available"
+        );
+
+        let config = Self::_config(&env);
+        Self::_require_raise(&config);
+
+        let key = DataKey::Purchase(buyer.clone());
+        let purchase: Purchase = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .expect("no purchase found");
+        env.storage().persistent().remove(&key);
+
+        token::Client::new(&env, &config.payment_token).transfer(
+            &env.current_contract_address(),
+            &buyer,
+            &purchase.contributed,
+        );
+
+        Self::_settle_one(&env);
+
+        events::refunded(&env, &buyer, purchase.contributed);
+    }
+
+    /// Buy `amount` tokens from the bonding curve, paying at most `max_cost`
+    /// (curve cost plus platform fee). Returns the total paid.
+    pub fn buy(env: Env, buyer: Address, amount: i128, max_cost: i128) -> i128 {
+        buyer.require_auth();
+        Self::_check_not_paused(&env);
+        assert!(amount > 0, "amount must be positive");
+        Self::_check_open(&env);
+
+        Self::_check_kyc(&env, &buyer);
+        Self::_check_rate_limit(&env, &buyer);
+
+        let config = Self::_config(&env);
+        let curve = Self::_curve(&config);
+        let this = env.current_contract_address();
+        let sale_token = token::Client::new(&env, &config.token);
+        assert!(
+            amount <= sale_token.balance(&this),
+            "insufficient tokens for sale"
+        );
+
+        let sold = Self::_tokens_sold(&env);
+        let cost = curve::buy_cost(&curve, sold, amount);
+        let fee = soropad_math::apply_bps(cost, config.fee_bps);
+        assert!(cost + fee <= max_cost, "cost exceeds max_cost");
+
+        let payment = token::Client::new(&env, &config.payment_token);
+        payment.transfer(&buyer, &this, &(cost + fee));
+        if fee > 0 {
+            payment.transfer(&this, &config.fee_recipient, &fee);
+        }
+        sale_token.transfer(&this, &buyer, &amount);
+
+        let mut purchase = Self::_purchase(&env, &buyer);
+        if purchase.contributed == 0 {
+            Self::_add_contributor(&env, &buyer);
+        }
+        purchase.contributed += cost;
+        purchase.tokens += amount;
+        Self::_set_purchase(&env, &buyer, &purchase);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalRaised, &(Self::_total_raised(&env) + cost));
+        env.storage()
+            .instance()
+            .set(&DataKey::TokensSold, &(sold + amount));
+        env.storage()
+            .instance()
+            .set(&DataKey::Reserve, &(Self::_reserve(&env) + cost));
+
+        env.events()
+            .publish((symbol_short!("buy"), buyer), (amount, cost + fee));
+        cost + fee
+    }
+
+    /// Sell `amount` tokens back to the bonding curve, receiving at least
+    /// `min_proceeds` (curve proceeds minus platform fee). Returns the amount
+    /// received. Selling stays possible after the sale ends or is cancelled.
+    pub fn sell(env: Env, seller: Address, amount: i128, min_proceeds: i128) -> i128 {
+        seller.require_auth();
+        Self::_check_not_paused(&env);
+        assert!(amount > 0, "amount must be positive");
+
+        let config = Self::_config(&env);
+        let curve = Self::_curve(&config);
+        let sold = Self::_tokens_sold(&env);
+        assert!(amount <= sold, "amount exceeds tokens sold");
+
+        let proceeds = curve::sell_proceeds(&curve, sold, amount);
+        let fee = soropad_math::apply_bps(proceeds, config.fee_bps);
+        assert!(
+            proceeds - fee >= min_proceeds,
+            "proceeds below min_proceeds"
+        );
+
+        let this = env.current_contract_address();
+        token::Client::new(&env, &config.token).transfer(&seller, &this, &amount);
+        let payment = token::Client::new(&env, &config.payment_token);
+        if proceeds - fee > 0 {
+            payment.transfer(&this, &seller, &(proceeds - fee));
+        }
+        if fee > 0 {
+            payment.transfer(&this, &config.fee_recipient, &fee);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TokensSold, &(sold - amount));
+        env.storage()
+            .instance()
+            .set(&DataKey::Reserve, &(Self::_reserve(&env) - proceeds));
+
+        env.events()
+            .publish((symbol_short!("sell"), seller), (amount, proceeds - fee));
+        proceeds - fee
+    }
+
+    // ── Settlement ──────────────────────────────────────────────────────
+
+    /// Settle the sale once it has ended or the hard cap is reached.
+    /// Can be called by anyone.
+    ///
+    /// On success the raise (minus the platform fee) goes to the admin and
+    /// unsold tokens are returned; on failure every sale token is returned and
+    /// buyers may `refund`.
+    pub fn finalize(env: Env) {
+        let config = Self::_config(&env);
+        Self::_require_raise(&config);
+        let succeeded = match Self::_state(&env) {
+            SaleState::Succeeded => true,
+            SaleState::Failed => false,
+            SaleState::Pending | SaleState::Active => panic!("sale still active"),
+            SaleState::Cancelled => panic!("sale cancelled"),
+            _ => panic!("already finalized"),
+        };
+        Self::_settle(
+            &env,
+            if succeeded {
+                SaleState::Claiming
+            } else {
+                SaleState::Refunding
+            },
+        );
+
+        let raised = Self::_total_raised(&env);
+        let admin = Self::admin(env.clone());
+        let this = env.current_contract_address();
+        let sale_token = token::Client::new(&env, &config.token);
+
+        if succeeded {
+            let fee = soropad_math::apply_bps(raised, config.fee_bps);
+            let payment = token::Client::new(&env, &config.payment_token);
+            let buyback = match &config.buyback {
+                Buyback::Enabled(buyback) => soropad_math::apply_bps(fee, buyback.share_bps),
+                Buyback::Disabled => 0,
+            };
+            if buyback > 0 {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::BuybackPending, &buyback);
+            }
+            if fee - buyback > 0 {
+                payment.transfer(&this, &config.fee_recipient, &(fee - buyback));
+            }
+            let mut net_raise = raised - fee;
+            if let Liquidity::Enabled(liquidity) = &config.liquidity {
+                net_raise -= Self::_provide_liquidity(&env, &config, liquidity, net_raise, &admin);
+            }
+            if let Payout::Milestones(_) = config.payout {
+                env.storage().instance().set(
+                    &DataKey::Escrow,
+                    &EscrowState {
+                        net_raise,
+                        released: 0,
+                        tranches_released: 0,
+                        abandoned: false,
+                    },
+                );
+            } else if net_raise > 0 {
+                payment.transfer(&this, &admin, &net_raise);
+            }
+
+            let unsold = sale_token.balance(&this) - Self::_tokens_sold(&env);
+            if unsold > 0 {
+                sale_token.transfer(&this, &admin, &unsold);
+            }
+        } else {
+            let inventory = sale_token.balance(&this);
+            if inventory > 0 {
+                sale_token.transfer(&this, &admin, &inventory);
+            }
+        }
+
+        events::sale_finalized(
+            &env,
+            events::SaleFinalized {
+                succeeded,
+                total_raised: raised,
+            },
+        );
+    }
+
+    /// Spend the fee slice reserved at finalize on the platform token through
+    /// the DEX adapter and burn what was bought. Guardian only, since the
+    /// caller picks the slippage bound. Returns the amount burned.
+    pub fn buyback_and_burn(env: Env, min_out: i128) -> i128 {
+        let config = Self::_config(&env);
+        config.guardian.require_auth();
+        let buyback = match &config.buyback {
+            Buyback::Enabled(buyback) => buyback.clone(),
+            Buyback::Disabled => panic!("buyback disabled"),
+        };
+
+        let spent = Self::buyback_pending(env.clone());
+        assert!(spent > 0, "nothing to buy back");
+        env.storage().instance().remove(&DataKey::BuybackPending);
+
+        let this = env.current_contract_address();
+        Self::_authorize_pull(&env, &config.payment_token, &buyback.adapter, spent);
+        let bought = DexAdapterClient::new(&env, &buyback.adapter).swap(
+            &this,
+            &config.payment_token,
+            &buyback.platform_token,
+            &spent,
+            &min_out,
+        );
+        assert!(bought >= min_out, "insufficient output");
+        token::Client::new(&env, &buyback.platform_token).burn(&this, &bought);
+
+        env.events()
+            .publish((symbol_short!("buyback"),), (spent, bought));
+        bought
+    }
+
+    // ── Deadline ────────────────────────────────────────────────────────
+
+    /// Push `end_ledger` back by `ledgers`, within the `Extension` rule set at
+    /// init. Only while the sale is still open. Admin only.
+    pub fn extend(env: Env, ledgers: u32) -> u32 {
+        Self::_require_admin(&env);
+        Self::_check_open(&env);
+
+        let mut config = Self::_config(&env);
+        let rule = match &config.extension {
+            Extension::Allowed(rule) => rule.clone(),
+            Extension::Disabled => panic!("extensions disabled"),
+        };
+        let used = Self::extensions_used(env.clone());
+        assert!(used < rule.max_extensions, "no extensions left");
+        assert!(
+            ledgers > 0 && ledgers <= rule.max_ledgers,
+            "extension too long"
+        );
+        if rule.only_below_soft_cap {
+            assert!(
+                Self::_total_raised(&env) < config.soft_cap,
+                "soft cap already reached"
+            );
+        }
+
+        config.end_ledger += ledgers;
+        env.storage().instance().set(&DataKey::Config, &config);
+        env.storage()
+            .instance()
+            .set(&DataKey::Extensions, &(used + 1));
+
+        env.events()
+            .publish((symbol_short!("extend"),), config.end_ledger);
+        config.end_ledger
+    }
+
+    // ── Milestone escrow ────────────────────────────────────────────────
+
+    /// Release the next escrowed tranche to the admin. Approver only.
+    pub fn approve_milestone(env: Env) -> i128 {
+        let plan = Self::_milestone_plan(&env);
+        plan.approver.require_auth();
+
+        let mut escrow = Self::escrow(env.clone());
+        assert!(!escrow.abandoned, "project abandoned");
+        assert!(
+            escrow.tranches_released < plan.tranches_bps.len(),
+            "all milestones released"
+        );
+
+        let index = escrow.tranches_released;
+        let amount = if index + 1 == plan.tranches_bps.len() {
+            escrow.net_raise - escrow.released
+        } else {
+            soropad_math::apply_bps(escrow.net_raise, plan.tranches_bps.get_unchecked(index))
+        };
+        escrow.released += amount;
+        escrow.tranches_released += 1;
+        env.storage().instance().set(&DataKey::Escrow, &escrow);
+
+        if amount > 0 {
+            let config = Self::_config(&env);
+            token::Client::new(&env, &config.payment_token).transfer(
+                &env.current_contract_address(),
+                &Self::admin(env.clone()),
+                &amount,
+            );
+        }
+
+        env.events()
+            .publish((symbol_short!("milestone"), index), amount);
+        amount
+    }
+
+    /// Declare the project abandoned, freezing the escrow so contributors can
+    /// `clawback` their share of the unreleased raise. Approver only.
+    pub fn abandon(env: Env) {
+        let plan = Self::_milestone_plan(&env);
+        plan.approver.require_auth();
+
+        let mut escrow = Self::escrow(env.clone());
+        assert!(!escrow.abandoned, "project abandoned");
+        escrow.abandoned = true;
+        env.storage().instance().set(&DataKey::Escrow, &escrow);
+
+        env.events().publish(
+            (symbol_short!("abandon"),),
+            escrow.net_raise - escrow.released,
+        );
+    }
+
+    /// Reclaim `buyer`'s pro-rata share of the unreleased escrow after the
+    /// project was abandoned. Purchased tokens are kept.
+    pub fn clawback(env: Env, buyer: Address) -> i128 {
+        let escrow = Self::escrow(env.clone());
+        assert!(escrow.abandoned, "project not abandoned");
+
+        let key = DataKey::ClawedBack(buyer.clone());
+        assert!(!env.storage().persistent().has(&key), "already clawed back");
+
+        let purchase = Self::_purchase(&env, &buyer);
+        assert!(purchase.contributed > 0, "no purchase found");
+        let amount = soropad_math::mul_div(
+            escrow.net_raise - escrow.released,
+            purchase.contributed,
+            Self::_total_raised(&env),
+        );
+        env.storage().persistent().set(&key, &true);
+        ttl::extend_persistent(&env, &key);
+
+        if amount > 0 {
+            let config = Self::_config(&env);
+            token::Client::new(&env, &config.payment_token).transfer(
+                &env.current_contract_address(),
+                &buyer,
+                &amount,
+            );
+        }
+
+        env.events()
+            .publish((symbol_short!("clawback"), buyer), amount);
+        amount
+    }
+
+    /// Cancel the sale before finalization. Buyers may then `refund` and the
+    /// sale tokens are returned to the admin. Admin only.
+    pub fn cancel(env: Env) {
+        Self::_require_admin(&env);
+        match Self::_stored_state(&env) {
+            Some(SaleState::Cancelled) => panic!("sale cancelled"),
+            Some(_) => panic!("already finalized"),
+            None => {}
+        }
+        Self::_settle(&env, SaleState::Cancelled);
+
+        let config = Self::_config(&env);
+        let this = env.current_contract_address();
+        let sale_token = token::Client::new(&env, &config.token);
+        let inventory = sale_token.balance(&this);
+        if inventory > 0 {
+            sale_token.transfer(&this, &Self::admin(env.clone()), &inventory);
+        }
+
+        events::sale_cancelled(&env);
+    }
+
+    // ── Access ──────────────────────────────────────────────────────────
+
+    /// Whitelist `buyers` for private round `round`. Admin only.
+    pub fn add_to_whitelist(env: Env, round: u32, buyers: Vec<Address>) {
+        Self::_require_admin(&env);
+        assert!(
+            round < Self::_config(&env).rounds.len(),
+            "round does not exist"
+        );
+        for buyer in buyers.iter() {
+            let key = DataKey::Whitelisted(round, buyer.clone());
+            env.storage().persistent().set(&key, &true);
+            ttl::extend_persistent(&env, &key);
+            env.events()
+                .publish((symbol_short!("wl_add"), buyer), round);
+        }
+    }
+
+    /// Remove `buyers` from the whitelist of round `round`. Admin only.
+    pub fn remove_from_whitelist(env: Env, round: u32, buyers: Vec<Address>) {
+        Self::_require_admin(&env);
+        for buyer in buyers.iter() {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Whitelisted(round, buyer.clone()));
+            env.events().publish((symbol_short!("wl_rm"), buyer), round);
+        }
+    }
+
+    /// Also admit to private round `round` everyone on list `list` of the
+    /// shared `allowlist` contract, or stop with `None`. Admin only.
+    pub fn set_round_allowlist(env: Env, round: u32, allowlist: Option<Address>, list: Symbol) {
+        Self::_require_admin(&env);
+        assert!(
+            round < Self::_config(&env).rounds.len(),
+            "round does not exist"
+        );
+        let key = DataKey::RoundAllowlist(round);
+        match &allowlist {
+            Some(contract) => env
+                .storage()
+                .instance()
+                .set(&key, &(contract.clone(), list.clone())),
+            None => env.storage().instance().remove(&key),
+        }
+        env.events()
+            .publish((symbol_short!("wl_list"), round), (allowlist, list));
+    }
+
+    /// Assign `tier` to each of `buyers`, selecting their entry in
+    /// `SaleConfig::tier_pricing`. Admin only, and only with
+    /// `TierSource::Admin`.
+    pub fn set_tier(env: Env, buyers: Vec<Address>, tier: u32) {
+        Self::_require_admin(&env);
+        assert!(
+            Self::_config(&env).tier_source == TierSource::Admin,
+            "tiers come from staking"
+        );
+        for buyer in buyers.iter() {
+            let key = DataKey::Tier(buyer.clone());
+            env.storage().persistent().set(&key, &tier);
+            ttl::extend_persistent(&env, &key);
+            env.events().publish((symbol_short!("tier"), buyer), tier);
+        }
+    }
+
+    /// Require buyers to be verified at `level` in `kyc_registry`, or drop
+    /// the requirement with `None`. Admin only.
+    pub fn set_kyc(env: Env, kyc_registry: Option<Address>, level: u32) {
+        Self::_require_admin(&env);
+        match &kyc_registry {
+            Some(registry) => env
+                .storage()
+                .instance()
+                .set(&DataKey::Kyc, &(registry.clone(), level)),
+            None => env.storage().instance().remove(&DataKey::Kyc),
+        }
+        env.events()
+            .publish((symbol_short!("kyc"),), (kyc_registry, level));
+    }
+
+    /// Allow each buyer at most `limit.limit` contributions or buys per
+    /// window, to slow down bots, or lift the limit with `None`. Admin only.
+    pub fn set_buyer_limit(env: Env, limit: Option<RateLimit>) {
+        Self::_require_admin(&env);
+        match &limit {
+            Some(limit) => {
+                limit.validate();
+                env.storage().instance().set(&DataKey::BuyerLimit, limit);
+            }
+            None => env.storage().instance().remove(&DataKey::BuyerLimit),
+        }
+        env.events().publish((symbol_short!("buyer_lim"),), limit);
+    }
+
+    /// Issue participation badges through `badges`, which must list this
+    /// sale as a minter. Admin only.
+    pub fn set_badges(env: Env, badges: Address) {
+        Self::_require_admin(&env);
+        env.storage().instance().set(&DataKey::Badges, &badges);
+        env.events().publish((symbol_short!("badges"),), badges);
+    }
+
+    /// Record claimed contributions in `points`, which must list this sale
+    /// as an awarder. Admin only.
+    pub fn set_points(env: Env, points: Address) {
+        Self::_require_admin(&env);
+        env.storage().instance().set(&DataKey::Points, &points);
+        env.events().publish((symbol_short!("points"),), points);
+    }
+
+    // ── Guardian ────────────────────────────────────────────────────────
+
+    /// Freeze `contribute`, `claim`, `buy` and `sell`, e.g. while an oracle
+    /// or the payment token is misbehaving. Refunds stay available. Guardian
+    /// only.
+    pub fn pause(env: Env) {
+        Self::_config(&env).guardian.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &true);
+        env.events().publish((symbol_short!("pause"),), true);
+    }
+
+    /// Resume a paused sale. Guardian only.
+    pub fn unpause(env: Env) {
+        Self::_config(&env).guardian.require_auth();
+        env.storage().instance().remove(&DataKey::Paused);
+        env.events().publish((symbol_short!("pause"),), false);
+    }
+
+    /// Also pause while the platform's pause registry has this sale paused.
+    /// Guardian only, so a project cannot opt out of a platform-wide freeze.
+    pub fn set_pause_registry(env: Env, registry: Address) {
+        Self::_config(&env).guardian.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::PauseRegistry, &registry);
+        env.events()
+            .publish((symbol_short!("pause_reg"),), registry);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Total payment collected (excluding bonding-curve fees).
+    pub fn total_raised(env: Env) -> i128 {
+        Self::_total_raised(&env)
+    }
+
+    /// Sale tokens sold and not sold back.
+    pub fn tokens_sold(env: Env) -> i128 {
+        Self::_tokens_sold(&env)
+    }
+
+    /// Raise progress toward the hard cap, in bps.
+    pub fn progress_bps(env: Env) -> u32 {
+        let hard_cap = Self::_config(&env).hard_cap;
+        soropad_math::to_bps(Self::_total_raised(&env), hard_cap) as u32
+    }
+
+    /// Number of distinct addresses that have contributed.
+    pub fn contributor_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ContributorCount)
+            .unwrap_or(0)
+    }
+
+    /// Total payment contributed by `buyer`.
+    pub fn contribution_of(env: Env, buyer: Address) -> i128 {
+        Self::_purchase(&env, &buyer).contributed
+    }
+
+    /// Up to `limit` contributors in order of first contribution, starting at
+    /// index `start`.
+    pub fn contributors(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        let count = Self::contributor_count(env.clone());
+        let end = start.saturating_add(limit).min(count);
+        let mut result = Vec::new(&env);
+        for index in start..end {
+            let contributor: Address = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ContributorAt(index))
+                .expect("contributor missing");
+            result.push_back(contributor);
+        }
+        result
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    pub fn get_config(env: Env) -> SaleConfig {
+        Self::_config(&env)
+    }
+
+    pub fn project_info(env: Env) -> ProjectInfo {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Project)
+            .expect("not initialized")
+    }
+
+    /// Return the purchase record of `buyer` (zeroed if they never bought).
+    pub fn get_purchase(env: Env, buyer: Address) -> Purchase {
+        Self::_purchase(&env, &buyer)
+    }
+
+    /// Marginal price of the next token, in payment units per
+    /// `PRICE_SCALE` tokens.
+    pub fn current_price(env: Env) -> i128 {
+        let config = Self::_config(&env);
+        match &config.mode {
+            SaleMode::FixedPrice => config.price,
+            SaleMode::BondingCurve(curve) => curve::spot_price(curve, Self::_tokens_sold(&env)),
+            SaleMode::Lbp(_) => Self::price_at(env.clone(), env.ledger().sequence()),
+        }
+    }
+
+    /// LBP spot price at `ledger` assuming no further contributions, so
+    /// frontends can chart the price decay over the sale window.
+    pub fn price_at(env: Env, ledger: u32) -> i128 {
+        let config = Self::_config(&env);
+        let lbp = Self::_lbp(&config);
+        let weight = lbp::token_weight(&lbp, config.start_ledger, config.end_ledger, ledger);
+        lbp::spot_price(
+            weight,
+            Self::_unsold(&env, &config),
+            lbp.virtual_reserve + Self::_total_raised(&env),
+        )
+    }
+
+    /// Tokens a contribution of `amount` would buy right now.
+    pub fn simulate_contribution(env: Env, amount: i128) -> i128 {
+        let config = Self::_config(&env);
+        Self::_require_raise(&config);
+        Self::_tokens_for(&env, &config, config.price, amount)
+    }
+
+    /// Price `buyer` pays per `PRICE_SCALE` tokens after any tier discount.
+    pub fn effective_price(env: Env, buyer: Address) -> i128 {
+        let config = Self::_config(&env);
+        Self::_effective_price(&env, &config, &buyer)
+    }
+
+    /// Whether `buyer` may contribute in private round `round`, either
+    /// whitelisted here or on the round's shared allowlist.
+    pub fn is_whitelisted(env: Env, round: u32, buyer: Address) -> bool {
+        let listed_here = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Whitelisted(round, buyer.clone()))
+            .unwrap_or(false);
+        listed_here
+            || Self::round_allowlist(env.clone(), round)
+                .map(|(allowlist, list)| {
+                    AllowlistClient::new(&env, &allowlist).is_listed(&list, &buyer)
+                })
+                .unwrap_or(false)
+    }
+
+    /// Shared allowlist contract and list name admitted to round `round`.
+    pub fn round_allowlist(env: Env, round: u32) -> Option<(Address, Symbol)> {
+        env.storage()
+            .instance()
+            .get(&DataKey::RoundAllowlist(round))
+    }
+
+    /// Index of the private round in progress, or `None` during the public
+    /// phase.
+    pub fn current_round(env: Env) -> Option<u32> {
+        Self::_current_round(&env, &Self::_config(&env))
+    }
+
+    /// Next nonce `buyer` must sign for `contribute_on_behalf`.
+    pub fn nonce(env: Env, buyer: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Nonce(buyer))
+            .unwrap_or(0)
+    }
+
+    /// Tier assigned to `buyer`, if any.
+    pub fn tier_of(env: Env, buyer: Address) -> Option<u32> {
+        Self::_tier(&env, &Self::_config(&env), &buyer)
+    }
+
+    /// Total payment (fee included) to `buy` `amount` tokens right now.
+    pub fn quote_buy(env: Env, amount: i128) -> i128 {
+        let config = Self::_config(&env);
+        let cost = curve::buy_cost(&Self::_curve(&config), Self::_tokens_sold(&env), amount);
+        cost + soropad_math::apply_bps(cost, config.fee_bps)
+    }
+
+    /// Net payment (fee deducted) received to `sell` `amount` tokens right now.
+    pub fn quote_sell(env: Env, amount: i128) -> i128 {
+        let config = Self::_config(&env);
+        let sold = Self::_tokens_sold(&env);
+        assert!(amount <= sold, "amount exceeds tokens sold");
+        let proceeds = curve::sell_proceeds(&Self::_curve(&config), sold, amount);
+        proceeds - soropad_math::apply_bps(proceeds, config.fee_bps)
+    }
+
+    /// Whether buyers pay in native XLM.
+    pub fn is_native_payment(env: Env) -> bool {
+        Self::_config(&env).payment_token == Self::_native_token(&env)
+    }
+
+    /// Decimals of the payment token, for converting base-unit amounts.
+    pub fn payment_decimals(env: Env) -> u32 {
+        token::Client::new(&env, &Self::_config(&env).payment_token).decimals()
+    }
+
+    /// Payment tokens held against outstanding bonding-curve tokens.
+    pub fn reserve(env: Env) -> i128 {
+        Self::_reserve(&env)
+    }
+
+    /// LP position locked at finalize.
+    pub fn liquidity_lock(env: Env) -> LiquidityLock {
+        env.storage()
+            .instance()
+            .get(&DataKey::LiquidityLock)
+            .expect("no liquidity lock")
+    }
+
+    /// Payment tokens reserved for `buyback_and_burn`.
+    pub fn buyback_pending(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::BuybackPending)
+            .unwrap_or(0)
+    }
+
+    /// Milestone escrow state; only available after a successful finalize of
+    /// a sale with a milestone plan.
+    pub fn escrow(env: Env) -> EscrowState {
+        env.storage()
+            .instance()
+            .get(&DataKey::Escrow)
+            .expect("no escrow")
+    }
+
+    /// Current lifecycle state; see `SaleState`.
+    pub fn state(env: Env) -> SaleState {
+        Self::_state(&env)
+    }
+
+    pub fn is_finalized(env: Env) -> bool {
+        Self::_is_finalized(&env)
+    }
+
+    pub fn is_cancelled(env: Env) -> bool {
+        Self::_is_cancelled(&env)
+    }
+
+    /// Deadline extensions granted so far.
+    pub fn extensions_used(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Extensions)
+            .unwrap_or(0)
+    }
+
+    /// Whether the sale is paused by its guardian or by the pause registry.
+    pub fn is_paused(env: Env) -> bool {
+        if env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false)
+        {
+            return true;
+        }
+        match Self::pause_registry(env.clone()) {
+            Some(registry) => {
+                PauseRegistryClient::new(&env, &registry).is_paused(&env.current_contract_address())
+            }
+            None => false,
+        }
+    }
+
+    pub fn pause_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PauseRegistry)
+    }
+
+    pub fn badges(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Badges)
+    }
+
+    pub fn points(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Points)
+    }
+
+    /// KYC registry and level buyers must be verified at, if required.
+    pub fn kyc_requirement(env: Env) -> Option<(Address, u32)> {
+        env.storage().instance().get(&DataKey::Kyc)
+    }
+
+    pub fn buyer_limit(env: Env) -> Option<RateLimit> {
+        env.storage().instance().get(&DataKey::BuyerLimit)
+    }
+
+    /// Contributions or buys `buyer` has left in the current window, if
+    /// limited.
+    pub fn buyer_actions_left(env: Env, buyer: Address) -> Option<i128> {
+        Self::buyer_limit(env.clone())
+            .map(|limit| rate_limit::available(&env, &DataKey::BuyerUsage(buyer), &limit))
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _check_rate_limit(env: &Env, buyer: &Address) {
+        if let Some(limit) = Self::buyer_limit(env.clone()) {
+            rate_limit::consume(env, &DataKey::BuyerUsage(buyer.clone()), &limit, 1);
+        }
+    }
+
+    fn _check_kyc(env: &Env, buyer: &Address) {
+        if let Some((registry, level)) = Self::kyc_requirement(env.clone()) {
+            assert!(
+                KycRegistryClient::new(env, &registry).is_verified(buyer, &level),
+                "kyc required"
+            );
+        }
+    }
+
+    fn _contribute(env: &Env, buyer: Address, amount: i128, funding: Funding) {
+        Self::_check_not_paused(env);
+        assert!(amount > 0, "amount must be positive");
+        Self::_check_open(env);
+        Self::_check_kyc(env, &buyer);
+        Self::_check_rate_limit(env, &buyer);
+
+        let config = Self::_config(env);
+        Self::_require_raise(&config);
+        if let Some(round) = Self::_current_round(env, &config) {
+            assert!(
+                Self::is_whitelisted(env.clone(), round, buyer.clone()),
+                "not whitelisted for round"
+            );
+        }
+        let mut purchase = Self::_purchase(env, &buyer);
+        if purchase.contributed == 0 {
+            Self::_add_contributor(env, &buyer);
+        }
+
+        let contributed = purchase.contributed + amount;
+        assert!(
+            contributed >= config.min_contribution,
+            "below min_contribution"
+        );
+        assert!(
+            contributed <= config.max_contribution,
+            "exceeds max_contribution"
+        );
+
+        let raised: i128 = Self::_total_raised(env);
+        assert!(raised + amount <= config.hard_cap, "exceeds hard_cap");
+
+        let price = Self::_effective_price(env, &config, &buyer);
+        let tokens = Self::_tokens_for(env, &config, price, amount);
+        assert!(tokens > 0, "amount too small");
+
+        let sold: i128 = Self::_tokens_sold(env);
+        let inventory =
+            token::Client::new(env, &config.token).balance(&env.current_contract_address());
+        assert!(
+            sold + tokens + Self::_liquidity_reserve(&config, sold + tokens) <= inventory,
+            "insufficient tokens for sale"
+        );
+
+        let payment = token::Client::new(env, &config.payment_token);
+        let this = env.current_contract_address();
+        match funding {
+            Funding::Transfer => payment.transfer(&buyer, &this, &amount),
+            Funding::Allowance => payment.transfer_from(&this, &buyer, &this, &amount),
+            Funding::Permit(expiration_ledger) => {
+                payment.approve(&buyer, &this, &amount, &expiration_ledger);
+                payment.transfer_from(&this, &buyer, &this, &amount);
+            }
+        }
+
+        purchase.contributed = contributed;
+        purchase.tokens += tokens;
+        Self::_set_purchase(env, &buyer, &purchase);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalRaised, &(raised + amount));
+        env.storage()
+            .instance()
+            .set(&DataKey::TokensSold, &(sold + tokens));
+
+        events::contributed(env, &buyer, events::Contribution { amount, tokens });
+    }
+
+    fn _require_admin(env: &Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized");
+        admin.require_auth();
+    }
+
+    fn _config(env: &Env) -> SaleConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .expect("not initialized")
+    }
+
+    fn _purchase(env: &Env, buyer: &Address) -> Purchase {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Purchase(buyer.clone()))
+            .unwrap_or(Purchase {
+                contributed: 0,
+                tokens: 0,
+                claimed: false,
+            })
+    }
+
+    fn _set_purchase(env: &Env, buyer: &Address, purchase: &Purchase) {
+        let key = DataKey::Purchase(buyer.clone());
+        env.storage().persistent().set(&key, purchase);
+        ttl::extend_persistent(env, &key);
+        ttl::extend_instance(env);
+    }
+
+    fn _add_contributor(env: &Env, buyer: &Address) {
+        let count: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ContributorCount)
+            .unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ContributorAt(count), buyer);
+        ttl::extend_persistent(env, &DataKey::ContributorAt(count));
+        env.storage()
+            .instance()
+            .set(&DataKey::ContributorCount, &(count + 1));
+    }
+
+    fn _total_raised(env: &Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalRaised)
+            .unwrap_or(0)
+    }
+
+    fn _tokens_sold(env: &Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TokensSold)
+            .unwrap_or(0)
+    }
+
+    fn _reserve(env: &Env) -> i128 {
+        env.storage().instance().get(&DataKey::Reserve).unwrap_or(0)
+    }
+
+    fn _curve(config: &SaleConfig) -> Curve {
+        match &config.mode {
+            SaleMode::BondingCurve(curve) => curve.clone(),
+            _ => panic!("not a bonding-curve sale"),
+        }
+    }
+
+    fn _milestone_plan(env: &Env) -> MilestonePlan {
+        match Self::_config(env).payout {
+            Payout::Milestones(plan) => plan,
+            Payout::Immediate => panic!("no milestone plan"),
+        }
+    }
+
+    fn _lbp(config: &SaleConfig) -> LbpConfig {
+        match &config.mode {
+            SaleMode::Lbp(lbp) => lbp.clone(),
+            _ => panic!("not an lbp sale"),
+        }
+    }
+
+    fn _require_raise(config: &SaleConfig) {
+        if let SaleMode::BondingCurve(_) = config.mode {
+            panic!("not available in bonding-curve mode");
+        }
+    }
+
+    /// Fixed price charged to `buyer`, falling back to the public price.
+    fn _effective_price(env: &Env, config: &SaleConfig, buyer: &Address) -> i128 {
+        let tier = Self::_tier(env, config, buyer);
+        match tier.and_then(|tier| config.tier_pricing.get(tier)) {
+            Some(TierPrice::Price(price)) => price,
+            Some(TierPrice::DiscountBps(bps)) => {
+                soropad_math::mul_div(config.price, BPS_DENOMINATOR - bps as i128, BPS_DENOMINATOR)
+            }
+            None => config.price,
+        }
+    }
+
+    fn _tier(env: &Env, config: &SaleConfig, buyer: &Address) -> Option<u32> {
+        match &config.tier_source {
+            TierSource::Admin => env
+                .storage()
+                .persistent()
+                .get(&DataKey::Tier(buyer.clone())),
+            TierSource::Staking(staking) => {
+                Self::_staking_tier(StakingClient::new(env, staking).tier_of(buyer))
+            }
+            TierSource::StakingSnapshot(staking, snapshot_id) => {
+                Self::_staking_tier(StakingClient::new(env, staking).tier_at(buyer, snapshot_id))
+            }
+        }
+    }
+
+    /// Staking reports "no tier" as tier 0.
+    fn _staking_tier(tier: u32) -> Option<u32> {
+        match tier {
+            0 => None,
+            tier => Some(tier),
+        }
+    }
+
+    /// Sale tokens bought by a contribution of `amount`. Fixed-price sales
+    /// charge `price`; LBP sales price off the pool instead.
+    fn _tokens_for(env: &Env, config: &SaleConfig, price: i128, amount: i128) -> i128 {
+        match &config.mode {
+            SaleMode::Lbp(lbp) => {
+                let weight = lbp::token_weight(
+                    lbp,
+                    config.start_ledger,
+                    config.end_ledger,
+                    env.ledger().sequence(),
+                );
+                lbp::tokens_out(
+                    weight,
+                    Self::_unsold(env, config),
+                    lbp.virtual_reserve + Self::_total_raised(env),
+                    amount,
+                )
+            }
+            _ => soropad_math::mul_div(amount, PRICE_SCALE, price),
+        }
+    }
+
+    /// Let `to` pull `amount` of `token` from this contract. Transfers made
+    /// by a contract we call are nested and need explicit authorization.
+    fn _authorize_pull(env: &Env, token: &Address, to: &Address, amount: i128) {
+        env.authorize_as_current_contract(vec![
+            env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token.clone(),
+                    fn_name: Symbol::new(env, "transfer"),
+                    args: (env.current_contract_address(), to.clone(), amount).into_val(env),
+                },
+                sub_invocations: vec![env],
+            }),
+        ]);
+    }
+
+    /// Sale tokens held back from buyers once `sold` are sold, so finalize
+    /// can still pair the liquidity share with tokens. The share of the net
+    /// raise is never more than the same share of the tokens sold at the
+    /// average price.
+    fn _liquidity_reserve(config: &SaleConfig, sold: i128) -> i128 {
+        match &config.liquidity {
+            Liquidity::Enabled(liquidity) => soropad_math::apply_bps(sold, liquidity.share_bps),
+            Liquidity::Disabled => 0,
+        }
+    }
+
+    /// Pair a share of `net_raise` with unsold tokens at the average sale
+    /// price, deposit both through the AMM adapter and lock the LP tokens.
+    /// Returns the payment tokens the pool actually took.
+    fn _provide_liquidity(
+        env: &Env,
+        config: &SaleConfig,
+        liquidity: &LiquidityConfig,
+        net_raise: i128,
+        admin: &Address,
+    ) -> i128 {
+        let this = env.current_contract_address();
+        let raised = Self::_total_raised(env);
+        let sold = Self::_tokens_sold(env);
+        let unsold = Self::_unsold(env, config);
+        if sold == 0 {
+            return 0;
+        }
+
+        let mut payment_amount = soropad_math::apply_bps(net_raise, liquidity.share_bps);
+        let mut token_amount = soropad_math::mul_div(payment_amount, sold, raised);
+        if token_amount > unsold {
+            token_amount = unsold;
+            payment_amount = soropad_math::mul_div(unsold, raised, sold);
+        }
+        if token_amount == 0 || payment_amount == 0 {
+            return 0;
+        }
+
+        // The adapter refunds whatever the pool does not take, so only the
+        // balance change tells what went in.
+        let sale_token = token::Client::new(env, &config.token);
+        let payment = token::Client::new(env, &config.payment_token);
+        let (tokens_before, payment_before) = (sale_token.balance(&this), payment.balance(&this));
+
+        let adapter = AmmAdapterClient::new(env, &liquidity.adapter);
+        Self::_authorize_pull(env, &config.token, &liquidity.adapter, token_amount);
+        Self::_authorize_pull(
+            env,
+            &config.payment_token,
+            &liquidity.adapter,
+            payment_amount,
+        );
+        let lp_amount = adapter.add_liquidity(
+            &this,
+            &config.token,
+            &config.payment_token,
+            &token_amount,
+            &payment_amount,
+        );
+        let lp_token = adapter.lp_token(&config.token, &config.payment_token);
+        let tokens_spent = tokens_before - sale_token.balance(&this);
+        let payment_spent = payment_before - payment.balance(&this);
+
+        let unlock_ledger = env.ledger().sequence() + liquidity.lock_ledgers;
+        Self::_authorize_pull(env, &lp_token, &liquidity.locker, lp_amount);
+        let lock_id = LockerClient::new(env, &liquidity.locker).lock(
+            &this,
+            &lp_token,
+            &lp_amount,
+            &unlock_ledger,
+            admin,
+        );
+
+        env.storage().instance().set(
+            &DataKey::LiquidityLock,
+            &LiquidityLock {
+                lp_token,
+                amount: lp_amount,
+                lock_id,
+                unlock_ledger,
+            },
+        );
+        env.events().publish(
+            (symbol_short!("lp_lock"),),
+            (lp_amount, unlock_ledger, tokens_spent, payment_spent),
+        );
+        payment_spent
+    }
+
+    /// Address of the Stellar Asset Contract wrapping native XLM.
+    fn _native_token(env: &Env) -> Address {
+        // XDR encoding of `Asset::Native`.
+        let native = Bytes::from_array(env, &[0u8; 4]);
+        env.deployer().with_stellar_asset(native).deployed_address()
+    }
+
+    /// Sale tokens held by the contract and not yet sold.
+    fn _unsold(env: &Env, config: &SaleConfig) -> i128 {
+        token::Client::new(env, &config.token).balance(&env.current_contract_address())
+            - Self::_tokens_sold(env)
+    }
+
+    fn _validate_lbp(lbp: &LbpConfig) {
+        assert!(lbp.virtual_reserve > 0, "virtual_reserve must be positive");
+        for weight in [lbp.start_weight_bps, lbp.end_weight_bps] {
+            assert!(
+                (weight as i128) >= lbp::MIN_TOKEN_WEIGHT_BPS && (weight as i128) < BPS_DENOMINATOR,
+                "weight out of range"
+            );
+        }
+    }
+
+    fn _validate_curve(curve: &Curve) {
+        match curve {
+            Curve::Linear(c) => {
+                assert!(c.base_price > 0, "base_price must be positive");
+                assert!(c.slope >= 0, "slope must be non-negative");
+            }
+            Curve::Exponential(c) => {
+                assert!(c.base_price > 0, "base_price must be positive");
+                assert!(c.growth_bps > 0, "growth_bps must be positive");
+                assert!(c.step > 0, "step must be positive");
+            }
+        }
+    }
+
+    fn _is_finalized(env: &Env) -> bool {
+        matches!(
+            Self::_stored_state(env),
+            Some(SaleState::Claiming | SaleState::Refunding)
+        )
+    }
+
+    fn _is_cancelled(env: &Env) -> bool {
+        Self::_stored_state(env) == Some(SaleState::Cancelled)
+    }
+
+    /// State recorded by `finalize` or `cancel`, if either has run.
+    fn _stored_state(env: &Env) -> Option<SaleState> {
+        env.storage().instance().get(&DataKey::State)
+    }
+
+    fn _state(env: &Env) -> SaleState {
+        if let Some(state) = Self::_stored_state(env) {
+            let unsettled: u32 = env
+                .storage()
+                .instance()
+                .get(&DataKey::Unsettled)
+                .unwrap_or(0);
+            return if unsettled == 0 {
+                SaleState::Closed
+            } else {
+                state
+            };
+        }
+
+        let config = Self::_config(env);
+        let current = env.ledger().sequence();
+        let raised = Self::_total_raised(env);
+        let curve = matches!(config.mode, SaleMode::BondingCurve(_));
+        if current < config.start_ledger {
+            SaleState::Pending
+        } else if current < config.end_ledger && (curve || raised < config.hard_cap) {
+            SaleState::Active
+        } else if curve {
+            SaleState::Closed
+        } else if raised >= config.soft_cap {
+            SaleState::Succeeded
+        } else {
+            SaleState::Failed
+        }
+    }
+
+    /// Record a settled state; every contributor now has a claim or refund
+    /// outstanding.
+    fn _settle(env: &Env, state: SaleState) {
+        let unsettled = Self::contributor_count(env.clone());
+        env.storage().instance().set(&DataKey::State, &state);
+        env.storage()
+            .instance()
+            .set(&DataKey::Unsettled, &unsettled);
+        Self::_publish_state(env, state);
+        if unsettled == 0 {
+            Self::_publish_state(env, SaleState::Closed);
+        }
+    }
+
+    /// Count off one claim or refund, closing the sale after the last one.
+    fn _settle_one(env: &Env) {
+        let unsettled: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Unsettled)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::Unsettled, &(unsettled - 1));
+        if unsettled == 1 {
+            Self::_publish_state(env, SaleState::Closed);
+        }
+    }
+
+    fn _publish_state(env: &Env, state: SaleState) {
+        env.events().publish((symbol_short!("state"),), state);
+    }
+
+    fn _current_round(env: &Env, config: &SaleConfig) -> Option<u32> {
+        let current = env.ledger().sequence();
+        config
+            .rounds
+            .iter()
+            .position(|round| current >= round.start_ledger && current < round.end_ledger)
+            .map(|index| index as u32)
+    }
+
+    fn _check_not_paused(env: &Env) {
+        assert!(!Self::is_paused(env.clone()), "sale paused");
+    }
+
+    fn _check_open(env: &Env) {
+        match Self::_state(env) {
+            SaleState::Active => {}
+            SaleState::Pending => panic!("sale not started"),
+            _ if Self::_is_cancelled(env) => panic!("sale cancelled"),
+            _ if Self::_is_finalized(env) => panic!("sale finalized"),
+            _ => panic!("sale ended"),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::TokenFixture;
+    use soroban_sdk::{
+        testutils::storage::{Instance as _, Persistent as _},
+        testutils::Address as _,
+        testutils::AuthorizedFunction,
+        testutils::Ledger,
+        testutils::MockAuth,
+        testutils::MockAuthInvoke,
+        token::StellarAssetClient,
+        vec,
+    };
+
+    struct Setup {
+        env: Env,
+        client: SaleContractClient<'static>,
+        admin: Address,
+        sale_token: TokenFixture,
+        payment_token: TokenFixture,
+        fee_recipient: Address,
+    }
+
+    /// Sale of 10 000 tokens at 1:1, soft cap 1 000, hard cap 5 000, open
+    /// between ledgers 100 and 200 with a 2.5 % platform fee.
+    fn setup() -> Setup {
+        setup_with_mode(SaleMode::FixedPrice)
+    }
+
+    fn setup_with_mode(mode: SaleMode) -> Setup {
+        setup_with_config(|_, config| config.mode = mode)
+    }
+
+    /// Like `setup`, with `customize` applied to the config before
+    /// initialization.
+    fn setup_with_config(customize: impl FnOnce(&Env, &mut SaleConfig)) -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let fee_recipient = Address::generate(&env);
+
+        let sale_token = TokenFixture::stellar_asset(&env);
+        let payment_token = TokenFixture::stellar_asset(&env);
+
+        let contract_id = env.register_contract(None, SaleContract);
+        let client = SaleContractClient::new(&env, &contract_id);
+
+        let mut config = SaleConfig {
+            token: sale_token.address.clone(),
+            payment_token: payment_token.address.clone(),
+            price: PRICE_SCALE,
+            soft_cap: 1_000,
+            hard_cap: 5_000,
+            min_contribution: 10,
+            max_contribution: 3_000,
+            start_ledger: 100,
+            end_ledger: 200,
+            fee_bps: 250,
+            fee_recipient: fee_recipient.clone(),
+            guardian: Address::generate(&env),
+            mode: SaleMode::FixedPrice,
+            payout: Payout::Immediate,
+            buyback: Buyback::Disabled,
+            tier_pricing: Map::new(&env),
+            tier_source: TierSource::Admin,
+            rounds: Vec::new(&env),
+            extension: Extension::Disabled,
+            liquidity: Liquidity::Disabled,
+        };
+        customize(&env, &mut config);
+        client.initialize(&admin, &config, &project_info(&env, &config.token));
+
+        sale_token.mint(&contract_id, 10_000);
+        env.ledger().set_sequence_number(100);
+
+        Setup {
+            sale_token,
+            payment_token,
+            env,
+            client,
+            admin,
+            fee_recipient,
+        }
+    }
+
+    fn project_info(env: &Env, token: &Address) -> ProjectInfo {
+        ProjectInfo {
+            name: String::from_str(env, "Soropad Demo"),
+            token: token.clone(),
+            website_hash: BytesN::from_array(env, &[1u8; 32]),
+            docs_hash: BytesN::from_array(env, &[2u8; 32]),
+            socials_hash: BytesN::from_array(env, &[3u8; 32]),
+            audited_by: vec![env, String::from_str(env, "Example Audits")],
+        }
+    }
+
+    fn funded_buyer(s: &Setup, amount: i128) -> Address {
+        let buyer = Address::generate(&s.env);
+        s.payment_token.mint(&buyer, amount);
+        buyer
+    }
+
+    #[test]
+    #[should_panic(expected = "already initialized")]
+    fn test_double_init_panics() {
+        let s = setup();
+        let config = s.client.get_config();
+        let project = s.client.project_info();
+        s.client.initialize(&s.admin, &config, &project);
+    }
+
+    #[test]
+    fn test_contribute_records_purchase() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 1_000);
+
+        s.client.contribute(&buyer, &400);
+        s.client.contribute(&buyer, &100);
+
+        let purchase = s.client.get_purchase(&buyer);
+        assert_eq!(purchase.contributed, 500);
+        assert_eq!(purchase.tokens, 500);
+        assert!(!purchase.claimed);
+        assert_eq!(s.payment_token.balance(&buyer), 500);
+        assert_eq!(s.payment_token.balance(&s.client.address), 500);
+    }
+
+    #[test]
+    #[should_panic(expected = "sale not started")]
+    fn test_contribute_before_start_panics() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 1_000);
+        s.env.ledger().set_sequence_number(99);
+        s.client.contribute(&buyer, &100);
+    }
+
+    #[test]
+    #[should_panic(expected = "sale ended")]
+    fn test_contribute_after_end_panics() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 1_000);
+        s.env.ledger().set_sequence_number(200);
+        s.client.contribute(&buyer, &100);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds max_contribution")]
+    fn test_contribute_above_max_panics() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 5_000);
+        s.client.contribute(&buyer, &3_001);
+    }
+
+    #[test]
+    #[should_panic(expected = "below min_contribution")]
+    fn test_contribute_below_min_panics() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 5_000);
+        s.client.contribute(&buyer, &9);
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds hard_cap")]
+    fn test_contribute_above_hard_cap_panics() {
+        let s = setup();
+        let a = funded_buyer(&s, 3_000);
+        let b = funded_buyer(&s, 3_000);
+        s.client.contribute(&a, &3_000);
+        s.client.contribute(&b, &2_001);
+    }
+
+    #[test]
+    fn test_successful_sale_pays_out_and_claims() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 2_000);
+        s.client.contribute(&buyer, &2_000);
+
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+
+        assert!(s.client.is_finalized());
+        // 2.5 % of 2 000 = 50
+        assert_eq!(s.payment_token.balance(&s.fee_recipient), 50);
+        assert_eq!(s.payment_token.balance(&s.admin), 1_950);
+        // Unsold tokens go back to the admin
+        assert_eq!(s.sale_token.balance(&s.admin), 8_000);
+
+        s.client.claim(&buyer);
+        assert_eq!(s.sale_token.balance(&buyer), 2_000);
+        assert!(s.client.get_purchase(&buyer).claimed);
+    }
+
+    #[test]
+    #[should_panic(expected = "already claimed")]
+    fn test_double_claim_panics() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 2_000);
+        s.client.contribute(&buyer, &2_000);
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+        s.client.claim(&buyer);
+        s.client.claim(&buyer);
+    }
+
+    #[test]
+    fn test_finalize_early_at_hard_cap() {
+        let s = setup();
+        let a = funded_buyer(&s, 3_000);
+        let b = funded_buyer(&s, 2_000);
+        s.client.contribute(&a, &3_000);
+        s.client.contribute(&b, &2_000);
+
+        // Still before end_ledger, but the hard cap is reached
+        s.client.finalize();
+        assert!(s.client.is_finalized());
+    }
+
+    #[test]
+    #[should_panic(expected = "sale still active")]
+    fn test_finalize_while_active_panics() {
+        let s = setup();
+        s.client.finalize();
+    }
+
+    #[test]
+    fn test_failed_sale_refunds() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 500);
+        s.client.contribute(&buyer, &500);
+
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+
+        // Soft cap missed: all tokens back to admin, buyer refunded
+        assert_eq!(s.sale_token.balance(&s.admin), 10_000);
+        s.client.refund(&buyer);
+        assert_eq!(s.payment_token.balance(&buyer), 500);
+
+        let res = s.client.try_claim(&buyer);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_cancel_enables_refunds() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 500);
+        s.client.contribute(&buyer, &500);
+
+        s.client.cancel();
+        assert!(s.client.is_cancelled());
+        s.client.refund(&buyer);
+        assert_eq!(s.payment_token.balance(&buyer), 500);
+    }
+
+    #[test]
+    #[should_panic(expected = "refunds not available")]
+    fn test_refund_during_sale_panics() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 500);
+        s.client.contribute(&buyer, &500);
+        s.client.refund(&buyer);
+    }
+
+    #[test]
+    fn test_purchase_and_instance_bumped_on_write() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 1_000);
+        let ttls = || {
+            s.env.as_contract(&s.client.address, || {
+                (
+                    s.env.storage().instance().get_ttl(),
+                    s.env
+                        .storage()
+                        .persistent()
+                        .get_ttl(&DataKey::Purchase(buyer.clone())),
+                )
+            })
+        };
+
+        s.client.contribute(&buyer, &1_000);
+        assert_eq!(ttls(), (ttl::INSTANCE_BUMP, ttl::PERSISTENT_BUMP));
+
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+        s.client.claim(&buyer);
+        // Not bumped again until below the thresholds
+        assert_eq!(
+            ttls(),
+            (ttl::INSTANCE_BUMP - 100, ttl::PERSISTENT_BUMP - 100)
+        );
+    }
+
+    // ── Bonding curve ───────────────────────────────────────────────────
+
+    /// Linear curve starting at 1:1 whose price doubles after 10 000 tokens.
+    fn setup_linear() -> Setup {
+        setup_with_mode(SaleMode::BondingCurve(Curve::Linear(LinearCurve {
+            base_price: PRICE_SCALE,
+            slope: 10_000_000_000,
+        })))
+    }
+
+    #[test]
+    fn test_linear_curve_quotes() {
+        let s = setup_linear();
+        assert_eq!(s.client.current_price(), PRICE_SCALE);
+        // The first 2 000 tokens move the price from 1.0 to 1.2, so they cost
+        // 2 000 * 1.1 = 2 200 plus the 2.5 % fee.
+        assert_eq!(s.client.quote_buy(&2_000), 2_200 + 55);
+    }
+
+    #[test]
+    fn test_buy_and_sell_against_curve() {
+        let s = setup_linear();
+        let buyer = funded_buyer(&s, 10_000);
+
+        let paid = s.client.buy(&buyer, &4_000, &10_000);
+        assert_eq!(s.sale_token.balance(&buyer), 4_000);
+        assert_eq!(s.payment_token.balance(&buyer), 10_000 - paid);
+        let reserve = s.client.reserve();
+        assert!(reserve > 0);
+        assert!(s.client.current_price() > PRICE_SCALE);
+
+        let quoted = s.client.quote_sell(&4_000);
+        let received = s.client.sell(&buyer, &4_000, &quoted);
+        assert_eq!(received, quoted);
+        assert_eq!(s.sale_token.balance(&buyer), 0);
+        assert_eq!(s.client.current_price(), PRICE_SCALE);
+        // Selling everything back never drains more than was reserved.
+        assert!(s.client.reserve() >= 0);
+    }
+
+    #[test]
+    fn test_exponential_curve_price_steps() {
+        let s = setup_with_mode(SaleMode::BondingCurve(Curve::Exponential(
+            ExponentialCurve {
+                base_price: PRICE_SCALE,
+                growth_bps: 1_000,
+                step: 1_000,
+            },
+        )));
+        let buyer = funded_buyer(&s, 10_000);
+
+        // 1 000 at 1.0 + 500 at 1.1
+        let cost = s.client.quote_buy(&1_500);
+        assert_eq!(cost, 1_550 + 38);
+        s.client.buy(&buyer, &1_500, &cost);
+        assert_eq!(s.client.current_price(), PRICE_SCALE * 11 / 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "curve step limit exceeded")]
+    fn test_exponential_curve_walk_is_bounded() {
+        let s = setup_with_mode(SaleMode::BondingCurve(Curve::Exponential(
+            ExponentialCurve {
+                base_price: PRICE_SCALE,
+                growth_bps: 1,
+                step: 1,
+            },
+        )));
+
+        // The last step still quotes; one token past it reverts.
+        assert!(s.client.quote_buy(&1_000) > 1_000);
+        s.client.quote_buy(&1_001);
+    }
+
+    #[test]
+    #[should_panic(expected = "cost exceeds max_cost")]
+    fn test_buy_slippage_protection() {
+        let s = setup_linear();
+        let buyer = funded_buyer(&s, 10_000);
+        s.client.buy(&buyer, &2_000, &2_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "not available in bonding-curve mode")]
+    fn test_contribute_rejected_in_curve_mode() {
+        let s = setup_linear();
+        let buyer = funded_buyer(&s, 1_000);
+        s.client.contribute(&buyer, &100);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a bonding-curve sale")]
+    fn test_buy_rejected_in_fixed_price_mode() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 1_000);
+        s.client.buy(&buyer, &100, &1_000);
+    }
+
+    // ── LBP ─────────────────────────────────────────────────────────────
+
+    /// 10 000 tokens against a 10 000 virtual reserve, token weight shifting
+    /// from 80 % to 50 % over ledgers 100..200. Opening price is 4.0.
+    fn setup_lbp() -> Setup {
+        setup_with_mode(SaleMode::Lbp(LbpConfig {
+            virtual_reserve: 10_000,
+            start_weight_bps: 8_000,
+            end_weight_bps: 5_000,
+        }))
+    }
+
+    #[test]
+    fn test_lbp_price_decays_over_time() {
+        let s = setup_lbp();
+        assert_eq!(s.client.current_price(), 4 * PRICE_SCALE);
+        assert_eq!(s.client.price_at(&150), PRICE_SCALE * 65 * 10 / (35 * 10));
+        assert_eq!(s.client.price_at(&200), PRICE_SCALE);
+
+        s.env.ledger().set_sequence_number(200);
+        assert_eq!(s.client.current_price(), PRICE_SCALE);
+    }
+
+    #[test]
+    fn test_lbp_buy_pressure_raises_price() {
+        let s = setup_lbp();
+        let buyer = funded_buyer(&s, 2_000);
+        s.env.ledger().set_sequence_number(150);
+        let before = s.client.current_price();
+
+        let expected = s.client.simulate_contribution(&2_000);
+        s.client.contribute(&buyer, &2_000);
+        assert_eq!(s.client.get_purchase(&buyer).tokens, expected);
+        // Average price paid is above the pre-trade spot price
+        assert!(2_000 * PRICE_SCALE / expected > before);
+        assert!(s.client.current_price() > before);
+    }
+
+    #[test]
+    fn test_lbp_settles_like_a_raise() {
+        let s = setup_lbp();
+        let buyer = funded_buyer(&s, 3_000);
+        s.client.contribute(&buyer, &3_000);
+        let tokens = s.client.get_purchase(&buyer).tokens;
+
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+        s.client.claim(&buyer);
+        assert_eq!(s.sale_token.balance(&buyer), tokens);
+        assert_eq!(s.sale_token.balance(&s.admin), 10_000 - tokens);
+    }
+
+    #[test]
+    #[should_panic(expected = "weight out of range")]
+    fn test_lbp_invalid_weight_rejected() {
+        setup_with_mode(SaleMode::Lbp(LbpConfig {
+            virtual_reserve: 10_000,
+            start_weight_bps: 10_000,
+            end_weight_bps: 5_000,
+        }));
+    }
+
+    #[test]
+    #[should_panic(expected = "weight out of range")]
+    fn test_lbp_token_weight_below_half_rejected() {
+        setup_with_mode(SaleMode::Lbp(LbpConfig {
+            virtual_reserve: 10_000,
+            start_weight_bps: 8_000,
+            end_weight_bps: 4_999,
+        }));
+    }
+
+    // ── Milestone escrow ────────────────────────────────────────────────
+
+    /// Fixed-price sale whose net raise is released 25 % / 75 %.
+    fn setup_milestones() -> Setup {
+        setup_with_config(|env, config| {
+            config.payout = Payout::Milestones(MilestonePlan {
+                approver: Address::generate(env),
+                tranches_bps: vec![env, 2_500, 7_500],
+            });
+        })
+    }
+
+    #[test]
+    fn test_milestones_escrow_raise_and_release_tranches() {
+        let s = setup_milestones();
+        let buyer = funded_buyer(&s, 2_000);
+        s.client.contribute(&buyer, &2_000);
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+
+        // Fee is paid out, the rest stays escrowed
+        assert_eq!(s.payment_token.balance(&s.fee_recipient), 50);
+        assert_eq!(s.payment_token.balance(&s.admin), 0);
+        assert_eq!(s.client.escrow().net_raise, 1_950);
+
+        assert_eq!(s.client.approve_milestone(), 487);
+        assert_eq!(s.client.approve_milestone(), 1_463);
+        assert_eq!(s.payment_token.balance(&s.admin), 1_950);
+        assert_eq!(s.client.escrow().tranches_released, 2);
+
+        assert!(s.client.try_approve_milestone().is_err());
+    }
+
+    #[test]
+    fn test_abandon_enables_pro_rata_clawback() {
+        let s = setup_milestones();
+        let a = funded_buyer(&s, 3_000);
+        let b = funded_buyer(&s, 1_000);
+        s.client.contribute(&a, &3_000);
+        s.client.contribute(&b, &1_000);
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+
+        // net 3 900, first tranche 975 released, 2 925 left
+        s.client.approve_milestone();
+        s.client.abandon();
+
+        assert_eq!(s.client.clawback(&a), 2_193);
+        assert_eq!(s.client.clawback(&b), 731);
+        assert_eq!(s.payment_token.balance(&a), 2_193);
+        assert!(s.client.try_clawback(&a).is_err());
+        assert!(s.client.try_approve_milestone().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "project not abandoned")]
+    fn test_clawback_before_abandon_panics() {
+        let s = setup_milestones();
+        let buyer = funded_buyer(&s, 2_000);
+        s.client.contribute(&buyer, &2_000);
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+        s.client.clawback(&buyer);
+    }
+
+    #[test]
+    #[should_panic(expected = "tranches must sum to 100%")]
+    fn test_invalid_tranches_rejected() {
+        setup_with_config(|env, config| {
+            config.payout = Payout::Milestones(MilestonePlan {
+                approver: Address::generate(env),
+                tranches_bps: vec![env, 2_500, 2_500],
+            });
+        });
+    }
+
+    // ── Stats ───────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_stats_getters() {
+        let s = setup();
+        assert_eq!(s.client.total_raised(), 0);
+        assert_eq!(s.client.progress_bps(), 0);
+        assert_eq!(s.client.contributor_count(), 0);
+
+        let a = funded_buyer(&s, 1_000);
+        let b = funded_buyer(&s, 1_000);
+        s.client.contribute(&a, &500);
+        s.client.contribute(&b, &1_000);
+        s.client.contribute(&a, &500);
+
+        assert_eq!(s.client.total_raised(), 2_000);
+        assert_eq!(s.client.tokens_sold(), 2_000);
+        assert_eq!(s.client.progress_bps(), 4_000);
+        assert_eq!(s.client.contributor_count(), 2);
+        assert_eq!(s.client.contribution_of(&a), 1_000);
+        assert_eq!(s.client.contribution_of(&Address::generate(&s.env)), 0);
+    }
+
+    #[test]
+    fn test_contributors_pagination() {
+        let s = setup();
+        let buyers = [
+            funded_buyer(&s, 100),
+            funded_buyer(&s, 100),
+            funded_buyer(&s, 100),
+        ];
+        for buyer in buyers.iter() {
+            s.client.contribute(buyer, &100);
+        }
+
+        assert_eq!(
+            s.client.contributors(&0, &2),
+            vec![&s.env, buyers[0].clone(), buyers[1].clone()]
+        );
+        assert_eq!(
+            s.client.contributors(&2, &10),
+            vec![&s.env, buyers[2].clone()]
+        );
+        assert_eq!(s.client.contributors(&5, &10).len(), 0);
+    }
+
+    // ── Buyback ─────────────────────────────────────────────────────────
+
+    /// DEX stand-in that sells its platform-token inventory at 2 per unit of
+    /// payment.
+    #[contract]
+    struct MockDex;
+
+    #[contractimpl]
+    impl MockDex {
+        pub fn swap(
+            env: Env,
+            from: Address,
+            token_in: Address,
+            token_out: Address,
+            amount_in: i128,
+            min_out: i128,
+        ) -> i128 {
+            let this = env.current_contract_address();
+            token::Client::new(&env, &token_in).transfer(&from, &this, &amount_in);
+            let out = amount_in * 2;
+            assert!(out >= min_out, "slippage");
+            token::Client::new(&env, &token_out).transfer(&this, &from, &out);
+            out
+        }
+    }
+
+    #[test]
+    fn test_buyback_and_burn_from_platform_fee() {
+        let s = setup_with_config(|env, config| {
+            let adapter = env.register_contract(None, MockDex);
+            let platform_token = TokenFixture::stellar_asset(env);
+            platform_token.mint(&adapter, 1_000);
+            config.buyback = Buyback::Enabled(BuybackConfig {
+                adapter,
+                platform_token: platform_token.address,
+                share_bps: 4_000,
+            });
+        });
+        let platform_token = match s.client.get_config().buyback {
+            Buyback::Enabled(buyback) => token::Client::new(&s.env, &buyback.platform_token),
+            Buyback::Disabled => unreachable!(),
+        };
+
+        let buyer = funded_buyer(&s, 2_000);
+        s.client.contribute(&buyer, &2_000);
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+
+        // Fee of 50: 40 % held for the buyback, the rest to the fee recipient
+        assert_eq!(s.client.buyback_pending(), 20);
+        assert_eq!(s.payment_token.balance(&s.fee_recipient), 30);
+
+        assert_eq!(s.client.buyback_and_burn(&40), 40);
+        assert_eq!(s.client.buyback_pending(), 0);
+        assert_eq!(platform_token.balance(&s.client.address), 0);
+        assert!(s.client.try_buyback_and_burn(&0).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "buyback disabled")]
+    fn test_buyback_disabled_panics() {
+        let s = setup();
+        s.client.buyback_and_burn(&0);
+    }
+
+    // ── Tiers ───────────────────────────────────────────────────────────
+
+    fn setup_tiers() -> Setup {
+        setup_with_config(|env, config| {
+            config.tier_pricing = Map::from_array(
+                env,
+                [
+                    (1, TierPrice::DiscountBps(2_000)),
+                    (2, TierPrice::Price(PRICE_SCALE / 2)),
+                ],
+            );
+        })
+    }
+
+    #[test]
+    fn test_tier_pricing_applies_to_purchases() {
+        let s = setup_tiers();
+        let public = funded_buyer(&s, 1_000);
+        let discounted = funded_buyer(&s, 1_000);
+        let priced = funded_buyer(&s, 1_000);
+        let unlisted = funded_buyer(&s, 1_000);
+        s.client.set_tier(&vec![&s.env, discounted.clone()], &1);
+        s.client.set_tier(&vec![&s.env, priced.clone()], &2);
+        s.client.set_tier(&vec![&s.env, unlisted.clone()], &7);
+
+        assert_eq!(s.client.tier_of(&public), None);
+        assert_eq!(s.client.tier_of(&discounted), Some(1));
+        assert_eq!(s.client.effective_price(&public), PRICE_SCALE);
+        assert_eq!(s.client.effective_price(&discounted), 8_000_000);
+        assert_eq!(s.client.effective_price(&priced), 5_000_000);
+        assert_eq!(s.client.effective_price(&unlisted), PRICE_SCALE);
+
+        for buyer in [&public, &discounted, &priced, &unlisted] {
+            s.client.contribute(buyer, &400);
+        }
+        assert_eq!(s.client.get_purchase(&public).tokens, 400);
+        assert_eq!(s.client.get_purchase(&discounted).tokens, 500);
+        assert_eq!(s.client.get_purchase(&priced).tokens, 800);
+        assert_eq!(s.client.get_purchase(&unlisted).tokens, 400);
+        assert_eq!(s.client.tokens_sold(), 2_100);
+    }
+
+    /// Staking stand-in with tiers set directly.
+    #[contract]
+    struct MockStaking;
+
+    #[contractimpl]
+    impl MockStaking {
+        pub fn set(env: Env, staker: Address, tier: u32) {
+            env.storage().persistent().set(&staker, &tier);
+        }
+
+        pub fn tier_of(env: Env, staker: Address) -> u32 {
+            env.storage().persistent().get(&staker).unwrap_or(0)
+        }
+
+        /// Tier 1 for everyone at snapshot 4.
+        pub fn tier_at(_env: Env, _staker: Address, snapshot_id: u32) -> u32 {
+            if snapshot_id == 4 {
+                1
+            } else {
+                0
+            }
+        }
+    }
+
+    #[test]
+    fn test_tiers_from_staking() {
+        let mut staking = None;
+        let s = setup_with_config(|env, config| {
+            let id = env.register_contract(None, MockStaking);
+            config.tier_pricing = Map::from_array(env, [(1, TierPrice::DiscountBps(5_000))]);
+            config.tier_source = TierSource::Staking(id.clone());
+            staking = Some(id);
+        });
+        let staking = MockStakingClient::new(&s.env, &staking.unwrap());
+        let staker = funded_buyer(&s, 1_000);
+        let outsider = funded_buyer(&s, 1_000);
+        staking.set(&staker, &1);
+
+        assert_eq!(s.client.tier_of(&staker), Some(1));
+        assert_eq!(s.client.tier_of(&outsider), None);
+        s.client.contribute(&staker, &100);
+        s.client.contribute(&outsider, &100);
+        assert_eq!(s.client.get_purchase(&staker).tokens, 200);
+        assert_eq!(s.client.get_purchase(&outsider).tokens, 100);
+
+        assert!(s
+            .client
+            .try_set_tier(&vec![&s.env, outsider.clone()], &1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_tiers_from_staking_snapshot() {
+        let s = setup_with_config(|env, config| {
+            let id = env.register_contract(None, MockStaking);
+            config.tier_pricing = Map::from_array(env, [(1, TierPrice::DiscountBps(5_000))]);
+            config.tier_source = TierSource::StakingSnapshot(id, 4);
+        });
+        let buyer = funded_buyer(&s, 1_000);
+        assert_eq!(s.client.tier_of(&buyer), Some(1));
+        assert_eq!(s.client.effective_price(&buyer), PRICE_SCALE / 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "discount must be below 100%")]
+    fn test_full_discount_rejected() {
+        setup_with_config(|env, config| {
+            config.tier_pricing = Map::from_array(env, [(1, TierPrice::DiscountBps(10_000))]);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "tier pricing requires fixed-price mode")]
+    fn test_tier_pricing_rejected_outside_fixed_price() {
+        setup_with_config(|env, config| {
+            config.mode = SaleMode::Lbp(LbpConfig {
+                virtual_reserve: 1_000,
+                start_weight_bps: 9_000,
+                end_weight_bps: 5_000,
+            });
+            config.tier_pricing = Map::from_array(env, [(1, TierPrice::DiscountBps(1_000))]);
+        });
+    }
+
+    // ── Whitelist rounds ────────────────────────────────────────────────
+
+    /// Two private rounds (100..120 and 120..150) followed by a public phase.
+    fn setup_rounds() -> Setup {
+        setup_with_config(|env, config| {
+            config.rounds = vec![
+                env,
+                Round {
+                    start_ledger: 100,
+                    end_ledger: 120,
+                },
+                Round {
+                    start_ledger: 120,
+                    end_ledger: 150,
+                },
+            ];
+        })
+    }
+
+    #[test]
+    fn test_rounds_gate_contributions_by_whitelist() {
+        let s = setup_rounds();
+        let early = funded_buyer(&s, 1_000);
+        let late = funded_buyer(&s, 1_000);
+        let public = funded_buyer(&s, 1_000);
+        s.client.add_to_whitelist(&0, &vec![&s.env, early.clone()]);
+        s.client.add_to_whitelist(&1, &vec![&s.env, late.clone()]);
+
+        assert_eq!(s.client.current_round(), Some(0));
+        s.client.contribute(&early, &100);
+        assert!(s.client.try_contribute(&late, &100).is_err());
+        assert!(s.client.try_contribute(&public, &100).is_err());
+
+        s.env.ledger().set_sequence_number(120);
+        assert_eq!(s.client.current_round(), Some(1));
+        s.client.contribute(&late, &100);
+        assert!(s.client.try_contribute(&early, &100).is_err());
+
+        s.env.ledger().set_sequence_number(150);
+        assert_eq!(s.client.current_round(), None);
+        s.client.contribute(&public, &100);
+        assert_eq!(s.client.total_raised(), 300);
+    }
+
+    #[test]
+    #[should_panic(expected = "not whitelisted for round")]
+    fn test_removed_from_whitelist_cannot_contribute() {
+        let s = setup_rounds();
+        let buyer = funded_buyer(&s, 1_000);
+        s.client.add_to_whitelist(&0, &vec![&s.env, buyer.clone()]);
+        s.client
+            .remove_from_whitelist(&0, &vec![&s.env, buyer.clone()]);
+        assert!(!s.client.is_whitelisted(&0, &buyer));
+        s.client.contribute(&buyer, &100);
+    }
+
+    #[contract]
+    struct MockAllowlist;
+
+    #[contractimpl]
+    impl MockAllowlist {
+        pub fn add(env: Env, name: Symbol, account: Address) {
+            env.storage().instance().set(&(name, account), &true);
+        }
+
+        pub fn is_listed(env: Env, name: Symbol, account: Address) -> bool {
+            env.storage().instance().has(&(name, account))
+        }
+    }
+
+    #[test]
+    fn test_round_admits_shared_allowlist() {
+        let s = setup_rounds();
+        let allowlist_id = s.env.register_contract(None, MockAllowlist);
+        let allowlist = MockAllowlistClient::new(&s.env, &allowlist_id);
+        let og = Symbol::new(&s.env, "og_community");
+        s.client
+            .set_round_allowlist(&0, &Some(allowlist_id.clone()), &og);
+        assert_eq!(
+            s.client.round_allowlist(&0),
+            Some((allowlist_id, og.clone()))
+        );
+
+        let buyer = funded_buyer(&s, 1_000);
+        assert!(s.client.try_contribute(&buyer, &100).is_err());
+        allowlist.add(&og, &buyer);
+        assert!(s.client.is_whitelisted(&0, &buyer));
+        assert!(!s.client.is_whitelisted(&1, &buyer));
+        s.client.contribute(&buyer, &100);
+
+        s.client.set_round_allowlist(&0, &None, &og);
+        assert!(!s.client.is_whitelisted(&0, &buyer));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid round window")]
+    fn test_overlapping_rounds_rejected() {
+        setup_with_config(|env, config| {
+            config.rounds = vec![
+                env,
+                Round {
+                    start_ledger: 100,
+                    end_ledger: 130,
+                },
+                Round {
+                    start_ledger: 120,
+                    end_ledger: 150,
+                },
+            ];
+        });
+    }
+
+    // ── Pause ───────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_pause_blocks_contributions_until_unpaused() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 1_000);
+
+        s.client.pause();
+        assert!(s.client.is_paused());
+        assert!(s.client.try_contribute(&buyer, &100).is_err());
+
+        s.client.unpause();
+        s.client.contribute(&buyer, &100);
+        assert_eq!(s.client.total_raised(), 100);
+    }
+
+    #[test]
+    fn test_pause_never_blocks_refunds() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 500);
+        s.client.contribute(&buyer, &500);
+
+        s.client.pause();
+        s.client.cancel();
+        s.client.refund(&buyer);
+        assert_eq!(s.payment_token.balance(&buyer), 500);
+    }
+
+    #[test]
+    #[should_panic(expected = "sale paused")]
+    fn test_pause_blocks_claims() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 2_000);
+        s.client.contribute(&buyer, &2_000);
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+
+        s.client.pause();
+        s.client.claim(&buyer);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pause_requires_guardian() {
+        let s = setup();
+        s.env.mock_auths(&[MockAuth {
+            address: &s.admin,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "pause",
+                args: ().into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.pause();
+    }
+
+    #[contract]
+    struct MockPauseRegistry;
+
+    #[contractimpl]
+    impl MockPauseRegistry {
+        pub fn set_paused(env: Env, contract: Address, paused: bool) {
+            env.storage().instance().set(&contract, &paused);
+        }
+
+        pub fn is_paused(env: Env, contract: Address) -> bool {
+            env.storage().instance().get(&contract).unwrap_or(false)
+        }
+    }
+
+    #[test]
+    fn test_pause_registry_freezes_sale() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 1_000);
+        let registry_id = s.env.register_contract(None, MockPauseRegistry);
+        let registry = MockPauseRegistryClient::new(&s.env, &registry_id);
+        s.client.set_pause_registry(&registry_id);
+        assert_eq!(s.client.pause_registry(), Some(registry_id));
+
+        registry.set_paused(&s.client.address, &true);
+        assert!(s.client.is_paused());
+        assert!(s.client.try_contribute(&buyer, &100).is_err());
+
+        registry.set_paused(&s.client.address, &false);
+        s.client.contribute(&buyer, &100);
+        assert_eq!(s.client.total_raised(), 100);
+    }
+
+    // ── Badges ──────────────────────────────────────────────────────────
+
+    #[contract]
+    struct MockBadges;
+
+    #[contractimpl]
+    impl MockBadges {
+        pub fn mint(env: Env, issuer: Address, owner: Address, kind: Symbol, _level: u32) -> u64 {
+            env.storage().instance().set(&(owner, kind), &issuer);
+            0
+        }
+
+        pub fn issuer_of(env: Env, owner: Address, kind: Symbol) -> Option<Address> {
+            env.storage().instance().get(&(owner, kind))
+        }
+    }
+
+    #[test]
+    fn test_claim_issues_participation_badge() {
+        let s = setup();
+        let badges_id = s.env.register_contract(None, MockBadges);
+        let badges = MockBadgesClient::new(&s.env, &badges_id);
+        s.client.set_badges(&badges_id);
+
+        let buyer = funded_buyer(&s, 2_000);
+        s.client.contribute(&buyer, &2_000);
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+        assert_eq!(badges.issuer_of(&buyer, &SALE_BADGE), None);
+
+        s.client.claim(&buyer);
+        assert_eq!(
+            badges.issuer_of(&buyer, &SALE_BADGE),
+            Some(s.client.address.clone())
+        );
+    }
+
+    // ── Points ──────────────────────────────────────────────────────────
+
+    #[contract]
+    struct MockPoints;
+
+    #[contractimpl]
+    impl MockPoints {
+        pub fn record(
+            env: Env,
+            _awarder: Address,
+            account: Address,
+            activity: Symbol,
+            units: i128,
+        ) -> i128 {
+            env.storage().instance().set(&(account, activity), &units);
+            units
+        }
+
+        pub fn units(env: Env, account: Address, activity: Symbol) -> i128 {
+            env.storage()
+                .instance()
+                .get(&(account, activity))
+                .unwrap_or(0)
+        }
+    }
+
+    #[test]
+    fn test_claim_records_contribution_points() {
+        let s = setup();
+        let points_id = s.env.register_contract(None, MockPoints);
+        let points = MockPointsClient::new(&s.env, &points_id);
+        s.client.set_points(&points_id);
+
+        let buyer = funded_buyer(&s, 2_000);
+        s.client.contribute(&buyer, &2_000);
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+        assert_eq!(points.units(&buyer, &CONTRIBUTION_ACTIVITY), 0);
+
+        s.client.claim(&buyer);
+        assert_eq!(points.units(&buyer, &CONTRIBUTION_ACTIVITY), 2_000);
+    }
+
+    // ── KYC ─────────────────────────────────────────────────────────────
+
+    #[contract]
+    struct MockKycRegistry;
+
+    #[contractimpl]
+    impl MockKycRegistry {
+        pub fn set_level(env: Env, account: Address, level: u32) {
+            env.storage().instance().set(&account, &level);
+        }
+
+        pub fn is_verified(env: Env, account: Address, level: u32) -> bool {
+            env.storage()
+                .instance()
+                .get::<_, u32>(&account)
+                .unwrap_or(0)
+                >= level
+        }
+    }
+
+    #[test]
+    fn test_kyc_gates_contributions() {
+        let s = setup();
+        let kyc_id = s.env.register_contract(None, MockKycRegistry);
+        let kyc = MockKycRegistryClient::new(&s.env, &kyc_id);
+        s.client.set_kyc(&Some(kyc_id.clone()), &2);
+        assert_eq!(s.client.kyc_requirement(), Some((kyc_id, 2)));
+
+        let buyer = funded_buyer(&s, 1_000);
+        kyc.set_level(&buyer, &1);
+        assert!(s.client.try_contribute(&buyer, &100).is_err());
+
+        kyc.set_level(&buyer, &2);
+        s.client.contribute(&buyer, &100);
+        assert_eq!(s.client.contribution_of(&buyer), 100);
+    }
+
+    #[test]
+    fn test_buyer_limit_throttles_contributions() {
+        let s = setup();
+        s.client.set_buyer_limit(&Some(RateLimit::fixed(2, 10)));
+        let buyer = funded_buyer(&s, 1_000);
+        s.client.contribute(&buyer, &100);
+        s.client.contribute(&buyer, &100);
+        assert_eq!(s.client.buyer_actions_left(&buyer), Some(0));
+        assert!(s.client.try_contribute(&buyer, &100).is_err());
+
+        let other = funded_buyer(&s, 1_000);
+        s.client.contribute(&other, &100);
+
+        s.env
+            .ledger()
+            .set_sequence_number(s.env.ledger().sequence() + 10);
+        s.client.contribute(&buyer, &100);
+        assert_eq!(s.client.contribution_of(&buyer), 300);
+    }
+
+    // ── Native XLM ──────────────────────────────────────────────────────
+
+    #[test]
+    fn test_native_xlm_payment_token() {
+        let mut native = None;
+        let s = setup_with_config(|env, config| {
+            let xlm = env
+                .deployer()
+                .with_stellar_asset(Bytes::from_array(env, &[0u8; 4]))
+                .deploy();
+            config.payment_token = xlm.clone();
+            native = Some(xlm);
+        });
+        assert_eq!(s.client.get_config().payment_token, native.unwrap());
+        assert!(s.client.is_native_payment());
+        assert_eq!(s.client.payment_decimals(), 7);
+    }
+
+    #[test]
+    fn test_issued_payment_token_is_not_native() {
+        let s = setup();
+        assert!(!s.client.is_native_payment());
+    }
+
+    // ── Permit ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_contribute_with_permit_approves_and_pulls() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 1_000);
+
+        s.env.mock_auths(&[MockAuth {
+            address: &buyer,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "contribute_with_permit",
+                args: (&buyer, 400i128, Some(150u32)).into_val(&s.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &s.payment_token.address,
+                    fn_name: "approve",
+                    args: (&buyer, &s.client.address, 400i128, 150u32).into_val(&s.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }]);
+        s.client.contribute_with_permit(&buyer, &400, &Some(150));
+
+        assert_eq!(s.client.get_purchase(&buyer).tokens, 400);
+        assert_eq!(s.payment_token.balance(&buyer), 600);
+        assert_eq!(
+            s.payment_token.client.allowance(&buyer, &s.client.address),
+            0
+        );
+    }
+
+    #[test]
+    fn test_contribute_with_existing_allowance() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 1_000);
+        s.payment_token
+            .client
+            .approve(&buyer, &s.client.address, &700, &150);
+
+        s.client.contribute_with_permit(&buyer, &300, &None);
+        assert_eq!(s.client.contribution_of(&buyer), 300);
+        assert_eq!(
+            s.payment_token.client.allowance(&buyer, &s.client.address),
+            400
+        );
+    }
+
+    #[test]
+    fn test_contribute_without_allowance_fails() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 1_000);
+        assert!(s
+            .client
+            .try_contribute_with_permit(&buyer, &300, &None)
+            .is_err());
+        assert_eq!(s.client.contribution_of(&buyer), 0);
+    }
+
+    // ── Project info ────────────────────────────────────────────────────
+
+    #[test]
+    fn test_project_info_stored_at_init() {
+        let s = setup();
+        let info = s.client.project_info();
+        assert_eq!(info, project_info(&s.env, &s.sale_token.address));
+        assert_eq!(info.audited_by.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "project token mismatch")]
+    fn test_project_token_must_match_sale_token() {
+        let s = setup();
+        let other = SaleContractClient::new(&s.env, &s.env.register_contract(None, SaleContract));
+        let mut info = s.client.project_info();
+        info.token = Address::generate(&s.env);
+        other.initialize(&s.admin, &s.client.get_config(), &info);
+    }
+
+    // ── Deadline extension ──────────────────────────────────────────────
+
+    fn setup_extension(only_below_soft_cap: bool) -> Setup {
+        setup_with_config(|_, config| {
+            config.extension = Extension::Allowed(ExtensionRule {
+                max_extensions: 1,
+                max_ledgers: 50,
+                only_below_soft_cap,
+            })
+        })
+    }
+
+    #[test]
+    fn test_extend_within_rule() {
+        let s = setup_extension(true);
+        assert_eq!(s.client.extend(&50), 250);
+        assert_eq!(s.client.get_config().end_ledger, 250);
+        assert_eq!(s.client.extensions_used(), 1);
+
+        // The sale now stays open past the original deadline
+        let buyer = funded_buyer(&s, 1_000);
+        s.env.ledger().set_sequence_number(220);
+        s.client.contribute(&buyer, &100);
+
+        assert!(s.client.try_extend(&10).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "extension too long")]
+    fn test_extend_beyond_max_ledgers_panics() {
+        let s = setup_extension(false);
+        s.client.extend(&51);
+    }
+
+    #[test]
+    #[should_panic(expected = "soft cap already reached")]
+    fn test_extend_after_soft_cap_panics() {
+        let s = setup_extension(true);
+        let buyer = funded_buyer(&s, 1_000);
+        s.client.contribute(&buyer, &1_000);
+        s.client.extend(&10);
+    }
+
+    #[test]
+    #[should_panic(expected = "extensions disabled")]
+    fn test_extend_disabled_by_default() {
+        let s = setup();
+        s.client.extend(&10);
+    }
+
+    #[test]
+    #[should_panic(expected = "sale ended")]
+    fn test_extend_after_end_panics() {
+        let s = setup_extension(false);
+        s.env.ledger().set_sequence_number(200);
+        s.client.extend(&10);
+    }
+
+    // ── Lifecycle ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_state_follows_successful_lifecycle() {
+        let s = setup();
+        s.env.ledger().set_sequence_number(99);
+        assert_eq!(s.client.state(), SaleState::Pending);
+
+        s.env.ledger().set_sequence_number(100);
+        assert_eq!(s.client.state(), SaleState::Active);
+        let a = funded_buyer(&s, 2_000);
+        let b = funded_buyer(&s, 500);
+        s.client.contribute(&a, &2_000);
+        s.client.contribute(&b, &500);
+
+        s.env.ledger().set_sequence_number(200);
+        assert_eq!(s.client.state(), SaleState::Succeeded);
+        s.client.finalize();
+        assert_eq!(s.client.state(), SaleState::Claiming);
+
+        s.client.claim(&a);
+        assert_eq!(s.client.state(), SaleState::Claiming);
+        s.client.claim(&b);
+        assert_eq!(s.client.state(), SaleState::Closed);
+    }
+
+    #[test]
+    fn test_state_follows_failed_lifecycle() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 500);
+        s.client.contribute(&buyer, &500);
+
+        s.env.ledger().set_sequence_number(200);
+        assert_eq!(s.client.state(), SaleState::Failed);
+        s.client.finalize();
+        assert_eq!(s.client.state(), SaleState::Refunding);
+        s.client.refund(&buyer);
+        assert_eq!(s.client.state(), SaleState::Closed);
+    }
+
+    #[test]
+    fn test_state_after_cancel() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 500);
+        s.client.contribute(&buyer, &500);
+
+        s.client.cancel();
+        assert_eq!(s.client.state(), SaleState::Cancelled);
+        assert!(s.client.try_contribute(&buyer, &100).is_err());
+        s.client.refund(&buyer);
+        assert_eq!(s.client.state(), SaleState::Closed);
+        assert!(s.client.try_cancel().is_err());
+    }
+
+    #[test]
+    fn test_state_succeeded_at_hard_cap() {
+        let s = setup();
+        let a = funded_buyer(&s, 3_000);
+        let b = funded_buyer(&s, 2_000);
+        s.client.contribute(&a, &3_000);
+        s.client.contribute(&b, &2_000);
+        assert_eq!(s.client.state(), SaleState::Succeeded);
+    }
+
+    // ── Relayed contributions ───────────────────────────────────────────
+
+    #[test]
+    fn test_contribute_on_behalf_uses_buyer_signature() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 1_000);
+        let relayer = Address::generate(&s.env);
+
+        s.client
+            .contribute_on_behalf(&relayer, &buyer, &400, &0, &150);
+
+        let buyer_auth = s
+            .env
+            .auths()
+            .into_iter()
+            .find(|(address, _)| *address == buyer)
+            .expect("buyer did not authorize");
+        assert_eq!(
+            buyer_auth.1.function,
+            AuthorizedFunction::Contract((
+                s.client.address.clone(),
+                Symbol::new(&s.env, "contribute_on_behalf"),
+                (relayer.clone(), 400i128, 0u64, 150u32).into_val(&s.env),
+            ))
+        );
+        assert_eq!(s.client.get_purchase(&buyer).tokens, 400);
+        assert_eq!(s.payment_token.balance(&buyer), 600);
+        assert_eq!(s.client.nonce(&buyer), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid nonce")]
+    fn test_contribute_on_behalf_replay_panics() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 1_000);
+        let relayer = Address::generate(&s.env);
+        s.client
+            .contribute_on_behalf(&relayer, &buyer, &100, &0, &150);
+        s.client
+            .contribute_on_behalf(&relayer, &buyer, &100, &0, &150);
+    }
+
+    #[test]
+    #[should_panic(expected = "signature expired")]
+    fn test_contribute_on_behalf_after_deadline_panics() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 1_000);
+        let relayer = Address::generate(&s.env);
+        s.env.ledger().set_sequence_number(150);
+        s.client
+            .contribute_on_behalf(&relayer, &buyer, &100, &0, &150);
+    }
+
+    // ── Liquidity ───────────────────────────────────────────────────────
+
+    /// AMM stand-in minting one LP token per unit of payment deposited.
+    /// It pulls both amounts in full, keeps `take_bps` of each (all by
+    /// default) and refunds the rest, like a pool whose ratio is off.
+    #[contract]
+    struct MockAmm;
+
+    #[contractimpl]
+    impl MockAmm {
+        pub fn set_lp_token(env: Env, lp_token: Address) {
+            env.storage().instance().set(&0u32, &lp_token);
+        }
+
+        pub fn set_take_bps(env: Env, take_bps: u32) {
+            env.storage().instance().set(&1u32, &take_bps);
+        }
+
+        pub fn add_liquidity(
+            env: Env,
+            from: Address,
+            token_a: Address,
+            token_b: Address,
+            amount_a: i128,
+            amount_b: i128,
+        ) -> i128 {
+            let this = env.current_contract_address();
+            let take_bps: u32 = env.storage().instance().get(&1u32).unwrap_or(10_000);
+            let mut taken_b = 0;
+            for (token, amount) in [(token_a, amount_a), (token_b, amount_b)] {
+                let client = token::Client::new(&env, &token);
+                client.transfer(&from, &this, &amount);
+                taken_b = soropad_math::apply_bps(amount, take_bps);
+                if amount > taken_b {
+                    client.transfer(&this, &from, &(amount - taken_b));
+                }
+            }
+            let lp_token: Address = env.storage().instance().get(&0u32).unwrap();
+            StellarAssetClient::new(&env, &lp_token).mint(&from, &taken_b);
+            taken_b
+        }
+
+        pub fn lp_token(env: Env, _token_a: Address, _token_b: Address) -> Address {
+            env.storage().instance().get(&0u32).unwrap()
+        }
+    }
+
+    /// Locker stand-in that just takes custody.
+    #[contract]
+    struct MockLocker;
+
+    #[contractimpl]
+    impl MockLocker {
+        pub fn lock(
+            env: Env,
+            from: Address,
+            token: Address,
+            amount: i128,
+            _unlock_ledger: u32,
+            _beneficiary: Address,
+        ) -> u64 {
+            token::Client::new(&env, &token).transfer(
+                &from,
+                &env.current_contract_address(),
+                &amount,
+            );
+            7
+        }
+    }
+
+    /// Sale routing 20 % of the net raise into liquidity through a `MockAmm`
+    /// that keeps `take_bps` of each deposit, with `customize` applied on top.
+    fn setup_liquidity(take_bps: u32, customize: impl FnOnce(&mut SaleConfig)) -> Setup {
+        setup_with_config(|env, config| {
+            let adapter = env.register_contract(None, MockAmm);
+            let lp_token = TokenFixture::stellar_asset_of(env, adapter.clone());
+            let amm = MockAmmClient::new(env, &adapter);
+            amm.set_lp_token(&lp_token.address);
+            amm.set_take_bps(&take_bps);
+            config.liquidity = Liquidity::Enabled(LiquidityConfig {
+                adapter,
+                locker: env.register_contract(None, MockLocker),
+                share_bps: 2_000,
+                lock_ledgers: 1_000,
+            });
+            customize(config);
+        })
+    }
+
+    fn locker(s: &Setup) -> Address {
+        match s.client.get_config().liquidity {
+            Liquidity::Enabled(liquidity) => liquidity.locker,
+            Liquidity::Disabled => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_finalize_provides_and_locks_liquidity() {
+        let s = setup_liquidity(10_000, |_| {});
+        let locker = locker(&s);
+
+        let buyer = funded_buyer(&s, 2_000);
+        s.client.contribute(&buyer, &2_000);
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+
+        // Net raise 1 950: 20 % (390) paired with 390 tokens at the 1:1 price
+        let lock = s.client.liquidity_lock();
+        assert_eq!(lock.amount, 390);
+        assert_eq!(lock.lock_id, 7);
+        assert_eq!(lock.unlock_ledger, 1_200);
+        assert_eq!(
+            token::Client::new(&s.env, &lock.lp_token).balance(&locker),
+            390
+        );
+        assert_eq!(s.payment_token.balance(&s.admin), 1_560);
+        assert_eq!(s.sale_token.balance(&s.admin), 7_610);
+
+        s.client.claim(&buyer);
+        assert_eq!(s.sale_token.balance(&buyer), 2_000);
+    }
+
+    #[test]
+    fn test_liquidity_refund_stays_with_the_raise() {
+        // The pool only takes half of the 390 / 390 deposit
+        let s = setup_liquidity(5_000, |_| {});
+        let buyer = funded_buyer(&s, 2_000);
+        s.client.contribute(&buyer, &2_000);
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+
+        let lock = s.client.liquidity_lock();
+        assert_eq!(lock.amount, 195);
+        assert_eq!(
+            token::Client::new(&s.env, &lock.lp_token).balance(&locker(&s)),
+            195
+        );
+        // Net raise 1 950 less the 195 the pool kept; the refunded tokens
+        // go back to the admin with the rest of the unsold inventory.
+        assert_eq!(s.payment_token.balance(&s.admin), 1_755);
+        assert_eq!(s.sale_token.balance(&s.admin), 7_805);
+        assert_eq!(s.payment_token.balance(&s.client.address), 0);
+        assert_eq!(s.sale_token.balance(&s.client.address), 2_000);
+    }
+
+    #[test]
+    fn test_sold_out_sale_still_provides_liquidity() {
+        // Two tokens per payment unit: 4 000 buys 8 000 of the 10 000
+        // tokens, and the 20 % reserve holds back 1 600 more.
+        let s = setup_liquidity(10_000, |config| {
+            config.price = PRICE_SCALE / 2;
+            config.max_contribution = 5_000;
+        });
+        let buyer = funded_buyer(&s, 4_200);
+        s.client.contribute(&buyer, &4_000);
+        // Another 200 would buy 400 tokens out of the reserve
+        assert!(s.client.try_contribute(&buyer, &200).is_err());
+
+        s.env.ledger().set_sequence_number(200);
+        s.client.finalize();
+
+        // Net raise 3 900: 780 paired with 1 560 tokens at two per unit
+        let lock = s.client.liquidity_lock();
+        assert_eq!(lock.amount, 780);
+        assert_eq!(s.sale_token.balance(&s.admin), 10_000 - 8_000 - 1_560);
+    }
+}
@@ -0,0 +1,931 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, symbol_short, xdr::ToXdr, Address,
+    BytesN, Env, Map, String, Symbol, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    SaleWasmHash,
+    Defaults,
+    AllowedPaymentToken(Address),
+    SaleCount,
+    Sale(u32),
+    IsSale(Address),
+    Reputation,
+    FeeDiscounts,
+    Config,
+    WasmRegistry,
+}
+
+/// Platform-wide settings every deployed sale inherits (fee, guardian and
+/// the platform-fee buyback). Projects cannot override these.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PlatformDefaults {
+    pub fee_bps: u32,
+    pub fee_recipient: Address,
+    pub guardian: Address,
+    pub buyback: Buyback,
+}
+
+/// Platform-fee discount for projects whose reputation score is at least
+/// `min_score`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct FeeDiscount {
+    pub min_score: i128,
+    pub discount_bps: u32,
+}
+
+/// Project-chosen parameters of a sale.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct SaleParams {
+    pub token: Address,
+    pub payment_token: Address,
+    pub price: i128,
+    pub soft_cap: i128,
+    pub hard_cap: i128,
+    pub min_contribution: i128,
+    pub max_contribution: i128,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+    pub mode: SaleMode,
+    pub payout: Payout,
+    pub tier_pricing: Map<u32, TierPrice>,
+    pub tier_source: TierSource,
+    pub rounds: Vec<Round>,
+    pub extension: Extension,
+    pub liquidity: Liquidity,
+    pub project: ProjectInfo,
+}
+
+/// Registry entry for a deployed sale.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SaleRecord {
+    pub sale: Address,
+    pub admin: Address,
+    pub token: Address,
+    pub created_ledger: u32,
+}
+
+/// Mirror of the sale contract's `SaleConfig`. Contract types are encoded by
+/// field name, so this must stay in sync with `contracts/sale`.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct SaleConfig {
+    pub token: Address,
+    pub payment_token: Address,
+    pub price: i128,
+    pub soft_cap: i128,
+    pub hard_cap: i128,
+    pub min_contribution: i128,
+    pub max_contribution: i128,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+    pub fee_bps: u32,
+    pub fee_recipient: Address,
+    pub guardian: Address,
+    pub mode: SaleMode,
+    pub payout: Payout,
+    pub buyback: Buyback,
+    pub tier_pricing: Map<u32, TierPrice>,
+    pub tier_source: TierSource,
+    pub rounds: Vec<Round>,
+    pub extension: Extension,
+    pub liquidity: Liquidity,
+}
+
+/// Mirror of the sale contract's `SaleMode`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum SaleMode {
+    FixedPrice,
+    BondingCurve(Curve),
+    Lbp(LbpConfig),
+}
+
+/// Mirror of the sale contract's `Curve`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Curve {
+    Linear(LinearCurve),
+    Exponential(ExponentialCurve),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LinearCurve {
+    pub base_price: i128,
+    pub slope: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ExponentialCurve {
+    pub base_price: i128,
+    pub growth_bps: u32,
+    pub step: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LbpConfig {
+    pub virtual_reserve: i128,
+    pub start_weight_bps: u32,
+    pub end_weight_bps: u32,
+}
+
+/// Mirror of the sale contract's `Payout`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Payout {
+    Immediate,
+    Milestones(MilestonePlan),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct MilestonePlan {
+    pub approver: Address,
+    pub tranches_bps: Vec<u32>,
+}
+
+/// Mirror of the sale contract's `Buyback`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Buyback {
+    Disabled,
+    Enabled(BuybackConfig),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct BuybackConfig {
+    pub adapter: Address,
+    pub platform_token: Address,
+    pub share_bps: u32,
+}
+
+/// Mirror of the sale contract's `TierPrice`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum TierPrice {
+    Price(i128),
+    DiscountBps(u32),
+}
+
+/// Mirror of the sale contract's `TierSource`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum TierSource {
+    Admin,
+    Staking(Address),
+    StakingSnapshot(Address, u32),
+}
+
+/// Mirror of the sale contract's `Round`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Round {
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+}
+
+/// Mirror of the sale contract's `Extension`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Extension {
+    Disabled,
+    Allowed(ExtensionRule),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ExtensionRule {
+    pub max_extensions: u32,
+    pub max_ledgers: u32,
+    pub only_below_soft_cap: bool,
+}
+
+/// Mirror of the sale contract's `Liquidity`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Liquidity {
+    Disabled,
+    Enabled(LiquidityConfig),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LiquidityConfig {
+    pub adapter: Address,
+    pub locker: Address,
+    pub share_bps: u32,
+    pub lock_ledgers: u32,
+}
+
+/// Mirror of the sale contract's `ProjectInfo`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ProjectInfo {
+    pub name: String,
+    pub token: Address,
+    pub website_hash: BytesN<32>,
+    pub docs_hash: BytesN<32>,
+    pub socials_hash: BytesN<32>,
+    pub audited_by: Vec<String>,
+}
+
+/// Mirror of the config contract's `ConfigValue`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum ConfigValue {
+    U32(u32),
+    I128(i128),
+    Address(Address),
+    Bool(bool),
+}
+
+/// Read interface of the platform config contract.
+#[contractclient(name = "ConfigClient")]
+pub trait ConfigInterface {
+    fn get(env: Env, key: Symbol) -> Option<ConfigValue>;
+
+    fn is_payment_token_allowed(env: Env, token: Address) -> bool;
+}
+
+/// Recording interface of the wasm registry.
+#[contractclient(name = "WasmRegistryClient")]
+pub trait WasmRegistryInterface {
+    fn record(env: Env, recorder: Address, contract: Address, wasm_hash: BytesN<32>);
+}
+
+/// Score interface of the reputation contract.
+#[contractclient(name = "ReputationClient")]
+pub trait ReputationInterface {
+    fn score(env: Env, project: Address) -> i128;
+}
+
+/// The subset of the sale contract interface the factory calls.
+#[contractclient(name = "SaleClient")]
+pub trait SaleInterface {
+    fn initialize(env: Env, admin: Address, config: SaleConfig, project: ProjectInfo);
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Sale Factory — deploys and initializes sale instances in one call.
+///
+/// Every sale is created from the registered sale wasm, stamped with the
+/// platform defaults (fee, fee recipient, guardian) and recorded in an
+/// on-chain registry. Only allow-listed payment tokens can be used.
+///
+/// With a reputation contract set, the platform fee of a deployer whose
+/// score reaches a `FeeDiscount` threshold is cut by the largest discount
+/// it qualifies for.
+///
+/// With the platform config contract set, its `fee_bps`, `fee_to` and
+/// `guardian` values take precedence over the stored defaults, and its
+/// payment-token list replaces this factory's own. With a wasm registry
+/// set, every sale's code hash is recorded there.
+#[contract]
+pub struct SaleFactory;
+
+#[contractimpl]
+impl SaleFactory {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the admin, the sale wasm hash to deploy and the platform defaults.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        sale_wasm_hash: BytesN<32>,
+        defaults: PlatformDefaults,
+    ) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        Self::_validate_defaults(&defaults);
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::SaleWasmHash, &sale_wasm_hash);
+        env.storage().instance().set(&DataKey::Defaults, &defaults);
+        env.storage().instance().set(&DataKey::SaleCount, &0u32);
+
+        env.events().publish((symbol_short!("init"),), admin);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Replace the platform defaults applied to future sales. Admin only.
+    pub fn set_defaults(env: Env, defaults: PlatformDefaults) {
+        Self::_require_admin(&env);
+        Self::_validate_defaults(&defaults);
+        env.storage().instance().set(&DataKey::Defaults, &defaults);
+        env.events().publish((symbol_short!("defaults"),), defaults);
+    }
+
+    /// Change the platform fee charged on future sales and where it goes,
+    /// leaving the other defaults as they are. Admin only.
+    pub fn set_platform_fee(env: Env, fee_bps: u32, fee_recipient: Address) {
+        Self::_require_admin(&env);
+        let mut defaults = Self::defaults(env.clone());
+        defaults.fee_bps = fee_bps;
+        defaults.fee_recipient = fee_recipient.clone();
+        Self::_validate_defaults(&defaults);
+        env.storage().instance().set(&DataKey::Defaults, &defaults);
+        env.events()
+            .publish((symbol_short!("fee"), fee_recipient), fee_bps);
+    }
+
+    /// Point future deployments at a new sale wasm. Admin only.
+    pub fn set_sale_wasm_hash(env: Env, sale_wasm_hash: BytesN<32>) {
+        Self::_require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::SaleWasmHash, &sale_wasm_hash);
+        env.events()
+            .publish((symbol_short!("wasm"),), sale_wasm_hash);
+    }
+
+    /// Allow or disallow `token` as a sale payment token. Admin only.
+    pub fn set_payment_token_allowed(env: Env, token: Address, allowed: bool) {
+        Self::_require_admin(&env);
+        let key = DataKey::AllowedPaymentToken(token.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("pay_tok"), token), allowed);
+    }
+
+    /// Record every deployed sale's code hash in the wasm registry, which must list this contract
+    /// as a recorder, or stop with `None`. Admin only.
+    pub fn set_wasm_registry(env: Env, registry: Option<Address>) {
+        Self::_require_admin(&env);
+        match &registry {
+            Some(registry) => env
+                .storage()
+                .instance()
+                .set(&DataKey::WasmRegistry, registry),
+            None => env.storage().instance().remove(&DataKey::WasmRegistry),
+        }
+        env.events().publish((symbol_short!("wasm_reg"),), registry);
+    }
+
+    /// Read the platform fee, fee recipient, guardian and payment tokens
+    /// from `config`, or go back to this factory's own with `None`. Admin
+    /// only.
+    pub fn set_config(env: Env, config: Option<Address>) {
+        Self::_require_admin(&env);
+        match &config {
+            Some(config) => env.storage().instance().set(&DataKey::Config, config),
+            None => env.storage().instance().remove(&DataKey::Config),
+        }
+        env.events().publish((symbol_short!("config"),), config);
+    }
+
+    /// Discount the platform fee by reputation score per `discounts`, or
+    /// stop with `None`. Admin only.
+    pub fn set_reputation(env: Env, reputation: Option<Address>, discounts: Vec<FeeDiscount>) {
+        Self::_require_admin(&env);
+        for discount in discounts.iter() {
+            assert!(discount.discount_bps <= 10_000, "discount_bps exceeds 100%");
+        }
+        match &reputation {
+            Some(reputation) => env
+                .storage()
+                .instance()
+                .set(&DataKey::Reputation, reputation),
+            None => env.storage().instance().remove(&DataKey::Reputation),
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::FeeDiscounts, &discounts);
+        env.events()
+            .publish((symbol_short!("reputat"),), (reputation, discounts));
+    }
+
+    // ── Deployment ──────────────────────────────────────────────────────
+
+    /// Deploy a new sale administered by `deployer` and initialize it with
+    /// `params` plus the platform defaults. Returns the sale address.
+    ///
+    /// The contract address is derived from `deployer` and `salt`, so two
+    /// projects cannot collide on (or front-run) each other's salt.
+    pub fn deploy_sale(
+        env: Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        params: SaleParams,
+    ) -> Address {
+        deployer.require_auth();
+        assert!(
+            Self::_is_payment_token_allowed(&env, &params.payment_token),
+            "payment token not allowed"
+        );
+
+        let wasm_hash: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SaleWasmHash)
+            .expect("not initialized");
+        let defaults = Self::effective_defaults(env.clone());
+        let fee_bps = Self::fee_bps_for(env.clone(), deployer.clone());
+
+        let salt: BytesN<32> = env
+            .crypto()
+            .sha256(&(deployer.clone(), salt).to_xdr(&env))
+            .into();
+        let sale = env
+            .deployer()
+            .with_current_contract(salt)
+            .deploy(wasm_hash.clone());
+
+        let config = SaleConfig {
+            token: params.token.clone(),
+            payment_token: params.payment_token,
+            price: params.price,
+            soft_cap: params.soft_cap,
+            hard_cap: params.hard_cap,
+            min_contribution: params.min_contribution,
+            max_contribution: params.max_contribution,
+            start_ledger: params.start_ledger,
+            end_ledger: params.end_ledger,
+            fee_bps,
+            fee_recipient: defaults.fee_recipient,
+            guardian: defaults.guardian,
+            mode: params.mode,
+            payout: params.payout,
+            buyback: defaults.buyback,
+            tier_pricing: params.tier_pricing,
+            tier_source: params.tier_source,
+            rounds: params.rounds,
+            extension: params.extension,
+            liquidity: params.liquidity,
+        };
+        SaleClient::new(&env, &sale).initialize(&deployer, &config, &params.project);
+        if let Some(registry) = Self::wasm_registry(env.clone()) {
+            WasmRegistryClient::new(&env, &registry).record(
+                &env.current_contract_address(),
+                &sale,
+                &wasm_hash,
+            );
+        }
+
+        let index = Self::sale_count(env.clone());
+        let record = SaleRecord {
+            sale: sale.clone(),
+            admin: deployer.clone(),
+            token: params.token,
+            created_ledger: env.ledger().sequence(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Sale(index), &record);
+        env.storage()
+            .persistent()
+            .set(&DataKey::IsSale(sale.clone()), &true);
+        env.storage()
+            .instance()
+            .set(&DataKey::SaleCount, &(index + 1));
+
+        env.events()
+            .publish((symbol_short!("deploy"), deployer), (sale.clone(), index));
+
+        sale
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    pub fn defaults(env: Env) -> PlatformDefaults {
+        env.storage()
+            .instance()
+            .get(&DataKey::Defaults)
+            .expect("not initialized")
+    }
+
+    /// Platform fee a sale deployed by `deployer` would be charged, after
+    /// any reputation discount.
+    pub fn fee_bps_for(env: Env, deployer: Address) -> u32 {
+        let fee_bps = Self::effective_defaults(env.clone()).fee_bps;
+        let Some(reputation) = Self::reputation(env.clone()) else {
+            return fee_bps;
+        };
+        let score = ReputationClient::new(&env, &reputation).score(&deployer);
+        let mut discount_bps = 0;
+        for discount in Self::fee_discounts(env).iter() {
+            if score >= discount.min_score {
+                discount_bps = discount_bps.max(discount.discount_bps);
+            }
+        }
+        (fee_bps as u64 * (10_000 - discount_bps) as u64 / 10_000) as u32
+    }
+
+    /// Defaults deployments use: the stored ones, overridden by whatever
+    /// the platform config contract sets.
+    pub fn effective_defaults(env: Env) -> PlatformDefaults {
+        let mut defaults = Self::defaults(env.clone());
+        let Some(config) = Self::config(env.clone()) else {
+            return defaults;
+        };
+        let config = ConfigClient::new(&env, &config);
+        if let Some(ConfigValue::U32(fee_bps)) = config.get(&symbol_short!("fee_bps")) {
+            defaults.fee_bps = fee_bps;
+        }
+        if let Some(ConfigValue::Address(recipient)) = config.get(&symbol_short!("fee_to")) {
+            defaults.fee_recipient = recipient;
+        }
+        if let Some(ConfigValue::Address(guardian)) = config.get(&symbol_short!("guardian")) {
+            defaults.guardian = guardian;
+        }
+        defaults
+    }
+
+    /// Recipient of the platform fee of sales deployed now.
+    pub fn fee_recipient(env: Env) -> Address {
+        Self::effective_defaults(env).fee_recipient
+    }
+
+    /// Guardian of sales deployed now.
+    pub fn guardian(env: Env) -> Address {
+        Self::effective_defaults(env).guardian
+    }
+
+    pub fn wasm_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::WasmRegistry)
+    }
+
+    pub fn config(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Config)
+    }
+
+    pub fn reputation(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Reputation)
+    }
+
+    pub fn fee_discounts(env: Env) -> Vec<FeeDiscount> {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeeDiscounts)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn sale_wasm_hash(env: Env) -> BytesN<32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::SaleWasmHash)
+            .expect("not initialized")
+    }
+
+    pub fn is_payment_token_allowed(env: Env, token: Address) -> bool {
+        Self::_is_payment_token_allowed(&env, &token)
+    }
+
+    /// Number of sales deployed through this factory.
+    pub fn sale_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SaleCount)
+            .unwrap_or(0)
+    }
+
+    /// Registry entry of the `index`-th deployed sale.
+    pub fn get_sale(env: Env, index: u32) -> SaleRecord {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Sale(index))
+            .expect("no sale found")
+    }
+
+    /// Returns `true` if `sale` was deployed by this factory.
+    pub fn is_sale(env: Env, sale: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::IsSale(sale))
+            .unwrap_or(false)
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _require_admin(env: &Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized");
+        admin.require_auth();
+    }
+
+    fn _is_payment_token_allowed(env: &Env, token: &Address) -> bool {
+        if let Some(config) = Self::config(env.clone()) {
+            return ConfigClient::new(env, &config).is_payment_token_allowed(token);
+        }
+        env.storage()
+            .persistent()
+            .get(&DataKey::AllowedPaymentToken(token.clone()))
+            .unwrap_or(false)
+    }
+
+    fn _validate_defaults(defaults: &PlatformDefaults) {
+        assert!(defaults.fee_bps <= 10_000, "fee_bps exceeds 100%");
+        if let Buyback::Enabled(buyback) = &defaults.buyback {
+            assert!(buyback.share_bps <= 10_000, "share_bps exceeds 100%");
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, IntoVal};
+
+    // Deploying a sale needs the compiled sale wasm, so these tests cover the
+    // factory's configuration, validation and registry behaviour.
+
+    fn setup() -> (Env, SaleFactoryClient<'static>, Address, PlatformDefaults) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, SaleFactory);
+        let client = SaleFactoryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let defaults = PlatformDefaults {
+            fee_bps: 250,
+            fee_recipient: Address::generate(&env),
+            guardian: Address::generate(&env),
+            buyback: Buyback::Disabled,
+        };
+        client.initialize(&admin, &BytesN::from_array(&env, &[7u8; 32]), &defaults);
+
+        (env, client, admin, defaults)
+    }
+
+    fn params(env: &Env, payment_token: &Address) -> SaleParams {
+        let token = Address::generate(env);
+        SaleParams {
+            token: token.clone(),
+            payment_token: payment_token.clone(),
+            price: 10_000_000,
+            soft_cap: 1_000,
+            hard_cap: 5_000,
+            min_contribution: 10,
+            max_contribution: 3_000,
+            start_ledger: 100,
+            end_ledger: 200,
+            mode: SaleMode::FixedPrice,
+            payout: Payout::Immediate,
+            tier_pricing: Map::new(env),
+            tier_source: TierSource::Admin,
+            rounds: Vec::new(env),
+            extension: Extension::Disabled,
+            liquidity: Liquidity::Disabled,
+            project: ProjectInfo {
+                name: String::from_str(env, "Demo"),
+                token,
+                website_hash: BytesN::from_array(env, &[1u8; 32]),
+                docs_hash: BytesN::from_array(env, &[2u8; 32]),
+                socials_hash: BytesN::from_array(env, &[3u8; 32]),
+                audited_by: Vec::new(env),
+            },
+        }
+    }
+
+    #[test]
+    fn test_initialize_and_getters() {
+        let (env, client, admin, defaults) = setup();
+        assert_eq!(client.admin(), admin);
+        assert_eq!(client.defaults(), defaults);
+        assert_eq!(
+            client.sale_wasm_hash(),
+            BytesN::from_array(&env, &[7u8; 32])
+        );
+        assert_eq!(client.sale_count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "already initialized")]
+    fn test_double_init_panics() {
+        let (env, client, admin, defaults) = setup();
+        client.initialize(&admin, &BytesN::from_array(&env, &[0u8; 32]), &defaults);
+    }
+
+    #[test]
+    #[should_panic(expected = "fee_bps exceeds 100%")]
+    fn test_invalid_fee_rejected() {
+        let (_, client, _, mut defaults) = setup();
+        defaults.fee_bps = 10_001;
+        client.set_defaults(&defaults);
+    }
+
+    #[test]
+    fn test_set_defaults_and_wasm_hash() {
+        let (env, client, _, mut defaults) = setup();
+        defaults.fee_bps = 500;
+        client.set_defaults(&defaults);
+        assert_eq!(client.defaults().fee_bps, 500);
+
+        let hash = BytesN::from_array(&env, &[9u8; 32]);
+        client.set_sale_wasm_hash(&hash);
+        assert_eq!(client.sale_wasm_hash(), hash);
+    }
+
+    #[test]
+    fn test_set_platform_fee() {
+        let (env, client, _, defaults) = setup();
+        let treasury = Address::generate(&env);
+        client.set_platform_fee(&100, &treasury);
+
+        let updated = client.defaults();
+        assert_eq!(updated.fee_bps, 100);
+        assert_eq!(updated.fee_recipient, treasury);
+        assert_eq!(updated.guardian, defaults.guardian);
+        assert!(client.try_set_platform_fee(&10_001, &treasury).is_err());
+    }
+
+    #[test]
+    fn test_payment_token_allow_list() {
+        let (env, client, _, _) = setup();
+        let usdc = Address::generate(&env);
+        assert!(!client.is_payment_token_allowed(&usdc));
+        client.set_payment_token_allowed(&usdc, &true);
+        assert!(client.is_payment_token_allowed(&usdc));
+        client.set_payment_token_allowed(&usdc, &false);
+        assert!(!client.is_payment_token_allowed(&usdc));
+    }
+
+    #[test]
+    #[should_panic(expected = "payment token not allowed")]
+    fn test_deploy_with_disallowed_payment_token_panics() {
+        let (env, client, _, _) = setup();
+        let deployer = Address::generate(&env);
+        let usdc = Address::generate(&env);
+        client.deploy_sale(
+            &deployer,
+            &BytesN::from_array(&env, &[1u8; 32]),
+            &params(&env, &usdc),
+        );
+    }
+
+    #[contract]
+    struct MockReputation;
+
+    #[contractimpl]
+    impl MockReputation {
+        pub fn set_score(env: Env, project: Address, score: i128) {
+            env.storage().instance().set(&project, &score);
+        }
+
+        pub fn score(env: Env, project: Address) -> i128 {
+            env.storage().instance().get(&project).unwrap_or(0)
+        }
+    }
+
+    #[test]
+    fn test_reputation_discounts_fee() {
+        let (env, client, _, _) = setup();
+        let reputation =
+            MockReputationClient::new(&env, &env.register_contract(None, MockReputation));
+        let (known, trusted, unknown) = (
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        );
+        reputation.set_score(&known, &100);
+        reputation.set_score(&trusted, &500);
+
+        let discounts = Vec::from_array(
+            &env,
+            [
+                FeeDiscount {
+                    min_score: 100,
+                    discount_bps: 2_000,
+                },
+                FeeDiscount {
+                    min_score: 500,
+                    discount_bps: 5_000,
+                },
+            ],
+        );
+        client.set_reputation(&Some(reputation.address.clone()), &discounts);
+        assert_eq!(client.fee_bps_for(&unknown), 250);
+        assert_eq!(client.fee_bps_for(&known), 200);
+        assert_eq!(client.fee_bps_for(&trusted), 125);
+
+        client.set_reputation(&None, &Vec::new(&env));
+        assert_eq!(client.fee_bps_for(&trusted), 250);
+    }
+
+    #[contract]
+    struct MockConfig;
+
+    #[contractimpl]
+    impl MockConfig {
+        pub fn set(env: Env, key: Symbol, value: ConfigValue) {
+            env.storage().instance().set(&key, &value);
+        }
+
+        pub fn get(env: Env, key: Symbol) -> Option<ConfigValue> {
+            env.storage().instance().get(&key)
+        }
+
+        pub fn is_payment_token_allowed(env: Env, token: Address) -> bool {
+            env.storage().instance().has(&token)
+        }
+
+        pub fn allow(env: Env, token: Address) {
+            env.storage().instance().set(&token, &true);
+        }
+    }
+
+    #[test]
+    fn test_config_overrides_defaults() {
+        let (env, client, _, defaults) = setup();
+        let config = MockConfigClient::new(&env, &env.register_contract(None, MockConfig));
+        let (usdc, guardian) = (Address::generate(&env), Address::generate(&env));
+        client.set_payment_token_allowed(&usdc, &true);
+        config.set(&symbol_short!("fee_bps"), &ConfigValue::U32(100));
+        config.set(
+            &symbol_short!("guardian"),
+            &ConfigValue::Address(guardian.clone()),
+        );
+
+        client.set_config(&Some(config.address.clone()));
+        let effective = client.effective_defaults();
+        assert_eq!(effective.fee_bps, 100);
+        assert_eq!(effective.guardian, guardian);
+        assert_eq!(effective.fee_recipient, defaults.fee_recipient);
+        assert_eq!(client.fee_bps_for(&Address::generate(&env)), 100);
+
+        // The config's payment-token list replaces the factory's
+        assert!(!client.is_payment_token_allowed(&usdc));
+        config.allow(&usdc);
+        assert!(client.is_payment_token_allowed(&usdc));
+
+        client.set_config(&None);
+        assert_eq!(client.effective_defaults(), defaults);
+    }
+
+    #[test]
+    #[should_panic(expected = "no sale found")]
+    fn test_get_unknown_sale_panics() {
+        let (_, client, _, _) = setup();
+        client.get_sale(&0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_non_admin_cannot_set_defaults() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, SaleFactory);
+        let client = SaleFactoryClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let defaults = PlatformDefaults {
+            fee_bps: 250,
+            fee_recipient: Address::generate(&env),
+            guardian: Address::generate(&env),
+            buyback: Buyback::Disabled,
+        };
+        client.initialize(&admin, &BytesN::from_array(&env, &[7u8; 32]), &defaults);
+
+        env.mock_auths(&[soroban_sdk::testutils::MockAuth {
+            address: &user,
+            invoke: &soroban_sdk::testutils::MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "set_defaults",
+                args: (defaults.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        client.set_defaults(&defaults);
+    }
+}
@@ -0,0 +1,351 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env, Vec};
+use soropad_math::{apply_bps, BPS_DENOMINATOR};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Payees,
+    Epoch,
+    /// Amount of a token paid out during an epoch.
+    TotalReleased(u32, Address),
+    /// Amount of a token paid out to a payee during an epoch.
+    Released(u32, Address, Address),
+}
+
+/// A payee and their share of everything the splitter receives.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Payee {
+    pub account: Address,
+    pub bps: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Payment Splitter Contract — shares incoming tokens between payees.
+///
+/// Any token sent to the splitter is owed to the payees in proportion to
+/// their bps shares, which sum to 100 %. Nothing is pushed: each payee's
+/// share accrues and is paid out by `release(token, payee)`, which anyone
+/// may call. A payee is owed `share × (balance + total released) − released
+/// to them`, so tokens can arrive at any time and in any order.
+///
+/// The payee set has no admin. `update_payees` needs every current payee to
+/// sign and starts a new epoch with fresh accounting. Before switching it
+/// pays out everything owed in the tokens it is given; whatever is left of
+/// any other token (and rounding dust) is split under the new shares.
+#[contract]
+pub struct SplitterContract;
+
+#[contractimpl]
+impl SplitterContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the payees. Shares must be positive and sum to 10 000 bps, with
+    /// each account listed once.
+    pub fn initialize(env: Env, payees: Vec<Payee>) {
+        if env.storage().instance().has(&DataKey::Payees) {
+            panic!("already initialized");
+        }
+        Self::_set_payees(&env, payees);
+    }
+
+    // ── Payouts ─────────────────────────────────────────────────────────
+
+    /// Pay `payee` everything owed to them in `token`. Anyone may call; the
+    /// tokens always go to `payee`. Returns the amount paid.
+    pub fn release(env: Env, token: Address, payee: Address) -> i128 {
+        let bps = Self::share_of(env.clone(), payee.clone());
+        assert!(bps > 0, "not a payee");
+        Self::_release(&env, &token, &payee, bps)
+    }
+
+    // ── Payee set ───────────────────────────────────────────────────────
+
+    /// Replace the payees. Pays out everything owed in `tokens` under the
+    /// current shares first. Every current payee must sign.
+    pub fn update_payees(env: Env, payees: Vec<Payee>, tokens: Vec<Address>) {
+        let current = Self::payees(env.clone());
+        for payee in current.iter() {
+            payee.account.require_auth();
+        }
+        for token in tokens.iter() {
+            for payee in current.iter() {
+                Self::_release(&env, &token, &payee.account, payee.bps);
+            }
+        }
+
+        let epoch = Self::epoch(env.clone()) + 1;
+        env.storage().instance().set(&DataKey::Epoch, &epoch);
+        Self::_set_payees(&env, payees);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// What `release(token, payee)` would pay right now.
+    pub fn releasable(env: Env, token: Address, payee: Address) -> i128 {
+        let bps = Self::share_of(env.clone(), payee.clone());
+        if bps == 0 {
+            return 0;
+        }
+        Self::_releasable(&env, &token, &payee, bps)
+    }
+
+    /// Share of `payee` in bps, 0 for anyone else.
+    pub fn share_of(env: Env, payee: Address) -> u32 {
+        Self::payees(env)
+            .iter()
+            .find(|p| p.account == payee)
+            .map(|p| p.bps)
+            .unwrap_or(0)
+    }
+
+    /// Amount of `token` paid to `payee` in the current epoch.
+    pub fn released(env: Env, token: Address, payee: Address) -> i128 {
+        let epoch = Self::epoch(env.clone());
+        env.storage()
+            .persistent()
+            .get(&DataKey::Released(epoch, token, payee))
+            .unwrap_or(0)
+    }
+
+    /// Amount of `token` paid out in the current epoch.
+    pub fn total_released(env: Env, token: Address) -> i128 {
+        let epoch = Self::epoch(env.clone());
+        env.storage()
+            .persistent()
+            .get(&DataKey::TotalReleased(epoch, token))
+            .unwrap_or(0)
+    }
+
+    /// Number of times the payee set has been updated.
+    pub fn epoch(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Epoch).unwrap_or(0)
+    }
+
+    pub fn payees(env: Env) -> Vec<Payee> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Payees)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _releasable(env: &Env, token: &Address, payee: &Address, bps: u32) -> i128 {
+        let balance = token::Client::new(env, token).balance(&env.current_contract_address());
+        let received = balance + Self::total_released(env.clone(), token.clone());
+        apply_bps(received, bps) - Self::released(env.clone(), token.clone(), payee.clone())
+    }
+
+    fn _release(env: &Env, token: &Address, payee: &Address, bps: u32) -> i128 {
+        let amount = Self::_releasable(env, token, payee, bps);
+        if amount <= 0 {
+            return 0;
+        }
+
+        let epoch = Self::epoch(env.clone());
+        let released = Self::released(env.clone(), token.clone(), payee.clone());
+        env.storage().persistent().set(
+            &DataKey::Released(epoch, token.clone(), payee.clone()),
+            &(released + amount),
+        );
+        let total = Self::total_released(env.clone(), token.clone());
+        env.storage().persistent().set(
+            &DataKey::TotalReleased(epoch, token.clone()),
+            &(total + amount),
+        );
+        token::Client::new(env, token).transfer(&env.current_contract_address(), payee, &amount);
+
+        env.events().publish(
+            (symbol_short!("release"), token.clone(), payee.clone()),
+            amount,
+        );
+        amount
+    }
+
+    fn _set_payees(env: &Env, payees: Vec<Payee>) {
+        assert!(!payees.is_empty(), "no payees");
+        let mut total: i128 = 0;
+        for payee in payees.iter() {
+            assert!(payee.bps > 0, "share must be positive");
+            assert!(
+                payees.iter().filter(|p| p.account == payee.account).count() == 1,
+                "duplicate payee"
+            );
+            total += payee.bps as i128;
+        }
+        assert!(total == BPS_DENOMINATOR, "shares must sum to 10000 bps");
+
+        env.storage().instance().set(&DataKey::Payees, &payees);
+        env.events().publish((symbol_short!("payees"),), payees);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{
+        testutils::Address as _, testutils::MockAuth, testutils::MockAuthInvoke, vec, IntoVal,
+    };
+
+    struct Setup {
+        env: Env,
+        client: SplitterContractClient<'static>,
+        token: TokenFixture,
+        founder: Address,
+        platform: Address,
+    }
+
+    /// Founder 70 %, platform 30 %.
+    fn setup() -> Setup {
+        let env = test_env();
+
+        let token = TokenFixture::stellar_asset(&env);
+        let client =
+            SplitterContractClient::new(&env, &env.register_contract(None, SplitterContract));
+        let (founder, platform) = (Address::generate(&env), Address::generate(&env));
+        client.initialize(&vec![
+            &env,
+            Payee {
+                account: founder.clone(),
+                bps: 7_000,
+            },
+            Payee {
+                account: platform.clone(),
+                bps: 3_000,
+            },
+        ]);
+
+        Setup {
+            token,
+            env,
+            client,
+            founder,
+            platform,
+        }
+    }
+
+    fn deposit(s: &Setup, amount: i128) {
+        s.token.mint(&s.client.address, amount);
+    }
+
+    #[test]
+    fn test_release_shares_as_funds_arrive() {
+        let s = setup();
+        deposit(&s, 1_000);
+        assert_eq!(s.client.release(&s.token.address, &s.founder), 700);
+
+        deposit(&s, 1_000);
+        assert_eq!(s.client.releasable(&s.token.address, &s.founder), 700);
+        assert_eq!(s.client.release(&s.token.address, &s.platform), 600);
+        assert_eq!(s.client.release(&s.token.address, &s.founder), 700);
+        assert_eq!(s.client.release(&s.token.address, &s.founder), 0);
+
+        assert_eq!(s.token.balance(&s.founder), 1_400);
+        assert_eq!(s.token.balance(&s.platform), 600);
+        assert_eq!(s.client.total_released(&s.token.address), 2_000);
+    }
+
+    #[test]
+    fn test_update_payees_settles_listed_tokens() {
+        let s = setup();
+        deposit(&s, 1_000);
+        s.client.release(&s.token.address, &s.founder);
+
+        let cofounder = Address::generate(&s.env);
+        s.client.update_payees(
+            &vec![
+                &s.env,
+                Payee {
+                    account: s.founder.clone(),
+                    bps: 5_000,
+                },
+                Payee {
+                    account: cofounder.clone(),
+                    bps: 5_000,
+                },
+            ],
+            &vec![&s.env, s.token.address.clone()],
+        );
+        assert_eq!(s.client.epoch(), 1);
+        assert_eq!(s.token.balance(&s.platform), 300);
+        assert_eq!(s.client.share_of(&s.platform), 0);
+
+        deposit(&s, 1_000);
+        assert_eq!(s.client.release(&s.token.address, &cofounder), 500);
+        assert_eq!(s.client.releasable(&s.token.address, &s.founder), 500);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_update_payees_needs_every_payee() {
+        let s = setup();
+        let payees = vec![
+            &s.env,
+            Payee {
+                account: s.founder.clone(),
+                bps: 10_000,
+            },
+        ];
+        let tokens: Vec<Address> = vec![&s.env];
+        s.env.mock_auths(&[MockAuth {
+            address: &s.founder,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "update_payees",
+                args: (payees.clone(), tokens.clone()).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.update_payees(&payees, &tokens);
+    }
+
+    #[test]
+    #[should_panic(expected = "shares must sum to 10000 bps")]
+    fn test_shares_must_sum_to_whole() {
+        let env = Env::default();
+        let client =
+            SplitterContractClient::new(&env, &env.register_contract(None, SplitterContract));
+        client.initialize(&vec![
+            &env,
+            Payee {
+                account: Address::generate(&env),
+                bps: 9_000,
+            },
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate payee")]
+    fn test_duplicate_payee() {
+        let env = Env::default();
+        let client =
+            SplitterContractClient::new(&env, &env.register_contract(None, SplitterContract));
+        let payee = Address::generate(&env);
+        client.initialize(&vec![
+            &env,
+            Payee {
+                account: payee.clone(),
+                bps: 5_000,
+            },
+            Payee {
+                account: payee,
+                bps: 5_000,
+            },
+        ]);
+    }
+}
@@ -0,0 +1,2268 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, symbol_short, token, Address, Env,
+    String, Symbol, Vec,
+};
+
+/// Denominator for all basis-point values.
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Fixed-point scale of the reward-per-share accumulator.
+pub const REWARD_SCALE: i128 = 1_000_000_000_000;
+
+/// Kind of the badge `claim_tier_badge` issues.
+pub const TIER_BADGE: Symbol = symbol_short!("tier");
+
+/// Points activity recorded for locked stake, in token-ledgers.
+pub const LOCK_ACTIVITY: Symbol = symbol_short!("lock");
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Token,
+    LockOptions,
+    Tiers,
+    TotalStaked,
+    PositionCount,
+    Position(u64),
+    Positions(Address),
+    Staked(Address),
+    Weight(Address),
+    RewardTokens,
+    RewardStream(Address),
+    RewardPerSharePaid(Address, Address),
+    Rewards(Address, Address),
+    Checkpoints(Address),
+    SnapshotCount,
+    Snapshot(u32),
+    CompoundIncentiveBps,
+    AutoCompound(Address),
+    SlashConfig,
+    PendingSlash(Address),
+    VestingConfig,
+    VestingStaked(Address),
+    CooldownLedgers,
+    Unbonding(Address),
+    TicketWeight,
+    SnapshotTicketWeight(u32),
+    TicketTotal(u32),
+    TicketHolders(u32),
+    TicketRange(u32, Address),
+    PauseRegistry,
+    Badges,
+    /// Level of the tier badge issued to a staker.
+    BadgeTier(Address),
+    Points,
+}
+
+/// A lock duration stakers can choose and the tier weight it earns.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LockOption {
+    pub lock_ledgers: u32,
+    /// Tier weight per staked unit, in bps.
+    pub multiplier_bps: u32,
+}
+
+/// A single stake with its own lock. The lock terms are copied from the
+/// chosen `LockOption`, so later option changes leave it untouched.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Position {
+    pub id: u64,
+    pub owner: Address,
+    pub amount: i128,
+    pub lock_ledgers: u32,
+    pub multiplier_bps: u32,
+    pub unlock_ledger: u32,
+}
+
+/// Lottery tickets `[start, end)` registered by an address for a snapshot.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TicketRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+/// Emission state of one reward token.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RewardStream {
+    /// Rewards streamed per ledger in the current period.
+    pub rate: i128,
+    /// Ledger at which the current period ends.
+    pub end_ledger: u32,
+    pub last_update: u32,
+    pub reward_per_share: i128,
+}
+
+/// Stake leaving through the cooldown queue, withdrawable from
+/// `ready_ledger`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Unbonding {
+    pub amount: i128,
+    pub ready_ledger: u32,
+}
+
+/// Where slashed stake goes and how long a staker has to appeal a slash.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SlashConfig {
+    pub insurance_fund: Address,
+    pub appeal_ledgers: u32,
+}
+
+/// A slash announced by the admin, executable from `execute_ledger` unless
+/// cancelled on appeal.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PendingSlash {
+    /// Share of every position to slash, in bps.
+    pub bps: u32,
+    pub reason: String,
+    pub execute_ledger: u32,
+}
+
+/// The vesting contract whose locked allocations can be staked and the tier
+/// weight they earn.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct VestingConfig {
+    pub vesting: Address,
+    /// Tier weight per staked unit, in bps.
+    pub multiplier_bps: u32,
+}
+
+/// Lock hooks of the vesting contract. Locked tokens stay in the vesting
+/// contract and cannot be released until unlocked.
+#[contractclient(name = "VestingClient")]
+pub trait Vesting {
+    fn lock(env: Env, recipient: Address, amount: i128);
+
+    fn unlock(env: Env, recipient: Address, amount: i128);
+}
+
+/// Platform-wide pause switch shared by the launchpad contracts.
+#[contractclient(name = "PauseRegistryClient")]
+pub trait PauseRegistry {
+    fn is_paused(env: Env, contract: Address) -> bool;
+}
+
+/// Minting interface of the badges contract.
+#[contractclient(name = "BadgesClient")]
+pub trait Badges {
+    fn mint(env: Env, issuer: Address, owner: Address, kind: Symbol, level: u32) -> u64;
+}
+
+/// Activity interface of the points contract.
+#[contractclient(name = "PointsClient")]
+pub trait Points {
+    fn record(env: Env, awarder: Address, account: Address, activity: Symbol, units: i128) -> i128;
+}
+
+/// Stake of an address from `ledger` onwards.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Checkpoint {
+    pub ledger: u32,
+    pub amount: i128,
+    pub weight: i128,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Staking Contract — platform-token staking for launchpad tiers.
+///
+/// Stakers lock the platform token in positions, each under one of the lock
+/// options set by the admin. A position's tier weight is its amount times
+/// the option's multiplier, and a staker's weight is the sum over their
+/// positions. Sales read `tier_of` to gate access and pricing.
+///
+/// Snapshots also back stake-weighted lotteries: every `ticket_weight` of
+/// weight at a snapshot is one ticket, and stakers register their tickets
+/// into consecutive ranges so a draw in `[0, total_tickets)` maps to exactly
+/// one address through `ticket_owner`.
+///
+/// Positions are numbered and can change hands with `transfer_position`,
+/// which needs both the owner and the admin to sign off, so custodians can
+/// manage client stakes.
+///
+/// Unstaking takes two steps: `request_unstake` moves stake into a cooldown
+/// queue where it earns neither tier weight nor rewards, and `withdraw` pays
+/// it out once the cooldown has passed. This keeps stakers from flash-staking
+/// around snapshots.
+///
+/// Stakers also earn the rewards funded through `fund_rewards`, streamed
+/// pro-rata to the amount staked via a reward-per-share accumulator. Each
+/// reward token, whether the staked token or a partner project's, runs its
+/// own stream and accumulator.
+///
+/// Vesting recipients can also stake their still-vesting allocation with
+/// `stake_vesting`: the vesting contract locks it in place and it earns tier
+/// weight and rewards without ever becoming liquid.
+///
+/// Stakers who break participation rules can be slashed: the admin announces
+/// a slash, the staker can appeal off-chain while it is pending, and once the
+/// appeal window has passed anyone can execute it, sending the slashed stake
+/// to the insurance fund.
+///
+/// Staker actions stop while the guardian's pause registry, set with
+/// `set_pause_registry`, has this contract paused.
+///
+/// Once a badges contract is set, stakers can `claim_tier_badge` for a
+/// soulbound badge recording the highest tier they have reached.
+///
+/// With a points contract set, every stake records a `lock` activity of
+/// its amount times its lock length, so longer locks earn more points.
+#[contract]
+pub struct StakingContract;
+
+#[contractimpl]
+impl StakingContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the admin, the staked token, the lock options (see
+    /// `set_lock_options`) and the tier thresholds (see `set_tiers`).
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        token: Address,
+        lock_options: Vec<LockOption>,
+        tiers: Vec<i128>,
+    ) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        Self::_validate_lock_options(&lock_options);
+        Self::_validate_tiers(&tiers);
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Token, &token);
+        env.storage()
+            .instance()
+            .set(&DataKey::LockOptions, &lock_options);
+        env.storage().instance().set(&DataKey::Tiers, &tiers);
+        env.storage().instance().set(&DataKey::TotalStaked, &0i128);
+
+        env.events()
+            .publish((symbol_short!("init"),), (admin, token));
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Replace the tier thresholds: strictly ascending minimum weights, where
+    /// reaching the n-th threshold puts a staker in tier n. Admin only.
+    pub fn set_tiers(env: Env, tiers: Vec<i128>) {
+        Self::_require_admin(&env);
+        Self::_validate_tiers(&tiers);
+        env.storage().instance().set(&DataKey::Tiers, &tiers);
+        env.events().publish((symbol_short!("tiers"),), tiers);
+    }
+
+    /// Replace the lock options offered for new positions. Existing positions
+    /// keep their terms. Admin only.
+    pub fn set_lock_options(env: Env, lock_options: Vec<LockOption>) {
+        Self::_require_admin(&env);
+        Self::_validate_lock_options(&lock_options);
+        env.storage()
+            .instance()
+            .set(&DataKey::LockOptions, &lock_options);
+        env.events()
+            .publish((symbol_short!("locks"),), lock_options);
+    }
+
+    /// Stream `amount` of `reward_token` to stakers over the next `duration`
+    /// ledgers, opening a stream for it if there is none yet. Rewards still
+    /// pending from a running period of that token roll into the new one.
+    /// Admin only.
+    pub fn fund_rewards(env: Env, reward_token: Address, amount: i128, duration: u32) {
+        Self::_require_admin(&env);
+        assert!(amount > 0, "amount must be positive");
+        assert!(duration > 0, "duration must be positive");
+        Self::_update_rewards(&env, None);
+
+        token::Client::new(&env, &reward_token).transfer(
+            &Self::admin(env.clone()),
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        let mut tokens = Self::reward_tokens(env.clone());
+        if !tokens.contains(&reward_token) {
+            tokens.push_back(reward_token.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::RewardTokens, &tokens);
+        }
+
+        let now = env.ledger().sequence();
+        let mut stream = Self::_stream(&env, &reward_token);
+        let leftover = if now < stream.end_ledger {
+            (stream.end_ledger - now) as i128 * stream.rate
+        } else {
+            0
+        };
+        stream.rate = (amount + leftover) / duration as i128;
+        assert!(stream.rate > 0, "reward rate is zero");
+        stream.end_ledger = now + duration;
+        stream.last_update = now;
+        env.storage()
+            .instance()
+            .set(&DataKey::RewardStream(reward_token.clone()), &stream);
+
+        env.events().publish(
+            (symbol_short!("fund"), reward_token),
+            (amount, now + duration),
+        );
+    }
+
+    /// Set the share of compounded rewards paid to whoever calls `compound`
+    /// for another staker. Admin only.
+    pub fn set_compound_incentive(env: Env, bps: u32) {
+        Self::_require_admin(&env);
+        assert!(bps as i128 <= BPS_DENOMINATOR, "bps exceeds 100%");
+        env.storage()
+            .instance()
+            .set(&DataKey::CompoundIncentiveBps, &bps);
+        env.events().publish((symbol_short!("incentive"),), bps);
+    }
+
+    /// Set the insurance fund receiving slashed stake and the appeal window
+    /// between announcing and executing a slash. Admin only.
+    pub fn set_slash_config(env: Env, insurance_fund: Address, appeal_ledgers: u32) {
+        Self::_require_admin(&env);
+        let config = SlashConfig {
+            insurance_fund,
+            appeal_ledgers,
+        };
+        env.storage().instance().set(&DataKey::SlashConfig, &config);
+        env.events().publish((symbol_short!("slashcfg"),), config);
+    }
+
+    /// Set the weight one lottery ticket stands for, applied to snapshots
+    /// taken from now on. Admin only.
+    pub fn set_ticket_weight(env: Env, weight: i128) {
+        Self::_require_admin(&env);
+        assert!(weight > 0, "ticket weight must be positive");
+        env.storage()
+            .instance()
+            .set(&DataKey::TicketWeight, &weight);
+        env.events().publish((symbol_short!("ticketwt"),), weight);
+    }
+
+    /// Set the cooldown between requesting an unstake and withdrawing it.
+    /// Admin only.
+    pub fn set_cooldown(env: Env, ledgers: u32) {
+        Self::_require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::CooldownLedgers, &ledgers);
+        env.events().publish((symbol_short!("cooldown"),), ledgers);
+    }
+
+    /// Accept stakes of allocations locked in `vesting`, weighted by
+    /// `multiplier_bps`. Admin only.
+    pub fn set_vesting(env: Env, vesting: Address, multiplier_bps: u32) {
+        Self::_require_admin(&env);
+        assert!(multiplier_bps > 0, "multiplier must be positive");
+        let config = VestingConfig {
+            vesting,
+            multiplier_bps,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::VestingConfig, &config);
+        env.events().publish((symbol_short!("vesting"),), config);
+    }
+
+    /// Follow the guardian's pause registry. Admin only.
+    pub fn set_pause_registry(env: Env, registry: Address) {
+        Self::_require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::PauseRegistry, &registry);
+        env.events()
+            .publish((symbol_short!("pause_reg"),), registry);
+    }
+
+    /// Issue tier badges through `badges`, which must list this contract as
+    /// a minter. Admin only.
+    pub fn set_badges(env: Env, badges: Address) {
+        Self::_require_admin(&env);
+        env.storage().instance().set(&DataKey::Badges, &badges);
+        env.events().publish((symbol_short!("badges"),), badges);
+    }
+
+    /// Record lock activity in `points`, which must list this contract as
+    /// an awarder. Admin only.
+    pub fn set_points(env: Env, points: Address) {
+        Self::_require_admin(&env);
+        env.storage().instance().set(&DataKey::Points, &points);
+        env.events().publish((symbol_short!("points"),), points);
+    }
+
+    // ── Slashing ────────────────────────────────────────────────────────
+
+    /// Announce a slash of `bps` of every position of `staker`. The staker
+    /// cannot unstake while it is pending. Admin only.
+    pub fn slash(env: Env, staker: Address, bps: u32, reason: String) {
+        Self::_require_admin(&env);
+        assert!(bps > 0 && bps as i128 <= BPS_DENOMINATOR, "invalid bps");
+        let key = DataKey::PendingSlash(staker.clone());
+        assert!(
+            !env.storage().persistent().has(&key),
+            "slash already pending"
+        );
+        let config = Self::slash_config(env.clone());
+
+        let pending = PendingSlash {
+            bps,
+            reason,
+            execute_ledger: env.ledger().sequence() + config.appeal_ledgers,
+        };
+        env.storage().persistent().set(&key, &pending);
+        env.events()
+            .publish((symbol_short!("slashreq"), staker), pending);
+    }
+
+    /// Drop the pending slash of `staker`, e.g. after a successful appeal.
+    /// Admin only.
+    pub fn cancel_slash(env: Env, staker: Address) {
+        Self::_require_admin(&env);
+        let key = DataKey::PendingSlash(staker.clone());
+        assert!(env.storage().persistent().has(&key), "no pending slash");
+        env.storage().persistent().remove(&key);
+        env.events()
+            .publish((symbol_short!("slashcncl"), staker), ());
+    }
+
+    /// Execute the pending slash of `staker` once its appeal window has
+    /// passed, moving the slashed stake to the insurance fund. Anyone may
+    /// call. Returns the amount slashed.
+    pub fn execute_slash(env: Env, staker: Address) -> i128 {
+        let key = DataKey::PendingSlash(staker.clone());
+        let pending: PendingSlash = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .expect("no pending slash");
+        assert!(
+            env.ledger().sequence() >= pending.execute_ledger,
+            "appeal window open"
+        );
+        env.storage().persistent().remove(&key);
+        Self::_update_rewards(&env, Some(&staker));
+
+        let mut slashed = 0i128;
+        let mut weight = 0i128;
+        for mut position in Self::positions_of(env.clone(), staker.clone()).iter() {
+            let cut = soropad_math::apply_bps(position.amount, pending.bps);
+            if cut == 0 {
+                continue;
+            }
+            weight += Self::_weight(&position, position.amount)
+                - Self::_weight(&position, position.amount - cut);
+            slashed += cut;
+            position.amount -= cut;
+            if position.amount == 0 {
+                Self::_close_position(&env, &staker, position.id);
+            } else {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Position(position.id), &position);
+            }
+        }
+        Self::_apply(&env, &staker, -slashed, -weight);
+
+        // Stake already queued for withdrawal is slashed as well
+        let mut queue = Self::unbonding_of(env.clone(), staker.clone());
+        for i in 0..queue.len() {
+            let mut entry = queue.get(i).unwrap();
+            let cut = soropad_math::apply_bps(entry.amount, pending.bps);
+            entry.amount -= cut;
+            slashed += cut;
+            queue.set(i, entry);
+        }
+        Self::_set_unbonding(&env, &staker, &queue);
+
+        if slashed > 0 {
+            token::Client::new(&env, &Self::token(env.clone())).transfer(
+                &env.current_contract_address(),
+                &Self::slash_config(env.clone()).insurance_fund,
+                &slashed,
+            );
+        }
+
+        env.events().publish(
+            (symbol_short!("slashed"), staker),
+            (slashed, pending.reason),
+        );
+        slashed
+    }
+
+    // ── Staker actions ──────────────────────────────────────────────────
+
+    /// Open a position of `amount` under lock option `option` (an index into
+    /// `lock_options`). Returns the position id.
+    pub fn stake(env: Env, staker: Address, amount: i128, option: u32) -> u64 {
+        staker.require_auth();
+        Self::_check_not_paused(&env);
+        assert!(amount > 0, "amount must be positive");
+        let lock = Self::lock_options(env.clone())
+            .get(option)
+            .expect("invalid lock option");
+        Self::_update_rewards(&env, Some(&staker));
+
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &staker,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        let id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PositionCount)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::PositionCount, &(id + 1));
+        let position = Position {
+            id,
+            owner: staker.clone(),
+            amount,
+            lock_ledgers: lock.lock_ledgers,
+            multiplier_bps: lock.multiplier_bps,
+            unlock_ledger: env.ledger().sequence() + lock.lock_ledgers,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Position(id), &position);
+
+        let key = DataKey::Positions(staker.clone());
+        let mut ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(&env));
+        ids.push_back(id);
+        env.storage().persistent().set(&key, &ids);
+        Self::_apply(&env, &staker, amount, Self::_weight(&position, amount));
+
+        if let Some(points) = Self::points(env.clone()) {
+            if lock.lock_ledgers > 0 {
+                PointsClient::new(&env, &points).record(
+                    &env.current_contract_address(),
+                    &staker,
+                    &LOCK_ACTIVITY,
+                    &(amount * lock.lock_ledgers as i128),
+                );
+            }
+        }
+
+        env.events().publish(
+            (symbol_short!("stake"), staker),
+            (id, amount, position.unlock_ledger),
+        );
+        id
+    }
+
+    /// Move position `position_id` from `from` to `to`, along with its tier
+    /// weight. Rewards earned so far stay with `from`. Needs the admin's
+    /// approval as well as the owner's.
+    pub fn transfer_position(env: Env, from: Address, to: Address, position_id: u64) {
+        from.require_auth();
+        Self::_check_not_paused(&env);
+        Self::_require_admin(&env);
+        assert!(from != to, "cannot transfer to self");
+        assert!(
+            !env.storage()
+                .persistent()
+                .has(&DataKey::PendingSlash(from.clone())),
+            "slash pending"
+        );
+
+        let mut position = Self::_owned_position(&env, &from, position_id);
+        Self::_update_rewards(&env, Some(&from));
+        Self::_update_rewards(&env, Some(&to));
+
+        let weight = Self::_weight(&position, position.amount);
+        Self::_close_position(&env, &from, position_id);
+        Self::_apply(&env, &from, -position.amount, -weight);
+
+        position.owner = to.clone();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Position(position_id), &position);
+        let key = DataKey::Positions(to.clone());
+        let mut ids = Self::position_ids(env.clone(), to.clone());
+        ids.push_back(position_id);
+        env.storage().persistent().set(&key, &ids);
+        Self::_apply(&env, &to, position.amount, weight);
+
+        env.events()
+            .publish((symbol_short!("transfer"), from, to), position_id);
+    }
+
+    /// Move `amount` of position `position_id` into the cooldown queue once
+    /// its lock has expired. It stops counting immediately and can be
+    /// withdrawn after the cooldown. An emptied position is closed.
+    pub fn request_unstake(env: Env, staker: Address, position_id: u64, amount: i128) {
+        staker.require_auth();
+        Self::_check_not_paused(&env);
+        assert!(amount > 0, "amount must be positive");
+
+        let mut position = Self::_owned_position(&env, &staker, position_id);
+        assert!(
+            env.ledger().sequence() >= position.unlock_ledger,
+            "stake still locked"
+        );
+        assert!(
+            !env.storage()
+                .persistent()
+                .has(&DataKey::PendingSlash(staker.clone())),
+            "slash pending"
+        );
+        assert!(amount <= position.amount, "insufficient stake");
+        Self::_update_rewards(&env, Some(&staker));
+
+        let weight = Self::_weight(&position, position.amount)
+            - Self::_weight(&position, position.amount - amount);
+        position.amount -= amount;
+        if position.amount == 0 {
+            Self::_close_position(&env, &staker, position_id);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Position(position_id), &position);
+        }
+        Self::_apply(&env, &staker, -amount, -weight);
+
+        let ready_ledger = env.ledger().sequence() + Self::cooldown_ledgers(env.clone());
+        let mut queue = Self::unbonding_of(env.clone(), staker.clone());
+        queue.push_back(Unbonding {
+            amount,
+            ready_ledger,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::Unbonding(staker.clone()), &queue);
+
+        env.events().publish(
+            (symbol_short!("unstake"), staker),
+            (position_id, amount, ready_ledger),
+        );
+    }
+
+    /// Pay out every queued unstake of `staker` whose cooldown has passed.
+    /// Returns the amount withdrawn.
+    pub fn withdraw(env: Env, staker: Address) -> i128 {
+        staker.require_auth();
+        Self::_check_not_paused(&env);
+        assert!(
+            !env.storage()
+                .persistent()
+                .has(&DataKey::PendingSlash(staker.clone())),
+            "slash pending"
+        );
+
+        let now = env.ledger().sequence();
+        let mut amount = 0i128;
+        let mut remaining = Vec::new(&env);
+        for entry in Self::unbonding_of(env.clone(), staker.clone()).iter() {
+            if entry.ready_ledger <= now {
+                amount += entry.amount;
+            } else {
+                remaining.push_back(entry);
+            }
+        }
+        assert!(amount > 0, "nothing to withdraw");
+        Self::_set_unbonding(&env, &staker, &remaining);
+
+        token::Client::new(&env, &Self::token(env.clone())).transfer(
+            &env.current_contract_address(),
+            &staker,
+            &amount,
+        );
+
+        env.events()
+            .publish((symbol_short!("withdraw"), staker), amount);
+        amount
+    }
+
+    /// Opt in or out of letting anyone `compound` for `staker`.
+    pub fn set_auto_compound(env: Env, staker: Address, enabled: bool) {
+        staker.require_auth();
+        let key = DataKey::AutoCompound(staker.clone());
+        if enabled {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("auto"), staker), enabled);
+    }
+
+    /// Restake the pending staked-token rewards of `staker` into their
+    /// position `position_id`, keeping its lock. Stakers can compound for themselves;
+    /// anyone can compound for a staker who opted in with `set_auto_compound`
+    /// and earns the compound incentive for doing so. Returns the amount
+    /// restaked.
+    pub fn compound(env: Env, caller: Address, staker: Address, position_id: u64) -> i128 {
+        caller.require_auth();
+        Self::_check_not_paused(&env);
+        if caller != staker {
+            assert!(
+                Self::is_auto_compound(env.clone(), staker.clone()),
+                "auto-compound not enabled"
+            );
+        }
+
+        let mut position = Self::_owned_position(&env, &staker, position_id);
+        Self::_update_rewards(&env, Some(&staker));
+        let key = DataKey::Rewards(staker.clone(), Self::token(env.clone()));
+        let reward: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().remove(&key);
+
+        let incentive = if caller != staker {
+            soropad_math::apply_bps(reward, Self::compound_incentive_bps(env.clone()))
+        } else {
+            0
+        };
+        if incentive > 0 {
+            token::Client::new(&env, &Self::token(env.clone())).transfer(
+                &env.current_contract_address(),
+                &caller,
+                &incentive,
+            );
+        }
+
+        let restaked = reward - incentive;
+        let weight = Self::_weight(&position, position.amount + restaked)
+            - Self::_weight(&position, position.amount);
+        position.amount += restaked;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Position(position_id), &position);
+        Self::_apply(&env, &staker, restaked, weight);
+
+        env.events()
+            .publish((symbol_short!("compound"), staker), (restaked, incentive));
+        restaked
+    }
+
+    /// Pay out the `reward_token` rewards earned by `staker`. Returns the
+    /// amount paid.
+    pub fn claim_rewards(env: Env, staker: Address, reward_token: Address) -> i128 {
+        staker.require_auth();
+        Self::_check_not_paused(&env);
+        Self::_update_rewards(&env, Some(&staker));
+
+        let key = DataKey::Rewards(staker.clone(), reward_token.clone());
+        let reward: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if reward > 0 {
+            env.storage().persistent().remove(&key);
+            token::Client::new(&env, &reward_token).transfer(
+                &env.current_contract_address(),
+                &staker,
+                &reward,
+            );
+        }
+
+        env.events()
+            .publish((symbol_short!("reward"), staker, reward_token), reward);
+        reward
+    }
+
+    /// Record the current ledger as a snapshot and return its id. Stakes
+    /// count as they stood at the end of the previous ledger, so nothing
+    /// staked in the snapshot ledger itself is included. Anyone may call.
+    pub fn snapshot(env: Env) -> u32 {
+        let id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::SnapshotCount)
+            .unwrap_or(0);
+        let ledger = env.ledger().sequence();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Snapshot(id), &ledger);
+        env.storage()
+            .instance()
+            .set(&DataKey::SnapshotCount, &(id + 1));
+        env.storage().persistent().set(
+            &DataKey::SnapshotTicketWeight(id),
+            &Self::ticket_weight(env.clone()),
+        );
+
+        env.events()
+            .publish((symbol_short!("snapshot"),), (id, ledger));
+        id
+    }
+
+    /// Give `staker` a tier badge at their current tier, or raise the level
+    /// of the one they hold. A badge never goes down: claiming at a lower
+    /// tier than the badge's changes nothing. Returns the badge's level.
+    /// Anyone may call.
+    pub fn claim_tier_badge(env: Env, staker: Address) -> u32 {
+        let badges = Self::badges(env.clone()).expect("no badges contract");
+        let tier = Self::tier_of(env.clone(), staker.clone());
+        assert!(tier > 0, "no tier");
+
+        let key = DataKey::BadgeTier(staker.clone());
+        let badge_tier: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        if tier <= badge_tier {
+            return badge_tier;
+        }
+        env.storage().persistent().set(&key, &tier);
+        BadgesClient::new(&env, &badges).mint(
+            &env.current_contract_address(),
+            &staker,
+            &TIER_BADGE,
+            &tier,
+        );
+        tier
+    }
+
+    // ── Lottery tickets ─────────────────────────────────────────────────
+
+    /// Assign the tickets of `staker` at snapshot `snapshot_id` the next
+    /// free range, in registration order. Anyone may call. Returns the range.
+    pub fn register_tickets(env: Env, staker: Address, snapshot_id: u32) -> TicketRange {
+        let key = DataKey::TicketRange(snapshot_id, staker.clone());
+        assert!(!env.storage().persistent().has(&key), "already registered");
+        let tickets = Self::tickets_of(env.clone(), staker.clone(), snapshot_id);
+        assert!(tickets > 0, "no tickets");
+
+        let start = Self::total_tickets(env.clone(), snapshot_id);
+        let range = TicketRange {
+            start,
+            end: start + tickets,
+        };
+        env.storage().persistent().set(&key, &range);
+        env.storage()
+            .persistent()
+            .set(&DataKey::TicketTotal(snapshot_id), &range.end);
+
+        let holders_key = DataKey::TicketHolders(snapshot_id);
+        let mut holders: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&holders_key)
+            .unwrap_or(Vec::new(&env));
+        holders.push_back(staker.clone());
+        env.storage().persistent().set(&holders_key, &holders);
+
+        env.events().publish(
+            (symbol_short!("tickets"), staker, snapshot_id),
+            (range.start, range.end),
+        );
+        range
+    }
+
+    // ── Vesting stakes ──────────────────────────────────────────────────
+
+    /// Stake `amount` of the still-vesting allocation of `staker`. The
+    /// vesting contract locks it; no tokens move.
+    pub fn stake_vesting(env: Env, staker: Address, amount: i128) {
+        staker.require_auth();
+        Self::_check_not_paused(&env);
+        assert!(amount > 0, "amount must be positive");
+        let config = Self::vesting_config(env.clone());
+        VestingClient::new(&env, &config.vesting).lock(&staker, &amount);
+
+        Self::_update_rewards(&env, Some(&staker));
+        Self::_set_vesting_stake(&env, &config, &staker, amount);
+        env.events()
+            .publish((symbol_short!("stakevest"), staker), amount);
+    }
+
+    /// Unstake `amount` of the vesting stake of `staker`, unlocking it in the
+    /// vesting contract.
+    pub fn unstake_vesting(env: Env, staker: Address, amount: i128) {
+        staker.require_auth();
+        Self::_check_not_paused(&env);
+        assert!(amount > 0, "amount must be positive");
+        assert!(
+            amount <= Self::vesting_staked_of(env.clone(), staker.clone()),
+            "insufficient stake"
+        );
+        let config = Self::vesting_config(env.clone());
+        VestingClient::new(&env, &config.vesting).unlock(&staker, &amount);
+
+        Self::_update_rewards(&env, Some(&staker));
+        Self::_set_vesting_stake(&env, &config, &staker, -amount);
+        env.events()
+            .publish((symbol_short!("unstkvest"), staker), amount);
+    }
+
+    /// Drop the whole vesting stake of `staker` after their schedule was
+    /// revoked. The vesting contract has already released its lock. Only
+    /// callable by the vesting contract.
+    pub fn on_vesting_revoked(env: Env, staker: Address) {
+        let config = Self::vesting_config(env.clone());
+        config.vesting.require_auth();
+        let amount = Self::vesting_staked_of(env.clone(), staker.clone());
+        if amount == 0 {
+            return;
+        }
+        Self::_update_rewards(&env, Some(&staker));
+        Self::_set_vesting_stake(&env, &config, &staker, -amount);
+        env.events()
+            .publish((symbol_short!("unstkvest"), staker), amount);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Ledger recorded by snapshot `snapshot_id`.
+    pub fn snapshot_ledger(env: Env, snapshot_id: u32) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Snapshot(snapshot_id))
+            .expect("no snapshot found")
+    }
+
+    /// Amount `staker` had staked at snapshot `snapshot_id`.
+    pub fn staked_at(env: Env, staker: Address, snapshot_id: u32) -> i128 {
+        Self::_checkpoint_at(&env, &staker, snapshot_id).amount
+    }
+
+    /// Tier of `staker` at snapshot `snapshot_id`, against the current tier
+    /// thresholds.
+    pub fn tier_at(env: Env, staker: Address, snapshot_id: u32) -> u32 {
+        let weight = Self::_checkpoint_at(&env, &staker, snapshot_id).weight;
+        Self::_tier_for(&env, weight)
+    }
+
+    pub fn ticket_weight(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TicketWeight)
+            .unwrap_or(1)
+    }
+
+    /// Lottery tickets of `staker` at snapshot `snapshot_id`: their weight
+    /// divided by the ticket weight in force when the snapshot was taken.
+    pub fn tickets_of(env: Env, staker: Address, snapshot_id: u32) -> u64 {
+        let weight = Self::_checkpoint_at(&env, &staker, snapshot_id).weight;
+        let ticket_weight: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SnapshotTicketWeight(snapshot_id))
+            .expect("no snapshot found");
+        (weight / ticket_weight) as u64
+    }
+
+    pub fn ticket_range(env: Env, staker: Address, snapshot_id: u32) -> Option<TicketRange> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TicketRange(snapshot_id, staker))
+    }
+
+    /// Tickets registered so far for snapshot `snapshot_id`.
+    pub fn total_tickets(env: Env, snapshot_id: u32) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TicketTotal(snapshot_id))
+            .unwrap_or(0)
+    }
+
+    /// Address holding ticket `ticket` of snapshot `snapshot_id`.
+    pub fn ticket_owner(env: Env, snapshot_id: u32, ticket: u64) -> Address {
+        assert!(
+            ticket < Self::total_tickets(env.clone(), snapshot_id),
+            "ticket out of range"
+        );
+        let holders: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TicketHolders(snapshot_id))
+            .expect("ticket out of range");
+
+        // Ranges are consecutive in registration order: find the last
+        // holder whose range starts at or before `ticket`.
+        let (mut lo, mut hi) = (0u32, holders.len() - 1);
+        while lo < hi {
+            let mid = (lo + hi).div_ceil(2);
+            let start = Self::ticket_range(env.clone(), holders.get(mid).unwrap(), snapshot_id)
+                .unwrap()
+                .start;
+            if start <= ticket {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        holders.get(lo).unwrap()
+    }
+
+    /// `reward_token` rewards earned by `staker` and not yet claimed.
+    pub fn pending_rewards(env: Env, staker: Address, reward_token: Address) -> i128 {
+        let stream = Self::_stream(&env, &reward_token);
+        let reward_per_share = Self::_reward_per_share(&env, &stream);
+        Self::_earned(&env, &staker, &reward_token, reward_per_share)
+    }
+
+    pub fn is_auto_compound(env: Env, staker: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AutoCompound(staker))
+            .unwrap_or(false)
+    }
+
+    pub fn compound_incentive_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CompoundIncentiveBps)
+            .unwrap_or(0)
+    }
+
+    /// Tokens that have been funded as rewards, in funding order.
+    pub fn reward_tokens(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::RewardTokens)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// `reward_token` streamed per ledger in its current period.
+    pub fn reward_rate(env: Env, reward_token: Address) -> i128 {
+        Self::_stream(&env, &reward_token).rate
+    }
+
+    /// Ledger at which the current period of `reward_token` ends.
+    pub fn reward_end(env: Env, reward_token: Address) -> u32 {
+        Self::_stream(&env, &reward_token).end_ledger
+    }
+
+    pub fn cooldown_ledgers(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CooldownLedgers)
+            .unwrap_or(0)
+    }
+
+    /// Queued unstakes of `staker`, oldest first.
+    pub fn unbonding_of(env: Env, staker: Address) -> Vec<Unbonding> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Unbonding(staker))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn vesting_config(env: Env) -> VestingConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::VestingConfig)
+            .expect("vesting not configured")
+    }
+
+    /// Amount of vesting allocation `staker` has staked.
+    pub fn vesting_staked_of(env: Env, staker: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VestingStaked(staker))
+            .unwrap_or(0)
+    }
+
+    pub fn slash_config(env: Env) -> SlashConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::SlashConfig)
+            .expect("slashing not configured")
+    }
+
+    pub fn pending_slash(env: Env, staker: Address) -> Option<PendingSlash> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingSlash(staker))
+    }
+
+    pub fn get_position(env: Env, position_id: u64) -> Position {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Position(position_id))
+            .expect("no position found")
+    }
+
+    pub fn owner_of(env: Env, position_id: u64) -> Address {
+        Self::get_position(env, position_id).owner
+    }
+
+    /// Ids of the open positions of `staker`, oldest first.
+    pub fn position_ids(env: Env, staker: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Positions(staker))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Open positions of `staker`, oldest first.
+    pub fn positions_of(env: Env, staker: Address) -> Vec<Position> {
+        let mut positions = Vec::new(&env);
+        for id in Self::position_ids(env.clone(), staker).iter() {
+            positions.push_back(Self::get_position(env.clone(), id));
+        }
+        positions
+    }
+
+    /// Total amount `staker` has staked across positions.
+    pub fn staked_of(env: Env, staker: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Staked(staker))
+            .unwrap_or(0)
+    }
+
+    /// Tier weight of `staker`: the sum of their positions' weights.
+    pub fn weight_of(env: Env, staker: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Weight(staker))
+            .unwrap_or(0)
+    }
+
+    /// Tier of `staker`: how many thresholds their weight reaches, so 0 means
+    /// no tier.
+    pub fn tier_of(env: Env, staker: Address) -> u32 {
+        let weight = Self::weight_of(env.clone(), staker);
+        Self::_tier_for(&env, weight)
+    }
+
+    pub fn tiers(env: Env) -> Vec<i128> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Tiers)
+            .expect("not initialized")
+    }
+
+    pub fn total_staked(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalStaked)
+            .unwrap_or(0)
+    }
+
+    pub fn lock_options(env: Env) -> Vec<LockOption> {
+        env.storage()
+            .instance()
+            .get(&DataKey::LockOptions)
+            .expect("not initialized")
+    }
+
+    pub fn token(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("not initialized")
+    }
+
+    /// Whether the pause registry currently has this contract paused.
+    pub fn is_paused(env: Env) -> bool {
+        match Self::pause_registry(env.clone()) {
+            Some(registry) => {
+                PauseRegistryClient::new(&env, &registry).is_paused(&env.current_contract_address())
+            }
+            None => false,
+        }
+    }
+
+    pub fn pause_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PauseRegistry)
+    }
+
+    pub fn badges(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Badges)
+    }
+
+    pub fn points(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Points)
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _require_admin(env: &Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized");
+        admin.require_auth();
+    }
+
+    fn _check_not_paused(env: &Env) {
+        assert!(!Self::is_paused(env.clone()), "contract is paused");
+    }
+
+    /// Tier weight of `amount` staked under the terms of `position`.
+    fn _weight(position: &Position, amount: i128) -> i128 {
+        soropad_math::apply_bps(amount, position.multiplier_bps)
+    }
+
+    fn _owned_position(env: &Env, staker: &Address, position_id: u64) -> Position {
+        let position = Self::get_position(env.clone(), position_id);
+        assert!(position.owner == *staker, "not position owner");
+        position
+    }
+
+    fn _close_position(env: &Env, staker: &Address, position_id: u64) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Position(position_id));
+        let key = DataKey::Positions(staker.clone());
+        let mut ids = Self::position_ids(env.clone(), staker.clone());
+        if let Some(index) = ids.first_index_of(position_id) {
+            ids.remove(index);
+        }
+        env.storage().persistent().set(&key, &ids);
+    }
+
+    /// Store the unstake queue of `staker`, dropping empty entries.
+    fn _set_unbonding(env: &Env, staker: &Address, queue: &Vec<Unbonding>) {
+        let mut kept = Vec::new(env);
+        for entry in queue.iter() {
+            if entry.amount > 0 {
+                kept.push_back(entry);
+            }
+        }
+        let key = DataKey::Unbonding(staker.clone());
+        if kept.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &kept);
+        }
+    }
+
+    /// Change the vesting stake of `staker` by `delta`. Rewards must already
+    /// be settled for `staker`.
+    fn _set_vesting_stake(env: &Env, config: &VestingConfig, staker: &Address, delta: i128) {
+        let weight_of = |amount: i128| soropad_math::apply_bps(amount, config.multiplier_bps);
+        let staked = Self::vesting_staked_of(env.clone(), staker.clone());
+        let key = DataKey::VestingStaked(staker.clone());
+        if staked + delta == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &(staked + delta));
+        }
+        Self::_apply(
+            env,
+            staker,
+            delta,
+            weight_of(staked + delta) - weight_of(staked),
+        );
+    }
+
+    /// Apply a change to the staked amount and weight of `staker`, updating
+    /// the pool total and their checkpoint history. Rewards must already be
+    /// settled for `staker`.
+    fn _apply(env: &Env, staker: &Address, amount: i128, weight: i128) {
+        let staked = Self::staked_of(env.clone(), staker.clone()) + amount;
+        let total_weight = Self::weight_of(env.clone(), staker.clone()) + weight;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Staked(staker.clone()), &staked);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Weight(staker.clone()), &total_weight);
+        Self::_add_total(env, amount);
+        Self::_checkpoint(env, staker);
+    }
+
+    fn _tier_for(env: &Env, weight: i128) -> u32 {
+        Self::tiers(env.clone())
+            .iter()
+            .take_while(|threshold| weight >= *threshold)
+            .count() as u32
+    }
+
+    /// Append the current stake of `staker` to their checkpoint history,
+    /// replacing the last entry if it is from this ledger.
+    fn _checkpoint(env: &Env, staker: &Address) {
+        let key = DataKey::Checkpoints(staker.clone());
+        let mut checkpoints: Vec<Checkpoint> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        let checkpoint = Checkpoint {
+            ledger: env.ledger().sequence(),
+            amount: Self::staked_of(env.clone(), staker.clone()),
+            weight: Self::weight_of(env.clone(), staker.clone()),
+        };
+        match checkpoints.last() {
+            Some(last) if last.ledger == checkpoint.ledger => {
+                checkpoints.set(checkpoints.len() - 1, checkpoint)
+            }
+            _ => checkpoints.push_back(checkpoint),
+        }
+        env.storage().persistent().set(&key, &checkpoints);
+    }
+
+    /// Latest checkpoint of `staker` from before the snapshot ledger.
+    fn _checkpoint_at(env: &Env, staker: &Address, snapshot_id: u32) -> Checkpoint {
+        let ledger = Self::snapshot_ledger(env.clone(), snapshot_id);
+        let checkpoints: Vec<Checkpoint> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Checkpoints(staker.clone()))
+            .unwrap_or(Vec::new(env));
+
+        // Binary search for the number of checkpoints before `ledger`.
+        let (mut low, mut high) = (0, checkpoints.len());
+        while low < high {
+            let mid = (low + high) / 2;
+            if checkpoints.get_unchecked(mid).ledger < ledger {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        if low == 0 {
+            Checkpoint {
+                ledger: 0,
+                amount: 0,
+                weight: 0,
+            }
+        } else {
+            checkpoints.get_unchecked(low - 1)
+        }
+    }
+
+    fn _add_total(env: &Env, delta: i128) {
+        let total = Self::total_staked(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalStaked, &(total + delta));
+    }
+
+    fn _stream(env: &Env, reward_token: &Address) -> RewardStream {
+        env.storage()
+            .instance()
+            .get(&DataKey::RewardStream(reward_token.clone()))
+            .unwrap_or(RewardStream {
+                rate: 0,
+                end_ledger: 0,
+                last_update: 0,
+                reward_per_share: 0,
+            })
+    }
+
+    /// Accumulator value of `stream` as of now, without writing it back.
+    fn _reward_per_share(env: &Env, stream: &RewardStream) -> i128 {
+        let total = Self::total_staked(env.clone());
+        let applicable = env.ledger().sequence().min(stream.end_ledger);
+        if total == 0 || applicable <= stream.last_update {
+            return stream.reward_per_share;
+        }
+        let emitted = (applicable - stream.last_update) as i128 * stream.rate;
+        stream.reward_per_share + soropad_math::mul_div(emitted, REWARD_SCALE, total)
+    }
+
+    fn _earned(
+        env: &Env,
+        staker: &Address,
+        reward_token: &Address,
+        reward_per_share: i128,
+    ) -> i128 {
+        let paid: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RewardPerSharePaid(
+                staker.clone(),
+                reward_token.clone(),
+            ))
+            .unwrap_or(0);
+        let accrued: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Rewards(staker.clone(), reward_token.clone()))
+            .unwrap_or(0);
+        let amount = Self::staked_of(env.clone(), staker.clone());
+        accrued + soropad_math::mul_div(amount, reward_per_share - paid, REWARD_SCALE)
+    }
+
+    /// Bring every stream's accumulator up to date and, for `staker`, settle
+    /// what they earned since their last update. Must run before any stake
+    /// changes.
+    fn _update_rewards(env: &Env, staker: Option<&Address>) {
+        for reward_token in Self::reward_tokens(env.clone()).iter() {
+            let mut stream = Self::_stream(env, &reward_token);
+            stream.reward_per_share = Self::_reward_per_share(env, &stream);
+            stream.last_update = env
+                .ledger()
+                .sequence()
+                .min(stream.end_ledger)
+                .max(stream.last_update);
+            env.storage()
+                .instance()
+                .set(&DataKey::RewardStream(reward_token.clone()), &stream);
+
+            if let Some(staker) = staker {
+                let earned = Self::_earned(env, staker, &reward_token, stream.reward_per_share);
+                env.storage().persistent().set(
+                    &DataKey::Rewards(staker.clone(), reward_token.clone()),
+                    &earned,
+                );
+                env.storage().persistent().set(
+                    &DataKey::RewardPerSharePaid(staker.clone(), reward_token.clone()),
+                    &stream.reward_per_share,
+                );
+            }
+        }
+    }
+
+    fn _validate_lock_options(lock_options: &Vec<LockOption>) {
+        assert!(!lock_options.is_empty(), "no lock options");
+        for option in lock_options.iter() {
+            assert!(option.lock_ledgers > 0, "lock_ledgers must be positive");
+            assert!(option.multiplier_bps > 0, "multiplier must be positive");
+        }
+    }
+
+    fn _validate_tiers(tiers: &Vec<i128>) {
+        let mut previous = 0;
+        for threshold in tiers.iter() {
+            assert!(threshold > previous, "tiers must be ascending");
+            previous = threshold;
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{
+        testutils::Address as _, testutils::Ledger, testutils::MockAuth, testutils::MockAuthInvoke,
+        vec, IntoVal,
+    };
+
+    struct Setup {
+        env: Env,
+        client: StakingContractClient<'static>,
+        admin: Address,
+        token: TokenFixture,
+    }
+
+    /// Lock options 0–3: 10, 100, 500 and 1 000 ledgers at 0.1×, 0.25×, 0.5×
+    /// and 1× weight; tiers at weights 100, 500 and 2 000.
+    fn setup() -> Setup {
+        let env = test_env();
+
+        let admin = Address::generate(&env);
+        let token = TokenFixture::stellar_asset(&env);
+        let contract_id = env.register_contract(None, StakingContract);
+        let client = StakingContractClient::new(&env, &contract_id);
+        let lock_options = vec![
+            &env,
+            LockOption {
+                lock_ledgers: 10,
+                multiplier_bps: 1_000,
+            },
+            LockOption {
+                lock_ledgers: 100,
+                multiplier_bps: 2_500,
+            },
+            LockOption {
+                lock_ledgers: 500,
+                multiplier_bps: 5_000,
+            },
+            LockOption {
+                lock_ledgers: 1_000,
+                multiplier_bps: 10_000,
+            },
+        ];
+        client.initialize(
+            &admin,
+            &token.address,
+            &lock_options,
+            &vec![&env, 100, 500, 2_000],
+        );
+        env.ledger().set_sequence_number(10);
+
+        Setup {
+            token,
+            env,
+            client,
+            admin,
+        }
+    }
+
+    fn funded_staker(s: &Setup, amount: i128) -> Address {
+        let staker = Address::generate(&s.env);
+        s.token.mint(&staker, amount);
+        staker
+    }
+
+    #[contract]
+    struct MockPauseRegistry;
+
+    #[contractimpl]
+    impl MockPauseRegistry {
+        pub fn set_paused(env: Env, contract: Address, paused: bool) {
+            env.storage().instance().set(&contract, &paused);
+        }
+
+        pub fn is_paused(env: Env, contract: Address) -> bool {
+            env.storage().instance().get(&contract).unwrap_or(false)
+        }
+    }
+
+    #[test]
+    fn test_pause_registry_blocks_staker_actions() {
+        let s = setup();
+        let staker = funded_staker(&s, 2_000);
+        let id = s.client.stake(&staker, &1_000, &0);
+        s.env.ledger().set_sequence_number(100);
+
+        let registry_id = s.env.register_contract(None, MockPauseRegistry);
+        let registry = MockPauseRegistryClient::new(&s.env, &registry_id);
+        s.client.set_pause_registry(&registry_id);
+        registry.set_paused(&s.client.address, &true);
+        assert!(s.client.is_paused());
+        assert!(s.client.try_stake(&staker, &1_000, &0).is_err());
+        assert!(s.client.try_request_unstake(&staker, &id, &1_000).is_err());
+
+        registry.set_paused(&s.client.address, &false);
+        s.client.request_unstake(&staker, &id, &1_000);
+        assert_eq!(s.client.staked_of(&staker), 0);
+    }
+
+    #[contract]
+    struct MockBadges;
+
+    #[contractimpl]
+    impl MockBadges {
+        pub fn mint(env: Env, _issuer: Address, owner: Address, kind: Symbol, level: u32) -> u64 {
+            env.storage().instance().set(&(owner, kind), &level);
+            0
+        }
+
+        pub fn level(env: Env, owner: Address, kind: Symbol) -> Option<u32> {
+            env.storage().instance().get(&(owner, kind))
+        }
+    }
+
+    #[test]
+    fn test_tier_badge_only_goes_up() {
+        let s = setup();
+        let badges_id = s.env.register_contract(None, MockBadges);
+        let badges = MockBadgesClient::new(&s.env, &badges_id);
+        s.client.set_badges(&badges_id);
+        let staker = funded_staker(&s, 5_000);
+        assert!(s.client.try_claim_tier_badge(&staker).is_err());
+
+        let id = s.client.stake(&staker, &4_000, &2);
+        assert_eq!(s.client.claim_tier_badge(&staker), 3);
+        assert_eq!(badges.level(&staker, &TIER_BADGE), Some(3));
+
+        s.env.ledger().set_sequence_number(600);
+        s.client.request_unstake(&staker, &id, &3_000);
+        assert_eq!(s.client.tier_of(&staker), 2);
+        assert_eq!(s.client.claim_tier_badge(&staker), 3);
+        assert_eq!(badges.level(&staker, &TIER_BADGE), Some(3));
+    }
+
+    #[contract]
+    struct MockPoints;
+
+    #[contractimpl]
+    impl MockPoints {
+        pub fn record(
+            env: Env,
+            _awarder: Address,
+            account: Address,
+            activity: Symbol,
+            units: i128,
+        ) -> i128 {
+            let key = (account, activity);
+            let total: i128 = env.storage().instance().get(&key).unwrap_or(0);
+            env.storage().instance().set(&key, &(total + units));
+            units
+        }
+
+        pub fn units(env: Env, account: Address, activity: Symbol) -> i128 {
+            env.storage()
+                .instance()
+                .get(&(account, activity))
+                .unwrap_or(0)
+        }
+    }
+
+    #[test]
+    fn test_stake_records_lock_points() {
+        let s = setup();
+        let points_id = s.env.register_contract(None, MockPoints);
+        let points = MockPointsClient::new(&s.env, &points_id);
+        s.client.set_points(&points_id);
+        let staker = funded_staker(&s, 5_000);
+
+        s.client.stake(&staker, &1_000, &0);
+        s.client.stake(&staker, &1_000, &2);
+        assert_eq!(points.units(&staker, &LOCK_ACTIVITY), 510_000);
+    }
+
+    #[test]
+    fn test_stake_and_tiers() {
+        let s = setup();
+        let staker = funded_staker(&s, 5_000);
+
+        s.client.stake(&staker, &1_000, &2);
+        assert_eq!(s.client.weight_of(&staker), 500);
+        assert_eq!(s.client.tier_of(&staker), 2);
+        assert_eq!(s.client.total_staked(), 1_000);
+        assert_eq!(s.token.balance(&staker), 4_000);
+
+        let id = s.client.stake(&staker, &1_000, &3);
+        assert_eq!(s.client.weight_of(&staker), 1_500);
+        assert_eq!(s.client.tier_of(&staker), 2);
+        assert_eq!(s.client.staked_of(&staker), 2_000);
+        assert_eq!(s.client.get_position(&id).unlock_ledger, 1_010);
+        assert_eq!(s.client.position_ids(&staker), vec![&s.env, 0, 1]);
+
+        let nobody = Address::generate(&s.env);
+        assert_eq!(s.client.tier_of(&nobody), 0);
+    }
+
+    #[test]
+    fn test_unstake_after_unlock() {
+        let s = setup();
+        let staker = funded_staker(&s, 1_000);
+        let id = s.client.stake(&staker, &1_000, &1);
+
+        s.env.ledger().set_sequence_number(110);
+        s.client.request_unstake(&staker, &id, &400);
+        assert_eq!(s.client.get_position(&id).amount, 600);
+        assert_eq!(s.client.weight_of(&staker), 150);
+        s.client.request_unstake(&staker, &id, &600);
+        assert_eq!(s.client.staked_of(&staker), 0);
+        assert_eq!(s.client.weight_of(&staker), 0);
+        assert!(s.client.positions_of(&staker).is_empty());
+        assert_eq!(s.client.withdraw(&staker), 1_000);
+        assert_eq!(s.token.balance(&staker), 1_000);
+        assert_eq!(s.client.total_staked(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "stake still locked")]
+    fn test_unstake_while_locked_panics() {
+        let s = setup();
+        let staker = funded_staker(&s, 1_000);
+        let id = s.client.stake(&staker, &1_000, &1);
+        s.env.ledger().set_sequence_number(109);
+        s.client.request_unstake(&staker, &id, &1_000);
+    }
+
+    #[test]
+    fn test_withdraw_after_cooldown() {
+        let s = setup();
+        s.client.set_cooldown(&50);
+        let staker = funded_staker(&s, 1_000);
+        let id = s.client.stake(&staker, &1_000, &0);
+
+        s.env.ledger().set_sequence_number(20);
+        s.client.request_unstake(&staker, &id, &600);
+        s.env.ledger().set_sequence_number(40);
+        s.client.request_unstake(&staker, &id, &400);
+        // Cooling-down stake has no weight
+        assert_eq!(s.client.weight_of(&staker), 0);
+        assert_eq!(s.client.unbonding_of(&staker).len(), 2);
+
+        s.env.ledger().set_sequence_number(69);
+        assert!(s.client.try_withdraw(&staker).is_err());
+        s.env.ledger().set_sequence_number(70);
+        assert_eq!(s.client.withdraw(&staker), 600);
+        s.env.ledger().set_sequence_number(90);
+        assert_eq!(s.client.withdraw(&staker), 400);
+        assert!(s.client.unbonding_of(&staker).is_empty());
+        assert_eq!(s.token.balance(&staker), 1_000);
+    }
+
+    #[test]
+    fn test_positions_unlock_independently() {
+        let s = setup();
+        let staker = funded_staker(&s, 2_000);
+        let short = s.client.stake(&staker, &1_000, &0);
+        let long = s.client.stake(&staker, &1_000, &2);
+
+        s.env.ledger().set_sequence_number(20);
+        s.client.request_unstake(&staker, &short, &1_000);
+        assert!(s
+            .client
+            .try_request_unstake(&staker, &long, &1_000)
+            .is_err());
+
+        let positions = s.client.positions_of(&staker);
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions.get(0).unwrap().id, long);
+        assert_eq!(s.client.weight_of(&staker), 500);
+    }
+
+    #[test]
+    fn test_lock_option_change_keeps_open_positions() {
+        let s = setup();
+        let staker = funded_staker(&s, 1_000);
+        let id = s.client.stake(&staker, &1_000, &2);
+
+        s.client.set_lock_options(&vec![
+            &s.env,
+            LockOption {
+                lock_ledgers: 50,
+                multiplier_bps: 20_000,
+            },
+        ]);
+        assert_eq!(s.client.lock_options().len(), 1);
+        assert_eq!(s.client.get_position(&id).multiplier_bps, 5_000);
+        assert_eq!(s.client.weight_of(&staker), 500);
+    }
+
+    #[test]
+    fn test_transfer_position() {
+        let s = setup();
+        let client_addr = funded_staker(&s, 1_000);
+        let custodian = Address::generate(&s.env);
+        let id = s.client.stake(&client_addr, &1_000, &2);
+        fund(&s, 1_000, 100);
+
+        s.env.ledger().set_sequence_number(60);
+        s.client.transfer_position(&client_addr, &custodian, &id);
+        assert_eq!(s.client.owner_of(&id), custodian);
+        assert_eq!(s.client.position_ids(&custodian), vec![&s.env, id]);
+        assert!(s.client.position_ids(&client_addr).is_empty());
+        assert_eq!(s.client.weight_of(&client_addr), 0);
+        assert_eq!(s.client.weight_of(&custodian), 500);
+
+        s.env.ledger().set_sequence_number(110);
+        assert_eq!(
+            s.client.pending_rewards(&client_addr, &s.token.address),
+            500
+        );
+        assert_eq!(s.client.pending_rewards(&custodian, &s.token.address), 500);
+
+        s.env.ledger().set_sequence_number(510);
+        s.client.request_unstake(&custodian, &id, &1_000);
+        assert_eq!(s.client.withdraw(&custodian), 1_000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_transfer_position_requires_admin() {
+        let s = setup();
+        let owner = funded_staker(&s, 1_000);
+        let to = Address::generate(&s.env);
+        let id = s.client.stake(&owner, &1_000, &2);
+        s.env.mock_auths(&[MockAuth {
+            address: &owner,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "transfer_position",
+                args: (owner.clone(), to.clone(), id).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.transfer_position(&owner, &to, &id);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid lock option")]
+    fn test_unknown_lock_option_panics() {
+        let s = setup();
+        let staker = funded_staker(&s, 1_000);
+        s.client.stake(&staker, &500, &4);
+    }
+
+    #[test]
+    #[should_panic(expected = "not position owner")]
+    fn test_unstake_others_position_panics() {
+        let s = setup();
+        let staker = funded_staker(&s, 1_000);
+        let id = s.client.stake(&staker, &1_000, &0);
+        s.env.ledger().set_sequence_number(20);
+        s.client
+            .request_unstake(&Address::generate(&s.env), &id, &1_000);
+    }
+
+    #[test]
+    fn test_set_tiers() {
+        let s = setup();
+        assert_eq!(s.client.admin(), s.admin);
+        let staker = funded_staker(&s, 1_000);
+        s.client.stake(&staker, &1_000, &3);
+        assert_eq!(s.client.tier_of(&staker), 2);
+
+        s.client.set_tiers(&vec![&s.env, 1_000]);
+        assert_eq!(s.client.tier_of(&staker), 1);
+        assert!(s.client.try_set_tiers(&vec![&s.env, 500, 500]).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_tiers_requires_admin() {
+        let s = setup();
+        let outsider = Address::generate(&s.env);
+        let tiers = vec![&s.env, 1i128];
+        s.env.mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "set_tiers",
+                args: (tiers.clone(),).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.set_tiers(&tiers);
+    }
+
+    // ── Rewards ─────────────────────────────────────────────────────────
+
+    fn fund(s: &Setup, amount: i128, duration: u32) {
+        s.token.mint(&s.admin, amount);
+        s.client.fund_rewards(&s.token.address, &amount, &duration);
+    }
+
+    #[test]
+    fn test_rewards_split_pro_rata() {
+        let s = setup();
+        let a = funded_staker(&s, 1_000);
+        let b = funded_staker(&s, 3_000);
+        s.client.stake(&a, &1_000, &1);
+        s.client.stake(&b, &3_000, &1);
+
+        // 10 per ledger for 100 ledgers
+        fund(&s, 1_000, 100);
+        s.env.ledger().set_sequence_number(60);
+        assert_eq!(s.client.pending_rewards(&a, &s.token.address), 125);
+        assert_eq!(s.client.pending_rewards(&b, &s.token.address), 375);
+
+        // Past the end of the period rewards stop accruing
+        s.env.ledger().set_sequence_number(500);
+        assert_eq!(s.client.claim_rewards(&a, &s.token.address), 250);
+        assert_eq!(s.client.claim_rewards(&b, &s.token.address), 750);
+        assert_eq!(s.client.pending_rewards(&a, &s.token.address), 0);
+        assert_eq!(s.token.balance(&a), 250);
+    }
+
+    #[test]
+    fn test_late_staker_earns_only_after_joining() {
+        let s = setup();
+        let a = funded_staker(&s, 1_000);
+        let b = funded_staker(&s, 1_000);
+        s.client.stake(&a, &1_000, &1);
+        fund(&s, 1_000, 100);
+
+        s.env.ledger().set_sequence_number(60);
+        s.client.stake(&b, &1_000, &1);
+        s.env.ledger().set_sequence_number(110);
+
+        // a: 50 ledgers alone + 50 shared = 500 + 250; b: 250
+        assert_eq!(s.client.pending_rewards(&a, &s.token.address), 750);
+        assert_eq!(s.client.pending_rewards(&b, &s.token.address), 250);
+    }
+
+    #[test]
+    fn test_rewards_survive_unstake() {
+        let s = setup();
+        let a = funded_staker(&s, 1_000);
+        let id = s.client.stake(&a, &1_000, &0);
+        fund(&s, 1_000, 100);
+
+        s.env.ledger().set_sequence_number(30);
+        s.client.request_unstake(&a, &id, &1_000);
+        s.env.ledger().set_sequence_number(80);
+        assert_eq!(s.client.pending_rewards(&a, &s.token.address), 200);
+        assert_eq!(s.client.claim_rewards(&a, &s.token.address), 200);
+    }
+
+    #[test]
+    fn test_refunding_rolls_over_leftover() {
+        let s = setup();
+        let a = funded_staker(&s, 1_000);
+        s.client.stake(&a, &1_000, &0);
+        fund(&s, 1_000, 100);
+
+        s.env.ledger().set_sequence_number(60);
+        // 500 left over + 1 000 new over 100 ledgers
+        fund(&s, 1_000, 100);
+        assert_eq!(s.client.reward_rate(&s.token.address), 15);
+        assert_eq!(s.client.reward_end(&s.token.address), 160);
+    }
+
+    #[test]
+    fn test_partner_token_stream() {
+        let s = setup();
+        let partner = TokenFixture::stellar_asset(&s.env);
+        let a = funded_staker(&s, 1_000);
+        let b = funded_staker(&s, 1_000);
+        s.client.stake(&a, &1_000, &0);
+        s.client.stake(&b, &1_000, &0);
+
+        fund(&s, 1_000, 100);
+        partner.mint(&s.admin, 4_000);
+        s.env.ledger().set_sequence_number(60);
+        s.client.fund_rewards(&partner.address, &4_000, &200);
+        assert_eq!(
+            s.client.reward_tokens(),
+            vec![&s.env, s.token.address.clone(), partner.address.clone()]
+        );
+
+        // Partner stream: 20 per ledger from ledger 60
+        s.env.ledger().set_sequence_number(160);
+        assert_eq!(s.client.pending_rewards(&a, &s.token.address), 500);
+        assert_eq!(s.client.pending_rewards(&a, &partner.address), 1_000);
+        assert_eq!(s.client.claim_rewards(&b, &partner.address), 1_000);
+        assert_eq!(partner.balance(&b), 1_000);
+        assert_eq!(s.client.pending_rewards(&b, &s.token.address), 500);
+        assert_eq!(s.client.reward_end(&partner.address), 260);
+    }
+
+    // ── Snapshots ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_snapshot_pins_stake() {
+        let s = setup();
+        let staker = funded_staker(&s, 5_000);
+        s.client.stake(&staker, &1_000, &2);
+
+        s.env.ledger().set_sequence_number(20);
+        let before = s.client.snapshot();
+        // Staking in the snapshot ledger itself does not count
+        s.client.stake(&staker, &4_000, &3);
+        assert_eq!(s.client.staked_at(&staker, &before), 1_000);
+        assert_eq!(s.client.tier_at(&staker, &before), 2);
+
+        s.env.ledger().set_sequence_number(30);
+        let after = s.client.snapshot();
+        assert_eq!(s.client.staked_at(&staker, &after), 5_000);
+        assert_eq!(s.client.tier_at(&staker, &after), 3);
+        assert_eq!(s.client.snapshot_ledger(&after), 30);
+    }
+
+    #[test]
+    fn test_snapshot_before_staking_is_empty() {
+        let s = setup();
+        let id = s.client.snapshot();
+        let staker = funded_staker(&s, 1_000);
+        s.env.ledger().set_sequence_number(11);
+        s.client.stake(&staker, &1_000, &3);
+        assert_eq!(s.client.staked_at(&staker, &id), 0);
+        assert_eq!(s.client.tier_at(&staker, &id), 0);
+    }
+
+    #[test]
+    fn test_unstake_recorded_in_checkpoints() {
+        let s = setup();
+        let staker = funded_staker(&s, 1_000);
+        let id = s.client.stake(&staker, &1_000, &0);
+        s.env.ledger().set_sequence_number(30);
+        s.client.request_unstake(&staker, &id, &600);
+
+        s.env.ledger().set_sequence_number(31);
+        let id = s.client.snapshot();
+        assert_eq!(s.client.staked_at(&staker, &id), 400);
+    }
+
+    #[test]
+    #[should_panic(expected = "no snapshot found")]
+    fn test_unknown_snapshot_panics() {
+        let s = setup();
+        s.client.staked_at(&Address::generate(&s.env), &3);
+    }
+
+    // ── Lottery tickets ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_ticket_ranges_follow_registration() {
+        let s = setup();
+        s.client.set_ticket_weight(&100);
+        let a = funded_staker(&s, 1_000);
+        let b = funded_staker(&s, 1_000);
+        let c = funded_staker(&s, 1_000);
+        s.client.stake(&a, &1_000, &3);
+        s.client.stake(&b, &1_000, &2);
+        s.client.stake(&c, &550, &3);
+
+        s.env.ledger().set_sequence_number(20);
+        let id = s.client.snapshot();
+        assert_eq!(s.client.tickets_of(&a, &id), 10);
+        assert_eq!(s.client.tickets_of(&c, &id), 5);
+
+        s.client.register_tickets(&b, &id);
+        s.client.register_tickets(&a, &id);
+        s.client.register_tickets(&c, &id);
+        assert_eq!(
+            s.client.ticket_range(&a, &id),
+            Some(TicketRange { start: 5, end: 15 })
+        );
+        assert_eq!(s.client.total_tickets(&id), 20);
+        assert_eq!(s.client.ticket_owner(&id, &0), b);
+        assert_eq!(s.client.ticket_owner(&id, &4), b);
+        assert_eq!(s.client.ticket_owner(&id, &5), a);
+        assert_eq!(s.client.ticket_owner(&id, &14), a);
+        assert_eq!(s.client.ticket_owner(&id, &19), c);
+        assert!(s.client.try_ticket_owner(&id, &20).is_err());
+        assert!(s.client.try_register_tickets(&a, &id).is_err());
+    }
+
+    #[test]
+    fn test_ticket_weight_pinned_per_snapshot() {
+        let s = setup();
+        let staker = funded_staker(&s, 1_000);
+        s.client.stake(&staker, &1_000, &3);
+        s.env.ledger().set_sequence_number(20);
+        let id = s.client.snapshot();
+
+        s.client.set_ticket_weight(&100);
+        assert_eq!(s.client.tickets_of(&staker, &id), 1_000);
+        assert_eq!(s.client.tickets_of(&staker, &s.client.snapshot()), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "no tickets")]
+    fn test_register_without_stake_panics() {
+        let s = setup();
+        let id = s.client.snapshot();
+        s.client.register_tickets(&Address::generate(&s.env), &id);
+    }
+
+    // ── Compounding ─────────────────────────────────────────────────────
+
+    #[test]
+    fn test_compound_own_rewards() {
+        let s = setup();
+        let staker = funded_staker(&s, 1_000);
+        let id = s.client.stake(&staker, &1_000, &2);
+        fund(&s, 1_000, 100);
+
+        s.env.ledger().set_sequence_number(60);
+        assert_eq!(s.client.compound(&staker, &staker, &id), 500);
+        assert_eq!(s.client.get_position(&id).amount, 1_500);
+        assert_eq!(s.client.pending_rewards(&staker, &s.token.address), 0);
+        assert_eq!(s.client.weight_of(&staker), 750);
+    }
+
+    #[test]
+    fn test_keeper_compounds_for_opted_in_staker() {
+        let s = setup();
+        s.client.set_compound_incentive(&100);
+        let staker = funded_staker(&s, 1_000);
+        let keeper = Address::generate(&s.env);
+        let id = s.client.stake(&staker, &1_000, &2);
+        s.client.set_auto_compound(&staker, &true);
+        fund(&s, 1_000, 100);
+
+        s.env.ledger().set_sequence_number(110);
+        // 1 % of 1 000 to the keeper, the rest restaked
+        assert_eq!(s.client.compound(&keeper, &staker, &id), 990);
+        assert_eq!(s.token.balance(&keeper), 10);
+        assert_eq!(s.client.get_position(&id).amount, 1_990);
+        assert_eq!(s.client.total_staked(), 1_990);
+    }
+
+    #[test]
+    #[should_panic(expected = "auto-compound not enabled")]
+    fn test_compound_for_others_requires_opt_in() {
+        let s = setup();
+        let staker = funded_staker(&s, 1_000);
+        let id = s.client.stake(&staker, &1_000, &2);
+        s.client.compound(&Address::generate(&s.env), &staker, &id);
+    }
+
+    // ── Slashing ────────────────────────────────────────────────────────
+
+    fn slash_setup() -> (Setup, Address, Address) {
+        let s = setup();
+        let fund = Address::generate(&s.env);
+        s.client.set_slash_config(&fund, &100);
+        let staker = funded_staker(&s, 2_000);
+        s.client.stake(&staker, &1_000, &0);
+        s.client.stake(&staker, &1_000, &3);
+        (s, staker, fund)
+    }
+
+    #[test]
+    fn test_slash_after_appeal_window() {
+        let (s, staker, fund) = slash_setup();
+        s.client
+            .slash(&staker, &2_500, &String::from_str(&s.env, "bot ring"));
+        assert_eq!(s.client.pending_slash(&staker).unwrap().execute_ledger, 110);
+        assert!(s.client.try_execute_slash(&staker).is_err());
+
+        s.env.ledger().set_sequence_number(110);
+        assert_eq!(s.client.execute_slash(&staker), 500);
+        assert_eq!(s.token.balance(&fund), 500);
+        assert_eq!(s.client.staked_of(&staker), 1_500);
+        assert_eq!(s.client.total_staked(), 1_500);
+        // 750 × 0.1 + 750 × 1
+        assert_eq!(s.client.weight_of(&staker), 825);
+        assert!(s.client.pending_slash(&staker).is_none());
+    }
+
+    #[test]
+    fn test_slash_reaches_unstake_queue() {
+        let (s, staker, fund) = slash_setup();
+        s.client.set_cooldown(&500);
+        s.env.ledger().set_sequence_number(20);
+        s.client.request_unstake(&staker, &0, &1_000);
+        s.client
+            .slash(&staker, &5_000, &String::from_str(&s.env, "bot ring"));
+
+        s.env.ledger().set_sequence_number(120);
+        assert_eq!(s.client.execute_slash(&staker), 1_000);
+        assert_eq!(s.token.balance(&fund), 1_000);
+        assert_eq!(s.client.unbonding_of(&staker).get(0).unwrap().amount, 500);
+    }
+
+    #[test]
+    fn test_cancelled_slash_cannot_execute() {
+        let (s, staker, _) = slash_setup();
+        s.client
+            .slash(&staker, &10_000, &String::from_str(&s.env, "bot ring"));
+        s.client.cancel_slash(&staker);
+
+        s.env.ledger().set_sequence_number(110);
+        assert!(s.client.try_execute_slash(&staker).is_err());
+        assert_eq!(s.client.staked_of(&staker), 2_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "slash pending")]
+    fn test_unstake_blocked_while_slash_pending() {
+        let (s, staker, _) = slash_setup();
+        s.client
+            .slash(&staker, &1_000, &String::from_str(&s.env, "bot ring"));
+        s.env.ledger().set_sequence_number(20);
+        s.client.request_unstake(&staker, &0, &1_000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slash_requires_admin() {
+        let (s, staker, _) = slash_setup();
+        let outsider = Address::generate(&s.env);
+        let reason = String::from_str(&s.env, "bot ring");
+        s.env.mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "slash",
+                args: (staker.clone(), 1_000u32, reason.clone()).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.slash(&staker, &1_000, &reason);
+    }
+
+    // ── Vesting stakes ──────────────────────────────────────────────────
+
+    /// Locks whatever it is asked to, up to 1 000 per recipient.
+    #[contract]
+    struct MockVesting;
+
+    #[contractimpl]
+    impl MockVesting {
+        pub fn lock(env: Env, recipient: Address, amount: i128) {
+            let locked: i128 = env.storage().persistent().get(&recipient).unwrap_or(0);
+            assert!(locked + amount <= 1_000, "insufficient unlocked balance");
+            env.storage()
+                .persistent()
+                .set(&recipient, &(locked + amount));
+        }
+
+        pub fn unlock(env: Env, recipient: Address, amount: i128) {
+            let locked: i128 = env.storage().persistent().get(&recipient).unwrap_or(0);
+            env.storage()
+                .persistent()
+                .set(&recipient, &(locked - amount));
+        }
+
+        pub fn locked(env: Env, recipient: Address) -> i128 {
+            env.storage().persistent().get(&recipient).unwrap_or(0)
+        }
+
+        pub fn revoke(env: Env, staking: Address, recipient: Address) {
+            env.storage().persistent().remove(&recipient);
+            StakingContractClient::new(&env, &staking).on_vesting_revoked(&recipient);
+        }
+    }
+
+    fn vesting_setup() -> (Setup, MockVestingClient<'static>, Address) {
+        let s = setup();
+        let vesting = MockVestingClient::new(&s.env, &s.env.register_contract(None, MockVesting));
+        s.client.set_vesting(&vesting.address, &5_000);
+        let staker = Address::generate(&s.env);
+        (s, vesting, staker)
+    }
+
+    #[test]
+    fn test_stake_vesting_allocation() {
+        let (s, vesting, staker) = vesting_setup();
+        s.client.stake_vesting(&staker, &1_000);
+        assert_eq!(vesting.locked(&staker), 1_000);
+        assert_eq!(s.client.vesting_staked_of(&staker), 1_000);
+        assert_eq!(s.client.weight_of(&staker), 500);
+        assert_eq!(s.client.tier_of(&staker), 2);
+        assert_eq!(s.client.total_staked(), 1_000);
+        assert_eq!(s.token.balance(&s.client.address), 0);
+
+        s.client.unstake_vesting(&staker, &400);
+        assert_eq!(vesting.locked(&staker), 600);
+        assert_eq!(s.client.weight_of(&staker), 300);
+        assert!(s.client.try_stake_vesting(&staker, &500).is_err());
+    }
+
+    #[test]
+    fn test_revoke_unwinds_vesting_stake() {
+        let (s, vesting, staker) = vesting_setup();
+        let liquid = funded_staker(&s, 0);
+        s.client.stake_vesting(&staker, &800);
+        s.client.stake_vesting(&liquid, &200);
+        fund(&s, 1_000, 100);
+
+        s.env.ledger().set_sequence_number(60);
+        vesting.revoke(&s.client.address, &staker);
+        assert_eq!(s.client.vesting_staked_of(&staker), 0);
+        assert_eq!(s.client.weight_of(&staker), 0);
+        assert_eq!(s.client.total_staked(), 200);
+        // Rewards earned before the revoke are kept
+        assert_eq!(s.client.pending_rewards(&staker, &s.token.address), 400);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vesting_revoke_hook_requires_vesting_contract() {
+        let (s, _, staker) = vesting_setup();
+        s.client.stake_vesting(&staker, &1_000);
+        let outsider = Address::generate(&s.env);
+        s.env.mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "on_vesting_revoked",
+                args: (staker.clone(),).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.on_vesting_revoked(&staker);
+    }
+}
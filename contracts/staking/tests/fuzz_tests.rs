@@ -0,0 +1,129 @@
+//! # Fuzz / Property-Based Tests for Reward Accrual
+//!
+//! Stakes and reward streams of boundary-biased sizes are run through a real
+//! `StakingContract`, and `pending_rewards`, which folds the stream into the
+//! reward-per-share accumulator, is compared against a checked model of it.
+//!
+//! ## Invariants
+//!
+//! 1. **Exact Accrual**
+//!    A staker's pending rewards are
+//!    `stake * (emitted * REWARD_SCALE / total) / REWARD_SCALE`, both
+//!    divisions rounding down, where `emitted` is what the stream has paid
+//!    out so far.
+//!
+//! 2. **No Wraparound at the `i128` Bounds**
+//!    Whenever a step of that model overflows `i128`, `pending_rewards`
+//!    reverts instead of returning a wrapped amount.
+//!
+//! 3. **Never More Than Streamed**
+//!    Pending rewards of all stakers together never exceed `emitted`.
+
+use launchpad_testutils::{test_env, TokenFixture};
+use proptest::prelude::*;
+use soroban_sdk::{testutils::Address as _, testutils::Ledger, vec, Address, Env};
+use soroban_staking::{LockOption, StakingContract, StakingContractClient, REWARD_SCALE};
+use soropad_math::checked_mul_div;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Ledger the stakes and the stream start at.
+const START: u32 = 10;
+
+/// A staking contract with a single 1× lock option, its staked token and
+/// its reward token, both Stellar asset contracts.
+fn setup() -> (
+    Env,
+    StakingContractClient<'static>,
+    TokenFixture,
+    TokenFixture,
+) {
+    let env = test_env();
+    env.ledger().set_sequence_number(START);
+
+    let admin = Address::generate(&env);
+    let token = TokenFixture::stellar_asset(&env);
+    let reward_token = TokenFixture::stellar_asset(&env);
+    let client = StakingContractClient::new(&env, &env.register_contract(None, StakingContract));
+    let lock_options = vec![
+        &env,
+        LockOption {
+            lock_ledgers: 1,
+            multiplier_bps: 10_000,
+        },
+    ];
+    client.initialize(&admin, &token.address, &lock_options, &vec![&env]);
+    reward_token.mint(&admin, i128::MAX);
+
+    (env, client, token, reward_token)
+}
+
+/// Positive amounts clustered at and just below `i128::MAX`, around half
+/// of it and around `i128::MAX / REWARD_SCALE`, where the accumulator
+/// products overflow, plus ordinary ones.
+fn boundary_amount() -> impl Strategy<Value = i128> {
+    prop_oneof![
+        Just(i128::MAX),
+        (i128::MAX - 1_000)..i128::MAX,
+        (i128::MAX / 2 - 1_000)..=(i128::MAX / 2 + 1_000),
+        (i128::MAX / REWARD_SCALE - 1_000)..=(i128::MAX / REWARD_SCALE + 1_000),
+        1i128..=1_000,
+        1i128..=1_000_000_000_000_000_000,
+    ]
+}
+
+// ===========================================================================
+// Property tests
+// ===========================================================================
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    /// Invariants 1 to 3.
+    #[test]
+    fn prop_reward_per_share_never_wraps(
+        stakes in prop::collection::vec(boundary_amount(), 1..=2),
+        reward in boundary_amount(),
+        duration in 1u32..=1_000,
+        elapsed in 0u32..=2_000,
+    ) {
+        let (env, client, token, reward_token) = setup();
+
+        let mut stakers = std::vec::Vec::new();
+        let mut total = 0i128;
+        for amount in stakes {
+            let staker = token.funded(amount);
+            // The total staked must fit in `i128`
+            let fits = total.checked_add(amount).is_some();
+            prop_assert_eq!(client.try_stake(&staker, &amount, &0).is_ok(), fits);
+            if fits {
+                total += amount;
+                stakers.push((staker, amount));
+            }
+        }
+
+        let rate = reward / duration as i128;
+        prop_assume!(rate > 0);
+        client.fund_rewards(&reward_token.address, &reward, &duration);
+        env.ledger().set_sequence_number(START + elapsed);
+        let emitted = elapsed.min(duration) as i128 * rate;
+
+        let reward_per_share = checked_mul_div(emitted, REWARD_SCALE, total);
+        let mut paid = 0i128;
+        for (staker, amount) in stakers {
+            let expected =
+                reward_per_share.and_then(|rps| checked_mul_div(amount, rps, REWARD_SCALE));
+            let pending = client.try_pending_rewards(&staker, &reward_token.address);
+            match expected {
+                Some(expected) => {
+                    prop_assert_eq!(pending.ok().and_then(Result::ok), Some(expected));
+                    paid += expected;
+                }
+                None => prop_assert!(pending.is_err()),
+            }
+        }
+        prop_assert!(paid <= emitted);
+    }
+}
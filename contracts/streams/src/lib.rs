@@ -0,0 +1,403 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    StreamCount,
+    Stream(u32),
+    SenderStreams(Address),
+    RecipientStreams(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Stream {
+    pub id: u32,
+    pub sender: Address,
+    pub recipient: Address,
+    pub token: Address,
+    /// Total amount paid out linearly between `start_ledger` and `end_ledger`.
+    pub amount: i128,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+    /// Amount the recipient has already taken out.
+    pub withdrawn: i128,
+    /// Ledger at which the sender cancelled the stream, if it was.
+    pub cancelled_ledger: Option<u32>,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Streams Contract — continuous per-ledger payments.
+///
+/// A sender deposits `amount` with `create_stream`, and it flows to the
+/// recipient in equal parts every ledger from `start_ledger` to
+/// `end_ledger`. The recipient can `withdraw` whatever has streamed so far
+/// at any time, which suits salaries and grants better than vesting's
+/// cliff-and-schedule model.
+///
+/// The sender can `cancel` a running stream: the recipient is paid
+/// everything streamed up to that ledger and the sender gets the rest back.
+#[contract]
+pub struct StreamsContract;
+
+#[contractimpl]
+impl StreamsContract {
+    // ── Sender actions ──────────────────────────────────────────────────
+
+    /// Deposit `amount` of `token` and stream it to `to` between
+    /// `start_ledger` and `end_ledger`. Returns the stream id.
+    pub fn create_stream(
+        env: Env,
+        sender: Address,
+        token: Address,
+        to: Address,
+        amount: i128,
+        start_ledger: u32,
+        end_ledger: u32,
+    ) -> u32 {
+        sender.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        assert!(end_ledger > start_ledger, "end must be after start");
+        assert!(
+            start_ledger >= env.ledger().sequence(),
+            "start must not be in the past"
+        );
+        assert!(sender != to, "cannot stream to self");
+
+        token::Client::new(&env, &token).transfer(
+            &sender,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        let id = Self::stream_count(env.clone());
+        let stream = Stream {
+            id,
+            sender: sender.clone(),
+            recipient: to.clone(),
+            token,
+            amount,
+            start_ledger,
+            end_ledger,
+            withdrawn: 0,
+            cancelled_ledger: None,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Stream(id), &stream);
+        env.storage()
+            .instance()
+            .set(&DataKey::StreamCount, &(id + 1));
+        Self::_push_id(&env, DataKey::SenderStreams(sender.clone()), id);
+        Self::_push_id(&env, DataKey::RecipientStreams(to.clone()), id);
+
+        env.events().publish(
+            (symbol_short!("create"), sender, to),
+            (id, amount, start_ledger, end_ledger),
+        );
+        id
+    }
+
+    /// Stop stream `stream_id`. The recipient receives everything streamed
+    /// so far and the sender is refunded the remainder. Sender only.
+    pub fn cancel(env: Env, stream_id: u32) {
+        let mut stream = Self::get_stream(env.clone(), stream_id);
+        stream.sender.require_auth();
+        assert!(stream.cancelled_ledger.is_none(), "stream cancelled");
+        let now = env.ledger().sequence();
+        assert!(now < stream.end_ledger, "stream finished");
+
+        let owed = Self::_streamed(&stream, now) - stream.withdrawn;
+        let refund = stream.amount - stream.withdrawn - owed;
+        stream.withdrawn += owed;
+        stream.cancelled_ledger = Some(now);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Stream(stream_id), &stream);
+
+        let client = token::Client::new(&env, &stream.token);
+        if owed > 0 {
+            client.transfer(&env.current_contract_address(), &stream.recipient, &owed);
+        }
+        if refund > 0 {
+            client.transfer(&env.current_contract_address(), &stream.sender, &refund);
+        }
+
+        env.events().publish(
+            (symbol_short!("cancel"), stream.sender),
+            (stream_id, owed, refund),
+        );
+    }
+
+    // ── Recipient actions ───────────────────────────────────────────────
+
+    /// Pay out everything streamed to the recipient and not yet withdrawn.
+    /// Recipient only. Returns the amount paid.
+    pub fn withdraw(env: Env, stream_id: u32) -> i128 {
+        let mut stream = Self::get_stream(env.clone(), stream_id);
+        stream.recipient.require_auth();
+        let amount = Self::withdrawable(env.clone(), stream_id);
+        assert!(amount > 0, "nothing to withdraw");
+
+        stream.withdrawn += amount;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Stream(stream_id), &stream);
+        token::Client::new(&env, &stream.token).transfer(
+            &env.current_contract_address(),
+            &stream.recipient,
+            &amount,
+        );
+
+        env.events().publish(
+            (symbol_short!("withdraw"), stream.recipient),
+            (stream_id, amount),
+        );
+        amount
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn get_stream(env: Env, stream_id: u32) -> Stream {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Stream(stream_id))
+            .expect("no stream found")
+    }
+
+    /// Amount the recipient could withdraw now.
+    pub fn withdrawable(env: Env, stream_id: u32) -> i128 {
+        let stream = Self::get_stream(env.clone(), stream_id);
+        if stream.cancelled_ledger.is_some() {
+            return 0;
+        }
+        Self::_streamed(&stream, env.ledger().sequence()) - stream.withdrawn
+    }
+
+    /// Amount still held for the stream, streamed or not.
+    pub fn remaining(env: Env, stream_id: u32) -> i128 {
+        let stream = Self::get_stream(env, stream_id);
+        if stream.cancelled_ledger.is_some() {
+            return 0;
+        }
+        stream.amount - stream.withdrawn
+    }
+
+    /// Ids of the streams created by `sender`, oldest first.
+    pub fn streams_from(env: Env, sender: Address) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SenderStreams(sender))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Ids of the streams paying `recipient`, oldest first.
+    pub fn streams_to(env: Env, recipient: Address) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RecipientStreams(recipient))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn stream_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::StreamCount)
+            .unwrap_or(0)
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    /// Amount streamed by `ledger`, rounded down.
+    fn _streamed(stream: &Stream, ledger: u32) -> i128 {
+        soropad_math::linear(
+            stream.start_ledger,
+            stream.end_ledger,
+            ledger,
+            stream.amount,
+        )
+    }
+
+    fn _push_id(env: &Env, key: DataKey, id: u32) {
+        let mut ids: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        ids.push_back(id);
+        env.storage().persistent().set(&key, &ids);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{
+        testutils::Address as _, testutils::Ledger, testutils::MockAuth, testutils::MockAuthInvoke,
+        IntoVal,
+    };
+
+    struct Setup {
+        env: Env,
+        client: StreamsContractClient<'static>,
+        token: TokenFixture,
+        sender: Address,
+        recipient: Address,
+    }
+
+    /// The sender holds 10 000 tokens; the ledger starts at 10.
+    fn setup() -> Setup {
+        let env = test_env();
+        env.ledger().set_sequence_number(10);
+
+        let token = TokenFixture::stellar_asset(&env);
+        let sender = Address::generate(&env);
+        token.mint(&sender, 10_000);
+        let contract_id = env.register_contract(None, StreamsContract);
+
+        Setup {
+            client: StreamsContractClient::new(&env, &contract_id),
+            token,
+            recipient: Address::generate(&env),
+            env,
+            sender,
+        }
+    }
+
+    /// 1 000 tokens over ledgers 100–200.
+    fn create(s: &Setup) -> u32 {
+        s.client.create_stream(
+            &s.sender,
+            &s.token.address,
+            &s.recipient,
+            &1_000,
+            &100,
+            &200,
+        )
+    }
+
+    #[test]
+    fn test_stream_accrues_per_ledger() {
+        let s = setup();
+        let id = create(&s);
+        assert_eq!(s.token.balance(&s.client.address), 1_000);
+        assert_eq!(s.client.withdrawable(&id), 0);
+
+        s.env.ledger().set_sequence_number(125);
+        assert_eq!(s.client.withdrawable(&id), 250);
+        assert_eq!(s.client.withdraw(&id), 250);
+        assert_eq!(s.token.balance(&s.recipient), 250);
+        assert_eq!(s.client.withdrawable(&id), 0);
+
+        s.env.ledger().set_sequence_number(150);
+        assert_eq!(s.client.withdrawable(&id), 250);
+
+        s.env.ledger().set_sequence_number(300);
+        assert_eq!(s.client.withdraw(&id), 750);
+        assert_eq!(s.token.balance(&s.recipient), 1_000);
+        assert_eq!(s.client.remaining(&id), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "nothing to withdraw")]
+    fn test_withdraw_before_start_panics() {
+        let s = setup();
+        let id = create(&s);
+        s.client.withdraw(&id);
+    }
+
+    #[test]
+    fn test_cancel_splits_fairly() {
+        let s = setup();
+        let id = create(&s);
+        s.env.ledger().set_sequence_number(120);
+        s.client.withdraw(&id);
+
+        s.env.ledger().set_sequence_number(160);
+        s.client.cancel(&id);
+        assert_eq!(s.token.balance(&s.recipient), 600);
+        assert_eq!(s.token.balance(&s.sender), 9_400);
+        assert_eq!(s.token.balance(&s.client.address), 0);
+
+        let stream = s.client.get_stream(&id);
+        assert_eq!(stream.cancelled_ledger, Some(160));
+        assert_eq!(stream.withdrawn, 600);
+        assert_eq!(s.client.withdrawable(&id), 0);
+        assert!(s.client.try_cancel(&id).is_err());
+    }
+
+    #[test]
+    fn test_cancel_before_start_refunds_all() {
+        let s = setup();
+        let id = create(&s);
+        s.client.cancel(&id);
+        assert_eq!(s.token.balance(&s.sender), 10_000);
+        assert_eq!(s.token.balance(&s.recipient), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "stream finished")]
+    fn test_cancel_after_end_panics() {
+        let s = setup();
+        let id = create(&s);
+        s.env.ledger().set_sequence_number(200);
+        s.client.cancel(&id);
+    }
+
+    #[test]
+    #[should_panic(expected = "end must be after start")]
+    fn test_empty_range_panics() {
+        let s = setup();
+        s.client.create_stream(
+            &s.sender,
+            &s.token.address,
+            &s.recipient,
+            &1_000,
+            &100,
+            &100,
+        );
+    }
+
+    #[test]
+    fn test_stream_indexes() {
+        let s = setup();
+        let first = create(&s);
+        let second = create(&s);
+        assert_eq!(s.client.stream_count(), 2);
+        assert_eq!(s.client.streams_from(&s.sender).len(), 2);
+        assert_eq!(s.client.streams_to(&s.recipient).get(0), Some(first));
+        assert_eq!(s.client.streams_to(&s.recipient).get(1), Some(second));
+        assert!(s.client.streams_to(&s.sender).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_only_sender_can_cancel() {
+        let s = setup();
+        let id = create(&s);
+        s.env.mock_auths(&[MockAuth {
+            address: &s.recipient,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "cancel",
+                args: (id,).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.cancel(&id);
+    }
+}
@@ -0,0 +1,304 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    SubscriptionCount,
+    Subscription(u32),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Subscription {
+    pub id: u32,
+    pub subscriber: Address,
+    pub merchant: Address,
+    pub token: Address,
+    /// Paid every period.
+    pub amount: i128,
+    /// Ledgers per period.
+    pub interval: u32,
+    /// Periods paid at most.
+    pub max_periods: u32,
+    /// Ledger the first period is due.
+    pub start: u32,
+    /// Periods paid so far.
+    pub collected: u32,
+    pub cancelled: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Subscriptions Contract — recurring payments pulled from subscribers.
+///
+/// A subscriber `subscribe`s to a merchant, such as the launchpad's premium
+/// tier, for `amount` of a token every `interval` ledgers, for at most
+/// `max_periods` periods. Their authorization of that call also covers an
+/// allowance to this contract for every period, added to any allowance it
+/// already holds, so later payments need no further signature.
+///
+/// The first period is due on subscription and the next every `interval`
+/// ledgers after it. Anyone, typically a keeper, can `collect` a
+/// subscription to pull every period that is due and pay it to the
+/// merchant. Either side can `cancel` at any time; periods not collected
+/// by then are never pulled.
+#[contract]
+pub struct SubscriptionsContract;
+
+#[contractimpl]
+impl SubscriptionsContract {
+    // ── Subscribers ─────────────────────────────────────────────────────
+
+    /// Subscribe `subscriber` to pay `merchant` `amount` of `token` every
+    /// `interval` ledgers for at most `max_periods` periods, approving this
+    /// contract for all of them until `expiration_ledger`. Returns the
+    /// subscription id.
+    #[allow(clippy::too_many_arguments)]
+    pub fn subscribe(
+        env: Env,
+        subscriber: Address,
+        merchant: Address,
+        token: Address,
+        amount: i128,
+        interval: u32,
+        max_periods: u32,
+        expiration_ledger: u32,
+    ) -> u32 {
+        subscriber.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        assert!(interval > 0, "interval must be positive");
+        assert!(max_periods > 0, "max periods must be positive");
+
+        let this = env.current_contract_address();
+        let payment = token::Client::new(&env, &token);
+        let allowance = payment.allowance(&subscriber, &this);
+        payment.approve(
+            &subscriber,
+            &this,
+            &(allowance + amount * max_periods as i128),
+            &expiration_ledger,
+        );
+
+        let id = Self::subscription_count(env.clone());
+        let subscription = Subscription {
+            id,
+            subscriber: subscriber.clone(),
+            merchant: merchant.clone(),
+            token,
+            amount,
+            interval,
+            max_periods,
+            start: env.ledger().sequence(),
+            collected: 0,
+            cancelled: false,
+        };
+        Self::_save(&env, &subscription);
+        env.storage()
+            .instance()
+            .set(&DataKey::SubscriptionCount, &(id + 1));
+
+        env.events().publish(
+            (symbol_short!("subscribe"), id),
+            (subscriber, merchant, amount, interval),
+        );
+        id
+    }
+
+    /// Stop a subscription. Subscriber or merchant only.
+    pub fn cancel(env: Env, subscription_id: u32, caller: Address) {
+        caller.require_auth();
+        let mut subscription = Self::get_subscription(env.clone(), subscription_id);
+        assert!(
+            caller == subscription.subscriber || caller == subscription.merchant,
+            "not a party"
+        );
+        assert!(!subscription.cancelled, "already cancelled");
+
+        subscription.cancelled = true;
+        Self::_save(&env, &subscription);
+
+        env.events()
+            .publish((symbol_short!("cancel"), subscription_id), caller);
+    }
+
+    // ── Keepers ─────────────────────────────────────────────────────────
+
+    /// Pull every due period of a subscription and pay it to the merchant.
+    /// Anyone may call. Returns the amount collected.
+    pub fn collect(env: Env, subscription_id: u32) -> i128 {
+        let mut subscription = Self::get_subscription(env.clone(), subscription_id);
+        let periods = Self::due_periods(env.clone(), subscription_id);
+        assert!(periods > 0, "nothing due");
+
+        let amount = subscription.amount * periods as i128;
+        subscription.collected += periods;
+        Self::_save(&env, &subscription);
+        token::Client::new(&env, &subscription.token).transfer_from(
+            &env.current_contract_address(),
+            &subscription.subscriber,
+            &subscription.merchant,
+            &amount,
+        );
+
+        env.events().publish(
+            (symbol_short!("collect"), subscription_id),
+            (periods, amount),
+        );
+        amount
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Periods of a subscription due and not yet collected.
+    pub fn due_periods(env: Env, subscription_id: u32) -> u32 {
+        let subscription = Self::get_subscription(env.clone(), subscription_id);
+        if subscription.cancelled {
+            return 0;
+        }
+        let elapsed = (env.ledger().sequence() - subscription.start) / subscription.interval + 1;
+        elapsed.min(subscription.max_periods) - subscription.collected
+    }
+
+    /// Ledger the next uncollected period is due, if any is left.
+    pub fn next_payment_ledger(env: Env, subscription_id: u32) -> Option<u32> {
+        let subscription = Self::get_subscription(env, subscription_id);
+        if subscription.cancelled || subscription.collected == subscription.max_periods {
+            return None;
+        }
+        Some(subscription.start + subscription.collected * subscription.interval)
+    }
+
+    pub fn get_subscription(env: Env, subscription_id: u32) -> Subscription {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Subscription(subscription_id))
+            .expect("no subscription found")
+    }
+
+    pub fn subscription_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SubscriptionCount)
+            .unwrap_or(0)
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _save(env: &Env, subscription: &Subscription) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Subscription(subscription.id), subscription);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger};
+
+    struct Setup {
+        env: Env,
+        client: SubscriptionsContractClient<'static>,
+        token: TokenFixture,
+        subscriber: Address,
+        merchant: Address,
+        id: u32,
+    }
+
+    /// 10 every 100 ledgers for at most 3 periods, from ledger 1 000.
+    fn setup() -> Setup {
+        let env = test_env();
+        env.ledger().set_sequence_number(1_000);
+
+        let token = TokenFixture::stellar_asset(&env);
+        let client = SubscriptionsContractClient::new(
+            &env,
+            &env.register_contract(None, SubscriptionsContract),
+        );
+        let (subscriber, merchant) = (Address::generate(&env), Address::generate(&env));
+        token.mint(&subscriber, 1_000);
+        let id = client.subscribe(
+            &subscriber,
+            &merchant,
+            &token.address,
+            &10,
+            &100,
+            &3,
+            &5_000,
+        );
+
+        Setup {
+            token,
+            env,
+            client,
+            subscriber,
+            merchant,
+            id,
+        }
+    }
+
+    #[test]
+    fn test_collect_pulls_due_periods() {
+        let s = setup();
+        assert_eq!(s.client.collect(&s.id), 10);
+        assert!(s.client.try_collect(&s.id).is_err());
+        assert_eq!(s.client.next_payment_ledger(&s.id), Some(1_100));
+
+        // A late keeper catches up, but never past max_periods
+        s.env.ledger().set_sequence_number(1_500);
+        assert_eq!(s.client.due_periods(&s.id), 2);
+        assert_eq!(s.client.collect(&s.id), 20);
+        assert_eq!(s.client.next_payment_ledger(&s.id), None);
+
+        assert_eq!(s.token.balance(&s.merchant), 30);
+        assert_eq!(s.token.balance(&s.subscriber), 970);
+    }
+
+    #[test]
+    fn test_cancel_stops_collection() {
+        let s = setup();
+        s.env.ledger().set_sequence_number(1_100);
+        s.client.cancel(&s.id, &s.subscriber);
+
+        assert_eq!(s.client.due_periods(&s.id), 0);
+        assert!(s.client.try_collect(&s.id).is_err());
+        assert_eq!(s.token.balance(&s.merchant), 0);
+    }
+
+    #[test]
+    fn test_subscriptions_share_the_allowance() {
+        let s = setup();
+        let other = Address::generate(&s.env);
+        let second =
+            s.client
+                .subscribe(&s.subscriber, &other, &s.token.address, &5, &50, &2, &5_000);
+        assert_eq!(
+            s.token.client.allowance(&s.subscriber, &s.client.address),
+            40
+        );
+
+        s.client.collect(&s.id);
+        s.client.collect(&second);
+        assert_eq!(s.token.balance(&other), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a party")]
+    fn test_only_parties_cancel() {
+        let s = setup();
+        s.client.cancel(&s.id, &Address::generate(&s.env));
+    }
+}
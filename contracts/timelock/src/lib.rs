@@ -0,0 +1,329 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Val, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    MinDelay,
+    OperationCount,
+    Operation(u32),
+}
+
+/// A contract call made when an operation is executed.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Action {
+    pub contract: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+}
+
+/// A batch of calls waiting out the delay.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Operation {
+    pub actions: Vec<Action>,
+    /// First ledger on which the operation can be executed.
+    pub ready_ledger: u32,
+    pub executed: bool,
+    pub cancelled: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Timelock Contract — delayed executor for governance decisions.
+///
+/// The admin, normally the governor, `schedule`s batches of contract calls.
+/// Each becomes executable `min_delay` ledgers later and can then be
+/// `execute`d by anyone, unless the admin cancelled it in the meantime.
+///
+/// The timelock is set as the admin of the token, vesting and sale
+/// contracts, so every change to them sits in public view for the whole
+/// delay before it takes effect. Proposals that call the governor itself
+/// are executed here directly, as a contract cannot be re-entered.
+#[contract]
+pub struct TimelockContract;
+
+#[contractimpl]
+impl TimelockContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the admin allowed to schedule and cancel operations and the
+    /// number of ledgers each operation waits.
+    pub fn initialize(env: Env, admin: Address, min_delay: u32) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::MinDelay, &min_delay);
+        env.storage()
+            .instance()
+            .set(&DataKey::OperationCount, &0u32);
+
+        env.events()
+            .publish((symbol_short!("init"),), (admin, min_delay));
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Queue `actions` for execution after the delay. Admin only. Returns
+    /// the operation id.
+    pub fn schedule(env: Env, actions: Vec<Action>) -> u32 {
+        Self::_require_admin(&env);
+        assert!(!actions.is_empty(), "no actions");
+
+        let id = Self::operation_count(env.clone());
+        let ready_ledger = env.ledger().sequence() + Self::min_delay(env.clone());
+        let operation = Operation {
+            actions,
+            ready_ledger,
+            executed: false,
+            cancelled: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Operation(id), &operation);
+        env.storage()
+            .instance()
+            .set(&DataKey::OperationCount, &(id + 1));
+
+        env.events()
+            .publish((symbol_short!("schedule"), id), ready_ledger);
+        id
+    }
+
+    /// Drop a pending operation. Admin only.
+    pub fn cancel(env: Env, operation_id: u32) {
+        Self::_require_admin(&env);
+        let mut operation = Self::get_operation(env.clone(), operation_id);
+        assert!(!operation.executed, "already executed");
+        assert!(!operation.cancelled, "already cancelled");
+        operation.cancelled = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Operation(operation_id), &operation);
+
+        env.events()
+            .publish((symbol_short!("cancel"), operation_id), ());
+    }
+
+    // ── Execution ───────────────────────────────────────────────────────
+
+    /// Make the calls of a ready operation, in order. Anyone may call.
+    pub fn execute(env: Env, operation_id: u32) {
+        let mut operation = Self::get_operation(env.clone(), operation_id);
+        assert!(!operation.cancelled, "operation cancelled");
+        assert!(!operation.executed, "already executed");
+        assert!(
+            env.ledger().sequence() >= operation.ready_ledger,
+            "operation not ready"
+        );
+        operation.executed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Operation(operation_id), &operation);
+
+        for action in operation.actions.iter() {
+            env.invoke_contract::<Val>(&action.contract, &action.function, action.args);
+        }
+
+        env.events()
+            .publish((symbol_short!("execute"), operation_id), ());
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn get_operation(env: Env, operation_id: u32) -> Operation {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Operation(operation_id))
+            .expect("no operation found")
+    }
+
+    /// Whether operation `operation_id` can be executed now.
+    pub fn is_ready(env: Env, operation_id: u32) -> bool {
+        let operation = Self::get_operation(env.clone(), operation_id);
+        !operation.executed
+            && !operation.cancelled
+            && env.ledger().sequence() >= operation.ready_ledger
+    }
+
+    pub fn is_done(env: Env, operation_id: u32) -> bool {
+        Self::get_operation(env, operation_id).executed
+    }
+
+    pub fn operation_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::OperationCount)
+            .expect("not initialized")
+    }
+
+    pub fn min_delay(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinDelay)
+            .expect("not initialized")
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _require_admin(env: &Env) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized");
+        admin.require_auth();
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        testutils::Address as _, testutils::Ledger, testutils::MockAuth, testutils::MockAuthInvoke,
+        vec, IntoVal,
+    };
+
+    /// A contract administered by the timelock.
+    #[contract]
+    struct MockTarget;
+
+    #[contractimpl]
+    impl MockTarget {
+        pub fn init(env: Env, admin: Address) {
+            env.storage().instance().set(&0u32, &admin);
+        }
+
+        pub fn set_value(env: Env, value: u32) {
+            let admin: Address = env.storage().instance().get(&0u32).unwrap();
+            admin.require_auth();
+            env.storage().instance().set(&1u32, &value);
+        }
+
+        pub fn value(env: Env) -> u32 {
+            env.storage().instance().get(&1u32).unwrap_or(0)
+        }
+    }
+
+    struct Setup {
+        env: Env,
+        client: TimelockContractClient<'static>,
+        target: MockTargetClient<'static>,
+        admin: Address,
+    }
+
+    /// Operations wait 50 ledgers; the target's admin is the timelock.
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_sequence_number(10);
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, TimelockContract);
+        let client = TimelockContractClient::new(&env, &contract_id);
+        client.initialize(&admin, &50);
+        let target = MockTargetClient::new(&env, &env.register_contract(None, MockTarget));
+        target.init(&contract_id);
+
+        Setup {
+            env,
+            client,
+            target,
+            admin,
+        }
+    }
+
+    fn set_value(s: &Setup, value: u32) -> Vec<Action> {
+        vec![
+            &s.env,
+            Action {
+                contract: s.target.address.clone(),
+                function: Symbol::new(&s.env, "set_value"),
+                args: vec![&s.env, value.into_val(&s.env)],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_execute_after_delay() {
+        let s = setup();
+        let id = s.client.schedule(&set_value(&s, 7));
+        assert_eq!(s.client.get_operation(&id).ready_ledger, 60);
+        assert!(!s.client.is_ready(&id));
+
+        s.env.ledger().set_sequence_number(60);
+        assert!(s.client.is_ready(&id));
+        s.client.execute(&id);
+        assert_eq!(s.target.value(), 7);
+        assert!(s.client.is_done(&id));
+        assert!(s.client.try_execute(&id).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "operation not ready")]
+    fn test_execute_before_delay_panics() {
+        let s = setup();
+        let id = s.client.schedule(&set_value(&s, 7));
+        s.env.ledger().set_sequence_number(59);
+        s.client.execute(&id);
+    }
+
+    #[test]
+    #[should_panic(expected = "operation cancelled")]
+    fn test_cancelled_operation_cannot_execute() {
+        let s = setup();
+        let id = s.client.schedule(&set_value(&s, 7));
+        s.client.cancel(&id);
+        s.env.ledger().set_sequence_number(60);
+        s.client.execute(&id);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_non_admin_cannot_schedule() {
+        let s = setup();
+        let actions = set_value(&s, 7);
+        let outsider = Address::generate(&s.env);
+        s.env.mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "schedule",
+                args: (actions.clone(),).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.schedule(&actions);
+    }
+
+    #[test]
+    fn test_admin_is_stored() {
+        let s = setup();
+        assert_eq!(s.client.admin(), s.admin);
+        assert_eq!(s.client.min_delay(), 50);
+    }
+}
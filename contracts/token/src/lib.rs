@@ -1,6 +1,16 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String};
+use launchpad_access as access;
+use launchpad_events as events;
+use launchpad_rate_limit::{self as rate_limit, RateLimit};
+use launchpad_ttl as ttl;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
+    String, Vec,
+};
+
+/// Highest transfer fee the admin can set, in basis points.
+pub const MAX_TRANSFER_FEE_BPS: u32 = 1_000;
 
 // ---------------------------------------------------------------------------
 // Storage keys
@@ -9,8 +19,6 @@ use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, E
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
-    Admin,
-    PendingAdmin,
     Name,
     Symbol,
     Decimals,
@@ -18,10 +26,75 @@ pub enum DataKey {
     TotalBurned,
     MaxSupply,
     ContractUri,
+    /// Everything kept per account; see `AccountState`.
+    Account(Address),
+    /// Set by `initialize` on tokens that never stored accounts unpacked.
+    PackedAccounts,
+    /// An allowance and its expiration ledger, in temporary storage until
+    /// it expires.
+    Approval(Address, Address), // (owner, spender)
+    /// Set by `initialize` on tokens that never stored allowances in
+    /// persistent storage.
+    TemporaryAllowances,
+    IsPaused,
+    BalanceCheckpoints(Address),
+    SupplyCheckpoints,
+    SnapshotCount,
+    Snapshot(u32),
+    HolderCount,
+    HolderAt(u32),
+    VoteCheckpoints(Address),
+    TransferFee, // (fee_bps, recipient)
+    /// Set for accounts that neither pay nor cause the transfer fee.
+    FeeExempt(Address),
+    PauseRegistry,
+    Restriction, // (kyc_registry, level)
+    MintLimit,
+    MintUsage,
+    // Persistent allowances from before `Approval`, read until the pair
+    // next approves. Those approved before expiries were kept have no
+    // `AllowanceExpiration` and never expire.
+    Allowance(Address, Address),           // (owner, spender)
+    AllowanceExpiration(Address, Address), // (owner, spender)
+    // Per-account entries from before `AccountState`, read until the
+    // account is next written
     Balance(Address),
-    Allowance(Address, Address), // (owner, spender)
     Frozen(Address),
-    IsPaused,
+    IsHolder(Address),
+    Delegate(Address),
+}
+
+/// Everything the token keeps per account, in one entry, so a transfer
+/// reads and writes each side once.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct AccountState {
+    pub balance: i128,
+    pub frozen: bool,
+    /// Listed in the holder registry.
+    pub holder: bool,
+    /// Who the account delegates its votes to, if anyone but itself.
+    pub delegate: Option<Address>,
+}
+
+/// A balance (or the total supply) from `ledger` onwards.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Checkpoint {
+    pub ledger: u32,
+    pub value: i128,
+}
+
+/// Platform-wide pause switch shared by the launchpad contracts.
+#[contractclient(name = "PauseRegistryClient")]
+pub trait PauseRegistry {
+    fn is_paused(env: Env, contract: Address) -> bool;
+}
+
+/// Verification lookup of the KYC registry.
+#[contractclient(name = "KycRegistryClient")]
+pub trait KycRegistry {
+    fn is_verified(env: Env, account: Address, level: u32) -> bool;
 }
 
 // ---------------------------------------------------------------------------
@@ -31,9 +104,44 @@ pub enum DataKey {
 /// SEP-41 Token Contract — base implementation.
 ///
 /// Contributor issues layered on top:
-/// - #1  freeze_account / unfreeze_account (guard on transfer)
+/// - #1  freeze_account / unfreeze_account (guard on transfers and burns)
 /// - #2  two-step admin transfer (propose_admin / accept_admin)
 /// - #4  max_supply cap enforcement in mint
+///
+/// Balances and the total supply are checkpointed on every change, so
+/// `balance_at` / `total_supply_at` can read them back at any `snapshot`,
+/// and `get_past_total_supply` at any past ledger.
+/// Every address that ever held tokens is listed in the holder registry
+/// (`holder_count` / `holder_at`) for holder-based distributions.
+///
+/// Holders vote with their own balance unless they `delegate` it; votes
+/// are checkpointed per delegatee, so `get_past_votes` reads the delegated
+/// voting power at any past ledger.
+///
+/// The admin, typically the governance timelock, can charge a fee of up to
+/// `MAX_TRANSFER_FEE_BPS` on transfers and lower the supply cap. Platform
+/// contracts that escrow the token (vesting, staking, lockers, sales) book
+/// the amounts they are sent, so they must be made `set_fee_exempt` before
+/// a fee is charged: transfers to or from an exempt account pay no fee.
+///
+/// Besides its own `pause`, the token honours the platform guardian's pause
+/// registry once one is set with `set_pause_registry`.
+///
+/// In restricted mode (`set_restricted`) both sides of every transfer must
+/// be verified in the KYC registry at the configured level.
+///
+/// `set_mint_limit` throttles how much the admin can mint per window of
+/// ledgers, bounding the damage of a compromised admin key.
+///
+/// An account's balance, frozen flag, holder flag and delegate share one
+/// `AccountState` entry. A token upgraded from the layout with one entry
+/// each reads the old entries until the account is next written or packed
+/// by `migrate_accounts`.
+///
+/// Allowances live in temporary storage until their expiration ledger, so
+/// an expired one costs no rent and needs no cleanup. Per-account entries
+/// are bumped by the policy in `launchpad-ttl` whenever they are written,
+/// and the instance on every mint, burn, transfer and approval.
 #[contract]
 pub struct TokenContract;
 
@@ -52,9 +160,7 @@ impl TokenContract {
         max_supply: Option<i128>,
     ) {
         // Prevent re-initialization
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
-        }
+        access::init_admin(&env, &admin);
 
         if let Some(cap) = max_supply {
             assert!(cap > 0, "max_supply must be positive");
@@ -62,11 +168,13 @@ impl TokenContract {
             env.storage().instance().set(&DataKey::MaxSupply, &cap);
         }
 
-        env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Decimals, &decimal);
         env.storage().instance().set(&DataKey::Name, &name);
         env.storage().instance().set(&DataKey::Symbol, &symbol);
         env.storage().instance().set(&DataKey::TotalSupply, &0i128);
+        env.storage().instance().set(&DataKey::PackedAccounts, &true);
+        env.storage().instance().set(&DataKey::TemporaryAllowances, &true);
+        ttl::extend_instance(&env);
 
         if initial_supply > 0 {
             Self::_mint(&env, &admin, initial_supply);
@@ -85,6 +193,9 @@ impl TokenContract {
         Self::_check_paused(&env);
         Self::_require_admin(&env);
         assert!(amount > 0, "amount must be positive");
+        if let Some(limit) = Self::mint_limit(env.clone()) {
+            rate_limit::consume(&env, &DataKey::MintUsage, &limit, amount);
+        }
         Self::_mint(&env, &to, amount);
     }
 
@@ -93,10 +204,12 @@ impl TokenContract {
         Self::_check_paused(&env);
         from.require_auth();
         assert!(amount > 0, "amount must be positive");
+        assert!(!Self::_is_frozen(&env, &from), "account is frozen");
         Self::_burn(&env, &from, amount);
     }
 
-    /// Forced burn of `amount` tokens from `from`. Admin only.
+    /// Forced burn of `amount` tokens from `from`. Admin only, and the
+    /// only way to take tokens from a frozen account.
     pub fn burn_admin(env: Env, from: Address, amount: i128) {
         Self::_check_paused(&env);
         Self::_require_admin(&env);
@@ -107,44 +220,46 @@ impl TokenContract {
     /// Propose a new admin. Must be called by the current admin.
     /// The new admin must call `accept_admin` to finalize the transfer.
     pub fn propose_admin(env: Env, new_admin: Address) {
-        Self::_require_admin(&env);
-        env.storage().instance().set(&DataKey::PendingAdmin, &new_admin);
+        access::propose_admin(&env, &new_admin);
     }
 
     /// Accept the admin role. Must be called by the pending admin.
     pub fn accept_admin(env: Env) {
-        let pending: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::PendingAdmin)
-            .expect("no pending admin");
-        pending.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &pending);
-        env.storage().instance().remove(&DataKey::PendingAdmin);
+        access::accept_admin(&env);
+    }
+
+    /// Hand the upgrade authority to `upgrader`, normally the upgrade
+    /// manager. Admin for the first upgrader, the current upgrader after.
+    pub fn set_upgrader(env: Env, upgrader: Address) {
+        access::set_upgrader(&env, &upgrader);
+    }
+
+    /// Replace the token's code with `wasm_hash`. Upgrader only.
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) {
+        access::upgrade(&env, &wasm_hash);
     }
 
     /// Transfer admin role instantly.
  /// TODO (issue #2): replace with two-step propose_admin / accept_admin.
  pub fn set_admin(env: Env, new_admin: Address) {
-     Self::_require_admin(&env);
-     env.storage().instance().set(&DataKey::Admin, &new_admin);
-     env.events().publish(
-         (symbol_short!("set_admin"),),
-         new_admin,
-     );
+     access::set_admin(&env, &new_admin);
  }
 
  /// Freeze an account, preventing it from sending tokens. Admin only.
  pub fn freeze_account(env: Env, addr: Address) {
      Self::_require_admin(&env);
-     env.storage().persistent().set(&DataKey::Frozen(addr.clone()), &true);
+     let mut account = Self::_account(&env, &addr);
+     account.frozen = true;
+     Self::_set_account(&env, &addr, &account);
      env.events().publish((symbol_short!("freeze"), addr), true);
  }
 
  /// Unfreeze a previously frozen account. Admin only.
  pub fn unfreeze_account(env: Env, addr: Address) {
      Self::_require_admin(&env);
-     env.storage().persistent().remove(&DataKey::Frozen(addr.clone()));
+     let mut account = Self::_account(&env, &addr);
+     account.frozen = false;
+     Self::_set_account(&env, &addr, &account);
      env.events().publish((symbol_short!("freeze"), addr), false);
  }
 
@@ -167,6 +282,81 @@ pub fn unpause(env: Env) {
     pub fn update_contract_uri(env: Env, uri: String) {
         Self::_require_admin(&env);
         env.storage().instance().set(&DataKey::ContractUri, &uri);
+        env.events().publish((symbol_short!("uri"),), uri);
+    }
+
+    /// Charge `fee_bps` of every transfer to `recipient`, or stop charging
+    /// with a zero fee. Admin only.
+    pub fn set_transfer_fee(env: Env, fee_bps: u32, recipient: Address) {
+        Self::_require_admin(&env);
+        assert!(fee_bps <= MAX_TRANSFER_FEE_BPS, "fee_bps exceeds maximum");
+        if fee_bps == 0 {
+            env.storage().instance().remove(&DataKey::TransferFee);
+        } else {
+            env.storage().instance().set(&DataKey::TransferFee, &(fee_bps, recipient.clone()));
+        }
+        env.events().publish((symbol_short!("fee"), recipient), fee_bps);
+    }
+
+    /// Exempt `account` from the transfer fee on everything it sends and
+    /// receives, or charge it again. Admin only.
+    pub fn set_fee_exempt(env: Env, account: Address, exempt: bool) {
+        Self::_require_admin(&env);
+        let key = DataKey::FeeExempt(account.clone());
+        if exempt {
+            env.storage().persistent().set(&key, &true);
+            ttl::extend_persistent(&env, &key);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("fee_exmpt"), account), exempt);
+    }
+
+    /// Lower the supply cap to `max_supply`, or set one if there is none.
+    /// The cap can never go back up. Admin only.
+    pub fn reduce_max_supply(env: Env, max_supply: i128) {
+        Self::_require_admin(&env);
+        if let Some(cap) = Self::max_supply(env.clone()) {
+            assert!(max_supply < cap, "max_supply can only be reduced");
+        }
+        let supply: i128 = env.storage().instance().get(&DataKey::TotalSupply).unwrap_or(0);
+        assert!(max_supply >= supply, "max_supply below total supply");
+        env.storage().instance().set(&DataKey::MaxSupply, &max_supply);
+        env.events().publish((symbol_short!("max_sup"),), max_supply);
+    }
+
+    /// Also stop while the guardian's pause registry has this token paused.
+    /// Admin only.
+    pub fn set_pause_registry(env: Env, registry: Address) {
+        Self::_require_admin(&env);
+        env.storage().instance().set(&DataKey::PauseRegistry, &registry);
+        env.events().publish((symbol_short!("pause_reg"),), registry);
+    }
+
+    /// Only let accounts verified at `level` in `kyc_registry` send and
+    /// receive transfers, or lift the restriction with `None`. Admin only.
+    pub fn set_restricted(env: Env, kyc_registry: Option<Address>, level: u32) {
+        Self::_require_admin(&env);
+        match &kyc_registry {
+            Some(registry) => env.storage().instance().set(&DataKey::Restriction, &(registry.clone(), level)),
+            None => env.storage().instance().remove(&DataKey::Restriction),
+        }
+        env.events().publish((symbol_short!("restrict"),), (kyc_registry, level));
+    }
+
+    /// Throttle `mint` to at most `limit.limit` tokens per window, or lift
+    /// the throttle with `None`. Admin only.
+    pub fn set_mint_limit(env: Env, limit: Option<RateLimit>) {
+        Self::_require_admin(&env);
+        match &limit {
+            Some(limit) => {
+                limit.validate();
+                env.storage().instance().set(&DataKey::MintLimit, limit);
+            }
+            None => env.storage().instance().remove(&DataKey::MintLimit),
+        }
+        env.events().publish((symbol_short!("mint_lim"),), limit);
     }
 
     // ── Token operations ────────────────────────────────────────────────
@@ -181,18 +371,20 @@ pub fn unpause(env: Env) {
         Self::_transfer(&env, &from, &to, amount);
     }
 
-    /// Approve `spender` to spend up to `amount` on behalf of `from`.
-    pub fn approve(env: Env, from: Address, spender: Address, amount: i128, _expiration_ledger: u32) {
+    /// Approve `spender` to spend up to `amount` on behalf of `from` until
+    /// `expiration_ledger`, after which the allowance reads as zero.
+    pub fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
         from.require_auth();
         assert!(amount >= 0, "amount must be non-negative");
+        assert!(
+            amount == 0 || expiration_ledger >= env.ledger().sequence(),
+            "expiration_ledger is in the past"
+        );
 
-        let key = DataKey::Allowance(from.clone(), spender.clone());
-        env.storage().persistent().set(&key, &amount);
+        Self::_set_allowance(&env, &from, &spender, amount, Some(expiration_ledger));
+        ttl::extend_instance(&env);
 
-        env.events().publish(
-            (symbol_short!("approve"), from, spender),
-            amount,
-        );
+        events::approve(&env, &from, &spender, amount);
     }
 
     /// Transfer `amount` from `from` to `to` using `spender`'s allowance.
@@ -202,29 +394,100 @@ pub fn unpause(env: Env) {
         assert!(amount > 0, "amount must be positive");
         assert!(!Self::_is_frozen(&env, &from), "account is frozen");
 
-        let key = DataKey::Allowance(from.clone(), spender.clone());
-        let allowance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
-        assert!(allowance >= amount, "insufficient allowance");
+        Self::_spend_allowance(&env, &from, &spender, amount);
+        Self::_transfer(&env, &from, &to, amount);
+    }
 
-        env.storage().persistent().set(&key, &(allowance - amount));
+    /// Burn `amount` of `from`'s tokens using `spender`'s allowance.
+    pub fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        Self::_check_paused(&env);
+        spender.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        assert!(!Self::_is_frozen(&env, &from), "account is frozen");
 
-        Self::_transfer(&env, &from, &to, amount);
+        Self::_spend_allowance(&env, &from, &spender, amount);
+        Self::_burn(&env, &from, amount);
+    }
+
+    // ── Delegation ──────────────────────────────────────────────────────
+
+    /// Give the voting power of `delegator`'s balance to `delegatee`, now
+    /// and for all future balance changes. Delegating to oneself restores
+    /// one's own votes.
+    pub fn delegate(env: Env, delegator: Address, delegatee: Address) {
+        delegator.require_auth();
+        let mut account = Self::_account(&env, &delegator);
+        let previous = account.delegate.clone().unwrap_or(delegator.clone());
+        account.delegate = Some(delegatee.clone());
+        Self::_set_account(&env, &delegator, &account);
+
+        Self::_move_votes(&env, &previous, &delegatee, account.balance);
+
+        env.events().publish(
+            (symbol_short!("delegate"), delegator),
+            (previous, delegatee),
+        );
+    }
+
+    // ── Snapshots ───────────────────────────────────────────────────────
+
+    /// Record the current ledger as a snapshot and return its id. Balances
+    /// count as they stood at the end of the previous ledger. Anyone may call.
+    pub fn snapshot(env: Env) -> u32 {
+        let id: u32 = env.storage().instance().get(&DataKey::SnapshotCount).unwrap_or(0);
+        let ledger = env.ledger().sequence();
+        env.storage().persistent().set(&DataKey::Snapshot(id), &ledger);
+        ttl::extend_persistent(&env, &DataKey::Snapshot(id));
+        env.storage().instance().set(&DataKey::SnapshotCount, &(id + 1));
+
+        env.events().publish((symbol_short!("snapshot"),), (id, ledger));
+        id
+    }
+
+    // ── Account layout ──────────────────────────────────────────────────
+
+    /// Pack the old per-account entries of `accounts` into one
+    /// `AccountState` each, on a token upgraded from the unpacked layout.
+    /// Accounts are packed on their next write anyway; this stops paying
+    /// rent on their old entries sooner. Anyone may call.
+    pub fn migrate_accounts(env: Env, accounts: Vec<Address>) {
+        if env.storage().instance().has(&DataKey::PackedAccounts) {
+            return;
+        }
+        for addr in accounts.iter() {
+            let account = Self::_account(&env, &addr);
+            if account != AccountState::default() {
+                Self::_set_account(&env, &addr, &account);
+            }
+        }
     }
 
     // ── Read-only getters ───────────────────────────────────────────────
 
     pub fn balance(env: Env, id: Address) -> i128 {
-        let key = DataKey::Balance(id);
-        env.storage().persistent().get(&key).unwrap_or(0)
+        Self::_account(&env, &id).balance
     }
 
+    /// Amount `spender` can still spend for `from`; zero once expired.
     pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
-        let key = DataKey::Allowance(from, spender);
-        env.storage().persistent().get(&key).unwrap_or(0)
+        let (amount, expiration) = Self::_allowance(&env, &from, &spender);
+        if expiration.is_some_and(|ledger| ledger < env.ledger().sequence()) {
+            return 0;
+        }
+        amount
     }
 
     pub fn admin(env: Env) -> Address {
-        env.storage().instance().get(&DataKey::Admin).expect("not initialized")
+        access::admin(&env)
+    }
+
+    /// Admin nominated by `propose_admin`, if any.
+    pub fn pending_admin(env: Env) -> Option<Address> {
+        access::pending_admin(&env)
+    }
+
+    pub fn upgrader(env: Env) -> Option<Address> {
+        access::upgrader(&env)
     }
 
     pub fn decimals(env: Env) -> u32 {
@@ -247,18 +510,116 @@ pub fn unpause(env: Env) {
         env.storage().instance().get(&DataKey::TotalBurned).unwrap_or(0)
     }
 
+    /// Ledger recorded by snapshot `snapshot_id`.
+    pub fn snapshot_ledger(env: Env, snapshot_id: u32) -> u32 {
+        env.storage().persistent().get(&DataKey::Snapshot(snapshot_id)).expect("no snapshot found")
+    }
+
+    /// Balance of `id` at snapshot `snapshot_id`.
+    pub fn balance_at(env: Env, id: Address, snapshot_id: u32) -> i128 {
+        let ledger = Self::snapshot_ledger(env.clone(), snapshot_id);
+        Self::_value_at(&env, &DataKey::BalanceCheckpoints(id), ledger)
+    }
+
+    /// Total supply at snapshot `snapshot_id`.
+    pub fn total_supply_at(env: Env, snapshot_id: u32) -> i128 {
+        let ledger = Self::snapshot_ledger(env.clone(), snapshot_id);
+        Self::_value_at(&env, &DataKey::SupplyCheckpoints, ledger)
+    }
+
+    /// Voting power of `account`, own or delegated, at the end of `ledger`,
+    /// which must be in the past.
+    pub fn get_past_votes(env: Env, account: Address, ledger: u32) -> i128 {
+        assert!(ledger < env.ledger().sequence(), "ledger not yet final");
+        Self::_value_at(&env, &DataKey::VoteCheckpoints(account), ledger + 1)
+    }
+
+    /// Total supply at the end of `ledger`, which must be in the past.
+    pub fn get_past_total_supply(env: Env, ledger: u32) -> i128 {
+        assert!(ledger < env.ledger().sequence(), "ledger not yet final");
+        Self::_value_at(&env, &DataKey::SupplyCheckpoints, ledger + 1)
+    }
+
+    /// Address `account` delegates its voting power to; itself unless it
+    /// delegated.
+    pub fn delegates(env: Env, account: Address) -> Address {
+        Self::_account(&env, &account).delegate.unwrap_or(account)
+    }
+
+    /// Current voting power of `account`: the balances delegated to it,
+    /// including its own unless delegated away.
+    pub fn delegated_votes(env: Env, account: Address) -> i128 {
+        Self::_value_at(&env, &DataKey::VoteCheckpoints(account), u32::MAX)
+    }
+
+    /// Number of addresses that ever held tokens.
+    pub fn holder_count(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::HolderCount).unwrap_or(0)
+    }
+
+    /// The `index`-th address to have held tokens.
+    pub fn holder_at(env: Env, index: u32) -> Address {
+        env.storage().persistent().get(&DataKey::HolderAt(index)).expect("no holder found")
+    }
+
     /// Returns `true` if the given address is frozen.
     pub fn is_frozen(env: Env, addr: Address) -> bool {
-        env.storage().persistent().get(&DataKey::Frozen(addr)).unwrap_or(false)
+        Self::_account(&env, &addr).frozen
     }
   
     pub fn max_supply(env: Env) -> Option<i128> {
         env.storage().instance().get(&DataKey::MaxSupply)
     }
 
-    /// Returns `true` if the contract is currently paused.
+    /// Fee charged on transfers, in basis points.
+    pub fn transfer_fee_bps(env: Env) -> u32 {
+        Self::_transfer_fee(&env).map(|(fee_bps, _)| fee_bps).unwrap_or(0)
+    }
+
+    /// Address transfer fees are paid to, if a fee is charged.
+    pub fn fee_recipient(env: Env) -> Option<Address> {
+        Self::_transfer_fee(&env).map(|(_, recipient)| recipient)
+    }
+
+    pub fn is_fee_exempt(env: Env, account: Address) -> bool {
+        Self::_is_fee_exempt(&env, &account)
+    }
+
+    /// Whether the token is paused by its admin or by the pause registry.
     pub fn is_paused(env: Env) -> bool {
-        env.storage().instance().get(&DataKey::IsPaused).unwrap_or(false)
+        if env.storage().instance().get(&DataKey::IsPaused).unwrap_or(false) {
+            return true;
+        }
+        match Self::pause_registry(env.clone()) {
+            Some(registry) => PauseRegistryClient::new(&env, &registry)
+                .is_paused(&env.current_contract_address()),
+            None => false,
+        }
+    }
+
+    pub fn pause_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PauseRegistry)
+    }
+
+    /// KYC registry transfers are checked against, if restricted.
+    pub fn kyc_registry(env: Env) -> Option<Address> {
+        Self::_restriction(&env).map(|(registry, _)| registry)
+    }
+
+    /// Verification level transfers require; 0 if unrestricted.
+    pub fn kyc_level(env: Env) -> u32 {
+        Self::_restriction(&env).map(|(_, level)| level).unwrap_or(0)
+    }
+
+    pub fn mint_limit(env: Env) -> Option<RateLimit> {
+        env.storage().instance().get(&DataKey::MintLimit)
+    }
+
+    /// Amount the admin can still mint before the throttle kicks in, if
+    /// one is set.
+    pub fn mint_available(env: Env) -> Option<i128> {
+        Self::mint_limit(env.clone())
+            .map(|limit| rate_limit::available(&env, &DataKey::MintUsage, &limit))
     }
 
     pub fn contract_uri(env: Env) -> String {
@@ -271,16 +632,103 @@ pub fn unpause(env: Env) {
     // ── Internal helpers ────────────────────────────────────────────────
 
     fn _require_admin(env: &Env) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("not initialized");
-        admin.require_auth();
+        access::require_admin(env);
     }
 
     fn _is_frozen(env: &Env, addr: &Address) -> bool {
-        env.storage().persistent().get(&DataKey::Frozen(addr.clone())).unwrap_or(false)
+        Self::_account(env, addr).frozen
+    }
+
+    /// The state of `addr`. On a token upgraded from the unpacked layout,
+    /// an account not written since is read from its old entries.
+    fn _account(env: &Env, addr: &Address) -> AccountState {
+        let storage = env.storage().persistent();
+        if let Some(account) = storage.get(&DataKey::Account(addr.clone())) {
+            return account;
+        }
+        if env.storage().instance().has(&DataKey::PackedAccounts) {
+            return AccountState::default();
+        }
+        AccountState {
+            balance: storage.get(&DataKey::Balance(addr.clone())).unwrap_or(0),
+            frozen: storage.get(&DataKey::Frozen(addr.clone())).unwrap_or(false),
+            holder: storage.has(&DataKey::IsHolder(addr.clone())),
+            delegate: storage.get(&DataKey::Delegate(addr.clone())),
+        }
+    }
+
+    /// Store the state of `addr`, dropping its old unpacked entries if it
+    /// still had them.
+    fn _set_account(env: &Env, addr: &Address, account: &AccountState) {
+        let storage = env.storage().persistent();
+        let key = DataKey::Account(addr.clone());
+        if !env.storage().instance().has(&DataKey::PackedAccounts) && !storage.has(&key) {
+            storage.remove(&DataKey::Balance(addr.clone()));
+            storage.remove(&DataKey::Frozen(addr.clone()));
+            storage.remove(&DataKey::IsHolder(addr.clone()));
+            storage.remove(&DataKey::Delegate(addr.clone()));
+        }
+        storage.set(&key, account);
+        ttl::extend_persistent(env, &key);
+    }
+
+    /// The allowance of `spender` over `from`'s tokens and its expiration
+    /// ledger, expired or not. On a token upgraded from persistent
+    /// allowances, a pair that has not approved since is read from its old
+    /// entries.
+    fn _allowance(env: &Env, from: &Address, spender: &Address) -> (i128, Option<u32>) {
+        let key = DataKey::Approval(from.clone(), spender.clone());
+        if let Some((amount, expiration)) = env.storage().temporary().get(&key) {
+            return (amount, Some(expiration));
+        }
+        if env.storage().instance().has(&DataKey::TemporaryAllowances) {
+            return (0, None);
+        }
+        let storage = env.storage().persistent();
+        (
+            storage.get(&DataKey::Allowance(from.clone(), spender.clone())).unwrap_or(0),
+            storage.get(&DataKey::AllowanceExpiration(from.clone(), spender.clone())),
+        )
+    }
+
+    /// Store the allowance of `spender` over `from`'s tokens, live until its
+    /// expiration, and drop the pair's old persistent entries if it had
+    /// them. Only an old allowance without an expiration stays persistent.
+    fn _set_allowance(
+        env: &Env,
+        from: &Address,
+        spender: &Address,
+        amount: i128,
+        expiration: Option<u32>,
+    ) {
+        let storage = env.storage().persistent();
+        let Some(expiration) = expiration else {
+            storage.set(&DataKey::Allowance(from.clone(), spender.clone()), &amount);
+            return;
+        };
+        let key = DataKey::Approval(from.clone(), spender.clone());
+        if amount == 0 {
+            env.storage().temporary().remove(&key);
+        } else {
+            ttl::set_temporary_until(env, &key, &(amount, expiration), expiration);
+        }
+        if !env.storage().instance().has(&DataKey::TemporaryAllowances) {
+            storage.remove(&DataKey::Allowance(from.clone(), spender.clone()));
+            storage.remove(&DataKey::AllowanceExpiration(from.clone(), spender.clone()));
+        }
+    }
+
+    /// Take `amount` off the allowance of `spender` over `from`'s tokens.
+    fn _spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+        let (allowance, expiration) = Self::_allowance(env, from, spender);
+        let live = expiration.is_none_or(|ledger| ledger >= env.ledger().sequence());
+        assert!(live && allowance >= amount, "insufficient allowance");
+
+        Self::_set_allowance(env, from, spender, allowance - amount, expiration);
     }
 
     fn _check_paused(env: &Env) {
-        if env.storage().instance().get::<DataKey, bool>(&DataKey::IsPaused).unwrap_or(false) {
+        if Self::is_paused(env.clone()) {
             panic!("contract is paused");
         }
     }
@@ -293,46 +741,160 @@ pub fn unpause(env: Env) {
             assert!(new_supply <= cap, "mint would exceed max_supply");
         }
 
-        let key = DataKey::Balance(to.clone());
-        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
-        env.storage().persistent().set(&key, &(balance + amount));
+        let mut account = Self::_account(env, to);
+        account.balance += amount;
+        Self::_register_holder(env, to, &mut account);
+        Self::_set_account(env, to, &account);
+        Self::_checkpoint(env, &DataKey::BalanceCheckpoints(to.clone()), account.balance);
+        Self::_add_votes(env, &account.delegate.unwrap_or(to.clone()), amount);
 
         env.storage().instance().set(&DataKey::TotalSupply, &new_supply);
+        Self::_checkpoint(env, &DataKey::SupplyCheckpoints, new_supply);
+        ttl::extend_instance(env);
 
-        env.events().publish((symbol_short!("mint"), to.clone()), amount);
+        events::mint(env, to, amount);
     }
 
     fn _burn(env: &Env, from: &Address, amount: i128) {
-        let key = DataKey::Balance(from.clone());
-        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
-        assert!(balance >= amount, "insufficient balance to burn");
-        env.storage().persistent().set(&key, &(balance - amount));
+        let mut account = Self::_account(env, from);
+        assert!(account.balance >= amount, "insufficient balance to burn");
+        account.balance -= amount;
+        Self::_set_account(env, from, &account);
+        Self::_checkpoint(env, &DataKey::BalanceCheckpoints(from.clone()), account.balance);
+        Self::_add_votes(env, &account.delegate.unwrap_or(from.clone()), -amount);
 
         let supply: i128 = env.storage().instance().get(&DataKey::TotalSupply).unwrap_or(0);
         env.storage().instance().set(&DataKey::TotalSupply, &(supply - amount));
+        Self::_checkpoint(env, &DataKey::SupplyCheckpoints, supply - amount);
 
         let burned: i128 = env.storage().instance().get(&DataKey::TotalBurned).unwrap_or(0);
         env.storage().instance().set(&DataKey::TotalBurned, &(burned + amount));
+        ttl::extend_instance(env);
 
-        env.events().publish((symbol_short!("burn"), from.clone()), amount);
+        events::burn(env, from, amount);
+    }
+
+    fn _transfer_fee(env: &Env) -> Option<(u32, Address)> {
+        env.storage().instance().get(&DataKey::TransferFee)
+    }
+
+    fn _is_fee_exempt(env: &Env, account: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::FeeExempt(account.clone()))
     }
 
+    fn _restriction(env: &Env) -> Option<(Address, u32)> {
+        env.storage().instance().get(&DataKey::Restriction)
+    }
+
+    /// Move `amount` from `from`, paying the transfer fee out of it and the
+    /// rest to `to`, unless either side is the fee recipient or exempt.
     fn _transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
-        let from_key = DataKey::Balance(from.clone());
-        let to_key = DataKey::Balance(to.clone());
+        if let Some((registry, level)) = Self::_restriction(env) {
+            let kyc = KycRegistryClient::new(env, &registry);
+            assert!(
+                kyc.is_verified(from, &level) && kyc.is_verified(to, &level),
+                "kyc required"
+            );
+        }
+        let mut amount = amount;
+        if let Some((fee_bps, recipient)) = Self::_transfer_fee(env) {
+            let fee = soropad_math::apply_bps(amount, fee_bps);
+            if fee > 0
+                && *from != recipient
+                && *to != recipient
+                && !Self::_is_fee_exempt(env, from)
+                && !Self::_is_fee_exempt(env, to)
+            {
+                Self::_move(env, from, &recipient, fee);
+                amount -= fee;
+            }
+        }
+        Self::_move(env, from, to, amount);
+        ttl::extend_instance(env);
+    }
 
-        let from_balance: i128 = env.storage().persistent().get(&from_key).unwrap_or(0);
-        assert!(from_balance >= amount, "insufficient balance");
+    fn _move(env: &Env, from: &Address, to: &Address, amount: i128) {
+        let mut sender = Self::_account(env, from);
+        assert!(sender.balance >= amount, "insufficient balance");
+        sender.balance -= amount;
+        Self::_set_account(env, from, &sender);
+        Self::_checkpoint(env, &DataKey::BalanceCheckpoints(from.clone()), sender.balance);
 
-        env.storage().persistent().set(&from_key, &(from_balance - amount));
+        // Read after the debit, so a transfer to oneself nets out
+        let mut receiver = Self::_account(env, to);
+        receiver.balance += amount;
+        Self::_register_holder(env, to, &mut receiver);
+        Self::_set_account(env, to, &receiver);
+        Self::_checkpoint(env, &DataKey::BalanceCheckpoints(to.clone()), receiver.balance);
 
-        let to_balance: i128 = env.storage().persistent().get(&to_key).unwrap_or(0);
-        env.storage().persistent().set(&to_key, &(to_balance + amount));
+        let from_delegate = sender.delegate.unwrap_or(from.clone());
+        let to_delegate = receiver.delegate.unwrap_or(to.clone());
+        Self::_move_votes(env, &from_delegate, &to_delegate, amount);
 
-        env.events().publish(
-            (symbol_short!("transfer"), from.clone(), to.clone()),
-            amount,
-        );
+        events::transfer(env, from, to, amount);
+    }
+
+    fn _move_votes(env: &Env, from: &Address, to: &Address, amount: i128) {
+        if from == to || amount == 0 {
+            return;
+        }
+        Self::_add_votes(env, from, -amount);
+        Self::_add_votes(env, to, amount);
+    }
+
+    fn _add_votes(env: &Env, delegatee: &Address, delta: i128) {
+        let key = DataKey::VoteCheckpoints(delegatee.clone());
+        let votes = Self::_value_at(env, &key, u32::MAX);
+        Self::_checkpoint(env, &key, votes + delta);
+    }
+
+    /// Record `value` under the checkpoint list at `key` for the current
+    /// ledger, overwriting an entry already written this ledger.
+    fn _checkpoint(env: &Env, key: &DataKey, value: i128) {
+        let ledger = env.ledger().sequence();
+        let mut checkpoints: Vec<Checkpoint> = env.storage().persistent().get(key).unwrap_or(Vec::new(env));
+        let checkpoint = Checkpoint { ledger, value };
+        match checkpoints.last() {
+            Some(last) if last.ledger == ledger => checkpoints.set(checkpoints.len() - 1, checkpoint),
+            _ => checkpoints.push_back(checkpoint),
+        }
+        env.storage().persistent().set(key, &checkpoints);
+        ttl::extend_persistent(env, key);
+    }
+
+    /// Value of the checkpoint list at `key` as it stood before `ledger`.
+    fn _value_at(env: &Env, key: &DataKey, ledger: u32) -> i128 {
+        let checkpoints: Vec<Checkpoint> = env.storage().persistent().get(key).unwrap_or(Vec::new(env));
+        // Binary search for the number of checkpoints written before `ledger`
+        let (mut lo, mut hi) = (0u32, checkpoints.len());
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if checkpoints.get(mid).unwrap().ledger < ledger {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        if lo == 0 {
+            0
+        } else {
+            checkpoints.get(lo - 1).unwrap().value
+        }
+    }
+
+    /// List `addr` in the holder registry unless `account`, its state
+    /// about to be stored, already is.
+    fn _register_holder(env: &Env, addr: &Address, account: &mut AccountState) {
+        if account.holder {
+            return;
+        }
+        let count = Self::holder_count(env.clone());
+        account.holder = true;
+        env.storage().persistent().set(&DataKey::HolderAt(count), addr);
+        ttl::extend_persistent(env, &DataKey::HolderAt(count));
+        env.storage().instance().set(&DataKey::HolderCount, &(count + 1));
     }
 }
 
@@ -341,9 +903,15 @@ pub fn unpause(env: Env) {
 // ---------------------------------------------------------------------------
 
 #[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping)]
 mod test {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, Env, IntoVal};
+    use soroban_sdk::{
+        testutils::storage::{Instance as _, Persistent as _, Temporary as _},
+        testutils::Address as _,
+        testutils::Ledger,
+        Env, IntoVal,
+    };
 
     fn setup() -> (Env, TokenContractClient<'static>, Address, Address) {
         let env = Env::default();
@@ -498,8 +1066,10 @@ mod test {
         client.propose_admin(&user);
         // Admin has not changed yet
         assert_ne!(client.admin(), user);
+        assert_eq!(client.pending_admin(), Some(user.clone()));
         client.accept_admin();
         assert_eq!(client.admin(), user);
+        assert_eq!(client.pending_admin(), None);
     }
 
     #[test]
@@ -519,6 +1089,21 @@ mod test {
         assert_eq!(client.admin(), other);
     }
 
+    #[test]
+    fn test_set_upgrader() {
+        let (env, client, _, _) = setup();
+        let manager = Address::generate(&env);
+        client.set_upgrader(&manager);
+        assert_eq!(client.upgrader(), Some(manager));
+    }
+
+    #[test]
+    #[should_panic(expected = "no upgrader")]
+    fn test_upgrade_without_upgrader() {
+        let (env, client, _, _) = setup();
+        client.upgrade(&BytesN::from_array(&env, &[0u8; 32]));
+    }
+
     #[test]
     fn test_old_admin_retains_role_until_accepted() {
         let (_, client, admin, user) = setup();
@@ -561,6 +1146,35 @@ mod test {
         client.transfer_from(&spender, &user, &admin, &500i128);
     }
 
+    #[test]
+    #[should_panic(expected = "account is frozen")]
+    fn test_frozen_burn_blocked() {
+        let (_, client, admin, user) = setup();
+        client.transfer(&admin, &user, &1000i128);
+        client.freeze_account(&user);
+        client.burn(&user, &500i128);
+    }
+
+    #[test]
+    #[should_panic(expected = "account is frozen")]
+    fn test_frozen_burn_from_blocked() {
+        let (env, client, admin, user) = setup();
+        let spender = Address::generate(&env);
+        client.transfer(&admin, &user, &1000i128);
+        client.approve(&user, &spender, &1000i128, &0u32);
+        client.freeze_account(&user);
+        client.burn_from(&spender, &user, &500i128);
+    }
+
+    #[test]
+    fn test_admin_burns_from_frozen_account() {
+        let (_, client, admin, user) = setup();
+        client.transfer(&admin, &user, &1000i128);
+        client.freeze_account(&user);
+        client.burn_admin(&user, &400i128);
+        assert_eq!(client.balance(&user), 600i128);
+    }
+
     #[test]
     fn test_unfreeze_restores_transfer() {
         let (_, client, admin, user) = setup();
@@ -816,4 +1430,444 @@ mod test {
         let (_, client, _, _) = setup();
         client.contract_uri();
     }
+
+    // ── Snapshot tests ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_balance_at_snapshot() {
+        let (env, client, admin, user) = setup();
+        env.ledger().set_sequence_number(10);
+        client.transfer(&admin, &user, &100_0000000i128);
+
+        env.ledger().set_sequence_number(20);
+        let id = client.snapshot();
+        // Moves in the snapshot ledger itself are not included
+        client.transfer(&user, &admin, &40_0000000i128);
+        client.burn(&admin, &1_000_0000000i128);
+
+        assert_eq!(client.balance_at(&user, &id), 100_0000000i128);
+        assert_eq!(client.balance_at(&admin, &id), 999_900_0000000i128);
+        assert_eq!(client.total_supply_at(&id), 1_000_000_0000000i128);
+        assert_eq!(client.snapshot_ledger(&id), 20);
+
+        env.ledger().set_sequence_number(21);
+        let later = client.snapshot();
+        assert_eq!(client.balance_at(&user, &later), 60_0000000i128);
+        assert_eq!(client.total_supply_at(&later), 999_000_0000000i128);
+    }
+
+    #[test]
+    fn test_holder_registry() {
+        let (env, client, admin, user) = setup();
+        let other = Address::generate(&env);
+        client.transfer(&admin, &user, &1i128);
+        client.mint(&other, &1i128);
+        client.transfer(&admin, &user, &1i128);
+
+        assert_eq!(client.holder_count(), 3);
+        assert_eq!(client.holder_at(&0), admin);
+        assert_eq!(client.holder_at(&1), user);
+        assert_eq!(client.holder_at(&2), other);
+    }
+
+    #[test]
+    fn test_get_past_votes() {
+        let (env, client, admin, user) = setup();
+        env.ledger().set_sequence_number(10);
+        client.transfer(&admin, &user, &100_0000000i128);
+        env.ledger().set_sequence_number(11);
+        client.transfer(&user, &admin, &30_0000000i128);
+        client.burn(&admin, &70_0000000i128);
+
+        env.ledger().set_sequence_number(12);
+        assert_eq!(client.get_past_votes(&user, &9), 0);
+        assert_eq!(client.get_past_votes(&user, &10), 100_0000000i128);
+        assert_eq!(client.get_past_votes(&user, &11), 70_0000000i128);
+        assert_eq!(client.get_past_total_supply(&10), 1_000_000_0000000i128);
+        assert_eq!(client.get_past_total_supply(&11), 999_930_0000000i128);
+    }
+
+    #[test]
+    #[should_panic(expected = "ledger not yet final")]
+    fn test_get_past_votes_current_ledger_panics() {
+        let (env, client, _, user) = setup();
+        env.ledger().set_sequence_number(10);
+        client.get_past_votes(&user, &10);
+    }
+
+    #[test]
+    #[should_panic(expected = "no snapshot found")]
+    fn test_unknown_snapshot_panics() {
+        let (_, client, admin, _) = setup();
+        client.balance_at(&admin, &0);
+    }
+
+    // ── Delegation tests ────────────────────────────────────────────────
+
+    #[test]
+    fn test_delegation_moves_votes() {
+        let (env, client, admin, user) = setup();
+        let delegatee = Address::generate(&env);
+        client.transfer(&admin, &user, &100_0000000i128);
+        assert_eq!(client.delegates(&user), user);
+        assert_eq!(client.delegated_votes(&user), 100_0000000i128);
+
+        client.delegate(&user, &delegatee);
+        assert_eq!(client.delegates(&user), delegatee);
+        assert_eq!(client.delegated_votes(&user), 0);
+        assert_eq!(client.delegated_votes(&delegatee), 100_0000000i128);
+
+        // Later balance changes follow the delegation
+        client.transfer(&admin, &user, &50_0000000i128);
+        client.burn(&user, &30_0000000i128);
+        assert_eq!(client.delegated_votes(&delegatee), 120_0000000i128);
+        assert_eq!(client.balance(&delegatee), 0);
+    }
+
+    #[test]
+    fn test_redelegation_keeps_past_votes() {
+        let (env, client, admin, user) = setup();
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        env.ledger().set_sequence_number(10);
+        client.transfer(&admin, &user, &100_0000000i128);
+        client.delegate(&user, &first);
+
+        env.ledger().set_sequence_number(20);
+        client.delegate(&user, &second);
+        env.ledger().set_sequence_number(30);
+        client.delegate(&user, &user);
+
+        env.ledger().set_sequence_number(31);
+        assert_eq!(client.get_past_votes(&first, &10), 100_0000000i128);
+        assert_eq!(client.get_past_votes(&first, &20), 0);
+        assert_eq!(client.get_past_votes(&second, &20), 100_0000000i128);
+        assert_eq!(client.get_past_votes(&second, &30), 0);
+        assert_eq!(client.get_past_votes(&user, &10), 0);
+        assert_eq!(client.get_past_votes(&user, &30), 100_0000000i128);
+    }
+
+    // ── Governance-controlled parameter tests ───────────────────────────
+
+    #[test]
+    fn test_transfer_fee() {
+        let (env, client, admin, user) = setup();
+        let treasury = Address::generate(&env);
+        client.set_transfer_fee(&200, &treasury);
+        assert_eq!(client.transfer_fee_bps(), 200);
+        assert_eq!(client.fee_recipient(), Some(treasury.clone()));
+
+        client.transfer(&admin, &user, &100_0000000i128);
+        assert_eq!(client.balance(&user), 98_0000000i128);
+        assert_eq!(client.balance(&treasury), 2_0000000i128);
+
+        // The fee recipient itself pays no fee
+        client.transfer(&treasury, &user, &2_0000000i128);
+        assert_eq!(client.balance(&user), 100_0000000i128);
+
+        client.set_transfer_fee(&0, &treasury);
+        assert_eq!(client.transfer_fee_bps(), 0);
+        assert_eq!(client.fee_recipient(), None);
+    }
+
+    #[test]
+    fn test_fee_exempt_accounts_pay_no_fee() {
+        let (env, client, admin, user) = setup();
+        let (treasury, escrow) = (Address::generate(&env), Address::generate(&env));
+        client.set_transfer_fee(&200, &treasury);
+        client.set_fee_exempt(&escrow, &true);
+        assert!(client.is_fee_exempt(&escrow));
+
+        // Neither into nor out of an exempt account
+        client.transfer(&admin, &escrow, &100_0000000i128);
+        assert_eq!(client.balance(&escrow), 100_0000000i128);
+        client.transfer(&escrow, &user, &100_0000000i128);
+        assert_eq!(client.balance(&user), 100_0000000i128);
+        assert_eq!(client.balance(&treasury), 0);
+
+        client.set_fee_exempt(&escrow, &false);
+        assert!(!client.is_fee_exempt(&escrow));
+        client.transfer(&user, &escrow, &100_0000000i128);
+        assert_eq!(client.balance(&escrow), 98_0000000i128);
+    }
+
+    #[test]
+    #[should_panic(expected = "fee_bps exceeds maximum")]
+    fn test_transfer_fee_above_maximum_panics() {
+        let (env, client, _, _) = setup();
+        client.set_transfer_fee(&(MAX_TRANSFER_FEE_BPS + 1), &Address::generate(&env));
+    }
+
+    #[test]
+    fn test_reduce_max_supply() {
+        let (_, client, _, _) = setup_with_cap();
+        client.reduce_max_supply(&800_0000000i128);
+        assert_eq!(client.max_supply(), Some(800_0000000i128));
+        assert!(client.try_reduce_max_supply(&900_0000000i128).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "max_supply below total supply")]
+    fn test_reduce_max_supply_below_supply_panics() {
+        let (_, client, _, _) = setup();
+        client.reduce_max_supply(&1i128);
+    }
+
+    // ── Pause registry tests ────────────────────────────────────────────
+
+    #[contract]
+    struct MockPauseRegistry;
+
+    #[contractimpl]
+    impl MockPauseRegistry {
+        pub fn set_paused(env: Env, contract: Address, paused: bool) {
+            env.storage().instance().set(&contract, &paused);
+        }
+
+        pub fn is_paused(env: Env, contract: Address) -> bool {
+            env.storage().instance().get(&contract).unwrap_or(false)
+        }
+    }
+
+    #[test]
+    fn test_pause_registry_halts_transfers() {
+        let (env, client, admin, user) = setup();
+        let registry = MockPauseRegistryClient::new(&env, &env.register_contract(None, MockPauseRegistry));
+        client.set_pause_registry(&registry.address);
+        assert_eq!(client.pause_registry(), Some(registry.address.clone()));
+
+        registry.set_paused(&client.address, &true);
+        assert!(client.is_paused());
+        assert!(client.try_transfer(&admin, &user, &1i128).is_err());
+
+        registry.set_paused(&client.address, &false);
+        client.transfer(&admin, &user, &1i128);
+        assert_eq!(client.balance(&user), 1i128);
+    }
+
+    // ── Restricted mode tests ───────────────────────────────────────────
+
+    #[contract]
+    struct MockKycRegistry;
+
+    #[contractimpl]
+    impl MockKycRegistry {
+        pub fn set_level(env: Env, account: Address, level: u32) {
+            env.storage().instance().set(&account, &level);
+        }
+
+        pub fn is_verified(env: Env, account: Address, level: u32) -> bool {
+            env.storage().instance().get::<_, u32>(&account).unwrap_or(0) >= level
+        }
+    }
+
+    #[test]
+    fn test_restricted_mode_requires_kyc() {
+        let (env, client, admin, user) = setup();
+        let kyc = MockKycRegistryClient::new(&env, &env.register_contract(None, MockKycRegistry));
+        client.set_restricted(&Some(kyc.address.clone()), &1);
+        assert_eq!(client.kyc_level(), 1);
+
+        kyc.set_level(&admin, &1);
+        assert!(client.try_transfer(&admin, &user, &1i128).is_err());
+
+        kyc.set_level(&user, &1);
+        client.transfer(&admin, &user, &1i128);
+        assert_eq!(client.balance(&user), 1i128);
+
+        // Both sides are checked
+        kyc.set_level(&admin, &0);
+        assert!(client.try_transfer(&user, &admin, &1i128).is_err());
+
+        client.set_restricted(&None, &0);
+        assert_eq!(client.kyc_registry(), None);
+        client.transfer(&user, &admin, &1i128);
+    }
+
+    // ── Mint throttle tests ─────────────────────────────────────────────
+
+    #[test]
+    fn test_mint_limit_throttles_per_window() {
+        let (env, client, _, user) = setup();
+        env.ledger().set_sequence_number(100);
+        client.set_mint_limit(&Some(RateLimit::fixed(1_000i128, 100)));
+        assert_eq!(client.mint_available(), Some(1_000i128));
+
+        client.mint(&user, &600i128);
+        assert_eq!(client.mint_available(), Some(400i128));
+        assert!(client.try_mint(&user, &401i128).is_err());
+
+        env.ledger().set_sequence_number(200);
+        client.mint(&user, &1_000i128);
+        assert_eq!(client.balance(&user), 1_600i128);
+
+        client.set_mint_limit(&None);
+        assert_eq!(client.mint_available(), None);
+        client.mint(&user, &1_000i128);
+    }
+
+    // ── Account layout tests ────────────────────────────────────────────
+
+    /// Turn `client` back into a token from before `AccountState`, with
+    /// `user` holding `balance` in the old entries, frozen and delegating
+    /// to `delegatee`.
+    fn unpack(
+        env: &Env,
+        client: &TokenContractClient,
+        user: &Address,
+        balance: i128,
+        delegatee: &Address,
+    ) {
+        env.as_contract(&client.address, || {
+            let storage = env.storage().persistent();
+            env.storage().instance().remove(&DataKey::PackedAccounts);
+            storage.set(&DataKey::Balance(user.clone()), &balance);
+            storage.set(&DataKey::Frozen(user.clone()), &true);
+            storage.set(&DataKey::IsHolder(user.clone()), &true);
+            storage.set(&DataKey::Delegate(user.clone()), delegatee);
+        });
+    }
+
+    fn stored_account(
+        env: &Env,
+        client: &TokenContractClient,
+        user: &Address,
+    ) -> Option<AccountState> {
+        env.as_contract(&client.address, || {
+            assert!(!env.storage().persistent().has(&DataKey::Balance(user.clone())));
+            env.storage().persistent().get(&DataKey::Account(user.clone()))
+        })
+    }
+
+    #[test]
+    fn test_unpacked_account_is_read_and_packed_on_write() {
+        let (env, client, _, user) = setup();
+        let delegatee = Address::generate(&env);
+        unpack(&env, &client, &user, 500i128, &delegatee);
+        assert_eq!(client.balance(&user), 500i128);
+        assert!(client.is_frozen(&user));
+        assert_eq!(client.delegates(&user), delegatee);
+
+        client.unfreeze_account(&user);
+        let expected = AccountState {
+            balance: 500i128,
+            frozen: false,
+            holder: true,
+            delegate: Some(delegatee),
+        };
+        assert_eq!(stored_account(&env, &client, &user), Some(expected));
+        client.transfer(&user, &Address::generate(&env), &200i128);
+        assert_eq!(client.balance(&user), 300i128);
+    }
+
+    #[test]
+    fn test_migrate_accounts_packs_old_entries() {
+        let (env, client, _, user) = setup();
+        let (delegatee, other) = (Address::generate(&env), Address::generate(&env));
+        unpack(&env, &client, &user, 500i128, &delegatee);
+
+        client.migrate_accounts(&soroban_sdk::vec![&env, user.clone(), other.clone()]);
+        assert_eq!(stored_account(&env, &client, &user).map(|a| a.balance), Some(500i128));
+        assert!(client.is_frozen(&user));
+        // Accounts without old entries get no new one
+        assert_eq!(stored_account(&env, &client, &other), None);
+    }
+    // ── Storage tier tests ──────────────────────────────────────────────
+
+    fn allowance_ttl(
+        env: &Env,
+        client: &TokenContractClient,
+        from: &Address,
+        spender: &Address,
+    ) -> u32 {
+        let key = DataKey::Approval(from.clone(), spender.clone());
+        env.as_contract(&client.address, || env.storage().temporary().get_ttl(&key))
+    }
+
+    #[test]
+    fn test_allowance_lives_until_expiration() {
+        let (env, client, admin, spender) = setup();
+        env.ledger().set_sequence_number(100);
+        client.approve(&admin, &spender, &1000i128, &5_000u32);
+        assert_eq!(allowance_ttl(&env, &client, &admin, &spender), 4_900);
+
+        // Spending keeps the expiration and the TTL
+        client.transfer_from(&spender, &admin, &spender, &400i128);
+        assert_eq!(client.allowance(&admin, &spender), 600i128);
+        assert_eq!(allowance_ttl(&env, &client, &admin, &spender), 4_900);
+
+        env.ledger().set_sequence_number(5_001);
+        assert_eq!(client.allowance(&admin, &spender), 0);
+        env.as_contract(&client.address, || {
+            let key = DataKey::Approval(admin.clone(), spender.clone());
+            assert!(!env.storage().temporary().has(&key));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "live_until is past the maximum TTL")]
+    fn test_approve_past_max_ttl_panics() {
+        let (env, client, admin, spender) = setup();
+        let too_far = env.ledger().max_live_until_ledger() + 1;
+        client.approve(&admin, &spender, &1000i128, &too_far);
+    }
+
+    #[test]
+    fn test_persistent_allowance_is_read_and_moved_on_write() {
+        let (env, client, admin, spender) = setup();
+        let other = Address::generate(&env);
+        env.as_contract(&client.address, || {
+            let storage = env.storage().persistent();
+            env.storage().instance().remove(&DataKey::TemporaryAllowances);
+            storage.set(&DataKey::Allowance(admin.clone(), spender.clone()), &1000i128);
+            storage.set(&DataKey::AllowanceExpiration(admin.clone(), spender.clone()), &50u32);
+            // Approved before expiries were kept
+            storage.set(&DataKey::Allowance(admin.clone(), other.clone()), &1000i128);
+        });
+        assert_eq!(client.allowance(&admin, &spender), 1000i128);
+
+        client.transfer_from(&spender, &admin, &spender, &400i128);
+        client.transfer_from(&other, &admin, &other, &400i128);
+        assert_eq!(client.allowance(&admin, &spender), 600i128);
+        assert_eq!(client.allowance(&admin, &other), 600i128);
+        env.as_contract(&client.address, || {
+            let storage = env.storage().persistent();
+            assert!(!storage.has(&DataKey::Allowance(admin.clone(), spender.clone())));
+            assert!(!storage.has(&DataKey::AllowanceExpiration(admin.clone(), spender.clone())));
+            assert_eq!(
+                env.storage().temporary().get(&DataKey::Approval(admin.clone(), spender.clone())),
+                Some((600i128, 50u32))
+            );
+            // Without an expiration it cannot go to temporary storage
+            assert!(storage.has(&DataKey::Allowance(admin.clone(), other.clone())));
+        });
+
+        env.ledger().set_sequence_number(51);
+        assert_eq!(client.allowance(&admin, &spender), 0);
+        assert_eq!(client.allowance(&admin, &other), 600i128);
+    }
+
+    #[test]
+    fn test_accounts_and_instance_bumped_on_write() {
+        let (env, client, admin, user) = setup();
+        client.transfer(&admin, &user, &100i128);
+        let ttls = || {
+            env.as_contract(&client.address, || {
+                (
+                    env.storage().instance().get_ttl(),
+                    env.storage().persistent().get_ttl(&DataKey::Account(user.clone())),
+                )
+            })
+        };
+        assert_eq!(ttls(), (ttl::INSTANCE_BUMP, ttl::PERSISTENT_BUMP));
+
+        // Reads alone leave the TTLs running down
+        let elapsed = 10 * ttl::DAY_IN_LEDGERS;
+        env.ledger().set_sequence_number(elapsed);
+        assert_eq!(client.balance(&user), 100i128);
+        assert_eq!(ttls(), (ttl::INSTANCE_BUMP - elapsed, ttl::PERSISTENT_BUMP - elapsed));
+
+        client.transfer(&user, &admin, &1i128);
+        assert_eq!(ttls(), (ttl::INSTANCE_BUMP, ttl::PERSISTENT_BUMP));
+    }
 }
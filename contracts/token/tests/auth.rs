@@ -0,0 +1,215 @@
+//! # Authorization Matrix for the Token
+//!
+//! Every entry point that needs someone's authorization is called twice
+//! with the same arguments: once authorized only by an outsider, which
+//! must be rejected, and once authorized only by the address the entry
+//! point is meant to require, which must succeed. The second call proves
+//! the first was rejected for the missing signature and not for anything
+//! else, so a dropped `require_auth` or admin check fails here.
+//!
+//! `upgrade` only gets the outsider call: the authorized one needs
+//! uploaded wasm, which native tests don't have.
+
+use launchpad_rate_limit::RateLimit;
+use launchpad_testutils::{mock_auth, test_env, TokenFixture};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, IntoVal, String, Val, Vec};
+use soroban_token::TokenContractClient;
+
+const SUPPLY: i128 = 1_000_000;
+
+struct Setup {
+    env: Env,
+    token: TokenContractClient<'static>,
+    admin: Address,
+    /// Holds tokens and has approved `spender` for 1 000 of them.
+    holder: Address,
+    spender: Address,
+    outsider: Address,
+}
+
+fn setup() -> Setup {
+    let env = test_env();
+    let fixture = TokenFixture::launch_token(&env, SUPPLY);
+    let token = TokenContractClient::new(&env, &fixture.address);
+    let holder = fixture.funded(10_000);
+    let spender = Address::generate(&env);
+    token.approve(&holder, &spender, &1_000, &100);
+    Setup {
+        token,
+        admin: fixture.admin,
+        holder,
+        spender,
+        outsider: Address::generate(&env),
+        env,
+    }
+}
+
+impl Setup {
+    /// Panic unless `call` fails when only the outsider authorizes it and
+    /// succeeds when only `signer` does.
+    fn assert_requires<A, T, E>(
+        &self,
+        signer: &Address,
+        fn_name: &str,
+        args: A,
+        call: impl Fn() -> Result<T, E>,
+    ) where
+        A: IntoVal<Env, Vec<Val>> + Clone,
+    {
+        self.assert_rejects_outsider(fn_name, args.clone(), &call);
+        mock_auth(&self.env, signer, &self.token.address, fn_name, args);
+        assert!(call().is_ok(), "{fn_name} rejected its signer");
+    }
+
+    /// Panic unless `call` fails when only the outsider authorizes it.
+    fn assert_rejects_outsider<A, T, E>(
+        &self,
+        fn_name: &str,
+        args: A,
+        call: impl Fn() -> Result<T, E>,
+    ) where
+        A: IntoVal<Env, Vec<Val>>,
+    {
+        mock_auth(
+            &self.env,
+            &self.outsider,
+            &self.token.address,
+            fn_name,
+            args,
+        );
+        assert!(call().is_err(), "{fn_name} accepted an outsider");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Holders and spenders
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_holder_entry_points() {
+    let s = setup();
+    let (holder, spender, outsider) = (&s.holder, &s.spender, &s.outsider);
+
+    s.assert_requires(holder, "transfer", (holder, outsider, 10i128), || {
+        s.token.try_transfer(holder, outsider, &10)
+    });
+    s.assert_requires(holder, "burn", (holder, 10i128), || {
+        s.token.try_burn(holder, &10)
+    });
+    s.assert_requires(
+        holder,
+        "approve",
+        (holder, spender, 500i128, 100u32),
+        || s.token.try_approve(holder, spender, &500, &100),
+    );
+    s.assert_requires(holder, "delegate", (holder, spender), || {
+        s.token.try_delegate(holder, spender)
+    });
+}
+
+#[test]
+fn test_spender_entry_points() {
+    let s = setup();
+    let (holder, spender, outsider) = (&s.holder, &s.spender, &s.outsider);
+
+    s.assert_requires(
+        spender,
+        "transfer_from",
+        (spender, holder, outsider, 10i128),
+        || s.token.try_transfer_from(spender, holder, outsider, &10),
+    );
+    s.assert_requires(spender, "burn_from", (spender, holder, 10i128), || {
+        s.token.try_burn_from(spender, holder, &10)
+    });
+}
+
+// ---------------------------------------------------------------------------
+// Admin
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_supply_admin_entry_points() {
+    let s = setup();
+    let (admin, holder) = (&s.admin, &s.holder);
+
+    s.assert_requires(admin, "mint", (holder, 10i128), || {
+        s.token.try_mint(holder, &10)
+    });
+    s.assert_requires(admin, "burn_admin", (holder, 10i128), || {
+        s.token.try_burn_admin(holder, &10)
+    });
+    s.assert_requires(admin, "reduce_max_supply", (SUPPLY * 2,), || {
+        s.token.try_reduce_max_supply(&(SUPPLY * 2))
+    });
+    let limit = Some(RateLimit::fixed(1_000, 100));
+    s.assert_requires(admin, "set_mint_limit", (limit.clone(),), || {
+        s.token.try_set_mint_limit(&limit)
+    });
+}
+
+#[test]
+fn test_account_admin_entry_points() {
+    let s = setup();
+    let (admin, holder) = (&s.admin, &s.holder);
+
+    s.assert_requires(admin, "freeze_account", (holder,), || {
+        s.token.try_freeze_account(holder)
+    });
+    s.assert_requires(admin, "unfreeze_account", (holder,), || {
+        s.token.try_unfreeze_account(holder)
+    });
+    s.assert_requires(admin, "pause", (), || s.token.try_pause());
+    s.assert_requires(admin, "unpause", (), || s.token.try_unpause());
+}
+
+#[test]
+fn test_configuration_admin_entry_points() {
+    let s = setup();
+    let admin = &s.admin;
+    let (treasury, registry) = (Address::generate(&s.env), Address::generate(&s.env));
+
+    let uri = String::from_str(&s.env, "https://example.com/token.json");
+    s.assert_requires(admin, "update_contract_uri", (uri.clone(),), || {
+        s.token.try_update_contract_uri(&uri)
+    });
+    s.assert_requires(admin, "set_transfer_fee", (100u32, &treasury), || {
+        s.token.try_set_transfer_fee(&100, &treasury)
+    });
+    s.assert_requires(admin, "set_restricted", (None::<Address>, 0u32), || {
+        s.token.try_set_restricted(&None, &0)
+    });
+    s.assert_requires(admin, "set_pause_registry", (&registry,), || {
+        s.token.try_set_pause_registry(&registry)
+    });
+}
+
+#[test]
+fn test_admin_transfer_entry_points() {
+    let s = setup();
+    let (admin, successor) = (&s.admin, &s.holder);
+
+    s.assert_requires(admin, "set_admin", (admin,), || {
+        s.token.try_set_admin(admin)
+    });
+    s.assert_requires(admin, "propose_admin", (successor,), || {
+        s.token.try_propose_admin(successor)
+    });
+    s.assert_requires(successor, "accept_admin", (), || s.token.try_accept_admin());
+    assert_eq!(s.token.admin(), *successor);
+}
+
+#[test]
+fn test_upgrade_entry_points() {
+    let s = setup();
+    let (admin, upgrader) = (&s.admin, &s.spender);
+
+    s.assert_requires(admin, "set_upgrader", (upgrader,), || {
+        s.token.try_set_upgrader(upgrader)
+    });
+    // From now on only the upgrader can hand the role on
+    s.assert_requires(upgrader, "set_upgrader", (upgrader,), || {
+        s.token.try_set_upgrader(upgrader)
+    });
+    let hash = BytesN::from_array(&s.env, &[0; 32]);
+    s.assert_rejects_outsider("upgrade", (hash.clone(),), || s.token.try_upgrade(&hash));
+}
@@ -0,0 +1,318 @@
+//! # Token Event Tests
+//!
+//! Every state-changing entry point is called once and the event it
+//! publishes is checked topic by topic and payload by payload, along with
+//! how many events the call published. Indexers and wallets read the token
+//! only through these events, so a missing or reshaped one is a breaking
+//! change even when balances stay right.
+//!
+//! `upgrade` is left out: it needs uploaded wasm, which native tests don't
+//! have. Its event comes from `launchpad-access` and is tested there.
+
+use launchpad_events as events;
+use launchpad_rate_limit::RateLimit;
+use launchpad_testutils::{
+    assert_emitted, assert_sole_event, events_during, test_env, TokenFixture,
+};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, IntoVal, String, Val, Vec};
+use soroban_token::{TokenContract, TokenContractClient};
+
+const SUPPLY: i128 = 1_000_000;
+
+struct Setup {
+    env: Env,
+    token: TokenContractClient<'static>,
+    admin: Address,
+    alice: Address,
+}
+
+/// A token with `SUPPLY` minted to its admin, and an empty account.
+fn setup() -> Setup {
+    let env = test_env();
+    let fixture = TokenFixture::launch_token(&env, SUPPLY);
+    let token = TokenContractClient::new(&env, &fixture.address);
+    let alice = Address::generate(&env);
+    Setup {
+        admin: fixture.admin,
+        token,
+        alice,
+        env,
+    }
+}
+
+impl Setup {
+    /// Panic unless `call` makes the token publish exactly this one event.
+    fn assert_event<R, T, D>(&self, call: impl FnOnce() -> R, topics: T, data: D)
+    where
+        T: IntoVal<Env, Vec<Val>>,
+        D: IntoVal<Env, Val>,
+    {
+        assert_sole_event(&self.env, &self.token.address, call, topics, data);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Initialization and supply
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_initialize_events() {
+    let env = test_env();
+    let admin = Address::generate(&env);
+    let token = TokenContractClient::new(&env, &env.register_contract(None, TokenContract));
+    let events = events_during(&env, &token.address, || {
+        token.initialize(
+            &admin,
+            &7,
+            &String::from_str(&env, "Launch Token"),
+            &String::from_str(&env, "LAUNCH"),
+            &SUPPLY,
+            &None,
+        )
+    });
+
+    assert_eq!(events.len(), 2);
+    assert_emitted(&env, &token.address, (events::MINT, admin.clone()), SUPPLY);
+    assert_emitted(&env, &token.address, (symbol_short!("init"),), admin);
+}
+
+#[test]
+fn test_mint_event() {
+    let s = setup();
+    s.assert_event(
+        || s.token.mint(&s.alice, &500),
+        (events::MINT, s.alice.clone()),
+        500i128,
+    );
+}
+
+#[test]
+fn test_burn_events() {
+    let s = setup();
+    s.assert_event(
+        || s.token.burn(&s.admin, &300),
+        (events::BURN, s.admin.clone()),
+        300i128,
+    );
+    s.assert_event(
+        || s.token.burn_admin(&s.admin, &200),
+        (events::BURN, s.admin.clone()),
+        200i128,
+    );
+}
+
+#[test]
+fn test_reduce_max_supply_event() {
+    let s = setup();
+    s.assert_event(
+        || s.token.reduce_max_supply(&(SUPPLY * 2)),
+        (symbol_short!("max_sup"),),
+        SUPPLY * 2,
+    );
+}
+
+#[test]
+fn test_set_mint_limit_events() {
+    let s = setup();
+    let limit = RateLimit::fixed(1_000, 100);
+    s.assert_event(
+        || s.token.set_mint_limit(&Some(limit.clone())),
+        (symbol_short!("mint_lim"),),
+        Some(limit.clone()),
+    );
+    s.assert_event(
+        || s.token.set_mint_limit(&None),
+        (symbol_short!("mint_lim"),),
+        None::<RateLimit>,
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Transfers and allowances
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_transfer_event() {
+    let s = setup();
+    s.assert_event(
+        || s.token.transfer(&s.admin, &s.alice, &1_000),
+        (events::TRANSFER, s.admin.clone(), s.alice.clone()),
+        1_000i128,
+    );
+}
+
+#[test]
+fn test_transfer_with_fee_events() {
+    let s = setup();
+    let treasury = Address::generate(&s.env);
+    s.assert_event(
+        || s.token.set_transfer_fee(&100, &treasury),
+        (symbol_short!("fee"), treasury.clone()),
+        100u32,
+    );
+
+    // The fee and the rest move as two transfers
+    let events = events_during(&s.env, &s.token.address, || {
+        s.token.transfer(&s.admin, &s.alice, &1_000)
+    });
+    assert_eq!(events.len(), 2);
+    let (admin, alice) = (s.admin.clone(), s.alice.clone());
+    assert_emitted(
+        &s.env,
+        &s.token.address,
+        (events::TRANSFER, admin.clone(), treasury),
+        10i128,
+    );
+    assert_emitted(
+        &s.env,
+        &s.token.address,
+        (events::TRANSFER, admin, alice),
+        990i128,
+    );
+}
+
+#[test]
+fn test_fee_exempt_event() {
+    let s = setup();
+    s.assert_event(
+        || s.token.set_fee_exempt(&s.alice, &true),
+        (symbol_short!("fee_exmpt"), s.alice.clone()),
+        true,
+    );
+}
+
+#[test]
+fn test_allowance_events() {
+    let s = setup();
+    let spender = Address::generate(&s.env);
+    s.assert_event(
+        || s.token.approve(&s.admin, &spender, &1_000, &100),
+        (events::APPROVE, s.admin.clone(), spender.clone()),
+        1_000i128,
+    );
+    s.assert_event(
+        || s.token.transfer_from(&spender, &s.admin, &s.alice, &400),
+        (events::TRANSFER, s.admin.clone(), s.alice.clone()),
+        400i128,
+    );
+    s.assert_event(
+        || s.token.burn_from(&spender, &s.admin, &600),
+        (events::BURN, s.admin.clone()),
+        600i128,
+    );
+}
+
+#[test]
+fn test_delegate_event() {
+    let s = setup();
+    s.assert_event(
+        || s.token.delegate(&s.admin, &s.alice),
+        (symbol_short!("delegate"), s.admin.clone()),
+        (s.admin.clone(), s.alice.clone()),
+    );
+}
+
+#[test]
+fn test_snapshot_event() {
+    let s = setup();
+    s.assert_event(
+        || s.token.snapshot(),
+        (symbol_short!("snapshot"),),
+        (0u32, s.env.ledger().sequence()),
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Administration
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_admin_transfer_events() {
+    let s = setup();
+    s.assert_event(
+        || s.token.propose_admin(&s.alice),
+        (symbol_short!("adm_prop"),),
+        s.alice.clone(),
+    );
+    s.assert_event(
+        || s.token.accept_admin(),
+        (symbol_short!("set_admin"),),
+        s.alice.clone(),
+    );
+    s.assert_event(
+        || s.token.set_admin(&s.admin),
+        (symbol_short!("set_admin"),),
+        s.admin.clone(),
+    );
+}
+
+#[test]
+fn test_set_upgrader_event() {
+    let s = setup();
+    s.assert_event(
+        || s.token.set_upgrader(&s.alice),
+        (symbol_short!("upgrader"),),
+        s.alice.clone(),
+    );
+}
+
+#[test]
+fn test_freeze_events() {
+    let s = setup();
+    s.assert_event(
+        || s.token.freeze_account(&s.alice),
+        (symbol_short!("freeze"), s.alice.clone()),
+        true,
+    );
+    s.assert_event(
+        || s.token.unfreeze_account(&s.alice),
+        (symbol_short!("freeze"), s.alice.clone()),
+        false,
+    );
+}
+
+#[test]
+fn test_pause_events() {
+    let s = setup();
+    s.assert_event(|| s.token.pause(), (symbol_short!("pause"),), true);
+    s.assert_event(|| s.token.unpause(), (symbol_short!("pause"),), false);
+}
+
+#[test]
+fn test_configuration_events() {
+    let s = setup();
+    let uri = String::from_str(&s.env, "https://example.com/token.json");
+    s.assert_event(
+        || s.token.update_contract_uri(&uri),
+        (symbol_short!("uri"),),
+        uri.clone(),
+    );
+
+    let registry = Address::generate(&s.env);
+    s.assert_event(
+        || s.token.set_pause_registry(&registry),
+        (symbol_short!("pause_reg"),),
+        registry.clone(),
+    );
+    s.assert_event(
+        || s.token.set_restricted(&Some(registry.clone()), &2),
+        (symbol_short!("restrict"),),
+        (Some(registry.clone()), 2u32),
+    );
+    s.assert_event(
+        || s.token.set_restricted(&None, &0),
+        (symbol_short!("restrict"),),
+        (None::<Address>, 0u32),
+    );
+}
+
+#[test]
+fn test_read_only_calls_are_silent() {
+    let s = setup();
+    let events = events_during(&s.env, &s.token.address, || {
+        s.token.balance(&s.admin);
+        s.token.allowance(&s.admin, &s.alice);
+        s.token.total_supply();
+    });
+    assert!(events.is_empty());
+}
@@ -33,11 +33,31 @@
 //!
 //! 8. **Overflow Protection**
 //!    Operations whose result would overflow `i128` must revert rather than
-//!    wrapping silently.
+//!    wrapping silently. Amounts drawn near `i128::MAX`, `i128::MIN` and the
+//!    supply cap either move exactly what a checked model predicts,
+//!    transfer fees included, or revert and change nothing.
+//!
+//! 9. **Spends Never Exceed Approvals**
+//!    Across any interleaving of `approve`, `transfer_from` and ledger
+//!    advances, the amount `transfer_from` moves after an `approve` never
+//!    exceeds the amount approved; a new `approve` replaces the allowance
+//!    rather than adding to it.
+//!
+//! 10. **Expired Allowances Are Unusable**
+//!     Once the ledger passes `expiration_ledger`, `allowance` reads zero
+//!     and every `transfer_from` reverts.
+//!
+//! 11. **Frozen Balances Only Fall by Admin Burn**
+//!     While an account is frozen, no `transfer`, `transfer_from`, `burn` or
+//!     `burn_from`, by any spender, lowers its balance; only `burn_admin`
+//!     does, and by exactly the amount burned.
 
+#![allow(clippy::inconsistent_digit_grouping)]
+
+use launchpad_testutils::TokenFixture;
 use proptest::prelude::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
-use soroban_token::{TokenContract, TokenContractClient};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, Env, String};
+use soroban_token::{TokenContract, TokenContractClient, MAX_TRANSFER_FEE_BPS};
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -55,23 +75,13 @@ fn setup_env() -> (Env, TokenContractClient<'static>, Address, Address, Address)
     let env = Env::default();
     env.mock_all_auths();
 
-    let id = env.register_contract(None, TokenContract);
-    let client = TokenContractClient::new(&env, &id);
+    let token = TokenFixture::launch_token(&env, INITIAL_SUPPLY);
+    let client = TokenContractClient::new(&env, &token.address);
 
-    let admin = Address::generate(&env);
     let user1 = Address::generate(&env);
     let user2 = Address::generate(&env);
 
-    client.initialize(
-        &admin,
-        &7u32,
-        &String::from_str(&env, "FuzzToken"),
-        &String::from_str(&env, "FZT"),
-        &INITIAL_SUPPLY,
-        &None,
-    );
-
-    (env, client, admin, user1, user2)
+    (env, client, token.admin, user1, user2)
 }
 
 /// Create a capped token environment.
@@ -132,6 +142,127 @@ fn assert_max_supply_invariant(client: &TokenContractClient) {
     }
 }
 
+/// One step of a random allowance history.
+#[derive(Clone, Debug)]
+enum AllowanceOp {
+    /// Approve `amount`, usable for `valid_for` more ledgers.
+    Approve { amount: i128, valid_for: u32 },
+    TransferFrom(i128),
+    /// Advance the ledger.
+    Wait(u32),
+}
+
+fn any_allowance_op() -> impl Strategy<Value = AllowanceOp> {
+    prop_oneof![
+        (0i128..=1_000, 0u32..20)
+            .prop_map(|(amount, valid_for)| AllowanceOp::Approve { amount, valid_for }),
+        (1i128..=600).prop_map(AllowanceOp::TransferFrom),
+        (1u32..15).prop_map(AllowanceOp::Wait),
+    ]
+}
+
+/// Create an uncapped token with `initial` minted to the admin, or a
+/// capped one.
+fn setup_boundary_env(
+    initial: i128,
+    cap: Option<i128>,
+) -> (Env, TokenContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let id = env.register_contract(None, TokenContract);
+    let client = TokenContractClient::new(&env, &id);
+    let admin = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &7u32,
+        &String::from_str(&env, "BoundaryFuzz"),
+        &String::from_str(&env, "BFZ"),
+        &initial,
+        &cap,
+    );
+
+    (env, client, admin)
+}
+
+/// Amounts clustered where `i128` arithmetic breaks: at and just below
+/// `i128::MAX`, around half of it (two of them overflow), small amounts,
+/// and zero or negative ones that must be rejected outright.
+fn boundary_amount() -> impl Strategy<Value = i128> {
+    prop_oneof![
+        Just(i128::MAX),
+        (i128::MAX - 1_000)..=i128::MAX,
+        (i128::MAX / 2 - 1_000)..=(i128::MAX / 2 + 1_000),
+        1i128..=1_000,
+        Just(0i128),
+        Just(i128::MIN),
+        (i128::MIN..=i128::MIN + 1_000),
+    ]
+}
+
+/// `bps` basis points of the non-negative `amount`, computed without ever
+/// forming `amount * bps`.
+fn reference_fee(amount: i128, bps: u32) -> i128 {
+    let bps = bps as i128;
+    amount / 10_000 * bps + amount % 10_000 * bps / 10_000
+}
+
+/// One step of a random history of transfers between `accounts` (fee
+/// recipient last) with boundary amounts.
+#[derive(Clone, Debug)]
+enum BoundaryOp {
+    Transfer { from: usize, to: usize, amount: i128 },
+    Approve { from: usize, spender: usize, amount: i128 },
+    TransferFrom { spender: usize, from: usize, to: usize, amount: i128 },
+    Burn { from: usize, amount: i128 },
+}
+
+fn any_boundary_op() -> impl Strategy<Value = BoundaryOp> {
+    let account = 0usize..4;
+    prop_oneof![
+        (account.clone(), account.clone(), boundary_amount())
+            .prop_map(|(from, to, amount)| BoundaryOp::Transfer { from, to, amount }),
+        (account.clone(), account.clone(), boundary_amount())
+            .prop_map(|(from, spender, amount)| BoundaryOp::Approve { from, spender, amount }),
+        (account.clone(), account.clone(), account.clone(), boundary_amount()).prop_map(
+            |(spender, from, to, amount)| BoundaryOp::TransferFrom { spender, from, to, amount }
+        ),
+        (account, boundary_amount())
+            .prop_map(|(from, amount)| BoundaryOp::Burn { from, amount }),
+    ]
+}
+
+/// One step of a random history against the frozen account, `user1`.
+/// Spender 0 is the admin, spender 1 is `user2`.
+#[derive(Clone, Debug)]
+enum FrozenOp {
+    Transfer(i128),
+    TransferFrom { spender: usize, amount: i128 },
+    Burn(i128),
+    BurnFrom { spender: usize, amount: i128 },
+    Approve { spender: usize, amount: i128 },
+    /// The admin sends the frozen account tokens.
+    Receive(i128),
+    BurnAdmin(i128),
+}
+
+fn any_frozen_op() -> impl Strategy<Value = FrozenOp> {
+    let amount = 1i128..=2_000;
+    prop_oneof![
+        amount.clone().prop_map(FrozenOp::Transfer),
+        (0usize..2, amount.clone())
+            .prop_map(|(spender, amount)| FrozenOp::TransferFrom { spender, amount }),
+        amount.clone().prop_map(FrozenOp::Burn),
+        (0usize..2, amount.clone())
+            .prop_map(|(spender, amount)| FrozenOp::BurnFrom { spender, amount }),
+        (0usize..2, amount.clone())
+            .prop_map(|(spender, amount)| FrozenOp::Approve { spender, amount }),
+        amount.clone().prop_map(FrozenOp::Receive),
+        amount.prop_map(FrozenOp::BurnAdmin),
+    ]
+}
+
 // ===========================================================================
 // Property tests
 // ===========================================================================
@@ -326,6 +457,278 @@ proptest! {
         assert_max_supply_invariant(&client);
         assert_supply_invariant(&client, &[&admin, &user]);
     }
+
+    // ── Allowances ──────────────────────────────────────────────────────
+
+    /// Invariants 9 and 10: a random approve / transfer_from / wait history
+    /// behaves exactly like a model allowance with an expiry.
+    #[test]
+    fn prop_allowance_history_matches_model(
+        ops in prop::collection::vec(any_allowance_op(), 1..40),
+    ) {
+        let (env, client, admin, spender, to) = setup_env();
+        let mut now        = 0u32;
+        let mut approved   = 0i128;
+        let mut remaining  = 0i128;
+        let mut expiration = 0u32;
+        let mut spent      = 0i128;
+        let mut total      = 0i128;
+
+        for op in ops {
+            match op {
+                AllowanceOp::Approve { amount, valid_for } => {
+                    expiration = now + valid_for;
+                    client.approve(&admin, &spender, &amount, &expiration);
+                    approved  = amount;
+                    remaining = amount;
+                    spent     = 0;
+                }
+                AllowanceOp::TransferFrom(amount) => {
+                    let expected = now <= expiration && amount <= remaining;
+                    let moved = client.try_transfer_from(&spender, &admin, &to, &amount).is_ok();
+                    prop_assert_eq!(moved, expected);
+                    if moved {
+                        remaining -= amount;
+                        spent     += amount;
+                        total     += amount;
+                    }
+                    prop_assert!(spent <= approved);
+                }
+                AllowanceOp::Wait(ledgers) => {
+                    now += ledgers;
+                    env.ledger().set_sequence_number(now);
+                }
+            }
+            let live = if now <= expiration { remaining } else { 0 };
+            prop_assert_eq!(client.allowance(&admin, &spender), live);
+        }
+
+        prop_assert_eq!(client.balance(&to), total);
+        assert_supply_invariant(&client, &[&admin, &spender, &to]);
+    }
+
+    /// Invariant 10: after its expiration ledger an allowance is unusable.
+    #[test]
+    fn prop_expired_allowance_is_unusable(
+        amount    in 1i128..=INITIAL_SUPPLY,
+        valid_for in 0u32..1_000,
+        after     in 1u32..1_000,
+    ) {
+        let (env, client, admin, spender, to) = setup_env();
+        client.approve(&admin, &spender, &amount, &valid_for);
+        env.ledger().set_sequence_number(valid_for + after);
+
+        prop_assert_eq!(client.allowance(&admin, &spender), 0);
+        prop_assert!(client.try_transfer_from(&spender, &admin, &to, &1).is_err());
+        prop_assert_eq!(client.balance(&admin), INITIAL_SUPPLY);
+    }
+
+    /// Invariant 9: re-approving sets the allowance, it does not add to it.
+    #[test]
+    fn prop_reapprove_replaces_allowance(
+        first     in 1i128..=1_000_000i128,
+        second    in 1i128..=1_000_000i128,
+        spend_pct in 0u32..=100u32,
+    ) {
+        let (_, client, admin, spender, to) = setup_env();
+        client.approve(&admin, &spender, &first, &100);
+        let spend = (first as u128 * spend_pct as u128 / 100) as i128;
+        if spend > 0 {
+            client.transfer_from(&spender, &admin, &to, &spend);
+        }
+
+        client.approve(&admin, &spender, &second, &100);
+        prop_assert_eq!(client.allowance(&admin, &spender), second);
+        prop_assert!(client.try_transfer_from(&spender, &admin, &to, &(second + 1)).is_err());
+        client.transfer_from(&spender, &admin, &to, &second);
+        prop_assert_eq!(client.allowance(&admin, &spender), 0);
+        prop_assert_eq!(client.balance(&to), spend + second);
+    }
+
+    // ── Overflow ────────────────────────────────────────────────────────
+
+    /// Invariant 8: mints of boundary amounts, with or without a cap near
+    /// `i128::MAX`, succeed exactly when the checked supply fits.
+    #[test]
+    fn prop_boundary_mints_never_wrap(
+        initial in prop_oneof![Just(0i128), boundary_amount().prop_filter("non-negative", |a| *a >= 0)],
+        cap     in prop_oneof![Just(None), boundary_amount().prop_map(Some)],
+        amounts in prop::collection::vec(boundary_amount(), 1..8),
+    ) {
+        prop_assume!(cap.is_none_or(|cap| cap > 0 && initial <= cap));
+        let (env, client, admin) = setup_boundary_env(initial, cap);
+        let user = Address::generate(&env);
+        let mut supply = initial;
+
+        for amount in amounts {
+            let expected = amount > 0
+                && supply
+                    .checked_add(amount)
+                    .is_some_and(|next| cap.is_none_or(|cap| next <= cap));
+            prop_assert_eq!(client.try_mint(&user, &amount).is_ok(), expected);
+            if expected {
+                supply += amount;
+            }
+            prop_assert_eq!(client.total_supply(), supply);
+            prop_assert_eq!(client.balance(&user), supply - initial);
+        }
+
+        assert_supply_invariant(&client, &[&admin, &user]);
+        assert_max_supply_invariant(&client);
+    }
+
+    /// Invariant 8: with the whole `i128` range minted and a transfer fee
+    /// charged, boundary transfers, approvals, spends and burns match a
+    /// checked model exactly.
+    #[test]
+    fn prop_boundary_transfers_match_checked_model(
+        fee_bps in 0u32..=MAX_TRANSFER_FEE_BPS,
+        ops     in prop::collection::vec(any_boundary_op(), 1..12),
+    ) {
+        let (env, client, admin) = setup_boundary_env(i128::MAX, None);
+        let accounts = [
+            admin.clone(),
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        let recipient = 3;
+        client.set_transfer_fee(&fee_bps, &accounts[recipient]);
+        let mut balances   = [i128::MAX, 0, 0, 0];
+        let mut allowances = [[0i128; 4]; 4];
+        let mut supply     = i128::MAX;
+
+        // Debit `amount` from `from`, charging the fee the token would
+        let pay = |balances: &mut [i128; 4], from: usize, to: usize, amount: i128| {
+            let fee = reference_fee(amount, fee_bps);
+            if fee > 0 && from != recipient && to != recipient {
+                balances[from] -= fee;
+                balances[recipient] += fee;
+                balances[from] -= amount - fee;
+                balances[to] += amount - fee;
+            } else {
+                balances[from] -= amount;
+                balances[to] += amount;
+            }
+        };
+
+        for op in ops {
+            match op {
+                BoundaryOp::Transfer { from, to, amount } => {
+                    let expected = amount > 0 && balances[from] >= amount;
+                    let moved = client.try_transfer(&accounts[from], &accounts[to], &amount).is_ok();
+                    prop_assert_eq!(moved, expected);
+                    if expected {
+                        pay(&mut balances, from, to, amount);
+                    }
+                }
+                BoundaryOp::Approve { from, spender, amount } => {
+                    let expected = amount >= 0;
+                    let approved = client
+                        .try_approve(&accounts[from], &accounts[spender], &amount, &1_000)
+                        .is_ok();
+                    prop_assert_eq!(approved, expected);
+                    if expected {
+                        allowances[from][spender] = amount;
+                    }
+                }
+                BoundaryOp::TransferFrom { spender, from, to, amount } => {
+                    let expected = amount > 0
+                        && allowances[from][spender] >= amount
+                        && balances[from] >= amount;
+                    let moved = client
+                        .try_transfer_from(&accounts[spender], &accounts[from], &accounts[to], &amount)
+                        .is_ok();
+                    prop_assert_eq!(moved, expected);
+                    if expected {
+                        allowances[from][spender] -= amount;
+                        pay(&mut balances, from, to, amount);
+                    }
+                }
+                BoundaryOp::Burn { from, amount } => {
+                    let expected = amount > 0 && balances[from] >= amount;
+                    prop_assert_eq!(client.try_burn(&accounts[from], &amount).is_ok(), expected);
+                    if expected {
+                        balances[from] -= amount;
+                        supply -= amount;
+                    }
+                }
+            }
+
+            prop_assert_eq!(client.total_supply(), supply);
+            for (owner, address) in accounts.iter().enumerate() {
+                prop_assert_eq!(client.balance(address), balances[owner]);
+                for (spender, spender_address) in accounts.iter().enumerate() {
+                    prop_assert_eq!(
+                        client.allowance(address, spender_address),
+                        allowances[owner][spender]
+                    );
+                }
+            }
+        }
+
+        let tracked: [&Address; 4] = core::array::from_fn(|i| &accounts[i]);
+        assert_supply_invariant(&client, &tracked);
+        assert_non_negative_balances(&client, &tracked);
+    }
+
+    // ── Frozen accounts ─────────────────────────────────────────────────
+
+    /// Invariant 11: once frozen, an account holding tokens and allowances
+    /// for both spenders loses tokens only to `burn_admin`.
+    #[test]
+    fn prop_frozen_balance_only_falls_by_admin_burn(
+        ops in prop::collection::vec(any_frozen_op(), 1..30),
+    ) {
+        let (_, client, admin, frozen, user2) = setup_env();
+        let spenders = [&admin, &user2];
+        client.transfer(&admin, &frozen, &10_000);
+        for spender in spenders {
+            client.approve(&frozen, spender, &10_000, &1_000);
+        }
+        client.freeze_account(&frozen);
+
+        for op in ops {
+            let before = client.balance(&frozen);
+            let burned = match op {
+                FrozenOp::Transfer(amount) => {
+                    prop_assert!(client.try_transfer(&frozen, &user2, &amount).is_err());
+                    0
+                }
+                FrozenOp::TransferFrom { spender, amount } => {
+                    let spender = spenders[spender];
+                    prop_assert!(client.try_transfer_from(spender, &frozen, &admin, &amount).is_err());
+                    0
+                }
+                FrozenOp::Burn(amount) => {
+                    prop_assert!(client.try_burn(&frozen, &amount).is_err());
+                    0
+                }
+                FrozenOp::BurnFrom { spender, amount } => {
+                    prop_assert!(client.try_burn_from(spenders[spender], &frozen, &amount).is_err());
+                    0
+                }
+                FrozenOp::Approve { spender, amount } => {
+                    client.approve(&frozen, spenders[spender], &amount, &1_000);
+                    0
+                }
+                FrozenOp::Receive(amount) => {
+                    client.transfer(&admin, &frozen, &amount);
+                    prop_assert_eq!(client.balance(&frozen), before + amount);
+                    0
+                }
+                FrozenOp::BurnAdmin(amount) => {
+                    if client.try_burn_admin(&frozen, &amount).is_ok() { amount } else { 0 }
+                }
+            };
+            prop_assert!(client.is_frozen(&frozen));
+            if !matches!(op, FrozenOp::Receive(_)) {
+                prop_assert_eq!(client.balance(&frozen), before - burned);
+            }
+        }
+
+        assert_supply_invariant(&client, &[&admin, &frozen, &user2]);
+    }
 }
 
 // ===========================================================================
@@ -429,3 +832,24 @@ fn test_mint_exceeds_max_supply_rejected() {
     // Remaining capacity is 500_0000000 — minting 1 more overflows the cap.
     client.mint(&user, &(500_0000001i128));
 }
+
+// ===========================================================================
+// Allowance expiry edge cases (Invariant 10)
+// ===========================================================================
+
+#[test]
+#[should_panic(expected = "expiration_ledger is in the past")]
+fn test_approve_with_past_expiration_rejected() {
+    let (env, client, admin, spender, _) = setup_env();
+    env.ledger().set_sequence_number(10);
+    client.approve(&admin, &spender, &100i128, &9u32);
+}
+
+#[test]
+fn test_zero_approval_with_past_expiration_revokes() {
+    let (env, client, admin, spender, _) = setup_env();
+    client.approve(&admin, &spender, &100i128, &20u32);
+    env.ledger().set_sequence_number(10);
+    client.approve(&admin, &spender, &0i128, &0u32);
+    assert_eq!(client.allowance(&admin, &spender), 0);
+}
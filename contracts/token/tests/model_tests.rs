@@ -0,0 +1,296 @@
+//! # Stateful Model-Based Tests for the Token
+//!
+//! The fuzz tests check one property per operation. Here random sequences
+//! mixing every balance-changing operation are run against a
+//! `TokenContract` and, side by side, against `Model`, a few lines of plain
+//! Rust holding what the token should contain. After every step the two
+//! must agree on the outcome and on the whole observable state.
+//!
+//! ## Invariants
+//!
+//! 1. **Same Outcome**
+//!    An operation succeeds on the contract exactly when the model accepts
+//!    it, and a rejected operation changes nothing.
+//!
+//! 2. **Same State**
+//!    Every balance, every allowance, every frozen flag and the total
+//!    supply match the model.
+//!
+//! Accounts are indices into `Setup::accounts`; account 0 is the admin.
+//! Allowances never expire within a sequence, as the allowance fuzz tests
+//! already cover expiry.
+
+use launchpad_testutils::test_env;
+use proptest::prelude::*;
+use soroban_sdk::{testutils::Address as _, Address, String};
+use soroban_token::{TokenContract, TokenContractClient};
+
+/// Accounts the sequences use, the admin included.
+const ACCOUNTS: usize = 4;
+
+/// Supply minted to the admin at initialization.
+const INITIAL_SUPPLY: i128 = 10_000;
+
+/// Expiration ledger of every approval.
+const EXPIRATION: u32 = 1_000;
+
+/// One step of a random sequence.
+#[derive(Clone, Debug)]
+enum Op {
+    Mint {
+        to: usize,
+        amount: i128,
+    },
+    Burn {
+        from: usize,
+        amount: i128,
+    },
+    Transfer {
+        from: usize,
+        to: usize,
+        amount: i128,
+    },
+    Approve {
+        from: usize,
+        spender: usize,
+        amount: i128,
+    },
+    TransferFrom {
+        spender: usize,
+        from: usize,
+        to: usize,
+        amount: i128,
+    },
+    Freeze(usize),
+    Unfreeze(usize),
+}
+
+fn any_op() -> impl Strategy<Value = Op> {
+    let account = 0..ACCOUNTS;
+    // Zero and negative amounts must be rejected like any other bad input
+    let amount = -10i128..=6_000;
+    prop_oneof![
+        (account.clone(), amount.clone()).prop_map(|(to, amount)| Op::Mint { to, amount }),
+        (account.clone(), amount.clone()).prop_map(|(from, amount)| Op::Burn { from, amount }),
+        (account.clone(), account.clone(), amount.clone())
+            .prop_map(|(from, to, amount)| Op::Transfer { from, to, amount }),
+        (account.clone(), account.clone(), amount.clone()).prop_map(|(from, spender, amount)| {
+            Op::Approve {
+                from,
+                spender,
+                amount,
+            }
+        }),
+        (account.clone(), account.clone(), account.clone(), amount).prop_map(
+            |(spender, from, to, amount)| Op::TransferFrom {
+                spender,
+                from,
+                to,
+                amount
+            }
+        ),
+        account.clone().prop_map(Op::Freeze),
+        account.prop_map(Op::Unfreeze),
+    ]
+}
+
+// ---------------------------------------------------------------------------
+// Reference model
+// ---------------------------------------------------------------------------
+
+/// What the token should hold.
+#[derive(Clone, Debug, Default)]
+struct Model {
+    balances: [i128; ACCOUNTS],
+    /// `allowances[owner][spender]`.
+    allowances: [[i128; ACCOUNTS]; ACCOUNTS],
+    frozen: [bool; ACCOUNTS],
+    supply: i128,
+}
+
+impl Model {
+    fn new() -> Self {
+        let mut model = Self::default();
+        model.balances[0] = INITIAL_SUPPLY;
+        model.supply = INITIAL_SUPPLY;
+        model
+    }
+
+    /// Apply `op` if the token should accept it, and report whether it
+    /// should.
+    fn apply(&mut self, op: &Op) -> bool {
+        match *op {
+            Op::Mint { to, amount } => {
+                if amount <= 0 {
+                    return false;
+                }
+                self.balances[to] += amount;
+                self.supply += amount;
+            }
+            Op::Burn { from, amount } => {
+                if amount <= 0 || self.frozen[from] || self.balances[from] < amount {
+                    return false;
+                }
+                self.balances[from] -= amount;
+                self.supply -= amount;
+            }
+            Op::Transfer { from, to, amount } => {
+                if amount <= 0 || self.frozen[from] || self.balances[from] < amount {
+                    return false;
+                }
+                self.balances[from] -= amount;
+                self.balances[to] += amount;
+            }
+            Op::Approve {
+                from,
+                spender,
+                amount,
+            } => {
+                if amount < 0 {
+                    return false;
+                }
+                self.allowances[from][spender] = amount;
+            }
+            Op::TransferFrom {
+                spender,
+                from,
+                to,
+                amount,
+            } => {
+                if amount <= 0
+                    || self.frozen[from]
+                    || self.allowances[from][spender] < amount
+                    || self.balances[from] < amount
+                {
+                    return false;
+                }
+                self.allowances[from][spender] -= amount;
+                self.balances[from] -= amount;
+                self.balances[to] += amount;
+            }
+            Op::Freeze(account) => self.frozen[account] = true,
+            Op::Unfreeze(account) => self.frozen[account] = false,
+        }
+        true
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Contract under test
+// ---------------------------------------------------------------------------
+
+struct Setup {
+    client: TokenContractClient<'static>,
+    accounts: [Address; ACCOUNTS],
+}
+
+/// A token with `INITIAL_SUPPLY` minted to account 0, its admin.
+fn setup() -> Setup {
+    let env = test_env();
+    // Long sequences outgrow the default per-test budget
+    env.budget().reset_unlimited();
+    let client = TokenContractClient::new(&env, &env.register_contract(None, TokenContract));
+    let accounts: [Address; ACCOUNTS] = core::array::from_fn(|_| Address::generate(&env));
+    client.initialize(
+        &accounts[0],
+        &7,
+        &String::from_str(&env, "ModelToken"),
+        &String::from_str(&env, "MDL"),
+        &INITIAL_SUPPLY,
+        &None,
+    );
+    Setup { client, accounts }
+}
+
+impl Setup {
+    /// Apply `op` to the contract and report whether it succeeded.
+    fn apply(&self, op: &Op) -> bool {
+        let a = &self.accounts;
+        let client = &self.client;
+        match *op {
+            Op::Mint { to, amount } => client.try_mint(&a[to], &amount).is_ok(),
+            Op::Burn { from, amount } => client.try_burn(&a[from], &amount).is_ok(),
+            Op::Transfer { from, to, amount } => {
+                client.try_transfer(&a[from], &a[to], &amount).is_ok()
+            }
+            Op::Approve {
+                from,
+                spender,
+                amount,
+            } => client
+                .try_approve(&a[from], &a[spender], &amount, &EXPIRATION)
+                .is_ok(),
+            Op::TransferFrom {
+                spender,
+                from,
+                to,
+                amount,
+            } => client
+                .try_transfer_from(&a[spender], &a[from], &a[to], &amount)
+                .is_ok(),
+            Op::Freeze(account) => client.try_freeze_account(&a[account]).is_ok(),
+            Op::Unfreeze(account) => client.try_unfreeze_account(&a[account]).is_ok(),
+        }
+    }
+
+    /// Panic unless the contract's state is exactly `model`.
+    fn assert_matches(&self, model: &Model) {
+        assert_eq!(self.client.total_supply(), model.supply);
+        for (owner, address) in self.accounts.iter().enumerate() {
+            assert_eq!(self.client.balance(address), model.balances[owner]);
+            assert_eq!(self.client.is_frozen(address), model.frozen[owner]);
+            for (spender, spender_address) in self.accounts.iter().enumerate() {
+                assert_eq!(
+                    self.client.allowance(address, spender_address),
+                    model.allowances[owner][spender]
+                );
+            }
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    /// Invariants 1 and 2 after every step of a random sequence.
+    #[test]
+    fn prop_token_matches_model(ops in prop::collection::vec(any_op(), 1..30)) {
+        let s = setup();
+        let mut model = Model::new();
+        for op in &ops {
+            let expected = model.apply(op);
+            prop_assert_eq!(s.apply(op), expected, "outcomes differ on {:?}", op);
+            s.assert_matches(&model);
+        }
+        // The model never lets the supply drift from the balances
+        prop_assert_eq!(model.balances.iter().sum::<i128>(), model.supply);
+    }
+}
+
+#[test]
+fn test_frozen_account_can_receive_but_not_spend() {
+    let s = setup();
+    let mut model = Model::new();
+    for op in [
+        Op::Freeze(1),
+        Op::Transfer {
+            from: 0,
+            to: 1,
+            amount: 500,
+        },
+        Op::Transfer {
+            from: 1,
+            to: 2,
+            amount: 100,
+        },
+        Op::Burn {
+            from: 1,
+            amount: 100,
+        },
+    ] {
+        let expected = model.apply(&op);
+        assert_eq!(s.apply(&op), expected, "outcomes differ on {op:?}");
+    }
+    s.assert_matches(&model);
+    assert_eq!(model.balances[1], 500);
+}
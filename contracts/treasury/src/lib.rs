@@ -0,0 +1,452 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, token, Address, Env, String, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    SpendDelay,
+    Spender(Address),
+    Tokens,
+    SpendCount,
+    Spend(u32),
+    Spent(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Spend {
+    pub id: u32,
+    pub proposer: Address,
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub memo: String,
+    /// First ledger on which the spend can be executed.
+    pub ready_ledger: u32,
+    pub executed: bool,
+    pub cancelled: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Treasury Contract — where platform fees land and how they are spent.
+///
+/// Set as the fee recipient of sales and tokens, the treasury collects fees
+/// in any token. Money only leaves through spend proposals: the admin,
+/// normally the governance timelock, or an address it granted the spender
+/// role `propose_spend`s a payment, which anyone can `execute_spend` once
+/// `spend_delay` ledgers have passed. The admin can `cancel_spend` during
+/// the delay. The delay can be raised but never lowered, so a spend always
+/// gets at least the notice the treasury was set up with.
+///
+/// `deposit` only takes tokens the admin listed with `track_token`, so
+/// nobody else can grow the `tokens` list.
+///
+/// Every spend is kept, so the full spending history can be read back
+/// alongside the current balances.
+#[contract]
+pub struct TreasuryContract;
+
+#[contractimpl]
+impl TreasuryContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, admin: Address, spend_delay: u32) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::SpendDelay, &spend_delay);
+        env.storage().instance().set(&DataKey::SpendCount, &0u32);
+
+        env.events()
+            .publish((symbol_short!("init"),), (admin, spend_delay));
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Grant or revoke the spender role. Admin only.
+    pub fn set_spender(env: Env, spender: Address, allowed: bool) {
+        Self::_require_admin(&env);
+        let key = DataKey::Spender(spender.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        env.events()
+            .publish((symbol_short!("spender"), spender), allowed);
+    }
+
+    /// Raise how many ledgers future spends wait. Admin only.
+    pub fn set_spend_delay(env: Env, spend_delay: u32) {
+        Self::_require_admin(&env);
+        assert!(
+            spend_delay >= Self::spend_delay(env.clone()),
+            "delay cannot decrease"
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::SpendDelay, &spend_delay);
+
+        env.events().publish((symbol_short!("delay"),), spend_delay);
+    }
+
+    /// List `token` in `tokens` and accept it in `deposit`, e.g. for fees
+    /// paid by plain transfer. Admin only.
+    pub fn track_token(env: Env, token: Address) {
+        Self::_require_admin(&env);
+        Self::_track(&env, &token);
+    }
+
+    /// Drop a pending spend. Admin only.
+    pub fn cancel_spend(env: Env, spend_id: u32) {
+        Self::_require_admin(&env);
+        let mut spend = Self::get_spend(env.clone(), spend_id);
+        assert!(!spend.executed, "already executed");
+        assert!(!spend.cancelled, "already cancelled");
+        spend.cancelled = true;
+        Self::_save(&env, &spend);
+
+        env.events()
+            .publish((symbol_short!("cancel"), spend_id), ());
+    }
+
+    // ── Deposits ────────────────────────────────────────────────────────
+
+    /// Pay `amount` of `token` into the treasury. The token must be tracked.
+    pub fn deposit(env: Env, from: Address, token: Address, amount: i128) {
+        from.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        assert!(
+            Self::tokens(env.clone()).contains(&token),
+            "token not tracked"
+        );
+        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+
+        env.events()
+            .publish((symbol_short!("deposit"), from, token), amount);
+    }
+
+    // ── Spending ────────────────────────────────────────────────────────
+
+    /// Propose paying `amount` of `token` to `to` after the spend delay.
+    /// Admin or spenders only. Returns the spend id.
+    pub fn propose_spend(
+        env: Env,
+        proposer: Address,
+        token: Address,
+        to: Address,
+        amount: i128,
+        memo: String,
+    ) -> u32 {
+        proposer.require_auth();
+        assert!(
+            proposer == Self::admin(env.clone()) || Self::is_spender(env.clone(), proposer.clone()),
+            "not a spender"
+        );
+        assert!(amount > 0, "amount must be positive");
+
+        let id = Self::spend_count(env.clone());
+        let ready_ledger = env.ledger().sequence() + Self::spend_delay(env.clone());
+        let spend = Spend {
+            id,
+            proposer: proposer.clone(),
+            token,
+            to,
+            amount,
+            memo,
+            ready_ledger,
+            executed: false,
+            cancelled: false,
+        };
+        Self::_save(&env, &spend);
+        env.storage()
+            .instance()
+            .set(&DataKey::SpendCount, &(id + 1));
+
+        env.events()
+            .publish((symbol_short!("propose"), proposer), (id, ready_ledger));
+        id
+    }
+
+    /// Make a spend whose delay has passed. Anyone may call.
+    pub fn execute_spend(env: Env, spend_id: u32) {
+        let mut spend = Self::get_spend(env.clone(), spend_id);
+        assert!(!spend.cancelled, "spend cancelled");
+        assert!(!spend.executed, "already executed");
+        assert!(
+            env.ledger().sequence() >= spend.ready_ledger,
+            "spend not ready"
+        );
+        spend.executed = true;
+        Self::_save(&env, &spend);
+        let spent = Self::total_spent(env.clone(), spend.token.clone()) + spend.amount;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Spent(spend.token.clone()), &spent);
+
+        token::Client::new(&env, &spend.token).transfer(
+            &env.current_contract_address(),
+            &spend.to,
+            &spend.amount,
+        );
+
+        env.events()
+            .publish((symbol_short!("spend"), spend.to), (spend_id, spend.amount));
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Current holdings of `token`.
+    pub fn balance(env: Env, token: Address) -> i128 {
+        token::Client::new(&env, &token).balance(&env.current_contract_address())
+    }
+
+    /// Tokens the admin listed with `track_token`.
+    pub fn tokens(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Tokens)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Total of `token` paid out by executed spends.
+    pub fn total_spent(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Spent(token))
+            .unwrap_or(0)
+    }
+
+    pub fn get_spend(env: Env, spend_id: u32) -> Spend {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Spend(spend_id))
+            .expect("no spend found")
+    }
+
+    /// Up to `limit` spends in proposal order, starting at id `start`.
+    pub fn spends(env: Env, start: u32, limit: u32) -> Vec<Spend> {
+        let count = Self::spend_count(env.clone());
+        let end = start.saturating_add(limit).min(count);
+        let mut result = Vec::new(&env);
+        for id in start..end {
+            result.push_back(Self::get_spend(env.clone(), id));
+        }
+        result
+    }
+
+    pub fn spend_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SpendCount)
+            .expect("not initialized")
+    }
+
+    pub fn spend_delay(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SpendDelay)
+            .expect("not initialized")
+    }
+
+    pub fn is_spender(env: Env, spender: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Spender(spender))
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _require_admin(env: &Env) {
+        Self::admin(env.clone()).require_auth();
+    }
+
+    fn _save(env: &Env, spend: &Spend) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Spend(spend.id), spend);
+    }
+
+    fn _track(env: &Env, token: &Address) {
+        let mut tokens = Self::tokens(env.clone());
+        if !tokens.contains(token) {
+            tokens.push_back(token.clone());
+            env.storage().instance().set(&DataKey::Tokens, &tokens);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{
+        testutils::Address as _, testutils::Ledger, testutils::MockAuth, testutils::MockAuthInvoke,
+        IntoVal,
+    };
+
+    struct Setup {
+        env: Env,
+        client: TreasuryContractClient<'static>,
+        token: TokenFixture,
+        admin: Address,
+    }
+
+    /// Spends wait 100 ledgers; the treasury holds 5 000 tokens paid in as
+    /// fees. The ledger starts at 10.
+    fn setup() -> Setup {
+        let env = test_env();
+        env.ledger().set_sequence_number(10);
+
+        let admin = Address::generate(&env);
+        let token = TokenFixture::stellar_asset(&env);
+        let contract_id = env.register_contract(None, TreasuryContract);
+        let client = TreasuryContractClient::new(&env, &contract_id);
+        client.initialize(&admin, &100);
+        token.mint(&contract_id, 5_000);
+
+        Setup {
+            client,
+            token,
+            env,
+            admin,
+        }
+    }
+
+    fn propose(s: &Setup, proposer: &Address, to: &Address, amount: i128) -> u32 {
+        s.client.propose_spend(
+            proposer,
+            &s.token.address,
+            to,
+            &amount,
+            &String::from_str(&s.env, "audit"),
+        )
+    }
+
+    #[test]
+    fn test_deposit_tracked_token() {
+        let s = setup();
+        let payer = Address::generate(&s.env);
+        s.token.mint(&payer, 1_000);
+        assert!(s.client.tokens().is_empty());
+        assert!(s
+            .client
+            .try_deposit(&payer, &s.token.address, &600)
+            .is_err());
+
+        s.client.track_token(&s.token.address);
+        s.client.track_token(&s.token.address);
+        s.client.deposit(&payer, &s.token.address, &600);
+        s.client.deposit(&payer, &s.token.address, &400);
+        assert_eq!(s.client.tokens().len(), 1);
+        assert_eq!(s.client.balance(&s.token.address), 6_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "delay cannot decrease")]
+    fn test_spend_delay_only_grows() {
+        let s = setup();
+        s.client.set_spend_delay(&200);
+        s.client.set_spend_delay(&0);
+    }
+
+    #[test]
+    fn test_spend_after_delay() {
+        let s = setup();
+        let auditor = Address::generate(&s.env);
+        let id = propose(&s, &s.admin, &auditor, 2_000);
+        assert_eq!(s.client.get_spend(&id).ready_ledger, 110);
+        assert!(s.client.try_execute_spend(&id).is_err());
+
+        s.env.ledger().set_sequence_number(110);
+        s.client.execute_spend(&id);
+        assert_eq!(s.token.balance(&auditor), 2_000);
+        assert_eq!(s.client.balance(&s.token.address), 3_000);
+        assert_eq!(s.client.total_spent(&s.token.address), 2_000);
+        assert!(s.client.try_execute_spend(&id).is_err());
+    }
+
+    #[test]
+    fn test_spender_role() {
+        let s = setup();
+        let spender = Address::generate(&s.env);
+        let to = Address::generate(&s.env);
+        let memo = String::from_str(&s.env, "grant");
+        assert!(s
+            .client
+            .try_propose_spend(&spender, &s.token.address, &to, &500, &memo)
+            .is_err());
+
+        s.client.set_spender(&spender, &true);
+        let id = propose(&s, &spender, &to, 500);
+        assert_eq!(s.client.get_spend(&id).proposer, spender);
+
+        s.client.set_spender(&spender, &false);
+        assert!(!s.client.is_spender(&spender));
+    }
+
+    #[test]
+    #[should_panic(expected = "spend cancelled")]
+    fn test_cancelled_spend_cannot_execute() {
+        let s = setup();
+        let id = propose(&s, &s.admin, &Address::generate(&s.env), 2_000);
+        s.client.cancel_spend(&id);
+        s.env.ledger().set_sequence_number(110);
+        s.client.execute_spend(&id);
+    }
+
+    #[test]
+    fn test_spend_history() {
+        let s = setup();
+        let to = Address::generate(&s.env);
+        for amount in [100, 200, 300] {
+            propose(&s, &s.admin, &to, amount);
+        }
+        let page = s.client.spends(&1, &5);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get(0).unwrap().amount, 200);
+        assert_eq!(s.client.spend_count(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_non_admin_cannot_cancel() {
+        let s = setup();
+        let id = propose(&s, &s.admin, &Address::generate(&s.env), 2_000);
+        let outsider = Address::generate(&s.env);
+        s.env.mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "cancel_spend",
+                args: (id,).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.cancel_spend(&id);
+    }
+}
@@ -0,0 +1,372 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, token, Address, Env, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Owner,
+    Guardian,
+    Delay,
+    WithdrawalCount,
+    Withdrawal(u32),
+    /// Ids of announced withdrawals not yet executed, cancelled or halted.
+    Queue,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+pub enum WithdrawalStatus {
+    Pending,
+    Executed,
+    /// Withdrawn by the owner.
+    Cancelled,
+    /// Stopped by the guardian.
+    Halted,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Withdrawal {
+    pub id: u32,
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub announced_ledger: u32,
+    /// First ledger on which the withdrawal can be executed.
+    pub ready_ledger: u32,
+    pub status: WithdrawalStatus,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Treasury Vault Contract — a project treasury that cannot be drained
+/// silently.
+///
+/// A project parks the funds it raised here. The owner (the project team)
+/// can only take money out by `announce`ing a withdrawal, which publishes
+/// an event and joins the `queue`, and executing it at least `delay`
+/// ledgers later. The delay can be raised but never lowered, so buyers
+/// always get the same warning before funds move.
+///
+/// During the delay the platform guardian can `halt` a withdrawal for
+/// good, e.g. one that looks like a rug right after a raise; the owner can
+/// also `cancel` its own. Deposits need no announcement.
+#[contract]
+pub struct TreasuryVaultContract;
+
+#[contractimpl]
+impl TreasuryVaultContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, owner: Address, guardian: Address, delay: u32) {
+        if env.storage().instance().has(&DataKey::Owner) {
+            panic!("already initialized");
+        }
+        assert!(delay > 0, "delay must be positive");
+        env.storage().instance().set(&DataKey::Owner, &owner);
+        env.storage().instance().set(&DataKey::Guardian, &guardian);
+        env.storage().instance().set(&DataKey::Delay, &delay);
+
+        env.events()
+            .publish((symbol_short!("init"),), (owner, guardian, delay));
+    }
+
+    // ── Funding ─────────────────────────────────────────────────────────
+
+    /// Add `amount` of `token` from `from`. Plain transfers count too.
+    pub fn deposit(env: Env, from: Address, token: Address, amount: i128) {
+        from.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+        env.events()
+            .publish((symbol_short!("deposit"), from, token), amount);
+    }
+
+    // ── Owner ───────────────────────────────────────────────────────────
+
+    /// Announce a withdrawal of `amount` of `token` to `to`, executable
+    /// `delay` ledgers from now. Owner only. Returns the withdrawal id.
+    pub fn announce(env: Env, token: Address, to: Address, amount: i128) -> u32 {
+        Self::owner(env.clone()).require_auth();
+        assert!(amount > 0, "amount must be positive");
+
+        let id = Self::withdrawal_count(env.clone());
+        let announced_ledger = env.ledger().sequence();
+        let withdrawal = Withdrawal {
+            id,
+            token: token.clone(),
+            to: to.clone(),
+            amount,
+            announced_ledger,
+            ready_ledger: announced_ledger + Self::delay(env.clone()),
+            status: WithdrawalStatus::Pending,
+        };
+        Self::_save(&env, &withdrawal);
+        env.storage()
+            .instance()
+            .set(&DataKey::WithdrawalCount, &(id + 1));
+        let mut queue = Self::queue(env.clone());
+        queue.push_back(id);
+        env.storage().instance().set(&DataKey::Queue, &queue);
+
+        env.events().publish(
+            (symbol_short!("announce"), token, to),
+            (id, amount, withdrawal.ready_ledger),
+        );
+        id
+    }
+
+    /// Withdraw an announced withdrawal. Owner only.
+    pub fn cancel(env: Env, withdrawal_id: u32) {
+        Self::owner(env.clone()).require_auth();
+        Self::_close(&env, withdrawal_id, WithdrawalStatus::Cancelled);
+        env.events()
+            .publish((symbol_short!("cancel"),), withdrawal_id);
+    }
+
+    /// Raise the notice period of future withdrawals. Owner only.
+    pub fn set_delay(env: Env, delay: u32) {
+        Self::owner(env.clone()).require_auth();
+        assert!(delay >= Self::delay(env.clone()), "delay cannot decrease");
+        env.storage().instance().set(&DataKey::Delay, &delay);
+        env.events().publish((symbol_short!("delay"),), delay);
+    }
+
+    // ── Guardian ────────────────────────────────────────────────────────
+
+    /// Stop an announced withdrawal for good. Guardian only.
+    pub fn halt(env: Env, withdrawal_id: u32) {
+        Self::guardian(env.clone()).require_auth();
+        Self::_close(&env, withdrawal_id, WithdrawalStatus::Halted);
+        env.events()
+            .publish((symbol_short!("halt"),), withdrawal_id);
+    }
+
+    /// Replace the guardian. Guardian only.
+    pub fn set_guardian(env: Env, guardian: Address) {
+        Self::guardian(env.clone()).require_auth();
+        env.storage().instance().set(&DataKey::Guardian, &guardian);
+        env.events().publish((symbol_short!("guardian"),), guardian);
+    }
+
+    // ── Execution ───────────────────────────────────────────────────────
+
+    /// Pay out a withdrawal whose notice period is over. Anyone may call.
+    pub fn execute(env: Env, withdrawal_id: u32) {
+        let withdrawal = Self::get_withdrawal(env.clone(), withdrawal_id);
+        assert!(
+            withdrawal.status == WithdrawalStatus::Pending,
+            "withdrawal not pending"
+        );
+        assert!(
+            env.ledger().sequence() >= withdrawal.ready_ledger,
+            "withdrawal not ready"
+        );
+        Self::_close(&env, withdrawal_id, WithdrawalStatus::Executed);
+        token::Client::new(&env, &withdrawal.token).transfer(
+            &env.current_contract_address(),
+            &withdrawal.to,
+            &withdrawal.amount,
+        );
+
+        env.events().publish(
+            (symbol_short!("withdraw"), withdrawal.token, withdrawal.to),
+            (withdrawal_id, withdrawal.amount),
+        );
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Announced withdrawals still pending, oldest first.
+    pub fn queue(env: Env) -> Vec<u32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Queue)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Withdrawals `start` to `start + limit - 1`, oldest first.
+    pub fn withdrawals(env: Env, start: u32, limit: u32) -> Vec<Withdrawal> {
+        let end = start
+            .saturating_add(limit)
+            .min(Self::withdrawal_count(env.clone()));
+        let mut withdrawals = Vec::new(&env);
+        for id in start..end {
+            withdrawals.push_back(Self::get_withdrawal(env.clone(), id));
+        }
+        withdrawals
+    }
+
+    pub fn get_withdrawal(env: Env, withdrawal_id: u32) -> Withdrawal {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Withdrawal(withdrawal_id))
+            .expect("no withdrawal found")
+    }
+
+    pub fn withdrawal_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::WithdrawalCount)
+            .unwrap_or(0)
+    }
+
+    pub fn balance(env: Env, token: Address) -> i128 {
+        token::Client::new(&env, &token).balance(&env.current_contract_address())
+    }
+
+    pub fn delay(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Delay)
+            .expect("not initialized")
+    }
+
+    pub fn guardian(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Guardian)
+            .expect("not initialized")
+    }
+
+    pub fn owner(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Owner)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _save(env: &Env, withdrawal: &Withdrawal) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Withdrawal(withdrawal.id), withdrawal);
+    }
+
+    /// Move a pending withdrawal to `status` and out of the queue.
+    fn _close(env: &Env, withdrawal_id: u32, status: WithdrawalStatus) {
+        let mut withdrawal = Self::get_withdrawal(env.clone(), withdrawal_id);
+        assert!(
+            withdrawal.status == WithdrawalStatus::Pending,
+            "withdrawal not pending"
+        );
+        withdrawal.status = status;
+        Self::_save(env, &withdrawal);
+
+        let mut queue = Self::queue(env.clone());
+        if let Some(index) = queue.first_index_of(withdrawal_id) {
+            queue.remove(index);
+        }
+        env.storage().instance().set(&DataKey::Queue, &queue);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{assert_emitted, jump_to, test_env, TokenFixture};
+    use soroban_sdk::testutils::Address as _;
+
+    struct Setup {
+        env: Env,
+        client: TreasuryVaultContractClient<'static>,
+        usdc: TokenFixture,
+        team: Address,
+    }
+
+    /// The vault holds 1 000 USDC with a 100-ledger notice period; the
+    /// ledger starts at 10.
+    fn setup() -> Setup {
+        let env = test_env();
+        jump_to(&env, 10);
+
+        let usdc = TokenFixture::stellar_asset(&env);
+        let client = TreasuryVaultContractClient::new(
+            &env,
+            &env.register_contract(None, TreasuryVaultContract),
+        );
+        client.initialize(&Address::generate(&env), &Address::generate(&env), &100);
+        usdc.mint(&client.address, 1_000);
+
+        Setup {
+            usdc,
+            team: Address::generate(&env),
+            env,
+            client,
+        }
+    }
+
+    #[test]
+    fn test_withdrawal_waits_for_notice() {
+        let s = setup();
+        let id = s.client.announce(&s.usdc.address, &s.team, &400);
+        assert_emitted(
+            &s.env,
+            &s.client.address,
+            (
+                symbol_short!("announce"),
+                s.usdc.address.clone(),
+                s.team.clone(),
+            ),
+            (id, 400i128, 110u32),
+        );
+        assert_eq!(s.client.queue().len(), 1);
+        assert_eq!(s.client.get_withdrawal(&id).ready_ledger, 110);
+
+        jump_to(&s.env, 109);
+        assert!(s.client.try_execute(&id).is_err());
+        jump_to(&s.env, 110);
+        s.client.execute(&id);
+
+        assert_eq!(s.usdc.balance(&s.team), 400);
+        assert_eq!(s.client.balance(&s.usdc.address), 600);
+        assert_eq!(s.client.queue().len(), 0);
+        assert!(s.client.try_execute(&id).is_err());
+    }
+
+    #[test]
+    fn test_guardian_halts_withdrawal() {
+        let s = setup();
+        let drain = s.client.announce(&s.usdc.address, &s.team, &1_000);
+        let payroll = s.client.announce(&s.usdc.address, &s.team, &100);
+        s.client.halt(&drain);
+        assert_eq!(s.client.queue(), Vec::from_array(&s.env, [payroll]));
+
+        jump_to(&s.env, 110);
+        assert!(s.client.try_execute(&drain).is_err());
+        assert_eq!(
+            s.client.get_withdrawal(&drain).status,
+            WithdrawalStatus::Halted
+        );
+        s.client.execute(&payroll);
+        assert_eq!(s.usdc.balance(&s.team), 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "delay cannot decrease")]
+    fn test_delay_only_grows() {
+        let s = setup();
+        s.client.set_delay(&200);
+        s.client.set_delay(&150);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_only_owner_announces() {
+        let s = setup();
+        s.env.mock_auths(&[]);
+        s.client.announce(&s.usdc.address, &s.team, &400);
+    }
+}
@@ -0,0 +1,525 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, symbol_short, token, Address, Env, Vec,
+};
+use soropad_math::mul_div;
+
+/// Fixed-point scale of every price: the amount of quote-token units for
+/// `PRICE_SCALE` asset units, so a price equal to `PRICE_SCALE` is 1:1.
+pub const PRICE_SCALE: i128 = 10_000_000;
+
+/// Denominator for all basis-point values.
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Largest share of a window, in bps, that `twap` lets one observation's
+/// price cover until the admin sets another.
+pub const DEFAULT_MAX_SAMPLE_SHARE_BPS: u32 = 2_500;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    /// Most observations kept per asset.
+    Capacity,
+    /// Largest share of a window one observation may cover, in bps.
+    MaxSampleShare,
+    Source(Address),
+    Observations(Address),
+}
+
+/// Where the spot price of an asset is sampled from.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Source {
+    /// The oracle adapter's `price(asset)`.
+    Oracle(Address),
+    /// The asset/quote pool behind a liquidity adapter, priced from the
+    /// pool's token balances.
+    Pool(PoolSource),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PoolSource {
+    pub adapter: Address,
+    pub quote: Address,
+}
+
+/// A price sample and the running sum of price × ledgers up to it.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Observation {
+    pub ledger: u32,
+    pub price: i128,
+    pub cumulative: i128,
+}
+
+/// Price interface of the oracle adapter.
+#[contractclient(name = "OracleAdapterClient")]
+pub trait OracleAdapter {
+    /// Latest price of `asset` as `(price, decimals, timestamp)`.
+    fn price(env: Env, asset: Address) -> (i128, u32, u64);
+}
+
+/// Pool lookup of the liquidity adapter.
+#[contractclient(name = "LiquidityAdapterClient")]
+pub trait LiquidityAdapter {
+    /// LP token of the `token_a`/`token_b` pool, which is the pool itself.
+    fn lp_token(env: Env, token_a: Address, token_b: Address) -> Address;
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// TWAP Contract — time-weighted average prices for the platform.
+///
+/// The admin gives each asset a price source: the oracle adapter, or a
+/// pool reached through the liquidity adapter. Keepers `poke` an asset to
+/// sample its spot price, at most once per ledger. Each observation keeps
+/// the running sum of price × ledgers, so `twap(asset, window)` is the
+/// difference of two sums divided by the window, the price having been
+/// held from one sample until the next.
+///
+/// A price pushed around within one ledger therefore only counts for the
+/// ledgers until the next sample. That alone is not enough: someone can
+/// swap, `poke` and swap back in one transaction, and if no keeper samples
+/// after them the skewed price is held for the rest of the window. So
+/// `twap` refuses any window in which a single observation covers more
+/// than `max_sample_share` of it, which bounds what one sample can do to
+/// the average and lets the buyback vault size its minimum output from
+/// it.
+/// The oldest observations are dropped once an asset has `capacity` of
+/// them, bounding the longest window that can be asked for.
+#[contract]
+pub struct TwapContract;
+
+#[contractimpl]
+impl TwapContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    /// Set the admin and the number of observations kept per asset.
+    pub fn initialize(env: Env, admin: Address, capacity: u32) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        assert!(capacity >= 2, "capacity too small");
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Capacity, &capacity);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Sample `asset` from `source`, dropping observations made from any
+    /// previous source. Admin only.
+    pub fn set_source(env: Env, asset: Address, source: Source) {
+        Self::admin(env.clone()).require_auth();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Source(asset.clone()), &source);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Observations(asset.clone()));
+        env.events()
+            .publish((symbol_short!("source"), asset), source);
+    }
+
+    /// Let one observation cover at most `share_bps` of a `twap` window.
+    /// Admin only.
+    pub fn set_max_sample_share(env: Env, share_bps: u32) {
+        Self::admin(env.clone()).require_auth();
+        assert!(
+            share_bps > 0 && share_bps as i128 <= BPS_DENOMINATOR,
+            "share_bps out of range"
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxSampleShare, &share_bps);
+        env.events().publish((symbol_short!("share"),), share_bps);
+    }
+
+    // ── Keepers ─────────────────────────────────────────────────────────
+
+    /// Sample the spot price of `asset`. Anyone may call; a second call in
+    /// the same ledger changes nothing. Returns the latest observation.
+    pub fn poke(env: Env, asset: Address) -> Observation {
+        let source = Self::source(env.clone(), asset.clone()).expect("no source for asset");
+        let mut observations = Self::observations(env.clone(), asset.clone());
+        let now = env.ledger().sequence();
+        if let Some(last) = observations.last() {
+            if last.ledger == now {
+                return last;
+            }
+        }
+
+        let price = Self::_spot_price(&env, &asset, &source);
+        let cumulative = match observations.last() {
+            Some(last) => last.cumulative + last.price * (now - last.ledger) as i128,
+            None => 0,
+        };
+        let observation = Observation {
+            ledger: now,
+            price,
+            cumulative,
+        };
+        observations.push_back(observation.clone());
+        if observations.len() > Self::capacity(env.clone()) {
+            observations.pop_front();
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Observations(asset.clone()), &observations);
+
+        env.events().publish((symbol_short!("poke"), asset), price);
+        observation
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Average price of `asset` over the last `window` ledgers, in quote
+    /// units per `PRICE_SCALE` asset units. Panics unless an observation
+    /// at least `window` ledgers old is kept, and unless every observation
+    /// in the window covers at most `max_sample_share` of it.
+    pub fn twap(env: Env, asset: Address, window: u32) -> i128 {
+        assert!(window > 0, "window must be positive");
+        let observations = Self::observations(env.clone(), asset);
+        let now = env.ledger().sequence();
+        assert!(now >= window, "window not covered");
+        let start = now - window;
+
+        let mut first = None;
+        for observation in observations.iter() {
+            if observation.ledger > start {
+                break;
+            }
+            first = Some(observation);
+        }
+        let first = first.expect("window not covered");
+        let last = observations.last().expect("window not covered");
+
+        let max_share = Self::max_sample_share(env.clone()) as i128;
+        let mut held_from = start;
+        for observation in observations.iter().filter(|o| o.ledger > start) {
+            Self::_check_share(observation.ledger - held_from, window, max_share);
+            held_from = observation.ledger;
+        }
+        Self::_check_share(now - held_from, window, max_share);
+
+        (Self::_cumulative_at(&last, now) - Self::_cumulative_at(&first, start)) / window as i128
+    }
+
+    /// Longest window `twap` can currently answer for `asset`.
+    pub fn max_window(env: Env, asset: Address) -> u32 {
+        match Self::observations(env.clone(), asset).first() {
+            Some(oldest) => env.ledger().sequence() - oldest.ledger,
+            None => 0,
+        }
+    }
+
+    /// Kept observations of `asset`, oldest first.
+    pub fn observations(env: Env, asset: Address) -> Vec<Observation> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Observations(asset))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn source(env: Env, asset: Address) -> Option<Source> {
+        env.storage().persistent().get(&DataKey::Source(asset))
+    }
+
+    pub fn max_sample_share(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxSampleShare)
+            .unwrap_or(DEFAULT_MAX_SAMPLE_SHARE_BPS)
+    }
+
+    pub fn capacity(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Capacity)
+            .expect("not initialized")
+    }
+
+    pub fn admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("not initialized")
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _spot_price(env: &Env, asset: &Address, source: &Source) -> i128 {
+        let price = match source {
+            Source::Oracle(adapter) => {
+                let (price, decimals, _) = OracleAdapterClient::new(env, adapter).price(asset);
+                mul_div(price, PRICE_SCALE, 10i128.pow(decimals))
+            }
+            Source::Pool(pool) => {
+                let pair =
+                    LiquidityAdapterClient::new(env, &pool.adapter).lp_token(asset, &pool.quote);
+                let reserve = token::Client::new(env, asset).balance(&pair);
+                assert!(reserve > 0, "empty pool");
+                mul_div(
+                    token::Client::new(env, &pool.quote).balance(&pair),
+                    PRICE_SCALE,
+                    reserve,
+                )
+            }
+        };
+        assert!(price > 0, "invalid price");
+        price
+    }
+
+    /// Reject a window in which one price was held for `held` of its
+    /// `window` ledgers, more than `max_share` bps.
+    fn _check_share(held: u32, window: u32, max_share: i128) {
+        assert!(
+            held as i128 * BPS_DENOMINATOR <= max_share * window as i128,
+            "window too sparsely sampled"
+        );
+    }
+
+    /// Running sum of `observation` carried forward to `ledger`.
+    fn _cumulative_at(observation: &Observation, ledger: u32) -> i128 {
+        observation.cumulative + observation.price * (ledger - observation.ledger) as i128
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger, token::StellarAssetClient};
+
+    /// Oracle adapter quoting a settable price with 2 decimals.
+    #[contract]
+    struct MockOracle;
+
+    #[contractimpl]
+    impl MockOracle {
+        pub fn set_price(env: Env, price: i128) {
+            env.storage().instance().set(&0u32, &price);
+        }
+
+        pub fn price(env: Env, _asset: Address) -> (i128, u32, u64) {
+            (env.storage().instance().get(&0u32).unwrap(), 2, 0)
+        }
+    }
+
+    /// Liquidity adapter with a single pool.
+    #[contract]
+    struct MockAdapter;
+
+    #[contractimpl]
+    impl MockAdapter {
+        pub fn set_pair(env: Env, pair: Address) {
+            env.storage().instance().set(&0u32, &pair);
+        }
+
+        pub fn lp_token(env: Env, _token_a: Address, _token_b: Address) -> Address {
+            env.storage().instance().get(&0u32).unwrap()
+        }
+    }
+
+    struct Setup {
+        env: Env,
+        client: TwapContractClient<'static>,
+        oracle: MockOracleClient<'static>,
+        asset: Address,
+    }
+
+    /// An asset priced by the oracle at 1.00, keeping 8 observations,
+    /// starting at ledger 100.
+    fn setup() -> Setup {
+        let env = test_env();
+        env.ledger().set_sequence_number(100);
+
+        let client = TwapContractClient::new(&env, &env.register_contract(None, TwapContract));
+        client.initialize(&Address::generate(&env), &8);
+        let oracle = MockOracleClient::new(&env, &env.register_contract(None, MockOracle));
+        oracle.set_price(&100);
+        let asset = Address::generate(&env);
+        client.set_source(&asset, &Source::Oracle(oracle.address.clone()));
+
+        Setup {
+            env,
+            client,
+            oracle,
+            asset,
+        }
+    }
+
+    /// Poke at each of `ledgers`, in order.
+    fn poke_at(s: &Setup, ledgers: &[u32]) {
+        for ledger in ledgers {
+            s.env.ledger().set_sequence_number(*ledger);
+            s.client.poke(&s.asset);
+        }
+    }
+
+    #[test]
+    fn test_twap_weights_prices_by_ledgers() {
+        let s = setup();
+        poke_at(&s, &[100, 125]);
+        s.oracle.set_price(&200);
+        poke_at(&s, &[150, 175]);
+
+        s.env.ledger().set_sequence_number(200);
+        assert_eq!(s.client.twap(&s.asset, &100), 15_000_000);
+        assert!(s.client.try_twap(&s.asset, &101).is_err());
+    }
+
+    #[test]
+    fn test_one_ledger_spike_barely_moves_twap() {
+        let s = setup();
+        poke_at(&s, &[100, 125, 150, 175]);
+        s.env.ledger().set_sequence_number(199);
+        s.oracle.set_price(&10_000);
+        s.client.poke(&s.asset);
+        // Pushed back in the same ledger: the second sample is ignored
+        s.oracle.set_price(&100);
+        s.client.poke(&s.asset);
+
+        s.env.ledger().set_sequence_number(200);
+        assert_eq!(s.client.twap(&s.asset, &100), 19_900_000);
+    }
+
+    #[test]
+    fn test_sparsely_sampled_window_rejected() {
+        let s = setup();
+        poke_at(&s, &[100, 150]);
+
+        // Each sample covers half of the window
+        s.env.ledger().set_sequence_number(200);
+        assert!(s.client.try_twap(&s.asset, &100).is_err());
+
+        s.client.set_max_sample_share(&5_000);
+        assert_eq!(s.client.twap(&s.asset, &100), PRICE_SCALE);
+    }
+
+    #[test]
+    fn test_capacity_drops_oldest() {
+        let s = setup();
+        poke_at(&s, &[100, 110, 120, 130, 140, 150, 160, 170, 180]);
+        assert_eq!(s.client.observations(&s.asset).len(), 8);
+        assert_eq!(s.client.max_window(&s.asset), 70);
+    }
+
+    /// Pool holding 4 000 of `asset` against 1 000 of `quote`, priced 0.25.
+    fn setup_pool(s: &Setup) -> (Address, Address, Address) {
+        let (asset, quote) = (
+            TokenFixture::stellar_asset(&s.env),
+            TokenFixture::stellar_asset(&s.env),
+        );
+        let pair = s.env.register_contract(None, MockPair);
+        asset.mint(&pair, 4_000);
+        quote.mint(&pair, 1_000);
+        let adapter = MockAdapterClient::new(&s.env, &s.env.register_contract(None, MockAdapter));
+        adapter.set_pair(&pair);
+
+        s.client.set_source(
+            &asset.address,
+            &Source::Pool(PoolSource {
+                adapter: adapter.address.clone(),
+                quote: quote.address.clone(),
+            }),
+        );
+        (asset.address, quote.address, pair)
+    }
+
+    #[test]
+    fn test_pool_source_prices_from_reserves() {
+        let s = setup();
+        let (asset, _, _) = setup_pool(&s);
+        assert_eq!(s.client.poke(&asset).price, PRICE_SCALE / 4);
+    }
+
+    /// Pool stand-in that can hand back tokens sent to it.
+    #[contract]
+    struct MockPair;
+
+    #[contractimpl]
+    impl MockPair {
+        pub fn skim(env: Env, token: Address, to: Address, amount: i128) {
+            token::Client::new(&env, &token).transfer(
+                &env.current_contract_address(),
+                &to,
+                &amount,
+            );
+        }
+    }
+
+    /// Skews the pool, pokes the TWAP and restores the pool in one call.
+    #[contract]
+    struct Manipulator;
+
+    #[contractimpl]
+    impl Manipulator {
+        pub fn attack(env: Env, twap: Address, asset: Address, quote: Address, pair: Address) {
+            let this = env.current_contract_address();
+            let amount = token::Client::new(&env, &quote).balance(&this);
+            token::Client::new(&env, &quote).transfer(&this, &pair, &amount);
+            TwapContractClient::new(&env, &twap).poke(&asset);
+            MockPairClient::new(&env, &pair).skim(&quote, &this, &amount);
+        }
+    }
+
+    /// Pool priced at 0.25 and sampled at ledger 100, then lifted 100x
+    /// for the length of one poke at ledger 101.
+    fn manipulated_pool(s: &Setup) -> Address {
+        let (asset, quote, pair) = setup_pool(s);
+        s.client.poke(&asset);
+
+        let attacker = s.env.register_contract(None, Manipulator);
+        StellarAssetClient::new(&s.env, &quote).mint(&attacker, &99_000);
+        s.env.ledger().set_sequence_number(101);
+        ManipulatorClient::new(&s.env, &attacker).attack(&s.client.address, &asset, &quote, &pair);
+
+        assert_eq!(token::Client::new(&s.env, &quote).balance(&pair), 1_000);
+        assert_eq!(
+            s.client.observations(&asset).last().unwrap().price,
+            25 * PRICE_SCALE
+        );
+        asset
+    }
+
+    #[test]
+    fn test_manipulated_sample_cannot_carry_window() {
+        let s = setup();
+        let asset = manipulated_pool(&s);
+
+        // Nobody sampled after the attack, so the skewed price would be
+        // the whole average; the window is refused instead.
+        s.env.ledger().set_sequence_number(201);
+        assert!(s.client.try_twap(&asset, &100).is_err());
+    }
+
+    #[test]
+    fn test_manipulated_sample_overwritten_by_keepers() {
+        let s = setup();
+        let asset = manipulated_pool(&s);
+
+        // The next keeper sample ends the skewed price after one ledger
+        for ledger in [102, 125, 150, 175, 200] {
+            s.env.ledger().set_sequence_number(ledger);
+            s.client.poke(&asset);
+        }
+        s.env.ledger().set_sequence_number(201);
+        assert_eq!(
+            s.client.twap(&asset, &100),
+            (25 * PRICE_SCALE + 99 * PRICE_SCALE / 4) / 100
+        );
+    }
+}
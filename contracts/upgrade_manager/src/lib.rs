@@ -0,0 +1,460 @@
+#![no_std]
+
+use launchpad_access as access;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec,
+};
+
+/// Shortest delay an upgrade can wait, in ledgers (about a day).
+pub const MIN_DELAY: u32 = 17_280;
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Guardian,
+    Delay,
+    UpgradeCount,
+    Upgrade(u32),
+    WasmRegistry,
+}
+
+/// What an upgrade proposal does to its contract once executed.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum UpgradeAction {
+    /// Replace the contract's code with this uploaded wasm hash.
+    Upgrade(BytesN<32>),
+    /// Hand the contract's upgrade authority to another address, e.g. a
+    /// new upgrade manager.
+    SetUpgrader(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Upgrade {
+    pub id: u32,
+    pub contract: Address,
+    pub action: UpgradeAction,
+    /// First ledger on which the upgrade can be executed.
+    pub ready_ledger: u32,
+    pub executed: bool,
+    pub vetoed: bool,
+}
+
+/// Upgrade entry points of the platform contracts, which only their
+/// upgrader may call.
+#[contractclient(name = "UpgradeableClient")]
+pub trait Upgradeable {
+    fn upgrade(env: Env, wasm_hash: BytesN<32>);
+
+    fn set_upgrader(env: Env, upgrader: Address);
+}
+
+/// Recording interface of the wasm registry.
+#[contractclient(name = "WasmRegistryClient")]
+pub trait WasmRegistryInterface {
+    fn record(env: Env, recorder: Address, contract: Address, wasm_hash: BytesN<32>);
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Upgrade Manager Contract — the upgrade authority of the platform
+/// contracts.
+///
+/// Each platform contract names this contract as its upgrader (see
+/// `set_upgrader` on the token and vesting contracts), so code changes only
+/// happen through it. The admin proposes an upgrade with the new wasm hash;
+/// it then waits at least `MIN_DELAY` ledgers, during which the guardian can
+/// `veto` it, before anyone can `execute_upgrade`. Every proposal is kept
+/// and announced through events, so users can follow or exit ahead of a
+/// change. With a wasm registry set, every executed code change is recorded
+/// there.
+#[contract]
+pub struct UpgradeManagerContract;
+
+#[contractimpl]
+impl UpgradeManagerContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, admin: Address, guardian: Address, delay: u32) {
+        access::init_admin(&env, &admin);
+        assert!(delay >= MIN_DELAY, "delay below minimum");
+        env.storage().instance().set(&DataKey::Guardian, &guardian);
+        env.storage().instance().set(&DataKey::Delay, &delay);
+        env.storage().instance().set(&DataKey::UpgradeCount, &0u32);
+
+        env.events()
+            .publish((symbol_short!("init"),), (admin, guardian, delay));
+    }
+
+    // ── Proposals ───────────────────────────────────────────────────────
+
+    /// Propose replacing the code of `contract` with `wasm_hash`. Admin
+    /// only. Returns the upgrade id.
+    pub fn propose_upgrade(env: Env, contract: Address, wasm_hash: BytesN<32>) -> u32 {
+        Self::_propose(&env, contract, UpgradeAction::Upgrade(wasm_hash))
+    }
+
+    /// Propose handing the upgrade authority of `contract` to `upgrader`.
+    /// Admin only. Returns the upgrade id.
+    pub fn propose_handover(env: Env, contract: Address, upgrader: Address) -> u32 {
+        Self::_propose(&env, contract, UpgradeAction::SetUpgrader(upgrader))
+    }
+
+    /// Run an upgrade whose delay has passed. Anyone may call.
+    pub fn execute_upgrade(env: Env, upgrade_id: u32) {
+        let mut upgrade = Self::get_upgrade(env.clone(), upgrade_id);
+        assert!(!upgrade.vetoed, "upgrade vetoed");
+        assert!(!upgrade.executed, "already executed");
+        assert!(
+            env.ledger().sequence() >= upgrade.ready_ledger,
+            "upgrade not ready"
+        );
+        upgrade.executed = true;
+        Self::_save(&env, &upgrade);
+
+        let client = UpgradeableClient::new(&env, &upgrade.contract);
+        match &upgrade.action {
+            UpgradeAction::Upgrade(wasm_hash) => {
+                client.upgrade(wasm_hash);
+                if let Some(registry) = Self::wasm_registry(env.clone()) {
+                    WasmRegistryClient::new(&env, &registry).record(
+                        &env.current_contract_address(),
+                        &upgrade.contract,
+                        wasm_hash,
+                    );
+                }
+            }
+            UpgradeAction::SetUpgrader(upgrader) => client.set_upgrader(upgrader),
+        }
+
+        env.events()
+            .publish((symbol_short!("upg_exec"), upgrade.contract), upgrade_id);
+    }
+
+    // ── Guardian ────────────────────────────────────────────────────────
+
+    /// Block a pending upgrade for good. Guardian only.
+    pub fn veto(env: Env, upgrade_id: u32) {
+        Self::guardian(env.clone()).require_auth();
+        let mut upgrade = Self::get_upgrade(env.clone(), upgrade_id);
+        assert!(!upgrade.executed, "already executed");
+        assert!(!upgrade.vetoed, "already vetoed");
+        upgrade.vetoed = true;
+        Self::_save(&env, &upgrade);
+
+        env.events()
+            .publish((symbol_short!("upg_veto"), upgrade.contract), upgrade_id);
+    }
+
+    /// Replace the guardian. Guardian only, so the admin cannot remove the
+    /// check on its own proposals.
+    pub fn set_guardian(env: Env, guardian: Address) {
+        Self::guardian(env.clone()).require_auth();
+        env.storage().instance().set(&DataKey::Guardian, &guardian);
+
+        env.events().publish((symbol_short!("guardian"),), guardian);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Change the delay of future proposals. Never below `MIN_DELAY`.
+    /// Admin only.
+    pub fn set_delay(env: Env, delay: u32) {
+        access::require_admin(&env);
+        assert!(delay >= MIN_DELAY, "delay below minimum");
+        env.storage().instance().set(&DataKey::Delay, &delay);
+
+        env.events().publish((symbol_short!("delay"),), delay);
+    }
+
+    /// Record executed code changes in the wasm registry, which must list this contract
+    /// as a recorder, or stop with `None`. Admin only.
+    pub fn set_wasm_registry(env: Env, registry: Option<Address>) {
+        access::require_admin(&env);
+        match &registry {
+            Some(registry) => env
+                .storage()
+                .instance()
+                .set(&DataKey::WasmRegistry, registry),
+            None => env.storage().instance().remove(&DataKey::WasmRegistry),
+        }
+        env.events().publish((symbol_short!("wasm_reg"),), registry);
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    pub fn get_upgrade(env: Env, upgrade_id: u32) -> Upgrade {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Upgrade(upgrade_id))
+            .expect("no upgrade found")
+    }
+
+    /// Up to `limit` upgrades in proposal order, starting at id `start`.
+    pub fn upgrades(env: Env, start: u32, limit: u32) -> Vec<Upgrade> {
+        let count = Self::upgrade_count(env.clone());
+        let end = start.saturating_add(limit).min(count);
+        let mut result = Vec::new(&env);
+        for id in start..end {
+            result.push_back(Self::get_upgrade(env.clone(), id));
+        }
+        result
+    }
+
+    pub fn upgrade_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::UpgradeCount)
+            .expect("not initialized")
+    }
+
+    pub fn wasm_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::WasmRegistry)
+    }
+
+    pub fn delay(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Delay)
+            .expect("not initialized")
+    }
+
+    pub fn guardian(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Guardian)
+            .expect("not initialized")
+    }
+
+    pub fn admin(env: Env) -> Address {
+        access::admin(&env)
+    }
+
+    // ── Internals ───────────────────────────────────────────────────────
+
+    fn _propose(env: &Env, contract: Address, action: UpgradeAction) -> u32 {
+        access::require_admin(env);
+        let id = Self::upgrade_count(env.clone());
+        let ready_ledger = env.ledger().sequence() + Self::delay(env.clone());
+        let upgrade = Upgrade {
+            id,
+            contract: contract.clone(),
+            action: action.clone(),
+            ready_ledger,
+            executed: false,
+            vetoed: false,
+        };
+        Self::_save(env, &upgrade);
+        env.storage()
+            .instance()
+            .set(&DataKey::UpgradeCount, &(id + 1));
+
+        env.events().publish(
+            (symbol_short!("upg_prop"), contract),
+            (id, action, ready_ledger),
+        );
+        id
+    }
+
+    fn _save(env: &Env, upgrade: &Upgrade) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Upgrade(upgrade.id), upgrade);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        testutils::Address as _, testutils::Ledger, testutils::MockAuth, testutils::MockAuthInvoke,
+        IntoVal,
+    };
+
+    /// Records what the manager asked it to do instead of swapping code,
+    /// which needs a real wasm upload.
+    #[contract]
+    struct MockUpgradeable;
+
+    #[contractimpl]
+    impl MockUpgradeable {
+        pub fn upgrade(env: Env, wasm_hash: BytesN<32>) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("wasm"), &wasm_hash);
+        }
+
+        pub fn set_upgrader(env: Env, upgrader: Address) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("upgrader"), &upgrader);
+        }
+
+        pub fn wasm(env: Env) -> Option<BytesN<32>> {
+            env.storage().instance().get(&symbol_short!("wasm"))
+        }
+
+        pub fn upgrader(env: Env) -> Option<Address> {
+            env.storage().instance().get(&symbol_short!("upgrader"))
+        }
+    }
+
+    struct Setup {
+        env: Env,
+        client: UpgradeManagerContractClient<'static>,
+        target: MockUpgradeableClient<'static>,
+        guardian: Address,
+    }
+
+    /// Upgrades wait `MIN_DELAY` ledgers. The ledger starts at 10.
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+        // Keep entries alive across the day-long delay
+        env.ledger().with_mut(|ledger| {
+            ledger.sequence_number = 10;
+            ledger.min_persistent_entry_ttl = 10 * MIN_DELAY;
+            ledger.min_temp_entry_ttl = 10 * MIN_DELAY;
+            ledger.max_entry_ttl = 100 * MIN_DELAY;
+        });
+
+        let admin = Address::generate(&env);
+        let guardian = Address::generate(&env);
+        let client = UpgradeManagerContractClient::new(
+            &env,
+            &env.register_contract(None, UpgradeManagerContract),
+        );
+        client.initialize(&admin, &guardian, &MIN_DELAY);
+        let target =
+            MockUpgradeableClient::new(&env, &env.register_contract(None, MockUpgradeable));
+
+        Setup {
+            env,
+            client,
+            target,
+            guardian,
+        }
+    }
+
+    fn wasm_hash(env: &Env) -> BytesN<32> {
+        BytesN::from_array(env, &[7u8; 32])
+    }
+
+    #[test]
+    fn test_upgrade_after_delay() {
+        let s = setup();
+        let id = s
+            .client
+            .propose_upgrade(&s.target.address, &wasm_hash(&s.env));
+        let upgrade = s.client.get_upgrade(&id);
+        assert_eq!(upgrade.ready_ledger, 10 + MIN_DELAY);
+        assert!(s.client.try_execute_upgrade(&id).is_err());
+
+        s.env.ledger().set_sequence_number(10 + MIN_DELAY);
+        s.client.execute_upgrade(&id);
+        assert_eq!(s.target.wasm(), Some(wasm_hash(&s.env)));
+        assert!(s.client.get_upgrade(&id).executed);
+        assert!(s.client.try_execute_upgrade(&id).is_err());
+    }
+
+    #[contract]
+    struct MockWasmRegistry;
+
+    #[contractimpl]
+    impl MockWasmRegistry {
+        pub fn record(env: Env, _recorder: Address, contract: Address, wasm_hash: BytesN<32>) {
+            env.storage().instance().set(&contract, &wasm_hash);
+        }
+
+        pub fn recorded(env: Env, contract: Address) -> Option<BytesN<32>> {
+            env.storage().instance().get(&contract)
+        }
+    }
+
+    #[test]
+    fn test_executed_upgrade_is_recorded() {
+        let s = setup();
+        let registry =
+            MockWasmRegistryClient::new(&s.env, &s.env.register_contract(None, MockWasmRegistry));
+        s.client.set_wasm_registry(&Some(registry.address.clone()));
+        let id = s
+            .client
+            .propose_upgrade(&s.target.address, &wasm_hash(&s.env));
+        s.env.ledger().set_sequence_number(10 + MIN_DELAY);
+        s.client.execute_upgrade(&id);
+        assert_eq!(
+            registry.recorded(&s.target.address),
+            Some(wasm_hash(&s.env))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "upgrade vetoed")]
+    fn test_vetoed_upgrade_cannot_execute() {
+        let s = setup();
+        let id = s
+            .client
+            .propose_upgrade(&s.target.address, &wasm_hash(&s.env));
+        s.client.veto(&id);
+        s.env.ledger().set_sequence_number(10 + MIN_DELAY);
+        s.client.execute_upgrade(&id);
+    }
+
+    #[test]
+    fn test_handover() {
+        let s = setup();
+        let successor = Address::generate(&s.env);
+        let id = s.client.propose_handover(&s.target.address, &successor);
+        s.env.ledger().set_sequence_number(10 + MIN_DELAY);
+        s.client.execute_upgrade(&id);
+        assert_eq!(s.target.upgrader(), Some(successor));
+        assert_eq!(s.client.upgrades(&0, &10).len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "delay below minimum")]
+    fn test_delay_floor() {
+        let s = setup();
+        s.client.set_delay(&(MIN_DELAY - 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_veto_requires_guardian() {
+        let s = setup();
+        let id = s
+            .client
+            .propose_upgrade(&s.target.address, &wasm_hash(&s.env));
+        let outsider = Address::generate(&s.env);
+        s.env.mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "veto",
+                args: (id,).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }]);
+        s.client.veto(&id);
+    }
+
+    #[test]
+    fn test_guardian_replaces_itself() {
+        let s = setup();
+        let successor = Address::generate(&s.env);
+        s.client.set_guardian(&successor);
+        assert_eq!(s.client.guardian(), successor);
+        assert_ne!(s.guardian, successor);
+    }
+}
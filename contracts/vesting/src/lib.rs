@@ -1,6 +1,15 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env};
+use launchpad_access as access;
+use launchpad_events as events;
+use launchpad_ttl as ttl;
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
+    Symbol,
+};
+
+/// Role of the contracts allowed to call `create_schedule_from`.
+pub const CREATOR_ROLE: Symbol = symbol_short!("creator");
 
 // ---------------------------------------------------------------------------
 // Storage types
@@ -9,9 +18,13 @@ use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, E
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
-    Admin,
     TokenContract,
     Schedule(Address),
+    Staking,
+    Locked(Address),
+    PauseRegistry,
+    Transferable,
+    Operator(Address),
 }
 
 #[derive(Clone, Debug)]
@@ -25,6 +38,19 @@ pub struct VestingSchedule {
     pub revoked: bool,
 }
 
+/// Hook the staking contract exposes for unwinding stakes of revoked
+/// schedules.
+#[contractclient(name = "StakingClient")]
+pub trait Staking {
+    fn on_vesting_revoked(env: Env, staker: Address);
+}
+
+/// Platform-wide pause switch shared by the launchpad contracts.
+#[contractclient(name = "PauseRegistryClient")]
+pub trait PauseRegistry {
+    fn is_paused(env: Env, contract: Address) -> bool;
+}
+
 // ---------------------------------------------------------------------------
 // Contract
 // ---------------------------------------------------------------------------
@@ -34,6 +60,23 @@ pub struct VestingSchedule {
 /// Contributor issues layered on top:
 /// - #3  revoke() — admin reclaims unvested tokens
 /// - #5  structured events audit
+///
+/// Recipients can stake their unreleased allocation through the staking
+/// contract set with `set_staking`, which locks it here via `lock`. Locked
+/// tokens cannot be released until unlocked, and revoking a schedule unwinds
+/// its stake.
+///
+/// Releases and creator-funded schedules stop while the guardian's pause
+/// registry (`set_pause_registry`) has this contract paused.
+///
+/// Once the admin enables it with `set_transferable`, a recipient can hand
+/// their whole schedule to another address with `transfer_schedule`, or
+/// approve an operator (e.g. a marketplace) to do so. An approved schedule
+/// is escrowed: nothing can be released or staked until the transfer
+/// happens or the approval is withdrawn.
+///
+/// A schedule's entry is kept live at least until its end ledger, so an
+/// untouched schedule is not archived before it has fully vested.
 #[contract]
 pub struct VestingContract;
 
@@ -43,10 +86,7 @@ impl VestingContract {
 
     /// Set the admin and the token contract this vesting module manages.
     pub fn initialize(env: Env, admin: Address, token_contract: Address) {
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
-        }
-        env.storage().instance().set(&DataKey::Admin, &admin);
+        access::init_admin(&env, &admin);
         env.storage()
             .instance()
             .set(&DataKey::TokenContract, &token_contract);
@@ -74,37 +114,134 @@ impl VestingContract {
         end_ledger: u32,
     ) {
         Self::_require_admin(&env);
-        assert!(total_amount > 0, "total_amount must be positive");
-        assert!(
-            end_ledger > cliff_ledger,
-            "end_ledger must be after cliff_ledger"
-        );
+        Self::_create_schedule(&env, recipient, total_amount, cliff_ledger, end_ledger);
+    }
 
-        let key = DataKey::Schedule(recipient.clone());
-        if env.storage().persistent().has(&key) {
-            panic!("schedule already exists for this recipient");
+    /// Admin-only: allow or disallow `creator` (e.g. an airdrop contract) to
+    /// create schedules through `create_schedule_from`.
+    pub fn set_creator(env: Env, creator: Address, allowed: bool) {
+        if allowed {
+            access::grant_role(&env, &CREATOR_ROLE, &creator);
+        } else {
+            access::revoke_role(&env, &CREATOR_ROLE, &creator);
         }
+    }
 
-        let schedule = VestingSchedule {
-            recipient: recipient.clone(),
-            total_amount,
-            cliff_ledger,
-            end_ledger,
-            released: 0,
-            revoked: false,
-        };
+    /// Admin-only: nominate `new_admin`, who takes over once they call
+    /// `accept_admin`.
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        access::propose_admin(&env, &new_admin);
+    }
 
-        env.storage().persistent().set(&key, &schedule);
+    /// Pending-admin-only: complete an admin transfer.
+    pub fn accept_admin(env: Env) {
+        access::accept_admin(&env);
+    }
 
-        env.events().publish(
-            (symbol_short!("create"), recipient),
-            total_amount,
+    /// Hand the upgrade authority to `upgrader`, normally the upgrade
+    /// manager. Admin for the first upgrader, the current upgrader after.
+    pub fn set_upgrader(env: Env, upgrader: Address) {
+        access::set_upgrader(&env, &upgrader);
+    }
+
+    /// Upgrader-only: replace this contract's code with `wasm_hash`.
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) {
+        access::upgrade(&env, &wasm_hash);
+    }
+
+    /// Admin-only: set the staking contract allowed to lock allocations.
+    pub fn set_staking(env: Env, staking: Address) {
+        Self::_require_admin(&env);
+        env.storage().instance().set(&DataKey::Staking, &staking);
+
+        env.events().publish((symbol_short!("staking"),), staking);
+    }
+
+    /// Admin-only: set the guardian pause registry this contract obeys.
+    pub fn set_pause_registry(env: Env, registry: Address) {
+        Self::_require_admin(&env);
+        env.storage().instance().set(&DataKey::PauseRegistry, &registry);
+
+        env.events().publish((symbol_short!("pause_reg"),), registry);
+    }
+
+    /// Admin-only: allow or stop recipients transferring their schedules.
+    pub fn set_transferable(env: Env, enabled: bool) {
+        Self::_require_admin(&env);
+        env.storage().instance().set(&DataKey::Transferable, &enabled);
+
+        env.events().publish((symbol_short!("transfers"),), enabled);
+    }
+
+    // ── Creator actions ─────────────────────────────────────────────────
+
+    /// Authorized-creator-only: create a schedule for `recipient` funded by
+    /// pulling `total_amount` tokens from `creator`. Same schedule rules as
+    /// `create_schedule`.
+    pub fn create_schedule_from(
+        env: Env,
+        creator: Address,
+        recipient: Address,
+        total_amount: i128,
+        cliff_ledger: u32,
+        end_ledger: u32,
+    ) {
+        creator.require_auth();
+        Self::_check_not_paused(&env);
+        assert!(
+            Self::is_creator(env.clone(), creator.clone()),
+            "not an authorized creator"
         );
+        Self::_create_schedule(&env, recipient, total_amount, cliff_ledger, end_ledger);
+
+        let token_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::TokenContract)
+            .expect("not initialized");
+
+        let token_client = soroban_sdk::token::Client::new(&env, &token_addr);
+        token_client.transfer(&creator, &env.current_contract_address(), &total_amount);
+    }
+
+    /// Authorized-creator-only: add `amount` to the existing schedule of
+    /// `recipient`, pulled from `creator`. The top-up vests on the
+    /// schedule's own cliff and end, so a recipient who already has a
+    /// schedule can still be paid by a creator.
+    pub fn top_up_from(env: Env, creator: Address, recipient: Address, amount: i128) {
+        creator.require_auth();
+        Self::_check_not_paused(&env);
+        assert!(
+            Self::is_creator(env.clone(), creator.clone()),
+            "not an authorized creator"
+        );
+        assert!(amount > 0, "amount must be positive");
+
+        let mut schedule = Self::get_schedule(env.clone(), recipient.clone());
+        assert!(!schedule.revoked, "schedule has been revoked");
+        schedule.total_amount = schedule
+            .total_amount
+            .checked_add(amount)
+            .expect("total_amount overflow");
+        Self::_set_schedule(&env, &schedule);
+
+        let token_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::TokenContract)
+            .expect("not initialized");
+
+        let token_client = soroban_sdk::token::Client::new(&env, &token_addr);
+        token_client.transfer(&creator, &env.current_contract_address(), &amount);
+
+        env.events()
+            .publish((symbol_short!("top_up"), recipient), amount);
     }
 
-    /// Release all currently vested (but unreleased) tokens to the recipient.
-    /// Can be called by anyone.
+    /// Release all currently vested (but unreleased) tokens to the recipient,
+    /// except those locked by staking. Can be called by anyone.
     pub fn release(env: Env, recipient: Address) {
+        Self::_check_not_paused(&env);
         let key = DataKey::Schedule(recipient.clone());
         let mut schedule: VestingSchedule = env
             .storage()
@@ -113,13 +250,16 @@ impl VestingContract {
             .expect("no schedule found");
 
         assert!(!schedule.revoked, "schedule has been revoked");
+        Self::_check_not_escrowed(&env, &recipient);
 
         let vested = Self::_vested_amount(&env, &schedule);
-        let releasable = vested - schedule.released;
+        let locked = Self::locked_amount(env.clone(), recipient.clone());
+        let releasable =
+            (vested - schedule.released).min(schedule.total_amount - schedule.released - locked);
         assert!(releasable > 0, "nothing to release");
 
         schedule.released += releasable;
-        env.storage().persistent().set(&key, &schedule);
+        Self::_set_schedule(&env, &schedule);
 
         // Transfer tokens from the vesting contract to the recipient via
         // the token contract's transfer function.
@@ -132,10 +272,7 @@ impl VestingContract {
         let token_client = soroban_sdk::token::Client::new(&env, &token_addr);
         token_client.transfer(&env.current_contract_address(), &recipient, &releasable);
 
-        env.events().publish(
-            (symbol_short!("release"), recipient),
-            releasable,
-        );
+        events::released(&env, &recipient, releasable);
     }
 
     /// Admin-only: revoke a schedule, send vested portion to recipient,
@@ -161,7 +298,7 @@ impl VestingContract {
         // Update schedule state
         schedule.revoked = true;
         schedule.released = vested; // All vested tokens are now accounted for as released (or being released)
-        env.storage().persistent().set(&key, &schedule);
+        Self::_set_schedule(&env, &schedule);
 
         let token_addr: Address = env
             .storage()
@@ -178,17 +315,119 @@ impl VestingContract {
 
         // 2. Transfer unvested tokens back to admin
         if unvested > 0 {
-            let admin: Address = env
+            let admin = access::admin(&env);
+            token_client.transfer(&env.current_contract_address(), &admin, &unvested);
+        }
+
+        // 3. Unwind any stake of the allocation
+        let locked_key = DataKey::Locked(recipient.clone());
+        let locked: i128 = env.storage().persistent().get(&locked_key).unwrap_or(0);
+        if locked > 0 {
+            env.storage().persistent().remove(&locked_key);
+            let staking: Address = env
                 .storage()
                 .instance()
-                .get(&DataKey::Admin)
-                .expect("not initialized");
-            token_client.transfer(&env.current_contract_address(), &admin, &unvested);
+                .get(&DataKey::Staking)
+                .expect("staking not set");
+            StakingClient::new(&env, &staking).on_vesting_revoked(&recipient);
+        }
+
+        events::schedule_revoked(
+            &env,
+            &recipient,
+            events::ScheduleRevoked {
+                released: releasable,
+                returned: unvested,
+            },
+        );
+    }
+
+    // ── Staking hooks ───────────────────────────────────────────────────
+
+    /// Staking-only: lock `amount` of the unreleased allocation of
+    /// `recipient` while it is staked.
+    pub fn lock(env: Env, recipient: Address, amount: i128) {
+        Self::_require_staking(&env);
+        assert!(amount > 0, "amount must be positive");
+
+        let schedule = Self::get_schedule(env.clone(), recipient.clone());
+        assert!(!schedule.revoked, "schedule has been revoked");
+        Self::_check_not_escrowed(&env, &recipient);
+
+        let locked = Self::locked_amount(env.clone(), recipient.clone());
+        assert!(
+            locked + amount <= schedule.total_amount - schedule.released,
+            "insufficient unlocked balance"
+        );
+        Self::_set_locked(&env, &recipient, locked + amount);
+
+        env.events()
+            .publish((symbol_short!("lock"), recipient), amount);
+    }
+
+    /// Staking-only: unlock `amount` previously locked for `recipient`.
+    pub fn unlock(env: Env, recipient: Address, amount: i128) {
+        Self::_require_staking(&env);
+        assert!(amount > 0, "amount must be positive");
+
+        let locked = Self::locked_amount(env.clone(), recipient.clone());
+        assert!(amount <= locked, "amount exceeds locked");
+        Self::_set_locked(&env, &recipient, locked - amount);
+
+        env.events()
+            .publish((symbol_short!("unlock"), recipient), amount);
+    }
+
+    // ── Schedule transfers ──────────────────────────────────────────────
+
+    /// Recipient-only: let `operator` transfer the schedule, escrowing it
+    /// until then, or withdraw the approval with `None`.
+    pub fn set_operator(env: Env, recipient: Address, operator: Option<Address>) {
+        recipient.require_auth();
+        let key = DataKey::Operator(recipient.clone());
+        match &operator {
+            Some(operator) => {
+                Self::_check_transferable(&env, &recipient);
+                env.storage().persistent().set(&key, operator);
+                ttl::extend_persistent(&env, &key);
+            }
+            None => env.storage().persistent().remove(&key),
         }
 
         env.events().publish(
-            (symbol_short!("revoke"), recipient),
-            (releasable, unvested),
+            (symbol_short!("operator"), recipient),
+            operator,
+        );
+    }
+
+    /// Move the schedule of `from`, with everything still unreleased, to
+    /// `to`, who must not have one. `caller` is `from` or its approved
+    /// operator; the approval is used up.
+    pub fn transfer_schedule(env: Env, caller: Address, from: Address, to: Address) {
+        caller.require_auth();
+        Self::_check_not_paused(&env);
+        let operator_key = DataKey::Operator(from.clone());
+        let operator: Option<Address> = env.storage().persistent().get(&operator_key);
+        assert!(
+            caller == from || operator == Some(caller),
+            "not owner or operator"
+        );
+        Self::_check_transferable(&env, &from);
+
+        let to_key = DataKey::Schedule(to.clone());
+        assert!(
+            !env.storage().persistent().has(&to_key),
+            "schedule already exists for this recipient"
+        );
+        let mut schedule = Self::get_schedule(env.clone(), from.clone());
+        schedule.recipient = to.clone();
+        env.storage().persistent().remove(&DataKey::Schedule(from.clone()));
+        env.storage().persistent().remove(&operator_key);
+        Self::_set_schedule(&env, &schedule);
+
+        env.events().publish(
+            (symbol_short!("sch_xfer"), from, to),
+            schedule.total_amount - schedule.released,
         );
     }
 
@@ -216,6 +455,70 @@ impl VestingContract {
         schedule.released
     }
 
+    /// Unreleased allocation of the recipient, vested or not; what they can
+    /// stake. Zero once revoked.
+    pub fn voting_balance(env: Env, recipient: Address) -> i128 {
+        let key = DataKey::Schedule(recipient);
+        match env.storage().persistent().get::<_, VestingSchedule>(&key) {
+            Some(schedule) if !schedule.revoked => schedule.total_amount - schedule.released,
+            _ => 0,
+        }
+    }
+
+    /// Amount of the allocation currently locked by staking.
+    pub fn locked_amount(env: Env, recipient: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Locked(recipient))
+            .unwrap_or(0)
+    }
+
+    /// Whether `creator` may call `create_schedule_from`.
+    pub fn is_creator(env: Env, creator: Address) -> bool {
+        access::has_role(&env, &CREATOR_ROLE, &creator)
+    }
+
+    pub fn admin(env: Env) -> Address {
+        access::admin(&env)
+    }
+
+    pub fn upgrader(env: Env) -> Option<Address> {
+        access::upgrader(&env)
+    }
+
+    /// Whether the pause registry currently has this contract paused.
+    pub fn is_paused(env: Env) -> bool {
+        match Self::pause_registry(env.clone()) {
+            Some(registry) => PauseRegistryClient::new(&env, &registry)
+                .is_paused(&env.current_contract_address()),
+            None => false,
+        }
+    }
+
+    pub fn pause_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PauseRegistry)
+    }
+
+    /// Whether recipients may transfer their schedules.
+    pub fn is_transferable(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Transferable)
+            .unwrap_or(false)
+    }
+
+    /// Address approved to transfer the schedule of `recipient`, if any.
+    pub fn operator(env: Env, recipient: Address) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Operator(recipient))
+    }
+
+    /// Whether `recipient` has a schedule, revoked or not.
+    pub fn has_schedule(env: Env, recipient: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Schedule(recipient))
+    }
+
     /// Return the full schedule struct for a recipient.
     pub fn get_schedule(env: Env, recipient: Address) -> VestingSchedule {
         let key = DataKey::Schedule(recipient);
@@ -228,12 +531,94 @@ impl VestingContract {
     // ── Internals ───────────────────────────────────────────────────────
 
     fn _require_admin(env: &Env) {
-        let admin: Address = env
+        access::require_admin(env);
+    }
+
+    fn _create_schedule(
+        env: &Env,
+        recipient: Address,
+        total_amount: i128,
+        cliff_ledger: u32,
+        end_ledger: u32,
+    ) {
+        assert!(total_amount > 0, "total_amount must be positive");
+        assert!(
+            end_ledger > cliff_ledger,
+            "end_ledger must be after cliff_ledger"
+        );
+
+        let key = DataKey::Schedule(recipient.clone());
+        if env.storage().persistent().has(&key) {
+            panic!("schedule already exists for this recipient");
+        }
+
+        let schedule = VestingSchedule {
+            recipient: recipient.clone(),
+            total_amount,
+            cliff_ledger,
+            end_ledger,
+            released: 0,
+            revoked: false,
+        };
+
+        Self::_set_schedule(env, &schedule);
+
+        events::schedule_created(
+            env,
+            &recipient,
+            events::ScheduleCreated {
+                total_amount,
+                cliff_ledger,
+                end_ledger,
+            },
+        );
+    }
+
+    /// Store `schedule` under its recipient, live at least until it has
+    /// fully vested.
+    fn _set_schedule(env: &Env, schedule: &VestingSchedule) {
+        let key = DataKey::Schedule(schedule.recipient.clone());
+        env.storage().persistent().set(&key, schedule);
+        ttl::extend_persistent_until(env, &key, schedule.end_ledger);
+        ttl::extend_instance(env);
+    }
+
+    fn _set_locked(env: &Env, recipient: &Address, locked: i128) {
+        let key = DataKey::Locked(recipient.clone());
+        env.storage().persistent().set(&key, &locked);
+        ttl::extend_persistent(env, &key);
+    }
+
+    fn _check_not_paused(env: &Env) {
+        if Self::is_paused(env.clone()) {
+            panic!("contract is paused");
+        }
+    }
+
+    fn _check_not_escrowed(env: &Env, recipient: &Address) {
+        if Self::operator(env.clone(), recipient.clone()).is_some() {
+            panic!("schedule is escrowed");
+        }
+    }
+
+    /// Transfers must be enabled, and the schedule live and not staked.
+    fn _check_transferable(env: &Env, recipient: &Address) {
+        assert!(Self::is_transferable(env.clone()), "transfers disabled");
+        let schedule = Self::get_schedule(env.clone(), recipient.clone());
+        assert!(!schedule.revoked, "schedule has been revoked");
+        assert!(
+            Self::locked_amount(env.clone(), recipient.clone()) == 0,
+            "allocation is staked"
+        );
+    }
+
+    fn _require_staking(env: &Env) {
+        let staking: Address = env
             .storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("not initialized");
-        admin.require_auth();
+            .get(&DataKey::Staking)
+            .expect("staking not set");
+        staking.require_auth();
     }
 
     /// Cliff + linear vesting formula.
@@ -242,19 +627,12 @@ impl VestingContract {
     /// - Between cliff and end → proportional
     /// - After end → total_amount
     fn _vested_amount(env: &Env, schedule: &VestingSchedule) -> i128 {
-        let current = env.ledger().sequence();
-
-        if current < schedule.cliff_ledger {
-            return 0;
-        }
-        if current >= schedule.end_ledger {
-            return schedule.total_amount;
-        }
-
-        // Linear interpolation between cliff and end
-        let elapsed = (current - schedule.cliff_ledger) as i128;
-        let duration = (schedule.end_ledger - schedule.cliff_ledger) as i128;
-        schedule.total_amount * elapsed / duration
+        soropad_math::linear(
+            schedule.cliff_ledger,
+            schedule.end_ledger,
+            env.ledger().sequence(),
+            schedule.total_amount,
+        )
     }
 }
 
@@ -265,24 +643,24 @@ impl VestingContract {
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, testutils::Ledger, Env};
+    use launchpad_testutils::TokenFixture;
+    use soroban_sdk::{
+        testutils::storage::Persistent as _, testutils::Address as _, testutils::Ledger, Env,
+    };
 
     // We don't use the token_client import in tests — we test the vesting
-    // schedule logic in isolation. The `release` function (which calls the
-    // token) would be tested in integration tests.
+    // schedule logic in isolation. Flows against the real token contract
+    // live in tests/integration.
 
     fn setup_schedule(env: &Env, client: &VestingContractClient) -> (Address, Address) {
         let admin = Address::generate(env);
         let recipient = Address::generate(env);
-        
-        // Register a mock token contract
-        let token = env.register_stellar_asset_contract(admin.clone());
-        let token_client = soroban_sdk::token::StellarAssetClient::new(env, &token);
-        
+
         // Mint tokens to the vesting contract
-        token_client.mint(&client.address, &1_000_000i128);
+        let token = TokenFixture::stellar_asset(env);
+        token.mint(&client.address, 1_000_000);
 
-        client.initialize(&admin, &token);
+        client.initialize(&admin, &token.address);
 
         // cliff at ledger 100, fully vested at ledger 200
         client.create_schedule(&recipient, &1_000i128, &100u32, &200u32);
@@ -526,4 +904,320 @@ mod test {
         // This should fail because we haven't mocked auth for admin
         client.revoke(&recipient);
     }
+
+    // ── Staking hooks ───────────────────────────────────────────────────
+
+    #[contract]
+    struct MockStaking;
+
+    #[contractimpl]
+    impl MockStaking {
+        pub fn on_vesting_revoked(env: Env, staker: Address) {
+            env.storage().persistent().set(&staker, &true);
+        }
+
+        pub fn revoked(env: Env, staker: Address) -> bool {
+            env.storage().persistent().has(&staker)
+        }
+    }
+
+    fn setup_staking(
+        env: &Env,
+        client: &VestingContractClient,
+    ) -> (Address, MockStakingClient<'static>) {
+        let (_, recipient) = setup_schedule(env, client);
+        let staking_id = env.register_contract(None, MockStaking);
+        client.set_staking(&staking_id);
+        (recipient, MockStakingClient::new(env, &staking_id))
+    }
+
+    #[test]
+    fn test_locked_tokens_are_not_released() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, VestingContract);
+        let client = VestingContractClient::new(&env, &contract_id);
+        let (recipient, _) = setup_staking(&env, &client);
+
+        client.lock(&recipient, &800);
+        assert_eq!(client.locked_amount(&recipient), 800);
+        assert_eq!(client.voting_balance(&recipient), 1_000);
+
+        // Fully vested, but only the unlocked 200 can be released
+        env.ledger().set_sequence_number(200);
+        client.release(&recipient);
+        assert_eq!(client.released_amount(&recipient), 200);
+        assert!(client.try_release(&recipient).is_err());
+        assert!(client.try_lock(&recipient, &1).is_err());
+
+        client.unlock(&recipient, &800);
+        client.release(&recipient);
+        assert_eq!(client.released_amount(&recipient), 1_000);
+        assert_eq!(client.voting_balance(&recipient), 0);
+    }
+
+    #[test]
+    fn test_revoke_unwinds_stake() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, VestingContract);
+        let client = VestingContractClient::new(&env, &contract_id);
+        let (recipient, staking) = setup_staking(&env, &client);
+
+        client.lock(&recipient, &600);
+        env.ledger().set_sequence_number(150);
+        client.revoke(&recipient);
+
+        assert!(staking.revoked(&recipient));
+        assert_eq!(client.locked_amount(&recipient), 0);
+        assert_eq!(client.voting_balance(&recipient), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "staking not set")]
+    fn test_lock_without_staking_panics() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, VestingContract);
+        let client = VestingContractClient::new(&env, &contract_id);
+        let (_, recipient) = setup_schedule(&env, &client);
+
+        client.lock(&recipient, &100);
+    }
+
+    // ── Authorized creators ─────────────────────────────────────────────
+
+    #[test]
+    fn test_create_schedule_from_creator() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, VestingContract);
+        let client = VestingContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        let token = TokenFixture::stellar_asset(&env);
+        token.mint(&creator, 1_000);
+
+        client.initialize(&admin, &token.address);
+        client.set_creator(&creator, &true);
+        assert!(client.is_creator(&creator));
+
+        client.create_schedule_from(&creator, &recipient, &1_000i128, &100u32, &200u32);
+        assert_eq!(client.get_schedule(&recipient).total_amount, 1_000);
+
+        assert_eq!(token.balance(&creator), 0);
+        assert_eq!(token.balance(&contract_id), 1_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "not an authorized creator")]
+    fn test_create_schedule_from_unknown_creator_panics() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, VestingContract);
+        let client = VestingContractClient::new(&env, &contract_id);
+        setup_schedule(&env, &client);
+
+        let creator = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        client.create_schedule_from(&creator, &recipient, &1_000i128, &100u32, &200u32);
+    }
+
+    #[test]
+    fn test_top_up_from_creator() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, VestingContract);
+        let client = VestingContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        let token = TokenFixture::stellar_asset(&env);
+        token.mint(&creator, 1_500);
+
+        client.initialize(&admin, &token.address);
+        client.set_creator(&creator, &true);
+        assert!(!client.has_schedule(&recipient));
+
+        client.create_schedule_from(&creator, &recipient, &1_000i128, &100u32, &200u32);
+        client.top_up_from(&creator, &recipient, &500i128);
+        assert!(client.has_schedule(&recipient));
+
+        let schedule = client.get_schedule(&recipient);
+        assert_eq!(schedule.total_amount, 1_500);
+        assert_eq!((schedule.cliff_ledger, schedule.end_ledger), (100, 200));
+        assert_eq!(token.balance(&creator), 0);
+        assert_eq!(token.balance(&contract_id), 1_500);
+    }
+
+    #[test]
+    #[should_panic(expected = "schedule has been revoked")]
+    fn test_top_up_revoked_schedule_panics() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, VestingContract);
+        let client = VestingContractClient::new(&env, &contract_id);
+        let (_, recipient) = setup_schedule(&env, &client);
+        client.revoke(&recipient);
+
+        let creator = Address::generate(&env);
+        client.set_creator(&creator, &true);
+        client.top_up_from(&creator, &recipient, &500i128);
+    }
+
+    // ── Pause registry ──────────────────────────────────────────────────
+
+    #[contract]
+    struct MockPauseRegistry;
+
+    #[contractimpl]
+    impl MockPauseRegistry {
+        pub fn set_paused(env: Env, contract: Address, paused: bool) {
+            env.storage().instance().set(&contract, &paused);
+        }
+
+        pub fn is_paused(env: Env, contract: Address) -> bool {
+            env.storage().instance().get(&contract).unwrap_or(false)
+        }
+    }
+
+    #[test]
+    fn test_pause_registry_blocks_release() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, VestingContract);
+        let client = VestingContractClient::new(&env, &contract_id);
+        let (_, recipient) = setup_schedule(&env, &client);
+
+        let registry_id = env.register_contract(None, MockPauseRegistry);
+        let registry = MockPauseRegistryClient::new(&env, &registry_id);
+        client.set_pause_registry(&registry_id);
+        registry.set_paused(&contract_id, &true);
+        assert!(client.is_paused());
+
+        env.ledger().set_sequence_number(200);
+        assert!(client.try_release(&recipient).is_err());
+
+        registry.set_paused(&contract_id, &false);
+        client.release(&recipient);
+        assert_eq!(client.released_amount(&recipient), 1_000);
+    }
+
+    // ── Schedule transfers ──────────────────────────────────────────────
+
+    #[test]
+    fn test_transfer_schedule() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, VestingContract);
+        let client = VestingContractClient::new(&env, &contract_id);
+        let (_, recipient) = setup_schedule(&env, &client);
+        let buyer = Address::generate(&env);
+        assert!(client.try_transfer_schedule(&recipient, &recipient, &buyer).is_err());
+
+        client.set_transferable(&true);
+        env.ledger().set_sequence_number(150);
+        client.release(&recipient);
+        client.transfer_schedule(&recipient, &recipient, &buyer);
+
+        assert!(client.try_get_schedule(&recipient).is_err());
+        assert_eq!(client.get_schedule(&buyer).recipient, buyer);
+        env.ledger().set_sequence_number(200);
+        client.release(&buyer);
+        assert_eq!(client.released_amount(&buyer), 1_000);
+    }
+
+    #[test]
+    fn test_operator_escrows_schedule() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, VestingContract);
+        let client = VestingContractClient::new(&env, &contract_id);
+        let (_, recipient) = setup_schedule(&env, &client);
+        client.set_transferable(&true);
+        let (market, buyer) = (Address::generate(&env), Address::generate(&env));
+
+        client.set_operator(&recipient, &Some(market.clone()));
+        env.ledger().set_sequence_number(150);
+        assert!(client.try_release(&recipient).is_err());
+        assert!(client.try_transfer_schedule(&buyer, &recipient, &buyer).is_err());
+
+        client.transfer_schedule(&market, &recipient, &buyer);
+        assert_eq!(client.operator(&recipient), None);
+        client.release(&buyer);
+        assert_eq!(client.released_amount(&buyer), 500);
+    }
+
+    #[test]
+    #[should_panic(expected = "allocation is staked")]
+    fn test_staked_schedule_cannot_transfer() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, VestingContract);
+        let client = VestingContractClient::new(&env, &contract_id);
+        let (recipient, _) = setup_staking(&env, &client);
+        client.set_transferable(&true);
+
+        client.lock(&recipient, &100);
+        client.transfer_schedule(&recipient, &recipient, &Address::generate(&env));
+    }
+
+    #[test]
+    fn test_admin_transfer() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, VestingContract);
+        let client = VestingContractClient::new(&env, &contract_id);
+        let (admin, _) = setup_schedule(&env, &client);
+        let new_admin = Address::generate(&env);
+
+        client.propose_admin(&new_admin);
+        assert_eq!(client.admin(), admin);
+        client.accept_admin();
+        assert_eq!(client.admin(), new_admin);
+    }
+    // ── Storage TTLs ────────────────────────────────────────────────────
+
+    #[test]
+    fn test_schedule_kept_live_until_fully_vested() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, VestingContract);
+        let client = VestingContractClient::new(&env, &contract_id);
+        let (_, recipient) = setup_schedule(&env, &client);
+        let end = 365 * ttl::DAY_IN_LEDGERS;
+        let long = Address::generate(&env);
+        client.create_schedule(&long, &900_000i128, &100u32, &end);
+
+        let schedule_ttl = |recipient: &Address| {
+            env.as_contract(&contract_id, || {
+                env.storage()
+                    .persistent()
+                    .get_ttl(&DataKey::Schedule(recipient.clone()))
+            })
+        };
+        // A schedule ending soon still gets the full bump
+        assert_eq!(schedule_ttl(&recipient), ttl::PERSISTENT_BUMP);
+        assert_eq!(schedule_ttl(&long), end);
+
+        env.ledger().set_sequence_number(150);
+        client.release(&long);
+        assert_eq!(schedule_ttl(&long), end - 150);
+    }
 }
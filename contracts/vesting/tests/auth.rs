@@ -0,0 +1,220 @@
+//! # Authorization Matrix for Vesting
+//!
+//! Every entry point that needs someone's authorization is called twice
+//! with the same arguments: once authorized only by an outsider, which
+//! must be rejected, and once authorized only by the address the entry
+//! point is meant to require, which must succeed. The second call proves
+//! the first was rejected for the missing signature and not for anything
+//! else, so a dropped `require_auth` or admin check fails here.
+//!
+//! Three entry points only get the outsider call: `upgrade`, whose
+//! authorized call needs uploaded wasm that native tests don't have, and
+//! `create_schedule_from` and `top_up_from`, whose authorized calls also
+//! need the creator's signature on the token transfer they make.
+
+use launchpad_testutils::{jump_to, mock_auth, test_env, VestingFixture};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, IntoVal, Val, Vec};
+use soroban_vesting::VestingContractClient;
+
+struct Setup {
+    env: Env,
+    vesting: VestingFixture,
+    /// Has 1 000 vesting from ledger 100 to 200, half vested.
+    recipient: Address,
+    /// The staking contract allowed to lock allocations.
+    staking: Address,
+    outsider: Address,
+}
+
+fn setup() -> Setup {
+    let env = test_env();
+    let vesting = VestingFixture::new(&env);
+    let recipient = vesting.schedule(1_000, 100, 200);
+    let staking = Address::generate(&env);
+    vesting.client.set_staking(&staking);
+    vesting.client.set_transferable(&true);
+    jump_to(&env, 150);
+    Setup {
+        vesting,
+        recipient,
+        staking,
+        outsider: Address::generate(&env),
+        env,
+    }
+}
+
+impl Setup {
+    fn client(&self) -> &VestingContractClient<'static> {
+        &self.vesting.client
+    }
+
+    /// Panic unless `call` fails when only the outsider authorizes it and
+    /// succeeds when only `signer` does.
+    fn assert_requires<A, T, E>(
+        &self,
+        signer: &Address,
+        fn_name: &str,
+        args: A,
+        call: impl Fn() -> Result<T, E>,
+    ) where
+        A: IntoVal<Env, Vec<Val>> + Clone,
+    {
+        self.assert_rejects_outsider(fn_name, args.clone(), &call);
+        mock_auth(&self.env, signer, &self.client().address, fn_name, args);
+        assert!(call().is_ok(), "{fn_name} rejected its signer");
+    }
+
+    /// Panic unless `call` fails when only the outsider authorizes it.
+    fn assert_rejects_outsider<A, T, E>(
+        &self,
+        fn_name: &str,
+        args: A,
+        call: impl Fn() -> Result<T, E>,
+    ) where
+        A: IntoVal<Env, Vec<Val>>,
+    {
+        mock_auth(
+            &self.env,
+            &self.outsider,
+            &self.client().address,
+            fn_name,
+            args,
+        );
+        assert!(call().is_err(), "{fn_name} accepted an outsider");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Admin
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_schedule_admin_entry_points() {
+    let s = setup();
+    let admin = &s.vesting.admin;
+    let other = Address::generate(&s.env);
+    s.vesting.token.mint(&s.client().address, 500);
+
+    s.assert_requires(
+        admin,
+        "create_schedule",
+        (&other, 500i128, 300u32, 400u32),
+        || s.client().try_create_schedule(&other, &500, &300, &400),
+    );
+    s.assert_requires(admin, "revoke", (&s.recipient,), || {
+        s.client().try_revoke(&s.recipient)
+    });
+}
+
+#[test]
+fn test_configuration_admin_entry_points() {
+    let s = setup();
+    let admin = &s.vesting.admin;
+    let (creator, registry) = (Address::generate(&s.env), Address::generate(&s.env));
+
+    s.assert_requires(admin, "set_creator", (&creator, true), || {
+        s.client().try_set_creator(&creator, &true)
+    });
+    s.assert_requires(admin, "set_staking", (&s.staking,), || {
+        s.client().try_set_staking(&s.staking)
+    });
+    s.assert_requires(admin, "set_transferable", (false,), || {
+        s.client().try_set_transferable(&false)
+    });
+    s.assert_requires(admin, "set_pause_registry", (&registry,), || {
+        s.client().try_set_pause_registry(&registry)
+    });
+}
+
+#[test]
+fn test_admin_transfer_entry_points() {
+    let s = setup();
+    let (admin, successor) = (&s.vesting.admin, &s.recipient);
+
+    s.assert_requires(admin, "propose_admin", (successor,), || {
+        s.client().try_propose_admin(successor)
+    });
+    s.assert_requires(successor, "accept_admin", (), || {
+        s.client().try_accept_admin()
+    });
+    assert_eq!(s.client().admin(), *successor);
+}
+
+#[test]
+fn test_upgrade_entry_points() {
+    let s = setup();
+    let (admin, upgrader) = (&s.vesting.admin, &s.staking);
+
+    s.assert_requires(admin, "set_upgrader", (upgrader,), || {
+        s.client().try_set_upgrader(upgrader)
+    });
+    s.assert_requires(upgrader, "set_upgrader", (upgrader,), || {
+        s.client().try_set_upgrader(upgrader)
+    });
+    let hash = BytesN::from_array(&s.env, &[0; 32]);
+    s.assert_rejects_outsider("upgrade", (hash.clone(),), || s.client().try_upgrade(&hash));
+}
+
+// ---------------------------------------------------------------------------
+// Creators, staking and recipients
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_creator_entry_point() {
+    let s = setup();
+    let creator = s.vesting.token.funded(500);
+    s.client().set_creator(&creator, &true);
+    let other = Address::generate(&s.env);
+
+    s.assert_rejects_outsider(
+        "create_schedule_from",
+        (&creator, &other, 500i128, 300u32, 400u32),
+        || {
+            s.client()
+                .try_create_schedule_from(&creator, &other, &500, &300, &400)
+        },
+    );
+    s.assert_rejects_outsider("top_up_from", (&creator, &s.recipient, 500i128), || {
+        s.client().try_top_up_from(&creator, &s.recipient, &500)
+    });
+}
+
+#[test]
+fn test_staking_entry_points() {
+    let s = setup();
+    let (staking, recipient) = (&s.staking, &s.recipient);
+
+    s.assert_requires(staking, "lock", (recipient, 400i128), || {
+        s.client().try_lock(recipient, &400)
+    });
+    s.assert_requires(staking, "unlock", (recipient, 400i128), || {
+        s.client().try_unlock(recipient, &400)
+    });
+}
+
+#[test]
+fn test_recipient_entry_points() {
+    let s = setup();
+    let recipient = &s.recipient;
+    let (operator, buyer) = (Address::generate(&s.env), Address::generate(&s.env));
+
+    s.assert_requires(
+        recipient,
+        "set_operator",
+        (recipient, Some(&operator)),
+        || {
+            s.client()
+                .try_set_operator(recipient, &Some(operator.clone()))
+        },
+    );
+    // The approved operator may move the schedule, the outsider may not
+    s.assert_requires(
+        &operator,
+        "transfer_schedule",
+        (&operator, recipient, &buyer),
+        || {
+            s.client()
+                .try_transfer_schedule(&operator, recipient, &buyer)
+        },
+    );
+}
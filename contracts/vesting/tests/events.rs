@@ -0,0 +1,257 @@
+//! # Vesting Event Tests
+//!
+//! Every state-changing entry point is called once and the event it
+//! publishes is checked topic by topic and payload by payload, and it must
+//! be the only event the vesting contract publishes for that call. Events
+//! the token publishes while schedules are funded or paid out are the
+//! token's to test.
+//!
+//! `upgrade` is left out: it needs uploaded wasm, which native tests don't
+//! have. Its event comes from `launchpad-access` and is tested there.
+
+use launchpad_events::{self as events, ScheduleCreated, ScheduleRevoked};
+use launchpad_testutils::{assert_sole_event, events_during, jump_to, test_env, VestingFixture};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, IntoVal, Val, Vec};
+use soroban_vesting::{VestingContract, VestingContractClient, CREATOR_ROLE};
+
+const TOTAL: i128 = 1_000;
+
+struct Setup {
+    env: Env,
+    vesting: VestingFixture,
+    /// Has `TOTAL` vesting from ledger 100 to 200.
+    recipient: Address,
+}
+
+fn setup() -> Setup {
+    let env = test_env();
+    let vesting = VestingFixture::new(&env);
+    let recipient = vesting.schedule(TOTAL, 100, 200);
+    Setup {
+        env,
+        vesting,
+        recipient,
+    }
+}
+
+impl Setup {
+    fn client(&self) -> &VestingContractClient<'static> {
+        &self.vesting.client
+    }
+
+    /// Panic unless `call` makes the vesting contract publish exactly this
+    /// one event.
+    fn assert_event<R, T, D>(&self, call: impl FnOnce() -> R, topics: T, data: D)
+    where
+        T: IntoVal<Env, Vec<Val>>,
+        D: IntoVal<Env, Val>,
+    {
+        assert_sole_event(&self.env, &self.vesting.client.address, call, topics, data);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Schedules
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_initialize_event() {
+    let env = test_env();
+    let (admin, token) = (Address::generate(&env), Address::generate(&env));
+    let client = VestingContractClient::new(&env, &env.register_contract(None, VestingContract));
+    assert_sole_event(
+        &env,
+        &client.address,
+        || client.initialize(&admin, &token),
+        (symbol_short!("init"),),
+        (admin.clone(), token.clone()),
+    );
+}
+
+#[test]
+fn test_create_schedule_event() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    s.vesting.token.mint(&s.client().address, 500);
+    s.assert_event(
+        || s.client().create_schedule(&recipient, &500, &10, &20),
+        (events::SCHEDULE_CREATED, recipient.clone()),
+        ScheduleCreated {
+            total_amount: 500,
+            cliff_ledger: 10,
+            end_ledger: 20,
+        },
+    );
+}
+
+#[test]
+fn test_creator_events() {
+    let s = setup();
+    let creator = s.vesting.token.funded(800);
+    s.assert_event(
+        || s.client().set_creator(&creator, &true),
+        (symbol_short!("role_grnt"), CREATOR_ROLE, creator.clone()),
+        (),
+    );
+
+    let recipient = Address::generate(&s.env);
+    s.assert_event(
+        || {
+            s.client()
+                .create_schedule_from(&creator, &recipient, &500, &10, &20)
+        },
+        (events::SCHEDULE_CREATED, recipient.clone()),
+        ScheduleCreated {
+            total_amount: 500,
+            cliff_ledger: 10,
+            end_ledger: 20,
+        },
+    );
+    s.assert_event(
+        || s.client().top_up_from(&creator, &recipient, &300),
+        (symbol_short!("top_up"), recipient.clone()),
+        300i128,
+    );
+
+    s.assert_event(
+        || s.client().set_creator(&creator, &false),
+        (symbol_short!("role_rvk"), CREATOR_ROLE, creator.clone()),
+        (),
+    );
+}
+
+#[test]
+fn test_release_event() {
+    let s = setup();
+    jump_to(&s.env, 150);
+    s.assert_event(
+        || s.client().release(&s.recipient),
+        (events::RELEASED, s.recipient.clone()),
+        500i128,
+    );
+}
+
+#[test]
+fn test_revoke_event() {
+    let s = setup();
+    jump_to(&s.env, 150);
+    s.assert_event(
+        || s.client().revoke(&s.recipient),
+        (events::SCHEDULE_REVOKED, s.recipient.clone()),
+        ScheduleRevoked {
+            released: 500,
+            returned: 500,
+        },
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Staking and schedule transfers
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_staking_events() {
+    let s = setup();
+    let staking = Address::generate(&s.env);
+    s.assert_event(
+        || s.client().set_staking(&staking),
+        (symbol_short!("staking"),),
+        staking.clone(),
+    );
+    s.assert_event(
+        || s.client().lock(&s.recipient, &400),
+        (symbol_short!("lock"), s.recipient.clone()),
+        400i128,
+    );
+    s.assert_event(
+        || s.client().unlock(&s.recipient, &400),
+        (symbol_short!("unlock"), s.recipient.clone()),
+        400i128,
+    );
+}
+
+#[test]
+fn test_schedule_transfer_events() {
+    let s = setup();
+    let (operator, buyer) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.assert_event(
+        || s.client().set_transferable(&true),
+        (symbol_short!("transfers"),),
+        true,
+    );
+    s.assert_event(
+        || {
+            s.client()
+                .set_operator(&s.recipient, &Some(operator.clone()))
+        },
+        (symbol_short!("operator"), s.recipient.clone()),
+        Some(operator.clone()),
+    );
+    s.assert_event(
+        || s.client().set_operator(&s.recipient, &None),
+        (symbol_short!("operator"), s.recipient.clone()),
+        None::<Address>,
+    );
+
+    jump_to(&s.env, 150);
+    s.client().release(&s.recipient);
+    s.assert_event(
+        || {
+            s.client()
+                .transfer_schedule(&s.recipient, &s.recipient, &buyer)
+        },
+        (
+            symbol_short!("sch_xfer"),
+            s.recipient.clone(),
+            buyer.clone(),
+        ),
+        500i128,
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Administration
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_admin_transfer_events() {
+    let s = setup();
+    let new_admin = Address::generate(&s.env);
+    s.assert_event(
+        || s.client().propose_admin(&new_admin),
+        (symbol_short!("adm_prop"),),
+        new_admin.clone(),
+    );
+    s.assert_event(
+        || s.client().accept_admin(),
+        (symbol_short!("set_admin"),),
+        new_admin.clone(),
+    );
+}
+
+#[test]
+fn test_configuration_events() {
+    let s = setup();
+    let (upgrader, registry) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.assert_event(
+        || s.client().set_upgrader(&upgrader),
+        (symbol_short!("upgrader"),),
+        upgrader.clone(),
+    );
+    s.assert_event(
+        || s.client().set_pause_registry(&registry),
+        (symbol_short!("pause_reg"),),
+        registry.clone(),
+    );
+}
+
+#[test]
+fn test_read_only_calls_are_silent() {
+    let s = setup();
+    let events = events_during(&s.env, &s.client().address, || {
+        s.client().vested_amount(&s.recipient);
+        s.client().get_schedule(&s.recipient);
+        s.client().locked_amount(&s.recipient);
+    });
+    assert!(events.is_empty());
+}
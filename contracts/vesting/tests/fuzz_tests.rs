@@ -0,0 +1,183 @@
+//! # Fuzz / Property-Based Tests for the Vesting Formula
+//!
+//! Random `(total, cliff, end, ledger)` tuples are run through a real
+//! `VestingContract` funded with a Stellar asset contract, so the formula
+//! is checked as `vested_amount` and `release` see it rather than through
+//! the math crate alone. Schedules unlock linearly from the cliff to the
+//! end; the shapes of the shared curves themselves are covered by
+//! `crates/soropad_math/tests/proptests.rs`.
+//!
+//! ## Invariants
+//!
+//! 1. **Nothing Before the Cliff**
+//!    `vested_amount == 0` at every ledger up to `cliff_ledger`.
+//!
+//! 2. **Everything After the End**
+//!    `vested_amount == total_amount` at every ledger from `end_ledger` on.
+//!
+//! 3. **Bounded and Monotonic**
+//!    `0 <= vested_amount <= total_amount`, and it never decreases as the
+//!    ledger advances.
+//!
+//! 4. **Releases Sum to the Total**
+//!    However releases are spread over time, their sum once the schedule
+//!    has ended is exactly `total_amount`, and the vesting contract is left
+//!    holding nothing.
+//!
+//! 5. **No Wraparound at the `i128` Bounds**
+//!    Invariants 1 to 4 hold for totals up to `i128::MAX`, and `lock`
+//!    calls (the locks staking places on unreleased tokens) of boundary
+//!    amounts succeed exactly when the checked locked amount fits in what
+//!    is unreleased, or revert and change nothing.
+
+use launchpad_testutils::{test_env, VestingFixture};
+use proptest::prelude::*;
+use soroban_sdk::{testutils::Address as _, testutils::Ledger, token, Address, Env};
+use soroban_vesting::VestingContractClient;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// A funded schedule of `total` vesting from `cliff` to `end`, with the
+/// token client and the recipient.
+fn setup_schedule(
+    total: i128,
+    cliff: u32,
+    end: u32,
+) -> (
+    Env,
+    VestingContractClient<'static>,
+    token::Client<'static>,
+    Address,
+) {
+    // `test_env` keeps entries alive across the multi-million-ledger jumps
+    // below
+    let env = test_env();
+    let vesting = VestingFixture::new(&env);
+    let recipient = vesting.schedule(total, cliff, end);
+    (env, vesting.client, vesting.token.client, recipient)
+}
+
+/// Totals up to 10^18, or at and just below `i128::MAX` and half of it.
+fn any_total() -> impl Strategy<Value = i128> {
+    prop_oneof![
+        3 => 1i128..=1_000_000_000_000_000,
+        1 => (i128::MAX - 1_000)..=i128::MAX,
+        1 => (i128::MAX / 2 - 1_000)..=(i128::MAX / 2 + 1_000),
+    ]
+}
+
+/// Lock and unlock amounts clustered at the `i128` bounds.
+fn boundary_amount() -> impl Strategy<Value = i128> {
+    prop_oneof![
+        Just(i128::MAX),
+        (i128::MAX / 2 - 1_000)..=(i128::MAX / 2 + 1_000),
+        1i128..=1_000,
+        Just(0i128),
+        Just(i128::MIN),
+    ]
+}
+
+/// `(total, cliff, end)` with `cliff < end`.
+fn any_schedule() -> impl Strategy<Value = (i128, u32, u32)> {
+    (any_total(), 0u32..1_000_000, 1u32..1_000_000)
+        .prop_map(|(total, cliff, duration)| (total, cliff, cliff + duration))
+}
+
+// ===========================================================================
+// Property tests
+// ===========================================================================
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    /// Invariant 1: nothing vests up to the cliff.
+    #[test]
+    fn prop_nothing_before_cliff((total, cliff, end) in any_schedule(), at in 0u32..=1_000_000) {
+        let (env, client, _, recipient) = setup_schedule(total, cliff, end);
+        env.ledger().set_sequence_number(at.min(cliff));
+        prop_assert_eq!(client.vested_amount(&recipient), 0);
+    }
+
+    /// Invariant 2: everything has vested from the end on.
+    #[test]
+    fn prop_everything_after_end((total, cliff, end) in any_schedule(), after in 0u32..1_000_000) {
+        let (env, client, _, recipient) = setup_schedule(total, cliff, end);
+        env.ledger().set_sequence_number(end + after);
+        prop_assert_eq!(client.vested_amount(&recipient), total);
+    }
+
+    /// Invariant 3: vesting stays within `[0, total]` and never goes back.
+    #[test]
+    fn prop_bounded_and_monotonic(
+        (total, cliff, end) in any_schedule(),
+        mut ledgers in prop::collection::vec(0u32..2_100_000, 1..20),
+    ) {
+        let (env, client, _, recipient) = setup_schedule(total, cliff, end);
+        ledgers.sort_unstable();
+
+        let mut previous = 0;
+        for ledger in ledgers {
+            env.ledger().set_sequence_number(ledger);
+            let vested = client.vested_amount(&recipient);
+            prop_assert!(vested >= previous);
+            prop_assert!(vested <= total);
+            previous = vested;
+        }
+    }
+
+    /// Invariant 4: releases at any points in time add up to the total.
+    #[test]
+    fn prop_releases_sum_to_total(
+        (total, cliff, end) in any_schedule(),
+        mut ledgers in prop::collection::vec(0u32..2_000_000, 0..10),
+    ) {
+        let (env, client, token, recipient) = setup_schedule(total, cliff, end);
+        ledgers.sort_unstable();
+        ledgers.push(end);
+
+        for ledger in ledgers {
+            env.ledger().set_sequence_number(ledger);
+            let vested = client.vested_amount(&recipient);
+            let released = client.released_amount(&recipient);
+            // `release` reverts when there is nothing new to release
+            prop_assert_eq!(client.try_release(&recipient).is_ok(), vested > released);
+            prop_assert_eq!(token.balance(&recipient), client.released_amount(&recipient));
+        }
+
+        prop_assert_eq!(token.balance(&recipient), total);
+        prop_assert_eq!(client.released_amount(&recipient), total);
+        prop_assert_eq!(token.balance(&client.address), 0);
+    }
+
+    /// Invariant 5: locks and unlocks of boundary amounts follow a checked
+    /// model of the locked amount.
+    #[test]
+    fn prop_boundary_locks_never_wrap(
+        (total, cliff, end) in any_schedule(),
+        ops in prop::collection::vec((any::<bool>(), boundary_amount()), 1..12),
+    ) {
+        let (env, client, _, recipient) = setup_schedule(total, cliff, end);
+        client.set_staking(&Address::generate(&env));
+        let mut locked = 0i128;
+
+        for (lock, amount) in ops {
+            if lock {
+                let expected = amount > 0
+                    && locked.checked_add(amount).is_some_and(|next| next <= total);
+                prop_assert_eq!(client.try_lock(&recipient, &amount).is_ok(), expected);
+                if expected {
+                    locked += amount;
+                }
+            } else {
+                let expected = amount > 0 && amount <= locked;
+                prop_assert_eq!(client.try_unlock(&recipient, &amount).is_ok(), expected);
+                if expected {
+                    locked -= amount;
+                }
+            }
+            prop_assert_eq!(client.locked_amount(&recipient), locked);
+        }
+    }
+}
@@ -0,0 +1,571 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, symbol_short, token, Address, Env, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    ListingCount,
+    Listing(u32),
+    OfferCount,
+    Offer(u32),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum TradeStatus {
+    Open,
+    /// The schedule changed hands.
+    Settled,
+    /// Withdrawn; any escrowed payment went back to the buyer.
+    Cancelled,
+}
+
+/// A schedule put up for sale at a fixed price.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Listing {
+    pub id: u32,
+    pub vesting: Address,
+    pub seller: Address,
+    pub payment_token: Address,
+    pub price: i128,
+    /// Unreleased allocation of the schedule when listed, which it must
+    /// still hold when bought.
+    pub remaining: i128,
+    pub status: TradeStatus,
+}
+
+/// A bid for someone's schedule, with the payment escrowed here.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Offer {
+    pub id: u32,
+    pub vesting: Address,
+    /// Holder of the schedule bid for.
+    pub seller: Address,
+    pub buyer: Address,
+    pub payment_token: Address,
+    pub amount: i128,
+    /// Least unreleased allocation the schedule must still hold on
+    /// acceptance, so the seller cannot release it first.
+    pub min_remaining: i128,
+    /// Last ledger on which the offer can be accepted.
+    pub expiry_ledger: u32,
+    pub status: TradeStatus,
+}
+
+/// Mirror of the vesting contract's schedule.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct VestingSchedule {
+    pub recipient: Address,
+    pub total_amount: i128,
+    pub cliff_ledger: u32,
+    pub end_ledger: u32,
+    pub released: i128,
+    pub revoked: bool,
+}
+
+/// Transfer interface of the vesting contract.
+#[contractclient(name = "VestingClient")]
+pub trait Vesting {
+    fn set_operator(env: Env, recipient: Address, operator: Option<Address>);
+
+    fn transfer_schedule(env: Env, caller: Address, from: Address, to: Address);
+
+    fn get_schedule(env: Env, recipient: Address) -> VestingSchedule;
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Vesting Market Contract — OTC trades of locked allocations.
+///
+/// Sellers `list` their vesting schedule at a price. Listing approves this
+/// contract as the schedule's operator in the vesting contract, which
+/// escrows it: nothing can be released or staked until it sells or the
+/// listing is cancelled. `buy` pays the seller and moves the schedule to the
+/// buyer in the same call, provided the schedule still holds what it did
+/// when listed.
+///
+/// Buyers can also `make_offer` for any schedule, escrowing the payment
+/// here. The holder takes it with `accept_offer`, which checks the schedule
+/// still holds the offer's `min_remaining` before swapping.
+///
+/// Whether a project's schedules can trade at all is up to its vesting
+/// admin (`set_transferable` on the vesting contract).
+#[contract]
+pub struct VestingMarketContract;
+
+#[contractimpl]
+impl VestingMarketContract {
+    // ── Listings ────────────────────────────────────────────────────────
+
+    /// Put the schedule of `seller` in `vesting` up for `price` of
+    /// `payment_token`, escrowing it. Returns the listing id.
+    pub fn list(
+        env: Env,
+        seller: Address,
+        vesting: Address,
+        payment_token: Address,
+        price: i128,
+    ) -> u32 {
+        seller.require_auth();
+        assert!(price > 0, "price must be positive");
+        let vesting_client = VestingClient::new(&env, &vesting);
+        let schedule = vesting_client.get_schedule(&seller);
+        vesting_client.set_operator(&seller, &Some(env.current_contract_address()));
+
+        let id = Self::listing_count(env.clone());
+        let listing = Listing {
+            id,
+            vesting,
+            seller: seller.clone(),
+            payment_token,
+            price,
+            remaining: schedule.total_amount - schedule.released,
+            status: TradeStatus::Open,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Listing(id), &listing);
+        env.storage()
+            .instance()
+            .set(&DataKey::ListingCount, &(id + 1));
+
+        env.events()
+            .publish((symbol_short!("list"), seller), (id, price));
+        id
+    }
+
+    /// Withdraw listing `listing_id` and release the schedule from escrow.
+    /// Seller only.
+    pub fn cancel_listing(env: Env, listing_id: u32) {
+        let mut listing = Self::get_listing(env.clone(), listing_id);
+        listing.seller.require_auth();
+        assert!(listing.status == TradeStatus::Open, "listing not open");
+
+        listing.status = TradeStatus::Cancelled;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Listing(listing_id), &listing);
+        VestingClient::new(&env, &listing.vesting).set_operator(&listing.seller, &None);
+
+        env.events()
+            .publish((symbol_short!("unlist"), listing.seller), listing_id);
+    }
+
+    /// Pay the price of listing `listing_id` to the seller and take over the
+    /// schedule.
+    pub fn buy(env: Env, buyer: Address, listing_id: u32) {
+        buyer.require_auth();
+        let mut listing = Self::get_listing(env.clone(), listing_id);
+        assert!(listing.status == TradeStatus::Open, "listing not open");
+
+        let vesting = VestingClient::new(&env, &listing.vesting);
+        let schedule = vesting.get_schedule(&listing.seller);
+        assert!(
+            schedule.total_amount - schedule.released >= listing.remaining,
+            "schedule below listing terms"
+        );
+
+        listing.status = TradeStatus::Settled;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Listing(listing_id), &listing);
+
+        token::Client::new(&env, &listing.payment_token).transfer(
+            &buyer,
+            &listing.seller,
+            &listing.price,
+        );
+        vesting.transfer_schedule(&env.current_contract_address(), &listing.seller, &buyer);
+
+        env.events().publish(
+            (symbol_short!("buy"), listing.seller, buyer),
+            (listing_id, listing.price),
+        );
+    }
+
+    // ── Offers ──────────────────────────────────────────────────────────
+
+    /// Bid `amount` of `payment_token` for the schedule of `seller` in
+    /// `vesting`, escrowing the payment. Returns the offer id.
+    #[allow(clippy::too_many_arguments)]
+    pub fn make_offer(
+        env: Env,
+        buyer: Address,
+        vesting: Address,
+        seller: Address,
+        payment_token: Address,
+        amount: i128,
+        min_remaining: i128,
+        expiry_ledger: u32,
+    ) -> u32 {
+        buyer.require_auth();
+        assert!(amount > 0, "amount must be positive");
+        assert!(
+            expiry_ledger >= env.ledger().sequence(),
+            "expiry in the past"
+        );
+        token::Client::new(&env, &payment_token).transfer(
+            &buyer,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        let id = Self::offer_count(env.clone());
+        let offer = Offer {
+            id,
+            vesting,
+            seller: seller.clone(),
+            buyer: buyer.clone(),
+            payment_token,
+            amount,
+            min_remaining,
+            expiry_ledger,
+            status: TradeStatus::Open,
+        };
+        env.storage().persistent().set(&DataKey::Offer(id), &offer);
+        env.storage()
+            .instance()
+            .set(&DataKey::OfferCount, &(id + 1));
+
+        env.events()
+            .publish((symbol_short!("offer"), seller, buyer), (id, amount));
+        id
+    }
+
+    /// Withdraw offer `offer_id` and refund the payment. Buyer only.
+    pub fn cancel_offer(env: Env, offer_id: u32) {
+        let mut offer = Self::get_offer(env.clone(), offer_id);
+        offer.buyer.require_auth();
+        assert!(offer.status == TradeStatus::Open, "offer not open");
+
+        offer.status = TradeStatus::Cancelled;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Offer(offer_id), &offer);
+        token::Client::new(&env, &offer.payment_token).transfer(
+            &env.current_contract_address(),
+            &offer.buyer,
+            &offer.amount,
+        );
+
+        env.events()
+            .publish((symbol_short!("offer_cxl"), offer.buyer), offer_id);
+    }
+
+    /// Hand the schedule to the buyer of offer `offer_id` and collect the
+    /// escrowed payment. Seller only.
+    pub fn accept_offer(env: Env, offer_id: u32) {
+        let mut offer = Self::get_offer(env.clone(), offer_id);
+        offer.seller.require_auth();
+        assert!(offer.status == TradeStatus::Open, "offer not open");
+        assert!(
+            env.ledger().sequence() <= offer.expiry_ledger,
+            "offer expired"
+        );
+
+        let vesting = VestingClient::new(&env, &offer.vesting);
+        let schedule = vesting.get_schedule(&offer.seller);
+        assert!(
+            schedule.total_amount - schedule.released >= offer.min_remaining,
+            "schedule below offer terms"
+        );
+
+        offer.status = TradeStatus::Settled;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Offer(offer_id), &offer);
+        vesting.transfer_schedule(&offer.seller, &offer.seller, &offer.buyer);
+        token::Client::new(&env, &offer.payment_token).transfer(
+            &env.current_contract_address(),
+            &offer.seller,
+            &offer.amount,
+        );
+
+        env.events().publish(
+            (symbol_short!("accept"), offer.seller, offer.buyer),
+            (offer_id, offer.amount),
+        );
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Listings `start` to `start + limit - 1`, oldest first.
+    pub fn listings(env: Env, start: u32, limit: u32) -> Vec<Listing> {
+        let end = start
+            .saturating_add(limit)
+            .min(Self::listing_count(env.clone()));
+        let mut listings = Vec::new(&env);
+        for id in start..end {
+            listings.push_back(Self::get_listing(env.clone(), id));
+        }
+        listings
+    }
+
+    /// Offers `start` to `start + limit - 1`, oldest first.
+    pub fn offers(env: Env, start: u32, limit: u32) -> Vec<Offer> {
+        let end = start
+            .saturating_add(limit)
+            .min(Self::offer_count(env.clone()));
+        let mut offers = Vec::new(&env);
+        for id in start..end {
+            offers.push_back(Self::get_offer(env.clone(), id));
+        }
+        offers
+    }
+
+    pub fn get_listing(env: Env, listing_id: u32) -> Listing {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Listing(listing_id))
+            .expect("no listing found")
+    }
+
+    pub fn get_offer(env: Env, offer_id: u32) -> Offer {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Offer(offer_id))
+            .expect("no offer found")
+    }
+
+    pub fn listing_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ListingCount)
+            .unwrap_or(0)
+    }
+
+    pub fn offer_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::OfferCount)
+            .unwrap_or(0)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use launchpad_testutils::{test_env, TokenFixture};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger};
+
+    /// Transfer rules of the vesting contract, minus the vesting itself.
+    #[contract]
+    struct MockVesting;
+
+    #[contracttype]
+    enum MockKey {
+        Schedule(Address),
+        Operator(Address),
+    }
+
+    #[contractimpl]
+    impl MockVesting {
+        pub fn create(env: Env, recipient: Address, total_amount: i128) {
+            let schedule = VestingSchedule {
+                recipient: recipient.clone(),
+                total_amount,
+                cliff_ledger: 0,
+                end_ledger: 1,
+                released: 0,
+                revoked: false,
+            };
+            env.storage()
+                .instance()
+                .set(&MockKey::Schedule(recipient), &schedule);
+        }
+
+        pub fn set_released(env: Env, recipient: Address, released: i128) {
+            let mut schedule = Self::get_schedule(env.clone(), recipient.clone());
+            schedule.released = released;
+            env.storage()
+                .instance()
+                .set(&MockKey::Schedule(recipient), &schedule);
+        }
+
+        pub fn set_operator(env: Env, recipient: Address, operator: Option<Address>) {
+            recipient.require_auth();
+            env.storage()
+                .instance()
+                .set(&MockKey::Operator(recipient), &operator);
+        }
+
+        pub fn operator(env: Env, recipient: Address) -> Option<Address> {
+            env.storage()
+                .instance()
+                .get(&MockKey::Operator(recipient))
+                .unwrap_or(None)
+        }
+
+        pub fn transfer_schedule(env: Env, caller: Address, from: Address, to: Address) {
+            caller.require_auth();
+            assert!(caller == from || Self::operator(env.clone(), from.clone()) == Some(caller));
+            let mut schedule = Self::get_schedule(env.clone(), from.clone());
+            schedule.recipient = to.clone();
+            env.storage()
+                .instance()
+                .remove(&MockKey::Schedule(from.clone()));
+            env.storage().instance().remove(&MockKey::Operator(from));
+            env.storage()
+                .instance()
+                .set(&MockKey::Schedule(to), &schedule);
+        }
+
+        pub fn get_schedule(env: Env, recipient: Address) -> VestingSchedule {
+            env.storage()
+                .instance()
+                .get(&MockKey::Schedule(recipient))
+                .expect("no schedule found")
+        }
+    }
+
+    struct Setup {
+        env: Env,
+        client: VestingMarketContractClient<'static>,
+        vesting: MockVestingClient<'static>,
+        usdc: TokenFixture,
+        seller: Address,
+    }
+
+    /// The seller holds a 1 000-token schedule.
+    fn setup() -> Setup {
+        let env = test_env();
+        env.ledger().set_sequence_number(100);
+
+        let usdc = TokenFixture::stellar_asset(&env);
+        let vesting = MockVestingClient::new(&env, &env.register_contract(None, MockVesting));
+        let client = VestingMarketContractClient::new(
+            &env,
+            &env.register_contract(None, VestingMarketContract),
+        );
+        let seller = Address::generate(&env);
+        vesting.create(&seller, &1_000);
+
+        Setup {
+            usdc,
+            env,
+            client,
+            vesting,
+            seller,
+        }
+    }
+
+    fn funded_buyer(s: &Setup, amount: i128) -> Address {
+        let buyer = Address::generate(&s.env);
+        s.usdc.mint(&buyer, amount);
+        buyer
+    }
+
+    #[test]
+    fn test_list_and_buy() {
+        let s = setup();
+        let id = s
+            .client
+            .list(&s.seller, &s.vesting.address, &s.usdc.address, &400);
+        assert_eq!(
+            s.vesting.operator(&s.seller),
+            Some(s.client.address.clone())
+        );
+
+        let buyer = funded_buyer(&s, 400);
+        s.client.buy(&buyer, &id);
+        assert_eq!(s.usdc.balance(&s.seller), 400);
+        assert_eq!(s.vesting.get_schedule(&buyer).recipient, buyer);
+        assert_eq!(s.client.get_listing(&id).status, TradeStatus::Settled);
+    }
+
+    #[test]
+    #[should_panic(expected = "schedule below listing terms")]
+    fn test_buy_guards_against_release_after_listing() {
+        let s = setup();
+        let id = s
+            .client
+            .list(&s.seller, &s.vesting.address, &s.usdc.address, &400);
+        assert_eq!(s.client.get_listing(&id).remaining, 1_000);
+
+        s.vesting.set_released(&s.seller, &200);
+        s.client.buy(&funded_buyer(&s, 400), &id);
+    }
+
+    #[test]
+    fn test_cancel_listing_releases_escrow() {
+        let s = setup();
+        let id = s
+            .client
+            .list(&s.seller, &s.vesting.address, &s.usdc.address, &400);
+        s.client.cancel_listing(&id);
+        assert_eq!(s.vesting.operator(&s.seller), None);
+        assert!(s.client.try_buy(&funded_buyer(&s, 400), &id).is_err());
+    }
+
+    #[test]
+    fn test_offer_accepted() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 300);
+        let id = s.client.make_offer(
+            &buyer,
+            &s.vesting.address,
+            &s.seller,
+            &s.usdc.address,
+            &300,
+            &1_000,
+            &200,
+        );
+        assert_eq!(s.usdc.balance(&s.client.address), 300);
+
+        s.client.accept_offer(&id);
+        assert_eq!(s.usdc.balance(&s.seller), 300);
+        assert_eq!(s.vesting.get_schedule(&buyer).total_amount, 1_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "schedule below offer terms")]
+    fn test_offer_guards_against_prior_release() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 300);
+        let id = s.client.make_offer(
+            &buyer,
+            &s.vesting.address,
+            &s.seller,
+            &s.usdc.address,
+            &300,
+            &1_000,
+            &200,
+        );
+        s.vesting.set_released(&s.seller, &200);
+        s.client.accept_offer(&id);
+    }
+
+    #[test]
+    fn test_cancel_offer_refunds() {
+        let s = setup();
+        let buyer = funded_buyer(&s, 300);
+        let id = s.client.make_offer(
+            &buyer,
+            &s.vesting.address,
+            &s.seller,
+            &s.usdc.address,
+            &300,
+            &0,
+            &200,
+        );
+        s.client.cancel_offer(&id);
+        assert_eq!(s.usdc.balance(&buyer), 300);
+        assert!(s.client.try_accept_offer(&id).is_err());
+    }
+}
@@ -0,0 +1,252 @@
+#![no_std]
+
+use launchpad_access as access;
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol,
+};
+
+// ---------------------------------------------------------------------------
+// Storage types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Set for factories and upgrade managers allowed to record code.
+    Recorder(Address),
+    Build(BytesN<32>),
+    Deployment(Address),
+}
+
+/// A canonical build published by the platform.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Build {
+    /// Contract kind, e.g. `sale` or `token`.
+    pub name: Symbol,
+    pub version: u32,
+    /// Withdrawn, e.g. after a vulnerability was found.
+    pub revoked: bool,
+}
+
+/// The code a platform contract currently runs.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Deployment {
+    pub wasm_hash: BytesN<32>,
+    /// Factory or upgrade manager that recorded it.
+    pub recorder: Address,
+    pub recorded_ledger: u32,
+    /// Times the contract was upgraded since its deployment.
+    pub upgrades: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Contract
+// ---------------------------------------------------------------------------
+
+/// Wasm Registry Contract — proof that a contract runs a canonical build.
+///
+/// The admin publishes every canonical build with `add_build`: its wasm
+/// hash, the kind of contract and a version. Approved recorders, the sale
+/// and launch factories and the upgrade manager, `record` the hash each
+/// contract is deployed from and every hash it is upgraded to.
+///
+/// Wallets and auditors call `verify` on a contract address: it passes
+/// only if the platform recorded the contract and its current code is a
+/// published build that has not been revoked. Contracts deployed outside
+/// the platform's factories are never recorded, so they never verify.
+#[contract]
+pub struct WasmRegistryContract;
+
+#[contractimpl]
+impl WasmRegistryContract {
+    // ── Initialization ──────────────────────────────────────────────────
+
+    pub fn initialize(env: Env, admin: Address) {
+        access::init_admin(&env, &admin);
+    }
+
+    // ── Admin actions ───────────────────────────────────────────────────
+
+    /// Approve or remove `recorder`. Admin only.
+    pub fn set_recorder(env: Env, recorder: Address, allowed: bool) {
+        access::require_admin(&env);
+        let key = DataKey::Recorder(recorder.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("recorder"), recorder), allowed);
+    }
+
+    /// Publish `wasm_hash` as version `version` of `name`. Admin only.
+    pub fn add_build(env: Env, wasm_hash: BytesN<32>, name: Symbol, version: u32) {
+        access::require_admin(&env);
+        let key = DataKey::Build(wasm_hash.clone());
+        assert!(!env.storage().persistent().has(&key), "build exists");
+        let build = Build {
+            name,
+            version,
+            revoked: false,
+        };
+        env.storage().persistent().set(&key, &build);
+
+        env.events()
+            .publish((symbol_short!("build"), wasm_hash), build);
+    }
+
+    /// Withdraw a published build; contracts running it stop verifying.
+    /// Admin only.
+    pub fn revoke_build(env: Env, wasm_hash: BytesN<32>) {
+        access::require_admin(&env);
+        let mut build = Self::get_build(env.clone(), wasm_hash.clone()).expect("no build found");
+        build.revoked = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Build(wasm_hash.clone()), &build);
+
+        env.events()
+            .publish((symbol_short!("revoke"), wasm_hash), ());
+    }
+
+    // ── Recorders ───────────────────────────────────────────────────────
+
+    /// Record that `contract` now runs `wasm_hash`, on deployment or
+    /// upgrade. Approved recorders only.
+    pub fn record(env: Env, recorder: Address, contract: Address, wasm_hash: BytesN<32>) {
+        recorder.require_auth();
+        assert!(
+            Self::is_recorder(env.clone(), recorder.clone()),
+            "not a recorder"
+        );
+        let upgrades = match Self::get_deployment(env.clone(), contract.clone()) {
+            Some(deployment) => deployment.upgrades + 1,
+            None => 0,
+        };
+        let deployment = Deployment {
+            wasm_hash: wasm_hash.clone(),
+            recorder,
+            recorded_ledger: env.ledger().sequence(),
+            upgrades,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Deployment(contract.clone()), &deployment);
+
+        env.events()
+            .publish((symbol_short!("record"), contract), (wasm_hash, upgrades));
+    }
+
+    // ── Read-only queries ───────────────────────────────────────────────
+
+    /// Whether `contract` was recorded and runs a published, unrevoked
+    /// build.
+    pub fn verify(env: Env, contract: Address) -> bool {
+        let Some(deployment) = Self::get_deployment(env.clone(), contract) else {
+            return false;
+        };
+        match Self::get_build(env, deployment.wasm_hash) {
+            Some(build) => !build.revoked,
+            None => false,
+        }
+    }
+
+    pub fn get_deployment(env: Env, contract: Address) -> Option<Deployment> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Deployment(contract))
+    }
+
+    pub fn get_build(env: Env, wasm_hash: BytesN<32>) -> Option<Build> {
+        env.storage().persistent().get(&DataKey::Build(wasm_hash))
+    }
+
+    pub fn is_recorder(env: Env, recorder: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Recorder(recorder))
+    }
+
+    pub fn admin(env: Env) -> Address {
+        access::admin(&env)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    struct Setup {
+        env: Env,
+        client: WasmRegistryContractClient<'static>,
+        factory: Address,
+    }
+
+    /// Versions 1 and 2 of the sale are published.
+    fn setup() -> Setup {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let client = WasmRegistryContractClient::new(
+            &env,
+            &env.register_contract(None, WasmRegistryContract),
+        );
+        client.initialize(&Address::generate(&env));
+        let factory = Address::generate(&env);
+        client.set_recorder(&factory, &true);
+        client.add_build(&hash(&env, 1), &symbol_short!("sale"), &1);
+        client.add_build(&hash(&env, 2), &symbol_short!("sale"), &2);
+
+        Setup {
+            env,
+            client,
+            factory,
+        }
+    }
+
+    fn hash(env: &Env, byte: u8) -> BytesN<32> {
+        BytesN::from_array(env, &[byte; 32])
+    }
+
+    #[test]
+    fn test_recorded_canonical_contract_verifies() {
+        let s = setup();
+        let sale = Address::generate(&s.env);
+        assert!(!s.client.verify(&sale));
+
+        s.client.record(&s.factory, &sale, &hash(&s.env, 1));
+        assert!(s.client.verify(&sale));
+
+        s.client.record(&s.factory, &sale, &hash(&s.env, 2));
+        let deployment = s.client.get_deployment(&sale).unwrap();
+        assert_eq!(deployment.upgrades, 1);
+        assert_eq!(deployment.wasm_hash, hash(&s.env, 2));
+        assert_eq!(s.client.get_build(&hash(&s.env, 2)).unwrap().version, 2);
+    }
+
+    #[test]
+    fn test_unknown_or_revoked_build_fails() {
+        let s = setup();
+        let (patched, vulnerable) = (Address::generate(&s.env), Address::generate(&s.env));
+        s.client.record(&s.factory, &patched, &hash(&s.env, 9));
+        s.client.record(&s.factory, &vulnerable, &hash(&s.env, 1));
+        s.client.revoke_build(&hash(&s.env, 1));
+
+        assert!(!s.client.verify(&patched));
+        assert!(!s.client.verify(&vulnerable));
+    }
+
+    #[test]
+    #[should_panic(expected = "not a recorder")]
+    fn test_only_recorders_record() {
+        let s = setup();
+        let outsider = Address::generate(&s.env);
+        s.client.record(&outsider, &outsider, &hash(&s.env, 1));
+    }
+}
@@ -0,0 +1,326 @@
+#![no_std]
+
+//! Shared access control for the launchpad contracts.
+//!
+//! Contracts call these helpers from their own entry points instead of each
+//! keeping its own admin key and auth checks:
+//!
+//! - a single admin, set once with `init_admin` and handed over either
+//!   instantly with `set_admin` or in two steps with `propose_admin` /
+//!   `accept_admin`;
+//! - named roles (`grant_role` / `revoke_role`), checked with `has_role` or
+//!   `require_role`;
+//! - an upgrader, the only address that can `upgrade` the contract's code.
+//!
+//! `AccessKey::Admin` and `AccessKey::PendingAdmin` encode exactly like the
+//! `Admin` / `PendingAdmin` variants the contracts used before, so existing
+//! instances keep their admin.
+
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[derive(Clone)]
+#[contracttype]
+pub enum AccessKey {
+    Admin,
+    PendingAdmin,
+    Role(Symbol, Address),
+    Upgrader,
+}
+
+// ---------------------------------------------------------------------------
+// Admin
+// ---------------------------------------------------------------------------
+
+/// Store the first admin. Panics if the contract already has one.
+pub fn init_admin(env: &Env, admin: &Address) {
+    if has_admin(env) {
+        panic!("already initialized");
+    }
+    env.storage().instance().set(&AccessKey::Admin, admin);
+}
+
+pub fn has_admin(env: &Env) -> bool {
+    env.storage().instance().has(&AccessKey::Admin)
+}
+
+pub fn admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&AccessKey::Admin)
+        .expect("not initialized")
+}
+
+/// Require the admin's authorization and return the admin.
+pub fn require_admin(env: &Env) -> Address {
+    let admin = admin(env);
+    admin.require_auth();
+    admin
+}
+
+/// Hand the admin role to `new_admin` immediately. Admin only.
+pub fn set_admin(env: &Env, new_admin: &Address) {
+    require_admin(env);
+    _set_admin(env, new_admin);
+}
+
+/// Nominate `new_admin`, who takes over once they `accept_admin`. Replaces
+/// any earlier nomination. Admin only.
+pub fn propose_admin(env: &Env, new_admin: &Address) {
+    require_admin(env);
+    env.storage()
+        .instance()
+        .set(&AccessKey::PendingAdmin, new_admin);
+    env.events()
+        .publish((symbol_short!("adm_prop"),), new_admin.clone());
+}
+
+/// Complete a two-step transfer. Pending admin only.
+pub fn accept_admin(env: &Env) {
+    let pending = pending_admin(env).expect("no pending admin");
+    pending.require_auth();
+    env.storage().instance().remove(&AccessKey::PendingAdmin);
+    _set_admin(env, &pending);
+}
+
+pub fn pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&AccessKey::PendingAdmin)
+}
+
+// ---------------------------------------------------------------------------
+// Roles
+// ---------------------------------------------------------------------------
+
+/// Give `account` the `role`. Admin only.
+pub fn grant_role(env: &Env, role: &Symbol, account: &Address) {
+    require_admin(env);
+    env.storage()
+        .persistent()
+        .set(&AccessKey::Role(role.clone(), account.clone()), &true);
+    env.events().publish(
+        (symbol_short!("role_grnt"), role.clone(), account.clone()),
+        (),
+    );
+}
+
+/// Take the `role` away from `account`. Admin only.
+pub fn revoke_role(env: &Env, role: &Symbol, account: &Address) {
+    require_admin(env);
+    env.storage()
+        .persistent()
+        .remove(&AccessKey::Role(role.clone(), account.clone()));
+    env.events().publish(
+        (symbol_short!("role_rvk"), role.clone(), account.clone()),
+        (),
+    );
+}
+
+pub fn has_role(env: &Env, role: &Symbol, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&AccessKey::Role(role.clone(), account.clone()))
+}
+
+/// Require `account`'s authorization and that it holds `role`.
+pub fn require_role(env: &Env, role: &Symbol, account: &Address) {
+    account.require_auth();
+    assert!(has_role(env, role, account), "missing role");
+}
+
+// ---------------------------------------------------------------------------
+// Upgrades
+// ---------------------------------------------------------------------------
+
+/// Give the upgrade authority to `new_upgrader`, normally the upgrade manager.
+/// The admin picks the first upgrader; after that only the current upgrader
+/// can hand the authority on, so the admin cannot route around the
+/// upgrader's delay.
+pub fn set_upgrader(env: &Env, new_upgrader: &Address) {
+    match upgrader(env) {
+        Some(current) => current.require_auth(),
+        None => {
+            require_admin(env);
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&AccessKey::Upgrader, new_upgrader);
+    env.events()
+        .publish((symbol_short!("upgrader"),), new_upgrader.clone());
+}
+
+pub fn upgrader(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&AccessKey::Upgrader)
+}
+
+/// Replace the contract's code with the uploaded `wasm_hash`. Upgrader only.
+pub fn upgrade(env: &Env, wasm_hash: &BytesN<32>) {
+    upgrader(env).expect("no upgrader").require_auth();
+    env.deployer()
+        .update_current_contract_wasm(wasm_hash.clone());
+    env.events()
+        .publish((symbol_short!("upgrade"),), wasm_hash.clone());
+}
+
+// ---------------------------------------------------------------------------
+// Internals
+// ---------------------------------------------------------------------------
+
+fn _set_admin(env: &Env, new_admin: &Address) {
+    env.storage().instance().set(&AccessKey::Admin, new_admin);
+    env.events()
+        .publish((symbol_short!("set_admin"),), new_admin.clone());
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        contract, contractimpl, testutils::Address as _, testutils::MockAuth,
+        testutils::MockAuthInvoke, IntoVal,
+    };
+
+    const MINTER: Symbol = symbol_short!("minter");
+
+    #[contract]
+    struct Guarded;
+
+    #[contractimpl]
+    impl Guarded {
+        pub fn init(env: Env, admin: Address) {
+            init_admin(&env, &admin);
+        }
+
+        pub fn propose(env: Env, new_admin: Address) {
+            propose_admin(&env, &new_admin);
+        }
+
+        pub fn accept(env: Env) {
+            accept_admin(&env);
+        }
+
+        pub fn grant(env: Env, account: Address) {
+            grant_role(&env, &MINTER, &account);
+        }
+
+        pub fn revoke(env: Env, account: Address) {
+            revoke_role(&env, &MINTER, &account);
+        }
+
+        pub fn mint(env: Env, account: Address) {
+            require_role(&env, &MINTER, &account);
+        }
+
+        pub fn admin(env: Env) -> Address {
+            admin(&env)
+        }
+
+        pub fn pending(env: Env) -> Option<Address> {
+            pending_admin(&env)
+        }
+
+        pub fn set_upgrader(env: Env, upgrader: Address) {
+            set_upgrader(&env, &upgrader);
+        }
+
+        pub fn upgrader(env: Env) -> Option<Address> {
+            upgrader(&env)
+        }
+    }
+
+    fn setup() -> (Env, GuardedClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = GuardedClient::new(&env, &env.register_contract(None, Guarded));
+        let admin = Address::generate(&env);
+        client.init(&admin);
+        (env, client, admin)
+    }
+
+    #[test]
+    #[should_panic(expected = "already initialized")]
+    fn test_init_admin_once() {
+        let (_, client, admin) = setup();
+        client.init(&admin);
+    }
+
+    #[test]
+    fn test_two_step_transfer() {
+        let (env, client, admin) = setup();
+        let next = Address::generate(&env);
+
+        client.propose(&next);
+        assert_eq!(client.admin(), admin);
+        assert_eq!(client.pending(), Some(next.clone()));
+
+        client.accept();
+        assert_eq!(client.admin(), next);
+        assert_eq!(client.pending(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "no pending admin")]
+    fn test_accept_without_proposal() {
+        let (_, client, _) = setup();
+        client.accept();
+    }
+
+    #[test]
+    fn test_roles() {
+        let (env, client, _) = setup();
+        let minter = Address::generate(&env);
+        assert!(client.try_mint(&minter).is_err());
+
+        client.grant(&minter);
+        client.mint(&minter);
+
+        client.revoke(&minter);
+        assert!(client.try_mint(&minter).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_grant_role_requires_admin() {
+        let (env, client, _) = setup();
+        let outsider = Address::generate(&env);
+        env.mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "grant",
+                args: (outsider.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        client.grant(&outsider);
+    }
+
+    #[test]
+    fn test_only_upgrader_hands_on_upgrade_authority() {
+        let (env, client, admin) = setup();
+        let manager = Address::generate(&env);
+        assert_eq!(client.upgrader(), None);
+
+        client.set_upgrader(&manager);
+        assert_eq!(client.upgrader(), Some(manager.clone()));
+
+        // The admin can no longer take the authority back
+        env.mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_upgrader",
+                args: (admin.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_set_upgrader(&admin).is_err());
+    }
+}
@@ -0,0 +1,260 @@
+#![no_std]
+
+//! Event schema shared by the launchpad contracts.
+//!
+//! Every platform event follows the same layout, so indexers can decode any
+//! contract's events with one set of rules:
+//!
+//! - topic 0 is the event name, a `symbol_short!` value;
+//! - the following topics are the addresses (or ids) the event is about, so
+//!   they can be filtered on;
+//! - the data is a single amount, or one of the payload structs below when
+//!   the event carries several values.
+//!
+//! Token events keep the SEP-41 shapes (`transfer`, `mint`, `burn`,
+//! `approve`) so wallets and explorers read them as for any other token.
+//!
+//! Contracts emit through the helpers here rather than calling
+//! `env.events().publish` for these events themselves.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+// ---------------------------------------------------------------------------
+// Topics
+// ---------------------------------------------------------------------------
+
+pub const TRANSFER: Symbol = symbol_short!("transfer");
+pub const MINT: Symbol = symbol_short!("mint");
+pub const BURN: Symbol = symbol_short!("burn");
+pub const APPROVE: Symbol = symbol_short!("approve");
+
+pub const SCHEDULE_CREATED: Symbol = symbol_short!("create");
+pub const RELEASED: Symbol = symbol_short!("release");
+pub const SCHEDULE_REVOKED: Symbol = symbol_short!("revoke");
+
+pub const CONTRIBUTED: Symbol = symbol_short!("contrib");
+pub const CLAIMED: Symbol = symbol_short!("claim");
+pub const REFUNDED: Symbol = symbol_short!("refund");
+pub const SALE_FINALIZED: Symbol = symbol_short!("finalize");
+pub const SALE_CANCELLED: Symbol = symbol_short!("cancel");
+
+pub const PROPOSAL_CREATED: Symbol = symbol_short!("propose");
+pub const VOTE_CAST: Symbol = symbol_short!("vote");
+pub const PROPOSAL_QUEUED: Symbol = symbol_short!("queue");
+pub const PROPOSAL_EXECUTED: Symbol = symbol_short!("execute");
+
+// ---------------------------------------------------------------------------
+// Payloads
+// ---------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ScheduleCreated {
+    pub total_amount: i128,
+    pub cliff_ledger: u32,
+    pub end_ledger: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ScheduleRevoked {
+    /// Vested amount paid out to the recipient.
+    pub released: i128,
+    /// Unvested amount returned to the admin.
+    pub returned: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Contribution {
+    /// Payment-token amount contributed.
+    pub amount: i128,
+    /// Sale tokens bought with it.
+    pub tokens: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SaleFinalized {
+    pub succeeded: bool,
+    pub total_raised: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ProposalCreated {
+    pub proposal_id: u32,
+    pub snapshot_ledger: u32,
+    pub end_ledger: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct VoteCast {
+    pub proposal_id: u32,
+    /// 0 against, 1 for, 2 abstain.
+    pub support: u32,
+    pub weight: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ProposalQueued {
+    pub proposal_id: u32,
+    pub operation_id: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Token
+// ---------------------------------------------------------------------------
+
+pub fn transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
+    env.events()
+        .publish((TRANSFER, from.clone(), to.clone()), amount);
+}
+
+pub fn mint(env: &Env, to: &Address, amount: i128) {
+    env.events().publish((MINT, to.clone()), amount);
+}
+
+pub fn burn(env: &Env, from: &Address, amount: i128) {
+    env.events().publish((BURN, from.clone()), amount);
+}
+
+pub fn approve(env: &Env, owner: &Address, spender: &Address, amount: i128) {
+    env.events()
+        .publish((APPROVE, owner.clone(), spender.clone()), amount);
+}
+
+// ---------------------------------------------------------------------------
+// Vesting schedules
+// ---------------------------------------------------------------------------
+
+pub fn schedule_created(env: &Env, recipient: &Address, payload: ScheduleCreated) {
+    env.events()
+        .publish((SCHEDULE_CREATED, recipient.clone()), payload);
+}
+
+pub fn released(env: &Env, recipient: &Address, amount: i128) {
+    env.events().publish((RELEASED, recipient.clone()), amount);
+}
+
+pub fn schedule_revoked(env: &Env, recipient: &Address, payload: ScheduleRevoked) {
+    env.events()
+        .publish((SCHEDULE_REVOKED, recipient.clone()), payload);
+}
+
+// ---------------------------------------------------------------------------
+// Sales
+// ---------------------------------------------------------------------------
+
+pub fn contributed(env: &Env, buyer: &Address, payload: Contribution) {
+    env.events().publish((CONTRIBUTED, buyer.clone()), payload);
+}
+
+pub fn claimed(env: &Env, buyer: &Address, tokens: i128) {
+    env.events().publish((CLAIMED, buyer.clone()), tokens);
+}
+
+pub fn refunded(env: &Env, buyer: &Address, amount: i128) {
+    env.events().publish((REFUNDED, buyer.clone()), amount);
+}
+
+pub fn sale_finalized(env: &Env, payload: SaleFinalized) {
+    env.events().publish((SALE_FINALIZED,), payload);
+}
+
+pub fn sale_cancelled(env: &Env) {
+    env.events().publish((SALE_CANCELLED,), ());
+}
+
+// ---------------------------------------------------------------------------
+// Governance
+// ---------------------------------------------------------------------------
+
+pub fn proposal_created(env: &Env, proposer: &Address, payload: ProposalCreated) {
+    env.events()
+        .publish((PROPOSAL_CREATED, proposer.clone()), payload);
+}
+
+pub fn vote_cast(env: &Env, voter: &Address, payload: VoteCast) {
+    env.events().publish((VOTE_CAST, voter.clone()), payload);
+}
+
+pub fn proposal_queued(env: &Env, payload: ProposalQueued) {
+    env.events().publish((PROPOSAL_QUEUED,), payload);
+}
+
+pub fn proposal_executed(env: &Env, proposal_id: u32) {
+    env.events().publish((PROPOSAL_EXECUTED,), proposal_id);
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        contract, contractimpl, testutils::Address as _, testutils::Events, vec, IntoVal,
+    };
+
+    #[contract]
+    struct Emitter;
+
+    #[contractimpl]
+    impl Emitter {
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            transfer(&env, &from, &to, amount);
+        }
+
+        pub fn contribute(env: Env, buyer: Address, amount: i128, tokens: i128) {
+            contributed(&env, &buyer, Contribution { amount, tokens });
+        }
+    }
+
+    #[test]
+    fn test_transfer_keeps_sep41_shape() {
+        let env = Env::default();
+        let client = EmitterClient::new(&env, &env.register_contract(None, Emitter));
+        let (from, to) = (Address::generate(&env), Address::generate(&env));
+
+        client.transfer(&from, &to, &100);
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    client.address.clone(),
+                    (TRANSFER, from, to).into_val(&env),
+                    100i128.into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_payload_struct_as_data() {
+        let env = Env::default();
+        let client = EmitterClient::new(&env, &env.register_contract(None, Emitter));
+        let buyer = Address::generate(&env);
+
+        client.contribute(&buyer, &500, &2_000);
+        let payload = Contribution {
+            amount: 500,
+            tokens: 2_000,
+        };
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    client.address.clone(),
+                    (CONTRIBUTED, buyer).into_val(&env),
+                    payload.into_val(&env),
+                ),
+            ]
+        );
+    }
+}
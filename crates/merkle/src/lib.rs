@@ -0,0 +1,194 @@
+#![no_std]
+
+//! Merkle proofs and claimed bitmaps shared by the launchpad contracts.
+//!
+//! Allocation lists (airdrop rounds, insurance loss lists) are committed to
+//! on-chain as a single Merkle root. Each leaf is
+//! `sha256(xdr((index, account, amount)))`, and inner nodes hash their two
+//! children in sorted order, so a proof is just the list of siblings from
+//! the leaf up. Odd nodes are carried up a level unchanged.
+//!
+//! Which leaves have been paid is kept in a bitmap of `u128` words, one
+//! persistent entry per `WORD_BITS` indices. Each contract chooses the key
+//! of a word, typically its own `Claimed(list_id, word)` variant.
+
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Val, Vec};
+
+/// Leaf indices tracked by one bitmap word.
+pub const WORD_BITS: u32 = 128;
+
+// ---------------------------------------------------------------------------
+// Proofs
+// ---------------------------------------------------------------------------
+
+/// Leaf committing `amount` to `account` at position `index`.
+pub fn leaf(env: &Env, index: u32, account: &Address, amount: i128) -> BytesN<32> {
+    env.crypto()
+        .sha256(&(index, account.clone(), amount).to_xdr(env))
+        .into()
+}
+
+/// Fold `proof` into `leaf` with sorted-pair hashing and compare against
+/// `root`.
+pub fn verify(env: &Env, leaf: BytesN<32>, proof: &Vec<BytesN<32>>, root: &BytesN<32>) -> bool {
+    let mut node = leaf;
+    for sibling in proof.iter() {
+        node = hash_pair(env, &node, &sibling);
+    }
+    node == *root
+}
+
+/// Parent of `a` and `b`, independent of their order.
+pub fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a.to_array() <= b.to_array() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let mut data = Bytes::from_array(env, &first.to_array());
+    data.append(&Bytes::from_array(env, &second.to_array()));
+    env.crypto().sha256(&data).into()
+}
+
+/// Root of the tree over `leaves`, together with the proof for leaf
+/// `index`. Meant for tests and tooling; contracts only `verify`.
+pub fn root_and_proof(
+    env: &Env,
+    leaves: &Vec<BytesN<32>>,
+    index: u32,
+) -> (BytesN<32>, Vec<BytesN<32>>) {
+    let mut level = leaves.clone();
+    let mut position = index;
+    let mut proof = Vec::new(env);
+    while level.len() > 1 {
+        let sibling = position ^ 1;
+        if sibling < level.len() {
+            proof.push_back(level.get(sibling).unwrap());
+        }
+        let mut next = Vec::new(env);
+        let mut i = 0;
+        while i < level.len() {
+            if i + 1 < level.len() {
+                let (a, b) = (level.get(i).unwrap(), level.get(i + 1).unwrap());
+                next.push_back(hash_pair(env, &a, &b));
+            } else {
+                next.push_back(level.get(i).unwrap());
+            }
+            i += 2;
+        }
+        level = next;
+        position /= 2;
+    }
+    (level.get(0).unwrap(), proof)
+}
+
+// ---------------------------------------------------------------------------
+// Claimed bitmap
+// ---------------------------------------------------------------------------
+
+/// Whether `index` is marked in the bitmap whose words live under
+/// `word_key(word)` in persistent storage.
+pub fn is_claimed<K, F>(env: &Env, word_key: F, index: u32) -> bool
+where
+    K: IntoVal<Env, Val>,
+    F: Fn(u32) -> K,
+{
+    let word: u128 = env
+        .storage()
+        .persistent()
+        .get(&word_key(index / WORD_BITS))
+        .unwrap_or(0);
+    word & (1 << (index % WORD_BITS)) != 0
+}
+
+/// Mark `index` in the bitmap whose words live under `word_key(word)`.
+pub fn set_claimed<K, F>(env: &Env, word_key: F, index: u32)
+where
+    K: IntoVal<Env, Val>,
+    F: Fn(u32) -> K,
+{
+    let key = word_key(index / WORD_BITS);
+    let word: u128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&key, &(word | (1 << (index % WORD_BITS))));
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _, vec};
+
+    #[contract]
+    struct Bitmap;
+
+    #[contractimpl]
+    impl Bitmap {
+        pub fn claim(env: Env, list: u32, index: u32) {
+            set_claimed(&env, |word| (list, word), index);
+        }
+
+        pub fn claimed(env: Env, list: u32, index: u32) -> bool {
+            is_claimed(&env, |word| (list, word), index)
+        }
+    }
+
+    fn leaves(env: &Env, count: u32) -> Vec<BytesN<32>> {
+        let mut leaves = Vec::new(env);
+        for i in 0..count {
+            leaves.push_back(leaf(env, i, &Address::generate(env), 100 * (i as i128 + 1)));
+        }
+        leaves
+    }
+
+    #[test]
+    fn test_every_leaf_verifies_against_the_root() {
+        let env = Env::default();
+        for count in 1..8 {
+            let leaves = leaves(&env, count);
+            for i in 0..count {
+                let (root, proof) = root_and_proof(&env, &leaves, i);
+                assert!(verify(&env, leaves.get(i).unwrap(), &proof, &root));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejects_wrong_leaf_or_proof() {
+        let env = Env::default();
+        let leaves = leaves(&env, 5);
+        let (root, proof) = root_and_proof(&env, &leaves, 2);
+
+        assert!(!verify(&env, leaves.get(3).unwrap(), &proof, &root));
+        let (_, other) = root_and_proof(&env, &leaves, 3);
+        assert!(!verify(&env, leaves.get(2).unwrap(), &other, &root));
+        assert!(!verify(&env, leaves.get(2).unwrap(), &vec![&env], &root));
+    }
+
+    #[test]
+    fn test_hash_pair_is_order_independent() {
+        let env = Env::default();
+        let leaves = leaves(&env, 2);
+        let (a, b) = (leaves.get(0).unwrap(), leaves.get(1).unwrap());
+        assert_eq!(hash_pair(&env, &a, &b), hash_pair(&env, &b, &a));
+    }
+
+    #[test]
+    fn test_bitmap_marks_single_indices_per_list() {
+        let env = Env::default();
+        let client = BitmapClient::new(&env, &env.register_contract(None, Bitmap));
+
+        for index in [0, 127, 128, 1_000] {
+            assert!(!client.claimed(&0, &index));
+            client.claim(&0, &index);
+            assert!(client.claimed(&0, &index));
+            assert!(!client.claimed(&1, &index));
+        }
+        assert!(!client.claimed(&0, &1));
+        assert!(!client.claimed(&0, &129));
+    }
+}
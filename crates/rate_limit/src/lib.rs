@@ -0,0 +1,255 @@
+#![no_std]
+
+//! Rate limits shared by the launchpad contracts.
+//!
+//! A `RateLimit` caps how much can be consumed per `window` ledgers under
+//! some key (an account, a contract, the whole contract). Windows are
+//! aligned: window `n` covers ledgers `[n * window, (n + 1) * window)`.
+//!
+//! - `WindowKind::Fixed` counts only the current window, so usage resets at
+//!   every window boundary;
+//! - `WindowKind::Sliding` also counts the previous window, weighted by how
+//!   much of it still overlaps the last `window` ledgers. Bursts across a
+//!   boundary are damped, at the cost of being an estimate: the exact amount
+//!   consumed in any `window` consecutive ledgers can reach twice the limit.
+//!
+//! `Usage` is the per-key state. The pure `RateLimit` methods work on it
+//! directly; `available`, `try_consume` and `consume` keep it in the calling
+//! contract's persistent storage under a key of the caller's choosing.
+//!
+//! Property tests live in `tests/proptests.rs`.
+
+use soroban_sdk::{contracttype, Env, IntoVal, Val};
+use soropad_math::mul_div_ceil;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+pub enum WindowKind {
+    Fixed,
+    Sliding,
+}
+
+/// At most `limit` per `window` ledgers.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RateLimit {
+    pub kind: WindowKind,
+    pub limit: i128,
+    pub window: u32,
+}
+
+/// Amount consumed under one key.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct Usage {
+    /// Index of the window `current` was consumed in.
+    pub window: u32,
+    pub current: i128,
+    /// Amount consumed in the window before `window`.
+    pub previous: i128,
+}
+
+// ---------------------------------------------------------------------------
+// Limits
+// ---------------------------------------------------------------------------
+
+impl RateLimit {
+    pub fn fixed(limit: i128, window: u32) -> Self {
+        Self::new(WindowKind::Fixed, limit, window)
+    }
+
+    pub fn sliding(limit: i128, window: u32) -> Self {
+        Self::new(WindowKind::Sliding, limit, window)
+    }
+
+    pub fn new(kind: WindowKind, limit: i128, window: u32) -> Self {
+        let rate_limit = RateLimit {
+            kind,
+            limit,
+            window,
+        };
+        rate_limit.validate();
+        rate_limit
+    }
+
+    /// Panic unless the limit and window are positive. Contracts taking a
+    /// `RateLimit` as an argument should call this before storing it.
+    pub fn validate(&self) {
+        assert!(self.limit > 0, "limit must be positive");
+        assert!(self.window > 0, "window must be positive");
+    }
+
+    /// Index of the window ledger `now` falls in.
+    pub fn window_index(&self, now: u32) -> u32 {
+        now / self.window
+    }
+
+    /// First ledger of the window after the one `now` falls in.
+    pub fn next_window_ledger(&self, now: u32) -> u32 {
+        (self.window_index(now) + 1).saturating_mul(self.window)
+    }
+
+    /// `usage` as seen from ledger `now`: moved on to the current window,
+    /// with the old current window becoming the previous one if adjacent.
+    pub fn roll(&self, usage: &Usage, now: u32) -> Usage {
+        let index = self.window_index(now);
+        if usage.window == index {
+            usage.clone()
+        } else if usage.window.checked_add(1) == Some(index) {
+            Usage {
+                window: index,
+                current: 0,
+                previous: usage.current,
+            }
+        } else {
+            Usage {
+                window: index,
+                ..Usage::default()
+            }
+        }
+    }
+
+    /// Amount counted against the limit at ledger `now`.
+    pub fn used(&self, usage: &Usage, now: u32) -> i128 {
+        let usage = self.roll(usage, now);
+        match self.kind {
+            WindowKind::Fixed => usage.current,
+            WindowKind::Sliding => {
+                let overlap = self.window - now % self.window;
+                let carried = mul_div_ceil(usage.previous, overlap as i128, self.window as i128);
+                usage.current.saturating_add(carried)
+            }
+        }
+    }
+
+    /// Amount that can still be consumed at ledger `now`.
+    pub fn available(&self, usage: &Usage, now: u32) -> i128 {
+        (self.limit - self.used(usage, now)).max(0)
+    }
+
+    /// `usage` after consuming `amount` at ledger `now`, or `None` if that
+    /// would exceed the limit.
+    pub fn try_consume(&self, usage: &Usage, now: u32, amount: i128) -> Option<Usage> {
+        assert!(amount >= 0, "amount must be non-negative");
+        if amount > self.available(usage, now) {
+            return None;
+        }
+        let mut usage = self.roll(usage, now);
+        usage.current += amount;
+        Some(usage)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Stored usage
+// ---------------------------------------------------------------------------
+
+/// Usage stored under `key`; empty if none.
+pub fn usage<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> Usage {
+    env.storage().persistent().get(key).unwrap_or_default()
+}
+
+/// Amount that can still be consumed under `key` at the current ledger.
+pub fn available<K: IntoVal<Env, Val>>(env: &Env, key: &K, limit: &RateLimit) -> i128 {
+    limit.available(&usage(env, key), env.ledger().sequence())
+}
+
+/// Consume `amount` under `key` if the limit allows it. Returns whether it
+/// did; usage is only stored on success.
+pub fn try_consume<K: IntoVal<Env, Val>>(
+    env: &Env,
+    key: &K,
+    limit: &RateLimit,
+    amount: i128,
+) -> bool {
+    match limit.try_consume(&usage(env, key), env.ledger().sequence(), amount) {
+        Some(usage) => {
+            env.storage().persistent().set(key, &usage);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Consume `amount` under `key`, panicking with "rate limit exceeded" if
+/// the limit does not allow it.
+pub fn consume<K: IntoVal<Env, Val>>(env: &Env, key: &K, limit: &RateLimit, amount: i128) {
+    assert!(try_consume(env, key, limit, amount), "rate limit exceeded");
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        contract, contractimpl, symbol_short, testutils::Address as _, testutils::Ledger, Address,
+        Symbol,
+    };
+
+    #[contract]
+    struct Limited;
+
+    #[contractimpl]
+    impl Limited {
+        pub fn take(env: Env, account: Address, amount: i128) {
+            consume(
+                &env,
+                &(symbol_short!("take"), account),
+                &RateLimit::fixed(100, 10),
+                amount,
+            );
+        }
+
+        pub fn left(env: Env, account: Address) -> i128 {
+            available(
+                &env,
+                &(symbol_short!("take"), account),
+                &RateLimit::fixed(100, 10),
+            )
+        }
+    }
+
+    #[test]
+    fn test_stored_usage_per_key() {
+        let env = Env::default();
+        env.ledger().set_sequence_number(100);
+        let client = LimitedClient::new(&env, &env.register_contract(None, Limited));
+        let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+
+        client.take(&alice, &60);
+        assert_eq!(client.left(&alice), 40);
+        assert_eq!(client.left(&bob), 100);
+        assert!(client.try_take(&alice, &41).is_err());
+        assert_eq!(client.left(&alice), 40);
+
+        env.ledger().set_sequence_number(110);
+        assert_eq!(client.left(&alice), 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "rate limit exceeded")]
+    fn test_consume_over_limit() {
+        let env = Env::default();
+        let client = LimitedClient::new(&env, &env.register_contract(None, Limited));
+        client.take(&Address::generate(&env), &101);
+    }
+
+    #[test]
+    fn test_limit_round_trips_through_storage() {
+        let env = Env::default();
+        let id = env.register_contract(None, Limited);
+        let limit = RateLimit::sliding(5, 20);
+        let key = Symbol::new(&env, "limit");
+        env.as_contract(&id, || {
+            env.storage().instance().set(&key, &limit);
+            assert_eq!(env.storage().instance().get(&key), Some(limit.clone()));
+        });
+    }
+}
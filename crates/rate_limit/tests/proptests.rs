@@ -0,0 +1,209 @@
+//! # Property-Based Tests for the Shared Rate Limits
+//!
+//! Random histories of `(ledgers to wait, amount)` requests are replayed
+//! against both window kinds, keeping every request the limit accepts.
+//!
+//! ## Invariants
+//!
+//! 1. **Per-window cap**
+//!    Whatever the kind, the amount accepted within one aligned window
+//!    never exceeds the limit.
+//!
+//! 2. **Sliding bound**
+//!    With `WindowKind::Sliding`, the amount accepted within any `window`
+//!    consecutive ledgers never exceeds twice the limit.
+//!
+//! 3. **Exact admission**
+//!    `try_consume` succeeds exactly when the amount fits in `available`,
+//!    and then raises `used` by exactly that amount.
+//!
+//! 4. **Sliding is stricter**
+//!    For the same usage, a sliding limit never leaves more available than
+//!    a fixed one, and its usage only decays while nothing is consumed.
+//!
+//! 5. **Recovery**
+//!    After two idle windows the full limit is available again.
+//!
+//! 6. **No Wraparound at the `i128` Bounds**
+//!    With limits and amounts near `i128::MAX`, invariant 3 still holds
+//!    and nothing panics.
+
+use launchpad_rate_limit::*;
+use proptest::prelude::*;
+
+fn any_kind() -> impl Strategy<Value = WindowKind> {
+    prop_oneof![Just(WindowKind::Fixed), Just(WindowKind::Sliding)]
+}
+
+/// Non-negative amounts clustered at and just below `i128::MAX` and half
+/// of it, where sums of two of them overflow.
+fn boundary_amount() -> impl Strategy<Value = i128> {
+    prop_oneof![
+        Just(i128::MAX),
+        (i128::MAX - 1_000)..=i128::MAX,
+        (i128::MAX / 2 - 1_000)..=(i128::MAX / 2 + 1_000),
+        0i128..=1_000,
+    ]
+}
+
+fn any_usage() -> impl Strategy<Value = Usage> {
+    (0u32..1_000, 0i128..1_000, 0i128..1_000).prop_map(|(window, current, previous)| Usage {
+        window,
+        current,
+        previous,
+    })
+}
+
+/// Replay `requests` from ledger 0 and return the accepted ones as
+/// `(ledger, amount)`.
+fn replay(limit: &RateLimit, requests: &[(u32, i128)]) -> Vec<(u32, i128)> {
+    let mut usage = Usage::default();
+    let mut now = 0;
+    let mut accepted = Vec::new();
+    for &(wait, amount) in requests {
+        now += wait;
+        if let Some(next) = limit.try_consume(&usage, now, amount) {
+            usage = next;
+            accepted.push((now, amount));
+        }
+    }
+    accepted
+}
+
+proptest! {
+    #[test]
+    fn prop_never_exceeds_limit_per_window(
+        kind in any_kind(),
+        limit in 1i128..1_000,
+        window in 1u32..50,
+        requests in prop::collection::vec((0u32..20, 0i128..400), 1..60),
+    ) {
+        let limit = RateLimit::new(kind, limit, window);
+        let accepted = replay(&limit, &requests);
+        for &(ledger, _) in &accepted {
+            let index = limit.window_index(ledger);
+            let total: i128 = accepted
+                .iter()
+                .filter(|(l, _)| limit.window_index(*l) == index)
+                .map(|(_, a)| a)
+                .sum();
+            prop_assert!(total <= limit.limit);
+        }
+    }
+
+    #[test]
+    fn prop_sliding_bounds_any_span(
+        limit in 1i128..1_000,
+        window in 1u32..50,
+        requests in prop::collection::vec((0u32..20, 0i128..400), 1..60),
+    ) {
+        let limit = RateLimit::sliding(limit, window);
+        let accepted = replay(&limit, &requests);
+        for &(end, _) in &accepted {
+            let start = end.saturating_sub(window - 1);
+            let total: i128 = accepted
+                .iter()
+                .filter(|(l, _)| (start..=end).contains(l))
+                .map(|(_, a)| a)
+                .sum();
+            prop_assert!(total <= 2 * limit.limit);
+        }
+    }
+
+    #[test]
+    fn prop_try_consume_matches_available(
+        kind in any_kind(),
+        limit in 1i128..2_000,
+        window in 1u32..50,
+        usage in any_usage(),
+        now in 0u32..60_000,
+        amount in 0i128..2_000,
+    ) {
+        let limit = RateLimit::new(kind, limit, window);
+        let available = limit.available(&usage, now);
+        match limit.try_consume(&usage, now, amount) {
+            Some(next) => {
+                prop_assert!(amount <= available);
+                prop_assert_eq!(limit.used(&next, now), limit.used(&usage, now) + amount);
+            }
+            None => prop_assert!(amount > available),
+        }
+    }
+
+    #[test]
+    fn prop_sliding_is_stricter_and_decays(
+        limit in 1i128..2_000,
+        window in 1u32..50,
+        usage in any_usage(),
+        now in 0u32..60_000,
+        wait in 0u32..100,
+    ) {
+        let fixed = RateLimit::fixed(limit, window);
+        let sliding = RateLimit::sliding(limit, window);
+        prop_assert!(sliding.available(&usage, now) <= fixed.available(&usage, now));
+
+        let usage = sliding.roll(&usage, now);
+        prop_assert!(sliding.used(&usage, now + wait) <= sliding.used(&usage, now));
+    }
+
+    #[test]
+    fn prop_recovers_after_two_idle_windows(
+        kind in any_kind(),
+        limit in 1i128..2_000,
+        window in 1u32..50,
+        usage in any_usage(),
+    ) {
+        let limit = RateLimit::new(kind, limit, window);
+        let later = (usage.window + 2) * window;
+        prop_assert_eq!(limit.available(&usage, later), limit.limit);
+    }
+
+    #[test]
+    fn prop_boundary_amounts_never_wrap(
+        kind in any_kind(),
+        limit in boundary_amount().prop_filter("positive", |limit| *limit > 0),
+        window in 1u32..50,
+        requests in prop::collection::vec((0u32..100, boundary_amount()), 1..20),
+    ) {
+        let limit = RateLimit::new(kind, limit, window);
+        let (mut usage, mut now) = (Usage::default(), 0u32);
+        for (wait, amount) in requests {
+            now += wait;
+            let available = limit.available(&usage, now);
+            match limit.try_consume(&usage, now, amount) {
+                Some(next) => {
+                    prop_assert!(amount <= available);
+                    prop_assert_eq!(limit.used(&next, now), limit.used(&usage, now) + amount);
+                    usage = next;
+                }
+                None => prop_assert!(amount > available),
+            }
+            prop_assert!(limit.used(&usage, now) <= limit.limit);
+        }
+    }
+}
+
+#[test]
+fn test_fixed_window_resets_at_boundary() {
+    let limit = RateLimit::fixed(100, 10);
+    let usage = limit.try_consume(&Usage::default(), 19, 100).unwrap();
+    assert_eq!(limit.available(&usage, 19), 0);
+    assert_eq!(limit.available(&usage, 20), 100);
+    assert_eq!(limit.next_window_ledger(19), 20);
+}
+
+#[test]
+fn test_sliding_window_carries_previous_usage() {
+    let limit = RateLimit::sliding(100, 10);
+    let usage = limit.try_consume(&Usage::default(), 19, 100).unwrap();
+    // 7 of the last 10 ledgers still overlap the previous window
+    assert_eq!(limit.available(&usage, 23), 30);
+    assert_eq!(limit.available(&usage, 29), 90);
+    assert_eq!(limit.available(&usage, 30), 100);
+}
+
+#[test]
+#[should_panic(expected = "window must be positive")]
+fn test_zero_window_rejected() {
+    RateLimit::fixed(1, 0);
+}
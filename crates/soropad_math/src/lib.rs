@@ -0,0 +1,161 @@
+#![no_std]
+
+//! Fixed-point math shared by the launchpad contracts.
+//!
+//! - `mul_div` / `mul_div_ceil`: `a * b / denominator` with a 256-bit
+//!   intermediate product, so only the final result has to fit in `i128`;
+//! - `apply_bps`: a basis-point share of an amount;
+//! - `linear` / `step`: how much of a total has unlocked at a given ledger.
+//!
+//! The `checked_*` variants return `None` on a zero denominator or an
+//! out-of-range result; the others panic with "math overflow".
+//!
+//! Property tests for every helper live in `tests/proptests.rs`.
+
+/// Denominator of basis-point values: 10 000 bps is 100 %.
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+// ---------------------------------------------------------------------------
+// Multiply-divide
+// ---------------------------------------------------------------------------
+
+/// `a * b / denominator`, rounded toward zero like integer division.
+pub fn checked_mul_div(a: i128, b: i128, denominator: i128) -> Option<i128> {
+    _mul_div(a, b, denominator, false)
+}
+
+/// `a * b / denominator`, rounded away from zero. For the non-negative
+/// amounts the contracts deal in, this is the ceiling.
+pub fn checked_mul_div_ceil(a: i128, b: i128, denominator: i128) -> Option<i128> {
+    _mul_div(a, b, denominator, true)
+}
+
+pub fn mul_div(a: i128, b: i128, denominator: i128) -> i128 {
+    checked_mul_div(a, b, denominator).expect("math overflow")
+}
+
+pub fn mul_div_ceil(a: i128, b: i128, denominator: i128) -> i128 {
+    checked_mul_div_ceil(a, b, denominator).expect("math overflow")
+}
+
+// ---------------------------------------------------------------------------
+// Basis points
+// ---------------------------------------------------------------------------
+
+/// `bps` basis points of `amount`, rounded toward zero.
+pub fn apply_bps(amount: i128, bps: u32) -> i128 {
+    mul_div(amount, bps as i128, BPS_DENOMINATOR)
+}
+
+/// `part` as a share of `whole` in basis points, rounded toward zero.
+pub fn to_bps(part: i128, whole: i128) -> i128 {
+    mul_div(part, BPS_DENOMINATOR, whole)
+}
+
+// ---------------------------------------------------------------------------
+// Interpolation
+// ---------------------------------------------------------------------------
+
+/// Share of `total` unlocked at ledger `at` when it unlocks linearly from
+/// `start` to `end`: nothing up to `start`, everything from `end` on.
+pub fn linear(start: u32, end: u32, at: u32, total: i128) -> i128 {
+    if at >= end {
+        return total;
+    }
+    if at <= start {
+        return 0;
+    }
+    mul_div(total, (at - start) as i128, (end - start) as i128)
+}
+
+/// Like `linear`, but `total` unlocks in `steps` equal installments, the
+/// first one a `steps`-th of the way from `start` to `end`.
+pub fn step(start: u32, end: u32, at: u32, total: i128, steps: u32) -> i128 {
+    assert!(steps > 0, "steps must be positive");
+    if at >= end {
+        return total;
+    }
+    if at <= start {
+        return 0;
+    }
+    let done = (at - start) as u64 * steps as u64 / (end - start) as u64;
+    mul_div(total, done as i128, steps as i128)
+}
+
+// ---------------------------------------------------------------------------
+// Internals
+// ---------------------------------------------------------------------------
+
+fn _mul_div(a: i128, b: i128, denominator: i128, round_up: bool) -> Option<i128> {
+    if denominator == 0 {
+        return None;
+    }
+    // Fast path: the product fits in i128.
+    if let Some(product) = a.checked_mul(b) {
+        let quotient = product.checked_div(denominator)?;
+        if round_up && product % denominator != 0 {
+            let away = if (product < 0) == (denominator < 0) {
+                1
+            } else {
+                -1
+            };
+            return quotient.checked_add(away);
+        }
+        return Some(quotient);
+    }
+
+    let negative = (a < 0) ^ (b < 0) ^ (denominator < 0);
+    let (hi, lo) = _widening_mul(a.unsigned_abs(), b.unsigned_abs());
+    let (mut quotient, remainder) = _div_wide(hi, lo, denominator.unsigned_abs())?;
+    if round_up && remainder != 0 {
+        quotient = quotient.checked_add(1)?;
+    }
+
+    if negative {
+        if quotient > i128::MIN.unsigned_abs() {
+            return None;
+        }
+        Some((quotient as i128).wrapping_neg())
+    } else {
+        i128::try_from(quotient).ok()
+    }
+}
+
+/// Full 256-bit product of `a` and `b` as `(high, low)` halves.
+fn _widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let middle = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let low = (middle << 64) | (lo_lo & MASK);
+    let high = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (middle >> 64);
+    (high, low)
+}
+
+/// `(high, low) / divisor` as `(quotient, remainder)`, or `None` if the
+/// quotient does not fit in 128 bits.
+fn _div_wide(high: u128, low: u128, divisor: u128) -> Option<(u128, u128)> {
+    if high >= divisor {
+        return None;
+    }
+    // Restoring long division, one bit of `low` at a time. `remainder`
+    // stays below `divisor`; `carry` holds the bit shifted out of it.
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    for i in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> i) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+    Some((quotient, remainder))
+}
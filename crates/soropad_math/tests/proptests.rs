@@ -0,0 +1,200 @@
+//! # Property-Based Tests for the Shared Math Helpers
+//!
+//! Every helper is checked against a straightforward 256-bit reference
+//! ([`ethnum::I256`]) over the full `i128` range, not just the amounts the
+//! contracts see today.
+//!
+//! ## Invariants
+//!
+//! 1. **Exact multiply-divide**
+//!    `checked_mul_div(a, b, d)` equals `a * b / d` computed in 256 bits and
+//!    truncated toward zero, and is `None` exactly when that does not fit in
+//!    `i128` or `d == 0`.
+//!
+//! 2. **Rounding up**
+//!    `checked_mul_div_ceil` differs from `checked_mul_div` by one step away
+//!    from zero exactly when the division leaves a remainder.
+//!
+//! 3. **Basis points**
+//!    `apply_bps(x, 10_000) == x`, `apply_bps(x, 0) == 0`, and shares never
+//!    exceed the amount for non-negative amounts.
+//!
+//! 4. **Interpolation**
+//!    `linear` and `step` are 0 up to `start`, `total` from `end` on, never
+//!    decrease over time, and `step` never runs ahead of `linear`.
+
+use ethnum::I256;
+use proptest::prelude::*;
+use soropad_math::*;
+
+fn reference(a: i128, b: i128, d: i128) -> Option<(i128, bool)> {
+    if d == 0 {
+        return None;
+    }
+    let product = I256::from(a) * I256::from(b);
+    let quotient = product / I256::from(d);
+    let exact = product % I256::from(d) == 0;
+    i128::try_from(quotient).ok().map(|q| (q, exact))
+}
+
+fn any_amount() -> impl Strategy<Value = i128> {
+    prop_oneof![
+        any::<i128>(),
+        -1_000_000_000_000i128..1_000_000_000_000i128,
+        Just(i128::MAX),
+        Just(i128::MIN),
+        Just(0i128),
+        Just(1i128),
+        Just(-1i128),
+    ]
+}
+
+// ---------------------------------------------------------------------------
+// Multiply-divide
+// ---------------------------------------------------------------------------
+
+proptest! {
+    #[test]
+    fn prop_mul_div_matches_reference(a in any_amount(), b in any_amount(), d in any_amount()) {
+        prop_assert_eq!(checked_mul_div(a, b, d), reference(a, b, d).map(|(q, _)| q));
+    }
+
+    #[test]
+    fn prop_mul_div_ceil_rounds_away_from_zero(
+        a in any_amount(),
+        b in any_amount(),
+        d in any_amount(),
+    ) {
+        let expected = reference(a, b, d).and_then(|(q, exact)| {
+            if exact {
+                Some(q)
+            } else {
+                let negative = (I256::from(a) * I256::from(b) < 0) != (d < 0);
+                q.checked_add(if negative { -1 } else { 1 })
+            }
+        });
+        prop_assert_eq!(checked_mul_div_ceil(a, b, d), expected);
+    }
+
+    #[test]
+    fn prop_mul_div_of_large_non_negative_amounts(
+        a in 0..=i128::MAX,
+        b in 0..=i128::MAX,
+        d in 1..=i128::MAX,
+    ) {
+        let (q, exact) = match reference(a, b, d) {
+            Some(r) => r,
+            None => return Ok(()),
+        };
+        prop_assert_eq!(mul_div(a, b, d), q);
+        prop_assert_eq!(mul_div_ceil(a, b, d), if exact { q } else { q + 1 });
+    }
+
+    #[test]
+    fn prop_mul_div_identity(a in any_amount(), d in any_amount()) {
+        prop_assume!(d != 0);
+        prop_assert_eq!(checked_mul_div(a, d, d), Some(a));
+    }
+}
+
+#[test]
+fn test_mul_div_keeps_wide_intermediates() {
+    // i128::MAX * 3 overflows i128, but the result fits
+    assert_eq!(mul_div(i128::MAX, 3, 3), i128::MAX);
+    assert_eq!(mul_div(i128::MAX, i128::MAX, i128::MAX), i128::MAX);
+    assert_eq!(mul_div(i128::MIN, 2, 2), i128::MIN);
+    assert_eq!(checked_mul_div(i128::MIN, 2, -2), None);
+}
+
+#[test]
+fn test_mul_div_rejects_zero_denominator_and_overflow() {
+    assert_eq!(checked_mul_div(1, 1, 0), None);
+    assert_eq!(checked_mul_div(i128::MAX, 2, 1), None);
+    assert_eq!(checked_mul_div(i128::MIN, -1, 1), None);
+    assert_eq!(checked_mul_div_ceil(i128::MAX, 1, 1), Some(i128::MAX));
+}
+
+#[test]
+#[should_panic(expected = "math overflow")]
+fn test_mul_div_panics_on_overflow() {
+    mul_div(i128::MAX, 2, 1);
+}
+
+// ---------------------------------------------------------------------------
+// Basis points
+// ---------------------------------------------------------------------------
+
+proptest! {
+    #[test]
+    fn prop_apply_bps_bounds(amount in 0..=i128::MAX, bps in 0u32..=10_000) {
+        let share = apply_bps(amount, bps);
+        prop_assert!(share >= 0 && share <= amount);
+        prop_assert_eq!(apply_bps(amount, 0), 0);
+        prop_assert_eq!(apply_bps(amount, 10_000), amount);
+    }
+
+    #[test]
+    fn prop_to_bps_inverts_apply_bps(whole in 1i128..1_000_000_000_000_000, bps in 0u32..=10_000) {
+        let part = apply_bps(whole, bps);
+        // Rounding in `apply_bps` can only lose part of one bps
+        prop_assert!(to_bps(part, whole) <= bps as i128);
+        prop_assert!(to_bps(part + 1, whole) >= bps as i128);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Interpolation
+// ---------------------------------------------------------------------------
+
+proptest! {
+    #[test]
+    fn prop_linear_bounds_and_monotonic(
+        start in 0u32..1_000_000,
+        length in 1u32..1_000_000,
+        at in 0u32..3_000_000,
+        total in 0..=i128::MAX,
+    ) {
+        let end = start + length;
+        let unlocked = linear(start, end, at, total);
+        prop_assert!(unlocked >= 0 && unlocked <= total);
+        prop_assert!(linear(start, end, at + 1, total) >= unlocked);
+        if at <= start {
+            prop_assert_eq!(unlocked, 0);
+        }
+        if at >= end {
+            prop_assert_eq!(unlocked, total);
+        }
+    }
+
+    #[test]
+    fn prop_step_lags_linear(
+        start in 0u32..1_000_000,
+        length in 1u32..1_000_000,
+        at in 0u32..3_000_000,
+        total in 0..=i128::MAX,
+        steps in 1u32..1_000,
+    ) {
+        let end = start + length;
+        let unlocked = step(start, end, at, total, steps);
+        prop_assert!(unlocked >= 0 && unlocked <= linear(start, end, at, total));
+        prop_assert!(step(start, end, at + 1, total, steps) >= unlocked);
+        if at >= end {
+            prop_assert_eq!(unlocked, total);
+        }
+    }
+}
+
+#[test]
+fn test_step_unlocks_in_installments() {
+    // 4 installments of 250 over ledgers 100..200
+    assert_eq!(step(100, 200, 124, 1_000, 4), 0);
+    assert_eq!(step(100, 200, 125, 1_000, 4), 250);
+    assert_eq!(step(100, 200, 199, 1_000, 4), 750);
+    assert_eq!(step(100, 200, 200, 1_000, 4), 1_000);
+}
+
+#[test]
+fn test_linear_with_empty_range() {
+    assert_eq!(linear(100, 100, 99, 1_000), 0);
+    assert_eq!(linear(100, 100, 100, 1_000), 1_000);
+}
@@ -0,0 +1,835 @@
+//! Test fixtures shared by the launchpad contracts.
+//!
+//! - `test_env` is an `Env` with every auth mocked and entries that outlive
+//!   long ledger jumps;
+//! - `jump_to` and `advance` move the ledger;
+//! - `mock_auth` authorizes a single call for a single address;
+//! - `emitted` and `assert_emitted` look for a contract event, while
+//!   `events_during` and `assert_sole_event` check what one call published;
+//! - `StorageSnapshot` captures a contract's storage so an upgrade or
+//!   migration can be checked by diffing it, and `upgrade_in_place`
+//!   swaps a contract's code while keeping that storage;
+//! - `TokenFixture`, `VestingFixture` and `SaleFixture` register a token,
+//!   the real vesting contract or the real sale contract with the defaults
+//!   most tests start from;
+//! - `FactoryFixture` runs the real `LaunchFactory::launch`, through the
+//!   real sale factory, with the contracts it deploys running natively;
+//! - `Scenario` launches a project through `FactoryFixture`, and `Launch`
+//!   scripts its lifecycle: buying, finalizing, moving the ledger and
+//!   claiming.
+//!
+//! Every contract crate takes it as a dev-dependency. The token, vesting
+//! and sale crates do too, but as this crate depends on them, their unit
+//! tests see a second copy of their own contract through it; there they
+//! stick to the fixtures that do not register it, such as
+//! `TokenFixture::stellar_asset`. Their `tests/` suites have no such limit.
+
+use soroban_launch_factory::{
+    self as launch_factory, LaunchConfig, LaunchFactory, LaunchFactoryClient, LaunchRecord,
+    SaleParams, TokenParams, VestingAllocation,
+};
+use soroban_sale::{
+    Buyback, Extension, Liquidity, Payout, ProjectInfo, SaleConfig, SaleContract,
+    SaleContractClient, SaleMode, TierSource, PRICE_SCALE,
+};
+use soroban_sale_factory::{PlatformDefaults, SaleFactory, SaleFactoryClient};
+use soroban_sdk::{
+    testutils::{Address as _, ContractFunctionSet, Events as _, Ledger, MockAuth, MockAuthInvoke},
+    token, vec,
+    xdr::{
+        ContractDataDurability, LedgerEntryData, LedgerKey, LedgerKeyContractData, ScAddress,
+        ScVal, ToXdr,
+    },
+    Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
+};
+use soroban_token::{TokenContract, TokenContractClient};
+use soroban_vesting::{VestingContract, VestingContractClient};
+use std::{collections::BTreeMap, rc::Rc};
+
+/// Ledger TTL of every entry created in a `test_env`.
+pub const TEST_TTL: u32 = 10_000_000;
+
+// ---------------------------------------------------------------------------
+// Environment
+// ---------------------------------------------------------------------------
+
+/// An `Env` with all auths mocked, at ledger 0, whose entries are not
+/// archived by jumps of up to `TEST_TTL` ledgers.
+pub fn test_env() -> Env {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|ledger| {
+        ledger.min_persistent_entry_ttl = TEST_TTL;
+        ledger.min_temp_entry_ttl = TEST_TTL;
+        ledger.max_entry_ttl = TEST_TTL;
+    });
+    env
+}
+
+// ---------------------------------------------------------------------------
+// Ledger
+// ---------------------------------------------------------------------------
+
+/// Move to ledger `sequence`, advancing the timestamp 5 seconds per ledger.
+pub fn jump_to(env: &Env, sequence: u32) {
+    env.ledger().with_mut(|ledger| {
+        let elapsed = sequence.saturating_sub(ledger.sequence_number) as u64;
+        ledger.timestamp += elapsed * 5;
+        ledger.sequence_number = sequence;
+    });
+}
+
+/// Move `ledgers` ledgers forward.
+pub fn advance(env: &Env, ledgers: u32) {
+    jump_to(env, env.ledger().sequence() + ledgers);
+}
+
+// ---------------------------------------------------------------------------
+// Auth
+// ---------------------------------------------------------------------------
+
+/// Replace the mocked auths with `signer` authorizing one call of
+/// `contract.fn_name(args)` and nothing below it. Any other address a
+/// call requires is then missing.
+pub fn mock_auth<A>(env: &Env, signer: &Address, contract: &Address, fn_name: &str, args: A)
+where
+    A: IntoVal<Env, Vec<Val>>,
+{
+    env.mock_auths(&[MockAuth {
+        address: signer,
+        invoke: &MockAuthInvoke {
+            contract,
+            fn_name,
+            args: args.into_val(env),
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+// The test host keeps every event published since the `Env` was created,
+// not just those of the last call.
+
+/// Whether `contract` has published an event with exactly `topics` and
+/// `data`.
+pub fn emitted<T, D>(env: &Env, contract: &Address, topics: T, data: D) -> bool
+where
+    T: IntoVal<Env, Vec<Val>>,
+    D: IntoVal<Env, Val>,
+{
+    let topics = topics.into_val(env);
+    // `Val` has no equality of its own; compare through the host
+    let data: Vec<Val> = vec![env, data.into_val(env)];
+    env.events()
+        .all()
+        .iter()
+        .any(|(source, event_topics, event_data)| {
+            &source == contract && event_topics == topics && vec![env, event_data] == data
+        })
+}
+
+/// Panic unless `contract` has published an event with exactly `topics`
+/// and `data`.
+pub fn assert_emitted<T, D>(env: &Env, contract: &Address, topics: T, data: D)
+where
+    T: IntoVal<Env, Vec<Val>>,
+    D: IntoVal<Env, Val>,
+{
+    assert!(
+        emitted(env, contract, topics, data),
+        "event not emitted; events: {:?}",
+        env.events().all()
+    );
+}
+
+/// Run `call` and return the events `contract` published during it, as
+/// `(topics, data)`.
+pub fn events_during<R>(
+    env: &Env,
+    contract: &Address,
+    call: impl FnOnce() -> R,
+) -> Vec<(Vec<Val>, Val)> {
+    let before = env.events().all().len();
+    call();
+    let mut events = Vec::new(env);
+    for (source, topics, data) in env.events().all().iter().skip(before as usize) {
+        if &source == contract {
+            events.push_back((topics, data));
+        }
+    }
+    events
+}
+
+/// Run `call` and panic unless it made `contract` publish exactly one
+/// event, with `topics` and `data`.
+pub fn assert_sole_event<R, T, D>(
+    env: &Env,
+    contract: &Address,
+    call: impl FnOnce() -> R,
+    topics: T,
+    data: D,
+) where
+    T: IntoVal<Env, Vec<Val>>,
+    D: IntoVal<Env, Val>,
+{
+    let events = events_during(env, contract, call);
+    let expected = vec![env, (topics.into_val(env), data.into_val(env))];
+    assert!(
+        events == expected,
+        "expected only {:?}; events: {:?}",
+        expected,
+        events
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Storage
+// ---------------------------------------------------------------------------
+
+/// Where a storage entry lives.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StorageKind {
+    Instance,
+    Persistent,
+    Temporary,
+}
+
+/// Every storage entry of one contract at one moment, keyed by kind and
+/// key.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StorageSnapshot(pub BTreeMap<(StorageKind, ScVal), ScVal>);
+
+/// An entry that differs between two snapshots. `before` or `after` is
+/// `None` where the entry is missing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StorageChange {
+    pub kind: StorageKind,
+    pub key: ScVal,
+    pub before: Option<ScVal>,
+    pub after: Option<ScVal>,
+}
+
+impl StorageSnapshot {
+    /// The instance, persistent and temporary entries of `contract`.
+    pub fn take(env: &Env, contract: &Address) -> Self {
+        let contract = ScAddress::from(contract);
+        let mut entries = BTreeMap::new();
+        for (_, (entry, _)) in env.to_ledger_snapshot().ledger_entries {
+            let LedgerEntryData::ContractData(data) = entry.data else {
+                continue;
+            };
+            if data.contract != contract {
+                continue;
+            }
+            let kind = match data.durability {
+                ContractDataDurability::Persistent => StorageKind::Persistent,
+                ContractDataDurability::Temporary => StorageKind::Temporary,
+            };
+            match data.val {
+                // Instance storage is a map inside the instance entry
+                ScVal::ContractInstance(instance) => {
+                    for item in instance.storage.iter().flat_map(|map| map.iter()) {
+                        entries.insert((StorageKind::Instance, item.key.clone()), item.val.clone());
+                    }
+                }
+                val => {
+                    entries.insert((kind, data.key), val);
+                }
+            }
+        }
+        Self(entries)
+    }
+
+    /// The entry under `kind` and `key`, decoded.
+    pub fn get<K, V>(&self, env: &Env, kind: StorageKind, key: K) -> Option<V>
+    where
+        K: IntoVal<Env, Val>,
+        V: TryFromVal<Env, Val>,
+    {
+        let key = ScVal::try_from_val(env, &key.into_val(env)).unwrap();
+        let val = self.0.get(&(kind, key))?;
+        let val = Val::try_from_val(env, val).unwrap();
+        Some(V::try_from_val(env, &val).unwrap_or_else(|_| panic!("unexpected value type")))
+    }
+
+    /// The entries that differ in `after`, in key order.
+    pub fn diff(&self, after: &Self) -> std::vec::Vec<StorageChange> {
+        let mut keys: std::vec::Vec<_> = self.0.keys().chain(after.0.keys()).collect();
+        keys.sort();
+        keys.dedup();
+        keys.into_iter()
+            .filter_map(|entry| {
+                let (before, after) = (self.0.get(entry), after.0.get(entry));
+                (before != after).then(|| StorageChange {
+                    kind: entry.0,
+                    key: entry.1.clone(),
+                    before: before.cloned(),
+                    after: after.cloned(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Replace the code of `contract` with `new_version`, keeping its storage,
+/// as an upgrade to new wasm would. Native tests have no wasm, so this
+/// stands in for `upgrade` followed by the new version's first call.
+pub fn upgrade_in_place<C: ContractFunctionSet + 'static>(
+    env: &Env,
+    contract: &Address,
+    new_version: C,
+) {
+    env.register_contract(contract, new_version);
+}
+
+// ---------------------------------------------------------------------------
+// Token
+// ---------------------------------------------------------------------------
+
+/// A token and the account allowed to mint it.
+pub struct TokenFixture {
+    pub env: Env,
+    pub address: Address,
+    pub admin: Address,
+    pub client: token::Client<'static>,
+}
+
+impl TokenFixture {
+    /// A Stellar asset contract with nothing minted.
+    pub fn stellar_asset(env: &Env) -> Self {
+        Self::stellar_asset_of(env, Address::generate(env))
+    }
+
+    /// A Stellar asset contract administered by `admin`, such as a
+    /// contract that mints it, with nothing minted.
+    pub fn stellar_asset_of(env: &Env, admin: Address) -> Self {
+        let address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        Self::wrap(env, address, admin)
+    }
+
+    /// The launchpad's own `TokenContract` with 7 decimals, `supply` minted
+    /// to its admin and no supply cap.
+    pub fn launch_token(env: &Env, supply: i128) -> Self {
+        let admin = Address::generate(env);
+        let address = env.register_contract(None, TokenContract);
+        TokenContractClient::new(env, &address).initialize(
+            &admin,
+            &7,
+            &String::from_str(env, "Launch Token"),
+            &String::from_str(env, "LAUNCH"),
+            &supply,
+            &None,
+        );
+        Self::wrap(env, address, admin)
+    }
+
+    fn wrap(env: &Env, address: Address, admin: Address) -> Self {
+        Self {
+            env: env.clone(),
+            client: token::Client::new(env, &address),
+            address,
+            admin,
+        }
+    }
+
+    /// Mint `amount` to `to`. Both kinds of token share the admin `mint`.
+    pub fn mint(&self, to: &Address, amount: i128) {
+        token::StellarAssetClient::new(&self.env, &self.address).mint(to, &amount);
+    }
+
+    /// A new account holding `amount`.
+    pub fn funded(&self, amount: i128) -> Address {
+        let account = Address::generate(&self.env);
+        self.mint(&account, amount);
+        account
+    }
+
+    pub fn balance(&self, id: &Address) -> i128 {
+        self.client.balance(id)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Vesting
+// ---------------------------------------------------------------------------
+
+/// The vesting contract managing `token`.
+pub struct VestingFixture {
+    pub env: Env,
+    pub client: VestingContractClient<'static>,
+    pub admin: Address,
+    pub token: TokenFixture,
+}
+
+impl VestingFixture {
+    /// Vesting of a fresh Stellar asset contract.
+    pub fn new(env: &Env) -> Self {
+        Self::with_token(env, TokenFixture::stellar_asset(env))
+    }
+
+    /// Vesting of `token`.
+    pub fn with_token(env: &Env, token: TokenFixture) -> Self {
+        let admin = Address::generate(env);
+        let client = VestingContractClient::new(env, &env.register_contract(None, VestingContract));
+        client.initialize(&admin, &token.address);
+        Self {
+            env: env.clone(),
+            client,
+            admin,
+            token,
+        }
+    }
+
+    /// Mint `total` to the vesting contract and schedule it for a new
+    /// recipient from `cliff` to `end`. Returns the recipient.
+    pub fn schedule(&self, total: i128, cliff: u32, end: u32) -> Address {
+        let recipient = Address::generate(&self.env);
+        self.token.mint(&self.client.address, total);
+        self.client
+            .create_schedule(&recipient, &total, &cliff, &end);
+        recipient
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Sale
+// ---------------------------------------------------------------------------
+
+/// A sale contract, its two tokens and its fee recipient.
+pub struct SaleFixture {
+    pub env: Env,
+    pub client: SaleContractClient<'static>,
+    pub admin: Address,
+    pub sale_token: TokenFixture,
+    pub payment_token: TokenFixture,
+    pub fee_recipient: Address,
+}
+
+impl SaleFixture {
+    /// Fixed-price sale of 10 000 tokens at 1:1, soft cap 1 000, hard cap
+    /// 5 000, open between ledgers 100 and 200 with a 2.5 % platform fee.
+    /// The ledger is moved to 100.
+    pub fn new(env: &Env) -> Self {
+        Self::with_config(env, |_, _| {})
+    }
+
+    /// Like `new`, with `customize` applied to the config before
+    /// initialization.
+    pub fn with_config(env: &Env, customize: impl FnOnce(&Env, &mut SaleConfig)) -> Self {
+        let sale = Self::with_tokens(
+            env,
+            TokenFixture::stellar_asset(env),
+            TokenFixture::stellar_asset(env),
+            customize,
+        );
+        sale.sale_token.mint(&sale.client.address, 10_000);
+        sale
+    }
+
+    /// Like `with_config`, selling `sale_token` for `payment_token`. The
+    /// sale is left unfunded.
+    pub fn with_tokens(
+        env: &Env,
+        sale_token: TokenFixture,
+        payment_token: TokenFixture,
+        customize: impl FnOnce(&Env, &mut SaleConfig),
+    ) -> Self {
+        let admin = Address::generate(env);
+        let fee_recipient = Address::generate(env);
+        let client = SaleContractClient::new(env, &env.register_contract(None, SaleContract));
+
+        let mut config = SaleConfig {
+            token: sale_token.address.clone(),
+            payment_token: payment_token.address.clone(),
+            price: PRICE_SCALE,
+            soft_cap: 1_000,
+            hard_cap: 5_000,
+            min_contribution: 10,
+            max_contribution: 3_000,
+            start_ledger: 100,
+            end_ledger: 200,
+            fee_bps: 250,
+            fee_recipient: fee_recipient.clone(),
+            guardian: Address::generate(env),
+            mode: SaleMode::FixedPrice,
+            payout: Payout::Immediate,
+            buyback: Buyback::Disabled,
+            tier_pricing: Map::new(env),
+            tier_source: TierSource::Admin,
+            rounds: Vec::new(env),
+            extension: Extension::Disabled,
+            liquidity: Liquidity::Disabled,
+        };
+        customize(env, &mut config);
+        let project = ProjectInfo {
+            name: String::from_str(env, "Soropad Demo"),
+            token: config.token.clone(),
+            website_hash: BytesN::from_array(env, &[1u8; 32]),
+            docs_hash: BytesN::from_array(env, &[2u8; 32]),
+            socials_hash: BytesN::from_array(env, &[3u8; 32]),
+            audited_by: Vec::new(env),
+        };
+        client.initialize(&admin, &config, &project);
+        jump_to(env, config.start_ledger);
+
+        Self {
+            env: env.clone(),
+            client,
+            admin,
+            sale_token,
+            payment_token,
+            fee_recipient,
+        }
+    }
+
+    /// A new buyer holding `amount` of the payment token.
+    pub fn buyer(&self, amount: i128) -> Address {
+        self.payment_token.funded(amount)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Factories
+// ---------------------------------------------------------------------------
+
+/// A launch factory and the sale factory it deploys sales through, with a
+/// 2.5 % platform fee and one allowed payment token.
+///
+/// The factories deploy the token, vesting and sale by wasm hash, and
+/// native tests have no wasm. Both factories are therefore given the hash
+/// of the empty wasm, the executable the host treats as "dispatch to the
+/// registered native contract", and `launch` registers the native
+/// contracts at the addresses the factories will deploy to before calling
+/// the real `LaunchFactory::launch`.
+pub struct FactoryFixture {
+    pub env: Env,
+    pub client: LaunchFactoryClient<'static>,
+    pub sale_factory: SaleFactoryClient<'static>,
+    pub admin: Address,
+    pub payment_token: TokenFixture,
+    pub fee_recipient: Address,
+    pub guardian: Address,
+}
+
+impl FactoryFixture {
+    pub fn new(env: &Env) -> Self {
+        let native = env.deployer().upload_contract_wasm(Bytes::new(env));
+        let admin = Address::generate(env);
+        let fee_recipient = Address::generate(env);
+        let guardian = Address::generate(env);
+        let payment_token = TokenFixture::stellar_asset(env);
+
+        let sale_factory = SaleFactoryClient::new(env, &env.register_contract(None, SaleFactory));
+        sale_factory.initialize(
+            &admin,
+            &native,
+            &PlatformDefaults {
+                fee_bps: 250,
+                fee_recipient: fee_recipient.clone(),
+                guardian: guardian.clone(),
+                buyback: soroban_sale_factory::Buyback::Disabled,
+            },
+        );
+        sale_factory.set_payment_token_allowed(&payment_token.address, &true);
+
+        let client = LaunchFactoryClient::new(env, &env.register_contract(None, LaunchFactory));
+        client.initialize(&admin, &native, &native, &sale_factory.address);
+
+        Self {
+            env: env.clone(),
+            client,
+            sale_factory,
+            admin,
+            payment_token,
+            fee_recipient,
+            guardian,
+        }
+    }
+
+    /// 1 000 000 tokens with no vesting, 10 000 of them sold at 1:1 for
+    /// the payment token with the caps, limits and window of
+    /// `SaleFixture::new`.
+    pub fn config(&self) -> LaunchConfig {
+        let env = &self.env;
+        // `launch` fills in the token it deploys
+        let placeholder = Address::generate(env);
+        LaunchConfig {
+            token: TokenParams {
+                name: String::from_str(env, "Launch Token"),
+                symbol: String::from_str(env, "LAUNCH"),
+                decimals: 7,
+                total_supply: 1_000_000,
+                max_supply: None,
+            },
+            vesting: Vec::new(env),
+            vesting_creators: Vec::new(env),
+            sale_allocation: 10_000,
+            sale: SaleParams {
+                token: placeholder.clone(),
+                payment_token: self.payment_token.address.clone(),
+                price: PRICE_SCALE,
+                soft_cap: 1_000,
+                hard_cap: 5_000,
+                min_contribution: 10,
+                max_contribution: 3_000,
+                start_ledger: 100,
+                end_ledger: 200,
+                mode: launch_factory::SaleMode::FixedPrice,
+                payout: launch_factory::Payout::Immediate,
+                tier_pricing: Map::new(env),
+                tier_source: launch_factory::TierSource::Admin,
+                rounds: Vec::new(env),
+                extension: launch_factory::Extension::Disabled,
+                liquidity: launch_factory::Liquidity::Disabled,
+                project: launch_factory::ProjectInfo {
+                    name: String::from_str(env, "Soropad Demo"),
+                    token: placeholder,
+                    website_hash: BytesN::from_array(env, &[1u8; 32]),
+                    docs_hash: BytesN::from_array(env, &[2u8; 32]),
+                    socials_hash: BytesN::from_array(env, &[3u8; 32]),
+                    audited_by: Vec::new(env),
+                },
+            },
+        }
+    }
+
+    /// Launch `config` for `creator` through `LaunchFactory::launch` and
+    /// return the launch record.
+    pub fn launch(
+        &self,
+        creator: &Address,
+        salt: &BytesN<32>,
+        config: &LaunchConfig,
+    ) -> LaunchRecord {
+        let env = &self.env;
+        let part = |name: &str| -> BytesN<32> {
+            env.crypto()
+                .sha256(&(creator.clone(), salt.clone(), Symbol::new(env, name)).to_xdr(env))
+                .into()
+        };
+        let sale_salt: BytesN<32> = env
+            .crypto()
+            .sha256(&(creator.clone(), salt.clone()).to_xdr(env))
+            .into();
+
+        register_native_at(env, &self.client.address, part("token"), TokenContract);
+        register_native_at(env, &self.client.address, part("vesting"), VestingContract);
+        register_native_at(env, &self.sale_factory.address, sale_salt, SaleContract);
+
+        let index = self.client.launch(creator, salt, config);
+        self.client.get_launch(&index)
+    }
+}
+
+/// Register `contract` natively at the address `deployer` deploys to with
+/// `salt`, leaving the address free for that deployment. The contract
+/// instance created by the deployment then dispatches to `contract`.
+fn register_native_at<C: ContractFunctionSet + 'static>(
+    env: &Env,
+    deployer: &Address,
+    salt: BytesN<32>,
+    contract: C,
+) {
+    let address = env.as_contract(deployer, || {
+        env.deployer()
+            .with_current_contract(salt)
+            .deployed_address()
+    });
+    env.register_contract(&address, contract);
+    let instance = Rc::new(LedgerKey::ContractData(LedgerKeyContractData {
+        contract: ScAddress::from(&address),
+        key: ScVal::LedgerKeyContractInstance,
+        durability: ContractDataDurability::Persistent,
+    }));
+    let budget = env.host().budget_cloned();
+    env.host()
+        .with_mut_storage(|storage| storage.del(&instance, &budget))
+        .unwrap();
+}
+
+// ---------------------------------------------------------------------------
+// Scenario
+// ---------------------------------------------------------------------------
+
+/// A sale params change, applied before the launch.
+type SaleCustomization = Box<dyn FnOnce(&Env, &mut SaleParams)>;
+
+/// Builder of a whole launch, made by the real `LaunchFactory::launch`
+/// through a `FactoryFixture`: the launch token, vesting of it funded with
+/// every team allocation, and a sale of it funded with the sale allocation
+/// and allowed to create schedules. The creator administers all three.
+pub struct Scenario {
+    env: Env,
+    supply: i128,
+    sale_allocation: i128,
+    team: std::vec::Vec<(i128, u32, u32)>,
+    customize_sale: SaleCustomization,
+}
+
+impl Scenario {
+    /// 1 000 000 tokens, 10 000 of them for sale, no team allocations and
+    /// the `FactoryFixture::config` sale, paid out when claimed.
+    pub fn new(env: &Env) -> Self {
+        Self {
+            env: env.clone(),
+            supply: 1_000_000,
+            sale_allocation: 10_000,
+            team: std::vec::Vec::new(),
+            customize_sale: Box::new(|_, _| {}),
+        }
+    }
+
+    pub fn supply(mut self, supply: i128) -> Self {
+        self.supply = supply;
+        self
+    }
+
+    pub fn sale_allocation(mut self, amount: i128) -> Self {
+        self.sale_allocation = amount;
+        self
+    }
+
+    /// Vest `amount` to a new team member from `cliff` to `end`.
+    pub fn team(mut self, amount: i128, cliff: u32, end: u32) -> Self {
+        self.team.push((amount, cliff, end));
+        self
+    }
+
+    /// Apply `customize` to the sale params before the launch. The token
+    /// is filled in by the factory.
+    pub fn sale(mut self, customize: impl FnOnce(&Env, &mut SaleParams) + 'static) -> Self {
+        self.customize_sale = Box::new(customize);
+        self
+    }
+
+    /// Launch through the factory and move to the start of the sale. The
+    /// rest of the supply stays with the creator.
+    pub fn launch(self) -> Launch {
+        let env = self.env;
+        let factories = FactoryFixture::new(&env);
+        let creator = Address::generate(&env);
+
+        let mut config = factories.config();
+        config.token.total_supply = self.supply;
+        config.sale_allocation = self.sale_allocation;
+        let mut team = std::vec::Vec::new();
+        for (amount, cliff, end) in self.team {
+            let member = Address::generate(&env);
+            config.vesting.push_back(VestingAllocation {
+                recipient: member.clone(),
+                amount,
+                cliff_ledger: cliff,
+                end_ledger: end,
+            });
+            team.push(member);
+        }
+        (self.customize_sale)(&env, &mut config.sale);
+        let record = factories.launch(&creator, &BytesN::from_array(&env, &[0u8; 32]), &config);
+
+        let handle = || TokenFixture::wrap(&env, record.token.clone(), creator.clone());
+        let vesting = VestingFixture {
+            env: env.clone(),
+            client: VestingContractClient::new(&env, &record.vesting),
+            admin: creator.clone(),
+            token: handle(),
+        };
+        let sale = SaleFixture {
+            env: env.clone(),
+            client: SaleContractClient::new(&env, &record.sale),
+            admin: creator.clone(),
+            sale_token: handle(),
+            payment_token: TokenFixture::wrap(
+                &env,
+                factories.payment_token.address.clone(),
+                factories.payment_token.admin.clone(),
+            ),
+            fee_recipient: factories.fee_recipient.clone(),
+        };
+        if env.ledger().sequence() < config.sale.start_ledger {
+            jump_to(&env, config.sale.start_ledger);
+        }
+
+        let token = handle();
+        Launch {
+            env,
+            creator,
+            factories,
+            token,
+            vesting,
+            sale,
+            team,
+            buyers: std::vec::Vec::new(),
+        }
+    }
+}
+
+/// A launched project, stepped through its lifecycle by chaining calls.
+pub struct Launch {
+    pub env: Env,
+    /// Admin of the token, vesting and sale, and holder of the supply not
+    /// allocated at launch.
+    pub creator: Address,
+    /// The factories the project was launched through.
+    pub factories: FactoryFixture,
+    pub token: TokenFixture,
+    pub vesting: VestingFixture,
+    pub sale: SaleFixture,
+    /// Team members, in the order of their `Scenario::team` allocations.
+    pub team: std::vec::Vec<Address>,
+    /// Buyers, in the order they bought.
+    pub buyers: std::vec::Vec<Address>,
+}
+
+impl Launch {
+    /// Have one new buyer contribute each of `amounts`.
+    pub fn buy(&mut self, amounts: &[i128]) -> &mut Self {
+        for &amount in amounts {
+            let buyer = self.sale.buyer(amount);
+            self.sale.client.contribute(&buyer, &amount);
+            self.buyers.push(buyer);
+        }
+        self
+    }
+
+    /// Move to the end of the sale and finalize it.
+    pub fn finalize(&mut self) -> &mut Self {
+        let end = self.sale.client.get_config().end_ledger;
+        if self.env.ledger().sequence() < end {
+            jump_to(&self.env, end);
+        }
+        self.sale.client.finalize();
+        self
+    }
+
+    pub fn jump_to(&mut self, sequence: u32) -> &mut Self {
+        jump_to(&self.env, sequence);
+        self
+    }
+
+    pub fn advance(&mut self, ledgers: u32) -> &mut Self {
+        advance(&self.env, ledgers);
+        self
+    }
+
+    /// Claim every unclaimed purchase and release every team member's
+    /// vested tokens. The sale must have succeeded.
+    pub fn claim(&mut self) -> &mut Self {
+        for buyer in &self.buyers {
+            if !self.sale.client.get_purchase(buyer).claimed {
+                self.sale.client.claim(buyer);
+            }
+        }
+        for member in &self.team {
+            let vesting = &self.vesting.client;
+            if vesting.vested_amount(member) > vesting.released_amount(member) {
+                vesting.release(member);
+            }
+        }
+        self
+    }
+}
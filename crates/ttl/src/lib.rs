@@ -0,0 +1,198 @@
+#![no_std]
+
+//! Storage tiers and TTL policy shared by the launchpad contracts.
+//!
+//! Every contract entry lives in the tier that matches how long it matters:
+//!
+//! - **instance**: configuration and running totals (admin, metadata, total
+//!   supply, amount raised). Nearly every call reads them, and they share the
+//!   TTL of the contract instance, which `extend_instance` keeps alive.
+//! - **persistent**: per-user state that must never silently disappear
+//!   (balances, schedules, purchases). Contracts call `extend_persistent`
+//!   whenever they write such an entry, so an entry in use stays live and
+//!   only an abandoned one is archived, from where it can still be restored.
+//! - **temporary**: data that is worthless after a known ledger, such as an
+//!   allowance past its expiration. `set_temporary_until` keeps it exactly
+//!   that long, and nothing has to clean it up afterwards.
+//!
+//! A bump only happens once the remaining TTL has dropped a day (instance)
+//! or a week (persistent) below the full bump, so an entry touched on every
+//! ledger pays for an extension once per interval instead of on every call.
+
+use soroban_sdk::{Env, IntoVal, Val};
+
+/// Ledgers closed in a day, at five seconds per ledger.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// TTL `extend_instance` gives the contract instance.
+pub const INSTANCE_BUMP: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Remaining instance TTL below which `extend_instance` bumps it.
+pub const INSTANCE_THRESHOLD: u32 = INSTANCE_BUMP - DAY_IN_LEDGERS;
+
+/// TTL `extend_persistent` gives a persistent entry.
+pub const PERSISTENT_BUMP: u32 = 60 * DAY_IN_LEDGERS;
+
+/// Remaining TTL below which `extend_persistent` bumps an entry.
+pub const PERSISTENT_THRESHOLD: u32 = PERSISTENT_BUMP - 7 * DAY_IN_LEDGERS;
+
+/// Keep the contract instance, and with it every instance entry, alive for
+/// at least `INSTANCE_THRESHOLD` more ledgers.
+pub fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_THRESHOLD, INSTANCE_BUMP);
+}
+
+/// Keep the persistent entry under `key` alive for at least
+/// `PERSISTENT_THRESHOLD` more ledgers. The entry must exist.
+pub fn extend_persistent<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_THRESHOLD, PERSISTENT_BUMP);
+}
+
+/// Like `extend_persistent`, but when ledger `live_until` is further out
+/// than a full bump, keep the entry live through it, as far as the maximum
+/// TTL allows.
+pub fn extend_persistent_until<K: IntoVal<Env, Val>>(env: &Env, key: &K, live_until: u32) {
+    let storage = env.storage();
+    let extend_to = live_until
+        .saturating_sub(env.ledger().sequence())
+        .max(PERSISTENT_BUMP)
+        .min(storage.max_ttl());
+    let threshold = extend_to.saturating_sub(PERSISTENT_BUMP - PERSISTENT_THRESHOLD);
+    storage.persistent().extend_ttl(key, threshold, extend_to);
+}
+
+/// Store `value` under `key` in temporary storage, live through ledger
+/// `live_until` and no longer needed after it.
+pub fn set_temporary_until<K, V>(env: &Env, key: &K, value: &V, live_until: u32)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    assert!(
+        live_until <= env.ledger().max_live_until_ledger(),
+        "live_until is past the maximum TTL"
+    );
+    let storage = env.storage().temporary();
+    storage.set(key, value);
+    let live_for = live_until.saturating_sub(env.ledger().sequence());
+    storage.extend_ttl(key, live_for, live_for);
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{
+        contract, contractimpl, symbol_short,
+        testutils::{
+            storage::{Instance as _, Persistent as _, Temporary as _},
+            Ledger,
+        },
+        Symbol,
+    };
+
+    const KEY: Symbol = symbol_short!("key");
+
+    #[contract]
+    struct Tiers;
+
+    #[contractimpl]
+    impl Tiers {
+        pub fn put(env: Env) {
+            env.storage().persistent().set(&KEY, &1u32);
+            extend_persistent(&env, &KEY);
+            extend_instance(&env);
+        }
+
+        pub fn put_through(env: Env, live_until: u32) {
+            env.storage().persistent().set(&KEY, &1u32);
+            extend_persistent_until(&env, &KEY, live_until);
+        }
+
+        pub fn put_until(env: Env, live_until: u32) {
+            set_temporary_until(&env, &KEY, &1u32, live_until);
+        }
+
+        pub fn ttls(env: Env) -> (u32, u32) {
+            (
+                env.storage().instance().get_ttl(),
+                env.storage().persistent().get_ttl(&KEY),
+            )
+        }
+
+        pub fn temporary_ttl(env: Env) -> u32 {
+            env.storage().temporary().get_ttl(&KEY)
+        }
+
+        pub fn has_temporary(env: Env) -> bool {
+            env.storage().temporary().has(&KEY)
+        }
+    }
+
+    fn setup() -> (Env, TiersClient<'static>) {
+        let env = Env::default();
+        let client = TiersClient::new(&env, &env.register_contract(None, Tiers));
+        (env, client)
+    }
+
+    #[test]
+    fn test_bumps_to_full_ttl_then_waits_for_threshold() {
+        let (env, client) = setup();
+        client.put();
+        assert_eq!(client.ttls(), (INSTANCE_BUMP, PERSISTENT_BUMP));
+
+        // Above the thresholds nothing is extended
+        env.ledger().set_sequence_number(DAY_IN_LEDGERS / 2);
+        client.put();
+        let elapsed = DAY_IN_LEDGERS / 2;
+        assert_eq!(
+            client.ttls(),
+            (INSTANCE_BUMP - elapsed, PERSISTENT_BUMP - elapsed)
+        );
+
+        env.ledger().set_sequence_number(8 * DAY_IN_LEDGERS);
+        client.put();
+        assert_eq!(client.ttls(), (INSTANCE_BUMP, PERSISTENT_BUMP));
+    }
+
+    #[test]
+    fn test_persistent_entry_kept_through_later_ledger() {
+        let (env, client) = setup();
+        client.put_through(&100);
+        assert_eq!(client.ttls().1, PERSISTENT_BUMP);
+
+        let end = 200 * DAY_IN_LEDGERS;
+        client.put_through(&end);
+        assert_eq!(client.ttls().1, end);
+
+        // Never past the maximum TTL
+        client.put_through(&u32::MAX);
+        assert_eq!(client.ttls().1, env.storage().max_ttl());
+    }
+
+    #[test]
+    fn test_temporary_entry_lives_until_given_ledger() {
+        let (env, client) = setup();
+        client.put_until(&1_000);
+        assert_eq!(client.temporary_ttl(), 1_000);
+
+        env.ledger().set_sequence_number(1_000);
+        assert!(client.has_temporary());
+        env.ledger().set_sequence_number(1_001);
+        assert!(!client.has_temporary());
+    }
+
+    #[test]
+    #[should_panic(expected = "live_until is past the maximum TTL")]
+    fn test_temporary_entry_cannot_outlive_max_ttl() {
+        let (env, client) = setup();
+        client.put_until(&(env.ledger().max_live_until_ledger() + 1));
+    }
+}
@@ -0,0 +1,134 @@
+//! # Budget Regression Tests
+//!
+//! Records the CPU-instruction and memory cost of the hottest entry points
+//! and fails when a change makes one of them noticeably more expensive, so
+//! storage-layout and feature changes cannot creep towards the network's
+//! per-transaction limits unnoticed.
+//!
+//! The contracts run natively here, so the figures cover host work
+//! (storage, auth, events, cross-contract calls) and undercount the guest
+//! code a wasm build would also pay for. They are a regression signal, not
+//! a fee estimate.
+//!
+//! When a change is meant to cost more, raise the recorded cost to the
+//! measured one from the failure message in the same commit.
+
+use launchpad_testutils::{jump_to, test_env, SaleFixture, TokenFixture, VestingFixture};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+/// Cost of one call.
+#[derive(Clone, Copy, Debug)]
+struct Cost {
+    cpu: u64,
+    mem: u64,
+}
+
+/// How far a measured cost may exceed the recorded one, in percent.
+const TOLERANCE_PERCENT: u64 = 10;
+
+/// Per-transaction limits of the network.
+const NETWORK_LIMIT: Cost = Cost {
+    cpu: 100_000_000,
+    mem: 41_943_040,
+};
+
+const TRANSFER: Cost = Cost {
+    cpu: 362_600,
+    mem: 43_200,
+};
+const TRANSFER_FROM: Cost = Cost {
+    cpu: 399_400,
+    mem: 48_500,
+};
+const RELEASE: Cost = Cost {
+    cpu: 464_700,
+    mem: 57_200,
+};
+const CONTRIBUTE: Cost = Cost {
+    cpu: 509_000,
+    mem: 72_900,
+};
+
+/// Cost of `call` alone.
+fn measure(env: &Env, call: impl FnOnce()) -> Cost {
+    env.budget().reset_default();
+    call();
+    let budget = env.budget();
+    Cost {
+        cpu: budget.cpu_instruction_cost(),
+        mem: budget.memory_bytes_cost(),
+    }
+}
+
+/// Panic if `measured` exceeds `recorded` by more than the tolerance, or
+/// the network limits at all.
+fn assert_within(name: &str, measured: Cost, recorded: Cost) {
+    let ceiling = |recorded: u64| recorded * (100 + TOLERANCE_PERCENT) / 100;
+    assert!(
+        measured.cpu <= ceiling(recorded.cpu),
+        "{name}: {} cpu instructions, recorded {}",
+        measured.cpu,
+        recorded.cpu
+    );
+    assert!(
+        measured.mem <= ceiling(recorded.mem),
+        "{name}: {} memory bytes, recorded {}",
+        measured.mem,
+        recorded.mem
+    );
+    assert!(measured.cpu < NETWORK_LIMIT.cpu && measured.mem < NETWORK_LIMIT.mem);
+}
+
+#[test]
+fn test_token_transfer_cost() {
+    let env = test_env();
+    let token = TokenFixture::launch_token(&env, 1_000_000);
+    let to = Address::generate(&env);
+    // Warm the receiver's entries so the measured call is a steady-state one
+    token.client.transfer(&token.admin, &to, &1);
+
+    let cost = measure(&env, || token.client.transfer(&token.admin, &to, &1_000));
+    assert_within("transfer", cost, TRANSFER);
+}
+
+#[test]
+fn test_token_transfer_from_cost() {
+    let env = test_env();
+    let token = TokenFixture::launch_token(&env, 1_000_000);
+    let (spender, to) = (Address::generate(&env), Address::generate(&env));
+    token
+        .client
+        .approve(&token.admin, &spender, &10_000, &1_000);
+    token.client.transfer(&token.admin, &to, &1);
+
+    let cost = measure(&env, || {
+        token
+            .client
+            .transfer_from(&spender, &token.admin, &to, &1_000)
+    });
+    assert_within("transfer_from", cost, TRANSFER_FROM);
+}
+
+#[test]
+fn test_vesting_release_cost() {
+    let env = test_env();
+    let vesting = VestingFixture::with_token(&env, TokenFixture::launch_token(&env, 0));
+    let recipient = vesting.schedule(1_000_000, 100, 200);
+    jump_to(&env, 150);
+    vesting.client.release(&recipient);
+    jump_to(&env, 175);
+
+    let cost = measure(&env, || vesting.client.release(&recipient));
+    assert_within("release", cost, RELEASE);
+}
+
+#[test]
+fn test_sale_contribute_cost() {
+    let env = test_env();
+    let sale = SaleFixture::new(&env);
+    let buyer = sale.buyer(2_000);
+    sale.client.contribute(&buyer, &1_000);
+
+    let cost = measure(&env, || sale.client.contribute(&buyer, &1_000));
+    assert_within("contribute", cost, CONTRIBUTE);
+}
@@ -0,0 +1,150 @@
+//! # Launch Factory Tests
+//!
+//! The real `LaunchFactory::launch`, deploying through the real sale
+//! factory, checked for what it leaves behind: who administers each
+//! contract, where the supply went and how the contracts point at each
+//! other.
+
+use launchpad_testutils::{assert_emitted, jump_to, test_env, FactoryFixture};
+use soroban_launch_factory::{VestingAllocation, WiringManifest};
+use soroban_registry::{RegistryContract, RegistryContractClient};
+use soroban_sale::SaleContractClient;
+use soroban_sdk::{symbol_short, testutils::Address as _, token, vec, Address, BytesN, Env};
+use soroban_token::TokenContractClient;
+use soroban_vesting::VestingContractClient;
+
+fn salt(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[7u8; 32])
+}
+
+#[test]
+fn test_launch_deploys_and_wires_every_contract() {
+    let env = test_env();
+    let factories = FactoryFixture::new(&env);
+    let registry =
+        RegistryContractClient::new(&env, &env.register_contract(None, RegistryContract));
+    registry.initialize(&factories.admin);
+    registry.set_registrar(&factories.client.address, &Some(1));
+    factories
+        .client
+        .set_registry(&Some(registry.address.clone()));
+
+    let (creator, member, airdrop) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    let mut config = factories.config();
+    config.vesting = vec![
+        &env,
+        VestingAllocation {
+            recipient: member.clone(),
+            amount: 200_000,
+            cliff_ledger: 1_000,
+            end_ledger: 5_000,
+        },
+    ];
+    config.vesting_creators = vec![&env, airdrop.clone()];
+    let launch = factories.launch(&creator, &salt(&env), &config);
+    assert_eq!(launch.creator, creator);
+    assert_eq!(factories.client.launch_count(), 1);
+
+    // The creator administers the token and holds what was not allocated
+    let token = TokenContractClient::new(&env, &launch.token);
+    assert_eq!(token.admin(), creator);
+    assert_eq!(token.pending_admin(), None);
+    assert_eq!(token.total_supply(), 1_000_000);
+    let balances = token::Client::new(&env, &launch.token);
+    assert_eq!(balances.balance(&creator), 790_000);
+    assert_eq!(balances.balance(&launch.vesting), 200_000);
+    assert_eq!(balances.balance(&launch.sale), 10_000);
+    assert_eq!(balances.balance(&factories.client.address), 0);
+
+    // Vesting of the new token, administered by the creator, funding the
+    // team schedule and open to the sale and the extra creators
+    let vesting = VestingContractClient::new(&env, &launch.vesting);
+    assert_eq!(vesting.admin(), creator);
+    assert!(vesting.is_creator(&launch.sale));
+    assert!(vesting.is_creator(&airdrop));
+    assert!(!vesting.is_creator(&creator));
+    assert_eq!(vesting.get_schedule(&member).total_amount, 200_000);
+    jump_to(&env, 5_000);
+    vesting.release(&member);
+    assert_eq!(balances.balance(&member), 200_000);
+
+    // Sale of the new token with the platform defaults, in the sale factory
+    let sale = SaleContractClient::new(&env, &launch.sale);
+    let sale_config = sale.get_config();
+    assert_eq!(sale.admin(), creator);
+    assert_eq!(sale_config.token, launch.token);
+    assert_eq!(sale.project_info().token, launch.token);
+    assert_eq!(sale_config.payment_token, factories.payment_token.address);
+    assert_eq!(sale_config.fee_bps, 250);
+    assert_eq!(sale_config.fee_recipient, factories.fee_recipient);
+    assert_eq!(sale_config.guardian, factories.guardian);
+    assert!(factories.sale_factory.is_sale(&launch.sale));
+    assert_eq!(factories.sale_factory.get_sale(&0).admin, creator);
+
+    let project = registry.project_by_token(&launch.token).unwrap();
+    assert_eq!(project.creator, creator);
+    assert_eq!(
+        (project.vesting, project.sale),
+        (launch.vesting.clone(), launch.sale.clone())
+    );
+    assert_eq!(project.factory, factories.client.address);
+
+    assert_emitted(
+        &env,
+        &factories.client.address,
+        (symbol_short!("wiring"), 0u32),
+        WiringManifest {
+            token: launch.token.clone(),
+            vesting: launch.vesting.clone(),
+            sale: launch.sale.clone(),
+            creator,
+            sale_allocation: 10_000,
+            vesting_creators: vec![&env, airdrop, launch.sale],
+            pause_registry: None,
+            guardian: factories.guardian.clone(),
+            fee_recipient: factories.fee_recipient.clone(),
+        },
+    );
+}
+
+#[test]
+fn test_launches_of_two_creators_do_not_collide() {
+    let env = test_env();
+    let factories = FactoryFixture::new(&env);
+    let config = factories.config();
+
+    // Same salt, different creators
+    let first = factories.launch(&Address::generate(&env), &salt(&env), &config);
+    let second = factories.launch(&Address::generate(&env), &salt(&env), &config);
+    assert_ne!(first.token, second.token);
+    assert_ne!(first.vesting, second.vesting);
+    assert_ne!(first.sale, second.sale);
+    assert_eq!(factories.client.get_launch(&1), second);
+}
+
+#[test]
+#[should_panic(expected = "sale factory pays fees to another recipient")]
+fn test_launch_refused_while_fees_go_elsewhere() {
+    let env = test_env();
+    let factories = FactoryFixture::new(&env);
+    factories
+        .client
+        .set_required_fee_recipient(&Some(Address::generate(&env)));
+    factories.launch(&Address::generate(&env), &salt(&env), &factories.config());
+}
+
+#[test]
+fn test_launch_allowed_once_fees_reach_required_recipient() {
+    let env = test_env();
+    let factories = FactoryFixture::new(&env);
+    factories
+        .client
+        .set_required_fee_recipient(&Some(factories.fee_recipient.clone()));
+    let launch = factories.launch(&Address::generate(&env), &salt(&env), &factories.config());
+    let sale = SaleContractClient::new(&env, &launch.sale);
+    assert_eq!(sale.get_config().fee_recipient, factories.fee_recipient);
+}
@@ -0,0 +1,58 @@
+//! # Launch Lifecycle Tests
+//!
+//! Whole launches scripted with `Scenario`: the token, its vesting and its
+//! sale deployed and wired by the launch factory, then buying, finalizing,
+//! moving the ledger and claiming. New features that cut across the
+//! contracts get a case here.
+
+use launchpad_testutils::{test_env, Scenario};
+
+#[test]
+fn test_launch_sale_and_team_vesting() {
+    let env = test_env();
+    let mut launch = Scenario::new(&env)
+        .team(200_000, 1_000, 5_000)
+        .team(100_000, 1_000, 3_000)
+        .launch();
+    // Everything not allocated at launch stays with the creator
+    assert_eq!(launch.token.balance(&launch.creator), 690_000);
+
+    launch.buy(&[2_000, 1_000]).finalize();
+    assert_eq!(launch.sale.payment_token.balance(&launch.sale.admin), 2_925);
+
+    launch.jump_to(3_000).claim();
+    let (buyers, team) = (&launch.buyers, &launch.team);
+    assert_eq!(launch.token.balance(&buyers[0]), 2_000);
+    assert_eq!(launch.token.balance(&buyers[1]), 1_000);
+    assert_eq!(launch.token.balance(&team[0]), 100_000);
+    assert_eq!(launch.token.balance(&team[1]), 100_000);
+
+    // Claiming again only releases what vested since
+    launch.jump_to(5_000).claim();
+    assert_eq!(launch.token.balance(&launch.team[0]), 200_000);
+    assert_eq!(launch.token.balance(&launch.vesting.client.address), 0);
+    // 10 000 for sale, 3 000 sold, the rest returned to the creator at
+    // finalize
+    assert_eq!(launch.token.balance(&launch.sale.client.address), 0);
+    assert_eq!(launch.token.balance(&launch.creator), 697_000);
+}
+
+#[test]
+fn test_launch_with_custom_sale() {
+    let env = test_env();
+    let mut launch = Scenario::new(&env)
+        .supply(50_000)
+        .sale_allocation(20_000)
+        .sale(|_, config| {
+            config.hard_cap = 20_000;
+            config.max_contribution = 10_000;
+            config.end_ledger = 400;
+        })
+        .launch();
+
+    launch.buy(&[10_000, 10_000]).advance(50).finalize();
+    assert_eq!(env.ledger().sequence(), 400);
+    launch.claim();
+    assert_eq!(launch.token.balance(&launch.sale.client.address), 0);
+    assert_eq!(launch.token.balance(&launch.creator), 30_000);
+}
@@ -0,0 +1,215 @@
+//! # Migration Harness Tests
+//!
+//! An upgrade replaces a contract's code and keeps its storage, so what
+//! has to be trusted is how the new code treats the old state. These tests
+//! populate the token and vesting contracts, take a `StorageSnapshot`,
+//! install a new version with `upgrade_in_place`, run its migration, and
+//! diff the storage against the snapshot.
+//!
+//! ## Invariants
+//!
+//! 1. **Reinstalling Is a No-Op**
+//!    Installing the same version again changes no entry, and every
+//!    balance, allowance and schedule reads as before.
+//!
+//! 2. **Migrations Change Only What They Say**
+//!    The storage diff of a migration lists exactly the entries it is
+//!    meant to add, rewrite or remove.
+
+use launchpad_testutils::{
+    jump_to, test_env, upgrade_in_place, StorageKind, StorageSnapshot, TokenFixture, VestingFixture,
+};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, xdr::ScVal, Address, Env, Symbol,
+};
+use soroban_token::{AccountState, DataKey, TokenContract, TokenContractClient};
+use soroban_vesting::VestingContract;
+
+const VERSION: Symbol = symbol_short!("version");
+
+/// A second token version: it records a storage version on `migrate` and
+/// serves balances with the current code.
+#[contract]
+struct TokenV2;
+
+#[contractimpl]
+impl TokenV2 {
+    pub fn migrate(env: Env) {
+        env.storage().instance().set(&VERSION, &2u32);
+    }
+
+    pub fn version(env: Env) -> u32 {
+        env.storage().instance().get(&VERSION).unwrap_or(1)
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        TokenContract::balance(env, id)
+    }
+}
+
+struct Setup {
+    env: Env,
+    token: TokenFixture,
+    vesting: VestingFixture,
+    holder: Address,
+    spender: Address,
+    recipient: Address,
+}
+
+/// A token with holders and an allowance, and vesting of it with a
+/// partly released schedule.
+fn setup() -> Setup {
+    let env = test_env();
+    let token = TokenFixture::launch_token(&env, 1_000_000);
+    let (holder, spender) = (token.funded(5_000), Address::generate(&env));
+    token.client.approve(&holder, &spender, &1_000, &10_000);
+
+    let vesting = VestingFixture::with_token(&env, TokenFixture::launch_token(&env, 0));
+    let recipient = vesting.schedule(1_000, 100, 200);
+    jump_to(&env, 150);
+    vesting.client.release(&recipient);
+
+    Setup {
+        env,
+        token,
+        vesting,
+        holder,
+        spender,
+        recipient,
+    }
+}
+
+#[test]
+fn test_reinstalling_token_keeps_state() {
+    let s = setup();
+    let before = StorageSnapshot::take(&s.env, &s.token.address);
+    assert_eq!(
+        before
+            .get::<_, AccountState>(
+                &s.env,
+                StorageKind::Persistent,
+                DataKey::Account(s.holder.clone())
+            )
+            .map(|account| account.balance),
+        Some(5_000)
+    );
+
+    upgrade_in_place(&s.env, &s.token.address, TokenContract);
+
+    let after = StorageSnapshot::take(&s.env, &s.token.address);
+    assert_eq!(before.diff(&after), vec![]);
+    assert_eq!(s.token.balance(&s.holder), 5_000);
+    assert_eq!(s.token.client.allowance(&s.holder, &s.spender), 1_000);
+}
+
+#[test]
+fn test_reinstalling_vesting_keeps_state() {
+    let s = setup();
+    let vesting = &s.vesting.client.address;
+    let before = StorageSnapshot::take(&s.env, vesting);
+
+    upgrade_in_place(&s.env, vesting, VestingContract);
+
+    assert_eq!(before.diff(&StorageSnapshot::take(&s.env, vesting)), vec![]);
+    let schedule = s.vesting.client.get_schedule(&s.recipient);
+    assert_eq!((schedule.total_amount, schedule.released), (1_000, 500));
+}
+
+#[test]
+fn test_migration_diff_lists_only_its_changes() {
+    let s = setup();
+    let before = StorageSnapshot::take(&s.env, &s.token.address);
+
+    upgrade_in_place(&s.env, &s.token.address, TokenV2);
+    let v2 = TokenV2Client::new(&s.env, &s.token.address);
+    assert_eq!(v2.version(), 1);
+    v2.migrate();
+
+    let after = StorageSnapshot::take(&s.env, &s.token.address);
+    let changes = before.diff(&after);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].kind, StorageKind::Instance);
+    assert_eq!(changes[0].key, ScVal::Symbol("version".try_into().unwrap()));
+    assert_eq!(
+        (&changes[0].before, &changes[0].after),
+        (&None, &Some(ScVal::U32(2)))
+    );
+
+    assert_eq!(v2.version(), 2);
+    assert_eq!(v2.balance(&s.holder), 5_000);
+    // Going back to the current code still reads the migrated storage
+    upgrade_in_place(&s.env, &s.token.address, TokenContract);
+    assert_eq!(
+        TokenContractClient::new(&s.env, &s.token.address).total_supply(),
+        1_005_000
+    );
+}
+
+#[test]
+fn test_unpacked_accounts_migrate_to_account_state() {
+    let s = setup();
+    // Rewind the holder to the layout from before `AccountState`
+    s.env.as_contract(&s.token.address, || {
+        let storage = s.env.storage().persistent();
+        s.env.storage().instance().remove(&DataKey::PackedAccounts);
+        storage.remove(&DataKey::Account(s.holder.clone()));
+        storage.set(&DataKey::Balance(s.holder.clone()), &5_000i128);
+        storage.set(&DataKey::IsHolder(s.holder.clone()), &true);
+    });
+    let before = StorageSnapshot::take(&s.env, &s.token.address);
+
+    let client = TokenContractClient::new(&s.env, &s.token.address);
+    client.migrate_accounts(&soroban_sdk::vec![&s.env, s.holder.clone()]);
+
+    let changes = before.diff(&StorageSnapshot::take(&s.env, &s.token.address));
+    let added: Vec<_> = changes.iter().filter(|c| c.before.is_none()).collect();
+    let removed: Vec<_> = changes.iter().filter(|c| c.after.is_none()).collect();
+    assert_eq!((changes.len(), added.len(), removed.len()), (3, 1, 2));
+    assert_eq!(client.balance(&s.holder), 5_000);
+    assert_eq!(client.allowance(&s.holder, &s.spender), 1_000);
+}
+
+#[test]
+fn test_persistent_allowances_move_to_temporary() {
+    let s = setup();
+    let (holder, spender) = (&s.holder, &s.spender);
+    // Rewind the allowance to the layout from before `Approval`
+    s.env.as_contract(&s.token.address, || {
+        let storage = s.env.storage().persistent();
+        s.env
+            .storage()
+            .instance()
+            .remove(&DataKey::TemporaryAllowances);
+        s.env
+            .storage()
+            .temporary()
+            .remove(&DataKey::Approval(holder.clone(), spender.clone()));
+        storage.set(
+            &DataKey::Allowance(holder.clone(), spender.clone()),
+            &1_000i128,
+        );
+        storage.set(
+            &DataKey::AllowanceExpiration(holder.clone(), spender.clone()),
+            &10_000u32,
+        );
+    });
+    assert_eq!(s.token.client.allowance(holder, spender), 1_000);
+    let before = StorageSnapshot::take(&s.env, &s.token.address);
+
+    s.token.client.approve(holder, spender, &700, &10_000);
+
+    let changes = before.diff(&StorageSnapshot::take(&s.env, &s.token.address));
+    let kinds: Vec<_> = changes
+        .iter()
+        .map(|c| (c.kind, c.before.is_some(), c.after.is_some()))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (StorageKind::Persistent, true, false),
+            (StorageKind::Persistent, true, false),
+            (StorageKind::Temporary, false, true),
+        ]
+    );
+    assert_eq!(s.token.client.allowance(holder, spender), 700);
+}
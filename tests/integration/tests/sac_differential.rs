@@ -0,0 +1,220 @@
+//! # Differential Tests Against the Stellar Asset Contract
+//!
+//! Wallets and other contracts treat every SEP-41 token the same way, and
+//! the built-in Stellar Asset Contract is the reference they are written
+//! against. Random operation sequences are replayed on a `TokenContract`
+//! and on a Stellar asset contract in the same environment, and the two
+//! must agree after every step.
+//!
+//! ## Invariants
+//!
+//! 1. **Same Outcome**
+//!    An operation succeeds on one token exactly when it succeeds on the
+//!    other.
+//!
+//! 2. **Same Balances**
+//!    Every account holds the same balance on both tokens.
+//!
+//! 3. **Same Allowances**
+//!    Every owner/spender pair has the same allowance on both tokens,
+//!    expiry included.
+//!
+//! ## Intended differences
+//!
+//! `TokenContract` rejects zero amounts, where the Stellar asset contract
+//! accepts them as no-ops, so the sequences only move positive amounts.
+//! Transfer fees, KYC restrictions and pauses are off, as the Stellar asset
+//! contract has no equivalent.
+
+use launchpad_testutils::{jump_to, test_env, TokenFixture};
+use proptest::prelude::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+/// Accounts the sequences move tokens between.
+const ACCOUNTS: usize = 3;
+
+/// Balance every account starts with on both tokens.
+const START_BALANCE: i128 = 1_000;
+
+/// One step of a random sequence. Accounts are indices into the fixture's
+/// accounts.
+#[derive(Clone, Debug)]
+enum Op {
+    Transfer {
+        from: usize,
+        to: usize,
+        amount: i128,
+    },
+    Approve {
+        from: usize,
+        spender: usize,
+        amount: i128,
+        valid_for: u32,
+    },
+    TransferFrom {
+        spender: usize,
+        from: usize,
+        to: usize,
+        amount: i128,
+    },
+    Burn {
+        from: usize,
+        amount: i128,
+    },
+    BurnFrom {
+        spender: usize,
+        from: usize,
+        amount: i128,
+    },
+    /// Advance the ledger.
+    Wait(u32),
+}
+
+fn any_op() -> impl Strategy<Value = Op> {
+    let account = 0..ACCOUNTS;
+    let amount = 1i128..=1_500;
+    prop_oneof![
+        (account.clone(), account.clone(), amount.clone())
+            .prop_map(|(from, to, amount)| Op::Transfer { from, to, amount }),
+        (account.clone(), account.clone(), 0i128..=1_500, 0u32..20).prop_map(
+            |(from, spender, amount, valid_for)| Op::Approve {
+                from,
+                spender,
+                amount,
+                valid_for
+            }
+        ),
+        (
+            account.clone(),
+            account.clone(),
+            account.clone(),
+            amount.clone()
+        )
+            .prop_map(|(spender, from, to, amount)| Op::TransferFrom {
+                spender,
+                from,
+                to,
+                amount
+            }),
+        (account.clone(), amount.clone()).prop_map(|(from, amount)| Op::Burn { from, amount }),
+        (account.clone(), account, amount).prop_map(|(spender, from, amount)| Op::BurnFrom {
+            spender,
+            from,
+            amount
+        }),
+        (1u32..15).prop_map(Op::Wait),
+    ]
+}
+
+struct Setup {
+    env: Env,
+    launch: TokenFixture,
+    sac: TokenFixture,
+    accounts: [Address; ACCOUNTS],
+}
+
+/// Both tokens, with every account holding `START_BALANCE` of each.
+fn setup() -> Setup {
+    let env = test_env();
+    // Long sequences outgrow the default per-test budget
+    env.budget().reset_unlimited();
+    let launch = TokenFixture::launch_token(&env, 0);
+    let sac = TokenFixture::stellar_asset(&env);
+    let accounts = core::array::from_fn(|_| Address::generate(&env));
+    for account in &accounts {
+        launch.mint(account, START_BALANCE);
+        sac.mint(account, START_BALANCE);
+    }
+    Setup {
+        env,
+        launch,
+        sac,
+        accounts,
+    }
+}
+
+/// Apply `op` to `token` and report whether it succeeded.
+fn apply(s: &Setup, token: &TokenFixture, op: &Op) -> bool {
+    let a = &s.accounts;
+    let client = &token.client;
+    match *op {
+        Op::Transfer { from, to, amount } => client.try_transfer(&a[from], &a[to], &amount).is_ok(),
+        Op::Approve {
+            from,
+            spender,
+            amount,
+            valid_for,
+        } => {
+            let expiration = s.env.ledger().sequence() + valid_for;
+            client
+                .try_approve(&a[from], &a[spender], &amount, &expiration)
+                .is_ok()
+        }
+        Op::TransferFrom {
+            spender,
+            from,
+            to,
+            amount,
+        } => client
+            .try_transfer_from(&a[spender], &a[from], &a[to], &amount)
+            .is_ok(),
+        Op::Burn { from, amount } => client.try_burn(&a[from], &amount).is_ok(),
+        Op::BurnFrom {
+            spender,
+            from,
+            amount,
+        } => client.try_burn_from(&a[spender], &a[from], &amount).is_ok(),
+        Op::Wait(_) => true,
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    /// Invariants 1–3 after every step of a random sequence.
+    #[test]
+    fn prop_token_matches_stellar_asset(ops in prop::collection::vec(any_op(), 1..20)) {
+        let s = setup();
+        for op in &ops {
+            if let Op::Wait(ledgers) = op {
+                jump_to(&s.env, s.env.ledger().sequence() + ledgers);
+            }
+            let launch_ok = apply(&s, &s.launch, op);
+            let sac_ok = apply(&s, &s.sac, op);
+            prop_assert_eq!(launch_ok, sac_ok, "outcomes differ on {:?}", op);
+
+            for owner in &s.accounts {
+                prop_assert_eq!(s.launch.balance(owner), s.sac.balance(owner));
+                for spender in &s.accounts {
+                    prop_assert_eq!(
+                        s.launch.client.allowance(owner, spender),
+                        s.sac.client.allowance(owner, spender)
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_metadata_matches_stellar_asset_decimals() {
+    let s = setup();
+    assert_eq!(s.launch.client.decimals(), s.sac.client.decimals());
+}
+
+#[test]
+fn test_expired_allowance_rejected_by_both() {
+    let s = setup();
+    let [owner, spender, to] = &s.accounts;
+    for token in [&s.launch, &s.sac] {
+        token.client.approve(owner, spender, &500, &10);
+    }
+    jump_to(&s.env, 11);
+    for token in [&s.launch, &s.sac] {
+        assert_eq!(token.client.allowance(owner, spender), 0);
+        assert!(token
+            .client
+            .try_transfer_from(spender, owner, to, &1)
+            .is_err());
+    }
+}
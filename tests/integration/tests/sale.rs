@@ -0,0 +1,42 @@
+//! # Sale Integration Tests
+//!
+//! The sale lifecycle through `SaleFixture`, checking balances and the
+//! events indexers rely on.
+
+use launchpad_events::CLAIMED;
+use launchpad_testutils::{assert_emitted, jump_to, test_env, SaleFixture};
+
+#[test]
+fn test_sale_contribute_finalize_claim() {
+    let env = test_env();
+    let s = SaleFixture::new(&env);
+    let (alice, bob) = (s.buyer(2_000), s.buyer(1_000));
+    s.client.contribute(&alice, &2_000);
+    s.client.contribute(&bob, &1_000);
+
+    jump_to(&env, 200);
+    s.client.finalize();
+    // 2.5 % of 3 000 = 75
+    assert_eq!(s.payment_token.balance(&s.fee_recipient), 75);
+    assert_eq!(s.payment_token.balance(&s.admin), 2_925);
+
+    s.client.claim(&alice);
+    assert_emitted(&env, &s.client.address, (CLAIMED, alice.clone()), 2_000i128);
+    s.client.claim(&bob);
+    assert_eq!(s.sale_token.balance(&alice), 2_000);
+    assert_eq!(s.sale_token.balance(&bob), 1_000);
+}
+
+#[test]
+fn test_failed_sale_refunds_every_buyer() {
+    let env = test_env();
+    let s = SaleFixture::new(&env);
+    let buyer = s.buyer(500);
+    s.client.contribute(&buyer, &500);
+
+    jump_to(&env, 200);
+    s.client.finalize();
+    s.client.refund(&buyer);
+    assert_eq!(s.payment_token.balance(&buyer), 500);
+    assert_eq!(s.payment_token.balance(&s.client.address), 0);
+}
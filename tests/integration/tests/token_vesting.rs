@@ -0,0 +1,160 @@
+//! # Token + Vesting Integration Tests
+//!
+//! The vesting unit tests fund schedules with a Stellar asset contract and
+//! mostly check schedule arithmetic. These tests register the real
+//! `TokenContract` and `VestingContract` together and follow every flow
+//! through to actual balances.
+//!
+//! ## Invariants
+//!
+//! 1. **Conservation**
+//!    Vesting moves tokens, it never mints or burns them: the total supply
+//!    is unchanged by every flow.
+//!
+//! 2. **Full accounting**
+//!    Once a schedule is fully released or revoked, the vesting contract
+//!    holds none of its tokens, and the recipient and admin hold exactly
+//!    what the schedule says they should.
+//!
+//! 3. **Real funding**
+//!    A schedule is only worth the tokens actually sent to the vesting
+//!    contract; releases beyond them fail in the token.
+
+use launchpad_testutils::{jump_to, test_env, TokenFixture, VestingFixture};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_token::TokenContractClient;
+use soroban_vesting::VestingContractClient;
+
+const SUPPLY: i128 = 1_000_000;
+
+struct Setup {
+    env: Env,
+    token: TokenContractClient<'static>,
+    vesting: VestingContractClient<'static>,
+    /// Holds the whole token supply.
+    issuer: Address,
+    /// Administers the vesting contract.
+    admin: Address,
+    recipient: Address,
+}
+
+/// The real token and vesting contracts; the ledger starts at 50.
+fn setup() -> Setup {
+    let env = test_env();
+    jump_to(&env, 50);
+    let vesting = VestingFixture::with_token(&env, TokenFixture::launch_token(&env, SUPPLY));
+
+    Setup {
+        token: TokenContractClient::new(&env, &vesting.token.address),
+        issuer: vesting.token.admin.clone(),
+        admin: vesting.admin.clone(),
+        recipient: Address::generate(&env),
+        vesting: vesting.client,
+        env,
+    }
+}
+
+/// Fund and create a 1 000-token schedule vesting from ledger 100 to 200.
+fn fund_schedule(s: &Setup) {
+    s.token.transfer(&s.issuer, &s.vesting.address, &1_000);
+    s.vesting.create_schedule(&s.recipient, &1_000, &100, &200);
+}
+
+#[test]
+fn test_release_pays_out_linearly() {
+    let s = setup();
+    fund_schedule(&s);
+    assert_eq!(s.token.balance(&s.vesting.address), 1_000);
+
+    jump_to(&s.env, 99);
+    assert!(s.vesting.try_release(&s.recipient).is_err());
+
+    jump_to(&s.env, 150);
+    s.vesting.release(&s.recipient);
+    assert_eq!(s.token.balance(&s.recipient), 500);
+    assert_eq!(s.token.balance(&s.vesting.address), 500);
+
+    jump_to(&s.env, 175);
+    s.vesting.release(&s.recipient);
+    assert_eq!(s.token.balance(&s.recipient), 750);
+
+    jump_to(&s.env, 250);
+    s.vesting.release(&s.recipient);
+    assert_eq!(s.token.balance(&s.recipient), 1_000);
+    assert_eq!(s.token.balance(&s.vesting.address), 0);
+    assert_eq!(s.vesting.released_amount(&s.recipient), 1_000);
+    assert!(s.vesting.try_release(&s.recipient).is_err());
+
+    assert_eq!(s.token.balance(&s.issuer), SUPPLY - 1_000);
+    assert_eq!(s.token.total_supply(), SUPPLY);
+}
+
+#[test]
+fn test_revoke_splits_vested_and_unvested() {
+    let s = setup();
+    fund_schedule(&s);
+
+    jump_to(&s.env, 130);
+    s.vesting.release(&s.recipient);
+    jump_to(&s.env, 160);
+    s.vesting.revoke(&s.recipient);
+
+    // 600 vested in total, 300 of it released before the revoke
+    assert_eq!(s.token.balance(&s.recipient), 600);
+    assert_eq!(s.token.balance(&s.admin), 400);
+    assert_eq!(s.token.balance(&s.vesting.address), 0);
+    assert_eq!(s.token.total_supply(), SUPPLY);
+
+    jump_to(&s.env, 250);
+    assert!(s.vesting.try_release(&s.recipient).is_err());
+}
+
+#[test]
+fn test_creator_funds_schedule_through_token() {
+    let s = setup();
+    let creator = Address::generate(&s.env);
+    s.token.transfer(&s.issuer, &creator, &2_000);
+    s.vesting.set_creator(&creator, &true);
+
+    s.vesting
+        .create_schedule_from(&creator, &s.recipient, &1_000, &100, &200);
+    assert_eq!(s.token.balance(&creator), 1_000);
+    assert_eq!(s.token.balance(&s.vesting.address), 1_000);
+
+    jump_to(&s.env, 200);
+    s.vesting.release(&s.recipient);
+    assert_eq!(s.token.balance(&s.recipient), 1_000);
+}
+
+#[test]
+fn test_fee_exempt_vesting_pays_out_in_full_under_a_fee() {
+    let s = setup();
+    let (treasury, creator) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.token.set_transfer_fee(&100, &treasury);
+    s.token.set_fee_exempt(&s.vesting.address, &true);
+    s.token.transfer(&s.issuer, &creator, &2_000);
+    s.vesting.set_creator(&creator, &true);
+
+    // The schedule books what the creator sent, and all of it arrives
+    s.vesting
+        .create_schedule_from(&creator, &s.recipient, &1_000, &100, &200);
+    assert_eq!(s.token.balance(&s.vesting.address), 1_000);
+
+    jump_to(&s.env, 200);
+    s.vesting.release(&s.recipient);
+    assert_eq!(s.token.balance(&s.recipient), 1_000);
+    assert_eq!(s.token.balance(&s.vesting.address), 0);
+    // Only the issuer's transfer to the creator paid the fee
+    assert_eq!(s.token.balance(&treasury), 20);
+}
+
+#[test]
+fn test_unfunded_schedule_cannot_release() {
+    let s = setup();
+    s.vesting.create_schedule(&s.recipient, &1_000, &100, &200);
+
+    jump_to(&s.env, 200);
+    assert!(s.vesting.try_release(&s.recipient).is_err());
+    assert_eq!(s.token.balance(&s.recipient), 0);
+    assert_eq!(s.vesting.released_amount(&s.recipient), 0);
+}